@@ -9,6 +9,7 @@ use rmcp::{
 };
 use tracing::info;
 
+use crate::config::BlockchainConfig;
 use crate::services::blockchain::BlockchainService;
 use crate::services::search::SearchService;
 
@@ -27,10 +28,16 @@ pub struct CombinedService {
 impl CombinedService {
     /// Create a new combined service instance
     pub async fn new() -> Result<Self> {
+        let blockchain_config = BlockchainConfig::from_env()?;
+        Self::with_blockchain_config(blockchain_config).await
+    }
+
+    /// Create a combined service with an explicit blockchain configuration
+    pub async fn with_blockchain_config(blockchain_config: BlockchainConfig) -> Result<Self> {
         info!("🔧 Creating combined MCP service");
         
         // Create blockchain service
-        let blockchain = BlockchainService::new().await
+        let blockchain = BlockchainService::with_config(blockchain_config).await
             .map_err(|e| anyhow::anyhow!("Failed to create blockchain service: {}", e))?;
         
         // Create search service
@@ -45,7 +52,7 @@ impl CombinedService {
     }
 
     // Blockchain tools - delegate to blockchain service
-    #[tool(description = "Get the balance of an account in wei")]
+    #[tool(description = "Get the balance of an account in wei, optionally at a past block")]
     async fn balance(
         &self,
         Parameters(request): Parameters<crate::services::blockchain::BalanceRequest>,
@@ -53,7 +60,7 @@ impl CombinedService {
         self.blockchain.balance(Parameters(request)).await
     }
 
-    #[tool(description = "Send ETH from Alice to a recipient")]
+    #[tool(description = "Send ETH to a recipient from Alice or another configured sender - amount may be 'all'/'max' or a percentage like '50%'; set dry_run to simulate without broadcasting")]
     async fn send_eth(
         &self,
         Parameters(request): Parameters<crate::services::blockchain::TransferRequest>,
@@ -61,7 +68,15 @@ impl CombinedService {
         self.blockchain.send_eth(Parameters(request)).await
     }
 
-    #[tool(description = "Check if a contract is deployed at the given address")]
+    #[tool(description = "Estimate the gas and total cost of sending ETH from Alice to a recipient")]
+    async fn estimate_transfer_cost(
+        &self,
+        Parameters(request): Parameters<crate::services::blockchain::TransferCostRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        self.blockchain.estimate_transfer_cost(Parameters(request)).await
+    }
+
+    #[tool(description = "Check if a contract is deployed at the given address and report its code size and keccak256 code hash")]
     async fn is_contract_deployed(
         &self,
         Parameters(request): Parameters<crate::services::blockchain::ContractDeploymentRequest>,
@@ -69,7 +84,55 @@ impl CombinedService {
         self.blockchain.is_contract_deployed(Parameters(request)).await
     }
 
-    #[tool(description = "Get ERC-20 token balance for an account")]
+    #[tool(description = "Deploy a contract from Alice given its creation bytecode (hex) and optional constructor signature and args; returns the new contract address - NOTE: Requires private key access")]
+    async fn deploy_contract(
+        &self,
+        Parameters(request): Parameters<crate::services::blockchain::DeployContractRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        self.blockchain.deploy_contract(Parameters(request)).await
+    }
+
+    #[tool(description = "Call any read-only (view) function on a contract and decode the result, e.g. signature 'totalSupply()(uint256)' or 'balanceOf(address)(uint256)' with args; use for contracts the other tools don't cover")]
+    async fn call_contract(
+        &self,
+        Parameters(request): Parameters<crate::services::blockchain::ContractCallRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        self.blockchain.call_contract(Parameters(request)).await
+    }
+
+    #[tool(description = "Call a state-changing function on any contract from Alice (e.g., mint() on a test token) with optional ETH value and gas limit; reverts are caught before sending - NOTE: Requires private key access")]
+    async fn send_contract_call(
+        &self,
+        Parameters(request): Parameters<crate::services::blockchain::ContractSendRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        self.blockchain.send_contract_call(Parameters(request)).await
+    }
+
+    #[tool(description = "Resolve an ENS name (e.g., vitalik.eth) to its Ethereum address")]
+    async fn resolve_ens(
+        &self,
+        Parameters(request): Parameters<crate::services::blockchain::EnsResolveRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        self.blockchain.resolve_ens(Parameters(request)).await
+    }
+
+    #[tool(description = "Reverse-resolve an Ethereum address to its primary ENS name, if it has one")]
+    async fn reverse_resolve_ens(
+        &self,
+        Parameters(request): Parameters<crate::services::blockchain::EnsReverseRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        self.blockchain.reverse_resolve_ens(Parameters(request)).await
+    }
+
+    #[tool(description = "Get ENS text records (e.g., avatar, email, url) for a name; empty records are omitted")]
+    async fn get_ens_records(
+        &self,
+        Parameters(request): Parameters<crate::services::blockchain::EnsRecordsRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        self.blockchain.get_ens_records(Parameters(request)).await
+    }
+
+    #[tool(description = "Get ERC-20 token balance for an account, optionally at a past block")]
     async fn token_balance(
         &self,
         Parameters(request): Parameters<crate::services::blockchain::TokenBalanceRequest>,
@@ -77,6 +140,189 @@ impl CombinedService {
         self.blockchain.token_balance(Parameters(request)).await
     }
 
+    #[tool(description = "Get the WETH balance for an account using the configured WETH contract")]
+    async fn weth_balance(
+        &self,
+        Parameters(request): Parameters<crate::services::blockchain::WethBalanceRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        self.blockchain.weth_balance(Parameters(request)).await
+    }
+
+    #[tool(description = "Transfer ERC-20 tokens from Alice to a recipient")]
+    async fn transfer_token(
+        &self,
+        Parameters(request): Parameters<crate::services::blockchain::TokenTransferRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        self.blockchain.transfer_token(Parameters(request)).await
+    }
+
+    #[tool(description = "Approve a spender to move Alice's ERC-20 tokens")]
+    async fn approve_token(
+        &self,
+        Parameters(request): Parameters<crate::services::blockchain::TokenApprovalRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        self.blockchain.approve_token(Parameters(request)).await
+    }
+
+    #[tool(description = "Get the ERC-20 allowance an owner has granted to a spender")]
+    async fn get_allowance(
+        &self,
+        Parameters(request): Parameters<crate::services::blockchain::AllowanceRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        self.blockchain.get_allowance(Parameters(request)).await
+    }
+
+    #[tool(description = "Get the owner of an ERC-721 NFT (collection address and token id), with the owner's ENS name if it has one")]
+    async fn nft_owner(
+        &self,
+        Parameters(request): Parameters<crate::services::blockchain::NftOwnerRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        self.blockchain.nft_owner(Parameters(request)).await
+    }
+
+    #[tool(description = "Get how many NFTs an address holds in an ERC-721 collection")]
+    async fn nft_balance(
+        &self,
+        Parameters(request): Parameters<crate::services::blockchain::NftBalanceRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        self.blockchain.nft_balance(Parameters(request)).await
+    }
+
+    #[tool(description = "Run several reads (balance, token_balance, allowance, nonce) concurrently in one call - use for dashboards or composite queries")]
+    async fn batch_read(
+        &self,
+        Parameters(request): Parameters<crate::services::blockchain::BatchReadRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        self.blockchain.batch_read(Parameters(request)).await
+    }
+
+    #[tool(description = "Get recent transaction history for an address by scanning the latest blocks")]
+    async fn get_transaction_history(
+        &self,
+        Parameters(request): Parameters<crate::services::blockchain::TransactionHistoryRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        self.blockchain.get_transaction_history(Parameters(request)).await
+    }
+
+    #[tool(description = "Check the status of a transaction by hash - returns success/failure and receipt details")]
+    async fn check_transaction_status(
+        &self,
+        Parameters(request): Parameters<crate::services::blockchain::TransactionStatusRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        self.blockchain.check_transaction_status(Parameters(request)).await
+    }
+
+    #[tool(description = "Watch a sent transaction until it is mined and confirmed, polling its receipt and reporting each status change (pending in mempool, mined, confirmed) - use when a send or swap timed out waiting for confirmation")]
+    async fn monitor_transaction(
+        &self,
+        Parameters(request): Parameters<crate::services::blockchain::MonitorTransactionRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        self.blockchain.monitor_transaction(Parameters(request)).await
+    }
+
+    #[tool(description = "Look up a token's contract address by symbol: the configured token table first, then a web search whose candidates are checked on-chain for deployed code and a matching symbol(); reports which source the address came from")]
+    async fn resolve_token_address(
+        &self,
+        Parameters(request): Parameters<crate::services::blockchain::ResolveTokenAddressRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        self.blockchain.resolve_token_address(&self.search, Parameters(request)).await
+    }
+
+    #[tool(description = "Get event logs emitted by a contract, optionally filtered by event signature and block range; Transfer, Approval and Swap events are decoded")]
+    async fn get_logs(
+        &self,
+        Parameters(request): Parameters<crate::services::blockchain::LogQueryRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        self.blockchain.get_logs(Parameters(request)).await
+    }
+
+    #[tool(description = "Decode a transaction's calldata into the function name and named parameters")]
+    async fn decode_calldata(
+        &self,
+        Parameters(request): Parameters<crate::services::blockchain::DecodeCalldataRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        self.blockchain.decode_calldata(Parameters(request)).await
+    }
+
+    #[tool(description = "Get the latest and pending nonce for an address - shows whether transactions are queued")]
+    async fn get_nonce(
+        &self,
+        Parameters(request): Parameters<crate::services::blockchain::NonceRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        self.blockchain.get_nonce(Parameters(request)).await
+    }
+
+    #[tool(description = "Set an account's ETH balance on an anvil node (anvil_setBalance) - for funding test accounts, not supported on real networks")]
+    async fn fund_account(
+        &self,
+        Parameters(request): Parameters<crate::services::blockchain::FundAccountRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        self.blockchain.fund_account(Parameters(request)).await
+    }
+
+    #[tool(description = "Send ETH or ERC-20 tokens from any address (e.g., a whale on a mainnet fork) without its private key, using anvil impersonation - test-only, requires ALLOW_IMPERSONATION=true")]
+    async fn impersonate_and_send(
+        &self,
+        Parameters(request): Parameters<crate::services::blockchain::ImpersonatedTransferRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        self.blockchain.impersonate_and_send(Parameters(request)).await
+    }
+
+    #[tool(description = "List pending and queued transactions from the node's txpool, optionally filtered by address")]
+    async fn get_pending_transactions(
+        &self,
+        Parameters(request): Parameters<crate::services::blockchain::PendingTransactionsRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        self.blockchain.get_pending_transactions(Parameters(request)).await
+    }
+
+    #[tool(description = "Get the authoritative on-chain USD price of a token (e.g., ETH, WBTC, USDC) from its Chainlink price feed - prefer this over web search for prices")]
+    async fn get_onchain_price(
+        &self,
+        Parameters(request): Parameters<crate::services::blockchain::OnchainPriceRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        self.blockchain.get_onchain_price(Parameters(request)).await
+    }
+
+    #[tool(description = "Convert an amount between wei, gwei and ether (e.g., how many gwei is 0.00003 ETH)")]
+    async fn convert_units(
+        &self,
+        Parameters(request): Parameters<crate::services::blockchain::UnitConversionRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        self.blockchain.convert_units(Parameters(request)).await
+    }
+
+    #[tool(description = "Calculate the expected output, minimum output after slippage and price impact of a Uniswap V2 swap from the pair's reserves (x*y=k with the 0.3% fee) - e.g., how much USDC 10 ETH buys at 0.5% slippage")]
+    async fn calculate_swap_output(
+        &self,
+        Parameters(request): Parameters<crate::services::blockchain::SwapOutputRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        self.blockchain.calculate_swap_output(Parameters(request)).await
+    }
+
+    #[tool(description = "Get current chain status - latest block number, chain id and gas price in gwei")]
+    async fn get_chain_status(&self) -> Result<CallToolResult, McpError> {
+        self.blockchain.get_chain_status().await
+    }
+
+    #[tool(description = "Health check - server uptime, provider connectivity, chain id, loaded accounts and private key availability")]
+    async fn health_check(&self) -> Result<CallToolResult, McpError> {
+        self.blockchain.health_check().await
+    }
+
+    #[tool(description = "Save a named address (e.g. treasury, deployer) to the address book so it can be used as a recipient")]
+    async fn add_address_book_entry(
+        &self,
+        Parameters(request): Parameters<crate::services::blockchain::AddressBookEntryRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        self.blockchain.add_address_book_entry(Parameters(request)).await
+    }
+
+    #[tool(description = "List the named addresses in the address book")]
+    async fn list_address_book(&self) -> Result<CallToolResult, McpError> {
+        self.blockchain.list_address_book().await
+    }
+
     #[tool(description = "Get list of available test accounts")]
     async fn get_accounts(&self) -> Result<CallToolResult, McpError> {
         self.blockchain.get_accounts().await
@@ -87,12 +333,12 @@ impl CombinedService {
         self.blockchain.get_private_keys().await
     }
 
-    #[tool(description = "Get default addresses (Alice and Bob)")]
+    #[tool(description = "Get the configured default sender and recipient addresses (Alice and Bob unless configured)")]
     async fn get_default_addresses(&self) -> Result<CallToolResult, McpError> {
         self.blockchain.get_default_addresses().await
     }
 
-    #[tool(description = "Swap tokens using Uniswap V2 Router - integrates with search API to find contract addresses")]
+    #[tool(description = "Swap tokens using Uniswap V2 Router - integrates with search API to find contract addresses; set dry_run to simulate without broadcasting")]
     async fn swap_tokens(
         &self,
         Parameters(request): Parameters<crate::services::blockchain::SwapRequest>,
@@ -1,21 +1,494 @@
-use std::env;
-use tracing::info;
+use alloy_primitives::{address, Address};
+use serde::Deserialize;
+use std::{collections::{BTreeMap, BTreeSet, HashMap}, env, fs, path::{Path, PathBuf}, str::FromStr};
+use tracing::{info, warn};
+
+/// Chain id of Ethereum mainnet (and mainnet forks), whose addresses are built in
+pub const MAINNET_CHAIN_ID: u64 = 1;
+
+/// Default anvil accounts (test mnemonic), used only when the node doesn't report any
+pub const DEFAULT_ANVIL_ACCOUNTS: [&str; 10] = [
+    "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266", // Account 0 - Alice
+    "0x70997970C51812dc3A010C7d01b50e0d17dc79C8", // Account 1 - Bob
+    "0x3C44CdDdB6a900fa2b585dd299e03d12FA4293BC", // Account 2
+    "0x90F79bf6EB2c4f870365E785982E1f101E93b906", // Account 3
+    "0x15d34AAf54267DB7D7c367839AAf71A00a2C6A65", // Account 4
+    "0x9965507D1a55bcC2695C58ba16FB37d819B0A4dc", // Account 5
+    "0x976EA74026E726554dB657fA54763abd0C3a0aa9", // Account 6
+    "0x14dC79964da2C08b23698B3D3cc7Ca32193d9955", // Account 7
+    "0x23618e81E3f5cdF7f54C3d65f7FBc0aBf5B21E8f", // Account 8
+    "0xa0Ee7A142d267C1f36714E4a8F75612F20a79720", // Account 9
+];
+
+/// Default name of the default sender account
+pub const DEFAULT_SENDER_NAME: &str = "alice";
+
+/// Default anvil account index of the default sender
+pub const DEFAULT_SENDER_INDEX: usize = 0;
+
+/// Default name of the default recipient account
+pub const DEFAULT_RECIPIENT_NAME: &str = "bob";
+
+/// Default anvil account index of the default recipient
+pub const DEFAULT_RECIPIENT_INDEX: usize = 1;
+
+/// Default number of attempts for transiently failing RPC reads
+pub const DEFAULT_RPC_RETRY_ATTEMPTS: u32 = 3;
+
+/// Default base delay between RPC retries, in milliseconds
+pub const DEFAULT_RPC_RETRY_BASE_DELAY_MS: u64 = 200;
+
+/// Default slippage tolerance for swaps in basis points (5%)
+pub const DEFAULT_SLIPPAGE_BPS: u32 = 500;
+
+/// Largest accepted slippage tolerance in basis points (100%)
+pub const MAX_SLIPPAGE_BPS: u32 = 10_000;
+
+/// Parse a slippage tolerance: whole basis points from 0 to `MAX_SLIPPAGE_BPS`
+///
+/// `name` labels the value in errors, e.g. "DEFAULT_SLIPPAGE_BPS" or "slippage".
+pub fn parse_slippage_bps(value: &str, name: &str) -> Result<u32, String> {
+    let bps = value.trim().parse::<u32>()
+        .map_err(|_| format!("Invalid {} '{}': expected whole basis points like 500 (5%)", name, value.trim()))?;
+    if bps > MAX_SLIPPAGE_BPS {
+        return Err(format!(
+            "Invalid {} '{}': cannot exceed {} basis points (100%)",
+            name, value.trim(), MAX_SLIPPAGE_BPS
+        ));
+    }
+    Ok(bps)
+}
+
+/// Default swap deadline, in seconds after the swap is sent (5 minutes)
+pub const DEFAULT_DEADLINE_SECS: u64 = 300;
+
+/// Accepted range for the swap deadline, in seconds (10 seconds to 1 day)
+pub const DEADLINE_SECS_RANGE: std::ops::RangeInclusive<u64> = 10..=86_400;
+
+/// Default time to wait for a transaction to be mined, in seconds
+pub const DEFAULT_CONFIRMATION_TIMEOUT_SECS: u64 = 30;
+
+/// Price impact in basis points above which router swaps need `force` (5%)
+pub const DEFAULT_MAX_PRICE_IMPACT_BPS: u32 = 500;
+
+/// Default cap on concurrent in-flight blockchain RPC calls
+pub const DEFAULT_MAX_CONCURRENT_OPERATIONS: usize = 8;
+
+/// Uniswap V2 router, token and price feed addresses for one network
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct NetworkAddresses {
+    /// Uniswap V2 Router02 address
+    pub uniswap_v2_router: Address,
+    /// Token contract addresses keyed by symbol (e.g. "WETH", "USDC")
+    #[serde(default)]
+    pub tokens: HashMap<String, Address>,
+    /// Chainlink <symbol>/USD aggregator addresses keyed by symbol (e.g. "ETH", "USDC")
+    #[serde(default)]
+    pub price_feeds: HashMap<String, Address>,
+    /// Decimals keyed by token address, used instead of calling `decimals()`
+    ///
+    /// For tokens whose `decimals()` is missing or unreliable, so their amounts
+    /// never fall back to the 18-decimal default.
+    #[serde(default)]
+    pub token_decimals: HashMap<Address, u8>,
+}
+
+impl NetworkAddresses {
+    /// Ethereum mainnet addresses, also correct for mainnet forks
+    pub fn mainnet() -> Self {
+        let tokens = [
+            ("WETH", address!("0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2")),
+            ("USDC", address!("0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48")),
+            ("USDT", address!("0xdAC17F958D2ee523a2206206994597C13D831ec7")),
+            ("DAI", address!("0x6B175474E89094C44Da98b954EedeAC495271d0F")),
+            ("WBTC", address!("0x2260FAC5E5542a773Aa44fBCfeDf7C193bc2C599")),
+            ("UNI", address!("0x1f9840a85d5aF5bf1D1762F925BDADdC4201F984")),
+            ("LINK", address!("0x514910771AF9Ca656af840dff83E8264EcF986CA")),
+        ];
+
+        let price_feeds = [
+            ("ETH", address!("0x5f4eC3Df9cbd43714FE2740f5E3616155c5b8419")),
+            ("BTC", address!("0xF4030086522a5bEEa4988F8cA5B36dbC97BeE88c")),
+            ("USDC", address!("0x8fFfFfd4AfB6115b954Bd326cbe7B4BA576818f6")),
+            ("USDT", address!("0x3E7d1eAB13ad0104d2750B8863b489D65364e32D")),
+            ("DAI", address!("0xAed0c38402a5d19df6E4c03F4E2DceD6e29c1ee9")),
+            ("LINK", address!("0x2c1d072e956AFFC0D435Cb7AC38EF18d24d9127c")),
+            ("UNI", address!("0x553303d460EE0afB37EdFf9bE42922D8FF63220e")),
+        ];
+
+        let token_decimals = [
+            (address!("0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48"), 6), // USDC
+            (address!("0xdAC17F958D2ee523a2206206994597C13D831ec7"), 6), // USDT
+            (address!("0x2260FAC5E5542a773Aa44fBCfeDf7C193bc2C599"), 8), // WBTC
+        ];
+
+        Self {
+            uniswap_v2_router: address!("0x7a250d5630B4cF539739dF2C5dAcb4c659F2488D"),
+            tokens: tokens.into_iter().map(|(symbol, addr)| (symbol.to_string(), addr)).collect(),
+            price_feeds: price_feeds.into_iter().map(|(symbol, addr)| (symbol.to_string(), addr)).collect(),
+            token_decimals: token_decimals.into_iter().collect(),
+        }
+    }
+
+    /// Configured decimals for a token, if it has an override
+    pub fn decimals_override(&self, token: &Address) -> Option<u8> {
+        self.token_decimals.get(token).copied()
+    }
+
+    /// Look up a token by symbol (case-insensitive); "ETH" resolves to WETH for router paths
+    pub fn token(&self, symbol: &str) -> Option<Address> {
+        let symbol = symbol.trim();
+        let lookup = |wanted: &str| {
+            self.tokens.iter()
+                .find(|(known, _)| known.eq_ignore_ascii_case(wanted))
+                .map(|(_, addr)| *addr)
+        };
+
+        lookup(symbol).or_else(|| if symbol.eq_ignore_ascii_case("ETH") { lookup("WETH") } else { None })
+    }
+
+    /// Look up a Chainlink USD price feed by symbol (case-insensitive); wrapped
+    /// tokens fall back to their underlying feed (WETH → ETH, WBTC → BTC)
+    pub fn price_feed(&self, symbol: &str) -> Option<Address> {
+        let symbol = symbol.trim();
+        let lookup = |wanted: &str| {
+            self.price_feeds.iter()
+                .find(|(known, _)| known.eq_ignore_ascii_case(wanted))
+                .map(|(_, addr)| *addr)
+        };
+
+        lookup(symbol).or_else(|| match symbol.to_uppercase().as_str() {
+            "WETH" => lookup("ETH"),
+            "WBTC" => lookup("BTC"),
+            _ => None,
+        })
+    }
+}
+
+/// A named anvil account, e.g. the default sender "alice" at index 0
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NamedAccount {
+    /// Lowercase name used to refer to the account (e.g. "alice")
+    pub name: String,
+    /// Index into the account list (0 = first anvil account)
+    pub index: usize,
+}
+
+impl NamedAccount {
+    /// Create a named account, validating and lowercasing the name
+    pub fn new(name: &str, index: usize) -> Result<Self, String> {
+        let name = name.trim().to_lowercase();
+        if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-') {
+            return Err(format!("Invalid account name '{}': use letters, digits, '-' or '_'", name));
+        }
+        if name.starts_with("0x") || name.strip_prefix("account").is_some_and(|rest| rest.chars().all(|c| c.is_ascii_digit())) {
+            return Err(format!("Invalid account name '{}': it would shadow an address or accountN name", name));
+        }
+        Ok(Self { name, index })
+    }
+
+    /// Name with its first letter capitalized, for display (e.g. "Alice")
+    pub fn display_name(&self) -> String {
+        let mut chars = self.name.chars();
+        match chars.next() {
+            Some(first) => first.to_uppercase().chain(chars).collect(),
+            None => String::new(),
+        }
+    }
+}
+
+/// How transaction fees are set on outgoing transactions
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FeeMode {
+    /// Leave gas pricing to the node (legacy behaviour)
+    #[default]
+    Legacy,
+    /// Set `max_fee_per_gas` / `max_priority_fee_per_gas` from `estimate_eip1559_fees`
+    Eip1559,
+}
+
+impl FromStr for FeeMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "legacy" => Ok(Self::Legacy),
+            "eip1559" | "eip-1559" | "1559" => Ok(Self::Eip1559),
+            other => Err(format!("Unknown fee mode '{}': expected 'legacy' or 'eip1559'", other)),
+        }
+    }
+}
+
+/// Output format of the tracing logs (`--log-format` or `RUST_LOG_FORMAT`)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LogFormat {
+    /// Human-readable lines, for running interactively
+    #[default]
+    Pretty,
+    /// One JSON object per line, for log aggregation
+    Json,
+}
+
+impl FromStr for LogFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "pretty" | "text" => Ok(Self::Pretty),
+            "json" => Ok(Self::Json),
+            other => Err(format!("Unknown log format '{}': expected 'pretty' or 'json'", other)),
+        }
+    }
+}
+
+impl LogFormat {
+    /// Read `RUST_LOG_FORMAT`, defaulting to pretty
+    ///
+    /// Runs before logging is set up, so an unknown value is reported on stderr.
+    pub fn from_env() -> Self {
+        match env::var("RUST_LOG_FORMAT") {
+            Ok(value) if !value.trim().is_empty() => value.parse().unwrap_or_else(|e| {
+                eprintln!("⚠️  {} in RUST_LOG_FORMAT, using pretty", e);
+                Self::Pretty
+            }),
+            _ => Self::Pretty,
+        }
+    }
+}
+
+/// Default address book file, used when `ADDRESS_BOOK_FILE` is not set
+pub const DEFAULT_ADDRESS_BOOK_FILE: &str = "address_book.toml";
+
+/// Named external addresses (e.g. "treasury", "deployer") persisted in a TOML file
+///
+/// The file is a flat table of name to address:
+///
+/// ```toml
+/// treasury = "0x70997970C51812dc3A010C7d01b50e0d17dc79C8"
+/// ```
+///
+/// Names are stored lowercase and may not shadow the configured sender and
+/// recipient names (alice and bob by default) or accountN.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AddressBook {
+    /// File entries are saved to; `None` keeps the book in memory only
+    path: Option<PathBuf>,
+    /// Configured account names entries may not use, besides accountN
+    reserved_names: BTreeSet<String>,
+    entries: BTreeMap<String, Address>,
+}
+
+impl Default for AddressBook {
+    /// An empty in-memory book reserving the default sender and recipient names
+    fn default() -> Self {
+        Self::with_reserved_names([DEFAULT_SENDER_NAME, DEFAULT_RECIPIENT_NAME])
+    }
+}
+
+impl AddressBook {
+    /// An empty in-memory address book
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// An empty in-memory address book reserving `names` (the configured sender and recipient)
+    pub fn with_reserved_names<S: AsRef<str>>(names: impl IntoIterator<Item = S>) -> Self {
+        Self {
+            path: None,
+            reserved_names: names.into_iter().map(|name| name.as_ref().trim().to_lowercase()).collect(),
+            entries: BTreeMap::new(),
+        }
+    }
+
+    /// Load the address book at `path`, starting empty if the file doesn't exist yet
+    pub fn load<S: AsRef<str>>(path: impl Into<PathBuf>, reserved_names: impl IntoIterator<Item = S>) -> Result<Self, String> {
+        let mut book = Self::with_reserved_names(reserved_names);
+        let path = path.into();
+        if path.exists() {
+            let contents = fs::read_to_string(&path)
+                .map_err(|e| format!("Failed to read address book {}: {}", path.display(), e))?;
+            book.entries = book.parse(&contents)?;
+        }
+        book.path = Some(path);
+        Ok(book)
+    }
+
+    /// Parse address book TOML, validating and lowercasing every name
+    pub fn parse(&self, toml_str: &str) -> Result<BTreeMap<String, Address>, String> {
+        let raw: BTreeMap<String, Address> = toml::from_str(toml_str)
+            .map_err(|e| format!("Invalid address book: {}", e))?;
+        raw.into_iter()
+            .map(|(name, address)| Ok((self.normalize_name(&name)?, address)))
+            .collect()
+    }
+
+    /// Replace the reserved account names, failing if an existing entry already uses one
+    pub fn set_reserved_names<S: AsRef<str>>(&mut self, names: impl IntoIterator<Item = S>) -> Result<(), String> {
+        let reserved_names: BTreeSet<String> = names.into_iter().map(|name| name.as_ref().trim().to_lowercase()).collect();
+        if let Some(name) = self.entries.keys().find(|name| reserved_names.contains(*name)) {
+            return Err(format!("Address book entry '{}' shadows a configured account name", name));
+        }
+        self.reserved_names = reserved_names;
+        Ok(())
+    }
+
+    /// Look up a name (case-insensitive)
+    pub fn get(&self, name: &str) -> Option<Address> {
+        self.entries.get(&name.trim().to_lowercase()).copied()
+    }
+
+    /// All entries, sorted by name
+    pub fn entries(&self) -> &BTreeMap<String, Address> {
+        &self.entries
+    }
+
+    /// File the book is saved to, if any
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    /// Add or replace an entry and persist the book; returns the normalized name
+    ///
+    /// If the book can't be saved the entry is rolled back, so memory never
+    /// disagrees with the file.
+    pub fn insert(&mut self, name: &str, address: Address) -> Result<String, String> {
+        let name = self.normalize_name(name)?;
+        let previous = self.entries.insert(name.clone(), address);
+        if let Err(e) = self.save() {
+            match previous {
+                Some(previous) => self.entries.insert(name, previous),
+                None => self.entries.remove(&name),
+            };
+            return Err(e);
+        }
+        Ok(name)
+    }
+
+    /// Write the book back to its file (no-op for in-memory books)
+    pub fn save(&self) -> Result<(), String> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        let entries: BTreeMap<&String, String> = self.entries.iter()
+            .map(|(name, address)| (name, address.to_checksum(None)))
+            .collect();
+        let contents = toml::to_string(&entries)
+            .map_err(|e| format!("Failed to serialize address book: {}", e))?;
+        fs::write(path, contents)
+            .map_err(|e| format!("Failed to write address book {}: {}", path.display(), e))
+    }
+
+    /// Whether `name` is a configured account name or accountN (account0, account1, ...)
+    pub fn is_reserved_name(&self, name: &str) -> bool {
+        let name = name.trim().to_lowercase();
+        self.reserved_names.contains(&name)
+            || name.strip_prefix("account").is_some_and(|index| !index.is_empty() && index.chars().all(|c| c.is_ascii_digit()))
+    }
+
+    /// Lowercase a name and reject reserved names or ones that look like addresses or ENS names
+    fn normalize_name(&self, name: &str) -> Result<String, String> {
+        let name = name.trim().to_lowercase();
+        if name.is_empty() {
+            return Err("Address book names cannot be empty".to_string());
+        }
+        if self.is_reserved_name(&name) {
+            return Err(format!("'{}' is a reserved account name", name));
+        }
+        if name.starts_with("0x") || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-') {
+            return Err(format!(
+                "Invalid address book name '{}': use letters, digits, '-' or '_' (not an address or ENS name)",
+                name
+            ));
+        }
+        Ok(name)
+    }
+}
 
 /// Configuration for blockchain service
 #[derive(Clone, Debug)]
 pub struct BlockchainConfig {
-    /// Default slippage in basis points (e.g., 500 = 5%)
-    pub default_slippage_bps: String,
+    /// Default slippage in basis points (e.g., 500 = 5%), validated to 0-10000
+    pub default_slippage_bps: u32,
     /// Default transaction deadline in seconds
     pub default_deadline_secs: u64,
     /// RPC URL for blockchain connection
     pub rpc_url: String,
-    /// Alice's private key for transactions
+    /// Private key of the default sender, used for transactions
     pub alice_private_key: String,
+    /// Additional private keys keyed by anvil account index
+    pub account_private_keys: HashMap<usize, String>,
+    /// Account addresses in index order (by default account 0 = Alice, account 1 = Bob)
+    ///
+    /// Populated from `ACCOUNTS` if set; otherwise left empty and filled in by
+    /// `BlockchainService` from `eth_accounts`, falling back to `DEFAULT_ANVIL_ACCOUNTS`
+    /// only when the node reports none. Only the default sender's index must exist.
+    pub accounts: Vec<Address>,
+    /// Fee mode for outgoing transactions (`FEE_MODE`, default legacy)
+    pub fee_mode: FeeMode,
+    /// Total attempts for RPC reads that fail transiently (`RPC_RETRY_ATTEMPTS`, default 3)
+    pub rpc_retry_attempts: u32,
+    /// Base backoff delay in milliseconds, doubled per retry (`RPC_RETRY_BASE_DELAY_MS`, default 200)
+    pub rpc_retry_base_delay_ms: u64,
+    /// Seconds to wait for a sent transaction to be mined (`CONFIRMATION_TIMEOUT_SECS`, default 30)
+    pub confirmation_timeout_secs: u64,
+    /// Router and token addresses keyed by chain id
+    ///
+    /// Always contains mainnet; entries from `NETWORK_ADDRESSES_FILE` are added on top.
+    pub networks: HashMap<u64, NetworkAddresses>,
+    /// Named external addresses from `ADDRESS_BOOK_FILE` (default `address_book.toml`)
+    pub address_book: AddressBook,
+    /// Default sender (`DEFAULT_SENDER_NAME` / `DEFAULT_SENDER_INDEX`, default alice = account 0)
+    pub default_sender: NamedAccount,
+    /// Default recipient (`DEFAULT_RECIPIENT_NAME` / `DEFAULT_RECIPIENT_INDEX`, default bob = account 1)
+    pub default_recipient: NamedAccount,
+    /// Allow impersonate_and_send to send from arbitrary addresses on anvil (`ALLOW_IMPERSONATION`, default false)
+    pub allow_impersonation: bool,
+    /// Largest ETH value a single send_eth or swap may spend (`MAX_TX_ETH`, default unlimited)
+    pub max_tx_eth: Option<f64>,
+    /// Total ETH send_eth and swaps may spend while the server runs (`MAX_SESSION_ETH`, default unlimited)
+    pub max_session_eth: Option<f64>,
+    /// Price impact in basis points above which swaps are refused unless forced (`MAX_PRICE_IMPACT_BPS`, default 500)
+    pub max_price_impact_bps: u32,
+    /// Chain id the node must report before the server starts or sends a transaction (`EXPECTED_CHAIN_ID`, default unchecked)
+    pub expected_chain_id: Option<u64>,
+    /// Blockchain RPC calls allowed in flight at once; more wait their turn (`MAX_CONCURRENT_OPERATIONS`, default 8)
+    pub max_concurrent_operations: usize,
+}
+
+impl Default for BlockchainConfig {
+    /// Local anvil defaults, matching the fallbacks used by `from_env`
+    fn default() -> Self {
+        Self {
+            default_slippage_bps: DEFAULT_SLIPPAGE_BPS,
+            default_deadline_secs: DEFAULT_DEADLINE_SECS,
+            rpc_url: "http://127.0.0.1:8545".to_string(),
+            alice_private_key: String::new(),
+            account_private_keys: HashMap::new(),
+            accounts: Self::default_accounts(),
+            fee_mode: FeeMode::default(),
+            rpc_retry_attempts: DEFAULT_RPC_RETRY_ATTEMPTS,
+            rpc_retry_base_delay_ms: DEFAULT_RPC_RETRY_BASE_DELAY_MS,
+            confirmation_timeout_secs: DEFAULT_CONFIRMATION_TIMEOUT_SECS,
+            networks: Self::default_networks(),
+            address_book: AddressBook::new(),
+            default_sender: NamedAccount { name: DEFAULT_SENDER_NAME.to_string(), index: DEFAULT_SENDER_INDEX },
+            default_recipient: NamedAccount { name: DEFAULT_RECIPIENT_NAME.to_string(), index: DEFAULT_RECIPIENT_INDEX },
+            allow_impersonation: false,
+            max_tx_eth: None,
+            max_session_eth: None,
+            max_price_impact_bps: DEFAULT_MAX_PRICE_IMPACT_BPS,
+            expected_chain_id: None,
+            max_concurrent_operations: DEFAULT_MAX_CONCURRENT_OPERATIONS,
+        }
+    }
 }
 
 impl BlockchainConfig {
-    pub fn from_env() -> Self {
+    /// Load the configuration from the environment and `.env`
+    ///
+    /// Fails on an invalid `DEFAULT_SLIPPAGE_BPS` or `DEFAULT_DEADLINE_SECS`
+    /// rather than silently swapping with a different value than configured.
+    pub fn from_env() -> anyhow::Result<Self> {
         // Load environment variables from .env file if it exists
         if dotenv::dotenv().is_err() {
             info!("No .env file found, using system environment variables only");
@@ -24,19 +497,21 @@ impl BlockchainConfig {
         }
 
         // Load configurable business logic values from environment
-        let default_slippage_bps = env::var("DEFAULT_SLIPPAGE_BPS")
-            .unwrap_or_else(|_| {
+        let default_slippage_bps = match env::var("DEFAULT_SLIPPAGE_BPS") {
+            Ok(value) if !value.trim().is_empty() => Self::parse_slippage_bps(&value).map_err(anyhow::Error::msg)?,
+            _ => {
                 info!("⚠️  No DEFAULT_SLIPPAGE_BPS found in environment, using default: 500 (5%)");
-                "500".to_string()
-            });
+                DEFAULT_SLIPPAGE_BPS
+            }
+        };
 
-        let default_deadline_secs = env::var("DEFAULT_DEADLINE_SECS")
-            .unwrap_or_else(|_| {
+        let default_deadline_secs = match env::var("DEFAULT_DEADLINE_SECS") {
+            Ok(value) if !value.trim().is_empty() => Self::parse_deadline_secs(&value).map_err(anyhow::Error::msg)?,
+            _ => {
                 info!("⚠️  No DEFAULT_DEADLINE_SECS found in environment, using default: 300 (5 minutes)");
-                "300".to_string()
-            })
-            .parse::<u64>()
-            .unwrap_or(300);
+                DEFAULT_DEADLINE_SECS
+            }
+        };
 
         let rpc_url = env::var("RPC_URL")
             .unwrap_or_else(|_| {
@@ -52,10 +527,96 @@ impl BlockchainConfig {
                 String::new()
             });
 
+        let account_private_keys = Self::load_account_private_keys();
+        let accounts = Self::load_accounts();
+
+        let fee_mode = env::var("FEE_MODE")
+            .ok()
+            .and_then(|value| {
+                value.parse::<FeeMode>()
+                    .map_err(|e| warn!("⚠️  {}, using legacy", e))
+                    .ok()
+            })
+            .unwrap_or_default();
+
+        let rpc_retry_attempts = env::var("RPC_RETRY_ATTEMPTS")
+            .ok()
+            .and_then(|value| value.trim().parse::<u32>().ok())
+            .filter(|attempts| *attempts > 0)
+            .unwrap_or(DEFAULT_RPC_RETRY_ATTEMPTS);
+
+        let rpc_retry_base_delay_ms = env::var("RPC_RETRY_BASE_DELAY_MS")
+            .ok()
+            .and_then(|value| value.trim().parse::<u64>().ok())
+            .unwrap_or(DEFAULT_RPC_RETRY_BASE_DELAY_MS);
+
+        let confirmation_timeout_secs = env::var("CONFIRMATION_TIMEOUT_SECS")
+            .ok()
+            .and_then(|value| value.trim().parse::<u64>().ok())
+            .filter(|secs| *secs > 0)
+            .unwrap_or(DEFAULT_CONFIRMATION_TIMEOUT_SECS);
+
+        let mut networks = Self::default_networks();
+        if let Ok(path) = env::var("NETWORK_ADDRESSES_FILE")
+            && !path.trim().is_empty()
+        {
+            match Self::load_network_addresses(Path::new(path.trim())) {
+                Ok(loaded) => {
+                    info!("📋 Loaded addresses for {} network(s) from {}", loaded.len(), path);
+                    networks.extend(loaded);
+                }
+                Err(e) => warn!("⚠️  {}, using built-in mainnet addresses only", e),
+            }
+        }
+
+        let (default_sender, default_recipient) = Self::load_default_accounts();
+
+        let address_book_file = env::var("ADDRESS_BOOK_FILE")
+            .ok()
+            .map(|path| path.trim().to_string())
+            .filter(|path| !path.is_empty())
+            .unwrap_or_else(|| DEFAULT_ADDRESS_BOOK_FILE.to_string());
+        let reserved_names = [&default_sender.name, &default_recipient.name];
+        let address_book = AddressBook::load(&address_book_file, reserved_names).unwrap_or_else(|e| {
+            warn!("⚠️  {}, starting with an empty address book", e);
+            AddressBook::with_reserved_names(reserved_names)
+        });
+
+        let allow_impersonation = env::var("ALLOW_IMPERSONATION")
+            .map(|value| matches!(value.trim().to_lowercase().as_str(), "true" | "1" | "yes"))
+            .unwrap_or(false);
+
+        let max_tx_eth = Self::load_eth_limit("MAX_TX_ETH");
+        let max_session_eth = Self::load_eth_limit("MAX_SESSION_ETH");
+
+        let max_price_impact_bps = env::var("MAX_PRICE_IMPACT_BPS")
+            .ok()
+            .and_then(|value| value.trim().parse::<u32>().ok())
+            .unwrap_or(DEFAULT_MAX_PRICE_IMPACT_BPS);
+
+        let expected_chain_id = env::var("EXPECTED_CHAIN_ID")
+            .ok()
+            .filter(|value| !value.trim().is_empty())
+            .and_then(|value| {
+                value.trim().parse::<u64>()
+                    .map_err(|_| warn!("⚠️  Invalid EXPECTED_CHAIN_ID '{}', the chain id will not be checked", value))
+                    .ok()
+            });
+        if expected_chain_id.is_none() {
+            warn!("⚠️  EXPECTED_CHAIN_ID not set - transactions go to whichever network RPC_URL points at. \
+                   Set it (e.g. 31337 for anvil --chain-id 31337) to refuse the wrong network.");
+        }
+
+        let max_concurrent_operations = env::var("MAX_CONCURRENT_OPERATIONS")
+            .ok()
+            .and_then(|value| value.trim().parse::<usize>().ok())
+            .filter(|limit| *limit > 0)
+            .unwrap_or(DEFAULT_MAX_CONCURRENT_OPERATIONS);
+
         info!("⚙️  Configuration loaded:");
         info!("    • Default slippage: {}bps ({}%)", 
             default_slippage_bps, 
-            default_slippage_bps.parse::<f64>().unwrap_or(500.0) / 100.0
+            default_slippage_bps as f64 / 100.0
         );
         info!("    • Default deadline: {}s ({}min)", 
             default_deadline_secs, 
@@ -65,12 +626,208 @@ impl BlockchainConfig {
         info!("    • Private key: {}", 
             if alice_private_key.is_empty() { "Not set" } else { "Set" }
         );
+        info!("    • Additional account keys: {}", account_private_keys.len());
+        info!("    • Accounts: {}", 
+            if accounts.is_empty() { "From node (eth_accounts)".to_string() } else { format!("{} from ACCOUNTS", accounts.len()) }
+        );
+        info!("    • Fee mode: {:?}", fee_mode);
+        info!("    • RPC retries: {} attempt(s), {}ms base delay", rpc_retry_attempts, rpc_retry_base_delay_ms);
+        info!("    • Confirmation timeout: {}s", confirmation_timeout_secs);
+        info!("    • Network address sets: {:?}", networks.keys().collect::<Vec<_>>());
+        info!("    • Address book: {} entr{} ({})",
+            address_book.entries().len(),
+            if address_book.entries().len() == 1 { "y" } else { "ies" },
+            address_book_file
+        );
+        info!("    • Default sender: {} (account {})", default_sender.name, default_sender.index);
+        info!("    • Default recipient: {} (account {})", default_recipient.name, default_recipient.index);
+        info!("    • Impersonation: {}", if allow_impersonation { "Enabled (anvil only)" } else { "Disabled" });
+        let limit_label = |limit: Option<f64>| limit.map(|eth| format!("{} ETH", eth)).unwrap_or_else(|| "Unlimited".to_string());
+        info!("    • Spend limits: {} per transaction, {} per session", limit_label(max_tx_eth), limit_label(max_session_eth));
+        info!("    • Max price impact: {}bps ({}%)", max_price_impact_bps, max_price_impact_bps as f64 / 100.0);
+        info!("    • Expected chain id: {}", expected_chain_id.map(|id| id.to_string()).unwrap_or_else(|| "Unchecked".to_string()));
+        info!("    • Max concurrent operations: {}", max_concurrent_operations);
 
-        Self {
+        Ok(Self {
             default_slippage_bps,
             default_deadline_secs,
             rpc_url,
             alice_private_key,
+            account_private_keys,
+            accounts,
+            fee_mode,
+            rpc_retry_attempts,
+            rpc_retry_base_delay_ms,
+            confirmation_timeout_secs,
+            networks,
+            address_book,
+            default_sender,
+            default_recipient,
+            allow_impersonation,
+            max_tx_eth,
+            max_session_eth,
+            max_price_impact_bps,
+            expected_chain_id,
+            max_concurrent_operations,
+        })
+    }
+
+    /// Validate `DEFAULT_SLIPPAGE_BPS`: whole basis points from 0 to 10000
+    pub fn parse_slippage_bps(value: &str) -> Result<u32, String> {
+        parse_slippage_bps(value, "DEFAULT_SLIPPAGE_BPS")
+    }
+
+    /// Validate `DEFAULT_DEADLINE_SECS`: whole seconds within `DEADLINE_SECS_RANGE`
+    pub fn parse_deadline_secs(value: &str) -> Result<u64, String> {
+        let secs = value.trim().parse::<u64>()
+            .map_err(|_| format!("Invalid DEFAULT_DEADLINE_SECS '{}': expected whole seconds like 300", value.trim()))?;
+        if !DEADLINE_SECS_RANGE.contains(&secs) {
+            return Err(format!(
+                "Invalid DEFAULT_DEADLINE_SECS '{}': must be between {} and {} seconds",
+                value.trim(), DEADLINE_SECS_RANGE.start(), DEADLINE_SECS_RANGE.end()
+            ));
+        }
+        Ok(secs)
+    }
+
+    /// Read an optional positive ETH spend limit, warning about unusable values
+    fn load_eth_limit(key: &str) -> Option<f64> {
+        let value = env::var(key).ok()?;
+        let value = value.trim();
+        if value.is_empty() {
+            return None;
+        }
+        match value.parse::<f64>() {
+            Ok(eth) if eth.is_finite() && eth > 0.0 => Some(eth),
+            _ => {
+                warn!("⚠️  {} must be a positive ETH amount, got '{}' - no limit applied", key, value);
+                None
+            }
         }
     }
+
+    /// Read the default sender and recipient from the environment, falling back
+    /// to alice (account 0) and bob (account 1) for invalid or clashing settings
+    fn load_default_accounts() -> (NamedAccount, NamedAccount) {
+        let load = |prefix: &str, default_name: &str, default_index: usize| {
+            let name = env::var(format!("{}_NAME", prefix)).unwrap_or_else(|_| default_name.to_string());
+            let index = env::var(format!("{}_INDEX", prefix))
+                .ok()
+                .and_then(|value| value.trim().parse::<usize>().ok())
+                .unwrap_or(default_index);
+            NamedAccount::new(&name, index).unwrap_or_else(|e| {
+                warn!("⚠️  {} for {}_NAME, using {}", e, prefix, default_name);
+                NamedAccount { name: default_name.to_string(), index }
+            })
+        };
+        let sender = load("DEFAULT_SENDER", DEFAULT_SENDER_NAME, DEFAULT_SENDER_INDEX);
+        let recipient = load("DEFAULT_RECIPIENT", DEFAULT_RECIPIENT_NAME, DEFAULT_RECIPIENT_INDEX);
+
+        if sender.name == recipient.name || sender.index == recipient.index {
+            warn!("⚠️  Default sender and recipient must differ in name and index, using alice (account 0) and bob (account 1)");
+            return (
+                NamedAccount { name: DEFAULT_SENDER_NAME.to_string(), index: DEFAULT_SENDER_INDEX },
+                NamedAccount { name: DEFAULT_RECIPIENT_NAME.to_string(), index: DEFAULT_RECIPIENT_INDEX },
+            );
+        }
+        (sender, recipient)
+    }
+
+    /// Override the RPC URL (e.g. from a `--rpc-url` CLI flag)
+    pub fn with_rpc_url(mut self, rpc_url: impl Into<String>) -> Self {
+        self.rpc_url = rpc_url.into();
+        info!("    • RPC URL overridden: {}", self.rpc_url);
+        self
+    }
+
+    /// Override the concurrent operation limit (e.g. from a `--max-concurrent-operations` CLI flag)
+    pub fn with_max_concurrent_operations(mut self, limit: usize) -> Self {
+        self.max_concurrent_operations = limit.max(1);
+        info!("    • Max concurrent operations overridden: {}", self.max_concurrent_operations);
+        self
+    }
+
+    /// Address sets for the connected chain, if one is configured
+    pub fn addresses_for_chain(&self, chain_id: u64) -> Option<&NetworkAddresses> {
+        self.networks.get(&chain_id)
+    }
+
+    /// Built-in address sets (mainnet only)
+    pub fn default_networks() -> HashMap<u64, NetworkAddresses> {
+        HashMap::from([(MAINNET_CHAIN_ID, NetworkAddresses::mainnet())])
+    }
+
+    /// Parse network address sets from JSON keyed by chain id
+    ///
+    /// ```json
+    /// { "8453": { "uniswap_v2_router": "0x...", "tokens": { "WETH": "0x..." } } }
+    /// ```
+    pub fn parse_network_addresses(json: &str) -> Result<HashMap<u64, NetworkAddresses>, String> {
+        serde_json::from_str(json).map_err(|e| format!("Invalid network address file: {}", e))
+    }
+
+    /// Load network address sets from a JSON file
+    pub fn load_network_addresses(path: &Path) -> Result<HashMap<u64, NetworkAddresses>, String> {
+        let json = fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read network address file {}: {}", path.display(), e))?;
+        Self::parse_network_addresses(&json)
+    }
+
+    /// Built-in anvil account list used when the node reports no accounts
+    pub fn default_accounts() -> Vec<Address> {
+        DEFAULT_ANVIL_ACCOUNTS
+            .iter()
+            .filter_map(|addr| Address::from_str(addr).ok())
+            .collect()
+    }
+
+    /// Load an explicit account list from the comma-separated `ACCOUNTS` variable
+    fn load_accounts() -> Vec<Address> {
+        let Ok(list) = env::var("ACCOUNTS") else {
+            return Vec::new();
+        };
+
+        list.split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .filter_map(|entry| match Address::from_str(entry) {
+                Ok(address) => Some(address),
+                Err(e) => {
+                    warn!("⚠️  Ignoring invalid address in ACCOUNTS '{}': {}", entry, e);
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// Load private keys for non-default senders
+    ///
+    /// Keys can be supplied as a comma-separated `ANVIL_PRIVATE_KEYS` list (in
+    /// anvil account order) and/or per-index `PRIVATE_KEY_N` variables, which
+    /// take precedence over the list.
+    fn load_account_private_keys() -> HashMap<usize, String> {
+        let mut keys = HashMap::new();
+
+        if let Ok(list) = env::var("ANVIL_PRIVATE_KEYS") {
+            for (index, key) in list.split(',').map(str::trim).enumerate() {
+                if !key.is_empty() {
+                    keys.insert(index, key.to_string());
+                }
+            }
+        }
+
+        for (name, value) in env::vars() {
+            if let Some(index) = name.strip_prefix("PRIVATE_KEY_") {
+                match index.parse::<usize>() {
+                    Ok(index) if !value.trim().is_empty() => {
+                        keys.insert(index, value.trim().to_string());
+                    }
+                    Ok(_) => {}
+                    Err(_) => warn!("⚠️  Ignoring {}: expected PRIVATE_KEY_<account index>", name),
+                }
+            }
+        }
+
+        keys
+    }
 }
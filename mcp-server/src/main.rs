@@ -5,19 +5,52 @@
 //! 2. transfer - Send ETH between addresses  
 //! 3. is_contract_deployed - Check if contract is deployed at address
 //!
-//! Connects to anvil network at 127.0.0.1:8545 as specified in PRD
+//! Connects to anvil network at 127.0.0.1:8545 as specified in PRD by default;
+//! override with the RPC_URL environment variable or the --rpc-url flag.
 
 use anyhow::Result;
+use clap::Parser;
 
-use mcp_server::server::{McpServer, ServerConfig, init_logging};
+use mcp_server::config::LogFormat;
+use mcp_server::server::{McpServer, ServerConfig, DEFAULT_SHUTDOWN_GRACE_SECS, init_logging};
+
+/// Command line arguments for the MCP server
+#[derive(Parser, Debug)]
+#[command(name = "mcp-server")]
+#[command(about = "MCP server exposing Ethereum blockchain and search tools")]
+struct Args {
+    /// RPC URL of the blockchain node (overrides RPC_URL)
+    #[arg(long)]
+    rpc_url: Option<String>,
+
+    /// Log output format: pretty or json (overrides RUST_LOG_FORMAT, default pretty)
+    #[arg(long, value_name = "FORMAT")]
+    log_format: Option<LogFormat>,
+
+    /// Blockchain RPC calls allowed in flight at once (overrides MAX_CONCURRENT_OPERATIONS, default 8)
+    #[arg(long, value_name = "N")]
+    max_concurrent_operations: Option<usize>,
+
+    /// Seconds in-flight requests get to finish after Ctrl+C or SIGTERM
+    #[arg(long, value_name = "SECS", default_value_t = DEFAULT_SHUTDOWN_GRACE_SECS)]
+    shutdown_grace_secs: u64,
+}
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    // Initialize logging
-    init_logging();
+    let args = Args::parse();
+
+    // Load .env first so RUST_LOG_FORMAT there applies to the logger
+    dotenv::dotenv().ok();
+    init_logging(args.log_format.unwrap_or_else(LogFormat::from_env));
 
     // Create server configuration
-    let config = ServerConfig::default();
+    let config = ServerConfig {
+        rpc_url: args.rpc_url,
+        max_concurrent_operations: args.max_concurrent_operations,
+        shutdown_grace_secs: args.shutdown_grace_secs,
+        ..ServerConfig::default()
+    };
     
     // Create and start the MCP server
     let server = McpServer::new(config).await?;
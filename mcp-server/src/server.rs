@@ -3,13 +3,22 @@
 //! This module handles the HTTP server setup, MCP service configuration,
 //! and server lifecycle management.
 
+use std::future::IntoFuture;
+use std::sync::Arc;
+use std::time::Duration;
+
 use anyhow::Result;
 use rmcp::transport::streamable_http_server::{
     StreamableHttpService, session::local::LocalSessionManager,
 };
-use tracing::info;
+use tokio::sync::Notify;
+use tracing::{info, warn};
 
 use crate::combined_service::CombinedService;
+use crate::config::{BlockchainConfig, LogFormat};
+
+/// Default time in-flight requests get to finish on shutdown, in seconds
+pub const DEFAULT_SHUTDOWN_GRACE_SECS: u64 = 30;
 
 /// Server configuration
 #[derive(Debug, Clone)]
@@ -17,6 +26,12 @@ pub struct ServerConfig {
     pub host: String,
     pub port: u16,
     pub mcp_path: String,
+    /// Overrides the RPC_URL environment value when set (e.g. via --rpc-url)
+    pub rpc_url: Option<String>,
+    /// Overrides MAX_CONCURRENT_OPERATIONS when set (e.g. via --max-concurrent-operations)
+    pub max_concurrent_operations: Option<usize>,
+    /// Seconds in-flight requests get to finish after a shutdown signal
+    pub shutdown_grace_secs: u64,
 }
 
 impl Default for ServerConfig {
@@ -25,6 +40,9 @@ impl Default for ServerConfig {
             host: "127.0.0.1".to_string(),
             port: 8080,
             mcp_path: "/mcp".to_string(),
+            rpc_url: None,
+            max_concurrent_operations: None,
+            shutdown_grace_secs: DEFAULT_SHUTDOWN_GRACE_SECS,
         }
     }
 }
@@ -33,6 +51,8 @@ impl Default for ServerConfig {
 pub struct McpServer {
     config: ServerConfig,
     combined_service: CombinedService,
+    /// RPC URL the blockchain service is connected to
+    rpc_url: String,
 }
 
 impl McpServer {
@@ -40,55 +60,36 @@ impl McpServer {
     pub async fn new(config: ServerConfig) -> Result<Self> {
         info!("🔧 Creating MCP server with config: {:?}", config);
         
+        // Load blockchain configuration, letting the CLI override the RPC URL and operation limit
+        let mut blockchain_config = BlockchainConfig::from_env()?;
+        if let Some(rpc_url) = &config.rpc_url {
+            blockchain_config = blockchain_config.with_rpc_url(rpc_url.clone());
+        }
+        if let Some(limit) = config.max_concurrent_operations {
+            blockchain_config = blockchain_config.with_max_concurrent_operations(limit);
+        }
+        let rpc_url = blockchain_config.rpc_url.clone();
+        
         // Create combined service
-        let combined_service = CombinedService::new().await
+        let combined_service = CombinedService::with_blockchain_config(blockchain_config).await
             .map_err(|e| anyhow::anyhow!("Failed to create combined service: {}", e))?;
         
         Ok(Self {
             config,
             combined_service,
+            rpc_url,
         })
     }
 
-    /// Start the HTTP server
+    /// Start the HTTP server, shutting down gracefully on Ctrl+C or SIGTERM
     pub async fn start(self) -> Result<()> {
-        let config = self.config.clone();
-        let combined_service = self.combined_service;
-        
-        info!("🚀 Starting MCP Combined Server");
-        info!("🌐 HTTP Server listening on http://{}:{}", config.host, config.port);
-        info!("📡 Connecting to anvil network at 127.0.0.1:8545");
-        info!("🔍 Brave Search API integration enabled");
-
-        // Create StreamableHttpService with sync constructor
-        let service = StreamableHttpService::new(
-            move || Ok(combined_service.clone()),
-            LocalSessionManager::default().into(),
-            Default::default(),
-        );
-
-        // Create axum router with MCP service and CORS
-        let router = axum::Router::new()
-            .route("/health", axum::routing::get(|| async { "OK" }))
-            .nest_service(&config.mcp_path, service)
-            .layer(tower_http::cors::CorsLayer::permissive());
-        let tcp_listener = tokio::net::TcpListener::bind(format!("{}:{}", config.host, config.port)).await?;
-        
-        info!("✅ MCP Combined Server ready on port {} - exposing blockchain and search tools", config.port);
-        info!("🔗 RIG clients can connect to: http://{}:{}{}", config.host, config.port, config.mcp_path);
-
-        // Start the axum server with graceful shutdown
-        axum::serve(tcp_listener, router)
-            .with_graceful_shutdown(async {
-                tokio::signal::ctrl_c().await.unwrap();
-                info!("🛑 MCP server shutting down...");
-            })
-            .await?;
-        
-        Ok(())
+        self.start_with_shutdown(shutdown_signal()).await
     }
 
     /// Start the server with custom shutdown signal
+    ///
+    /// Once the signal fires no new connections are accepted, and in-flight
+    /// requests get `shutdown_grace_secs` to finish before the server exits.
     pub async fn start_with_shutdown<F>(self, shutdown_signal: F) -> Result<()>
     where
         F: std::future::Future<Output = ()> + Send + 'static,
@@ -98,7 +99,7 @@ impl McpServer {
         
         info!("🚀 Starting MCP Combined Server");
         info!("🌐 HTTP Server listening on http://{}:{}", config.host, config.port);
-        info!("📡 Connecting to anvil network at 127.0.0.1:8545");
+        info!("📡 Connecting to blockchain network at {}", self.rpc_url);
         info!("🔍 Brave Search API integration enabled");
 
         // Create StreamableHttpService with sync constructor
@@ -110,30 +111,110 @@ impl McpServer {
 
         // Create axum router with MCP service and CORS
         let router = axum::Router::new()
+            .route("/health", axum::routing::get(|| async { "OK" }))
             .nest_service(&config.mcp_path, service)
             .layer(tower_http::cors::CorsLayer::permissive());
         let tcp_listener = tokio::net::TcpListener::bind(format!("{}:{}", config.host, config.port)).await?;
         
-        info!("✅ MCP Blockchain Server ready on port {} - exposing balance, transfer, and is_contract_deployed tools", config.port);
+        info!("✅ MCP Combined Server ready on port {} - exposing blockchain and search tools", config.port);
         info!("🔗 RIG clients can connect to: http://{}:{}{}", config.host, config.port, config.mcp_path);
 
-        // Start the axum server with custom shutdown
-        axum::serve(tcp_listener, router)
-            .with_graceful_shutdown(async {
-                shutdown_signal.await;
-                info!("🛑 MCP server shutting down...");
-            })
-            .await?;
+        let grace_period = Duration::from_secs(config.shutdown_grace_secs);
+        serve_with_graceful_shutdown(tcp_listener, router, shutdown_signal, grace_period).await?;
         
         Ok(())
     }
 }
 
-/// Initialize logging for the server
-pub fn init_logging() {
-    tracing_subscriber::fmt()
+/// How a graceful shutdown ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShutdownOutcome {
+    /// Every in-flight request finished within the grace period
+    Drained,
+    /// The grace period ran out with requests still in flight
+    GracePeriodElapsed,
+}
+
+/// Serve `router` until `shutdown_signal` fires, then drain in-flight requests
+///
+/// The listener stops accepting connections as soon as the signal fires. Open
+/// connections keep being served until they finish or `grace_period` passes,
+/// whichever comes first; long-lived MCP event streams would otherwise keep
+/// the server up forever.
+pub async fn serve_with_graceful_shutdown<F>(
+    tcp_listener: tokio::net::TcpListener,
+    router: axum::Router,
+    shutdown_signal: F,
+    grace_period: Duration,
+) -> Result<ShutdownOutcome>
+where
+    F: std::future::Future<Output = ()> + Send + 'static,
+{
+    let signalled = Arc::new(Notify::new());
+    let notify = signalled.clone();
+    let server = axum::serve(tcp_listener, router)
+        .with_graceful_shutdown(async move {
+            shutdown_signal.await;
+            info!("🛑 MCP server shutting down - no longer accepting connections");
+            info!("⏳ Waiting up to {}s for in-flight requests to finish", grace_period.as_secs());
+            notify.notify_one();
+        })
+        .into_future();
+
+    tokio::select! {
+        result = server => {
+            result?;
+            info!("✅ In-flight requests finished - MCP server stopped");
+            Ok(ShutdownOutcome::Drained)
+        }
+        _ = async {
+            signalled.notified().await;
+            tokio::time::sleep(grace_period).await;
+        } => {
+            warn!("⚠️  Grace period of {}s elapsed with requests still in flight - MCP server stopped", grace_period.as_secs());
+            Ok(ShutdownOutcome::GracePeriodElapsed)
+        }
+    }
+}
+
+/// Resolve on Ctrl+C, or on SIGTERM on unix (as sent by process supervisors)
+pub async fn shutdown_signal() {
+    let ctrl_c = async {
+        if let Err(e) = tokio::signal::ctrl_c().await {
+            warn!("⚠️  Failed to listen for Ctrl+C: {}", e);
+            std::future::pending::<()>().await;
+        }
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+            Ok(mut sigterm) => {
+                sigterm.recv().await;
+            }
+            Err(e) => {
+                warn!("⚠️  Failed to listen for SIGTERM: {}", e);
+                std::future::pending::<()>().await;
+            }
+        }
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => info!("🛑 Received Ctrl+C"),
+        _ = terminate => info!("🛑 Received SIGTERM"),
+    }
+}
+
+/// Initialize logging for the server in the given format
+pub fn init_logging(format: LogFormat) {
+    let subscriber = tracing_subscriber::fmt()
         .with_env_filter(tracing_subscriber::EnvFilter::from_default_env().add_directive(tracing::Level::INFO.into()))
         .with_writer(std::io::stderr)
-        .with_ansi(false)
-        .init();
+        .with_ansi(false);
+    match format {
+        LogFormat::Pretty => subscriber.init(),
+        LogFormat::Json => subscriber.json().init(),
+    }
 }
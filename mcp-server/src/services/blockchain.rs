@@ -1,545 +1,3876 @@
 //! Blockchain MCP Server Implementation
 //! 
 //! This module implements the core blockchain functionality as MCP tools.
-//! Following the PRD example exactly - using Cast struct directly from foundry.
+//! Following the PRD example - Cast handles ABI encoding, and chain reads/sends go
+//! through the `ChainProvider` trait so they can be mocked in tests.
+//! 
+//! Balance, transfer and swap tools return a typed result (`BalanceResult`,
+//! `TransferResult`, `SwapResult`) as JSON, followed by a human-readable summary.
 //! 
 //! Tools exposed:
-//! - balance: Get ETH balance of an address (exact PRD example implementation)
-//! - transfer: Send ETH between addresses using Cast::send
-//! - is_contract_deployed: Check if contract code exists using Cast::code
-
-use alloy_ens::NameOrAddress;
-use alloy_network::AnyNetwork;
-use alloy_primitives::{Address, U256, Bytes, TxHash};
-use alloy_provider::{Provider, ProviderBuilder, RootProvider, PendingTransactionBuilder};
-use alloy_rpc_types::TransactionRequest;
+//! - balance: Get ETH balance of an address, optionally at a past block (exact PRD example implementation)
+//! - transfer: Send ETH between addresses
+//! - is_contract_deployed: Check if contract code exists at an address, with its size and code hash
+//! - deploy_contract: Deploy creation bytecode (plus ABI-encoded constructor args) from Alice
+//! - call_contract: Call any view function and decode the result from the signature's return types
+//! - send_contract_call: Send a transaction calling any contract function from Alice, with optional value and gas limit
+//! - weth_balance: WETH balance of an account via the configured WETH contract
+//! - transfer_token: Send ERC-20 tokens using transfer(address,uint256)
+//! - approve_token / get_allowance: Manage ERC-20 allowances for DEX swaps
+//! - nft_owner / nft_balance: ERC-721 ownerOf and balanceOf queries
+//! - add_address_book_entry / list_address_book: Named recipients persisted to ADDRESS_BOOK_FILE
+//! - resolve_ens / reverse_resolve_ens: Forward and reverse ENS lookups
+//! - get_ens_records: ENS text records (avatar, email, url, ...) via the name's resolver
+//! - decode_calldata: Decode a transaction's input against common function signatures
+//! - get_logs: Query contract event logs over a capped block range, decoding Transfer/Approval/Swap
+//! - resolve_token_address: Find a token's address in the token table, or by a verified web search (wired up in CombinedService)
+//! - get_nonce / get_pending_transactions: Diagnose stuck or queued transactions (paged with limit/offset)
+//! - monitor_transaction: Poll a sent transaction's receipt, logging each status change until it is confirmed
+//! - fund_account: Set an account's ETH balance on anvil (anvil_setBalance)
+//! - impersonate_and_send: Send from any address on anvil via impersonation (ALLOW_IMPERSONATION)
+//! - batch_read: Run several balance/allowance/nonce reads concurrently in one call
+//! - get_onchain_price: USD price of a token from its Chainlink feed (latestRoundData)
+//! - convert_units: Convert amounts between wei, gwei and ether (SimpleCast)
+//! - calculate_swap_output: Uniswap V2 expected/minimum output and price impact from pair reserves
+//! - get_chain_status: Current block number, chain id and gas price
+//! - health_check: Uptime, provider connectivity and account/key availability
+
+use alloy_consensus::Transaction as _;
+use alloy_dyn_abi::{DynSolType, DynSolValue};
+use alloy_ens::{namehash, NameOrAddress, ProviderEnsExt, ENS_ADDRESS};
+use alloy_network::{AnyNetwork, AnyTransactionReceipt, TransactionResponse as _};
+use alloy_primitives::{keccak256, Address, B256, I256, U256, Bytes, TxHash};
+use alloy_provider::{Provider, ProviderBuilder, RootProvider};
+use alloy_rpc_types::{BlockId, BlockNumberOrTag, Filter, TransactionRequest};
 use alloy_serde::WithOtherFields;
-use cast::{Cast, SimpleCast};
+use alloy_signer_local::PrivateKeySigner;
+use cast::SimpleCast;
 use eyre::Result;
+use futures::future::join_all;
 use num_traits::cast::ToPrimitive;
 use alloy_primitives::hex;
 use rmcp::{
     handler::server::{router::tool::ToolRouter, tool::Parameters}, model::{CallToolResult, Content, ServerCapabilities, ServerInfo}, schemars::JsonSchema, tool, tool_handler, tool_router, ErrorData as McpError, ServerHandler
 };
 use serde::{Deserialize, Serialize};
-use std::{str::FromStr, time::Duration, collections::HashMap, sync::Mutex};
-use tracing::{info, error};
-use crate::config::BlockchainConfig;
+use std::{str::FromStr, time::{Duration, Instant}, collections::HashMap, sync::{Arc, Mutex}};
+use tracing::{debug, info, warn, error};
+use crate::config::{AddressBook, BlockchainConfig, FeeMode, NetworkAddresses, MAINNET_CHAIN_ID};
+use crate::services::pagination::{page_params, Page};
+use crate::services::search::{ContractInfoRequest, SearchService};
+use crate::services::provider::{is_execution_revert, is_method_not_found, is_transient_rpc_error, retry_rpc, ChainProvider, ConcurrencyLimited, SigningProvider};
+use tokio::sync::{Semaphore, SemaphorePermit};
 use tokio::time::sleep;
 use once_cell::sync::Lazy;
 use reqwest;
 use regex::Regex;
 
 /// Global cache for token contract addresses - populated from web search results
-static TOKEN_ADDRESS_CACHE: Lazy<Mutex<HashMap<String, Address>>> = Lazy::new(|| {
+///
+/// Keyed by chain ID and uppercased symbol, so an address verified on one
+/// network is never handed out for another.
+static TOKEN_ADDRESS_CACHE: Lazy<Mutex<HashMap<(u64, String), Address>>> = Lazy::new(|| {
     Mutex::new(HashMap::new())
 });
 
-/// Request structure for balance queries
-#[derive(Debug, Serialize, Deserialize, JsonSchema)]
-pub struct BalanceRequest {
-    #[schemars(description = "The address or ENS name to check balance for")]
-    pub who: String,
+/// Key for `TOKEN_ADDRESS_CACHE`
+fn token_cache_key(chain_id: u64, symbol: &str) -> (u64, String) {
+    (chain_id, symbol.trim().to_uppercase())
 }
 
-/// Request structure for ETH transfers
-#[derive(Debug, Serialize, Deserialize, JsonSchema)]
-pub struct TransferRequest {
-    #[schemars(description = "Recipient address")]
-    pub to: String,
-    #[schemars(description = "Amount in ETH (e.g., '1.0')")]
-    pub amount: String,
+/// Parse a decimal ETH amount (e.g. "0.5", "1.25") into wei
+///
+/// Accepts up to 18 decimal places and rejects negative, empty or
+/// non-numeric input instead of silently mis-scaling it.
+pub fn parse_eth_to_wei(amount: &str) -> Result<U256, McpError> {
+    parse_token_amount(amount, 18, "ETH")
 }
 
-/// Request structure for contract deployment checks
-#[derive(Debug, Serialize, Deserialize, JsonSchema)]
-pub struct ContractDeploymentRequest {
-    #[schemars(description = "Contract address to check")]
-    pub address: String,
+/// An ETH amount as the user phrased it: a literal value, the whole balance, or a share of it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AmountExpression {
+    /// A literal amount in wei
+    Exact(U256),
+    /// Everything left after reserving the gas cost ("all", "max", "100%")
+    All,
+    /// A share of the balance in basis points ("50%" and "half" are 5000)
+    Percent(u32),
 }
 
-/// Request structure for ERC-20 token balance queries
-#[derive(Debug, Serialize, Deserialize, JsonSchema)]
-pub struct TokenBalanceRequest {
-    #[schemars(description = "Token contract address (e.g., USDC: 0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48)")]
-    pub token_address: String,
-    #[schemars(description = "Account address to check balance for")]
-    pub account_address: String,
+impl AmountExpression {
+    /// The amount in wei to send, given the sender's balance and the transaction's gas cost
+    ///
+    /// "all" always holds back the gas cost so the transfer can be paid for; a
+    /// percentage is taken of the whole balance.
+    pub fn resolve(&self, balance: U256, gas_cost: U256) -> Result<U256, McpError> {
+        let amount = match self {
+            AmountExpression::Exact(wei) => return Ok(*wei),
+            AmountExpression::All => {
+                if balance <= gas_cost {
+                    return Err(McpError::invalid_params(
+                        format!(
+                            "Nothing to send: the balance of {:.6} ETH doesn't cover the estimated gas cost of {:.6} ETH",
+                            balance.to_f64().unwrap_or(0.0) / 1e18,
+                            gas_cost.to_f64().unwrap_or(0.0) / 1e18
+                        ),
+                        None
+                    ));
+                }
+                balance - gas_cost
+            }
+            AmountExpression::Percent(basis_points) => balance * U256::from(*basis_points) / U256::from(10_000u32),
+        };
+        if amount.is_zero() {
+            return Err(McpError::invalid_params(
+                format!("Nothing to send: {} of a {:.6} ETH balance is 0 wei", self.describe(), balance.to_f64().unwrap_or(0.0) / 1e18),
+                None
+            ));
+        }
+        Ok(amount)
+    }
+
+    /// Short description for responses, e.g. "all" or "50%"
+    pub fn describe(&self) -> String {
+        match self {
+            AmountExpression::Exact(wei) => format_token_amount(*wei, 18, "ETH"),
+            AmountExpression::All => "all".to_string(),
+            AmountExpression::Percent(basis_points) if basis_points % 100 == 0 => format!("{}%", basis_points / 100),
+            AmountExpression::Percent(basis_points) => format!("{}.{:02}%", basis_points / 100, basis_points % 100),
+        }
+    }
 }
 
-/// Request structure for token swaps
-#[derive(Debug, Serialize, Deserialize, JsonSchema)]
-pub struct SwapRequest {
-    #[schemars(description = "Token to swap from (e.g., 'ETH')")]
-    pub from_token: String,
-    #[schemars(description = "Token to swap to (e.g., 'USDC')")]
-    pub to_token: String,
-    #[schemars(description = "Amount to swap (e.g., '10')")]
-    pub amount: String,
-    #[schemars(description = "DEX to use (e.g., 'Uniswap V2')")]
-    pub dex: Option<String>,
-    #[schemars(description = "Slippage tolerance in basis points (e.g., '500' for 5%)")]
-    pub slippage: Option<String>,
+/// Parse an ETH amount that may also be "all"/"max", "half" or a percentage like "50%"
+///
+/// Percentages take up to two decimal places ("12.5%"); "100%" means the same
+/// as "all". Anything else must be a decimal ETH amount, as for `parse_eth_to_wei`.
+pub fn parse_amount_expression(amount: &str) -> Result<AmountExpression, McpError> {
+    let normalized = amount.trim().to_lowercase();
+    match normalized.as_str() {
+        "all" | "max" | "everything" => return Ok(AmountExpression::All),
+        "half" => return Ok(AmountExpression::Percent(5_000)),
+        _ => {}
+    }
+
+    if let Some(percent) = normalized.strip_suffix('%') {
+        let invalid = |reason: &str| {
+            McpError::invalid_params(format!("Invalid percentage '{}': {}", amount, reason), None)
+        };
+        // Two decimal places of a percentage are basis points
+        let basis_points = parse_token_amount(percent.trim(), 2, "percentage")
+            .map_err(|_| invalid("expected a value like '50%' or '12.5%'"))?;
+        if basis_points.is_zero() || basis_points > U256::from(10_000u32) {
+            return Err(invalid("must be more than 0% and at most 100%"));
+        }
+        let basis_points = basis_points.to::<u32>();
+        return Ok(if basis_points == 10_000 { AmountExpression::All } else { AmountExpression::Percent(basis_points) });
+    }
+
+    parse_eth_to_wei(amount).map(AmountExpression::Exact)
 }
 
-/// Request structure for transaction status checks
-#[derive(Debug, Serialize, Deserialize, JsonSchema)]
-pub struct TransactionStatusRequest {
-    #[schemars(description = "Transaction hash to check status for")]
-    pub tx_hash: String,
-    #[schemars(description = "Timeout in seconds for waiting for transaction (default: 30)")]
-    pub timeout: Option<u64>,
+/// Convert a configured ETH spend limit into wei
+fn eth_limit_to_wei(eth: f64) -> U256 {
+    U256::from((eth * 1e18) as u128)
 }
 
-/// Response structure for account information
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
-pub struct AccountInfo {
-    #[schemars(description = "Account index (0-9)")]
-    pub index: u32,
-    #[schemars(description = "Public address")]
-    pub address: String,
-    #[schemars(description = "Private key (only included in get_private_keys)")]
-    pub private_key: Option<String>,
+/// Parse a human-readable token amount into its raw on-chain units
+///
+/// `decimals` is the token's `decimals()` value, so "1.5" with 6 decimals
+/// becomes 1500000. `unit` is only used in error messages.
+pub fn parse_token_amount(amount: &str, decimals: u8, unit: &str) -> Result<U256, McpError> {
+    let trimmed = amount.trim();
+    let invalid = |reason: &str| {
+        McpError::invalid_params(format!("Invalid {} amount '{}': {}", unit, amount, reason), None)
+    };
+
+    if trimmed.starts_with('-') {
+        return Err(invalid("amount cannot be negative"));
+    }
+
+    let (whole, fraction) = trimmed.split_once('.').unwrap_or((trimmed, ""));
+
+    if whole.is_empty() && fraction.is_empty() {
+        return Err(invalid("expected a decimal number like '1.5'"));
+    }
+    if !whole.chars().all(|c| c.is_ascii_digit()) || !fraction.chars().all(|c| c.is_ascii_digit()) {
+        return Err(invalid("expected a decimal number like '1.5'"));
+    }
+    if fraction.len() > decimals as usize {
+        return Err(invalid(&format!("{} supports at most {} decimal places", unit, decimals)));
+    }
+
+    // Right-pad the fractional part to `decimals` digits so "1.25" becomes "1" + "250000000000000000"
+    let digits = format!("{}{:0<width$}", whole, fraction, width = decimals as usize);
+    U256::from_str_radix(&digits, 10).map_err(|e| invalid(&e.to_string()))
 }
 
-/// Response structure for account listings
-#[derive(Debug, Serialize, Deserialize, JsonSchema)]
-pub struct AccountListResponse {
-    #[schemars(description = "List of available accounts")]
-    pub accounts: Vec<AccountInfo>,
-    #[schemars(description = "Total number of accounts")]
-    pub total: u32,
+/// Format a raw token amount using the token's decimals (e.g. "1.500000 USDC")
+pub fn format_token_amount(raw: U256, decimals: u8, symbol: &str) -> String {
+    if decimals > 0 {
+        let divisor = U256::from(10).pow(U256::from(decimals));
+        let whole = raw / divisor;
+        let fraction = raw % divisor;
+        format!("{}.{:0width$} {}", whole, fraction, symbol, width = decimals as usize)
+    } else {
+        format!("{} {}", raw, symbol)
+    }
 }
 
-/// Validated address information
-#[derive(Debug, Clone)]
-pub struct ValidatedAddress {
-    pub address: String,
-    pub resolved_address: Address,
-    pub address_type: String,
+/// Canonical `SimpleCast` unit name for a user-supplied ETH unit ("eth" and "ether" are the same)
+fn eth_unit(unit: &str) -> Result<&'static str, McpError> {
+    match unit.trim().to_lowercase().as_str() {
+        "wei" => Ok("wei"),
+        "gwei" => Ok("gwei"),
+        "eth" | "ether" => Ok("ether"),
+        _ => Err(McpError::invalid_params(
+            format!("Unsupported unit '{}': expected wei, gwei or ether", unit),
+            None
+        )),
+    }
 }
 
-/// Blockchain MCP Service - Following PRD Example Exactly
-/// 
-/// This matches the "MyMcp" struct from the PRD example, using Cast directly
-#[derive(Clone)]
-pub struct BlockchainService {
-    /// Provider for blockchain connection (we'll create Cast on-demand)
-    provider: RootProvider<AnyNetwork>,
-    /// Alice's address (default sender from PRD)
-    alice_address: Address,
-    /// Bob's address (default recipient from PRD)
-    bob_address: Address,
-    /// Alice's private key for transactions
-    alice_private_key: String,
-    /// All available anvil accounts (addresses and private keys)
-    anvil_accounts: Vec<AccountInfo>,
-    /// Tool router for MCP
-    tool_router: ToolRouter<Self>,
-    /// Configuration for the blockchain service
-    config: BlockchainConfig,
+/// Drop trailing fractional zeros from a decimal string ("30000.000000000" becomes "30000")
+fn trim_decimal(value: &str) -> String {
+    if value.contains('.') {
+        value.trim_end_matches('0').trim_end_matches('.').to_string()
+    } else {
+        value.to_string()
+    }
 }
 
-#[tool_router]
-impl BlockchainService {
-    /// Search for a token's contract address using cache and web search
-    async fn search_token_address(&self, token_symbol: &str) -> Result<Option<Address>, McpError> {
-        println!("\n🔎 Starting search for token contract address: {}", token_symbol);
-        
-        // Step 1: Check cache first
-        let cache_result = TOKEN_ADDRESS_CACHE.lock()
-            .map_err(|e| McpError::internal_error(format!("Cache lock error: {}", e), None))?
-            .get(token_symbol)
-            .copied();
-            
-        if let Some(cached_address) = cache_result {
-            println!("✨ Found {} in cache! Address: {:?}", token_symbol, cached_address);
-            return Ok(Some(cached_address));
+/// Convert an amount between wei, gwei and ether with `SimpleCast`
+///
+/// Returns the converted amount and the amount in wei. Fractional input is
+/// allowed down to one wei; anything finer is rejected.
+pub fn convert_eth_units(value: &str, from: &str, to: &str) -> Result<(String, String), McpError> {
+    let (from, to) = (eth_unit(from)?, eth_unit(to)?);
+    let invalid = |reason: String| {
+        McpError::invalid_params(format!("Invalid {} amount '{}': {}", from, value, reason), None)
+    };
+
+    let wei = SimpleCast::to_wei(value.trim(), from).map_err(|e| invalid(e.to_string()))?;
+    let converted = SimpleCast::from_wei(&wei, to).map_err(|e| invalid(e.to_string()))?;
+    Ok((trim_decimal(&converted), wei))
+}
+
+/// Format a gas price for tool responses, e.g. "12.50 gwei (12500000000 wei)"
+///
+/// Every transaction response uses this so gas prices read the same everywhere.
+pub fn format_gas_price(wei: u128) -> String {
+    let gwei = SimpleCast::from_wei(&wei.to_string(), "gwei")
+        .map(|gwei| trim_decimal(&gwei))
+        .unwrap_or_else(|_| (wei as f64 / 1e9).to_string());
+    match gwei.parse::<f64>() {
+        // Prices below 0.01 gwei (common on L2s) keep full precision instead of rounding to zero
+        Ok(value) if value >= 0.01 || wei == 0 => format!("{:.2} gwei ({} wei)", value, wei),
+        _ => format!("{} gwei ({} wei)", gwei, wei),
+    }
+}
+
+/// Decode the return data of an ERC-20 `symbol()` call
+///
+/// Most tokens return an ABI-encoded dynamic `string`, but older tokens such as
+/// MKR return a fixed `bytes32`. Dynamic strings are tried first; a bare 32-byte
+/// word without a valid string offset is decoded as a null-padded `bytes32`.
+pub fn decode_token_symbol(result: &[u8]) -> Option<String> {
+    if result.len() >= 64 {
+        let offset = U256::from_be_slice(&result[..32]).saturating_to::<usize>();
+        if let Some(length_end) = offset.checked_add(32)
+            && length_end <= result.len()
+        {
+            let length = U256::from_be_slice(&result[offset..length_end]).saturating_to::<usize>();
+            if let Some(end) = length_end.checked_add(length)
+                && end <= result.len()
+            {
+                return String::from_utf8(result[length_end..end].to_vec()).ok();
+            }
         }
-        
-        println!("🌐 {} not in cache, searching web...", token_symbol);
-        
-        // Prepare search query
-        let query = format!("{} token contract address ethereum mainnet", token_symbol);
-        info!("🌐 Web searching: {}", query);
-        
-        // Create HTTP client
-        let client = reqwest::Client::new();
-        
-        // Use environment variable for API key
-        let api_key = std::env::var("BRAVE_SEARCH_API_KEY")
-            .map_err(|_| McpError::internal_error("BRAVE_SEARCH_API_KEY environment variable not set".to_string(), None))?;
-            
-        // Add delay to avoid rate limiting (1 second between requests)
-        println!("⏳ Rate limit protection: waiting 1 second before API call...");
-        sleep(Duration::from_secs(1)).await;
-        
-        // Make request to Brave Search API
-        let response = client
-            .get("https://api.search.brave.com/res/v1/web/search")
-            .header("X-Subscription-Token", api_key)
-            .header("Accept", "application/json")
-            .query(&[("q", &query)])
-            .send()
-            .await
-            .map_err(|e| McpError::internal_error(format!("Search API request failed: {}", e), None))?;
-            
-        if !response.status().is_success() {
-            return Err(McpError::internal_error(
-                format!("Search API returned error status: {}", response.status()),
-                None
-            ));
+    }
+    
+    if result.len() == 32 {
+        let trimmed: Vec<u8> = result.iter().copied().take_while(|b| *b != 0).collect();
+        if !trimmed.is_empty() && result[trimmed.len()..].iter().all(|b| *b == 0) {
+            return String::from_utf8(trimmed).ok();
         }
-        
-        let search_result: serde_json::Value = response.json().await
-            .map_err(|e| McpError::internal_error(format!("Failed to parse search response: {}", e), None))?;
-            
-        // Extract contract address using regex
-        let address_regex = Regex::new(r"0x[a-fA-F0-9]{40}")
-            .map_err(|e| McpError::internal_error(format!("Regex creation failed: {}", e), None))?;
-            
-        if let Some(results) = search_result["web"]["results"].as_array() {
-            for result in results {
-                if let Some(text) = result["description"].as_str() {
-                    if let Some(address_match) = address_regex.find(text) {
-                        let address_str = address_match.as_str();
-                        if let Ok(address) = Address::from_str(address_str) {
-                            println!("\n💎 Found contract address for {} via web search!", token_symbol);
-                            println!("📝 Contract Address: {:?}", address);
-                            
-                            // Cache the result
-                            TOKEN_ADDRESS_CACHE.lock()
-                                .map_err(|e| McpError::internal_error(format!("Cache lock error: {}", e), None))?
-                                .insert(token_symbol.to_string(), address);
-                                
-                            println!("💾 Stored {} address in cache for future use", token_symbol);
-                            return Ok(Some(address));
-                        }
-                    }
-                }
-            }
+    }
+    
+    None
+}
+
+/// Most web search candidates resolve_token_address verifies on-chain for one symbol
+pub const MAX_TOKEN_SEARCH_CANDIDATES: usize = 5;
+
+/// Every distinct 0x-prefixed 20-byte address in `text`, in the order they first appear
+pub fn extract_addresses(text: &str) -> Vec<Address> {
+    static ADDRESS_PATTERN: Lazy<Regex> = Lazy::new(|| Regex::new(r"0x[a-fA-F0-9]{40}\b").expect("valid address regex"));
+    let mut addresses = Vec::new();
+    for found in ADDRESS_PATTERN.find_iter(text) {
+        if let Ok(address) = Address::from_str(found.as_str())
+            && !address.is_zero()
+            && !addresses.contains(&address)
+        {
+            addresses.push(address);
         }
-        
-        println!("\n❌ Could not find contract address for {}", token_symbol);
-        println!("   - Not found in cache");
-        println!("   - Web search returned no valid results");
-        Ok(None)
     }
+    addresses
+}
 
-    /// Create a new blockchain service instance
-    pub async fn new() -> Result<Self> {
-        // Load configuration from environment
-        let config = BlockchainConfig::from_env();
-        
-        // Create provider connection to anvil
-        let provider = ProviderBuilder::<_, _, AnyNetwork>::default()
-            .connect(&config.rpc_url)
-            .await?;
+/// Text records read by get_ens_records when no keys are given
+pub const DEFAULT_ENS_TEXT_KEYS: &[&str] = &["avatar", "email", "url", "description", "com.twitter", "com.github"];
 
-        // Hardcoded accounts from anvil output
-        let available_addresses = vec![
-            Address::from_str("0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266").unwrap(), // Account 0 - Alice
-            Address::from_str("0x70997970C51812dc3A010C7d01b50e0d17dc79C8").unwrap(), // Account 1 - Bob
-            Address::from_str("0x3C44CdDdB6a900fa2b585dd299e03d12FA4293BC").unwrap(), // Account 2
-            Address::from_str("0x90F79bf6EB2c4f870365E785982E1f101E93b906").unwrap(), // Account 3
-            Address::from_str("0x15d34AAf54267DB7D7c367839AAf71A00a2C6A65").unwrap(), // Account 4
-            Address::from_str("0x9965507D1a55bcC2695C58ba16FB37d819B0A4dc").unwrap(), // Account 5
-            Address::from_str("0x976EA74026E726554dB657fA54763abd0C3a0aa9").unwrap(), // Account 6
-            Address::from_str("0x14dC79964da2C08b23698B3D3cc7Ca32193d9955").unwrap(), // Account 7
-            Address::from_str("0x23618e81E3f5cdF7f54C3d65f7FBc0aBf5B21E8f").unwrap(), // Account 8
-            Address::from_str("0xa0Ee7A142d267C1f36714E4a8F75612F20a79720").unwrap(), // Account 9
-        ];
+/// A well-known function signature used to decode transaction calldata
+struct KnownFunction {
+    signature: &'static str,
+    params: &'static [&'static str],
+}
 
-        // PRD requirement: Default sender is account 0 (first account from anvil)
-        let alice_address = available_addresses[0]; // Account 0 - default sender
-        
-        // PRD requirement: Bob is account 1 (second account from anvil)
-        let bob_address = available_addresses[1]; // Account 1 - default recipient
+/// Common ERC-20, WETH and Uniswap V2 Router functions recognised by decode_calldata
+const KNOWN_FUNCTIONS: &[KnownFunction] = &[
+    KnownFunction { signature: "transfer(address,uint256)", params: &["to", "amount"] },
+    KnownFunction { signature: "transferFrom(address,address,uint256)", params: &["from", "to", "amount"] },
+    KnownFunction { signature: "approve(address,uint256)", params: &["spender", "amount"] },
+    KnownFunction { signature: "deposit()", params: &[] },
+    KnownFunction { signature: "withdraw(uint256)", params: &["amount"] },
+    KnownFunction { signature: "swapExactETHForTokens(uint256,address[],address,uint256)", params: &["amountOutMin", "path", "to", "deadline"] },
+    KnownFunction { signature: "swapETHForExactTokens(uint256,address[],address,uint256)", params: &["amountOut", "path", "to", "deadline"] },
+    KnownFunction { signature: "swapExactTokensForETH(uint256,uint256,address[],address,uint256)", params: &["amountIn", "amountOutMin", "path", "to", "deadline"] },
+    KnownFunction { signature: "swapExactTokensForTokens(uint256,uint256,address[],address,uint256)", params: &["amountIn", "amountOutMin", "path", "to", "deadline"] },
+    KnownFunction { signature: "swapTokensForExactTokens(uint256,uint256,address[],address,uint256)", params: &["amountOut", "amountInMax", "path", "to", "deadline"] },
+    KnownFunction { signature: "addLiquidityETH(address,uint256,uint256,uint256,address,uint256)", params: &["token", "amountTokenDesired", "amountTokenMin", "amountETHMin", "to", "deadline"] },
+];
 
-        // Load accounts from hardcoded list
-        let anvil_accounts = Self::load_anvil_accounts(&available_addresses).await?;
-        
-        let alice_private_key = config.alice_private_key.clone();
+/// Result of decoding transaction input against the known signature table
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecodedCalldata {
+    /// 4-byte function selector as 0x-prefixed hex
+    pub selector: String,
+    /// Matched function signature, if the selector is known
+    pub signature: Option<String>,
+    /// Decoded (name, value) pairs for known functions
+    pub params: Vec<(String, String)>,
+    /// Argument bytes after the selector, as 0x-prefixed hex
+    pub raw_args: String,
+}
 
-        info!("🔗 Blockchain service configured for anvil network at {}", config.rpc_url);
-        info!("👤 Alice (Account 0): {} (default sender per PRD)", alice_address);
-        info!("👤 Bob (Account 1): {} (default recipient per PRD)", bob_address);
-        info!("📊 Loaded {} accounts from anvil", anvil_accounts.len());
-        if !alice_private_key.is_empty() {
-            info!("🔑 Alice's private key loaded for transaction signing");
-        } else {
-            info!("⚠️  Alice's private key not available - transactions disabled");
+/// Decode calldata by matching its selector against KNOWN_FUNCTIONS
+pub fn decode_calldata_input(input: &[u8]) -> Result<DecodedCalldata, McpError> {
+    if input.len() < 4 {
+        return Err(McpError::invalid_params(
+            format!("Calldata too short to contain a function selector ({} bytes)", input.len()),
+            None
+        ));
+    }
+    
+    let (selector, args) = input.split_at(4);
+    let mut decoded = DecodedCalldata {
+        selector: format!("0x{}", hex::encode(selector)),
+        signature: None,
+        params: Vec::new(),
+        raw_args: format!("0x{}", hex::encode(args)),
+    };
+    
+    let Some(known) = KNOWN_FUNCTIONS.iter().find(|f| keccak256(f.signature)[..4] == *selector) else {
+        return Ok(decoded);
+    };
+    
+    let arg_types = &known.signature[known.signature.find('(').unwrap_or(0)..];
+    let values = DynSolType::parse(arg_types)
+        .and_then(|ty| ty.abi_decode_params(args))
+        .map_err(|e| McpError::invalid_params(
+            format!("Selector matches {} but arguments failed to decode: {}", known.signature, e),
+            None
+        ))?;
+    
+    if let DynSolValue::Tuple(values) = values {
+        decoded.params = known.params.iter()
+            .zip(values.iter())
+            .map(|(name, value)| (name.to_string(), format_sol_value(value)))
+            .collect();
+    }
+    decoded.signature = Some(known.signature.to_string());
+    Ok(decoded)
+}
+
+/// Build contract creation data: the bytecode followed by the ABI-encoded constructor arguments
+///
+/// `constructor` gives the parameter types as `constructor(uint256,address)` or just
+/// `(uint256,address)`; it is only needed when there are arguments.
+pub fn deployment_data(bytecode: &str, constructor: Option<&str>, args: &[String]) -> Result<Bytes, McpError> {
+    let trimmed = bytecode.trim();
+    let code = hex::decode(trimmed.strip_prefix("0x").unwrap_or(trimmed))
+        .map_err(|e| McpError::invalid_params(format!("Invalid bytecode: expected hex ({})", e), None))?;
+    if code.is_empty() {
+        return Err(McpError::invalid_params("Invalid bytecode: it is empty".to_string(), None));
+    }
+    
+    let Some(constructor) = constructor.map(str::trim).filter(|constructor| !constructor.is_empty()) else {
+        if !args.is_empty() {
+            return Err(McpError::invalid_params(
+                format!("{} constructor argument(s) given without a constructor signature like 'constructor(uint256)'", args.len()),
+                None
+            ));
         }
+        return Ok(code.into());
+    };
+    
+    let param_types = constructor.strip_prefix("constructor").unwrap_or(constructor).trim();
+    let Ok(DynSolType::Tuple(types)) = DynSolType::parse(param_types) else {
+        return Err(McpError::invalid_params(
+            format!("Invalid constructor signature '{}': expected e.g. 'constructor(uint256,address)'", constructor),
+            None
+        ));
+    };
+    if types.len() != args.len() {
+        return Err(McpError::invalid_params(
+            format!("Constructor {} takes {} argument(s) but {} were given", constructor, types.len(), args.len()),
+            None
+        ));
+    }
+    
+    let values = types.iter().zip(args)
+        .map(|(ty, arg)| ty.coerce_str(arg).map_err(|e| {
+            McpError::invalid_params(format!("Invalid constructor argument '{}' for type {}: {}", arg, ty, e), None)
+        }))
+        .collect::<Result<Vec<_>, _>>()?;
+    
+    let mut data = code;
+    data.extend_from_slice(&DynSolValue::Tuple(values).abi_encode_params());
+    Ok(data.into())
+}
 
-        Ok(Self {
-            provider,
-            alice_address,
-            bob_address,
-            alice_private_key,
-            anvil_accounts,
-            tool_router: Self::tool_router(),
-            config,
-        })
+/// Render a decoded ABI value for display
+fn format_sol_value(value: &DynSolValue) -> String {
+    match value {
+        DynSolValue::Address(addr) => format!("{:?}", addr),
+        DynSolValue::Uint(v, _) => v.to_string(),
+        DynSolValue::Int(v, _) => v.to_string(),
+        DynSolValue::Bool(b) => b.to_string(),
+        DynSolValue::String(s) => s.clone(),
+        DynSolValue::Bytes(bytes) => format!("0x{}", hex::encode(bytes)),
+        DynSolValue::FixedBytes(word, size) => format!("0x{}", hex::encode(&word[..*size])),
+        DynSolValue::Array(items) | DynSolValue::FixedArray(items) | DynSolValue::Tuple(items) => {
+            format!("[{}]", items.iter().map(format_sol_value).collect::<Vec<_>>().join(", "))
+        }
+        other => format!("{:?}", other),
     }
+}
 
-    /// Load anvil accounts dynamically - addresses only from eth_accounts RPC
-    async fn load_anvil_accounts(addresses: &[Address]) -> Result<Vec<AccountInfo>> {
+/// Split a cast-style signature like `balanceOf(address)(uint256)` into the function
+/// part and its return types
+///
+/// The return types are `None` when the signature has none; `returns (...)` is
+/// accepted in place of the bare tuple.
+pub fn split_function_signature(signature: &str) -> Result<(String, Option<String>), McpError> {
+    let signature = signature.trim();
+    let invalid = || McpError::invalid_params(
+        format!("Invalid function signature '{}': expected e.g. 'totalSupply()(uint256)' or 'balanceOf(address)(uint256)'", signature),
+        None
+    );
+    
+    let open = signature.find('(').filter(|&open| open > 0).ok_or_else(invalid)?;
+    let mut depth = 0usize;
+    let mut close = None;
+    for (i, c) in signature[open..].char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    close = Some(open + i);
+                    break;
+                }
+            }
+            _ => {}
+        }
+    }
+    let close = close.ok_or_else(invalid)?;
+    
+    let function = signature[..=close].to_string();
+    let rest = signature[close + 1..].trim();
+    let rest = rest.strip_prefix("returns").map(str::trim).unwrap_or(rest);
+    if rest.is_empty() {
+        return Ok((function, None));
+    }
+    if !rest.starts_with('(') || !rest.ends_with(')') {
+        return Err(invalid());
+    }
+    Ok((function, Some(rest.to_string())))
+}
+
+/// Decode call return data against a return type tuple like `(uint256,address)`
+///
+/// Returns one rendered value per return type.
+pub fn decode_return_values(output_types: &str, data: &[u8]) -> Result<Vec<String>, McpError> {
+    let ty = DynSolType::parse(output_types)
+        .map_err(|e| McpError::invalid_params(format!("Invalid return types '{}': {}", output_types, e), None))?;
+    let decoded = ty.abi_decode_params(data)
+        .map_err(|e| McpError::invalid_params(
+            format!("Failed to decode {} bytes of return data as {}: {}", data.len(), output_types, e),
+            None
+        ))?;
+    
+    Ok(match decoded {
+        DynSolValue::Tuple(values) => values.iter().map(format_sol_value).collect(),
+        value => vec![format_sol_value(&value)],
+    })
+}
+
+/// An event recognised by get_logs, with its parameter names and which are indexed
+struct KnownEvent {
+    signature: &'static str,
+    params: &'static [(&'static str, bool)],
+}
+
+/// Common ERC-20, ERC-721 and Uniswap V2 Pair events decoded by get_logs
+///
+/// ERC-20 and ERC-721 `Transfer` share a topic0, so entries are told apart by
+/// their number of indexed parameters.
+const KNOWN_EVENTS: &[KnownEvent] = &[
+    KnownEvent { signature: "Transfer(address,address,uint256)", params: &[("from", true), ("to", true), ("value", false)] },
+    KnownEvent { signature: "Transfer(address,address,uint256)", params: &[("from", true), ("to", true), ("tokenId", true)] },
+    KnownEvent { signature: "Approval(address,address,uint256)", params: &[("owner", true), ("spender", true), ("value", false)] },
+    KnownEvent { signature: "Swap(address,uint256,uint256,uint256,uint256,address)", params: &[("sender", true), ("amount0In", false), ("amount1In", false), ("amount0Out", false), ("amount1Out", false), ("to", true)] },
+];
+
+/// Result of decoding a log against the known event table
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecodedLog {
+    /// Matched event signature, if topic0 is known
+    pub event: Option<String>,
+    /// Decoded (name, value) pairs for known events
+    pub params: Vec<(String, String)>,
+    /// Raw topics as 0x-prefixed hex
+    pub topics: Vec<String>,
+    /// Raw data as 0x-prefixed hex
+    pub data: String,
+}
+
+/// Resolve an event signature, or the bare name of a known event, to its topic0
+///
+/// A 32-byte 0x-prefixed hash is taken as topic0 as-is.
+pub fn event_topic(signature: &str) -> Result<B256, McpError> {
+    let signature: String = signature.chars().filter(|c| !c.is_whitespace()).collect();
+    if signature.is_empty() {
+        return Err(McpError::invalid_params("Event signature cannot be empty".to_string(), None));
+    }
+    if signature.starts_with("0x") && signature.len() == 66 {
+        return B256::from_str(&signature)
+            .map_err(|e| McpError::invalid_params(format!("Invalid topic hash '{}': {}", signature, e), None));
+    }
+    if signature.contains('(') {
+        return Ok(keccak256(signature.as_bytes()));
+    }
+    KNOWN_EVENTS.iter()
+        .find(|event| event.signature.split('(').next().is_some_and(|name| name.eq_ignore_ascii_case(&signature)))
+        .map(|event| keccak256(event.signature.as_bytes()))
+        .ok_or_else(|| McpError::invalid_params(
+            format!("Unknown event '{}': give the full signature, e.g. 'Transfer(address,address,uint256)'", signature),
+            None
+        ))
+}
+
+/// Decode a log by matching its topic0 against KNOWN_EVENTS
+///
+/// Unknown events, and known ones whose topics or data don't fit the signature,
+/// are returned with only the raw topics and data.
+pub fn decode_log(topics: &[B256], data: &[u8]) -> DecodedLog {
+    let mut decoded = DecodedLog {
+        event: None,
+        params: Vec::new(),
+        topics: topics.iter().map(|topic| format!("{:?}", topic)).collect(),
+        data: format!("0x{}", hex::encode(data)),
+    };
+    
+    let Some((topic0, indexed_topics)) = topics.split_first() else {
+        return decoded;
+    };
+    let Some(known) = KNOWN_EVENTS.iter().find(|event| {
+        keccak256(event.signature) == *topic0
+            && event.params.iter().filter(|(_, indexed)| *indexed).count() == indexed_topics.len()
+    }) else {
+        return decoded;
+    };
+    
+    let arg_types = &known.signature[known.signature.find('(').unwrap_or(0) + 1..known.signature.len() - 1];
+    let Ok(types) = arg_types.split(',').map(DynSolType::parse).collect::<Result<Vec<_>, _>>() else {
+        return decoded;
+    };
+    let data_types: Vec<DynSolType> = types.iter()
+        .zip(known.params)
+        .filter(|(_, (_, indexed))| !*indexed)
+        .map(|(ty, _)| ty.clone())
+        .collect();
+    let Ok(DynSolValue::Tuple(data_values)) = DynSolType::Tuple(data_types).abi_decode_params(data) else {
+        return decoded;
+    };
+    
+    let mut indexed_values = indexed_topics.iter();
+    let mut data_values = data_values.into_iter();
+    let mut params = Vec::with_capacity(known.params.len());
+    for (ty, (name, indexed)) in types.iter().zip(known.params) {
+        let value = if *indexed {
+            indexed_values.next().and_then(|topic| ty.abi_decode(topic.as_slice()).ok())
+        } else {
+            data_values.next()
+        };
+        let Some(value) = value else {
+            return decoded;
+        };
+        params.push((name.to_string(), format_sol_value(&value)));
+    }
+    
+    decoded.event = Some(known.signature.to_string());
+    decoded.params = params;
+    decoded
+}
+
+/// Selector of the standard `Error(string)` revert payload
+const ERROR_STRING_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+
+/// Selector of the `Panic(uint256)` payload emitted by failed asserts and checked arithmetic
+const PANIC_SELECTOR: [u8; 4] = [0x4e, 0x48, 0x7b, 0x71];
+
+/// Describe a Solidity panic code
+fn panic_code_description(code: U256) -> &'static str {
+    match code.saturating_to::<u64>() {
+        0x00 => "generic compiler panic",
+        0x01 => "assertion failed",
+        0x11 => "arithmetic overflow or underflow",
+        0x12 => "division or modulo by zero",
+        0x21 => "invalid enum value",
+        0x22 => "invalid storage byte array encoding",
+        0x31 => "pop on an empty array",
+        0x32 => "array index out of bounds",
+        0x41 => "out of memory",
+        0x51 => "call to an uninitialized function pointer",
+        _ => "unknown panic code",
+    }
+}
+
+/// Decode revert data into a readable reason
+///
+/// Handles the standard `Error(string)` and `Panic(uint256)` shapes, e.g.
+/// "execution reverted: UniswapV2: INSUFFICIENT_OUTPUT_AMOUNT". Custom errors are
+/// reported by selector since their ABI isn't known here.
+pub fn decode_revert_reason(data: &[u8]) -> String {
+    if data.is_empty() {
+        return "execution reverted (no reason given)".to_string();
+    }
+    if data.len() < 4 {
+        return format!("execution reverted: 0x{}", hex::encode(data));
+    }
+    
+    let (selector, args) = data.split_at(4);
+    if selector == ERROR_STRING_SELECTOR
+        && let Ok(DynSolValue::Tuple(values)) = DynSolType::parse("(string)").and_then(|ty| ty.abi_decode_params(args))
+        && let Some(DynSolValue::String(reason)) = values.first()
+    {
+        return format!("execution reverted: {}", reason);
+    }
+    if selector == PANIC_SELECTOR && args.len() >= 32 {
+        let code = U256::from_be_slice(&args[..32]);
+        return format!("execution reverted: panic 0x{:02x} ({})", code, panic_code_description(code));
+    }
+    
+    format!("execution reverted: custom error 0x{} (data: 0x{})", hex::encode(selector), hex::encode(args))
+}
+
+/// Extract a readable revert reason from a failed eth_call
+///
+/// Uses the revert data in the JSON-RPC error response when the node returns it,
+/// falling back to the node's error message.
+pub fn revert_reason_from_error(error: &alloy_transport::TransportError) -> String {
+    match error.as_error_resp().and_then(|payload| payload.as_revert_data()) {
+        Some(data) => decode_revert_reason(&data),
+        None => error.to_string(),
+    }
+}
+
+/// Revert reason from a `debug_traceTransaction` callTracer result, `None` if the call didn't fail
+///
+/// Prefers the revert data in `output`, then the node's decoded `revertReason`,
+/// then its bare `error` (e.g. "out of gas").
+pub fn revert_reason_from_trace(trace: &serde_json::Value) -> Option<String> {
+    let error = trace["error"].as_str()?;
+    if let Some(data) = trace["output"].as_str().and_then(|output| hex::decode(output).ok())
+        && !data.is_empty()
+    {
+        return Some(decode_revert_reason(&data));
+    }
+    Some(match trace["revertReason"].as_str() {
+        Some(reason) => format!("execution reverted: {}", reason),
+        None => error.to_string(),
+    })
+}
+
+/// Block whose state a mined transaction is replayed on: the parent of the block it was mined in
+///
+/// eth_call at block N runs on the state after N, where the transaction's own
+/// effects (nonce, balances, a passed deadline) already apply.
+pub fn replay_block(mined_in: Option<u64>) -> BlockId {
+    match mined_in {
+        Some(block_number) => BlockId::number(block_number.saturating_sub(1)),
+        None => BlockId::latest(),
+    }
+}
+
+/// Revert reason Uniswap V2 routers give for a swap mined after its deadline
+pub const ROUTER_EXPIRED_REASON: &str = "UniswapV2Router: EXPIRED";
+
+/// Whether a revert reason is the router's expired-deadline check
+pub fn is_deadline_expired(reason: &str) -> bool {
+    reason.contains(ROUTER_EXPIRED_REASON)
+}
+
+/// Describe a swap deadline relative to `now` (both unix seconds)
+///
+/// The router accepts a swap while `block.timestamp <= deadline`.
+pub fn describe_deadline(deadline: u64, now: u64) -> String {
+    if now > deadline {
+        format!("{} (passed {}s ago)", deadline, now - deadline)
+    } else {
+        format!("{} ({}s remaining)", deadline, deadline - now)
+    }
+}
+
+/// Check the chain id the node reports against `EXPECTED_CHAIN_ID`
+///
+/// Passes when no chain id is expected; otherwise the error explains which
+/// network the node is on so a misconfigured `RPC_URL` is obvious.
+pub fn verify_chain_id(expected: Option<u64>, actual: u64) -> Result<(), String> {
+    match expected {
+        Some(expected) if expected != actual => Err(format!(
+            "🚨 WRONG NETWORK: the node at RPC_URL reports chain id {}{} but EXPECTED_CHAIN_ID is {}. \
+            Refusing to send transactions - point RPC_URL at the intended node (e.g. your anvil fork) \
+            or correct EXPECTED_CHAIN_ID.",
+            actual,
+            if actual == MAINNET_CHAIN_ID { " (Ethereum mainnet - real funds)" } else { "" },
+            expected
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Parse the configured private keys, keyed by the account address each one signs for
+///
+/// Every key must belong to the account it was configured for (Alice's key to
+/// the default sender, `PRIVATE_KEY_<n>` to account n), so a wrong key is
+/// refused at startup instead of passing the key check and failing to sign.
+pub fn load_signers(config: &BlockchainConfig) -> Result<HashMap<Address, PrivateKeySigner>, String> {
+    let mut keys: Vec<(usize, &str)> = config.account_private_keys.iter()
+        .map(|(index, key)| (*index, key.as_str()))
+        .collect();
+    keys.sort();
+    if !config.alice_private_key.is_empty() {
+        keys.push((config.default_sender.index, config.alice_private_key.as_str()));
+    }
+
+    let mut signers = HashMap::new();
+    for (index, key) in keys {
+        let Some(&account) = config.accounts.get(index) else {
+            info!("⚠️  Private key provided for unknown account index {}", index);
+            continue;
+        };
+        let signer = PrivateKeySigner::from_str(key.trim())
+            .map_err(|e| format!("Invalid private key for account {} ({}): {}", index, account, e))?;
+        if signer.address() != account {
+            return Err(format!(
+                "The private key configured for account {} ({}) belongs to {} - check ALICE_PRIVATE_KEY, \
+                PRIVATE_KEY_{} and ANVIL_PRIVATE_KEYS against the ACCOUNTS order",
+                index, account, signer.address(), index
+            ));
+        }
+        signers.insert(account, signer);
+    }
+    Ok(signers)
+}
+
+/// Render the get_chain_status response
+pub fn format_chain_status(block_number: u64, chain_id: u64, gas_price_wei: u128) -> String {
+    format!(
+        "Chain Status:\n\
+        Block Number: {}\n\
+        Chain ID: {}\n\
+        Gas Price: {}\n\
+        Status: LIVE",
+        block_number,
+        chain_id,
+        format_gas_price(gas_price_wei)
+    )
+}
+
+/// Render the health_check response
+///
+/// `block_number` and `chain_id` are the results of the live probes; either
+/// failing marks the server as degraded.
+pub fn format_health_check(
+    uptime: Duration,
+    block_number: Result<u64, String>,
+    chain_id: Result<u64, String>,
+    account_count: usize,
+    private_key_available: bool,
+) -> String {
+    let provider_line = match &block_number {
+        Ok(block) => format!("CONNECTED (block {})", block),
+        Err(e) => format!("DISCONNECTED ({})", e),
+    };
+    let chain_id_line = match &chain_id {
+        Ok(chain_id) => chain_id.to_string(),
+        Err(e) => format!("UNAVAILABLE ({})", e),
+    };
+    let status = if block_number.is_ok() && chain_id.is_ok() { "HEALTHY" } else { "DEGRADED" };
+    format!(
+        "Health Check:\n\
+        Status: {}\n\
+        Uptime: {}s\n\
+        Provider: {}\n\
+        Chain ID: {}\n\
+        Accounts Loaded: {}\n\
+        Private Key Available: {}",
+        status,
+        uptime.as_secs(),
+        provider_line,
+        chain_id_line,
+        account_count,
+        private_key_available
+    )
+}
+
+/// Default number of recent blocks scanned by get_transaction_history
+pub const DEFAULT_HISTORY_BLOCKS: u64 = 100;
+
+/// Upper bound on blocks scanned by get_transaction_history, to avoid hammering the RPC
+pub const MAX_HISTORY_BLOCKS: u64 = 1000;
+
+/// Default number of recent blocks searched by get_logs
+pub const DEFAULT_LOG_BLOCKS: u64 = 1000;
+
+/// Upper bound on the block range of a get_logs query; wider ranges are truncated to the most recent blocks
+pub const MAX_LOG_BLOCKS: u64 = 10_000;
+
+/// Default seconds between receipt checks in monitor_transaction
+pub const DEFAULT_MONITOR_POLL_SECS: u64 = 2;
+
+/// Default time monitor_transaction watches a transaction before giving up
+pub const DEFAULT_MONITOR_DURATION_SECS: u64 = 120;
+
+/// Upper bound on how long one monitor_transaction call may poll
+pub const MAX_MONITOR_DURATION_SECS: u64 = 600;
+
+/// How often a confirmation wait re-checks for the receipt
+const RECEIPT_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Status history of a monitored transaction
+///
+/// Consecutive polls with the same status are collapsed into one line, e.g.
+/// "[0s-12s] PENDING - still in mempool (7 checks)".
+#[derive(Debug, Clone, Default)]
+pub struct MonitorLog {
+    /// First and last elapsed seconds, status and number of polls for each run of equal statuses
+    entries: Vec<(u64, u64, String, usize)>,
+}
+
+impl MonitorLog {
+    /// Record the status seen `elapsed_secs` after monitoring started
+    pub fn record(&mut self, elapsed_secs: u64, status: impl Into<String>) {
+        let status = status.into();
+        match self.entries.last_mut() {
+            Some((_, last_seen, last_status, checks)) if *last_status == status => {
+                *last_seen = elapsed_secs;
+                *checks += 1;
+            }
+            _ => self.entries.push((elapsed_secs, elapsed_secs, status, 1)),
+        }
+    }
+
+    /// One line per run of equal statuses, oldest first
+    pub fn lines(&self) -> Vec<String> {
+        self.entries.iter()
+            .map(|(first, last, status, checks)| match checks {
+                1 => format!("[{}s] {}", first, status),
+                _ => format!("[{}s-{}s] {} ({} checks)", first, last, status, checks),
+            })
+            .collect()
+    }
+}
+
+impl std::fmt::Display for MonitorLog {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.lines().join("\n"))
+    }
+}
+
+/// Maximum runtime bytecode size allowed by EIP-170 (24KB)
+pub const EIP170_MAX_CODE_SIZE: usize = 24576;
+
+/// Parse a slippage tolerance in basis points (e.g. "500" for 5%)
+pub fn parse_slippage_bps(slippage: &str) -> Result<u32, McpError> {
+    crate::config::parse_slippage_bps(slippage, "slippage").map_err(|e| McpError::invalid_params(e, None))
+}
+
+/// Parse a block number (decimal or 0x-hex) or tag (latest, earliest, pending, safe, finalized)
+pub fn parse_block_id(block: &str) -> Result<BlockNumberOrTag, McpError> {
+    let block = block.trim();
+    if let Ok(number) = block.parse::<u64>() {
+        return Ok(BlockNumberOrTag::Number(number));
+    }
+    BlockNumberOrTag::from_str(&block.to_lowercase()).map_err(|e| {
+        McpError::invalid_params(
+            format!("Invalid block '{}': expected a block number or one of latest, earliest, pending, safe, finalized ({})", block, e),
+            None
+        )
+    })
+}
+
+/// Outcome of matching user input against the known account names
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AccountNameMatch {
+    /// Exactly one name matches, after normalising, or is one edit away
+    Resolved(String),
+    /// Several names are equally close; never resolved automatically
+    Ambiguous(Vec<String>),
+    /// The closest name is two edits away - suggested but not used
+    Suggestion(String),
+    /// Nothing is close
+    NoMatch,
+}
+
+/// Lowercase an account name, drop whitespace and expand `acct`/`acc` to `account`
+///
+/// "Account 1", "acct1" and "acc 1" all become "account1".
+pub fn normalize_account_name(input: &str) -> String {
+    let name: String = input.chars().filter(|c| !c.is_whitespace()).collect::<String>().to_lowercase();
+    for prefix in ["account", "acct", "acc"] {
+        if let Some(index) = name.strip_prefix(prefix)
+            && !index.is_empty()
+            && index.chars().all(|c| c.is_ascii_digit())
+        {
+            return format!("account{}", index);
+        }
+    }
+    name
+}
+
+/// Levenshtein edit distance between two strings, counted in characters
+pub fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+/// Match possibly misspelt input like "alise" or "account 1" against `known` names
+///
+/// A unique name within one edit of the normalised input is resolved; ties
+/// are reported as ambiguous, and a name two edits away is only suggested.
+/// Digits have to match exactly, so "account11" never resolves to "account1".
+pub fn match_account_name(input: &str, known: &[String]) -> AccountNameMatch {
+    let input = normalize_account_name(input);
+    if input.is_empty() {
+        return AccountNameMatch::NoMatch;
+    }
+    let digits = |name: &str| name.chars().filter(char::is_ascii_digit).collect::<String>();
+    
+    let mut candidates: Vec<(usize, &String)> = known.iter()
+        .filter(|name| digits(&normalize_account_name(name)) == digits(&input))
+        .map(|name| (levenshtein(&input, &normalize_account_name(name)), name))
+        .collect();
+    candidates.sort();
+    candidates.dedup();
+    
+    let Some(&(best, _)) = candidates.first() else {
+        return AccountNameMatch::NoMatch;
+    };
+    let closest: Vec<String> = candidates.iter()
+        .filter(|(distance, _)| *distance == best)
+        .map(|(_, name)| name.to_string())
+        .collect();
+    match (best, closest.as_slice()) {
+        (_, [_, _, ..]) if best <= 2 => AccountNameMatch::Ambiguous(closest),
+        (0 | 1, [name]) => AccountNameMatch::Resolved(name.clone()),
+        (2, [name]) => AccountNameMatch::Suggestion(name.clone()),
+        _ => AccountNameMatch::NoMatch,
+    }
+}
+
+/// Human-readable block label: decimal numbers rather than alloy's hex, tags as-is
+fn block_label(block: BlockNumberOrTag) -> String {
+    match block {
+        BlockNumberOrTag::Number(number) => number.to_string(),
+        tag => tag.to_string(),
+    }
+}
+
+/// Resolve a parsed block to a concrete number, treating latest/pending/safe/finalized as `latest`
+fn block_number_or_latest(block: BlockNumberOrTag, latest: u64) -> u64 {
+    match block {
+        BlockNumberOrTag::Number(number) => number.min(latest),
+        BlockNumberOrTag::Earliest => 0,
+        _ => latest,
+    }
+}
+
+/// Clamp an inclusive block range to at most MAX_LOG_BLOCKS, keeping the most recent blocks
+///
+/// Returns the (possibly raised) first block and whether the range was truncated.
+pub fn cap_log_range(from_block: u64, to_block: u64) -> (u64, bool) {
+    if to_block - from_block >= MAX_LOG_BLOCKS {
+        (to_block - (MAX_LOG_BLOCKS - 1), true)
+    } else {
+        (from_block, false)
+    }
+}
+
+/// Derive the minimum acceptable output from an expected output and slippage tolerance
+///
+/// `amount_out_min = expected * (10000 - slippage_bps) / 10000`
+pub fn apply_slippage(expected: U256, slippage_bps: u32) -> U256 {
+    let bps = U256::from(10_000u32.saturating_sub(slippage_bps));
+    expected * bps / U256::from(10_000u32)
+}
+
+/// Quote a Uniswap V2 swap against a pair's reserves: `(expected_out, min_out, price_impact)`
+///
+/// `expected_out` is the pair's constant-product `getAmountOut` with the 0.3% fee,
+/// `min_out` applies `slippage_bps` to it (see `apply_slippage`), and `price_impact`
+/// is the percentage by which the execution price falls short of the spot price
+/// `reserve_out / reserve_in`, fee included.
+pub fn calculate_slippage(amount_in: U256, reserve_in: U256, reserve_out: U256, slippage_bps: u32) -> Result<(U256, U256, f64), McpError> {
+    if reserve_in.is_zero() || reserve_out.is_zero() {
+        return Err(McpError::invalid_params("Reserves must be greater than zero: the pair has no liquidity".to_string(), None));
+    }
+
+    let overflow = || McpError::invalid_params("Swap amount or reserves are too large to quote".to_string(), None);
+    let amount_in_with_fee = amount_in.checked_mul(U256::from(997u32)).ok_or_else(overflow)?;
+    let numerator = amount_in_with_fee.checked_mul(reserve_out).ok_or_else(overflow)?;
+    let denominator = reserve_in.checked_mul(U256::from(1000u32))
+        .and_then(|scaled| scaled.checked_add(amount_in_with_fee))
+        .ok_or_else(overflow)?;
+    let expected_out = numerator / denominator;
+
+    Ok((expected_out, apply_slippage(expected_out, slippage_bps), price_impact(amount_in, expected_out, reserve_in, reserve_out)))
+}
+
+/// Percentage by which a swap's execution price `amount_out / amount_in` falls short of
+/// the pair's spot price `reserve_out / reserve_in`
+pub fn price_impact(amount_in: U256, amount_out: U256, reserve_in: U256, reserve_out: U256) -> f64 {
+    let spot = amount_in.to_f64().unwrap_or(0.0) * reserve_out.to_f64().unwrap_or(0.0);
+    if spot == 0.0 {
+        return 0.0;
+    }
+    let execution = amount_out.to_f64().unwrap_or(0.0) * reserve_in.to_f64().unwrap_or(0.0);
+    (1.0 - execution / spot) * 100.0
+}
+
+/// Warning for a swap whose `price_impact` (a percentage) exceeds `max_bps`, if it does
+pub fn price_impact_warning(price_impact: f64, max_bps: u32) -> Option<String> {
+    (price_impact * 100.0 > max_bps as f64).then(|| format!(
+        "⚠️  HIGH PRICE IMPACT: this swap moves the price {:.2}%, above the {:.2}% limit (MAX_PRICE_IMPACT_BPS). \
+        The pool is too thin for this amount - you would receive far less than the spot price.",
+        price_impact, max_bps as f64 / 100.0
+    ))
+}
+
+/// Request structure for balance queries
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct BalanceRequest {
+    #[schemars(description = "The address, ENS name or account name (e.g., 'alice') to check balance for")]
+    pub who: String,
+    #[schemars(description = "Block number or tag (latest, earliest, pending, safe, finalized) to read the balance at (default: latest)")]
+    pub block: Option<String>,
+}
+
+/// Request structure for ETH transfers
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct TransferRequest {
+    #[schemars(description = "Recipient address")]
+    pub to: String,
+    #[schemars(description = "Amount in ETH (e.g., '1.0'), 'all'/'max' for the whole balance less gas, or a share of the balance like '50%' or 'half'")]
+    pub amount: String,
+    #[schemars(description = "Sender address or account name (default: Alice)")]
+    pub from: Option<String>,
+    #[schemars(description = "Simulate the transfer with eth_call instead of broadcasting it (default: false)")]
+    pub dry_run: Option<bool>,
+    #[schemars(description = "Seconds to wait for confirmation (default: CONFIRMATION_TIMEOUT_SECS, 30)")]
+    pub confirmation_timeout: Option<u64>,
+}
+
+/// Request structure for ETH transfer cost estimates
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct TransferCostRequest {
+    #[schemars(description = "Recipient address")]
+    pub to: String,
+    #[schemars(description = "Amount in ETH (e.g., '1.0')")]
+    pub amount: String,
+}
+
+/// Request structure for contract deployment checks
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct ContractDeploymentRequest {
+    #[schemars(description = "Contract address to check")]
+    pub address: String,
+    #[schemars(description = "Expected keccak256 hash of the deployed code (0x-prefixed); reports MATCH or MISMATCH when provided")]
+    pub expected_code_hash: Option<String>,
+}
+
+/// Request structure for contract deployments
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct DeployContractRequest {
+    #[schemars(description = "Contract creation bytecode as hex (0x-prefixed or not)")]
+    pub bytecode: String,
+    #[schemars(description = "Constructor parameter types, e.g. 'constructor(uint256,address)' or '(uint256,address)' - required with args")]
+    pub constructor: Option<String>,
+    #[schemars(description = "Constructor arguments, one per parameter (e.g., ['1000', '0x...'])")]
+    pub args: Option<Vec<String>>,
+    #[schemars(description = "Seconds to wait for confirmation (default: CONFIRMATION_TIMEOUT_SECS, 30)")]
+    pub confirmation_timeout: Option<u64>,
+}
+
+/// Request structure for generic read-only contract calls
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct ContractCallRequest {
+    #[schemars(description = "Contract address to call")]
+    pub address: String,
+    #[schemars(description = "Function signature with return types, e.g. 'totalSupply()(uint256)' or 'balanceOf(address)(uint256)'; without return types the raw result is shown")]
+    pub signature: String,
+    #[schemars(description = "Function arguments, one per parameter (e.g., ['0x...'])")]
+    pub args: Option<Vec<String>>,
+    #[schemars(description = "Block number or tag (latest, earliest, pending, safe, finalized) to call at (default: latest)")]
+    pub block: Option<String>,
+}
+
+/// Request structure for generic state-changing contract calls
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct ContractSendRequest {
+    #[schemars(description = "Contract address to call")]
+    pub address: String,
+    #[schemars(description = "Function signature, e.g. 'mint()' or 'mint(address,uint256)'; return types are ignored")]
+    pub signature: String,
+    #[schemars(description = "Function arguments, one per parameter (e.g., ['0x...', '1000'])")]
+    pub args: Option<Vec<String>>,
+    #[schemars(description = "ETH to send with the call (e.g., '0.1'), for payable functions (default: 0)")]
+    pub value: Option<String>,
+    #[schemars(description = "Gas limit to use instead of the node's estimate")]
+    pub gas_limit: Option<u64>,
+    #[schemars(description = "Simulate the call with eth_call instead of broadcasting it (default: false)")]
+    pub dry_run: Option<bool>,
+    #[schemars(description = "Seconds to wait for confirmation (default: CONFIRMATION_TIMEOUT_SECS, 30)")]
+    pub confirmation_timeout: Option<u64>,
+}
+
+/// Request structure for ERC-20 token balance queries
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct TokenBalanceRequest {
+    #[schemars(description = "Token contract address (e.g., USDC: 0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48)")]
+    pub token_address: String,
+    #[schemars(description = "Account address to check balance for")]
+    pub account_address: String,
+    #[schemars(description = "Block number or tag (latest, earliest, pending, safe, finalized) to read the balance at (default: latest)")]
+    pub block: Option<String>,
+}
+
+/// Request structure for adding an address book entry
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct AddressBookEntryRequest {
+    #[schemars(description = "Name for the address (case-insensitive, e.g. treasury); the default sender and recipient names (alice and bob unless configured) and accountN are reserved")]
+    pub name: String,
+    #[schemars(description = "Ethereum address to save under the name")]
+    pub address: String,
+}
+
+/// Request structure for WETH balance queries
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct WethBalanceRequest {
+    #[schemars(description = "Account address or ENS name to check the WETH balance for")]
+    pub account_address: String,
+}
+
+/// Request structure for ERC-20 token transfers
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct TokenTransferRequest {
+    #[schemars(description = "Token contract address (e.g., USDC: 0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48)")]
+    pub token_address: String,
+    #[schemars(description = "Recipient address")]
+    pub to: String,
+    #[schemars(description = "Amount in whole tokens (e.g., '100.5'), scaled by the token's decimals")]
+    pub amount: String,
+    #[schemars(description = "Seconds to wait for confirmation (default: CONFIRMATION_TIMEOUT_SECS, 30)")]
+    pub confirmation_timeout: Option<u64>,
+}
+
+/// Request structure for ERC-20 approvals
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct TokenApprovalRequest {
+    #[schemars(description = "Token contract address")]
+    pub token_address: String,
+    #[schemars(description = "Spender address allowed to move Alice's tokens (e.g., Uniswap V2 Router)")]
+    pub spender: String,
+    #[schemars(description = "Allowance in whole tokens (e.g., '100.5'), scaled by the token's decimals")]
+    pub amount: String,
+    #[schemars(description = "Seconds to wait for confirmation (default: CONFIRMATION_TIMEOUT_SECS, 30)")]
+    pub confirmation_timeout: Option<u64>,
+}
+
+/// Request structure for ERC-20 allowance queries
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct AllowanceRequest {
+    #[schemars(description = "Token contract address")]
+    pub token_address: String,
+    #[schemars(description = "Token owner address")]
+    pub owner: String,
+    #[schemars(description = "Spender address")]
+    pub spender: String,
+}
+
+/// Request structure for ERC-721 owner lookups
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct NftOwnerRequest {
+    #[schemars(description = "ERC-721 collection contract address")]
+    pub collection_address: String,
+    #[schemars(description = "Token id, in decimal or 0x-prefixed hex")]
+    pub token_id: String,
+}
+
+/// Request structure for ERC-721 balance queries
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct NftBalanceRequest {
+    #[schemars(description = "ERC-721 collection contract address")]
+    pub collection_address: String,
+    #[schemars(description = "Owner address or account name (e.g., 'alice')")]
+    pub owner: String,
+}
+
+/// Request structure for token swaps
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct SwapRequest {
+    #[schemars(description = "Token to swap from (e.g., 'ETH')")]
+    pub from_token: String,
+    #[schemars(description = "Token to swap to (e.g., 'USDC')")]
+    pub to_token: String,
+    #[schemars(description = "Amount to swap (e.g., '10')")]
+    pub amount: String,
+    #[schemars(description = "DEX to use (e.g., 'Uniswap V2')")]
+    pub dex: Option<String>,
+    #[schemars(description = "Slippage tolerance in basis points (e.g., '500' for 5%)")]
+    pub slippage: Option<String>,
+    #[schemars(description = "Simulate the swap with eth_call instead of broadcasting it (default: false)")]
+    pub dry_run: Option<bool>,
+    #[schemars(description = "Seconds to wait for confirmation (default: CONFIRMATION_TIMEOUT_SECS, 30)")]
+    pub confirmation_timeout: Option<u64>,
+    #[schemars(description = "Swap even if the price impact exceeds MAX_PRICE_IMPACT_BPS (default: false)")]
+    pub force: Option<bool>,
+}
+
+/// Request structure for transaction status checks
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct TransactionStatusRequest {
+    #[schemars(description = "Transaction hash to check status for")]
+    pub tx_hash: String,
+    #[schemars(description = "Timeout in seconds for waiting for transaction (default: CONFIRMATION_TIMEOUT_SECS, 30)")]
+    pub timeout: Option<u64>,
+}
+
+/// Request structure for watching a transaction until it is mined
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct MonitorTransactionRequest {
+    #[schemars(description = "Transaction hash to watch")]
+    pub tx_hash: String,
+    #[schemars(description = "Seconds between receipt checks (default: 2)")]
+    pub poll_interval: Option<u64>,
+    #[schemars(description = "Seconds to keep watching before returning the pending status (default: 120, max: 600)")]
+    pub max_duration: Option<u64>,
+    #[schemars(description = "Blocks to wait for, counting the one the transaction was mined in (default: 1)")]
+    pub confirmations: Option<u64>,
+}
+
+/// Request structure for looking up a token's contract address
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct ResolveTokenAddressRequest {
+    #[schemars(description = "Token symbol to look up (e.g., 'PEPE', 'AAVE')")]
+    pub symbol: String,
+    #[schemars(description = "Network to search for the token on (default: 'ethereum')")]
+    pub network: Option<String>,
+    #[schemars(description = "Whether to fall back to a web search when the symbol is not in the token table (default: true)")]
+    pub search: Option<bool>,
+}
+
+/// Request structure for forward ENS resolution
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct EnsResolveRequest {
+    #[schemars(description = "ENS name to resolve (e.g., 'vitalik.eth')")]
+    pub name: String,
+}
+
+/// Request structure for reverse ENS resolution
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct EnsReverseRequest {
+    #[schemars(description = "Ethereum address to look up the primary ENS name for")]
+    pub address: String,
+}
+
+/// Request structure for ENS text record lookups
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct EnsRecordsRequest {
+    #[schemars(description = "ENS name to read records for (e.g., 'vitalik.eth')")]
+    pub name: String,
+    #[schemars(description = "Text record keys to read (e.g., ['avatar', 'email', 'url']); defaults to DEFAULT_ENS_TEXT_KEYS")]
+    #[serde(default)]
+    pub keys: Vec<String>,
+}
+
+/// Request structure for calldata decoding
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct DecodeCalldataRequest {
+    #[schemars(description = "Hash of the transaction whose input should be decoded")]
+    pub tx_hash: String,
+}
+
+/// Request structure for funding an account on anvil
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct FundAccountRequest {
+    #[schemars(description = "Address or account name to fund (e.g., 'account3')")]
+    pub address: String,
+    #[schemars(description = "New ETH balance to set (e.g., '100.0'); replaces the current balance")]
+    pub amount: String,
+}
+
+/// Request structure for impersonated transfers on anvil
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct ImpersonatedTransferRequest {
+    #[schemars(description = "Address to send from without its private key (e.g., a token whale on a mainnet fork)")]
+    pub from: String,
+    #[schemars(description = "Recipient address or account name")]
+    pub to: String,
+    #[schemars(description = "Amount in ETH, or in whole tokens when token_address is set (e.g., '1000')")]
+    pub amount: String,
+    #[schemars(description = "ERC-20 token contract address; omit to send ETH")]
+    pub token_address: Option<String>,
+    #[schemars(description = "Seconds to wait for confirmation (default: CONFIRMATION_TIMEOUT_SECS, 30)")]
+    pub confirmation_timeout: Option<u64>,
+}
+
+/// Request structure for Chainlink price queries
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct OnchainPriceRequest {
+    #[schemars(description = "Token symbol with a configured Chainlink USD feed (e.g., 'ETH', 'WBTC', 'USDC')")]
+    pub symbol: String,
+}
+
+/// Request structure for wei/gwei/ether conversions
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct UnitConversionRequest {
+    #[schemars(description = "Amount to convert (e.g., '0.00003' or '21000000000')")]
+    pub value: String,
+    #[schemars(description = "Unit of the amount: 'wei', 'gwei' or 'ether' (alias 'eth')")]
+    pub from: String,
+    #[schemars(description = "Unit to convert to: 'wei', 'gwei' or 'ether' (alias 'eth')")]
+    pub to: String,
+}
+
+/// Request structure for Uniswap V2 swap output quotes
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct SwapOutputRequest {
+    #[schemars(description = "Amount of the input token to swap, in token units (e.g., '10')")]
+    pub amount_in: String,
+    #[schemars(description = "Pair reserve of the input token, in token units")]
+    pub reserve_in: String,
+    #[schemars(description = "Pair reserve of the output token, in token units")]
+    pub reserve_out: String,
+    #[schemars(description = "Slippage tolerance in basis points (e.g., '50' for 0.5%, default: DEFAULT_SLIPPAGE_BPS)")]
+    pub slippage: Option<String>,
+    #[schemars(description = "Decimals of the input token (default: 18)")]
+    pub decimals_in: Option<u8>,
+    #[schemars(description = "Decimals of the output token (default: 18, e.g. 6 for USDC)")]
+    pub decimals_out: Option<u8>,
+}
+
+/// Request structure for nonce queries
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct NonceRequest {
+    #[schemars(description = "Address or account name to get the nonce for (e.g., 'alice')")]
+    pub address: String,
+}
+
+/// Request structure for listing pending transactions
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct PendingTransactionsRequest {
+    #[schemars(description = "Optional address or account name to filter by; lists all senders when omitted")]
+    pub address: Option<String>,
+    #[schemars(description = "Maximum number of transactions to return (default: 20, max: 100)")]
+    pub limit: Option<usize>,
+    #[schemars(description = "Number of transactions to skip, from a previous response's footer (default: 0)")]
+    pub offset: Option<usize>,
+}
+
+/// Request structure for transaction history queries
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct TransactionHistoryRequest {
+    #[schemars(description = "Address or account name to look up (e.g., 'alice')")]
+    pub address: String,
+    #[schemars(description = "Number of recent blocks to scan (default: 100, max: 1000)")]
+    pub block_range: Option<u64>,
+    #[schemars(description = "Maximum number of transactions to return, newest first (default: 20, max: 100)")]
+    pub limit: Option<usize>,
+    #[schemars(description = "Number of transactions to skip, from a previous response's footer (default: 0)")]
+    pub offset: Option<usize>,
+}
+
+/// Request structure for event log queries
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct LogQueryRequest {
+    #[schemars(description = "Contract address that emitted the events")]
+    pub address: String,
+    #[schemars(description = "Event signature (e.g. 'Transfer(address,address,uint256)'), a known event name (Transfer, Approval, Swap) or a topic0 hash; omit for all events")]
+    pub event_signature: Option<String>,
+    #[schemars(description = "First block to search, number or tag (default: 1000 blocks before to_block)")]
+    pub from_block: Option<String>,
+    #[schemars(description = "Last block to search, number or tag (default: latest)")]
+    pub to_block: Option<String>,
+    #[schemars(description = "Maximum number of logs to return, oldest first (default: 20, max: 100)")]
+    pub limit: Option<usize>,
+    #[schemars(description = "Number of logs to skip, from a previous response's footer (default: 0)")]
+    pub offset: Option<usize>,
+}
+
+/// Upper bound on reads in a single batch_read request, to avoid hammering the RPC
+pub const MAX_BATCH_READS: usize = 50;
+
+/// A single read in a batch_read request, tagged by `type`
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ReadSpec {
+    /// ETH balance of an address
+    Balance {
+        #[schemars(description = "Address or account name")]
+        address: String,
+    },
+    /// ERC-20 balance of an account
+    TokenBalance {
+        #[schemars(description = "Token contract address")]
+        token_address: String,
+        #[schemars(description = "Account address or name")]
+        account_address: String,
+    },
+    /// ERC-20 allowance an owner has granted to a spender
+    Allowance {
+        #[schemars(description = "Token contract address")]
+        token_address: String,
+        #[schemars(description = "Token owner address or name")]
+        owner: String,
+        #[schemars(description = "Spender address or name")]
+        spender: String,
+    },
+    /// Latest nonce of an address
+    Nonce {
+        #[schemars(description = "Address or account name")]
+        address: String,
+    },
+}
+
+impl ReadSpec {
+    /// The `type` tag of this read
+    pub fn kind(&self) -> &'static str {
+        match self {
+            ReadSpec::Balance { .. } => "balance",
+            ReadSpec::TokenBalance { .. } => "token_balance",
+            ReadSpec::Allowance { .. } => "allowance",
+            ReadSpec::Nonce { .. } => "nonce",
+        }
+    }
+}
+
+/// Request structure for batched read-only queries
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct BatchReadRequest {
+    #[schemars(description = "Reads to run concurrently, each with a 'type' (balance, token_balance, allowance, nonce) and its parameters, e.g. {\"type\": \"balance\", \"address\": \"alice\"}")]
+    pub reads: Vec<ReadSpec>,
+}
+
+/// Response structure for account information
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AccountInfo {
+    #[schemars(description = "Account index (0-9)")]
+    pub index: u32,
+    #[schemars(description = "Public address")]
+    pub address: String,
+    #[schemars(description = "Private key (only included in get_private_keys)")]
+    pub private_key: Option<String>,
+}
+
+/// Response structure for account listings
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct AccountListResponse {
+    #[schemars(description = "List of available accounts")]
+    pub accounts: Vec<AccountInfo>,
+    #[schemars(description = "Total number of accounts")]
+    pub total: u32,
+}
+
+/// Outcome of one read in a batch_read response
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct BatchReadResult {
+    #[schemars(description = "Position of the read in the request")]
+    pub index: usize,
+    #[schemars(description = "Read type (balance, token_balance, allowance, nonce)")]
+    pub read_type: String,
+    #[schemars(description = "Raw value (wei, token base units or nonce); absent when the read failed")]
+    pub value: Option<String>,
+    #[schemars(description = "Human-readable value, e.g. '1.5 ETH'")]
+    pub formatted: Option<String>,
+    #[schemars(description = "Why the read failed")]
+    pub error: Option<String>,
+}
+
+/// Response structure for batch_read
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct BatchReadResponse {
+    #[schemars(description = "Number of reads that succeeded")]
+    pub succeeded: usize,
+    #[schemars(description = "Number of reads that failed")]
+    pub failed: usize,
+    #[schemars(description = "Per-read results, in request order")]
+    pub results: Vec<BatchReadResult>,
+}
+
+/// Lifecycle stage of a transaction reported in a structured result
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum TxStatus {
+    /// Dry run only - nothing was broadcast
+    Simulated,
+    /// Mined and succeeded
+    Confirmed,
+    /// Mined but reverted
+    Failed,
+    /// Broadcast, but no receipt arrived before the confirmation timeout
+    Pending,
+}
+
+/// Receipt details of a mined transaction
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct TransactionConfirmation {
+    #[schemars(description = "Transaction hash")]
+    pub tx_hash: String,
+    #[schemars(description = "Whether the transaction succeeded")]
+    pub succeeded: bool,
+    #[schemars(description = "Block the transaction was mined in")]
+    pub block_number: Option<u64>,
+    #[schemars(description = "Gas used by the transaction")]
+    pub gas_used: u64,
+    #[schemars(description = "Effective gas price in wei")]
+    pub effective_gas_price_wei: String,
+    #[schemars(description = "Decoded revert reason, for failed transactions")]
+    pub revert_reason: Option<String>,
+    #[schemars(description = "Address of the contract created by the transaction, for deployments")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contract_address: Option<String>,
+}
+
+impl TransactionConfirmation {
+    /// Status of the mined transaction
+    pub fn status(&self) -> TxStatus {
+        if self.succeeded { TxStatus::Confirmed } else { TxStatus::Failed }
+    }
+}
+
+impl std::fmt::Display for TransactionConfirmation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let status = if self.succeeded { "SUCCESS" } else { "FAILED" };
+        let gas_price = self.effective_gas_price_wei.parse::<u128>().unwrap_or(0);
+        let total_cost = self.gas_used as u128 * gas_price;
+        write!(
+            f,
+            "Transaction Confirmed: {}\n\
+            Hash: {}\n\
+            Block Number: {}\n\
+            Gas Used: {}\n\
+            Gas Price: {}\n\
+            Total Cost: {} wei ({:.6} ETH)\n\
+            Status: {}",
+            status,
+            self.tx_hash,
+            self.block_number.unwrap_or_default(),
+            self.gas_used,
+            format_gas_price(gas_price),
+            total_cost,
+            total_cost.to_f64().unwrap_or(0.0) / 1e18,
+            status
+        )?;
+        if let Some(reason) = &self.revert_reason {
+            write!(f, "\nRevert Reason: {}", reason)?;
+        }
+        Ok(())
+    }
+}
+
+/// Structured result of send_eth and transfer_token
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct TransferResult {
+    #[schemars(description = "simulated, confirmed, failed or pending")]
+    pub status: TxStatus,
+    #[schemars(description = "Sender address")]
+    pub from: String,
+    #[schemars(description = "Sender account name or type")]
+    pub from_label: String,
+    #[schemars(description = "Recipient as given (address, ENS name or account name)")]
+    pub to: String,
+    #[schemars(description = "Resolved recipient address")]
+    pub to_address: String,
+    #[schemars(description = "ETH or the token symbol")]
+    pub asset: String,
+    #[schemars(description = "Token contract, for ERC-20 transfers")]
+    pub token_address: Option<String>,
+    #[schemars(description = "Human-readable amount, e.g. '1.5 ETH'")]
+    pub amount: String,
+    #[schemars(description = "Amount in wei or token base units")]
+    pub amount_raw: String,
+    #[schemars(description = "Transaction hash; absent for dry runs")]
+    pub tx_hash: Option<String>,
+    #[schemars(description = "Receipt details once mined")]
+    pub confirmation: Option<TransactionConfirmation>,
+}
+
+/// Structured result of get_ens_records
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct EnsRecordsResult {
+    #[schemars(description = "ENS name the records belong to")]
+    pub name: String,
+    #[schemars(description = "Resolver contract the records were read from")]
+    pub resolver: String,
+    #[schemars(description = "Non-empty text records by key")]
+    pub records: std::collections::BTreeMap<String, String>,
+}
+
+/// Structured result of balance, token_balance and weth_balance
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct BalanceResult {
+    #[schemars(description = "Account as given (address, ENS name or account name)")]
+    pub account: String,
+    #[schemars(description = "Resolved account address")]
+    pub address: String,
+    #[schemars(description = "How the account was resolved")]
+    pub address_type: String,
+    #[schemars(description = "Block the balance was read at")]
+    pub block: String,
+    #[schemars(description = "ETH or the token symbol")]
+    pub asset: String,
+    #[schemars(description = "Token contract, for ERC-20 balances")]
+    pub token_address: Option<String>,
+    #[schemars(description = "Human-readable balance, e.g. '1.5 ETH'")]
+    pub balance: String,
+    #[schemars(description = "Balance in wei or token base units")]
+    pub balance_raw: String,
+    #[schemars(description = "Decimals used to format the balance")]
+    pub decimals: u8,
+}
+
+/// Structured result of swap_tokens
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SwapResult {
+    #[schemars(description = "simulated, confirmed, failed or pending")]
+    pub status: TxStatus,
+    #[schemars(description = "Address that sent the swap")]
+    pub from: String,
+    #[schemars(description = "Token sold")]
+    pub from_token: String,
+    #[schemars(description = "Token bought")]
+    pub to_token: String,
+    #[schemars(description = "DEX or contract used (e.g. 'Uniswap V2', 'WETH')")]
+    pub dex: String,
+    #[schemars(description = "Router or WETH contract address")]
+    pub contract: String,
+    #[schemars(description = "Amount sold, as requested")]
+    pub amount_in: String,
+    #[schemars(description = "Amount sold in wei or token base units")]
+    pub amount_in_raw: String,
+    #[schemars(description = "Router-quoted output in base units (router swaps only)")]
+    pub expected_out_raw: Option<String>,
+    #[schemars(description = "Minimum output after slippage in base units (router swaps only)")]
+    pub minimum_out_raw: Option<String>,
+    #[schemars(description = "Price impact in percent against the pair's spot price (router swaps only, when the reserves could be read)")]
+    pub price_impact_pct: Option<f64>,
+    #[schemars(description = "Unix time after which the router rejects the swap (router swaps only)")]
+    pub deadline: Option<u64>,
+    #[schemars(description = "Whether the deadline had passed when the response was generated (router swaps only, once sent)")]
+    pub deadline_passed: Option<bool>,
+    #[schemars(description = "Transaction hash; absent for dry runs")]
+    pub tx_hash: Option<String>,
+    #[schemars(description = "Receipt details once mined")]
+    pub confirmation: Option<TransactionConfirmation>,
+}
+
+/// Build a tool result from a structured value (as JSON) followed by a human-readable summary
+fn structured_result<T: Serialize>(result: &T, summary: String) -> Result<CallToolResult, McpError> {
+    let json_response = serde_json::to_string_pretty(result)
+        .map_err(|e| McpError::internal_error(format!("Failed to serialize response: {}", e), None))?;
+    Ok(CallToolResult::success(vec![Content::text(json_response), Content::text(summary)]))
+}
+
+/// Pick the account list from the node's eth_accounts result
+///
+/// Falls back to the built-in anvil accounts only when the node reports none or
+/// the call fails; a short list is kept as-is so the service can degrade to
+/// fewer named accounts.
+pub fn accounts_or_defaults(node_accounts: alloy_transport::TransportResult<Vec<Address>>) -> Vec<Address> {
+    match node_accounts {
+        Ok(accounts) if !accounts.is_empty() => {
+            info!("📋 Loaded {} accounts from node via eth_accounts", accounts.len());
+            if accounts.len() < 2 {
+                warn!("⚠️  Node reported only {} account - there is no default recipient", accounts.len());
+            }
+            accounts
+        }
+        Ok(_) => {
+            warn!("⚠️  Node reported no accounts, falling back to default anvil accounts");
+            BlockchainConfig::default_accounts()
+        }
+        Err(e) => {
+            warn!("⚠️  eth_accounts failed ({}), falling back to default anvil accounts", e);
+            BlockchainConfig::default_accounts()
+        }
+    }
+}
+
+/// Validated address information
+#[derive(Debug, Clone)]
+pub struct ValidatedAddress {
+    pub address: String,
+    pub resolved_address: Address,
+    pub address_type: String,
+}
+
+/// Estimated gas usage and projected cost for a transaction
+#[derive(Debug, Clone)]
+pub struct GasCostEstimate {
+    /// Estimated gas limit
+    pub gas_limit: U256,
+    /// Current gas price in wei
+    pub gas_price: U256,
+    /// Projected cost in wei (gas_limit * gas_price)
+    pub total_cost: U256,
+}
+
+impl GasCostEstimate {
+    /// Human-readable summary for tool responses
+    pub fn summary(&self) -> String {
+        format!(
+            "Estimated Gas: {}\n\
+            Gas Price: {}\n\
+            Estimated Cost: {} wei ({:.6} ETH)",
+            self.gas_limit,
+            format_gas_price(self.gas_price.saturating_to::<u128>()),
+            self.total_cost,
+            self.total_cost.to_f64().unwrap_or(0.0) / 1e18
+        )
+    }
+}
+
+/// Blockchain MCP Service - Following PRD Example Exactly
+/// 
+/// This matches the "MyMcp" struct from the PRD example, using Cast directly
+#[derive(Clone)]
+pub struct BlockchainService {
+    /// Provider for blockchain connection (ENS, gas, blocks and txpool queries)
+    provider: RootProvider<AnyNetwork>,
+    /// Balance, code, call, receipt and send access - the live provider, or a mock in tests
+    chain: Arc<dyn ChainProvider>,
+    /// Alice's address (default sender from PRD)
+    alice_address: Address,
+    /// Bob's address (default recipient from PRD), absent when the node has too few accounts
+    bob_address: Option<Address>,
+    /// Alice's private key for transactions
+    alice_private_key: String,
+    /// Private keys for every sender that can sign transactions (including Alice)
+    private_keys: HashMap<Address, String>,
+    /// All available anvil accounts (addresses and private keys)
+    anvil_accounts: Vec<AccountInfo>,
+    /// Chain id reported by the node at startup
+    chain_id: u64,
+    /// Router and token addresses selected for `chain_id`
+    addresses: NetworkAddresses,
+    /// When the service was created, for health_check uptime
+    started_at: Instant,
+    /// Named external addresses, shared so entries added at runtime reach every clone
+    address_book: Arc<Mutex<AddressBook>>,
+    /// Memoized token metadata (symbol, decimals) keyed by token address
+    token_metadata_cache: Arc<Mutex<HashMap<Address, (String, u8)>>>,
+    /// ETH spent by send_eth and swaps since the server started, shared by every clone
+    session_spent_wei: Arc<Mutex<U256>>,
+    /// Permits for in-flight RPC calls (`max_concurrent_operations`), shared by `chain` and every clone
+    operation_permits: Arc<Semaphore>,
+    /// Tool router for MCP
+    tool_router: ToolRouter<Self>,
+    /// Configuration for the blockchain service
+    config: BlockchainConfig,
+}
+
+#[tool_router]
+impl BlockchainService {
+    /// Search for a token's contract address using cache and web search
+    async fn search_token_address(&self, token_symbol: &str) -> Result<Option<Address>, McpError> {
+        println!("\n🔎 Starting search for token contract address: {}", token_symbol);
+        
+        // Step 0: Addresses configured for the connected network
+        if let Some(configured_address) = self.addresses.token(token_symbol) {
+            println!("📋 Found {} in network config (chain {}): {:?}", token_symbol, self.chain_id, configured_address);
+            return Ok(Some(configured_address));
+        }
+        
+        // Step 1: Check cache first
+        let cache_result = TOKEN_ADDRESS_CACHE.lock()
+            .map_err(|e| McpError::internal_error(format!("Cache lock error: {}", e), None))?
+            .get(&token_cache_key(self.chain_id, token_symbol))
+            .copied();
+            
+        if let Some(cached_address) = cache_result {
+            println!("✨ Found {} in cache! Address: {:?}", token_symbol, cached_address);
+            return Ok(Some(cached_address));
+        }
+        
+        println!("🌐 {} not in cache, searching web...", token_symbol);
+        
+        // Prepare search query
+        let query = format!("{} token contract address ethereum mainnet", token_symbol);
+        info!("🌐 Web searching: {}", query);
+        
+        // Create HTTP client
+        let client = reqwest::Client::new();
+        
+        // Use environment variable for API key
+        let api_key = std::env::var("BRAVE_SEARCH_API_KEY")
+            .map_err(|_| McpError::internal_error("BRAVE_SEARCH_API_KEY environment variable not set".to_string(), None))?;
+            
+        // Add delay to avoid rate limiting (1 second between requests)
+        println!("⏳ Rate limit protection: waiting 1 second before API call...");
+        sleep(Duration::from_secs(1)).await;
+        
+        // Make request to Brave Search API
+        let response = client
+            .get("https://api.search.brave.com/res/v1/web/search")
+            .header("X-Subscription-Token", api_key)
+            .header("Accept", "application/json")
+            .query(&[("q", &query)])
+            .send()
+            .await
+            .map_err(|e| McpError::internal_error(format!("Search API request failed: {}", e), None))?;
+            
+        if !response.status().is_success() {
+            return Err(McpError::internal_error(
+                format!("Search API returned error status: {}", response.status()),
+                None
+            ));
+        }
+        
+        let search_result: serde_json::Value = response.json().await
+            .map_err(|e| McpError::internal_error(format!("Failed to parse search response: {}", e), None))?;
+            
+        // Extract contract address using regex
+        let address_regex = Regex::new(r"0x[a-fA-F0-9]{40}")
+            .map_err(|e| McpError::internal_error(format!("Regex creation failed: {}", e), None))?;
+            
+        if let Some(results) = search_result["web"]["results"].as_array() {
+            for result in results {
+                if let Some(text) = result["description"].as_str() {
+                    if let Some(address_match) = address_regex.find(text) {
+                        let address_str = address_match.as_str();
+                        if let Ok(address) = Address::from_str(address_str) {
+                            println!("\n💎 Found contract address for {} via web search!", token_symbol);
+                            println!("📝 Contract Address: {:?}", address);
+                            
+                            // Cache the result
+                            TOKEN_ADDRESS_CACHE.lock()
+                                .map_err(|e| McpError::internal_error(format!("Cache lock error: {}", e), None))?
+                                .insert(token_cache_key(self.chain_id, token_symbol), address);
+                                
+                            println!("💾 Stored {} address in cache for future use", token_symbol);
+                            return Ok(Some(address));
+                        }
+                    }
+                }
+            }
+        }
+        
+        println!("\n❌ Could not find contract address for {}", token_symbol);
+        println!("   - Not found in cache");
+        println!("   - Web search returned no valid results");
+        Ok(None)
+    }
+
+    /// Check that `address` holds a contract whose `symbol()` matches `symbol`, returning its symbol and decimals
+    async fn verify_token_contract(&self, address: Address, symbol: &str) -> Result<(String, u8), String> {
+        let code = self.retry_read(|| self.chain.get_code_at(address)).await
+            .map_err(|e| format!("failed to read code: {}", e))?;
+        if code.is_empty() {
+            return Err("no contract deployed".to_string());
+        }
+        let (found_symbol, decimals) = self.get_token_info(&address).await
+            .map_err(|e| format!("failed to read token info: {}", e.message))?;
+        // The table maps ETH to WETH, so accept the wrapped symbol for it
+        let matches = found_symbol.eq_ignore_ascii_case(symbol)
+            || (symbol.eq_ignore_ascii_case("ETH") && found_symbol.eq_ignore_ascii_case("WETH"));
+        if !matches {
+            return Err(if found_symbol == "UNKNOWN" {
+                "symbol() did not return a token symbol (not an ERC-20?)".to_string()
+            } else {
+                format!("symbol() is {}, not {}", found_symbol, symbol)
+            });
+        }
+        Ok((found_symbol, decimals))
+    }
+
+    /// Find a token's address in the network config, or else through the search service's contract lookup
+    ///
+    /// Search candidates are only returned once they check out on-chain as a
+    /// contract answering `symbol()` with the requested symbol. Verified search
+    /// results are cached so swap_tokens can use the symbol afterwards.
+    pub async fn resolve_token_address(
+        &self,
+        search: &SearchService,
+        Parameters(ResolveTokenAddressRequest { symbol, network, search: allow_search }): Parameters<ResolveTokenAddressRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        let symbol = symbol.trim().to_string();
+        if symbol.is_empty() {
+            return Err(McpError::invalid_params("A token symbol is required".to_string(), None));
+        }
+        info!("🔎 MCP Server: resolve_token_address called for {}", symbol);
+        
+        // Step 1: The configured token table for the connected chain
+        if let Some(address) = self.addresses.token(&symbol) {
+            let verification = match self.verify_token_contract(address, &symbol).await {
+                Ok((found_symbol, decimals)) => format!("Verified: deployed ERC-20, symbol() = {}, {} decimals", found_symbol, decimals),
+                Err(reason) => format!("⚠️  Not verified on this chain: {}", reason),
+            };
+            let response_text = format!(
+                "Token Address Resolved:\n\
+                Symbol: {}\n\
+                Address: {}\n\
+                Source: network config (chain {})\n\
+                {}",
+                symbol, address, self.chain_id, verification
+            );
+            info!("🔍 MCP Server resolve_token_address response: {}", response_text);
+            return Ok(CallToolResult::success(vec![Content::text(response_text)]));
+        }
+        
+        // A table-only lookup stops here, without spending a search or touching the cache
+        if allow_search == Some(false) {
+            return Err(McpError::invalid_params(
+                format!("{} is not in the token table for chain {} (search disabled)", symbol, self.chain_id),
+                None
+            ));
+        }
+        
+        // Step 2: Candidate addresses from the search service
+        let network = network.unwrap_or_else(|| "ethereum".to_string());
+        info!("🌐 {} is not in the token table, searching for its {} contract", symbol, network);
+        let search_result = search.get_contract_info(Parameters(ContractInfoRequest {
+            contract: format!("{} token", symbol),
+            network: Some(network.clone()),
+        })).await.map_err(|e| McpError::internal_error(
+            format!("{} is not in the token table and the contract search failed: {}", symbol, e.message),
+            None
+        ))?;
+        let search_text: String = search_result.content.iter()
+            .filter_map(|content| content.as_text())
+            .map(|content| content.text.as_str())
+            .collect::<Vec<_>>()
+            .join("\n");
+        let candidates = extract_addresses(&search_text);
+        
+        // Step 3: Return the first candidate that is a deployed token with this symbol
+        let mut rejected = Vec::new();
+        for address in candidates.iter().take(MAX_TOKEN_SEARCH_CANDIDATES) {
+            match self.verify_token_contract(*address, &symbol).await {
+                Ok((found_symbol, decimals)) => {
+                    TOKEN_ADDRESS_CACHE.lock()
+                        .map_err(|e| McpError::internal_error(format!("Cache lock error: {}", e), None))?
+                        .insert(token_cache_key(self.chain_id, &symbol), *address);
+                    let response_text = format!(
+                        "Token Address Resolved:\n\
+                        Symbol: {}\n\
+                        Address: {}\n\
+                        Source: web search for {} ({} candidate(s) checked)\n\
+                        Verified: deployed ERC-20, symbol() = {}, {} decimals\n\
+                        \n⚠️  Found by web search, not the configured token table - anyone can deploy a token \
+                        with this symbol, so confirm the address on a block explorer before sending funds.",
+                        symbol, address, network, rejected.len() + 1, found_symbol, decimals
+                    );
+                    info!("🔍 MCP Server resolve_token_address response: {}", response_text);
+                    return Ok(CallToolResult::success(vec![Content::text(response_text)]));
+                }
+                Err(reason) => {
+                    info!("⚠️  Rejected {} candidate {}: {}", symbol, address, reason);
+                    rejected.push(format!("  - {}: {}", address, reason));
+                }
+            }
+        }
+        
+        let details = if rejected.is_empty() {
+            "The search results contained no contract addresses.".to_string()
+        } else {
+            format!("Candidates rejected:\n{}", rejected.join("\n"))
+        };
+        Err(McpError::invalid_params(
+            format!(
+                "Could not resolve {}: it is not in the token table for chain {} and no {} search result is a deployed token with that symbol.\n{}",
+                symbol, self.chain_id, network, details
+            ),
+            None
+        ))
+    }
+
+    /// Create a new blockchain service instance
+    pub async fn new() -> Result<Self> {
+        // Load configuration from environment
+        Self::with_config(BlockchainConfig::from_env().map_err(|e| eyre::eyre!(e))?).await
+    }
+
+    /// Create a blockchain service backed by an injected provider (e.g. `MockProvider`)
+    ///
+    /// Uses the default local configuration and anvil accounts without querying a node.
+    /// Tools that aren't routed through `ChainProvider` still target the default RPC URL.
+    pub async fn with_provider(chain: impl ChainProvider + 'static) -> Result<Self> {
+        Self::with_provider_and_config(chain, BlockchainConfig::default()).await
+    }
+
+    /// Create a blockchain service backed by an injected provider and an explicit configuration
+    pub async fn with_provider_and_config(chain: impl ChainProvider + 'static, config: BlockchainConfig) -> Result<Self> {
+        let provider = RootProvider::<AnyNetwork>::new_http(config.rpc_url.parse()?);
+        let signers = load_signers(&config).map_err(|e| eyre::eyre!(e))?;
+        Self::from_parts(provider, Arc::new(chain), signers, MAINNET_CHAIN_ID, config).await
+    }
+
+    /// Create a blockchain service from an explicit configuration
+    pub async fn with_config(mut config: BlockchainConfig) -> Result<Self> {
+        // Create provider connection to anvil
+        let provider = ProviderBuilder::<_, _, AnyNetwork>::default()
+            .connect(&config.rpc_url)
+            .await?;
+
+        // Resolve accounts: explicit config first, then the node's eth_accounts, then the anvil defaults
+        if config.accounts.is_empty() {
+            config.accounts = accounts_or_defaults(provider.get_accounts().await);
+        }
+
+        // Pick the router/token address set for the connected chain
+        let chain_id = match provider.get_chain_id().await {
+            Ok(chain_id) => chain_id,
+            Err(e) => {
+                warn!("⚠️  eth_chainId failed ({}), assuming mainnet addresses", e);
+                MAINNET_CHAIN_ID
+            }
+        };
+
+        // Sign locally so sends don't depend on the node's unlocked accounts
+        let signers = load_signers(&config).map_err(|e| eyre::eyre!(e))?;
+        let chain = Arc::new(SigningProvider::new(provider.clone(), signers.values().cloned()));
+        Self::from_parts(provider, chain, signers, chain_id, config).await
+    }
+
+    /// Finish construction once the provider, signers, chain id and account list are settled
+    async fn from_parts(
+        provider: RootProvider<AnyNetwork>,
+        chain: Arc<dyn ChainProvider>,
+        signers: HashMap<Address, PrivateKeySigner>,
+        chain_id: u64,
+        config: BlockchainConfig,
+    ) -> Result<Self> {
+        let (sender, recipient) = (&config.default_sender, &config.default_recipient);
+        let Some(&alice_address) = config.accounts.get(sender.index) else {
+            return Err(eyre::eyre!(
+                "At least {} accounts are required ({} is account {}), got {}. Check the ACCOUNTS setting.",
+                sender.index + 1,
+                sender.name,
+                sender.index,
+                config.accounts.len()
+            ));
+        };
+        // Without a recipient account the service still runs; only the recipient name is unavailable
+        let bob_address = config.accounts.get(recipient.index).copied();
+        if bob_address.is_none() {
+            warn!("⚠️  Only {} account(s) available - {} (account {}) is disabled as the default recipient",
+                config.accounts.len(), recipient.display_name(), recipient.index);
+        }
+        let available_addresses = config.accounts.clone();
+
+        // Refuse to start against the wrong network when a chain id is expected
+        if let Some(expected) = config.expected_chain_id {
+            let actual = chain.get_chain_id().await
+                .map_err(|e| eyre::eyre!("Could not read the chain id to check EXPECTED_CHAIN_ID={}: {}", expected, e))?;
+            verify_chain_id(Some(expected), actual).map_err(|e| eyre::eyre!(e))?;
+            info!("🔒 Chain id {} matches EXPECTED_CHAIN_ID", actual);
+        }
+
+        // Queue RPC calls past the configured limit instead of flooding the node
+        let operation_permits = Arc::new(Semaphore::new(config.max_concurrent_operations.max(1)));
+        let chain: Arc<dyn ChainProvider> = Arc::new(ConcurrencyLimited::new(chain, operation_permits.clone()));
+
+        let addresses = match config.addresses_for_chain(chain_id) {
+            Some(addresses) => addresses.clone(),
+            None => {
+                warn!("⚠️  No router/token addresses configured for chain {} - falling back to mainnet addresses. \
+                       Add an entry to NETWORK_ADDRESSES_FILE for this chain.", chain_id);
+                NetworkAddresses::mainnet()
+            }
+        };
+
+        // Build account info from the resolved account list
+        let anvil_accounts = Self::load_anvil_accounts(&available_addresses).await?;
+        
+        let alice_private_key = config.alice_private_key.clone();
+
+        // Map configured keys onto account addresses so senders can be looked up by address
+        let private_keys: HashMap<Address, String> = signers
+            .into_iter()
+            .map(|(address, signer)| (address, format!("0x{}", hex::encode(signer.to_bytes()))))
+            .collect();
+
+        info!("🔗 Blockchain service configured for anvil network at {} (chain {})", config.rpc_url, chain_id);
+        info!("🦄 Uniswap V2 Router: {} ({} known tokens)", addresses.uniswap_v2_router, addresses.tokens.len());
+        info!("👤 {} (Account {}): {} (default sender per PRD)", sender.display_name(), sender.index, alice_address);
+        if let Some(bob_address) = bob_address {
+            info!("👤 {} (Account {}): {} (default recipient per PRD)", recipient.display_name(), recipient.index, bob_address);
+        }
+        info!("📊 Loaded {} accounts from anvil", anvil_accounts.len());
+        info!("🔑 Private keys available for {} sender(s)", private_keys.len());
+        info!("🚦 At most {} blockchain operation(s) in flight at once", config.max_concurrent_operations.max(1));
+        if !alice_private_key.is_empty() {
+            info!("🔑 Alice's private key loaded for transaction signing");
+        } else {
+            info!("⚠️  Alice's private key not available - transactions disabled");
+        }
+
+        // The address book reserves exactly the configured sender and recipient names
+        let mut address_book = config.address_book.clone();
+        address_book.set_reserved_names([&config.default_sender.name, &config.default_recipient.name])
+            .map_err(|e| eyre::eyre!(e))?;
+
+        Ok(Self {
+            provider,
+            chain,
+            chain_id,
+            addresses,
+            started_at: Instant::now(),
+            alice_address,
+            bob_address,
+            alice_private_key,
+            private_keys,
+            anvil_accounts,
+            address_book: Arc::new(Mutex::new(address_book)),
+            token_metadata_cache: Arc::new(Mutex::new(HashMap::new())),
+            session_spent_wei: Arc::new(Mutex::new(U256::ZERO)),
+            operation_permits,
+            tool_router: Self::tool_router(),
+            config,
+        })
+    }
+
+    /// Load anvil accounts dynamically - addresses only from eth_accounts RPC
+    async fn load_anvil_accounts(addresses: &[Address]) -> Result<Vec<AccountInfo>> {
         let mut accounts = Vec::new();
         
-        for (index, &address) in addresses.iter().enumerate() {
-            let address_str = format!("{:?}", address);
-            
-            // No private keys available via RPC - this is by design for security
-            accounts.push(AccountInfo {
-                index: index as u32,
-                address: address_str,
-                private_key: None, // Private keys not exposed via RPC
-            });
+        for (index, &address) in addresses.iter().enumerate() {
+            let address_str = format!("{:?}", address);
+            
+            // No private keys available via RPC - this is by design for security
+            accounts.push(AccountInfo {
+                index: index as u32,
+                address: address_str,
+                private_key: None, // Private keys not exposed via RPC
+            });
+        }
+
+        Ok(accounts)
+    }
+
+    /// Get the balance of an account in wei - Following PRD Example Pattern
+    #[tool(description = "Get the balance of an account in wei, optionally at a past block")]
+    pub async fn balance(
+        &self,
+        Parameters(BalanceRequest { who, block }): Parameters<BalanceRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        let who = who.trim();
+        if who.is_empty() {
+            return Err(McpError::invalid_params(
+                "No account given: provide an address, ENS name or account name (e.g. 'alice')".to_string(),
+                None
+            ));
+        }
+        let block = self.resolve_query_block(block.as_deref()).await?;
+        
+        // Same resolution as transfers: addresses, ENS names, alice/bob/accountN and address book names
+        let validated_address = self.validate_recipient_address(who).await?;
+        let address = validated_address.resolved_address;
+        let balance = self.retry_read(|| self.chain.get_balance_at(address, block.into())).await
+            .map_err(|e| McpError::internal_error(format!("Failed to get balance: {}", e), None))?;
+
+        // Convert wei to ETH for better readability
+        let balance_eth = balance.to_f64().unwrap_or(0.0) / 1e18;
+        
+        let response_text = format!(
+            "ETH Balance Query:\n\
+            Account: {} (resolved to {})\n\
+            Address Type: {}\n\
+            Block: {}\n\
+            Balance: {:.6} ETH ({} wei)",
+            who, address, validated_address.address_type, block_label(block), balance_eth, balance
+        );
+        let result = BalanceResult {
+            account: who.to_string(),
+            address: address.to_string(),
+            address_type: validated_address.address_type,
+            block: block_label(block),
+            asset: "ETH".to_string(),
+            token_address: None,
+            balance: format_token_amount(balance, 18, "ETH"),
+            balance_raw: balance.to_string(),
+            decimals: 18,
+        };
+
+        structured_result(&result, response_text)
+    }
+
+    /// Send ETH from Alice (or another configured sender) to another address
+    #[tool(description = "Send ETH from Alice (or an optional 'from' account) to another address; amount may be 'all'/'max' or a percentage like '50%' - NOTE: Requires private key access")]
+    pub async fn send_eth(
+        &self,
+        Parameters(TransferRequest { to, amount, from, dry_run, confirmation_timeout }): Parameters<TransferRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("🚀 MCP Server: send_eth called with to={}, amount={}, from={:?}, dry_run={:?}", to, amount, from, dry_run);
+        let dry_run = dry_run.unwrap_or(false);
+        // Step 1: Validate recipient address (PRD requirement)
+        let validated_recipient = self.validate_recipient_address(&to).await?;
+        
+        // Step 2: Resolve the sender - Alice unless explicitly specified
+        let (sender_address, sender_label) = match from.as_deref() {
+            Some(from) => {
+                let validated_sender = self.validate_recipient_address(from).await?;
+                (validated_sender.resolved_address, validated_sender.address_type)
+            }
+            None => (self.alice_address, self.config.default_sender.display_name()),
+        };
+        
+        // Check if we have Alice's private key available from environment (simulations don't sign)
+        if !dry_run && sender_address == self.alice_address && self.alice_private_key.is_empty() {
+            return Ok(CallToolResult::success(vec![Content::text(
+                format!(
+                    "ERROR: Cannot send transaction - private key not available.\n\n\
+                    Alice's address: {}\n\
+                    Validated recipient: {} ({})\n\
+                    Requested transfer: {} ETH\n\n\
+                    SOLUTION: Set the private key in your environment:\n\
+                    export ALICE_PRIVATE_KEY=\"0x...\"\n\
+                    or\n\
+                    export PRIVATE_KEY=\"0x...\"\n\n\
+                    The private key should correspond to Alice's address ({}).\n\
+                    Accounts are loaded dynamically from anvil, but private keys must be\n\
+                    provided via environment variables for security.",
+                    self.alice_address, validated_recipient.address, validated_recipient.address_type, amount, self.alice_address
+                )
+            )]))
+        }
+
+        // Other senders need their own key configured
+        if !dry_run && !self.private_keys.contains_key(&sender_address) {
+            return Err(McpError::invalid_params(
+                format!(
+                    "No private key available for sender {} ({}).\n\n\
+                    SOLUTION: Provide the key for this account in your environment:\n\
+                    export PRIVATE_KEY_<account index>=\"0x...\"\n\
+                    or\n\
+                    export ANVIL_PRIVATE_KEYS=\"0x...,0x...\" (comma-separated, in anvil account order)",
+                    sender_address, sender_label
+                ),
+                None
+            ));
+        }
+
+        let to_address = validated_recipient.resolved_address;
+        
+        // Parse the amount - a literal, "all"/"max" or a percentage of the balance
+        let expression = parse_amount_expression(&amount)?;
+        
+        // Create transaction request
+        let tx = TransactionRequest::default()
+            .to(to_address)
+            .value(match expression {
+                AmountExpression::Exact(wei) => wei,
+                _ => U256::ZERO,
+            })
+            .from(sender_address);
+        
+        let mut tx = WithOtherFields::new(tx);
+        let fee_summary = self.apply_fee_mode(&mut tx).await?;
+        
+        // Relative amounts are worked out from the balance, less the gas the transfer will cost
+        let (amount_wei, amount_text, reserved_gas) = match expression {
+            AmountExpression::Exact(wei) => (wei, format!("{} ETH", amount), None),
+            relative => {
+                let balance = self.chain.get_balance(sender_address).await
+                    .map_err(|e| McpError::internal_error(format!("Failed to get balance: {}", e), None))?;
+                let gas_estimate = self.estimate_transaction_cost(&tx).await?;
+                let amount_wei = relative.resolve(balance, gas_estimate.total_cost)?;
+                
+                // Pin the gas so the node can't charge more than was held back
+                tx.value = Some(amount_wei);
+                tx.gas = Some(gas_estimate.gas_limit.saturating_to::<u64>());
+                if tx.max_fee_per_gas.is_none() {
+                    tx.gas_price = Some(gas_estimate.gas_price.saturating_to::<u128>());
+                }
+                
+                let amount_text = format!(
+                    "{} ({} of the {:.6} ETH balance, {:.6} ETH reserved for gas)",
+                    format_token_amount(amount_wei, 18, "ETH"),
+                    relative.describe(),
+                    balance.to_f64().unwrap_or(0.0) / 1e18,
+                    gas_estimate.total_cost.to_f64().unwrap_or(0.0) / 1e18
+                );
+                info!("🧮 Resolved amount '{}' to {} wei", amount, amount_wei);
+                (amount_wei, amount_text, Some(gas_estimate))
+            }
+        };
+        self.check_spend_limits(amount_wei)?;
+        let mut result = TransferResult {
+            status: TxStatus::Simulated,
+            from: sender_address.to_string(),
+            from_label: sender_label.clone(),
+            to: validated_recipient.address.clone(),
+            to_address: to_address.to_string(),
+            asset: "ETH".to_string(),
+            token_address: None,
+            amount: format_token_amount(amount_wei, 18, "ETH"),
+            amount_raw: amount_wei.to_string(),
+            tx_hash: None,
+            confirmation: None,
+        };
+        
+        if dry_run {
+            let simulation = self.simulate_transaction(&tx).await?;
+            let response_text = format!(
+                "ETH Transfer Simulation (dry run):\n\
+                From: {} ({})\n\
+                To: {} ({})\n\
+                Amount: {}\n\
+                {}\n\
+                {}",
+                sender_address,
+                sender_label,
+                validated_recipient.address,
+                validated_recipient.address_type,
+                amount_text,
+                fee_summary,
+                simulation
+            );
+            
+            info!("🔍 MCP Server send_eth dry run response: {}", response_text);
+            return structured_result(&result, response_text);
+        }
+        
+        // Estimate gas and make sure the sender can cover value + gas before broadcasting
+        let gas_estimate = match reserved_gas {
+            Some(gas_estimate) => gas_estimate,
+            None => self.estimate_transaction_cost(&tx).await?,
+        };
+        self.ensure_sufficient_balance(sender_address, amount_wei, &gas_estimate).await?;
+        info!("⛽ Gas estimate: {} gas at {} wei ({} wei total)", 
+              gas_estimate.gas_limit, gas_estimate.gas_price, gas_estimate.total_cost);
+        
+        self.ensure_expected_chain().await?;
+        
+        // Broadcast the transaction, counting its value against the spend limits
+        self.reserve_spend(amount_wei)?;
+        let tx_hash = self.chain.send_transaction(tx).await
+            .map_err(|e| {
+                self.release_spend(amount_wei);
+                McpError::internal_error(format!("Failed to send transaction: {}", e), None)
+            })?;
+        
+        info!("📝 Transaction sent with hash: {}", tx_hash);
+        result.tx_hash = Some(tx_hash.to_string());
+        
+        // Wait for transaction confirmation (configured timeout unless overridden per request)
+        match self.wait_for_transaction_confirmation(tx_hash, self.confirmation_timeout(confirmation_timeout)).await {
+            Ok(confirmation_text) => {
+                let response_text = format!(
+                    "ETH Transfer:\n\
+                    From: {} ({})\n\
+                    To: {} ({})\n\
+                    Amount: {}\n\
+                    {}\n\
+                    {}\n\
+                    \n{}",
+                    sender_address,
+                    sender_label,
+                    validated_recipient.address,
+                    validated_recipient.address_type,
+                    amount_text,
+                    gas_estimate.summary(),
+                    fee_summary,
+                    confirmation_text
+                );
+                
+                info!("🔍 MCP Server send_eth response: {}", response_text);
+                result.status = confirmation_text.status();
+                result.confirmation = Some(confirmation_text);
+                structured_result(&result, response_text)
+            }
+            Err(_e) => {
+                // If waiting fails, return the transaction hash for manual checking
+                let response_text = format!(
+                    "ETH Transfer Sent:\n\
+                    From: {} ({})\n\
+                    To: {} ({})\n\
+                    Amount: {}\n\
+                    {}\n\
+                    {}\n\
+                    Transaction Hash: {}\n\
+                    Status: Sent to network (confirmation timeout)\n\
+                    \n⚠️  Transaction was sent but confirmation timed out.\n\
+                    Use check_transaction_status with hash {} to check the final status.",
+                    sender_address,
+                    sender_label,
+                    validated_recipient.address,
+                    validated_recipient.address_type,
+                    amount_text,
+                    gas_estimate.summary(),
+                    fee_summary,
+                    tx_hash,
+                    tx_hash
+                );
+                
+                info!("⚠️  MCP Server send_eth response (timeout): {}", response_text);
+                result.status = TxStatus::Pending;
+                structured_result(&result, response_text)
+            }
+        }
+    }
+
+    /// Estimate the cost of sending ETH from Alice without broadcasting anything
+    #[tool(description = "Estimate the gas and total cost of sending ETH from Alice to a recipient")]
+    pub async fn estimate_transfer_cost(
+        &self,
+        Parameters(TransferCostRequest { to, amount }): Parameters<TransferCostRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("⛽ MCP Server: estimate_transfer_cost called with to={}, amount={}", to, amount);
+        let validated_recipient = self.validate_recipient_address(&to).await?;
+        let amount_wei = self.parse_amount_to_wei(&amount, "ETH").await?;
+
+        let tx = TransactionRequest::default()
+            .to(validated_recipient.resolved_address)
+            .value(amount_wei)
+            .from(self.alice_address);
+        let tx = WithOtherFields::new(tx);
+
+        let gas_estimate = self.estimate_transaction_cost(&tx).await?;
+        let balance = self.chain.get_balance(self.alice_address).await
+            .map_err(|e| McpError::internal_error(format!("Failed to get balance: {}", e), None))?;
+        let total_required = amount_wei + gas_estimate.total_cost;
+
+        let response_text = format!(
+            "ETH Transfer Cost Estimate:\n\
+            From: {} (Alice)\n\
+            To: {} ({})\n\
+            Amount: {} ETH ({} wei)\n\
+            {}\n\
+            Total Required: {} wei ({:.6} ETH)\n\
+            Sender Balance: {:.6} ETH\n\
+            Sufficient Funds: {}",
+            self.alice_address,
+            validated_recipient.address,
+            validated_recipient.address_type,
+            amount, amount_wei,
+            gas_estimate.summary(),
+            total_required,
+            total_required.to_f64().unwrap_or(0.0) / 1e18,
+            balance.to_f64().unwrap_or(0.0) / 1e18,
+            if balance >= total_required { "YES" } else { "NO" }
+        );
+
+        info!("🔍 MCP Server estimate_transfer_cost response: {}", response_text);
+        Ok(CallToolResult::success(vec![Content::text(response_text)]))
+    }
+
+    /// Resolve the optional `block` parameter of a read tool, rejecting blocks past the chain head
+    async fn resolve_query_block(&self, block: Option<&str>) -> Result<BlockNumberOrTag, McpError> {
+        let Some(block) = block else {
+            return Ok(BlockNumberOrTag::Latest);
+        };
+        let block = parse_block_id(block)?;
+        if let BlockNumberOrTag::Number(number) = block {
+            let latest = self.retry_read(|| self.chain.get_block_number()).await
+                .map_err(|e| McpError::internal_error(format!("Failed to get block number: {}", e), None))?;
+            if number > latest {
+                return Err(McpError::invalid_params(
+                    format!("Block {} is in the future: the latest block is {}", number, latest),
+                    None
+                ));
+            }
+        }
+        Ok(block)
+    }
+
+    /// Timestamp of the latest block, the clock the router checks swap deadlines against
+    ///
+    /// A fork's block time can drift from the host clock (evm_increaseTime,
+    /// evm_setNextBlockTimestamp, an old fork block), so deadlines never use `SystemTime`.
+    async fn chain_time(&self) -> Result<u64, McpError> {
+        self.retry_read(|| self.chain.get_block_timestamp()).await
+            .map_err(|e| McpError::internal_error(format!("Failed to get latest block timestamp: {}", e), None))
+    }
+
+    /// Run a read-only chain call with the configured retry policy for transient failures
+    async fn retry_read<T, F, Fut>(&self, f: F) -> alloy_transport::TransportResult<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: std::future::Future<Output = alloy_transport::TransportResult<T>>,
+    {
+        retry_rpc(
+            self.config.rpc_retry_attempts,
+            Duration::from_millis(self.config.rpc_retry_base_delay_ms),
+            f,
+        ).await
+    }
+
+    /// Wait for a free slot before an RPC call made through `provider` rather than `chain`
+    async fn operation_permit(&self) -> SemaphorePermit<'_> {
+        // The semaphore is never closed
+        self.operation_permits.acquire().await.expect("operation semaphore closed")
+    }
+
+    /// Await an RPC call made through `provider`, holding a slot only while it runs
+    async fn limited<F: IntoFuture>(&self, call: F) -> F::Output {
+        let _permit = self.operation_permit().await;
+        call.await
+    }
+
+    /// Poll for a transaction's receipt until it is mined or `timeout` passes
+    ///
+    /// Each check goes through `chain` and takes its own slot, so a long wait
+    /// never holds one.
+    async fn wait_for_receipt(&self, tx_hash: TxHash, timeout: Duration) -> Result<AnyTransactionReceipt, String> {
+        let started = Instant::now();
+        loop {
+            match self.chain.get_transaction_receipt(tx_hash).await {
+                Ok(Some(receipt)) => return Ok(receipt),
+                Ok(None) => {}
+                Err(e) => debug!("Receipt lookup for {} failed, retrying: {}", tx_hash, e),
+            }
+            let elapsed = started.elapsed();
+            if elapsed >= timeout {
+                return Err(format!("no receipt for {} after {}s", tx_hash, timeout.as_secs()));
+            }
+            sleep(RECEIPT_POLL_INTERVAL.min(timeout - elapsed)).await;
+        }
+    }
+
+    /// Estimate the gas limit for a transaction
+    async fn estimate_gas(&self, tx: &WithOtherFields<TransactionRequest>) -> Result<U256, McpError> {
+        let _permit = self.operation_permit().await;
+        let gas = self.provider.estimate_gas(tx.clone()).await
+            .map_err(|e| McpError::internal_error(format!("Failed to estimate gas: {}", e), None))?;
+        Ok(U256::from(gas))
+    }
+
+    /// Estimate gas and projected cost (gas * current gas price) for a transaction
+    async fn estimate_transaction_cost(&self, tx: &WithOtherFields<TransactionRequest>) -> Result<GasCostEstimate, McpError> {
+        let gas_limit = self.estimate_gas(tx).await?;
+        self.transaction_cost(tx, gas_limit).await
+    }
+
+    /// Projected cost of `tx` at a known gas limit
+    async fn transaction_cost(&self, tx: &WithOtherFields<TransactionRequest>, gas_limit: U256) -> Result<GasCostEstimate, McpError> {
+        // EIP-1559 transactions are budgeted at their max fee; legacy ones at the node's gas price
+        let gas_price = match tx.max_fee_per_gas {
+            Some(max_fee) => max_fee,
+            None => self.retry_read(|| self.chain.get_gas_price()).await
+                .map_err(|e| McpError::internal_error(format!("Failed to get gas price: {}", e), None))?,
+        };
+        let gas_price = U256::from(gas_price);
+
+        Ok(GasCostEstimate {
+            gas_limit,
+            gas_price,
+            total_cost: gas_limit * gas_price,
+        })
+    }
+
+    /// Reject transactions the sender cannot afford (value + estimated gas)
+    async fn ensure_sufficient_balance(&self, sender: Address, value: U256, gas_estimate: &GasCostEstimate) -> Result<(), McpError> {
+        let balance = self.chain.get_balance(sender).await
+            .map_err(|e| McpError::internal_error(format!("Failed to get balance: {}", e), None))?;
+        let total_required = value + gas_estimate.total_cost;
+
+        if balance < total_required {
+            return Err(McpError::invalid_params(
+                format!(
+                    "Insufficient balance: {} has {:.6} ETH but this transaction needs {:.6} ETH \
+                    ({:.6} ETH value + {:.6} ETH estimated gas)",
+                    sender,
+                    balance.to_f64().unwrap_or(0.0) / 1e18,
+                    total_required.to_f64().unwrap_or(0.0) / 1e18,
+                    value.to_f64().unwrap_or(0.0) / 1e18,
+                    gas_estimate.total_cost.to_f64().unwrap_or(0.0) / 1e18
+                ),
+                None
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Re-check the node's chain id against `EXPECTED_CHAIN_ID` right before broadcasting
+    ///
+    /// The node behind `RPC_URL` can change while the server runs (e.g. anvil
+    /// restarted without a fork), so this runs on every send, not only at startup.
+    async fn ensure_expected_chain(&self) -> Result<(), McpError> {
+        let Some(expected) = self.config.expected_chain_id else {
+            return Ok(());
+        };
+        let actual = self.retry_read(|| self.chain.get_chain_id()).await
+            .map_err(|e| McpError::internal_error(
+                format!("Could not verify the chain id (EXPECTED_CHAIN_ID={}), so the transaction was not sent: {}", expected, e),
+                None
+            ))?;
+        verify_chain_id(Some(expected), actual).map_err(|e| {
+            error!("{}", e);
+            McpError::invalid_params(e, None)
+        })
+    }
+
+    /// Reject an ETH spend above `MAX_TX_ETH` or one that would push the session past `MAX_SESSION_ETH`
+    fn check_spend_limits(&self, value: U256) -> Result<(), McpError> {
+        let spent = *self.session_spent_wei.lock()
+            .map_err(|e| McpError::internal_error(format!("Spend counter lock error: {}", e), None))?;
+        self.check_spend_limits_against(value, spent)
+    }
+
+    /// Check the spend limits given the ETH `spent` so far this session
+    fn check_spend_limits_against(&self, value: U256, spent: U256) -> Result<(), McpError> {
+        let eth = |wei: U256| format_token_amount(wei, 18, "ETH");
+        
+        if let Some(max_tx_eth) = self.config.max_tx_eth {
+            let max_tx = eth_limit_to_wei(max_tx_eth);
+            if value > max_tx {
+                return Err(McpError::invalid_params(
+                    format!(
+                        "Spending limit exceeded: this transaction spends {} but the per-transaction limit is {} (MAX_TX_ETH). \
+                        Split it into smaller transactions or raise MAX_TX_ETH in the MCP server environment.",
+                        eth(value), eth(max_tx)
+                    ),
+                    None
+                ));
+            }
+        }
+        
+        if let Some(max_session_eth) = self.config.max_session_eth {
+            let max_session = eth_limit_to_wei(max_session_eth);
+            if spent.saturating_add(value) > max_session {
+                return Err(McpError::invalid_params(
+                    format!(
+                        "Spending limit exceeded: this transaction spends {} but only {} of the {} session limit \
+                        remains ({} already spent, MAX_SESSION_ETH). Restart the MCP server or raise MAX_SESSION_ETH to spend more.",
+                        eth(value), eth(max_session.saturating_sub(spent)), eth(max_session), eth(spent)
+                    ),
+                    None
+                ));
+            }
+        }
+        
+        Ok(())
+    }
+
+    /// Check the spend limits and count `value` as spent, atomically so concurrent sends can't overshoot
+    fn reserve_spend(&self, value: U256) -> Result<(), McpError> {
+        let mut spent = self.session_spent_wei.lock()
+            .map_err(|e| McpError::internal_error(format!("Spend counter lock error: {}", e), None))?;
+        self.check_spend_limits_against(value, *spent)?;
+        *spent = spent.saturating_add(value);
+        Ok(())
+    }
+
+    /// Give back a reservation for a transaction that failed to broadcast
+    fn release_spend(&self, value: U256) {
+        if let Ok(mut spent) = self.session_spent_wei.lock() {
+            *spent = spent.saturating_sub(value);
+        }
+    }
+
+    /// Set fee fields on a transaction according to the configured fee mode
+    ///
+    /// Returns a summary of the fee parameters for inclusion in tool responses.
+    async fn apply_fee_mode(&self, tx: &mut WithOtherFields<TransactionRequest>) -> Result<String, McpError> {
+        match self.config.fee_mode {
+            FeeMode::Legacy => Ok("Fee Mode: Legacy (gas price set by node)".to_string()),
+            FeeMode::Eip1559 => {
+                let permit = self.operation_permit().await;
+                let fees = self.provider.estimate_eip1559_fees().await
+                    .map_err(|e| McpError::internal_error(format!("Failed to estimate EIP-1559 fees: {}", e), None))?;
+                drop(permit);
+                tx.max_fee_per_gas = Some(fees.max_fee_per_gas);
+                tx.max_priority_fee_per_gas = Some(fees.max_priority_fee_per_gas);
+                info!("⛽ EIP-1559 fees: max_fee={} wei, priority_fee={} wei", 
+                      fees.max_fee_per_gas, fees.max_priority_fee_per_gas);
+
+                Ok(format!(
+                    "Fee Mode: EIP-1559\n\
+                    Max Fee Per Gas: {}\n\
+                    Max Priority Fee Per Gas: {}",
+                    format_gas_price(fees.max_fee_per_gas),
+                    format_gas_price(fees.max_priority_fee_per_gas)
+                ))
+            }
+        }
+    }
+
+    /// Simulate a transaction with eth_call, reporting whether it would revert
+    ///
+    /// Nothing is signed or broadcast, so no gas or nonce is spent. Reverts are
+    /// reported in the returned summary; only RPC failures are returned as errors.
+    async fn simulate_transaction(&self, tx: &WithOtherFields<TransactionRequest>) -> Result<String, McpError> {
+        match self.retry_read(|| self.chain.call(tx.clone())).await {
+            Ok(output) => {
+                info!("✅ Simulation succeeded, return data length: {}", output.len());
+                Ok(format!(
+                    "Simulation: WOULD SUCCEED\n\
+                    Return Data: {}\n\
+                    \nℹ️  Dry run only - no transaction was broadcast, no gas or nonce was spent.",
+                    output
+                ))
+            }
+            Err(e) if is_transient_rpc_error(&e) => Err(McpError::internal_error(
+                format!("Failed to simulate transaction: {}", e),
+                None
+            )),
+            Err(e) => {
+                let reason = revert_reason_from_error(&e);
+                info!("⚠️  Simulation reverted: {}", reason);
+                Ok(format!(
+                    "Simulation: WOULD REVERT\n\
+                    Reason: {}\n\
+                    \nℹ️  Dry run only - no transaction was broadcast, no gas or nonce was spent.",
+                    reason
+                ))
+            }
+        }
+    }
+
+    /// Check if a contract is deployed by reading the code at the address
+    #[tool(description = "Check if a contract is deployed at the specified address and report its code size and keccak256 code hash")]
+    pub async fn is_contract_deployed(
+        &self,
+        Parameters(ContractDeploymentRequest { address, expected_code_hash }): Parameters<ContractDeploymentRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        // Validate the contract address
+        let validated_address = self.validate_recipient_address(&address).await?;
+        let addr = validated_address.resolved_address;
+
+        // Parse the expected hash up front so a typo is reported before any RPC call
+        let expected_hash = expected_code_hash
+            .as_deref()
+            .map(|hash| B256::from_str(hash.trim()).map_err(|e| {
+                McpError::invalid_params(format!("Invalid expected_code_hash '{}': {}", hash, e), None)
+            }))
+            .transpose()?;
+        
+        // Check if there's code at the address
+        let code = self.retry_read(|| self.chain.get_code_at(addr)).await
+            .map_err(|e| McpError::internal_error(format!("Failed to get contract code: {}", e), None))?;
+        
+        // Contract is deployed if the code is non-empty
+        let is_deployed = !code.is_empty();
+        let code_hash = keccak256(&code);
+        
+        let mut response_text = format!(
+            "Contract Deployment Check:\n\
+            Input: {} ({})\n\
+            Resolved Address: {}\n\
+            Status: {}\n\
+            Code Length: {} bytes\n\
+            Code Hash: {}\n\
+            Exceeds EIP-170 Limit: {} (max {} bytes)",
+            validated_address.address,
+            validated_address.address_type,
+            validated_address.resolved_address,
+            if is_deployed { "DEPLOYED" } else { "NOT DEPLOYED" },
+            code.len(),
+            code_hash,
+            if code.len() > EIP170_MAX_CODE_SIZE { "YES" } else { "NO" },
+            EIP170_MAX_CODE_SIZE
+        );
+        
+        if let Some(expected) = expected_hash {
+            let verdict = if expected == code_hash { "MATCH" } else { "MISMATCH" };
+            info!("🔍 Code hash check for {}: {}", addr, verdict);
+            response_text.push_str(&format!("\nCode Hash Match: {} (expected {})", verdict, expected));
+        }
+        
+        Ok(CallToolResult::success(vec![Content::text(response_text)]))
+    }
+
+    /// Deploy a contract from Alice with a contract-creation transaction
+    #[tool(description = "Deploy a contract from Alice given its creation bytecode (hex) and optional constructor signature and args; returns the new contract address - NOTE: Requires private key access")]
+    pub async fn deploy_contract(
+        &self,
+        Parameters(DeployContractRequest { bytecode, constructor, args, confirmation_timeout }): Parameters<DeployContractRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        let args = args.unwrap_or_default();
+        info!("🚀 MCP Server: deploy_contract called with {} hex chars of bytecode, constructor={:?}, args={:?}",
+              bytecode.len(), constructor, args);
+        
+        let data = deployment_data(&bytecode, constructor.as_deref(), &args)?;
+        
+        if self.alice_private_key.is_empty() {
+            return Err(McpError::invalid_params(
+                format!(
+                    "Cannot deploy contract - private key not available for Alice ({}).\n\
+                    SOLUTION: export ALICE_PRIVATE_KEY=\"0x...\" or export PRIVATE_KEY=\"0x...\"",
+                    self.alice_address
+                ),
+                None
+            ));
+        }
+        
+        // No `to`: the node creates a contract from the input data
+        let tx = TransactionRequest::default()
+            .from(self.alice_address)
+            .input(data.clone().into())
+            .into_create();
+        let mut tx = WithOtherFields::new(tx);
+        let fee_summary = self.apply_fee_mode(&mut tx).await?;
+        
+        self.ensure_expected_chain().await?;
+        let tx_hash = self.chain.send_transaction(tx).await
+            .map_err(|e| McpError::internal_error(format!("Failed to send deployment transaction: {}", e), None))?;
+        
+        info!("📝 Deployment transaction sent with hash: {}", tx_hash);
+        
+        let deploy_summary = format!(
+            "From: {} (Alice)\n\
+            Init Code: {} bytes{}\n\
+            {}",
+            self.alice_address,
+            data.len(),
+            constructor.as_deref().map(|constructor| format!(" ({} with {} argument(s))", constructor.trim(), args.len())).unwrap_or_default(),
+            fee_summary
+        );
+        
+        let response_text = match self.wait_for_transaction_confirmation(tx_hash, self.confirmation_timeout(confirmation_timeout)).await {
+            Ok(confirmation) => {
+                let contract_line = match (&confirmation.contract_address, confirmation.succeeded) {
+                    (Some(address), true) => format!(
+                        "Contract Address: {}\n💡 Verify it with is_contract_deployed using address {}",
+                        address, address
+                    ),
+                    _ => "Contract Address: None (deployment failed)".to_string(),
+                };
+                format!("Contract Deployment:\n{}\n{}\n\n{}", deploy_summary, contract_line, confirmation)
+            }
+            Err(_e) => format!(
+                "Contract Deployment Sent:\n{}\n\
+                Transaction Hash: {}\n\
+                Status: Sent to network (confirmation timeout)\n\
+                \n⚠️  Transaction was sent but confirmation timed out.\n\
+                Use check_transaction_status with hash {} to find the contract address once it is mined.",
+                deploy_summary, tx_hash, tx_hash
+            ),
+        };
+        
+        info!("🔍 MCP Server deploy_contract response: {}", response_text);
+        Ok(CallToolResult::success(vec![Content::text(response_text)]))
+    }
+
+    /// Call any view function with eth_call and decode the result from the signature's return types
+    #[tool(description = "Call any read-only (view) function on a contract and decode the result, e.g. signature 'totalSupply()(uint256)' or 'balanceOf(address)(uint256)' with args; use for contracts the other tools don't cover")]
+    pub async fn call_contract(
+        &self,
+        Parameters(ContractCallRequest { address, signature, args, block }): Parameters<ContractCallRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        let args = args.unwrap_or_default();
+        info!("📞 MCP Server: call_contract called with address={}, signature={}, args={:?}, block={:?}",
+              address, signature, args, block);
+        
+        let (function, output_types) = split_function_signature(&signature)?;
+        let validated_address = self.validate_recipient_address(&address).await?;
+        let contract = validated_address.resolved_address;
+        
+        let calldata = SimpleCast::calldata_encode(&function, &args)
+            .map_err(|e| McpError::invalid_params(format!("Failed to encode {} with args {:?}: {}", function, args, e), None))?;
+        let call_request = WithOtherFields::new(TransactionRequest::default()
+            .to(contract)
+            .input(Bytes::from_str(&calldata)
+                .map_err(|e| McpError::internal_error(format!("Failed to parse calldata: {}", e), None))?.into()));
+        let block = self.resolve_query_block(block.as_deref()).await?;
+        
+        let result = self.retry_read(|| self.chain.call_at(call_request.clone(), block.into())).await
+            .map_err(|e| if is_execution_revert(&e) {
+                McpError::invalid_params(
+                    format!("{} on {} reverted: {}", function, contract, revert_reason_from_error(&e)),
+                    None
+                )
+            } else {
+                McpError::internal_error(format!("Failed to call {} on {}: {}", function, contract, e), None)
+            })?;
+        
+        let mut response_text = format!(
+            "Contract Call:\n\
+            Contract: {} ({})\n\
+            Resolved Address: {}\n\
+            Function: {}\n\
+            Arguments: {}\n\
+            Block: {}",
+            validated_address.address,
+            validated_address.address_type,
+            contract,
+            function,
+            if args.is_empty() { "none".to_string() } else { args.join(", ") },
+            block_label(block)
+        );
+        
+        match output_types {
+            Some(output_types) => {
+                if result.is_empty() && output_types != "()" {
+                    return Err(McpError::invalid_params(
+                        format!("{} on {} returned no data - is it a contract that implements this function?", function, contract),
+                        None
+                    ));
+                }
+                let values = decode_return_values(&output_types, &result)?;
+                response_text.push_str(&format!("\nReturns {}:", output_types));
+                for value in values {
+                    response_text.push_str(&format!("\n  - {}", value));
+                }
+            }
+            None => response_text.push_str(&format!(
+                "\nRaw Result: 0x{}\n💡 Add return types to the signature (e.g. '{}(uint256)') to decode it",
+                hex::encode(&result),
+                function
+            )),
+        }
+        
+        info!("🔍 MCP Server call_contract response: {}", response_text);
+        Ok(CallToolResult::success(vec![Content::text(response_text)]))
+    }
+
+    /// Send a transaction calling any contract function from Alice, optionally with ETH attached
+    #[tool(description = "Call a state-changing function on any contract from Alice (e.g., mint() on a test token) with optional ETH value and gas limit; reverts are caught before sending - NOTE: Requires private key access")]
+    pub async fn send_contract_call(
+        &self,
+        Parameters(ContractSendRequest { address, signature, args, value, gas_limit, dry_run, confirmation_timeout }): Parameters<ContractSendRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        let args = args.unwrap_or_default();
+        let dry_run = dry_run.unwrap_or(false);
+        info!("🚀 MCP Server: send_contract_call called with address={}, signature={}, args={:?}, value={:?}, gas_limit={:?}, dry_run={}",
+              address, signature, args, value, gas_limit, dry_run);
+        
+        let (function, _) = split_function_signature(&signature)?;
+        let validated_address = self.validate_recipient_address(&address).await?;
+        let contract = validated_address.resolved_address;
+        let value_wei = match value.as_deref() {
+            Some(value) => parse_token_amount(value, 18, "ETH")?,
+            None => U256::ZERO,
+        };
+        
+        let calldata = SimpleCast::calldata_encode(&function, &args)
+            .map_err(|e| McpError::invalid_params(format!("Failed to encode {} with args {:?}: {}", function, args, e), None))?;
+        
+        if !dry_run && self.alice_private_key.is_empty() {
+            return Err(McpError::invalid_params(
+                format!(
+                    "Cannot send contract call - private key not available for Alice ({}).\n\
+                    SOLUTION: export ALICE_PRIVATE_KEY=\"0x...\" or export PRIVATE_KEY=\"0x...\"",
+                    self.alice_address
+                ),
+                None
+            ));
+        }
+        self.check_spend_limits(value_wei)?;
+        
+        let tx = TransactionRequest::default()
+            .to(contract)
+            .value(value_wei)
+            .input(Bytes::from_str(&calldata)
+                .map_err(|e| McpError::internal_error(format!("Failed to parse calldata: {}", e), None))?.into())
+            .from(self.alice_address);
+        let mut tx = WithOtherFields::new(tx);
+        let fee_summary = self.apply_fee_mode(&mut tx).await?;
+        
+        let call_summary = format!(
+            "From: {} (Alice)\n\
+            Contract: {} ({})\n\
+            Resolved Address: {}\n\
+            Function: {}\n\
+            Arguments: {}\n\
+            Value: {}",
+            self.alice_address,
+            validated_address.address,
+            validated_address.address_type,
+            contract,
+            function,
+            if args.is_empty() { "none".to_string() } else { args.join(", ") },
+            format_token_amount(value_wei, 18, "ETH")
+        );
+        
+        if dry_run {
+            let simulation = self.simulate_transaction(&tx).await?;
+            let response_text = format!(
+                "Contract Call Simulation (dry run):\n{}\n{}\n{}",
+                call_summary, fee_summary, simulation
+            );
+            info!("🔍 MCP Server send_contract_call dry run response: {}", response_text);
+            return Ok(CallToolResult::success(vec![Content::text(response_text)]));
+        }
+        
+        // Refuse calls that would revert, with the decoded reason, rather than paying gas to find out
+        if let Err(e) = self.retry_read(|| self.chain.call(tx.clone())).await {
+            if is_transient_rpc_error(&e) {
+                return Err(McpError::internal_error(format!("Failed to simulate {} on {}: {}", function, contract, e), None));
+            }
+            return Err(McpError::invalid_params(
+                format!("{} on {} would revert, so it was not sent: {}", function, contract, revert_reason_from_error(&e)),
+                None
+            ));
+        }
+        
+        // An explicit gas limit skips estimation; either way make sure Alice can cover value + gas
+        let gas_line = match gas_limit {
+            Some(gas_limit) => {
+                tx.gas = Some(gas_limit);
+                let gas_cost = self.transaction_cost(&tx, U256::from(gas_limit)).await?;
+                self.ensure_sufficient_balance(self.alice_address, value_wei, &gas_cost).await?;
+                format!("Gas Limit: {} (requested)", gas_limit)
+            }
+            None => {
+                let gas_estimate = self.estimate_transaction_cost(&tx).await?;
+                self.ensure_sufficient_balance(self.alice_address, value_wei, &gas_estimate).await?;
+                info!("⛽ Gas estimate: {} gas at {} wei ({} wei total)",
+                      gas_estimate.gas_limit, gas_estimate.gas_price, gas_estimate.total_cost);
+                gas_estimate.summary()
+            }
+        };
+        
+        self.ensure_expected_chain().await?;
+        
+        // Broadcast the transaction, counting its value against the spend limits
+        self.reserve_spend(value_wei)?;
+        let tx_hash = self.chain.send_transaction(tx).await
+            .map_err(|e| {
+                self.release_spend(value_wei);
+                McpError::internal_error(format!("Failed to send contract call: {}", e), None)
+            })?;
+        
+        info!("📝 Contract call sent with hash: {}", tx_hash);
+        
+        let response_text = match self.wait_for_transaction_confirmation(tx_hash, self.confirmation_timeout(confirmation_timeout)).await {
+            Ok(confirmation) => format!(
+                "Contract Call:\n{}\n{}\n{}\n\n{}",
+                call_summary, gas_line, fee_summary, confirmation
+            ),
+            Err(_e) => format!(
+                "Contract Call Sent:\n{}\n{}\n{}\n\
+                Transaction Hash: {}\n\
+                Status: Sent to network (confirmation timeout)\n\
+                \n⚠️  Transaction was sent but confirmation timed out.\n\
+                Use check_transaction_status with hash {} to check the final status.",
+                call_summary, gas_line, fee_summary, tx_hash, tx_hash
+            ),
+        };
+        
+        info!("🔍 MCP Server send_contract_call response: {}", response_text);
+        Ok(CallToolResult::success(vec![Content::text(response_text)]))
+    }
+
+    /// Resolve an ENS name to an address
+    #[tool(description = "Resolve an ENS name (e.g., vitalik.eth) to its Ethereum address")]
+    pub async fn resolve_ens(
+        &self,
+        Parameters(EnsResolveRequest { name }): Parameters<EnsResolveRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("🔎 MCP Server: resolve_ens called with name={}", name);
+        let name = name.trim().to_string();
+        
+        let response_text = match self.limited(NameOrAddress::from(name.clone()).resolve(&self.provider)).await {
+            Ok(address) => {
+                info!("✅ Resolved {} to {}", name, address);
+                format!(
+                    "ENS Resolution:\n\
+                    Name: {}\n\
+                    Resolved: true\n\
+                    Address: {}",
+                    name, address
+                )
+            }
+            Err(e) => {
+                warn!("⚠️  Failed to resolve ENS name {}: {}", name, e);
+                format!(
+                    "ENS Resolution:\n\
+                    Name: {}\n\
+                    Resolved: false\n\
+                    Reason: {}",
+                    name, e
+                )
+            }
+        };
+        
+        Ok(CallToolResult::success(vec![Content::text(response_text)]))
+    }
+
+    /// Look up the primary ENS name for an address
+    #[tool(description = "Reverse-resolve an Ethereum address to its primary ENS name, if it has one")]
+    pub async fn reverse_resolve_ens(
+        &self,
+        Parameters(EnsReverseRequest { address }): Parameters<EnsReverseRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("🔎 MCP Server: reverse_resolve_ens called with address={}", address);
+        
+        let addr = Address::from_str(address.trim())
+            .map_err(|e| McpError::invalid_params(format!("Invalid address: {}", e), None))?;
+        
+        let response_text = match self.limited(self.provider.lookup_address(&addr)).await {
+            Ok(ens_name) => {
+                info!("✅ {} has primary ENS name {}", addr, ens_name);
+                format!(
+                    "Reverse ENS Lookup:\n\
+                    Address: {}\n\
+                    Has ENS Name: true\n\
+                    ENS Name: {}",
+                    addr, ens_name
+                )
+            }
+            Err(e) => {
+                info!("ℹ️  No primary ENS name for {}: {}", addr, e);
+                format!(
+                    "Reverse ENS Lookup:\n\
+                    Address: {}\n\
+                    Has ENS Name: false\n\
+                    Reason: {}",
+                    addr, e
+                )
+            }
+        };
+        
+        Ok(CallToolResult::success(vec![Content::text(response_text)]))
+    }
+
+    /// Read ENS text records (avatar, email, url, ...) through the name's resolver
+    #[tool(description = "Get ENS text records (e.g., avatar, email, url) for a name; empty records are omitted")]
+    pub async fn get_ens_records(
+        &self,
+        Parameters(EnsRecordsRequest { name, keys }): Parameters<EnsRecordsRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("🔎 MCP Server: get_ens_records called with name={}, keys={:?}", name, keys);
+        let name = name.trim().to_string();
+        if !name.contains('.') {
+            return Err(McpError::invalid_params(format!("'{}' is not an ENS name (e.g. 'vitalik.eth')", name), None));
+        }
+        let keys: Vec<String> = if keys.is_empty() {
+            DEFAULT_ENS_TEXT_KEYS.iter().map(|key| key.to_string()).collect()
+        } else {
+            keys.into_iter().map(|key| key.trim().to_string()).filter(|key| !key.is_empty()).collect()
+        };
+        
+        // namehash doesn't normalise, so fold case and drop a trailing dot first
+        let node = namehash(&name.trim_end_matches('.').to_lowercase()).to_string();
+        let registry = ENS_ADDRESS;
+        
+        let result = self.call_raw(registry, "resolver(bytes32)", &[node.clone()]).await
+            .map_err(|e| McpError::internal_error(format!("Failed to look up the resolver for {}: {}", name, e.message), None))?;
+        if result.len() < 32 {
+            return Err(McpError::invalid_params(
+                format!("No ENS registry at {} on this chain - ENS records need mainnet or a mainnet fork", registry),
+                None
+            ));
+        }
+        let resolver = Address::from_slice(&result[12..32]);
+        if resolver.is_zero() {
+            return Err(McpError::invalid_params(
+                format!("{} does not resolve: it has no resolver set (the name may not be registered)", name),
+                None
+            ));
+        }
+        
+        let mut records = std::collections::BTreeMap::new();
+        for key in &keys {
+            match self.call_raw(resolver, "text(bytes32,string)", &[node.clone(), key.clone()]).await {
+                // text() returns an ABI-encoded string
+                Ok(result) => match decode_token_symbol(&result) {
+                    Some(value) if !value.trim().is_empty() => {
+                        records.insert(key.clone(), value);
+                    }
+                    _ => info!("ℹ️  {} has no '{}' record", name, key),
+                },
+                Err(e) => warn!("⚠️  Failed to read '{}' record of {} from resolver {}: {}", key, name, resolver, e.message),
+            }
+        }
+        
+        let mut summary = format!(
+            "ENS Records:\n\
+            Name: {}\n\
+            Resolver: {}",
+            name, resolver
+        );
+        if records.is_empty() {
+            summary.push_str(&format!("\nNo records set for: {}", keys.join(", ")));
+        }
+        for (key, value) in &records {
+            summary.push_str(&format!("\n{}: {}", key, value));
+        }
+        
+        info!("✅ Read {} of {} text records for {}", records.len(), keys.len(), name);
+        let result = EnsRecordsResult {
+            name,
+            resolver: resolver.to_string(),
+            records,
+        };
+        structured_result(&result, summary)
+    }
+
+    /// Get ERC-20 token balance for an account
+    #[tool(description = "Get ERC-20 token balance (e.g., USDC) for an account, optionally at a past block")]
+    pub async fn token_balance(
+        &self,
+        Parameters(TokenBalanceRequest { token_address, account_address, block }): Parameters<TokenBalanceRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("🔍 Starting token balance query for token: {}, account: {}, block: {:?}", token_address, account_address, block);
+        
+        let token_addr = Address::from_str(&token_address)
+            .map_err(|e| {
+                error!("❌ Invalid token address: {}", e);
+                McpError::invalid_params(format!("Invalid token address: {}", e), None)
+            })?;
+        let account_addr = Address::from_str(&account_address)
+            .map_err(|e| {
+                error!("❌ Invalid account address: {}", e);
+                McpError::invalid_params(format!("Invalid account address: {}", e), None)
+            })?;
+        
+        info!("✅ Address validation passed");
+        let block = self.resolve_query_block(block.as_deref()).await?;
+        
+        info!("📞 Making balanceOf call to token contract at block {}...", block_label(block));
+        let balance = self.get_token_balance_raw(&token_addr, &account_addr, block.into()).await?;
+        info!("📊 Decoded balance: {}", balance);
+        
+        // Try to get token symbol and decimals for better formatting
+        info!("🔍 Getting token info (symbol and decimals)...");
+        let (symbol, decimals) = self.get_token_info(&token_addr).await
+            .map_err(|e| McpError::internal_error(format!("Failed to get token info: {}", e), None))?;
+        info!("✅ Token info: symbol={}, decimals={}", symbol, decimals);
+        
+        let formatted_balance = format_token_amount(balance, decimals, &symbol);
+        
+        let response_text = format!(
+            "Token Balance:\nAccount: {}\nToken: {} ({})\nBlock: {}\nBalance: {} (raw: {})",
+            account_address, token_address, symbol, block_label(block), formatted_balance, balance
+        );
+        
+        info!("✅ Token balance query completed successfully");
+        info!("📝 Response: {}", response_text);
+        
+        let result = BalanceResult {
+            account: account_address,
+            address: account_addr.to_string(),
+            address_type: "Ethereum Address".to_string(),
+            block: block_label(block),
+            asset: symbol,
+            token_address: Some(token_addr.to_string()),
+            balance: formatted_balance,
+            balance_raw: balance.to_string(),
+            decimals,
+        };
+        structured_result(&result, response_text)
+    }
+
+    /// Get the WETH balance of an account using the configured WETH contract
+    #[tool(description = "Get the WETH (wrapped ETH) balance for an account - no token address needed")]
+    pub async fn weth_balance(
+        &self,
+        Parameters(WethBalanceRequest { account_address }): Parameters<WethBalanceRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("🔍 Starting WETH balance query for account: {}", account_address);
+
+        let validated_account = self.validate_recipient_address(&account_address).await?;
+        let account_addr = validated_account.resolved_address;
+        let weth_addr = self.addresses.token("WETH")
+            .ok_or_else(|| McpError::internal_error(
+                format!("No WETH address configured for chain id {}", self.chain_id),
+                None,
+            ))?;
+        info!("📋 Using WETH contract: {}", weth_addr);
+
+        let balance = self.get_token_balance_raw(&weth_addr, &account_addr, BlockId::latest()).await?;
+        let (symbol, decimals) = self.get_token_info(&weth_addr).await
+            .map_err(|e| McpError::internal_error(format!("Failed to get token info: {}", e), None))?;
+
+        let response_text = format!(
+            "WETH Balance:\n\
+            Account: {}\n\
+            WETH Contract: {}\n\
+            Balance: {} (raw: {})\n\
+            ETH Equivalent: {}\n\
+            Note: WETH is redeemable 1:1 for ETH (unwrap with a WETH → ETH swap)",
+            account_address,
+            weth_addr,
+            format_token_amount(balance, decimals, &symbol),
+            balance,
+            format_token_amount(balance, decimals, "ETH")
+        );
+
+        info!("✅ WETH balance query completed successfully");
+        let result = BalanceResult {
+            account: account_address,
+            address: account_addr.to_string(),
+            address_type: validated_account.address_type,
+            block: "latest".to_string(),
+            asset: symbol.clone(),
+            token_address: Some(weth_addr.to_string()),
+            balance: format_token_amount(balance, decimals, &symbol),
+            balance_raw: balance.to_string(),
+            decimals,
+        };
+        structured_result(&result, response_text)
+    }
+
+    /// Transfer ERC-20 tokens from Alice using transfer(address,uint256)
+    #[tool(description = "Transfer ERC-20 tokens (e.g., USDC) from Alice to a recipient - NOTE: Requires private key access")]
+    pub async fn transfer_token(
+        &self,
+        Parameters(TokenTransferRequest { token_address, to, amount, confirmation_timeout }): Parameters<TokenTransferRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("🚀 MCP Server: transfer_token called with token={}, to={}, amount={}", token_address, to, amount);
+        
+        let token_addr = Address::from_str(&token_address)
+            .map_err(|e| McpError::invalid_params(format!("Invalid token address: {}", e), None))?;
+        let validated_recipient = self.validate_recipient_address(&to).await?;
+        
+        if self.alice_private_key.is_empty() {
+            return Err(McpError::invalid_params(
+                format!(
+                    "Cannot send token transfer - private key not available for Alice ({}).\n\
+                    SOLUTION: export ALICE_PRIVATE_KEY=\"0x...\" or export PRIVATE_KEY=\"0x...\"",
+                    self.alice_address
+                ),
+                None
+            ));
+        }
+        
+        // Scale the human amount by the token's decimals
+        let (symbol, decimals) = self.get_token_info_for_transfer(&token_addr).await?;
+        let amount_raw = parse_token_amount(&amount, decimals, &symbol)?;
+        let formatted_amount = format_token_amount(amount_raw, decimals, &symbol);
+        
+        // Make sure Alice actually holds enough tokens before broadcasting
+        let sender_balance = self.get_token_balance_raw(&token_addr, &self.alice_address, BlockId::latest()).await?;
+        if sender_balance < amount_raw {
+            return Err(McpError::invalid_params(
+                format!(
+                    "Insufficient {} balance: Alice ({}) has {} but tried to send {}",
+                    symbol,
+                    self.alice_address,
+                    format_token_amount(sender_balance, decimals, &symbol),
+                    formatted_amount
+                ),
+                None
+            ));
+        }
+        
+        // Use Cast to encode transfer(address,uint256)
+        let calldata = SimpleCast::calldata_encode(
+            "transfer(address,uint256)",
+            &[validated_recipient.resolved_address.to_string(), amount_raw.to_string()]
+        ).map_err(|e| McpError::internal_error(format!("Failed to encode transfer call: {}", e), None))?;
+        
+        let tx = TransactionRequest::default()
+            .to(token_addr)
+            .input(Bytes::from_str(&calldata)
+                .map_err(|e| McpError::internal_error(format!("Failed to parse calldata: {}", e), None))?.into())
+            .from(self.alice_address);
+        
+        let tx = WithOtherFields::new(tx);
+        
+        self.ensure_expected_chain().await?;
+        
+        // Broadcast the transaction
+        let tx_hash = self.chain.send_transaction(tx).await
+            .map_err(|e| McpError::internal_error(format!("Failed to send token transfer: {}", e), None))?;
+        
+        info!("📝 Token transfer sent with hash: {}", tx_hash);
+        
+        let transfer_summary = format!(
+            "From: {} (Alice)\n\
+            To: {} ({})\n\
+            Token: {} ({})\n\
+            Amount: {} (raw: {}, {} decimals)",
+            self.alice_address,
+            validated_recipient.address,
+            validated_recipient.address_type,
+            token_address,
+            symbol,
+            formatted_amount,
+            amount_raw,
+            decimals
+        );
+        
+        let mut result = TransferResult {
+            status: TxStatus::Pending,
+            from: self.alice_address.to_string(),
+            from_label: self.config.default_sender.display_name(),
+            to: validated_recipient.address.clone(),
+            to_address: validated_recipient.resolved_address.to_string(),
+            asset: symbol.clone(),
+            token_address: Some(token_addr.to_string()),
+            amount: formatted_amount.clone(),
+            amount_raw: amount_raw.to_string(),
+            tx_hash: Some(tx_hash.to_string()),
+            confirmation: None,
+        };
+        
+        // Wait for transaction confirmation (configured timeout unless overridden per request)
+        let response_text = match self.wait_for_transaction_confirmation(tx_hash, self.confirmation_timeout(confirmation_timeout)).await {
+            Ok(confirmation) => {
+                let response_text = format!(
+                    "Token Transfer:\n{}\n\n{}",
+                    transfer_summary, confirmation
+                );
+                result.status = confirmation.status();
+                result.confirmation = Some(confirmation);
+                response_text
+            }
+            Err(_e) => format!(
+                "Token Transfer Sent:\n{}\n\
+                Transaction Hash: {}\n\
+                Status: Sent to network (confirmation timeout)\n\
+                \n⚠️  Transaction was sent but confirmation timed out.\n\
+                Use check_transaction_status with hash {} to check the final status.",
+                transfer_summary, tx_hash, tx_hash
+            ),
+        };
+        
+        info!("🔍 MCP Server transfer_token response: {}", response_text);
+        structured_result(&result, response_text)
+    }
+
+    /// Approve a spender to move Alice's ERC-20 tokens using approve(address,uint256)
+    #[tool(description = "Approve a spender (e.g., a DEX router) to spend Alice's ERC-20 tokens - NOTE: Requires private key access")]
+    pub async fn approve_token(
+        &self,
+        Parameters(TokenApprovalRequest { token_address, spender, amount, confirmation_timeout }): Parameters<TokenApprovalRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("🚀 MCP Server: approve_token called with token={}, spender={}, amount={}", token_address, spender, amount);
+        
+        let token_addr = Address::from_str(&token_address)
+            .map_err(|e| McpError::invalid_params(format!("Invalid token address: {}", e), None))?;
+        let validated_spender = self.validate_recipient_address(&spender).await?;
+        
+        if self.alice_private_key.is_empty() {
+            return Err(McpError::invalid_params(
+                format!(
+                    "Cannot send approval - private key not available for Alice ({}).\n\
+                    SOLUTION: export ALICE_PRIVATE_KEY=\"0x...\" or export PRIVATE_KEY=\"0x...\"",
+                    self.alice_address
+                ),
+                None
+            ));
+        }
+        
+        let (symbol, decimals) = self.get_token_info_for_transfer(&token_addr).await?;
+        let amount_raw = parse_token_amount(&amount, decimals, &symbol)?;
+        
+        // Use Cast to encode approve(address,uint256)
+        let calldata = SimpleCast::calldata_encode(
+            "approve(address,uint256)",
+            &[validated_spender.resolved_address.to_string(), amount_raw.to_string()]
+        ).map_err(|e| McpError::internal_error(format!("Failed to encode approve call: {}", e), None))?;
+        
+        let tx = TransactionRequest::default()
+            .to(token_addr)
+            .input(Bytes::from_str(&calldata)
+                .map_err(|e| McpError::internal_error(format!("Failed to parse calldata: {}", e), None))?.into())
+            .from(self.alice_address);
+        
+        let tx = WithOtherFields::new(tx);
+        
+        self.ensure_expected_chain().await?;
+        
+        // Broadcast the transaction
+        let tx_hash = self.chain.send_transaction(tx).await
+            .map_err(|e| McpError::internal_error(format!("Failed to send approval: {}", e), None))?;
+        
+        info!("📝 Approval sent with hash: {}", tx_hash);
+        
+        let approval_summary = format!(
+            "Owner: {} (Alice)\n\
+            Spender: {} ({})\n\
+            Token: {} ({})\n\
+            Allowance: {} (raw: {})",
+            self.alice_address,
+            validated_spender.address,
+            validated_spender.address_type,
+            token_address,
+            symbol,
+            format_token_amount(amount_raw, decimals, &symbol),
+            amount_raw
+        );
+        
+        // Wait for transaction confirmation (configured timeout unless overridden per request)
+        let response_text = match self.wait_for_transaction_confirmation(tx_hash, self.confirmation_timeout(confirmation_timeout)).await {
+            Ok(confirmation_text) => format!(
+                "Token Approval:\n{}\n\n{}",
+                approval_summary, confirmation_text
+            ),
+            Err(_e) => format!(
+                "Token Approval Sent:\n{}\n\
+                Transaction Hash: {}\n\
+                Status: Sent to network (confirmation timeout)\n\
+                \n⚠️  Transaction was sent but confirmation timed out.\n\
+                Use check_transaction_status with hash {} to check the final status.",
+                approval_summary, tx_hash, tx_hash
+            ),
+        };
+        
+        info!("🔍 MCP Server approve_token response: {}", response_text);
+        Ok(CallToolResult::success(vec![Content::text(response_text)]))
+    }
+
+    /// Read how many tokens a spender may move on behalf of an owner
+    #[tool(description = "Get the ERC-20 allowance an owner has granted to a spender")]
+    pub async fn get_allowance(
+        &self,
+        Parameters(AllowanceRequest { token_address, owner, spender }): Parameters<AllowanceRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("🔍 MCP Server: get_allowance called with token={}, owner={}, spender={}", token_address, owner, spender);
+        
+        let token_addr = Address::from_str(&token_address)
+            .map_err(|e| McpError::invalid_params(format!("Invalid token address: {}", e), None))?;
+        let validated_owner = self.validate_recipient_address(&owner).await?;
+        let validated_spender = self.validate_recipient_address(&spender).await?;
+        
+        let allowance = self.call_token_uint(
+            &token_addr,
+            "allowance(address,address)",
+            &[validated_owner.resolved_address.to_string(), validated_spender.resolved_address.to_string()],
+            BlockId::latest()
+        ).await?;
+        
+        let (symbol, decimals) = self.get_token_info(&token_addr).await?;
+        
+        let response_text = format!(
+            "Token Allowance:\n\
+            Token: {} ({})\n\
+            Owner: {} ({})\n\
+            Spender: {} ({})\n\
+            Allowance: {} (raw: {})",
+            token_address,
+            symbol,
+            validated_owner.address,
+            validated_owner.address_type,
+            validated_spender.address,
+            validated_spender.address_type,
+            if allowance == U256::MAX { format!("Unlimited {}", symbol) } else { format_token_amount(allowance, decimals, &symbol) },
+            allowance
+        );
+        
+        info!("📝 Response: {}", response_text);
+        Ok(CallToolResult::success(vec![Content::text(response_text)]))
+    }
+
+    /// Look up the owner of an ERC-721 token via ownerOf(uint256)
+    #[tool(description = "Get the owner of an ERC-721 NFT (collection address and token id), with the owner's ENS name if it has one")]
+    pub async fn nft_owner(
+        &self,
+        Parameters(NftOwnerRequest { collection_address, token_id }): Parameters<NftOwnerRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("🖼️  MCP Server: nft_owner called with collection={}, token_id={}", collection_address, token_id);
+        
+        let collection = Address::from_str(collection_address.trim())
+            .map_err(|e| McpError::invalid_params(format!("Invalid collection address: {}", e), None))?;
+        let token_id = U256::from_str(token_id.trim())
+            .map_err(|e| McpError::invalid_params(format!("Invalid token id '{}': {}", token_id, e), None))?;
+        
+        let calldata = SimpleCast::calldata_encode("ownerOf(uint256)", &[token_id.to_string()])
+            .map_err(|e| McpError::internal_error(format!("Failed to encode ownerOf call: {}", e), None))?;
+        let call_request = WithOtherFields::new(TransactionRequest::default()
+            .to(collection)
+            .input(Bytes::from_str(&calldata)
+                .map_err(|e| McpError::internal_error(format!("Failed to parse calldata: {}", e), None))?.into()));
+        
+        // ERC-721 requires ownerOf to revert for tokens that were never minted or were burned
+        let result = self.retry_read(|| self.chain.call_at(call_request.clone(), BlockId::latest())).await
+            .map_err(|e| if is_execution_revert(&e) {
+                McpError::invalid_params(
+                    format!(
+                        "Token {} does not exist in collection {} (ownerOf reverted: {})",
+                        token_id, collection, revert_reason_from_error(&e)
+                    ),
+                    None
+                )
+            } else {
+                McpError::internal_error(format!("Failed to call ownerOf: {}", e), None)
+            })?;
+        if result.len() < 32 {
+            return Err(McpError::invalid_params(
+                format!("{} returned no owner for token {} - is it an ERC-721 collection?", collection, token_id),
+                None
+            ));
         }
-
-        Ok(accounts)
+        let owner = Address::from_slice(&result[12..32]);
+        
+        let ens_name = self.limited(self.provider.lookup_address(&owner)).await.ok();
+        let response_text = format!(
+            "NFT Owner:\n\
+            Collection: {}\n\
+            Token ID: {}\n\
+            Owner: {}\n\
+            ENS Name: {}",
+            collection,
+            token_id,
+            owner,
+            ens_name.as_deref().unwrap_or("none")
+        );
+        
+        info!("✅ Token {} of {} is owned by {}", token_id, collection, owner);
+        Ok(CallToolResult::success(vec![Content::text(response_text)]))
     }
 
-    /// Get the balance of an account in wei - Following PRD Example Pattern
-    #[tool(description = "Get the balance of an account in wei")]
-    pub async fn balance(
+    /// Count the ERC-721 tokens an address holds in a collection via balanceOf(address)
+    #[tool(description = "Get how many NFTs an address holds in an ERC-721 collection")]
+    pub async fn nft_balance(
         &self,
-        Parameters(BalanceRequest { who }): Parameters<BalanceRequest>,
+        Parameters(NftBalanceRequest { collection_address, owner }): Parameters<NftBalanceRequest>,
     ) -> Result<CallToolResult, McpError> {
-        let who_clone = who.clone();
+        info!("🖼️  MCP Server: nft_balance called with collection={}, owner={}", collection_address, owner);
         
-        // First try to parse as direct address
-        let address = if let Ok(addr) = Address::from_str(&who) {
-            addr
-        } else {
-            // If not a direct address, try ENS resolution
-            NameOrAddress::from(who)
-                .resolve(&self.provider)
-                .await
-                .map_err(|e| McpError::invalid_params(format!("Failed to resolve address '{}': {}", who_clone, e), None))?
-        };
-        let balance = self.provider.get_balance(address).await
-            .map_err(|e| McpError::internal_error(format!("Failed to get balance: {}", e), None))?;
-
-        // Convert wei to ETH for better readability
-        let balance_eth = balance.to_f64().unwrap_or(0.0) / 1e18;
+        let collection = Address::from_str(collection_address.trim())
+            .map_err(|e| McpError::invalid_params(format!("Invalid collection address: {}", e), None))?;
+        let validated_owner = self.validate_recipient_address(&owner).await?;
+        
+        let balance = self.call_token_uint(
+            &collection,
+            "balanceOf(address)",
+            &[validated_owner.resolved_address.to_string()],
+            BlockId::latest()
+        ).await?;
         
         let response_text = format!(
-            "ETH Balance Query:\n\
-            Account: {} (resolved to {})\n\
-            Balance: {:.6} ETH ({} wei)",
-            who_clone, address, balance_eth, balance
+            "NFT Balance:\n\
+            Collection: {}\n\
+            Owner: {} ({})\n\
+            Tokens Owned: {}",
+            collection,
+            validated_owner.address,
+            validated_owner.address_type,
+            balance
         );
-
+        
+        info!("✅ {} holds {} tokens of {}", validated_owner.resolved_address, balance, collection);
         Ok(CallToolResult::success(vec![Content::text(response_text)]))
     }
 
-    /// Send ETH from Alice to another address using Cast::send
-    #[tool(description = "Send ETH from Alice to another address - NOTE: Requires private key access")]
-    pub async fn send_eth(
+    /// Run several read-only queries concurrently and return their combined results
+    #[tool(description = "Run several reads (balance, token_balance, allowance, nonce) concurrently in one call - use for dashboards or composite queries")]
+    pub async fn batch_read(
         &self,
-        Parameters(TransferRequest { to, amount }): Parameters<TransferRequest>,
+        Parameters(BatchReadRequest { reads }): Parameters<BatchReadRequest>,
     ) -> Result<CallToolResult, McpError> {
-        info!("🚀 MCP Server: send_eth called with to={}, amount={}", to, amount);
-        // Step 1: Validate recipient address (PRD requirement)
-        let validated_recipient = self.validate_recipient_address(&to).await?;
+        info!("📦 MCP Server: batch_read called with {} reads", reads.len());
         
-        // Check if we have Alice's private key available from environment
-        if self.alice_private_key.is_empty() {
-            return Ok(CallToolResult::success(vec![Content::text(
-                format!(
-                    "ERROR: Cannot send transaction - private key not available.\n\n\
-                    Alice's address: {}\n\
-                    Validated recipient: {} ({})\n\
-                    Requested transfer: {} ETH\n\n\
-                    SOLUTION: Set the private key in your environment:\n\
-                    export ALICE_PRIVATE_KEY=\"0x...\"\n\
-                    or\n\
-                    export PRIVATE_KEY=\"0x...\"\n\n\
-                    The private key should correspond to Alice's address ({}).\n\
-                    Accounts are loaded dynamically from anvil, but private keys must be\n\
-                    provided via environment variables for security.",
-                    self.alice_address, validated_recipient.address, validated_recipient.address_type, amount, self.alice_address
-                )
-            )]))
+        if reads.is_empty() {
+            return Err(McpError::invalid_params("batch_read needs at least one read".to_string(), None));
+        }
+        if reads.len() > MAX_BATCH_READS {
+            return Err(McpError::invalid_params(
+                format!("batch_read accepts at most {} reads, got {}", MAX_BATCH_READS, reads.len()),
+                None
+            ));
         }
-
-        let to_address = validated_recipient.resolved_address;
-        
-        // Parse amount to wei
-        let amount_wei = U256::from_str(&format!("{}000000000000000000", amount.replace(".", "")))
-            .map_err(|e| McpError::invalid_params(format!("Failed to parse amount '{}': {}", amount, e), None))?;
-        
-        // Create transaction request
-        let tx = TransactionRequest::default()
-            .to(to_address)
-            .value(amount_wei)
-            .from(self.alice_address);
         
-        let tx = WithOtherFields::new(tx);
+        // Each read fails independently, so one bad address doesn't sink the whole batch
+        let outcomes = join_all(reads.iter().map(|spec| self.execute_read(spec))).await;
+        let results: Vec<BatchReadResult> = reads.iter().zip(outcomes).enumerate()
+            .map(|(index, (spec, outcome))| match outcome {
+                Ok((value, formatted)) => BatchReadResult {
+                    index,
+                    read_type: spec.kind().to_string(),
+                    value: Some(value),
+                    formatted: Some(formatted),
+                    error: None,
+                },
+                Err(e) => {
+                    warn!("⚠️  batch_read {} #{} failed: {}", spec.kind(), index, e.message);
+                    BatchReadResult {
+                        index,
+                        read_type: spec.kind().to_string(),
+                        value: None,
+                        formatted: None,
+                        error: Some(e.message.to_string()),
+                    }
+                }
+            })
+            .collect();
         
-        // Create Cast instance and send transaction
-        let cast = Cast::new(self.provider.clone());
-        let pending_tx = cast.send(tx).await
-            .map_err(|e| McpError::internal_error(format!("Failed to send transaction: {}", e), None))?;
-        let tx_hash = *pending_tx.tx_hash();
+        let failed = results.iter().filter(|result| result.error.is_some()).count();
+        let response = BatchReadResponse {
+            succeeded: results.len() - failed,
+            failed,
+            results,
+        };
+        info!("✅ batch_read completed: {} succeeded, {} failed", response.succeeded, response.failed);
         
-        info!("📝 Transaction sent with hash: {}", tx_hash);
+        let json_response = serde_json::to_string_pretty(&response)
+            .map_err(|e| McpError::internal_error(format!("Failed to serialize response: {}", e), None))?;
         
-        // Wait for transaction confirmation (30 second timeout)
-        match self.wait_for_transaction_confirmation(tx_hash, 30).await {
-            Ok(confirmation_text) => {
-                let response_text = format!(
-                    "ETH Transfer:\n\
-                    From: {} (Alice)\n\
-                    To: {} ({})\n\
-                    Amount: {} ETH\n\
-                    \n{}",
-                    self.alice_address,
-                    validated_recipient.address,
-                    validated_recipient.address_type,
-                    amount,
-                    confirmation_text
-                );
-                
-                info!("🔍 MCP Server send_eth response: {}", response_text);
-                Ok(CallToolResult::success(vec![Content::text(response_text)]))
+        Ok(CallToolResult::success(vec![Content::text(json_response)]))
+    }
+
+    /// Execute one batch_read entry, returning its raw and formatted value
+    async fn execute_read(&self, spec: &ReadSpec) -> Result<(String, String), McpError> {
+        match spec {
+            ReadSpec::Balance { address } => {
+                let addr = self.validate_recipient_address(address).await?.resolved_address;
+                let balance = self.retry_read(|| self.chain.get_balance(addr)).await
+                    .map_err(|e| McpError::internal_error(format!("Failed to get balance: {}", e), None))?;
+                Ok((balance.to_string(), format_token_amount(balance, 18, "ETH")))
             }
-            Err(_e) => {
-                // If waiting fails, return the transaction hash for manual checking
-                let response_text = format!(
-                    "ETH Transfer Sent:\n\
-                    From: {} (Alice)\n\
-                    To: {} ({})\n\
-                    Amount: {} ETH\n\
-                    Transaction Hash: {}\n\
-                    Status: Sent to network (confirmation timeout)\n\
-                    \n⚠️  Transaction was sent but confirmation timed out.\n\
-                    Use check_transaction_status with hash {} to check the final status.",
-                    self.alice_address,
-                    validated_recipient.address,
-                    validated_recipient.address_type,
-                    amount,
-                    tx_hash,
-                    tx_hash
-                );
-                
-                info!("⚠️  MCP Server send_eth response (timeout): {}", response_text);
-                Ok(CallToolResult::success(vec![Content::text(response_text)]))
+            ReadSpec::TokenBalance { token_address, account_address } => {
+                let token_addr = Address::from_str(token_address)
+                    .map_err(|e| McpError::invalid_params(format!("Invalid token address: {}", e), None))?;
+                let account_addr = self.validate_recipient_address(account_address).await?.resolved_address;
+                let balance = self.get_token_balance_raw(&token_addr, &account_addr, BlockId::latest()).await?;
+                let (symbol, decimals) = self.get_token_info(&token_addr).await?;
+                Ok((balance.to_string(), format_token_amount(balance, decimals, &symbol)))
+            }
+            ReadSpec::Allowance { token_address, owner, spender } => {
+                let token_addr = Address::from_str(token_address)
+                    .map_err(|e| McpError::invalid_params(format!("Invalid token address: {}", e), None))?;
+                let owner = self.validate_recipient_address(owner).await?.resolved_address;
+                let spender = self.validate_recipient_address(spender).await?.resolved_address;
+                let allowance = self.call_token_uint(
+                    &token_addr,
+                    "allowance(address,address)",
+                    &[owner.to_string(), spender.to_string()],
+                    BlockId::latest()
+                ).await?;
+                let (symbol, decimals) = self.get_token_info(&token_addr).await?;
+                let formatted = if allowance == U256::MAX { format!("Unlimited {}", symbol) } else { format_token_amount(allowance, decimals, &symbol) };
+                Ok((allowance.to_string(), formatted))
+            }
+            ReadSpec::Nonce { address } => {
+                let addr = self.validate_recipient_address(address).await?.resolved_address;
+                let nonce = self.retry_read(|| self.chain.get_transaction_count(addr, BlockId::latest())).await
+                    .map_err(|e| McpError::internal_error(format!("Failed to get nonce: {}", e), None))?;
+                Ok((nonce.to_string(), format!("nonce {}", nonce)))
             }
         }
     }
 
-    /// Check if a contract is deployed using Cast::code
-    #[tool(description = "Check if a contract is deployed at the specified address")]
-    pub async fn is_contract_deployed(
-        &self,
-        Parameters(ContractDeploymentRequest { address }): Parameters<ContractDeploymentRequest>,
-    ) -> Result<CallToolResult, McpError> {
-        // Validate the contract address
-        let validated_address = self.validate_recipient_address(&address).await?;
-        let addr = validated_address.resolved_address;
-        
-        // Create Cast instance and check if there's code at the address
-        let cast = Cast::new(self.provider.clone());
-        let code = cast.code(addr, None, false).await
-            .map_err(|e| McpError::internal_error(format!("Failed to get contract code: {}", e), None))?;
-        
-        // Contract is deployed if code is not "0x" (empty)
-        let is_deployed = !code.is_empty() && code != "0x";
-        
-        Ok(CallToolResult::success(vec![Content::text(format!(
-            "Contract Deployment Check:\n\
-            Input: {} ({})\n\
-            Resolved Address: {}\n\
-            Status: {}\n\
-            Code Length: {} bytes",
-            validated_address.address,
-            validated_address.address_type,
-            validated_address.resolved_address,
-            if is_deployed { "DEPLOYED" } else { "NOT DEPLOYED" },
-            if code.len() > 2 { (code.len() - 2) / 2 } else { 0 } // Remove 0x prefix and convert hex to bytes
-        ))]))
+    /// Helper function to read a raw ERC-20 balance via balanceOf(address) as of `block`
+    async fn get_token_balance_raw(&self, token_addr: &Address, account_addr: &Address, block: BlockId) -> Result<U256, McpError> {
+        self.call_token_uint(token_addr, "balanceOf(address)", &[account_addr.to_string()], block).await
     }
 
-    /// Get ERC-20 token balance for an account
-    #[tool(description = "Get ERC-20 token balance (e.g., USDC) for an account")]
-    pub async fn token_balance(
-        &self,
-        Parameters(TokenBalanceRequest { token_address, account_address }): Parameters<TokenBalanceRequest>,
-    ) -> Result<CallToolResult, McpError> {
-        info!("🔍 Starting token balance query for token: {}, account: {}", token_address, account_address);
-        
-        let token_addr = Address::from_str(&token_address)
-            .map_err(|e| {
-                error!("❌ Invalid token address: {}", e);
-                McpError::invalid_params(format!("Invalid token address: {}", e), None)
-            })?;
-        let account_addr = Address::from_str(&account_address)
-            .map_err(|e| {
-                error!("❌ Invalid account address: {}", e);
-                McpError::invalid_params(format!("Invalid account address: {}", e), None)
-            })?;
-        
-        info!("✅ Address validation passed");
+    /// Cast-encode a call to `signature` on `contract` and return the raw result at the latest block
+    async fn call_raw(&self, contract: Address, signature: &str, args: &[String]) -> Result<Bytes, McpError> {
+        let calldata = SimpleCast::calldata_encode(signature, args)
+            .map_err(|e| McpError::internal_error(format!("Failed to encode {} call: {}", signature, e), None))?;
+        let call_request = WithOtherFields::new(TransactionRequest::default()
+            .to(contract)
+            .input(Bytes::from_str(&calldata)
+                .map_err(|e| McpError::internal_error(format!("Failed to parse calldata: {}", e), None))?.into()));
         
-        // Use Cast to encode and call balanceOf
-        let calldata = SimpleCast::calldata_encode("balanceOf(address)", &[account_addr.to_string()])
-            .map_err(|e| McpError::internal_error(format!("Failed to encode balanceOf call: {}", e), None))?;
+        self.retry_read(|| self.chain.call_at(call_request.clone(), BlockId::latest())).await
+            .map_err(|e| McpError::internal_error(format!("{} call to {} failed: {}", signature, contract, e), None))
+    }
+
+    /// Helper function to call a view function on a token contract at `block` and decode a U256 result
+    async fn call_token_uint(&self, token_addr: &Address, signature: &str, args: &[String], block: BlockId) -> Result<U256, McpError> {
+        // Use Cast to encode the call
+        let calldata = SimpleCast::calldata_encode(signature, args)
+            .map_err(|e| McpError::internal_error(format!("Failed to encode {} call: {}", signature, e), None))?;
             
         let call_request = TransactionRequest::default()
-            .to(token_addr)
+            .to(*token_addr)
             .input(Bytes::from_str(&calldata)
                 .map_err(|e| McpError::internal_error(format!("Failed to parse calldata: {}", e), None))?.into());
         
-        info!("📞 Making balanceOf call to token contract...");
-        
         // Make the call
-        let result = self.provider.call(WithOtherFields::new(call_request)).await
+        let call_request = WithOtherFields::new(call_request);
+        let result = self.retry_read(|| self.chain.call_at(call_request.clone(), block)).await
             .map_err(|e| {
                 error!("❌ Failed to call token contract: {}", e);
                 McpError::internal_error(format!("Failed to call token contract: {}", e), None)
             })?;
         
-        info!("✅ balanceOf call successful, result length: {}", result.len());
+        info!("✅ {} call successful, result length: {}", signature, result.len());
         
-        // Decode the result (U256 balance)
-        let balance = if result.len() >= 32 {
+        // Decode the result (U256)
+        Ok(if result.len() >= 32 {
             U256::from_be_slice(&result[result.len()-32..])
         } else {
             U256::ZERO
-        };
-        
-        info!("📊 Decoded balance: {}", balance);
-        
-        // Try to get token symbol and decimals for better formatting
-        info!("🔍 Getting token info (symbol and decimals)...");
-        let (symbol, decimals) = self.get_token_info(&token_addr).await
-            .map_err(|e| McpError::internal_error(format!("Failed to get token info: {}", e), None))?;
-        info!("✅ Token info: symbol={}, decimals={}", symbol, decimals);
-        
-        let formatted_balance = if decimals > 0 {
-            let divisor = U256::from(10).pow(U256::from(decimals));
-            let whole = balance / divisor;
-            let fraction = balance % divisor;
-            format!("{}.{:0width$} {}", whole, fraction, symbol, width = decimals as usize)
-        } else {
-            format!("{} {}", balance, symbol)
-        };
-        
-        let response_text = format!(
-            "Token Balance:\nAccount: {}\nToken: {} ({})\nBalance: {} (raw: {})",
-            account_address, token_address, symbol, formatted_balance, balance
-        );
+        })
+    }
+
+    /// Helper function to get token symbol and decimals for display, assuming 18 decimals when they can't be read
+    async fn get_token_info(&self, token_addr: &Address) -> Result<(String, u8), McpError> {
+        let (symbol, decimals) = self.lookup_token_info(token_addr).await?;
+        Ok((symbol, decimals.unwrap_or_else(|| {
+            warn!("⚠️  decimals() unreadable for {}, displaying amounts with 18 decimals", token_addr);
+            18
+        })))
+    }
+
+    /// Token symbol and decimals for scaling an amount that will be sent
+    ///
+    /// Unlike `get_token_info` there is no 18-decimal fallback: guessing wrong would
+    /// move 10^12 times too much of a 6-decimal token like USDC.
+    async fn get_token_info_for_transfer(&self, token_addr: &Address) -> Result<(String, u8), McpError> {
+        match self.lookup_token_info(token_addr).await? {
+            (symbol, Some(decimals)) => Ok((symbol, decimals)),
+            (symbol, None) => Err(McpError::internal_error(
+                format!(
+                    "Could not read decimals() from token {} ({}), so the amount can't be scaled safely. \
+                    Check the token address, or add a decimals override for it to NETWORK_ADDRESSES_FILE.",
+                    token_addr, symbol
+                ),
+                None
+            )),
+        }
+    }
+
+    /// Token symbol and decimals, served from the metadata cache when possible
+    ///
+    /// Decimals are `None` when `decimals()` failed or returned short data. Only
+    /// fully read metadata (or a configured decimals override) is memoized, so a
+    /// failed read is retried on the next call instead of sticking for the process.
+    async fn lookup_token_info(&self, token_addr: &Address) -> Result<(String, Option<u8>), McpError> {
+        let cached = self.token_metadata_cache.lock()
+            .map_err(|e| McpError::internal_error(format!("Cache lock error: {}", e), None))?
+            .get(token_addr)
+            .cloned();
+        if let Some((symbol, decimals)) = cached {
+            info!("✅ Token info cache hit for {}: symbol={}, decimals={}", token_addr, symbol, decimals);
+            return Ok((symbol, Some(decimals)));
+        }
         
-        info!("✅ Token balance query completed successfully");
-        info!("📝 Response: {}", response_text);
+        let (symbol, decimals) = self.fetch_token_info(token_addr).await?;
         
-        Ok(CallToolResult::success(vec![Content::text(response_text)]))
+        if symbol != "UNKNOWN" && let Some(decimals) = decimals {
+            self.token_metadata_cache.lock()
+                .map_err(|e| McpError::internal_error(format!("Cache lock error: {}", e), None))?
+                .insert(*token_addr, (symbol.clone(), decimals));
+        }
+        Ok((symbol, decimals))
     }
 
-    /// Helper function to get token symbol and decimals
-    async fn get_token_info(&self, token_addr: &Address) -> Result<(String, u8), McpError> {
+    /// Fetch token symbol and decimals from the token contract
+    async fn fetch_token_info(&self, token_addr: &Address) -> Result<(String, Option<u8>), McpError> {
         info!("🔍 Getting token info for address: {}", token_addr);
         
         // Use Cast to encode symbol() call
@@ -551,28 +3882,30 @@ impl BlockchainService {
             .input(Bytes::from_str(&symbol_calldata)
                 .map_err(|e| McpError::internal_error(format!("Failed to parse symbol calldata: {}", e), None))?.into());
         
-        let symbol = if let Ok(result) = self.provider.call(WithOtherFields::new(symbol_call)).await {
+        let symbol_call = WithOtherFields::new(symbol_call);
+        let symbol = if let Ok(result) = self.retry_read(|| self.chain.call(symbol_call.clone())).await {
             info!("✅ Symbol call successful, result length: {}", result.len());
-            // Decode string (skip first 64 bytes for offset and length, then read the string)
-            if result.len() > 64 {
-                let length = u32::from_be_bytes([result[60], result[61], result[62], result[63]]) as usize;
-                if result.len() >= 64 + length {
-                    let symbol_str = String::from_utf8(result[64..64+length].to_vec()).unwrap_or_else(|_| "UNKNOWN".to_string());
+            match decode_token_symbol(&result) {
+                Some(symbol_str) => {
                     info!("📊 Decoded symbol: {}", symbol_str);
                     symbol_str
-                } else {
-                    info!("⚠️  Symbol result too short for decoding");
+                }
+                None => {
+                    info!("⚠️  Symbol result could not be decoded");
                     "UNKNOWN".to_string()
                 }
-            } else {
-                info!("⚠️  Symbol result too short");
-                "UNKNOWN".to_string()
             }
         } else {
             info!("⚠️  Symbol call failed");
             "UNKNOWN".to_string()
         };
         
+        // Configured overrides win over the token contract
+        if let Some(decimals) = self.addresses.decimals_override(token_addr) {
+            info!("✅ Token info complete: symbol={}, decimals={} (configured override)", symbol, decimals);
+            return Ok((symbol, Some(decimals)));
+        }
+        
         // Use Cast to encode decimals() call
         let decimals_calldata = SimpleCast::calldata_encode("decimals()", &[] as &[&str])
             .map_err(|e| McpError::internal_error(format!("Failed to encode decimals call: {}", e), None))?;
@@ -582,22 +3915,23 @@ impl BlockchainService {
             .input(Bytes::from_str(&decimals_calldata)
                 .map_err(|e| McpError::internal_error(format!("Failed to parse decimals calldata: {}", e), None))?.into());
         
-        let decimals = if let Ok(result) = self.provider.call(WithOtherFields::new(decimals_call)).await {
+        let decimals_call = WithOtherFields::new(decimals_call);
+        let decimals = if let Ok(result) = self.retry_read(|| self.chain.call(decimals_call.clone())).await {
             info!("✅ Decimals call successful, result length: {}", result.len());
             if result.len() >= 32 {
                 let decimals_val = result[31]; // Last byte should contain decimals for most tokens
                 info!("📊 Decoded decimals: {}", decimals_val);
-                decimals_val
+                Some(decimals_val)
             } else {
-                info!("⚠️  Decimals result too short, defaulting to 18");
-                18 // Default to 18 decimals
+                info!("⚠️  Decimals result too short");
+                None
             }
         } else {
-            info!("⚠️  Decimals call failed, defaulting to 18");
-            18 // Default to 18 decimals
+            info!("⚠️  Decimals call failed");
+            None
         };
         
-        info!("✅ Token info complete: symbol={}, decimals={}", symbol, decimals);
+        info!("✅ Token info complete: symbol={}, decimals={:?}", symbol, decimals);
         Ok((symbol, decimals))
     }
 
@@ -617,7 +3951,7 @@ impl BlockchainService {
         
         // Step 2: Check if it's an ENS name and try to resolve it
         if trimmed_input.ends_with(".eth") || trimmed_input.contains('.') {
-            match NameOrAddress::from(trimmed_input.to_string()).resolve(&self.provider).await {
+            match self.limited(NameOrAddress::from(trimmed_input.to_string()).resolve(&self.provider)).await {
                 Ok(resolved_address) => {
                     return Ok(ValidatedAddress {
                         address: trimmed_input.to_string(),
@@ -634,67 +3968,163 @@ impl BlockchainService {
             }
         }
         
-        // Step 3: Check if it's a known account name (Alice, Bob, etc.)
+        // Step 3: Check if it's a known account name (default sender/recipient, accountN, address book)
         let lowercase_input = trimmed_input.to_lowercase();
-        
-        // Handle Alice and Bob specifically (PRD requirement)
-        if lowercase_input == "alice" {
-            return Ok(ValidatedAddress {
-                address: format!("{:?}", self.alice_address),
-                resolved_address: self.alice_address,
-                address_type: "Alice (Account 0 - Default Sender)".to_string(),
-            });
-        }
-        
-        if lowercase_input == "bob" {
-            return Ok(ValidatedAddress {
-                address: format!("{:?}", self.bob_address),
-                resolved_address: self.bob_address,
-                address_type: "Bob (Account 1 - Default Recipient)".to_string(),
-            });
+        if let Some(validated) = self.resolve_account_name(&lowercase_input) {
+            return validated;
         }
         
-        // Handle numbered accounts
-        let known_accounts = [
-            ("account0", 0),
-            ("account1", 1),
-            ("account2", 2),
-            ("account3", 3),
-            ("account4", 4),
-            ("account5", 5),
-            ("account6", 6),
-            ("account7", 7),
-            ("account8", 8),
-            ("account9", 9),
-        ];
-        
-        for (name, index) in known_accounts.iter() {
-            if lowercase_input == *name
-                && let Some(account) = self.anvil_accounts.get(*index)
-                    && let Ok(addr) = Address::from_str(&account.address) {
-                        return Ok(ValidatedAddress {
-                            address: account.address.clone(),
-                            resolved_address: addr,
-                            address_type: format!("Anvil Account {}", index),
-                        });
-                    }
-        }
+        // Step 4: Tolerate spacing and small typos ("account 1", "acct0", "alise"), never guessing between names
+        let suggestion = match match_account_name(trimmed_input, &self.known_account_names()) {
+            AccountNameMatch::Resolved(name) => match self.resolve_account_name(&name) {
+                Some(validated) => {
+                    info!("🔤 Matched '{}' to account name '{}'", trimmed_input, name);
+                    return validated.map(|validated| ValidatedAddress {
+                        address_type: format!("{}, matched from '{}'", validated.address_type, trimmed_input),
+                        ..validated
+                    });
+                }
+                None => String::new(),
+            },
+            AccountNameMatch::Ambiguous(names) => format!("Did you mean {}?\n\n", names.join(" or ")),
+            AccountNameMatch::Suggestion(name) => format!("Did you mean {}?\n\n", name),
+            AccountNameMatch::NoMatch => String::new(),
+        };
+        let (sender, recipient) = (&self.config.default_sender, &self.config.default_recipient);
         
-        // Step 4: If nothing matches, return validation error
+        // Step 5: If nothing matches, return validation error
         Err(McpError::invalid_params(
             format!(
                 "Invalid recipient address: '{}'\n\n\
-                Valid formats:\n\
+                {}Valid formats:\n\
                 - Ethereum address: 0x742d35Cc6634C0532925a3b8D8C9C0C4e8C6C85b\n\
                 - ENS name: vitalik.eth\n\
-                - Known accounts: alice, bob, account0, account1, etc.\n\n\
+                - Known accounts: {}, {}, account0, account1, etc.\n\
+                - Address book names (see list_address_book)\n\n\
                 Please provide a valid recipient address.",
-                trimmed_input
+                trimmed_input,
+                suggestion,
+                sender.name,
+                recipient.name
             ),
             None
         ))
     }
 
+    /// Resolve a lowercase account name: the default sender/recipient, accountN or an address book name
+    fn resolve_account_name(&self, name: &str) -> Option<Result<ValidatedAddress, McpError>> {
+        let (sender, recipient) = (&self.config.default_sender, &self.config.default_recipient);
+        
+        // Handle the configured default sender and recipient (PRD requirement)
+        if name == sender.name {
+            return Some(Ok(ValidatedAddress {
+                address: format!("{:?}", self.alice_address),
+                resolved_address: self.alice_address,
+                address_type: format!("{} (Account {} - Default Sender)", sender.display_name(), sender.index),
+            }));
+        }
+        
+        if name == recipient.name {
+            let Some(bob_address) = self.bob_address else {
+                return Some(Err(McpError::invalid_params(
+                    format!(
+                        "{} (account {}) is not available - the node only has {} account(s). Use an address instead.",
+                        recipient.display_name(), recipient.index, self.anvil_accounts.len()
+                    ),
+                    None
+                )));
+            };
+            return Some(Ok(ValidatedAddress {
+                address: format!("{:?}", bob_address),
+                resolved_address: bob_address,
+                address_type: format!("{} (Account {} - Default Recipient)", recipient.display_name(), recipient.index),
+            }));
+        }
+        
+        // Handle numbered accounts (account0, account1, ...) for every loaded account
+        if let Some(index) = name.strip_prefix("account").and_then(|index| index.parse::<usize>().ok())
+            && let Some(account) = self.anvil_accounts.get(index)
+            && let Ok(addr) = Address::from_str(&account.address)
+        {
+            return Some(Ok(ValidatedAddress {
+                address: account.address.clone(),
+                resolved_address: addr,
+                address_type: format!("Anvil Account {}", index),
+            }));
+        }
+        
+        // The configured address book (e.g. "treasury")
+        let addr = self.address_book.lock().ok().and_then(|book| book.get(name))?;
+        Some(Ok(ValidatedAddress {
+            address: addr.to_checksum(None),
+            resolved_address: addr,
+            address_type: format!("Address Book ({})", name),
+        }))
+    }
+
+    /// Every name `resolve_account_name` accepts, for suggesting corrections
+    fn known_account_names(&self) -> Vec<String> {
+        let mut names = vec![self.config.default_sender.name.clone(), self.config.default_recipient.name.clone()];
+        names.extend((0..self.anvil_accounts.len()).map(|index| format!("account{}", index)));
+        if let Ok(book) = self.address_book.lock() {
+            names.extend(book.entries().keys().cloned());
+        }
+        names
+    }
+
+    /// Save a named address to the address book
+    #[tool(description = "Save a named address (e.g. treasury, deployer) to the address book so it can be used as a recipient")]
+    pub async fn add_address_book_entry(
+        &self,
+        Parameters(AddressBookEntryRequest { name, address }): Parameters<AddressBookEntryRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("📒 MCP Server: add_address_book_entry called with name={}, address={}", name, address);
+        
+        let addr = Address::from_str(address.trim())
+            .map_err(|e| McpError::invalid_params(format!("Invalid address: {}", e), None))?;
+        let mut book = self.address_book.lock()
+            .map_err(|_| McpError::internal_error("Address book lock poisoned".to_string(), None))?;
+        let saved_name = book.insert(&name, addr)
+            .map_err(|e| McpError::invalid_params(e, None))?;
+        let location = book.path()
+            .map(|path| path.display().to_string())
+            .unwrap_or_else(|| "memory only".to_string());
+        
+        let response_text = format!(
+            "Address Book Entry Saved:\n\
+            Name: {}\n\
+            Address: {}\n\
+            Saved To: {}",
+            saved_name, addr.to_checksum(None), location
+        );
+        
+        info!("✅ {}", response_text.replace('\n', " | "));
+        Ok(CallToolResult::success(vec![Content::text(response_text)]))
+    }
+
+    /// List every named address in the address book
+    #[tool(description = "List the named addresses in the address book")]
+    pub async fn list_address_book(&self) -> Result<CallToolResult, McpError> {
+        info!("📒 MCP Server: list_address_book called");
+        
+        let book = self.address_book.lock()
+            .map_err(|_| McpError::internal_error("Address book lock poisoned".to_string(), None))?;
+        
+        let response_text = if book.entries().is_empty() {
+            "Address Book: empty\nAdd entries with add_address_book_entry (e.g. name=treasury, address=0x...).".to_string()
+        } else {
+            let lines: Vec<String> = book.entries().iter()
+                .map(|(name, addr)| format!("- {}: {}", name, addr.to_checksum(None)))
+                .collect();
+            format!(
+                "Address Book ({} entr{}):\n{}",
+                lines.len(), if lines.len() == 1 { "y" } else { "ies" }, lines.join("\n")
+            )
+        };
+        
+        Ok(CallToolResult::success(vec![Content::text(response_text)]))
+    }
+
     /// Get list of all available anvil accounts (addresses only)
     #[tool(description = "Get list of all available anvil accounts with their addresses")]
     pub async fn get_accounts(&self) -> Result<CallToolResult, McpError> {
@@ -725,9 +4155,12 @@ impl BlockchainService {
         // Clone accounts and add private key info where available
         let mut accounts_with_keys = self.anvil_accounts.clone();
         
-        // Only Alice (first account) has private key from environment
-        if !accounts_with_keys.is_empty() && !self.alice_private_key.is_empty() {
-            accounts_with_keys[0].private_key = Some(format!("{}...", &self.alice_private_key[..10])); // Show only first 10 chars
+        // Mark every account whose private key was loaded from environment
+        for account in accounts_with_keys.iter_mut() {
+            if let Ok(addr) = Address::from_str(&account.address)
+                && let Some(key) = self.private_keys.get(&addr) {
+                    account.private_key = Some(format!("{}...", key.get(..10).unwrap_or(key))); // Show only first 10 chars
+                }
         }
 
         let response = AccountListResponse {
@@ -744,9 +4177,11 @@ impl BlockchainService {
             Private key for Alice (account 0) loaded from environment variable.\n\
             Environment variables checked: ALICE_PRIVATE_KEY, PRIVATE_KEY\n\
             Private key available for transactions: {}\n\n\
-            Other accounts would need their private keys provided via additional\n\
-            environment variables to enable transactions from those addresses.",
-            if self.alice_private_key.is_empty() { "NO" } else { "YES" }
+            Other accounts can send transactions once their private keys are provided via\n\
+            PRIVATE_KEY_<account index> or ANVIL_PRIVATE_KEYS (comma-separated, in account order).\n\
+            Senders with keys loaded: {}",
+            if self.alice_private_key.is_empty() { "NO" } else { "YES" },
+            self.private_keys.len()
         );
         
         Ok(CallToolResult::success(vec![Content::text(format!("{}{}", json_response, explanation))]))
@@ -756,13 +4191,14 @@ impl BlockchainService {
     #[tool(description = "Swap tokens using Uniswap V2 Router - integrates with search API to find contract addresses")]
     pub async fn swap_tokens(
         &self,
-        Parameters(SwapRequest { from_token, to_token, amount, dex, slippage }): Parameters<SwapRequest>,
+        Parameters(SwapRequest { from_token, to_token, amount, dex, slippage, dry_run, confirmation_timeout, force }): Parameters<SwapRequest>,
     ) -> Result<CallToolResult, McpError> {
-        info!("🔄 MCP Server: swap_tokens called with from={}, to={}, amount={}, dex={:?}", 
-              from_token, to_token, amount, dex);
+        info!("🔄 MCP Server: swap_tokens called with from={}, to={}, amount={}, dex={:?}, dry_run={:?}", 
+              from_token, to_token, amount, dex, dry_run);
+        let dry_run = dry_run.unwrap_or(false);
         
-        // Check if we have Alice's private key available
-        if self.alice_private_key.is_empty() {
+        // Check if we have Alice's private key available (simulations don't sign)
+        if !dry_run && self.alice_private_key.is_empty() {
             return Ok(CallToolResult::success(vec![Content::text(
                 format!(
                     "ERROR: Cannot execute swap - private key not available.\n\n\
@@ -782,29 +4218,24 @@ impl BlockchainService {
         // Special handling for ETH to WETH swaps - use direct WETH contract
         if from_token.to_uppercase() == "ETH" && to_token.to_uppercase() == "WETH" {
             info!("🎯 Detected ETH to WETH swap - using direct WETH contract");
-            return self.swap_eth_to_weth_direct(amount).await;
+            return self.swap_eth_to_weth_direct(amount, dry_run, confirmation_timeout).await;
         }
 
         // Special handling for WETH to ETH swaps - use direct WETH contract
         if from_token.to_uppercase() == "WETH" && to_token.to_uppercase() == "ETH" {
             info!("🎯 Detected WETH to ETH swap - using direct WETH contract");
-            return self.swap_weth_to_eth_direct(amount).await;
+            return self.swap_weth_to_eth_direct(amount, dry_run, confirmation_timeout).await;
         }
 
         let dex_name = dex.unwrap_or_else(|| "Uniswap V2".to_string());
-        let slippage_bps = slippage.unwrap_or_else(|| self.config.default_slippage_bps.clone());
         
-        // Look up Uniswap V2 Router address
-        let router_address = match self.search_token_address("Uniswap V2 Router").await? {
-            Some(addr) => format!("{:?}", addr),
-            None => return Err(McpError::internal_error("Failed to find Uniswap V2 Router address".to_string(), None)),
-        };
-        let router_addr = Address::from_str(&router_address)
-            .map_err(|e| McpError::internal_error(format!("Invalid router address: {}", e), None))?;
+        // Uniswap V2 Router for the connected network
+        let router_addr = self.addresses.uniswap_v2_router;
+        let router_address = format!("{:?}", router_addr);
         
         info!("📋 Using Uniswap V2 Router: {}", router_address);
         
-        // Step 2: Get token addresses (hardcoded common tokens for now)
+        // Step 2: Get token addresses (network config first, then cache/web search)
         let (from_token_addr, to_token_addr) = self.get_token_addresses(&from_token, &to_token).await?;
         
         info!("🪙 Token addresses - From: {} ({}) To: {} ({})", 
@@ -812,19 +4243,56 @@ impl BlockchainService {
         
         // Step 3: Calculate swap parameters
         let amount_wei = self.parse_amount_to_wei(&amount, &from_token).await?;
-        let amount_out_min = U256::ZERO; // For now, set to 0 (no slippage protection)
+        self.check_spend_limits(amount_wei)?;
+        let slippage_bps_value = match slippage.as_deref() {
+            Some(slippage) => parse_slippage_bps(slippage)?,
+            None => self.config.default_slippage_bps,
+        };
         
         // Step 4: Create swap path
         let path = vec![from_token_addr, to_token_addr];
         
-        // Step 5: Calculate deadline (5 minutes from now)
-        let deadline = U256::from(
-            std::time::SystemTime::now()
-                .duration_since(std::time::UNIX_EPOCH)
-                .map_err(|e| McpError::internal_error(format!("Failed to get system time: {}", e), None))?
-                .as_secs() + self.config.default_deadline_secs
+        // Ask the router for the expected output and apply slippage protection
+        let expected_out = self.get_amounts_out(router_addr, amount_wei, &path).await?;
+        let amount_out_min = apply_slippage(expected_out, slippage_bps_value);
+        let (to_symbol, to_decimals) = self.get_token_info_for_transfer(&to_token_addr).await?;
+        let mut output_summary = format!(
+            "Expected Output: {} (raw: {})\n\
+            Minimum Output: {} (raw: {})",
+            format_token_amount(expected_out, to_decimals, &to_symbol), expected_out,
+            format_token_amount(amount_out_min, to_decimals, &to_symbol), amount_out_min
         );
         
+        // Compare the quote with the pair's spot price; a swap that moves it too far needs `force`
+        let impact = match self.get_pair_reserves(router_addr, from_token_addr, to_token_addr).await {
+            Ok((reserve_in, reserve_out)) => Some(price_impact(amount_wei, expected_out, reserve_in, reserve_out)),
+            Err(e) => {
+                warn!("⚠️  Could not read pair reserves, skipping the price impact check: {}", e.message);
+                None
+            }
+        };
+        if let Some(impact) = impact {
+            output_summary.push_str(&format!("\nPrice Impact: {:.2}%", impact));
+            info!("📉 Price impact: {:.2}% (limit {}bps)", impact, self.config.max_price_impact_bps);
+            
+            if let Some(warning) = price_impact_warning(impact, self.config.max_price_impact_bps) {
+                if !dry_run && !force.unwrap_or(false) {
+                    return Err(McpError::invalid_params(
+                        format!("{}\nSwap refused: try a smaller amount, or set force: true to swap anyway.", warning),
+                        Some(serde_json::json!({ "price_impact_pct": impact }))
+                    ));
+                }
+                output_summary = format!("{}\n\n{}", warning, output_summary);
+            }
+        }
+        
+        info!("📈 Expected output: {}, minimum after {}bps slippage: {}", 
+              expected_out, slippage_bps_value, amount_out_min);
+        
+        // Step 5: Calculate deadline (DEFAULT_DEADLINE_SECS past the latest block's timestamp)
+        let deadline_secs = self.chain_time().await? + self.config.default_deadline_secs;
+        let deadline = U256::from(deadline_secs);
+        
         info!("📊 Swap parameters - Amount: {} wei, Path: {:?}, Deadline: {}", 
               amount_wei, path, deadline);
         
@@ -847,19 +4315,101 @@ impl BlockchainService {
                 .into())
             .from(self.alice_address);
         
-        let tx = WithOtherFields::new(tx);
+        let mut tx = WithOtherFields::new(tx);
+        let fee_summary = self.apply_fee_mode(&mut tx).await?;
+        let mut result = SwapResult {
+            status: TxStatus::Simulated,
+            from: self.alice_address.to_string(),
+            from_token: from_token.clone(),
+            to_token: to_token.clone(),
+            dex: dex_name.clone(),
+            contract: router_address.clone(),
+            amount_in: amount.clone(),
+            amount_in_raw: amount_wei.to_string(),
+            expected_out_raw: Some(expected_out.to_string()),
+            minimum_out_raw: Some(amount_out_min.to_string()),
+            price_impact_pct: impact,
+            deadline: Some(deadline_secs),
+            deadline_passed: None,
+            tx_hash: None,
+            confirmation: None,
+        };
+        
+        if dry_run {
+            let simulation = self.simulate_transaction(&tx).await?;
+            let response_text = format!(
+                "Token Swap Simulation (dry run):\n\
+                From: {} (Alice)\n\
+                Swap: {} {} → {}\n\
+                DEX: {}\n\
+                Router: {}\n\
+                Amount: {} {} ({} wei)\n\
+                Path: {} → {}\n\
+                Slippage: {}%\n\
+                {}\n\
+                {}\n\
+                {}",
+                self.alice_address,
+                amount, from_token, to_token,
+                dex_name,
+                router_address,
+                amount, from_token, amount_wei,
+                from_token, to_token,
+                slippage_bps_value as f64 / 100.0,
+                output_summary,
+                fee_summary,
+                simulation
+            );
+            
+            info!("🔍 MCP Server swap_tokens dry run response: {}", response_text);
+            return structured_result(&result, response_text);
+        }
+        
+        self.ensure_expected_chain().await?;
         
-        // Create Cast instance and send transaction
-        let cast = Cast::new(self.provider.clone());
-        let pending_tx = cast.send(tx).await
-            .map_err(|e| McpError::internal_error(format!("Failed to send swap transaction: {}", e), None))?;
-        let tx_hash = *pending_tx.tx_hash();
+        // Broadcast the transaction, counting its value against the spend limits
+        self.reserve_spend(amount_wei)?;
+        let tx_hash = match self.chain.send_transaction(tx).await {
+            Ok(tx_hash) => tx_hash,
+            Err(e) => {
+                self.release_spend(amount_wei);
+                let reason = revert_reason_from_error(&e);
+                if is_deadline_expired(&reason) {
+                    // The block timestamp moved past the deadline between quoting and sending
+                    let now = self.chain_time().await.unwrap_or(deadline_secs);
+                    return Err(McpError::internal_error(format!(
+                        "Swap rejected: the router deadline expired ({}). Deadline: {}. \
+                        The node's block timestamp is already past it - check for evm_increaseTime/evm_setNextBlockTimestamp \
+                        on the fork, or raise DEFAULT_DEADLINE_SECS (currently {}s).",
+                        ROUTER_EXPIRED_REASON,
+                        describe_deadline(deadline_secs, now),
+                        self.config.default_deadline_secs
+                    ), None));
+                }
+                return Err(McpError::internal_error(format!("Failed to send swap transaction: {}", e), None));
+            }
+        };
         
         info!("📝 Swap transaction sent with hash: {}", tx_hash);
+        result.tx_hash = Some(tx_hash.to_string());
         
-        // Wait for transaction confirmation (30 second timeout)
-        match self.wait_for_transaction_confirmation(tx_hash, 30).await {
+        // Wait for transaction confirmation (configured timeout unless overridden per request)
+        match self.wait_for_transaction_confirmation(tx_hash, self.confirmation_timeout(confirmation_timeout)).await {
             Ok(confirmation_text) => {
+                let now = self.chain_time().await?;
+                result.deadline_passed = Some(now > deadline_secs);
+                let deadline_note = if confirmation_text.revert_reason.as_deref().is_some_and(is_deadline_expired) {
+                    format!(
+                        "\n\n⏰ Deadline Expired: the swap was mined after its deadline {} and the router rejected it \
+                        ({}), not a price or liquidity problem. Resubmit the swap, or raise DEFAULT_DEADLINE_SECS \
+                        (currently {}s) if confirmations are slow.",
+                        describe_deadline(deadline_secs, now),
+                        ROUTER_EXPIRED_REASON,
+                        self.config.default_deadline_secs
+                    )
+                } else {
+                    String::new()
+                };
                 let response_text = format!(
                     "Token Swap:\n\
                     From: {} (Alice)\n\
@@ -869,7 +4419,9 @@ impl BlockchainService {
                     Amount: {} {} ({} wei)\n\
                     Path: {} → {}\n\
                     Slippage: {}%\n\
-                    \n{}\n\n\
+                    {}\n\
+                    {}\n\
+                    \n{}{}\n\n\
                     💡 Note: This is a test transaction on forked mainnet.\n\
                     The swap will execute using real Uniswap V2 contracts.",
                     self.alice_address,
@@ -878,14 +4430,32 @@ impl BlockchainService {
                     router_address,
                     amount, from_token, amount_wei,
                     from_token, to_token,
-                    (slippage_bps.parse::<u32>().unwrap_or(500) as f64) / 100.0,
-                    confirmation_text
+                    slippage_bps_value as f64 / 100.0,
+                    output_summary,
+                    fee_summary,
+                    confirmation_text,
+                    deadline_note
                 );
                 
                 info!("🔍 MCP Server swap_tokens response: {}", response_text);
-                Ok(CallToolResult::success(vec![Content::text(response_text)]))
+                result.status = confirmation_text.status();
+                result.confirmation = Some(confirmation_text);
+                structured_result(&result, response_text)
             }
             Err(_e) => {
+                // Say whether the deadline has passed, so a slow network isn't mistaken for a short deadline
+                let now = self.chain_time().await?;
+                result.deadline_passed = Some(now > deadline_secs);
+                let deadline_hint = if now > deadline_secs {
+                    format!(
+                        "⏰ The swap deadline has already passed: if this transaction is mined now it will revert with {}. \
+                        Resubmit the swap rather than waiting, or raise DEFAULT_DEADLINE_SECS (currently {}s).",
+                        ROUTER_EXPIRED_REASON, self.config.default_deadline_secs
+                    )
+                } else {
+                    "The deadline has not passed yet, so the delay is the network rather than the deadline.".to_string()
+                };
+                
                 // If waiting fails, return the transaction hash for manual checking
                 let response_text = format!(
                     "Token Swap Sent:\n\
@@ -896,10 +4466,14 @@ impl BlockchainService {
                     Amount: {} {} ({} wei)\n\
                     Path: {} → {}\n\
                     Slippage: {}%\n\
+                    {}\n\
+                    {}\n\
                     Transaction Hash: {}\n\
                     Status: Sent to network (confirmation timeout)\n\
+                    Deadline: {}\n\
                     \n⚠️  Transaction was sent but confirmation timed out.\n\
-                    Use check_transaction_status with hash {} to check the final status.\n\n\
+                    {}\n\
+                    Use monitor_transaction with hash {} to follow it until it is mined.\n\n\
                     💡 Note: This is a test transaction on forked mainnet.\n\
                     The swap will execute using real Uniswap V2 contracts.",
                     self.alice_address,
@@ -908,19 +4482,24 @@ impl BlockchainService {
                     router_address,
                     amount, from_token, amount_wei,
                     from_token, to_token,
-                    (slippage_bps.parse::<u32>().unwrap_or(500) as f64) / 100.0,
+                    slippage_bps_value as f64 / 100.0,
+                    output_summary,
+                    fee_summary,
                     tx_hash,
+                    describe_deadline(deadline_secs, now),
+                    deadline_hint,
                     tx_hash
                 );
                 
                 info!("⚠️  MCP Server swap_tokens response (timeout): {}", response_text);
-                Ok(CallToolResult::success(vec![Content::text(response_text)]))
+                result.status = TxStatus::Pending;
+                structured_result(&result, response_text)
             }
         }
     }
 
     /// Direct ETH to WETH swap using WETH contract
-    async fn swap_eth_to_weth_direct(&self, amount: String) -> Result<CallToolResult, McpError> {
+    async fn swap_eth_to_weth_direct(&self, amount: String, dry_run: bool, confirmation_timeout: Option<u64>) -> Result<CallToolResult, McpError> {
         info!("🎯 Executing direct ETH to WETH swap for {} ETH", amount);
         
         // Look up WETH contract address
@@ -935,6 +4514,7 @@ impl BlockchainService {
         
         // Step 2: Calculate amount in wei
         let amount_wei = self.parse_amount_to_wei(&amount, "ETH").await?;
+        self.check_spend_limits(amount_wei)?;
         
         info!("💰 Amount to wrap: {} ETH ({} wei)", amount, amount_wei);
         
@@ -948,21 +4528,68 @@ impl BlockchainService {
         let tx = TransactionRequest::default()
             .to(weth_addr)
             .value(amount_wei) // Send ETH with the transaction
-            .input(Bytes::from(hex::decode(&calldata[2..]).unwrap()).into())
+            .input(Bytes::from_str(&calldata)
+                .map_err(|e| McpError::internal_error(format!("Failed to parse calldata: {}", e), None))?.into())
             .from(self.alice_address);
         
-        let tx = WithOtherFields::new(tx);
+        let mut tx = WithOtherFields::new(tx);
+        let fee_summary = self.apply_fee_mode(&mut tx).await?;
+        let mut result = SwapResult {
+            status: TxStatus::Simulated,
+            from: self.alice_address.to_string(),
+            from_token: "ETH".to_string(),
+            to_token: "WETH".to_string(),
+            dex: "WETH.deposit()".to_string(),
+            contract: weth_address.clone(),
+            amount_in: amount.clone(),
+            amount_in_raw: amount_wei.to_string(),
+            expected_out_raw: Some(amount_wei.to_string()),
+            minimum_out_raw: Some(amount_wei.to_string()),
+            price_impact_pct: None,
+            deadline: None,
+            deadline_passed: None,
+            tx_hash: None,
+            confirmation: None,
+        };
+        
+        if dry_run {
+            let simulation = self.simulate_transaction(&tx).await?;
+            let response_text = format!(
+                "ETH to WETH Swap Simulation (dry run):\n\
+                From: {} (Alice)\n\
+                Swap: {} ETH → {} WETH\n\
+                WETH Contract: {}\n\
+                Amount: {} ETH ({} wei)\n\
+                Method: WETH.deposit()\n\
+                {}\n\
+                {}",
+                self.alice_address,
+                amount, amount,
+                weth_address,
+                amount, amount_wei,
+                fee_summary,
+                simulation
+            );
+            
+            info!("🔍 MCP Server swap_eth_to_weth_direct dry run response: {}", response_text);
+            return structured_result(&result, response_text);
+        }
+        
+        self.ensure_expected_chain().await?;
         
-        // Create Cast instance and send transaction
-        let cast = Cast::new(self.provider.clone());
-        let pending_tx = cast.send(tx).await
-            .map_err(|e| McpError::internal_error(format!("Failed to send ETH to WETH transaction: {}", e), None))?;
-        let tx_hash = *pending_tx.tx_hash();
+        // Broadcast the transaction, counting its value against the spend limits
+        self.reserve_spend(amount_wei)?;
+        let tx_hash = self.chain.send_transaction(tx).await
+            .map_err(|e| {
+                self.release_spend(amount_wei);
+                McpError::internal_error(format!("Failed to send ETH to WETH transaction: {}", e), None)
+            })?;
         
         info!("📝 ETH to WETH transaction sent with hash: {}", tx_hash);
+        result.tx_hash = Some(tx_hash.to_string());
         
-        // Wait for transaction confirmation (30 second timeout)
-        match self.wait_for_transaction_confirmation(tx_hash, 30).await {
+        // Wait for transaction confirmation (configured timeout unless overridden per request)
+        match self.wait_for_transaction_confirmation(tx_hash, self.confirmation_timeout(confirmation_timeout)).await {
             Ok(confirmation_text) => {
                 let response_text = format!(
                     "ETH to WETH Swap (Direct):\n\
@@ -971,6 +4598,7 @@ impl BlockchainService {
                     WETH Contract: {}\n\
                     Amount: {} ETH ({} wei)\n\
                     Method: WETH.deposit()\n\
+                    {}\n\
                     \n{}\n\n\
                     💡 Note: This is a test transaction on forked mainnet.\n\
                     The ETH has been wrapped into WETH using the official WETH contract.\n\
@@ -979,11 +4607,14 @@ impl BlockchainService {
                     amount, amount,
                     weth_address,
                     amount, amount_wei,
+                    fee_summary,
                     confirmation_text
                 );
                 
                 info!("🔍 MCP Server swap_eth_to_weth_direct response: {}", response_text);
-                Ok(CallToolResult::success(vec![Content::text(response_text)]))
+                result.status = confirmation_text.status();
+                result.confirmation = Some(confirmation_text);
+                structured_result(&result, response_text)
             }
             Err(_e) => {
                 // If waiting fails, return the transaction hash for manual checking
@@ -994,28 +4625,31 @@ impl BlockchainService {
                     WETH Contract: {}\n\
                     Amount: {} ETH ({} wei)\n\
                     Method: WETH.deposit()\n\
+                    {}\n\
                     Transaction Hash: {}\n\
                     Status: Sent to network (confirmation timeout)\n\
                     \n⚠️  Transaction was sent but confirmation timed out.\n\
-                    Use check_transaction_status with hash {} to check the final status.\n\n\
+                    Use monitor_transaction with hash {} to follow it until it is mined.\n\n\
                     💡 Note: This is a test transaction on forked mainnet.\n\
                     The ETH will be wrapped into WETH using the official WETH contract.",
                     self.alice_address,
                     amount, amount,
                     weth_address,
                     amount, amount_wei,
+                    fee_summary,
                     tx_hash,
                     tx_hash
                 );
                 
                 info!("⚠️  MCP Server swap_eth_to_weth_direct response (timeout): {}", response_text);
-                Ok(CallToolResult::success(vec![Content::text(response_text)]))
+                result.status = TxStatus::Pending;
+                structured_result(&result, response_text)
             }
         }
     }
 
     /// Direct WETH to ETH swap using WETH contract
-    async fn swap_weth_to_eth_direct(&self, amount: String) -> Result<CallToolResult, McpError> {
+    async fn swap_weth_to_eth_direct(&self, amount: String, dry_run: bool, confirmation_timeout: Option<u64>) -> Result<CallToolResult, McpError> {
         info!("🎯 Executing direct WETH to ETH swap for {} WETH", amount);
         
         // Look up WETH contract address
@@ -1042,21 +4676,64 @@ impl BlockchainService {
         // Step 4: Create and send transaction using Cast
         let tx = TransactionRequest::default()
             .to(weth_addr)
-            .input(Bytes::from(hex::decode(&calldata[2..]).unwrap()).into())
+            .input(Bytes::from_str(&calldata)
+                .map_err(|e| McpError::internal_error(format!("Failed to parse calldata: {}", e), None))?.into())
             .from(self.alice_address);
         
-        let tx = WithOtherFields::new(tx);
+        let mut tx = WithOtherFields::new(tx);
+        let fee_summary = self.apply_fee_mode(&mut tx).await?;
+        let mut result = SwapResult {
+            status: TxStatus::Simulated,
+            from: self.alice_address.to_string(),
+            from_token: "WETH".to_string(),
+            to_token: "ETH".to_string(),
+            dex: "WETH.withdraw()".to_string(),
+            contract: weth_address.clone(),
+            amount_in: amount.clone(),
+            amount_in_raw: amount_wei.to_string(),
+            expected_out_raw: Some(amount_wei.to_string()),
+            minimum_out_raw: Some(amount_wei.to_string()),
+            price_impact_pct: None,
+            deadline: None,
+            deadline_passed: None,
+            tx_hash: None,
+            confirmation: None,
+        };
+        
+        if dry_run {
+            let simulation = self.simulate_transaction(&tx).await?;
+            let response_text = format!(
+                "WETH to ETH Swap Simulation (dry run):\n\
+                From: {} (Alice)\n\
+                Swap: {} WETH → {} ETH\n\
+                WETH Contract: {}\n\
+                Amount: {} WETH ({} wei)\n\
+                Method: WETH.withdraw()\n\
+                {}\n\
+                {}",
+                self.alice_address,
+                amount, amount,
+                weth_address,
+                amount, amount_wei,
+                fee_summary,
+                simulation
+            );
+            
+            info!("🔍 MCP Server swap_weth_to_eth_direct dry run response: {}", response_text);
+            return structured_result(&result, response_text);
+        }
         
-        // Create Cast instance and send transaction
-        let cast = Cast::new(self.provider.clone());
-        let pending_tx = cast.send(tx).await
+        self.ensure_expected_chain().await?;
+        
+        // Broadcast the transaction
+        let tx_hash = self.chain.send_transaction(tx).await
             .map_err(|e| McpError::internal_error(format!("Failed to send WETH to ETH transaction: {}", e), None))?;
-        let tx_hash = *pending_tx.tx_hash();
         
         info!("📝 WETH to ETH transaction sent with hash: {}", tx_hash);
+        result.tx_hash = Some(tx_hash.to_string());
         
-        // Wait for transaction confirmation (30 second timeout)
-        match self.wait_for_transaction_confirmation(tx_hash, 30).await {
+        // Wait for transaction confirmation (configured timeout unless overridden per request)
+        match self.wait_for_transaction_confirmation(tx_hash, self.confirmation_timeout(confirmation_timeout)).await {
             Ok(confirmation_text) => {
                 let response_text = format!(
                     "WETH to ETH Swap (Direct):\n\
@@ -1065,6 +4742,7 @@ impl BlockchainService {
                     WETH Contract: {}\n\
                     Amount: {} WETH ({} wei)\n\
                     Method: WETH.withdraw()\n\
+                    {}\n\
                     \n{}\n\n\
                     💡 Note: This is a test transaction on forked mainnet.\n\
                     The WETH has been unwrapped into ETH using the official WETH contract.",
@@ -1072,11 +4750,14 @@ impl BlockchainService {
                     amount, amount,
                     weth_address,
                     amount, amount_wei,
+                    fee_summary,
                     confirmation_text
                 );
                 
                 info!("🔍 MCP Server swap_weth_to_eth_direct response: {}", response_text);
-                Ok(CallToolResult::success(vec![Content::text(response_text)]))
+                result.status = confirmation_text.status();
+                result.confirmation = Some(confirmation_text);
+                structured_result(&result, response_text)
             }
             Err(_e) => {
                 // If waiting fails, return the transaction hash for manual checking
@@ -1087,22 +4768,25 @@ impl BlockchainService {
                     WETH Contract: {}\n\
                     Amount: {} WETH ({} wei)\n\
                     Method: WETH.withdraw()\n\
+                    {}\n\
                     Transaction Hash: {}\n\
                     Status: Sent to network (confirmation timeout)\n\
                     \n⚠️  Transaction was sent but confirmation timed out.\n\
-                    Use check_transaction_status with hash {} to check the final status.\n\n\
+                    Use monitor_transaction with hash {} to follow it until it is mined.\n\n\
                     💡 Note: This is a test transaction on forked mainnet.\n\
                     The WETH will be unwrapped into ETH using the official WETH contract.",
                     self.alice_address,
                     amount, amount,
                     weth_address,
                     amount, amount_wei,
+                    fee_summary,
                     tx_hash,
                     tx_hash
                 );
                 
                 info!("⚠️  MCP Server swap_weth_to_eth_direct response (timeout): {}", response_text);
-                Ok(CallToolResult::success(vec![Content::text(response_text)]))
+                result.status = TxStatus::Pending;
+                structured_result(&result, response_text)
             }
         }
     }
@@ -1110,127 +4794,698 @@ impl BlockchainService {
     /// Get default addresses as specified in PRD
     #[tool(description = "Get the default sender and recipient addresses as specified in PRD")]
     pub async fn get_default_addresses(&self) -> Result<CallToolResult, McpError> {
+        let sender = self.config.default_sender.display_name();
+        let sender_index = self.config.default_sender.index;
+        let recipient = self.config.default_recipient.display_name();
+        let recipient_index = self.config.default_recipient.index;
         let response = format!(
             "Default Addresses (PRD Configuration):\n\n\
-            👤 Alice (Account 0 - Default Sender):\n\
+            👤 {sender} (Account {sender_index} - Default Sender):\n\
             Address: {}\n\
             Private Key: {}\n\
             Status: {}\n\n\
-            👤 Bob (Account 1 - Default Recipient):\n\
+            👤 {recipient} (Account {recipient_index} - Default Recipient):\n\
             Address: {}\n\
             Private Key: Not available (for security)\n\n\
             📋 Usage:\n\
-            • Alice (Account 0) is the default sender for all transactions\n\
-            • Bob (Account 1) is the default recipient when not specified\n\
+            • {sender} (Account {sender_index}) is the default sender for all transactions\n\
+            • {recipient} (Account {recipient_index}) is the default recipient when not specified\n\
             • Addresses are dynamically loaded from anvil (PRD requirement)\n\
-            • Alice's private key must be set in environment for transactions\n\n\
+            • {sender}'s private key must be set in environment for transactions\n\n\
             🔧 Configuration:\n\
-            • Alice: Account 0 from anvil (default sender)\n\
-            • Bob: Account 1 from anvil (default recipient)\n\
+            • {sender}: Account {sender_index} from anvil (default sender, DEFAULT_SENDER_NAME / DEFAULT_SENDER_INDEX)\n\
+            • {recipient}: Account {recipient_index} from anvil (default recipient, DEFAULT_RECIPIENT_NAME / DEFAULT_RECIPIENT_INDEX)\n\
             • ALICE_PRIVATE_KEY: [set in .env file]\n\n\
             💡 Example Commands:\n\
-            • \"send 1 ETH from Alice to Bob\"\n\
-            • \"send 0.5 ETH to Bob\" (Alice is default sender)\n\
-            • \"How much ETH does Alice have?\"\n\n\
+            • \"send 1 ETH from {sender} to {recipient}\"\n\
+            • \"send 0.5 ETH to {recipient}\" ({sender} is default sender)\n\
+            • \"How much ETH does {sender} have?\"\n\n\
             📊 Anvil Accounts Loaded: {}",
             self.alice_address,
             if self.alice_private_key.is_empty() { "NOT SET" } else { "SET" },
             if self.alice_private_key.is_empty() { "❌ Transactions disabled" } else { "✅ Transactions enabled" },
-            self.bob_address,
+            self.bob_address.map(|address| address.to_string())
+                .unwrap_or_else(|| format!("Not available (only {} account(s) loaded)", self.anvil_accounts.len())),
             self.anvil_accounts.len()
         );
         
-        Ok(CallToolResult::success(vec![Content::text(response)]))
+        Ok(CallToolResult::success(vec![Content::text(response)]))
+    }
+
+    /// Helper method to get token addresses for common tokens
+    async fn get_token_addresses(&self, from_token: &str, to_token: &str) -> Result<(Address, Address), McpError> {
+        info!("🔍 Getting token addresses for {} → {}", from_token, to_token);
+        
+        // Try to get addresses from cache or search
+        let from_addr = if let Ok(addr) = Address::from_str(from_token) {
+            // Direct address provided
+            addr
+        } else {
+            // Try cache/search
+            match self.search_token_address(from_token).await? {
+                Some(addr) => addr,
+                None => {
+                    return Err(McpError::invalid_params(
+                        format!(
+                            "Could not find contract address for token: {}.\n\
+                            The token was not found in cache and web search returned no results.\n\
+                            Please provide the contract address directly (e.g., '0x...').",
+                            from_token
+                        ),
+                        None
+                    ));
+                }
+            }
+        };
+
+        let to_addr = if let Ok(addr) = Address::from_str(to_token) {
+            // Direct address provided
+            addr
+        } else {
+            // Try cache/search
+            match self.search_token_address(to_token).await? {
+                Some(addr) => addr,
+                None => {
+                    return Err(McpError::invalid_params(
+                        format!(
+                            "Could not find contract address for token: {}.\n\
+                            The token was not found in cache and web search returned no results.\n\
+                            Please provide the contract address directly (e.g., '0x...').",
+                            to_token
+                        ),
+                        None
+                    ));
+                }
+            }
+        };
+
+        info!("✅ Found addresses: {} → {}", from_addr, to_addr);
+        Ok((from_addr, to_addr))
+    }
+
+    /// Helper method to parse amount to wei
+    async fn parse_amount_to_wei(&self, amount: &str, _token: &str) -> Result<U256, McpError> {
+        // ETH and WETH both use 18 decimals
+        parse_eth_to_wei(amount)
+    }
+
+    /// Helper method to encode swapExactETHForTokens function call
+    async fn encode_swap_exact_eth_for_tokens(
+        &self,
+        amount_out_min: U256,
+        path: &[Address],
+        to: Address,
+        deadline: U256,
+    ) -> Result<String, McpError> {
+        // Use Cast to encode swapExactETHForTokens
+        // Convert path addresses to strings
+        let path_strings: Vec<String> = path.iter()
+            .map(|addr| addr.to_string())
+            .collect();
+            
+        let args = vec![
+            amount_out_min.to_string(),
+            format!("[{}]", path_strings.join(",")),
+            to.to_string(),
+            deadline.to_string(),
+        ];
+        
+        let calldata = SimpleCast::calldata_encode(
+            "swapExactETHForTokens(uint256,address[],address,uint256)",
+            &args
+        ).map_err(|e| McpError::internal_error(format!("Failed to encode swap call: {}", e), None))?;
+        
+        // calldata_encode already returns a 0x-prefixed hex string
+        Ok(calldata)
+    }
+
+    /// Query the router's getAmountsOut for the expected output of a swap along `path`
+    async fn get_amounts_out(&self, router_addr: Address, amount_in: U256, path: &[Address]) -> Result<U256, McpError> {
+        let path_strings: Vec<String> = path.iter()
+            .map(|addr| addr.to_string())
+            .collect();
+        
+        let calldata = SimpleCast::calldata_encode(
+            "getAmountsOut(uint256,address[])",
+            &[amount_in.to_string(), format!("[{}]", path_strings.join(","))]
+        ).map_err(|e| McpError::internal_error(format!("Failed to encode getAmountsOut call: {}", e), None))?;
+        
+        let call_request = WithOtherFields::new(TransactionRequest::default()
+            .to(router_addr)
+            .input(Bytes::from_str(&calldata)
+                .map_err(|e| McpError::internal_error(format!("Failed to parse calldata: {}", e), None))?.into()));
+        
+        let result = self.retry_read(|| self.chain.call(call_request.clone())).await
+            .map_err(|e| McpError::internal_error(format!("Failed to query getAmountsOut (is there liquidity for this pair?): {}", e), None))?;
+        
+        // Result is a dynamic uint256[]: offset, length, then one amount per path hop.
+        // The last element is the output amount for the final token.
+        if result.len() < 64 + 32 * path.len() {
+            return Err(McpError::internal_error(
+                format!("Unexpected getAmountsOut response length: {} bytes", result.len()),
+                None
+            ));
+        }
+        Ok(U256::from_be_slice(&result[result.len()-32..]))
+    }
+
+    /// Read the Uniswap V2 pair reserves for a swap, as `(reserve_in, reserve_out)`
+    ///
+    /// The pair is looked up through the router's factory; pairs store reserves in
+    /// sorted token order, so they are swapped when `token_in` is the larger address.
+    async fn get_pair_reserves(&self, router_addr: Address, token_in: Address, token_out: Address) -> Result<(U256, U256), McpError> {
+        let address_word = |bytes: &Bytes, what: &str| {
+            if bytes.len() < 32 {
+                return Err(McpError::internal_error(format!("Unexpected {} response length: {} bytes", what, bytes.len()), None));
+            }
+            Ok(Address::from_slice(&bytes[12..32]))
+        };
+        
+        let factory = address_word(&self.call_raw(router_addr, "factory()", &[]).await?, "factory")?;
+        let pair = address_word(
+            &self.call_raw(factory, "getPair(address,address)", &[token_in.to_string(), token_out.to_string()]).await?,
+            "getPair"
+        )?;
+        if pair == Address::ZERO {
+            return Err(McpError::invalid_params(format!("No Uniswap V2 pair exists for {} and {}", token_in, token_out), None));
+        }
+        
+        // getReserves() returns (uint112 reserve0, uint112 reserve1, uint32 blockTimestampLast)
+        let reserves = self.call_raw(pair, "getReserves()", &[]).await?;
+        if reserves.len() < 64 {
+            return Err(McpError::internal_error(format!("Unexpected getReserves response length: {} bytes", reserves.len()), None));
+        }
+        let reserve0 = U256::from_be_slice(&reserves[0..32]);
+        let reserve1 = U256::from_be_slice(&reserves[32..64]);
+        Ok(if token_in < token_out { (reserve0, reserve1) } else { (reserve1, reserve0) })
+    }
+
+    /// Read a token's USD price from its Chainlink aggregator
+    #[tool(description = "Get the authoritative on-chain USD price of a token (e.g., ETH, WBTC, USDC) from its Chainlink price feed - prefer this over web search for prices")]
+    pub async fn get_onchain_price(
+        &self,
+        Parameters(OnchainPriceRequest { symbol }): Parameters<OnchainPriceRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("📈 MCP Server: get_onchain_price called with symbol={}", symbol);
+        
+        let symbol = symbol.trim().to_uppercase();
+        let feed = self.addresses.price_feed(&symbol).ok_or_else(|| {
+            let mut known: Vec<&str> = self.addresses.price_feeds.keys().map(String::as_str).collect();
+            known.sort();
+            McpError::invalid_params(
+                format!(
+                    "No Chainlink price feed configured for '{}' on chain {}. Known feeds: {}. \
+                    Add one under price_feeds in NETWORK_ADDRESSES_FILE.",
+                    symbol, self.chain_id, if known.is_empty() { "none".to_string() } else { known.join(", ") }
+                ),
+                None
+            )
+        })?;
+        
+        // latestRoundData() returns (roundId, answer, startedAt, updatedAt, answeredInRound)
+        let calldata = SimpleCast::calldata_encode("latestRoundData()", &[] as &[&str])
+            .map_err(|e| McpError::internal_error(format!("Failed to encode latestRoundData call: {}", e), None))?;
+        let call_request = WithOtherFields::new(TransactionRequest::default()
+            .to(feed)
+            .input(Bytes::from_str(&calldata)
+                .map_err(|e| McpError::internal_error(format!("Failed to parse calldata: {}", e), None))?.into()));
+        let round_data = self.retry_read(|| self.chain.call(call_request.clone())).await
+            .map_err(|e| McpError::internal_error(format!("Failed to read Chainlink feed {}: {}", feed, e), None))?;
+        if round_data.len() < 160 {
+            return Err(McpError::internal_error(
+                format!("Unexpected latestRoundData response from {} ({} bytes) - is it a Chainlink aggregator?", feed, round_data.len()),
+                None
+            ));
+        }
+        let word = |index: usize| U256::from_be_slice(&round_data[index * 32..(index + 1) * 32]);
+        let (round_id, answer, updated_at) = (word(0), I256::from_raw(word(1)), word(3));
+        
+        if answer <= I256::ZERO {
+            return Err(McpError::internal_error(
+                format!("Chainlink feed {} for {} reported a non-positive answer ({})", feed, symbol, answer),
+                None
+            ));
+        }
+        
+        let decimals = self.call_token_uint(&feed, "decimals()", &[], BlockId::latest()).await?;
+        let decimals = u8::try_from(decimals)
+            .map_err(|_| McpError::internal_error(format!("Invalid decimals() from Chainlink feed {}: {}", feed, decimals), None))?;
+        
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_secs())
+            .unwrap_or_default();
+        let updated_at = updated_at.saturating_to::<u64>();
+        
+        let response_text = format!(
+            "Chainlink Price:\n\
+            Token: {}\n\
+            Price: {}\n\
+            Feed: {} ({} decimals)\n\
+            Round: {}\n\
+            Updated At: {} ({}s ago)",
+            symbol,
+            format_token_amount(answer.into_raw(), decimals, "USD"),
+            feed, decimals,
+            round_id,
+            updated_at, now.saturating_sub(updated_at)
+        );
+        
+        info!("🔍 MCP Server get_onchain_price response: {}", response_text);
+        Ok(CallToolResult::success(vec![Content::text(response_text)]))
+    }
+
+    /// Convert an amount between wei, gwei and ether
+    #[tool(description = "Convert an amount between wei, gwei and ether (e.g., how many gwei is 0.00003 ETH)")]
+    pub async fn convert_units(
+        &self,
+        Parameters(UnitConversionRequest { value, from, to }): Parameters<UnitConversionRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("🔁 MCP Server: convert_units called with value={}, from={}, to={}", value, from, to);
+        
+        let (converted, wei) = convert_eth_units(&value, &from, &to)?;
+        let response_text = format!(
+            "Unit Conversion:\n\
+            Input: {} {}\n\
+            Result: {} {}\n\
+            In Wei: {} wei",
+            value.trim(),
+            eth_unit(&from)?,
+            converted,
+            eth_unit(&to)?,
+            wei
+        );
+        
+        Ok(CallToolResult::success(vec![Content::text(response_text)]))
+    }
+
+    /// Quote a Uniswap V2 swap from pair reserves with constant-product math
+    #[tool(description = "Calculate the expected output, minimum output after slippage and price impact of a Uniswap V2 swap from the pair's reserves (x*y=k with the 0.3% fee) - e.g., how much USDC 10 ETH buys at 0.5% slippage")]
+    pub async fn calculate_swap_output(
+        &self,
+        Parameters(SwapOutputRequest { amount_in, reserve_in, reserve_out, slippage, decimals_in, decimals_out }): Parameters<SwapOutputRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("🧮 MCP Server: calculate_swap_output called with amount_in={}, reserve_in={}, reserve_out={}, slippage={:?}",
+              amount_in, reserve_in, reserve_out, slippage);
+
+        let decimals_in = decimals_in.unwrap_or(18);
+        let decimals_out = decimals_out.unwrap_or(18);
+        let slippage_bps = match slippage.as_deref() {
+            Some(slippage) => parse_slippage_bps(slippage)?,
+            None => self.config.default_slippage_bps,
+        };
+
+        let amount_in_raw = parse_token_amount(&amount_in, decimals_in, "input token")?;
+        let reserve_in_raw = parse_token_amount(&reserve_in, decimals_in, "input reserve")?;
+        let reserve_out_raw = parse_token_amount(&reserve_out, decimals_out, "output reserve")?;
+
+        let (expected_out, min_out, price_impact) = calculate_slippage(amount_in_raw, reserve_in_raw, reserve_out_raw, slippage_bps)?;
+        let units = |raw: U256, decimals: u8| format_token_amount(raw, decimals, "").trim_end().to_string();
+        let response_text = format!(
+            "Swap Output (Uniswap V2, 0.3% fee):\n\
+            Amount In: {}\n\
+            Expected Out: {} ({} base units)\n\
+            Minimum Out ({:.2}% slippage): {} ({} base units)\n\
+            Price Impact: {:.2}%",
+            units(amount_in_raw, decimals_in),
+            units(expected_out, decimals_out),
+            expected_out,
+            slippage_bps as f64 / 100.0,
+            units(min_out, decimals_out),
+            min_out,
+            price_impact
+        );
+
+        Ok(CallToolResult::success(vec![Content::text(response_text)]))
+    }
+
+    /// Get the current block number, chain id and gas price
+    #[tool(description = "Get current chain status - latest block number, chain id and gas price in gwei")]
+    pub async fn get_chain_status(&self) -> Result<CallToolResult, McpError> {
+        info!("📡 MCP Server: get_chain_status called");
+        
+        let block_number = self.limited(self.provider.get_block_number()).await
+            .map_err(|e| McpError::internal_error(format!("Failed to get block number: {}", e), None))?;
+        let chain_id = self.limited(self.provider.get_chain_id()).await
+            .map_err(|e| McpError::internal_error(format!("Failed to get chain id: {}", e), None))?;
+        let gas_price = self.limited(self.provider.get_gas_price()).await
+            .map_err(|e| McpError::internal_error(format!("Failed to get gas price: {}", e), None))?;
+        
+        let response_text = format_chain_status(block_number, chain_id, gas_price);
+        
+        info!("🔍 MCP Server get_chain_status response: {}", response_text);
+        Ok(CallToolResult::success(vec![Content::text(response_text)]))
+    }
+
+    /// Lightweight liveness probe for the server and its provider connection
+    #[tool(description = "Health check - server uptime, provider connectivity, chain id, loaded accounts and private key availability")]
+    pub async fn health_check(&self) -> Result<CallToolResult, McpError> {
+        info!("🩺 MCP Server: health_check called");
+        
+        let block_number = self.chain.get_block_number().await.map_err(|e| e.to_string());
+        if let Err(e) = &block_number {
+            warn!("⚠️  Health check: provider unreachable: {}", e);
+        }
+        // Ask the node rather than reporting the chain id cached at startup
+        let chain_id = self.chain.get_chain_id().await.map_err(|e| e.to_string());
+        if let Ok(live_chain_id) = chain_id
+            && live_chain_id != self.chain_id
+        {
+            warn!("⚠️  Health check: node reports chain id {} but the server started on {}", live_chain_id, self.chain_id);
+        }
+        
+        let response_text = format_health_check(
+            self.started_at.elapsed(),
+            block_number,
+            chain_id,
+            self.anvil_accounts.len(),
+            !self.alice_private_key.is_empty(),
+        );
+        
+        info!("🔍 MCP Server health_check response: {}", response_text);
+        Ok(CallToolResult::success(vec![Content::text(response_text)]))
+    }
+
+    /// Get the latest and pending nonce for an address
+    #[tool(description = "Get the latest and pending nonce for an address - shows whether transactions are queued")]
+    pub async fn get_nonce(
+        &self,
+        Parameters(NonceRequest { address }): Parameters<NonceRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("🔢 MCP Server: get_nonce called with address={}", address);
+        
+        let validated_address = self.validate_recipient_address(&address).await?;
+        let addr = validated_address.resolved_address;
+        
+        let latest_nonce = self.retry_read(|| self.chain.get_transaction_count(addr, BlockId::latest())).await
+            .map_err(|e| McpError::internal_error(format!("Failed to get latest nonce: {}", e), None))?;
+        let pending_nonce = self.retry_read(|| self.chain.get_transaction_count(addr, BlockId::pending())).await
+            .map_err(|e| McpError::internal_error(format!("Failed to get pending nonce: {}", e), None))?;
+        
+        let queue_note = if pending_nonce > latest_nonce {
+            format!(
+                "⚠️  {} transaction(s) are queued and not yet mined.\n\
+                New transactions from this address will wait behind them - use get_pending_transactions to inspect the queue.",
+                pending_nonce - latest_nonce
+            )
+        } else {
+            "✅ No queued transactions - the next transaction will use the latest nonce.".to_string()
+        };
+        
+        let response_text = format!(
+            "Nonce Information:\n\
+            Address: {} ({})\n\
+            Latest Nonce: {}\n\
+            Pending Nonce: {}\n\n{}",
+            validated_address.address,
+            validated_address.address_type,
+            latest_nonce,
+            pending_nonce,
+            queue_note
+        );
+        
+        info!("✅ Nonce for {}: latest={}, pending={}", addr, latest_nonce, pending_nonce);
+        Ok(CallToolResult::success(vec![Content::text(response_text)]))
+    }
+
+    /// Set an account's ETH balance with anvil's anvil_setBalance cheatcode
+    #[tool(description = "Set an account's ETH balance on an anvil node (anvil_setBalance) - for funding test accounts, not supported on real networks")]
+    pub async fn fund_account(
+        &self,
+        Parameters(FundAccountRequest { address, amount }): Parameters<FundAccountRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("💰 MCP Server: fund_account called with address={}, amount={}", address, amount);
+        
+        let validated_address = self.validate_recipient_address(&address).await?;
+        let addr = validated_address.resolved_address;
+        let amount_wei = parse_eth_to_wei(&amount)?;
+        
+        let previous_balance = self.retry_read(|| self.chain.get_balance(addr)).await
+            .map_err(|e| McpError::internal_error(format!("Failed to get balance: {}", e), None))?;
+        
+        self.anvil_request("fund_account", "anvil_setBalance", (addr, amount_wei)).await?;
+        
+        let new_balance = self.retry_read(|| self.chain.get_balance(addr)).await
+            .map_err(|e| McpError::internal_error(format!("Failed to get balance: {}", e), None))?;
+        
+        let response_text = format!(
+            "Account Funded (anvil_setBalance):\n\
+            Account: {} ({})\n\
+            Resolved Address: {}\n\
+            Previous Balance: {}\n\
+            New Balance: {} ({} wei)",
+            validated_address.address,
+            validated_address.address_type,
+            addr,
+            format_token_amount(previous_balance, 18, "ETH"),
+            format_token_amount(new_balance, 18, "ETH"),
+            new_balance
+        );
+        
+        info!("✅ Set balance of {} to {} wei", addr, new_balance);
+        Ok(CallToolResult::success(vec![Content::text(response_text)]))
     }
 
-    /// Helper method to get token addresses for common tokens
-    async fn get_token_addresses(&self, from_token: &str, to_token: &str) -> Result<(Address, Address), McpError> {
-        info!("🔍 Getting token addresses for {} → {}", from_token, to_token);
+    /// Send ETH or ERC-20 tokens from any address by impersonating it on anvil
+    #[tool(description = "Send ETH or ERC-20 tokens from any address (e.g., a whale on a mainnet fork) without its private key, using anvil impersonation - test-only, requires ALLOW_IMPERSONATION=true")]
+    pub async fn impersonate_and_send(
+        &self,
+        Parameters(ImpersonatedTransferRequest { from, to, amount, token_address, confirmation_timeout }): Parameters<ImpersonatedTransferRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("🎭 MCP Server: impersonate_and_send called with from={}, to={}, amount={}, token={:?}", from, to, amount, token_address);
         
-        // Try to get addresses from cache or search
-        let from_addr = if let Ok(addr) = Address::from_str(from_token) {
-            // Direct address provided
-            addr
-        } else {
-            // Try cache/search
-            match self.search_token_address(from_token).await? {
-                Some(addr) => addr,
-                None => {
-                    return Err(McpError::invalid_params(
-                        format!(
-                            "Could not find contract address for token: {}.\n\
-                            The token was not found in cache and web search returned no results.\n\
-                            Please provide the contract address directly (e.g., '0x...').",
-                            from_token
-                        ),
-                        None
-                    ));
-                }
+        if !self.config.allow_impersonation {
+            return Err(McpError::invalid_params(
+                "impersonate_and_send is disabled. It is a test-only capability for anvil nodes; \
+                set ALLOW_IMPERSONATION=true in the MCP server environment to enable it.".to_string(),
+                None
+            ));
+        }
+        
+        let validated_sender = self.validate_recipient_address(&from).await?;
+        let validated_recipient = self.validate_recipient_address(&to).await?;
+        let sender = validated_sender.resolved_address;
+        
+        // Build the transfer: plain value for ETH, transfer(address,uint256) for tokens
+        let (tx, amount_summary) = match token_address.as_deref().map(str::trim).filter(|token| !token.is_empty()) {
+            None => {
+                let amount_wei = parse_eth_to_wei(&amount)?;
+                let tx = TransactionRequest::default()
+                    .to(validated_recipient.resolved_address)
+                    .value(amount_wei)
+                    .from(sender);
+                (tx, format!("Amount: {}", format_token_amount(amount_wei, 18, "ETH")))
+            }
+            Some(token) => {
+                let token_addr = Address::from_str(token)
+                    .map_err(|e| McpError::invalid_params(format!("Invalid token address: {}", e), None))?;
+                let (symbol, decimals) = self.get_token_info_for_transfer(&token_addr).await?;
+                let amount_raw = parse_token_amount(&amount, decimals, &symbol)?;
+                let calldata = SimpleCast::calldata_encode(
+                    "transfer(address,uint256)",
+                    &[validated_recipient.resolved_address.to_string(), amount_raw.to_string()]
+                ).map_err(|e| McpError::internal_error(format!("Failed to encode transfer call: {}", e), None))?;
+                let tx = TransactionRequest::default()
+                    .to(token_addr)
+                    .input(Bytes::from_str(&calldata)
+                        .map_err(|e| McpError::internal_error(format!("Failed to parse calldata: {}", e), None))?.into())
+                    .from(sender);
+                (tx, format!(
+                    "Token: {} ({})\nAmount: {} (raw: {})",
+                    token_addr, symbol, format_token_amount(amount_raw, decimals, &symbol), amount_raw
+                ))
             }
         };
+        
+        self.ensure_expected_chain().await?;
+        
+        // Impersonate only for the duration of the send, stopping even if it fails
+        self.anvil_request("impersonate_and_send", "anvil_impersonateAccount", (sender,)).await?;
+        info!("🎭 Impersonating {}", sender);
+        let sent = self.chain.send_transaction(WithOtherFields::new(tx)).await;
+        if let Err(e) = self.anvil_request("impersonate_and_send", "anvil_stopImpersonatingAccount", (sender,)).await {
+            warn!("⚠️  Failed to stop impersonating {}: {}", sender, e.message);
+        }
+        let tx_hash = sent
+            .map_err(|e| McpError::internal_error(format!("Failed to send impersonated transaction: {}", e), None))?;
+        
+        info!("📝 Impersonated transaction sent with hash: {}", tx_hash);
+        
+        let transfer_summary = format!(
+            "From: {} ({}, impersonated)\n\
+            To: {} ({})\n\
+            {}",
+            validated_sender.address,
+            validated_sender.address_type,
+            validated_recipient.address,
+            validated_recipient.address_type,
+            amount_summary
+        );
+        
+        let response_text = match self.wait_for_transaction_confirmation(tx_hash, self.confirmation_timeout(confirmation_timeout)).await {
+            Ok(confirmation_text) => format!(
+                "Impersonated Transfer:\n{}\n\n{}",
+                transfer_summary, confirmation_text
+            ),
+            Err(_e) => format!(
+                "Impersonated Transfer Sent:\n{}\n\
+                Transaction Hash: {}\n\
+                Status: Sent to network (confirmation timeout)\n\
+                \n⚠️  Transaction was sent but confirmation timed out.\n\
+                Use check_transaction_status with hash {} to check the final status.",
+                transfer_summary, tx_hash, tx_hash
+            ),
+        };
+        
+        info!("🔍 MCP Server impersonate_and_send response: {}", response_text);
+        Ok(CallToolResult::success(vec![Content::text(response_text)]))
+    }
 
-        let to_addr = if let Ok(addr) = Address::from_str(to_token) {
-            // Direct address provided
-            addr
-        } else {
-            // Try cache/search
-            match self.search_token_address(to_token).await? {
-                Some(addr) => addr,
-                None => {
-                    return Err(McpError::invalid_params(
-                        format!(
-                            "Could not find contract address for token: {}.\n\
-                            The token was not found in cache and web search returned no results.\n\
-                            Please provide the contract address directly (e.g., '0x...').",
-                            to_token
-                        ),
+    /// Call an anvil_* cheatcode, reporting nodes that don't implement it as anvil-only
+    async fn anvil_request<P>(&self, tool: &str, method: &'static str, params: P) -> Result<(), McpError>
+    where
+        P: Serialize + Clone + std::fmt::Debug + Send + Sync + Unpin + 'static,
+    {
+        let _: serde_json::Value = self.limited(self.provider.raw_request(method.into(), params))
+            .await
+            .map_err(|e| {
+                if is_method_not_found(&e) {
+                    McpError::invalid_params(
+                        format!("{} is only supported on anvil: this node doesn't implement {} ({})", tool, method, e),
                         None
-                    ));
+                    )
+                } else {
+                    McpError::internal_error(format!("Failed to call {}: {}", method, e), None)
                 }
-            }
-        };
-
-        info!("✅ Found addresses: {} → {}", from_addr, to_addr);
-        Ok((from_addr, to_addr))
+            })?;
+        Ok(())
     }
 
-    /// Helper method to parse amount to wei
-    async fn parse_amount_to_wei(&self, amount: &str, _token: &str) -> Result<U256, McpError> {
-        let amount_float = amount.parse::<f64>()
-            .map_err(|e| McpError::invalid_params(format!("Invalid amount: {}", e), None))?;
+    /// List transactions waiting in the node's txpool
+    #[tool(description = "List pending and queued transactions from the node's txpool, optionally filtered by address")]
+    pub async fn get_pending_transactions(
+        &self,
+        Parameters(PendingTransactionsRequest { address, limit, offset }): Parameters<PendingTransactionsRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("⏳ MCP Server: get_pending_transactions called with address={:?}, limit={:?}, offset={:?}", address, limit, offset);
+        
+        let (limit, offset) = page_params(limit, offset)?;
+        let filter = match address {
+            Some(address) => Some(self.validate_recipient_address(&address).await?.resolved_address),
+            None => None,
+        };
+        
+        let content: serde_json::Value = self.limited(self.provider.raw_request("txpool_content".into(), ()))
+            .await
+            .map_err(|e| McpError::internal_error(
+                format!("txpool API is not available on this node: {}", e),
+                None
+            ))?;
+        
+        let mut entries = Vec::new();
+        for pool in ["pending", "queued"] {
+            let Some(senders) = content.get(pool).and_then(|v| v.as_object()) else { continue };
+            for (sender, txs) in senders {
+                let Ok(sender_addr) = Address::from_str(sender) else { continue };
+                if filter.is_some_and(|f| f != sender_addr) {
+                    continue;
+                }
+                let Some(txs) = txs.as_object() else { continue };
+                for (nonce, tx) in txs {
+                    let value = tx.get("value")
+                        .and_then(|v| v.as_str())
+                        .and_then(|v| U256::from_str(v).ok())
+                        .unwrap_or_default();
+                    entries.push(format!(
+                        "- [{}] From: {:?} | Nonce: {} | To: {} | Value: {:.6} ETH | Hash: {}",
+                        pool.to_uppercase(),
+                        sender_addr,
+                        nonce,
+                        tx.get("to").and_then(|v| v.as_str()).unwrap_or("Contract Creation"),
+                        value.to_f64().unwrap_or(0.0) / 1e18,
+                        tx.get("hash").and_then(|v| v.as_str()).unwrap_or("unknown")
+                    ));
+                }
+            }
+        }
+        
+        let found = entries.len();
+        let page = Page::new(entries, offset, limit);
+        let response_text = format!(
+            "Pending Transactions:\n\
+            Filter: {}\n\
+            Transactions Found: {}\n\n{}",
+            filter.map(|addr| format!("{:?}", addr)).unwrap_or_else(|| "All senders".to_string()),
+            found,
+            if found == 0 {
+                "No pending or queued transactions in the txpool.".to_string()
+            } else {
+                format!("{}\n\n{}", page.items.join("\n"), page.footer())
+            }
+        );
         
-        // Convert to wei (18 decimals for ETH)
-        let amount_wei = (amount_float * 1e18) as u128;
-        Ok(U256::from(amount_wei))
+        info!("✅ Found {} txpool transactions", found);
+        Ok(CallToolResult::success(vec![Content::text(response_text)]))
     }
 
-    /// Helper method to encode swapExactETHForTokens function call
-    async fn encode_swap_exact_eth_for_tokens(
+    /// Decode the input data of a transaction
+    #[tool(description = "Decode a transaction's calldata into the function name and named parameters")]
+    pub async fn decode_calldata(
         &self,
-        amount_out_min: U256,
-        path: &[Address],
-        to: Address,
-        deadline: U256,
-    ) -> Result<String, McpError> {
-        // Use Cast to encode swapExactETHForTokens
-        // Convert path addresses to strings
-        let path_strings: Vec<String> = path.iter()
-            .map(|addr| addr.to_string())
-            .collect();
-            
-        let args = vec![
-            amount_out_min.to_string(),
-            format!("[{}]", path_strings.join(",")),
-            to.to_string(),
-            deadline.to_string(),
-        ];
+        Parameters(DecodeCalldataRequest { tx_hash }): Parameters<DecodeCalldataRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("🧩 MCP Server: decode_calldata called with tx_hash={}", tx_hash);
         
-        let calldata = SimpleCast::calldata_encode(
-            "swapExactETHForTokens(uint256,address[],address,uint256)",
-            &args
-        ).map_err(|e| McpError::internal_error(format!("Failed to encode swap call: {}", e), None))?;
+        let tx_hash = TxHash::from_str(&tx_hash)
+            .map_err(|e| McpError::invalid_params(format!("Invalid transaction hash: {}", e), None))?;
+        
+        let tx = self.limited(self.provider.get_transaction_by_hash(tx_hash)).await
+            .map_err(|e| McpError::internal_error(format!("Failed to get transaction: {}", e), None))?
+            .ok_or_else(|| McpError::invalid_params(format!("Transaction {} not found", tx_hash), None))?;
+        
+        let input = tx.input();
+        if input.is_empty() {
+            let response_text = format!(
+                "Transaction {} has no calldata (plain ETH transfer of {:.6} ETH)",
+                tx_hash,
+                tx.value().to_f64().unwrap_or(0.0) / 1e18
+            );
+            return Ok(CallToolResult::success(vec![Content::text(response_text)]));
+        }
+        
+        let decoded = decode_calldata_input(input)?;
+        let response_text = match &decoded.signature {
+            Some(signature) => format!(
+                "Decoded Calldata:\n\
+                Transaction: {}\n\
+                Function: {}\n\
+                Selector: {}\n\
+                Parameters:\n{}",
+                tx_hash,
+                signature,
+                decoded.selector,
+                if decoded.params.is_empty() {
+                    "- (none)".to_string()
+                } else {
+                    decoded.params.iter()
+                        .map(|(name, value)| format!("- {}: {}", name, value))
+                        .collect::<Vec<_>>()
+                        .join("\n")
+                }
+            ),
+            None => format!(
+                "Unknown Function Selector:\n\
+                Transaction: {}\n\
+                Selector: {}\n\
+                Raw Arguments: {}",
+                tx_hash,
+                decoded.selector,
+                decoded.raw_args
+            ),
+        };
         
-        Ok(format!("0x{}", hex::encode(calldata)))
+        info!("✅ Decoded calldata for {}: {}", tx_hash, decoded.signature.as_deref().unwrap_or("unknown selector"));
+        Ok(CallToolResult::success(vec![Content::text(response_text)]))
     }
 
     /// Check transaction status and receipt
@@ -1244,17 +5499,15 @@ impl BlockchainService {
         let tx_hash = TxHash::from_str(&tx_hash)
             .map_err(|e| McpError::invalid_params(format!("Invalid transaction hash: {}", e), None))?;
         
-        let timeout_secs = timeout.unwrap_or(30);
+        let timeout_secs = self.confirmation_timeout(timeout);
         
         // Try to get the transaction receipt
-        match self.provider.get_transaction_receipt(tx_hash).await {
+        match self.chain.get_transaction_receipt(tx_hash).await {
             Ok(Some(receipt)) => {
                 // Transaction has been mined
-                let status = if receipt.inner.inner.inner.receipt.status.coerce_status() {
-                    "SUCCESS"
-                } else {
-                    "FAILED"
-                };
+                let succeeded = receipt.inner.inner.inner.receipt.status.coerce_status();
+                let status = if succeeded { "SUCCESS" } else { "FAILED" };
+                let revert_reason = self.revert_reason_line(tx_hash, succeeded, receipt.block_number).await;
                 
                 let gas_used = receipt.gas_used;
                 let gas_price = receipt.effective_gas_price;
@@ -1265,9 +5518,9 @@ impl BlockchainService {
                     Hash: {}\n\
                     Block Number: {}\n\
                     Gas Used: {}\n\
-                    Gas Price: {} wei\n\
+                    Gas Price: {}\n\
                     Total Cost: {} wei ({:.6} ETH)\n\
-                    Status: {}\n\
+                    Status: {}{}\n\
                     \n📋 Receipt Details:\n\
                     - Transaction Type: {}\n\
                     - Cumulative Gas Used: {}\n\
@@ -1277,10 +5530,11 @@ impl BlockchainService {
                     tx_hash,
                     receipt.block_number.unwrap_or_default(),
                     gas_used,
-                    gas_price,
+                    format_gas_price(gas_price),
                     total_cost,
                     total_cost.to_f64().unwrap_or(0.0) / 1e18,
                     status,
+                    revert_reason,
                     receipt.inner.inner.r#type,
                     receipt.inner.inner.inner.receipt.cumulative_gas_used,
                     receipt.contract_address.map(|addr| format!("{:?}", addr)).unwrap_or_else(|| "None".to_string()),
@@ -1294,17 +5548,11 @@ impl BlockchainService {
                 // Transaction not yet mined, try to wait for it
                 info!("⏳ Transaction not yet mined, waiting up to {} seconds...", timeout_secs);
                 
-                match PendingTransactionBuilder::new(self.provider.clone(), tx_hash)
-                    .with_timeout(Some(Duration::from_secs(timeout_secs)))
-                    .get_receipt()
-                    .await
-                {
+                match self.wait_for_receipt(tx_hash, Duration::from_secs(timeout_secs)).await {
                     Ok(receipt) => {
-                        let status = if receipt.inner.inner.inner.receipt.status.coerce_status() {
-                            "SUCCESS"
-                        } else {
-                            "FAILED"
-                        };
+                        let succeeded = receipt.inner.inner.inner.receipt.status.coerce_status();
+                        let status = if succeeded { "SUCCESS" } else { "FAILED" };
+                        let revert_reason = self.revert_reason_line(tx_hash, succeeded, receipt.block_number).await;
                         
                         let gas_used = receipt.gas_used;
                         let gas_price = receipt.effective_gas_price;
@@ -1315,9 +5563,9 @@ impl BlockchainService {
                             Hash: {}\n\
                             Block Number: {}\n\
                             Gas Used: {}\n\
-                            Gas Price: {} wei\n\
+                            Gas Price: {}\n\
                             Total Cost: {} wei ({:.6} ETH)\n\
-                            Status: {}\n\
+                            Status: {}{}\n\
                             \n📋 Receipt Details:\n\
                             - Transaction Type: {}\n\
                             - Cumulative Gas Used: {}\n\
@@ -1327,10 +5575,11 @@ impl BlockchainService {
                             tx_hash,
                             receipt.block_number.unwrap_or_default(),
                             gas_used,
-                            gas_price,
+                            format_gas_price(gas_price),
                             total_cost,
                             total_cost.to_f64().unwrap_or(0.0) / 1e18,
                             status,
+                            revert_reason,
                             receipt.inner.inner.r#type,
                             receipt.inner.inner.inner.receipt.cumulative_gas_used,
                             receipt.contract_address.map(|addr| format!("{:?}", addr)).unwrap_or_else(|| "None".to_string()),
@@ -1342,7 +5591,7 @@ impl BlockchainService {
                     }
                     Err(_e) => {
                         // Check if transaction exists in mempool
-                        match self.provider.get_transaction_by_hash(tx_hash).await {
+                        match self.limited(self.provider.get_transaction_by_hash(tx_hash)).await {
                             Ok(Some(_)) => {
                                 let response_text = format!(
                                     "Transaction Status: PENDING\n\
@@ -1393,46 +5642,347 @@ impl BlockchainService {
         }
     }
 
-    /// Wait for transaction confirmation and return detailed status
-    async fn wait_for_transaction_confirmation(&self, tx_hash: TxHash, timeout_secs: u64) -> Result<String, McpError> {
-        info!("⏳ Waiting for transaction confirmation: {}", tx_hash);
+    /// Poll for a transaction's receipt, recording each status change until it is confirmed or time runs out
+    #[tool(description = "Watch a sent transaction until it is mined and confirmed, polling its receipt and reporting each status change (pending in mempool, mined, confirmed) - use when a send or swap timed out waiting for confirmation")]
+    pub async fn monitor_transaction(
+        &self,
+        Parameters(MonitorTransactionRequest { tx_hash, poll_interval, max_duration, confirmations }): Parameters<MonitorTransactionRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        let tx_hash = TxHash::from_str(tx_hash.trim())
+            .map_err(|e| McpError::invalid_params(format!("Invalid transaction hash: {}", e), None))?;
+        let poll_interval = poll_interval.unwrap_or(DEFAULT_MONITOR_POLL_SECS).max(1);
+        let max_duration = max_duration.unwrap_or(DEFAULT_MONITOR_DURATION_SECS).min(MAX_MONITOR_DURATION_SECS);
+        let confirmations = confirmations.unwrap_or(1).max(1);
+        info!("👀 MCP Server: monitor_transaction called for {} (every {}s for up to {}s, {} confirmation(s))",
+              tx_hash, poll_interval, max_duration, confirmations);
         
-        match PendingTransactionBuilder::new(self.provider.clone(), tx_hash)
-            .with_timeout(Some(Duration::from_secs(timeout_secs)))
-            .get_receipt()
-            .await
-        {
-            Ok(receipt) => {
-                let status = if receipt.inner.inner.inner.receipt.status.coerce_status() {
-                    "SUCCESS"
+        let started = Instant::now();
+        let mut log = MonitorLog::default();
+        loop {
+            let elapsed = started.elapsed().as_secs();
+            match self.retry_read(|| self.chain.get_transaction_receipt(tx_hash)).await {
+                Ok(Some(receipt)) => {
+                    let mined_in = receipt.block_number.unwrap_or_default();
+                    let latest = self.retry_read(|| self.chain.get_block_number()).await.unwrap_or(mined_in);
+                    let confirmed = latest.saturating_sub(mined_in) + 1;
+                    let succeeded = receipt.inner.inner.inner.receipt.status.coerce_status();
+                    
+                    if !succeeded || confirmed >= confirmations {
+                        let outcome = if succeeded { "CONFIRMED" } else { "FAILED" };
+                        log.record(elapsed, format!("{} - mined in block {}, {} confirmation(s)", outcome, mined_in, confirmed));
+                        let confirmation = self.confirmation_from_receipt(tx_hash, &receipt).await;
+                        let response_text = format!(
+                            "Transaction Monitor: {} after {}s\n\
+                            \nStatus Log:\n{}\n\
+                            \n{}",
+                            outcome, elapsed, log, confirmation
+                        );
+                        info!("🔍 MCP Server monitor_transaction response: {}", response_text);
+                        return Ok(CallToolResult::success(vec![Content::text(response_text)]));
+                    }
+                    log.record(elapsed, format!("MINED - in block {}, {} of {} confirmations", mined_in, confirmed, confirmations));
+                }
+                Ok(None) => {
+                    let status = match self.limited(self.provider.get_transaction_by_hash(tx_hash)).await {
+                        Ok(Some(_)) => "PENDING - still in mempool",
+                        Ok(None) => "PENDING - not found in the mempool (dropped, replaced or not yet propagated)",
+                        Err(_) => "PENDING - no receipt yet",
+                    };
+                    log.record(elapsed, status);
+                }
+                Err(e) => log.record(elapsed, format!("UNKNOWN - receipt lookup failed: {}", e)),
+            }
+            
+            if elapsed + poll_interval > max_duration {
+                break;
+            }
+            info!("⏳ {} not yet confirmed after {}s, checking again in {}s", tx_hash, elapsed, poll_interval);
+            sleep(Duration::from_secs(poll_interval)).await;
+        }
+        
+        let response_text = format!(
+            "Transaction Monitor: NOT CONFIRMED after {}s\n\
+            Hash: {}\n\
+            \nStatus Log:\n{}\n\
+            \n⏳ Stopped watching after the {}s limit; the transaction may still be mined.\n\
+            Run monitor_transaction again with a longer max_duration, or check_transaction_status later.",
+            started.elapsed().as_secs(), tx_hash, log, max_duration
+        );
+        info!("🔍 MCP Server monitor_transaction response: {}", response_text);
+        Ok(CallToolResult::success(vec![Content::text(response_text)]))
+    }
+
+    /// Scan recent blocks for transactions sent from or to an address
+    #[tool(description = "Get recent transaction history for an address by scanning the latest blocks")]
+    pub async fn get_transaction_history(
+        &self,
+        Parameters(TransactionHistoryRequest { address, block_range, limit, offset }): Parameters<TransactionHistoryRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("📜 MCP Server: get_transaction_history called with address={}, block_range={:?}, limit={:?}, offset={:?}", 
+            address, block_range, limit, offset);
+        
+        let (limit, offset) = page_params(limit, offset)?;
+        let validated_address = self.validate_recipient_address(&address).await?;
+        let target = validated_address.resolved_address;
+        
+        let block_range = block_range.unwrap_or(DEFAULT_HISTORY_BLOCKS);
+        if block_range == 0 || block_range > MAX_HISTORY_BLOCKS {
+            return Err(McpError::invalid_params(
+                format!(
+                    "Invalid block_range {}: must be between 1 and {} blocks.\n\
+                    Scanning more blocks would make too many RPC calls - narrow the range and try again.",
+                    block_range, MAX_HISTORY_BLOCKS
+                ),
+                None
+            ));
+        }
+        
+        let latest_block = self.limited(self.provider.get_block_number()).await
+            .map_err(|e| McpError::internal_error(format!("Failed to get block number: {}", e), None))?;
+        let first_block = latest_block.saturating_sub(block_range - 1);
+        
+        info!("🔍 Scanning blocks {}..={} for {}", first_block, latest_block, target);
+        
+        let mut entries = Vec::new();
+        for block_number in (first_block..=latest_block).rev() {
+            let block = self.limited(self.provider.get_block_by_number(BlockNumberOrTag::Number(block_number)).full())
+                .await
+                .map_err(|e| McpError::internal_error(format!("Failed to get block {}: {}", block_number, e), None))?;
+            let Some(block) = block else { continue };
+            
+            for tx in block.transactions.txns() {
+                let from = tx.from();
+                let to = tx.to();
+                let (direction, counterparty) = if from == target {
+                    ("OUT", to.map(|addr| format!("{:?}", addr)).unwrap_or_else(|| "Contract Creation".to_string()))
+                } else if to == Some(target) {
+                    ("IN", format!("{:?}", from))
                 } else {
-                    "FAILED"
+                    continue;
                 };
                 
-                let gas_used = receipt.gas_used;
-                let gas_price = receipt.effective_gas_price;
-                let total_cost = gas_used as u128 * gas_price;
-                
-                let response_text = format!(
-                    "Transaction Confirmed: {}\n\
-                    Hash: {}\n\
-                    Block Number: {}\n\
-                    Gas Used: {}\n\
-                    Gas Price: {} wei\n\
-                    Total Cost: {} wei ({:.6} ETH)\n\
-                    Status: {}",
-                    status,
-                    tx_hash,
-                    receipt.block_number.unwrap_or_default(),
-                    gas_used,
-                    gas_price,
-                    total_cost,
-                    total_cost.to_f64().unwrap_or(0.0) / 1e18,
-                    status
-                );
-                
-                info!("✅ Transaction confirmed: {}", status);
-                Ok(response_text)
+                entries.push(format!(
+                    "- Block {} | {} | {} | Counterparty: {} | Value: {:.6} ETH",
+                    block_number,
+                    direction,
+                    tx.tx_hash(),
+                    counterparty,
+                    tx.value().to_f64().unwrap_or(0.0) / 1e18
+                ));
+            }
+        }
+        
+        let found = entries.len();
+        let page = Page::new(entries, offset, limit);
+        let response_text = format!(
+            "Transaction History:\n\
+            Address: {} ({})\n\
+            Blocks Scanned: {} to {} ({} blocks)\n\
+            Transactions Found: {}\n\n{}",
+            validated_address.address,
+            validated_address.address_type,
+            first_block,
+            latest_block,
+            latest_block - first_block + 1,
+            found,
+            if found == 0 {
+                "No transactions found in this range.".to_string()
+            } else {
+                format!("{}\n\n{}", page.items.join("\n"), page.footer())
+            }
+        );
+        
+        info!("✅ Found {} transactions for {}", found, target);
+        Ok(CallToolResult::success(vec![Content::text(response_text)]))
+    }
+
+    /// Query the event logs emitted by a contract over a block range
+    #[tool(description = "Get event logs emitted by a contract, optionally filtered by event signature and block range; Transfer, Approval and Swap events are decoded")]
+    pub async fn get_logs(
+        &self,
+        Parameters(LogQueryRequest { address, event_signature, from_block, to_block, limit, offset }): Parameters<LogQueryRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        info!("📋 MCP Server: get_logs called with address={}, event={:?}, from_block={:?}, to_block={:?}", 
+            address, event_signature, from_block, to_block);
+        
+        let (limit, offset) = page_params(limit, offset)?;
+        let contract = Address::from_str(address.trim())
+            .map_err(|e| McpError::invalid_params(format!("Invalid contract address: {}", e), None))?;
+        let topic0 = event_signature.as_deref().map(event_topic).transpose()?;
+        let from_block = from_block.as_deref().map(parse_block_id).transpose()?;
+        let to_block = to_block.as_deref().map(parse_block_id).transpose()?;
+        
+        let latest_block = self.retry_read(|| self.chain.get_block_number()).await
+            .map_err(|e| McpError::internal_error(format!("Failed to get block number: {}", e), None))?;
+        let to_block = to_block.map_or(latest_block, |block| block_number_or_latest(block, latest_block));
+        let from_block = from_block.map_or(
+            to_block.saturating_sub(DEFAULT_LOG_BLOCKS - 1),
+            |block| block_number_or_latest(block, latest_block)
+        );
+        if from_block > to_block {
+            return Err(McpError::invalid_params(
+                format!("Invalid block range: from_block {} is after to_block {}", from_block, to_block),
+                None
+            ));
+        }
+        
+        let (from_block, truncated) = cap_log_range(from_block, to_block);
+        if truncated {
+            warn!("⚠️  get_logs range truncated to the last {} blocks ({}..={})", MAX_LOG_BLOCKS, from_block, to_block);
+        }
+        
+        let mut filter = Filter::new().address(contract).from_block(from_block).to_block(to_block);
+        if let Some(topic0) = topic0 {
+            filter = filter.event_signature(topic0);
+        }
+        
+        let logs = self.retry_read(|| self.chain.get_logs(&filter)).await
+            .map_err(|e| McpError::internal_error(format!("Failed to get logs: {}", e), None))?;
+        
+        let mut entries = Vec::with_capacity(logs.len());
+        for log in &logs {
+            let decoded = decode_log(log.topics(), &log.data().data);
+            let location = format!(
+                "- Block {} | Tx {} | Log {}",
+                log.block_number.map(|n| n.to_string()).unwrap_or_else(|| "pending".to_string()),
+                log.transaction_hash.map(|hash| hash.to_string()).unwrap_or_else(|| "unknown".to_string()),
+                log.log_index.map(|i| i.to_string()).unwrap_or_else(|| "?".to_string())
+            );
+            entries.push(match decoded.event {
+                Some(event) => format!(
+                    "{}\n  Event: {}\n{}",
+                    location,
+                    event,
+                    decoded.params.iter()
+                        .map(|(name, value)| format!("  {}: {}", name, value))
+                        .collect::<Vec<_>>()
+                        .join("\n")
+                ),
+                None => format!(
+                    "{}\n  Topics: [{}]\n  Data: {}",
+                    location,
+                    decoded.topics.join(", "),
+                    decoded.data
+                ),
+            });
+        }
+        
+        let found = entries.len();
+        let page = Page::new(entries, offset, limit);
+        let response_text = format!(
+            "Event Logs:\n\
+            Contract: {}\n\
+            Event: {}\n\
+            Blocks Searched: {} to {} ({} blocks){}\n\
+            Logs Found: {}\n\n{}",
+            contract,
+            event_signature.as_deref().unwrap_or("all"),
+            from_block,
+            to_block,
+            to_block - from_block + 1,
+            if truncated {
+                format!("\n⚠️  Range truncated to the most recent {} blocks - query earlier blocks separately", MAX_LOG_BLOCKS)
+            } else {
+                String::new()
+            },
+            found,
+            if found == 0 {
+                "No logs found in this range.".to_string()
+            } else {
+                format!("{}\n\n{}", page.items.join("\n"), page.footer())
+            }
+        );
+        
+        info!("✅ Found {} logs for {}", found, contract);
+        Ok(CallToolResult::success(vec![Content::text(response_text)]))
+    }
+
+    /// Recover the revert reason of a failed transaction
+    ///
+    /// Asks the node to trace the transaction (`debug_traceTransaction`) when it
+    /// supports that. Otherwise replays it with eth_call on the parent of the block
+    /// it was mined in and decodes the revert data; the replay doesn't see earlier
+    /// transactions in the same block. Returns a short note when neither gives a reason.
+    async fn fetch_revert_reason(&self, tx_hash: TxHash, block_number: Option<u64>) -> String {
+        match self.chain.trace_transaction(tx_hash).await {
+            Ok(trace) => {
+                if let Some(reason) = revert_reason_from_trace(&trace) {
+                    return reason;
+                }
+                info!("⚠️  Trace of {} shows no failing call, replaying it instead", tx_hash);
+            }
+            Err(e) => info!("⚠️  debug_traceTransaction unavailable ({}), replaying {} instead", e, tx_hash),
+        }
+        
+        let tx = match self.limited(self.provider.get_transaction_by_hash(tx_hash)).await {
+            Ok(Some(tx)) => tx,
+            Ok(None) => return "unavailable (transaction not found)".to_string(),
+            Err(e) => return format!("unavailable (failed to fetch transaction: {})", e),
+        };
+        
+        let mut call_request = TransactionRequest::default()
+            .from(tx.from())
+            .value(tx.value())
+            .input(tx.input().clone().into())
+            .gas_limit(tx.gas_limit());
+        if let Some(to) = tx.to() {
+            call_request = call_request.to(to);
+        }
+        
+        match self.limited(self.provider.call(WithOtherFields::new(call_request)).block(replay_block(block_number))).await {
+            Ok(_) => "unavailable (replaying the transaction did not revert)".to_string(),
+            Err(e) => revert_reason_from_error(&e),
+        }
+    }
+
+    /// "Revert Reason" line for a receipt, empty for successful transactions
+    async fn revert_reason_line(&self, tx_hash: TxHash, succeeded: bool, block_number: Option<u64>) -> String {
+        if succeeded {
+            return String::new();
+        }
+        let reason = self.fetch_revert_reason(tx_hash, block_number).await;
+        info!("🧾 Revert reason for {}: {}", tx_hash, reason);
+        format!("\nRevert Reason: {}", reason)
+    }
+
+    /// Confirmation timeout for a request, falling back to the configured default
+    fn confirmation_timeout(&self, requested: Option<u64>) -> u64 {
+        requested.unwrap_or(self.config.confirmation_timeout_secs)
+    }
+
+    /// Receipt details of a mined transaction, with the revert reason if it failed
+    async fn confirmation_from_receipt(&self, tx_hash: TxHash, receipt: &AnyTransactionReceipt) -> TransactionConfirmation {
+        let succeeded = receipt.inner.inner.inner.receipt.status.coerce_status();
+        let revert_reason = if succeeded {
+            None
+        } else {
+            let reason = self.fetch_revert_reason(tx_hash, receipt.block_number).await;
+            info!("🧾 Revert reason for {}: {}", tx_hash, reason);
+            Some(reason)
+        };
+        
+        TransactionConfirmation {
+            tx_hash: tx_hash.to_string(),
+            succeeded,
+            block_number: receipt.block_number,
+            gas_used: receipt.gas_used,
+            effective_gas_price_wei: receipt.effective_gas_price.to_string(),
+            revert_reason,
+            contract_address: receipt.contract_address.map(|address| address.to_string()),
+        }
+    }
+
+    /// Wait for transaction confirmation and return the receipt details
+    ///
+    /// The confirmation's `Display` renders the detailed status text used in tool responses.
+    async fn wait_for_transaction_confirmation(&self, tx_hash: TxHash, timeout_secs: u64) -> Result<TransactionConfirmation, McpError> {
+        info!("⏳ Waiting for transaction confirmation: {}", tx_hash);
+        
+        match self.wait_for_receipt(tx_hash, Duration::from_secs(timeout_secs)).await {
+            Ok(receipt) => {
+                let confirmation = self.confirmation_from_receipt(tx_hash, &receipt).await;
+                info!("✅ Transaction confirmed: {:?}", confirmation.status());
+                Ok(confirmation)
             }
             Err(e) => {
                 Err(McpError::internal_error(
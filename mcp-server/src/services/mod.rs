@@ -1,4 +1,7 @@
 //! Services module for MCP server
 
 pub mod blockchain;
+pub mod pagination;
+pub mod provider;
 pub mod search;
+pub mod search_provider;
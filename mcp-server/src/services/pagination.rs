@@ -0,0 +1,76 @@
+//! Limit/offset paging for tools that return lists of results
+//!
+//! Tools build their full result list, slice it into a `Page` and append the
+//! page's footer, so the agent can see how much it hasn't been shown and which
+//! offset fetches the rest.
+
+use rmcp::ErrorData as McpError;
+
+/// Results per page when a tool call doesn't pass `limit`
+pub const DEFAULT_PAGE_SIZE: usize = 20;
+
+/// Upper bound on `limit`, to keep tool responses within the model's context
+pub const MAX_PAGE_SIZE: usize = 100;
+
+/// One page of a result list
+#[derive(Debug, Clone, PartialEq)]
+pub struct Page<T> {
+    /// Results on this page
+    pub items: Vec<T>,
+    /// Zero-based position of the first item in the full list
+    pub offset: usize,
+    /// Size of the full list, when known
+    pub total: Option<usize>,
+    /// Whether results exist past this page
+    pub has_more: bool,
+}
+
+impl<T> Page<T> {
+    /// Slice `limit` items starting at `offset` out of the full result list
+    pub fn new(all: Vec<T>, offset: usize, limit: usize) -> Self {
+        let total = all.len();
+        let items: Vec<T> = all.into_iter().skip(offset).take(limit).collect();
+        let has_more = offset + items.len() < total;
+        Self { items, offset, total: Some(total), has_more }
+    }
+
+    /// Page of a list whose full size isn't known, e.g. search engine results
+    pub fn open_ended(items: Vec<T>, offset: usize, has_more: bool) -> Self {
+        Self { items, offset, total: None, has_more }
+    }
+
+    /// Offset to pass for the next page, if there is one
+    pub fn next_offset(&self) -> Option<usize> {
+        self.has_more.then(|| self.offset + self.items.len())
+    }
+
+    /// Footer such as "Showing 21-40 of 63, use offset 40 for more"
+    pub fn footer(&self) -> String {
+        let of_total = self.total.map(|total| format!(" of {}", total)).unwrap_or_default();
+        if self.items.is_empty() {
+            return if self.offset == 0 {
+                format!("Showing 0{}", of_total)
+            } else {
+                format!("No results at offset {}{}, use a smaller offset", self.offset, of_total)
+            };
+        }
+
+        let shown = format!("Showing {}-{}{}", self.offset + 1, self.offset + self.items.len(), of_total);
+        match self.next_offset() {
+            Some(next) => format!("{}, use offset {} for more", shown, next),
+            None => shown,
+        }
+    }
+}
+
+/// Resolve optional `limit`/`offset` tool parameters, rejecting an out-of-range limit
+pub fn page_params(limit: Option<usize>, offset: Option<usize>) -> Result<(usize, usize), McpError> {
+    let limit = limit.unwrap_or(DEFAULT_PAGE_SIZE);
+    if limit == 0 || limit > MAX_PAGE_SIZE {
+        return Err(McpError::invalid_params(
+            format!("Invalid limit {}: must be between 1 and {}", limit, MAX_PAGE_SIZE),
+            None
+        ));
+    }
+    Ok((limit, offset.unwrap_or(0)))
+}
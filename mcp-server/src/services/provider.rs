@@ -0,0 +1,697 @@
+//! Provider abstraction for the blockchain service
+//!
+//! `BlockchainService` reads chain state through the `ChainProvider` trait so the
+//! tool logic can be exercised against `MockProvider` without a running anvil node.
+
+use alloy_network::{AnyNetwork, AnyTransactionReceipt, EthereumWallet, NetworkWallet, TransactionBuilder};
+use alloy_primitives::{keccak256, Address, Bytes, TxHash, U256};
+use alloy_provider::{Provider, RootProvider};
+use alloy_rpc_types::{BlockId, BlockNumberOrTag, Filter, Log, TransactionRequest};
+use alloy_signer_local::PrivateKeySigner;
+use alloy_serde::WithOtherFields;
+use alloy_transport::{RpcError, TransportError, TransportErrorKind, TransportResult};
+use async_trait::async_trait;
+use std::{
+    collections::HashMap,
+    future::Future,
+    sync::{Arc, Mutex},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use tokio::sync::{Semaphore, SemaphorePermit};
+use tracing::warn;
+use crate::config::MAINNET_CHAIN_ID;
+
+/// Error text that marks a transport failure as transient
+const TRANSIENT_ERROR_MARKERS: &[&str] = &[
+    "connection",
+    "timed out",
+    "timeout",
+    "broken pipe",
+    "temporarily unavailable",
+];
+
+/// Whether an RPC error is worth retrying
+///
+/// Only transport-level failures (dropped connections, timeouts, rate limits)
+/// qualify; JSON-RPC error responses such as reverts are logical errors and
+/// would fail the same way again.
+pub fn is_transient_rpc_error(error: &TransportError) -> bool {
+    match error {
+        RpcError::Transport(kind) => {
+            let message = kind.to_string().to_lowercase();
+            kind.is_retry_err() || TRANSIENT_ERROR_MARKERS.iter().any(|marker| message.contains(marker))
+        }
+        _ => false,
+    }
+}
+
+/// JSON-RPC error code for an unknown method
+const METHOD_NOT_FOUND_CODE: i64 = -32601;
+
+/// Error text nodes use when they don't implement an RPC method
+///
+/// Kept to the exact phrases (geth's "the method X does not exist/is not
+/// available"), so a revert such as "token does not exist" doesn't match.
+const METHOD_NOT_FOUND_MARKERS: &[&str] = &[
+    "method not found",
+    "does not exist/is not available",
+];
+
+/// Whether an RPC error means the node doesn't implement the method
+///
+/// Used to tell a non-anvil node apart from a failing anvil one when calling
+/// `anvil_*` cheatcodes.
+pub fn is_method_not_found(error: &TransportError) -> bool {
+    if let RpcError::ErrorResp(payload) = error
+        && payload.code == METHOD_NOT_FOUND_CODE
+    {
+        return true;
+    }
+    let message = error.to_string().to_lowercase();
+    METHOD_NOT_FOUND_MARKERS.iter().any(|marker| message.contains(marker))
+}
+
+/// Whether an `eth_call` error means the call reverted, rather than the node failing
+pub fn is_execution_revert(error: &TransportError) -> bool {
+    if let RpcError::ErrorResp(payload) = error
+        && payload.as_revert_data().is_some()
+    {
+        return true;
+    }
+    error.to_string().to_lowercase().contains("revert")
+}
+
+/// Run an RPC call, retrying transient failures with exponential backoff and jitter
+///
+/// `attempts` counts the first call, so `attempts = 1` disables retries. The
+/// delay before retry `n` is `base_delay * 2^(n-1)` plus up to 50% jitter.
+pub async fn retry_rpc<T, F, Fut>(attempts: u32, base_delay: Duration, mut f: F) -> TransportResult<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = TransportResult<T>>,
+{
+    let attempts = attempts.max(1);
+    let mut attempt = 1;
+    loop {
+        match f().await {
+            Err(e) if attempt < attempts && is_transient_rpc_error(&e) => {
+                let delay = backoff_delay(base_delay, attempt);
+                warn!("⚠️  RPC call failed (attempt {}/{}): {} - retrying in {:?}", attempt, attempts, e, delay);
+                tokio::time::sleep(delay).await;
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+/// Exponential backoff for the given (1-based) attempt, with up to 50% jitter
+fn backoff_delay(base_delay: Duration, attempt: u32) -> Duration {
+    let exponential = base_delay.saturating_mul(1u32 << (attempt - 1).min(16));
+    let jitter_range = exponential.as_millis() as u64 / 2;
+    if jitter_range == 0 {
+        return exponential;
+    }
+    let seed = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.subsec_nanos() as u64)
+        .unwrap_or(0);
+    exponential + Duration::from_millis(seed % (jitter_range + 1))
+}
+
+/// The provider calls used by the blockchain tools
+#[async_trait]
+pub trait ChainProvider: Send + Sync {
+    /// Get the latest block number
+    async fn get_block_number(&self) -> TransportResult<u64>;
+
+    /// Get the chain id the node reports
+    async fn get_chain_id(&self) -> TransportResult<u64>;
+
+    /// Get the timestamp of the latest block in unix seconds
+    async fn get_block_timestamp(&self) -> TransportResult<u64>;
+
+    /// Get the node's current gas price in wei
+    async fn get_gas_price(&self) -> TransportResult<u128>;
+
+    /// Get the ETH balance of an account in wei at the latest block
+    async fn get_balance(&self, address: Address) -> TransportResult<U256> {
+        self.get_balance_at(address, BlockId::latest()).await
+    }
+
+    /// Get the ETH balance of an account in wei as of `block`
+    async fn get_balance_at(&self, address: Address, block: BlockId) -> TransportResult<U256>;
+
+    /// Get the deployed bytecode at an address (empty for EOAs)
+    async fn get_code_at(&self, address: Address) -> TransportResult<Bytes>;
+
+    /// Get the number of transactions sent from an account as of `block`
+    async fn get_transaction_count(&self, address: Address, block: BlockId) -> TransportResult<u64>;
+
+    /// Execute a read-only call against the latest block and return the raw return data
+    async fn call(&self, tx: WithOtherFields<TransactionRequest>) -> TransportResult<Bytes> {
+        self.call_at(tx, BlockId::latest()).await
+    }
+
+    /// Execute a read-only call against the state at `block`
+    async fn call_at(&self, tx: WithOtherFields<TransactionRequest>, block: BlockId) -> TransportResult<Bytes>;
+
+    /// Get the receipt of a mined transaction, or `None` if it is still pending
+    async fn get_transaction_receipt(&self, tx_hash: TxHash) -> TransportResult<Option<AnyTransactionReceipt>>;
+
+    /// Broadcast a transaction and return its hash
+    async fn send_transaction(&self, tx: WithOtherFields<TransactionRequest>) -> TransportResult<TxHash>;
+
+    /// Trace a mined transaction with `debug_traceTransaction`'s callTracer
+    async fn trace_transaction(&self, tx_hash: TxHash) -> TransportResult<serde_json::Value>;
+
+    /// Get the logs matching a filter
+    async fn get_logs(&self, filter: &Filter) -> TransportResult<Vec<Log>>;
+}
+
+#[async_trait]
+impl ChainProvider for RootProvider<AnyNetwork> {
+    async fn get_block_number(&self) -> TransportResult<u64> {
+        Provider::get_block_number(self).await
+    }
+
+    async fn get_chain_id(&self) -> TransportResult<u64> {
+        Provider::get_chain_id(self).await
+    }
+
+    async fn get_block_timestamp(&self) -> TransportResult<u64> {
+        let block = Provider::get_block_by_number(self, BlockNumberOrTag::Latest).await?
+            .ok_or_else(|| TransportErrorKind::custom_str("node returned no latest block"))?;
+        Ok(block.header.timestamp)
+    }
+
+    async fn get_gas_price(&self) -> TransportResult<u128> {
+        Provider::get_gas_price(self).await
+    }
+
+    async fn get_balance_at(&self, address: Address, block: BlockId) -> TransportResult<U256> {
+        Provider::get_balance(self, address).block_id(block).await
+    }
+
+    async fn get_code_at(&self, address: Address) -> TransportResult<Bytes> {
+        Provider::get_code_at(self, address).await
+    }
+
+    async fn get_transaction_count(&self, address: Address, block: BlockId) -> TransportResult<u64> {
+        Provider::get_transaction_count(self, address).block_id(block).await
+    }
+
+    async fn call_at(&self, tx: WithOtherFields<TransactionRequest>, block: BlockId) -> TransportResult<Bytes> {
+        Provider::call(self, tx).block(block).await
+    }
+
+    async fn get_transaction_receipt(&self, tx_hash: TxHash) -> TransportResult<Option<AnyTransactionReceipt>> {
+        Provider::get_transaction_receipt(self, tx_hash).await
+    }
+
+    async fn send_transaction(&self, tx: WithOtherFields<TransactionRequest>) -> TransportResult<TxHash> {
+        let pending_tx = Provider::send_transaction(self, tx).await?;
+        Ok(*pending_tx.tx_hash())
+    }
+
+    async fn trace_transaction(&self, tx_hash: TxHash) -> TransportResult<serde_json::Value> {
+        let call_tracer = serde_json::json!({ "tracer": "callTracer" });
+        Provider::raw_request(self, "debug_traceTransaction".into(), (tx_hash, call_tracer)).await
+    }
+
+    async fn get_logs(&self, filter: &Filter) -> TransportResult<Vec<Log>> {
+        Provider::get_logs(self, filter).await
+    }
+}
+
+/// Caps how many calls to the wrapped provider are in flight at once
+///
+/// Calls past the limit wait for a permit instead of all hitting the node
+/// together. A permit is held for one RPC call only, so retry backoff and
+/// confirmation polling leave the slot free for other requests.
+pub struct ConcurrencyLimited {
+    inner: Arc<dyn ChainProvider>,
+    permits: Arc<Semaphore>,
+}
+
+impl ConcurrencyLimited {
+    /// Limit `inner` to the permits available in `permits`
+    pub fn new(inner: Arc<dyn ChainProvider>, permits: Arc<Semaphore>) -> Self {
+        Self { inner, permits }
+    }
+
+    async fn permit(&self) -> SemaphorePermit<'_> {
+        // The semaphore is never closed
+        self.permits.acquire().await.expect("operation semaphore closed")
+    }
+}
+
+#[async_trait]
+impl ChainProvider for ConcurrencyLimited {
+    async fn get_block_number(&self) -> TransportResult<u64> {
+        let _permit = self.permit().await;
+        self.inner.get_block_number().await
+    }
+
+    async fn get_chain_id(&self) -> TransportResult<u64> {
+        let _permit = self.permit().await;
+        self.inner.get_chain_id().await
+    }
+
+    async fn get_block_timestamp(&self) -> TransportResult<u64> {
+        let _permit = self.permit().await;
+        self.inner.get_block_timestamp().await
+    }
+
+    async fn get_gas_price(&self) -> TransportResult<u128> {
+        let _permit = self.permit().await;
+        self.inner.get_gas_price().await
+    }
+
+    async fn get_balance_at(&self, address: Address, block: BlockId) -> TransportResult<U256> {
+        let _permit = self.permit().await;
+        self.inner.get_balance_at(address, block).await
+    }
+
+    async fn get_code_at(&self, address: Address) -> TransportResult<Bytes> {
+        let _permit = self.permit().await;
+        self.inner.get_code_at(address).await
+    }
+
+    async fn get_transaction_count(&self, address: Address, block: BlockId) -> TransportResult<u64> {
+        let _permit = self.permit().await;
+        self.inner.get_transaction_count(address, block).await
+    }
+
+    async fn call_at(&self, tx: WithOtherFields<TransactionRequest>, block: BlockId) -> TransportResult<Bytes> {
+        let _permit = self.permit().await;
+        self.inner.call_at(tx, block).await
+    }
+
+    async fn get_transaction_receipt(&self, tx_hash: TxHash) -> TransportResult<Option<AnyTransactionReceipt>> {
+        let _permit = self.permit().await;
+        self.inner.get_transaction_receipt(tx_hash).await
+    }
+
+    async fn send_transaction(&self, tx: WithOtherFields<TransactionRequest>) -> TransportResult<TxHash> {
+        let _permit = self.permit().await;
+        self.inner.send_transaction(tx).await
+    }
+
+    async fn trace_transaction(&self, tx_hash: TxHash) -> TransportResult<serde_json::Value> {
+        let _permit = self.permit().await;
+        self.inner.trace_transaction(tx_hash).await
+    }
+
+    async fn get_logs(&self, filter: &Filter) -> TransportResult<Vec<Log>> {
+        let _permit = self.permit().await;
+        self.inner.get_logs(filter).await
+    }
+}
+
+/// The live provider, signing transactions locally with the configured private keys
+///
+/// Transactions from an address with a key are filled in (nonce, chain id, gas
+/// and fees), signed and broadcast with `eth_sendRawTransaction`, so sending
+/// works against nodes that don't hold unlocked accounts. Transactions from any
+/// other address are left to the node via `eth_sendTransaction`, which only
+/// succeeds for accounts the node can sign for, such as impersonated ones.
+pub struct SigningProvider {
+    provider: RootProvider<AnyNetwork>,
+    wallet: Option<EthereumWallet>,
+}
+
+impl SigningProvider {
+    /// Sign with every given key, sending through `provider`
+    pub fn new(provider: RootProvider<AnyNetwork>, signers: impl IntoIterator<Item = PrivateKeySigner>) -> Self {
+        let mut signers = signers.into_iter();
+        let wallet = signers.next().map(|first| {
+            let mut wallet = EthereumWallet::from(first);
+            for signer in signers {
+                wallet.register_signer(signer);
+            }
+            wallet
+        });
+        Self { provider, wallet }
+    }
+
+    /// Fill in the fields the node would otherwise choose, then sign `tx` with the sender's key
+    async fn sign_and_send(&self, wallet: &EthereumWallet, from: Address, mut tx: WithOtherFields<TransactionRequest>) -> TransportResult<TxHash> {
+        if tx.nonce.is_none() {
+            tx.nonce = Some(Provider::get_transaction_count(&self.provider, from).pending().await?);
+        }
+        if tx.chain_id.is_none() {
+            tx.chain_id = Some(Provider::get_chain_id(&self.provider).await?);
+        }
+        if tx.gas.is_none() {
+            tx.gas = Some(Provider::estimate_gas(&self.provider, tx.clone()).await?);
+        }
+        if tx.gas_price.is_none() && tx.max_fee_per_gas.is_none() {
+            tx.gas_price = Some(Provider::get_gas_price(&self.provider).await?);
+        }
+
+        let envelope = tx.build(wallet).await
+            .map_err(|e| TransportErrorKind::custom_str(&format!("Failed to sign transaction from {}: {}", from, e)))?;
+        let pending_tx = Provider::send_tx_envelope(&self.provider, envelope).await?;
+        Ok(*pending_tx.tx_hash())
+    }
+}
+
+#[async_trait]
+impl ChainProvider for SigningProvider {
+    async fn get_block_number(&self) -> TransportResult<u64> {
+        ChainProvider::get_block_number(&self.provider).await
+    }
+
+    async fn get_chain_id(&self) -> TransportResult<u64> {
+        ChainProvider::get_chain_id(&self.provider).await
+    }
+
+    async fn get_block_timestamp(&self) -> TransportResult<u64> {
+        ChainProvider::get_block_timestamp(&self.provider).await
+    }
+
+    async fn get_gas_price(&self) -> TransportResult<u128> {
+        ChainProvider::get_gas_price(&self.provider).await
+    }
+
+    async fn get_balance_at(&self, address: Address, block: BlockId) -> TransportResult<U256> {
+        ChainProvider::get_balance_at(&self.provider, address, block).await
+    }
+
+    async fn get_code_at(&self, address: Address) -> TransportResult<Bytes> {
+        ChainProvider::get_code_at(&self.provider, address).await
+    }
+
+    async fn get_transaction_count(&self, address: Address, block: BlockId) -> TransportResult<u64> {
+        ChainProvider::get_transaction_count(&self.provider, address, block).await
+    }
+
+    async fn call_at(&self, tx: WithOtherFields<TransactionRequest>, block: BlockId) -> TransportResult<Bytes> {
+        ChainProvider::call_at(&self.provider, tx, block).await
+    }
+
+    async fn get_transaction_receipt(&self, tx_hash: TxHash) -> TransportResult<Option<AnyTransactionReceipt>> {
+        ChainProvider::get_transaction_receipt(&self.provider, tx_hash).await
+    }
+
+    async fn send_transaction(&self, tx: WithOtherFields<TransactionRequest>) -> TransportResult<TxHash> {
+        let signer = self.wallet.as_ref().zip(tx.from)
+            .filter(|(wallet, from)| NetworkWallet::<AnyNetwork>::has_signer_for(*wallet, from));
+        match signer {
+            Some((wallet, from)) => self.sign_and_send(wallet, from, tx).await,
+            None => ChainProvider::send_transaction(&self.provider, tx).await,
+        }
+    }
+
+    async fn trace_transaction(&self, tx_hash: TxHash) -> TransportResult<serde_json::Value> {
+        ChainProvider::trace_transaction(&self.provider, tx_hash).await
+    }
+
+    async fn get_logs(&self, filter: &Filter) -> TransportResult<Vec<Log>> {
+        ChainProvider::get_logs(&self.provider, filter).await
+    }
+}
+
+/// Gas price `MockProvider` reports unless `with_gas_price` sets one (1 gwei)
+pub const DEFAULT_MOCK_GAS_PRICE: u128 = 1_000_000_000;
+
+/// Canned chain state for offline tests
+///
+/// Unknown balances and nonces read as zero, unknown code as empty, plain value transfers
+/// (calls without input) succeed, and other calls without a registered
+/// response fail like a revert. Balances registered with `with_balance_at` are
+/// served for that block number; other blocks read the current balance. Calls
+/// and nonces ignore the block. Logs are filtered by address, topic0 and block range only.
+/// Tracing a transaction without a registered trace fails like a node without
+/// the debug namespace. Clones share the same state, so a
+/// test can keep a handle to inspect sent transactions after handing the mock
+/// to `BlockchainService::with_provider`.
+#[derive(Clone, Default)]
+pub struct MockProvider {
+    state: Arc<Mutex<MockState>>,
+}
+
+#[derive(Default)]
+struct MockState {
+    block_number: u64,
+    /// Reported chain id, mainnet unless set
+    chain_id: Option<u64>,
+    /// Latest block timestamp, the host clock unless set
+    block_timestamp: Option<u64>,
+    /// Gas price in wei, `DEFAULT_MOCK_GAS_PRICE` unless set
+    gas_price: Option<u128>,
+    balances: HashMap<Address, U256>,
+    /// Balances keyed by (account, block number)
+    historical_balances: HashMap<(Address, u64), U256>,
+    code: HashMap<Address, Bytes>,
+    nonces: HashMap<Address, u64>,
+    /// Return data keyed by (contract, 4-byte selector)
+    call_responses: HashMap<(Address, [u8; 4]), Bytes>,
+    receipts: HashMap<TxHash, AnyTransactionReceipt>,
+    /// callTracer results keyed by transaction hash
+    traces: HashMap<TxHash, serde_json::Value>,
+    logs: Vec<Log>,
+    sent_transactions: Vec<WithOtherFields<TransactionRequest>>,
+    /// When set, `send_transaction` fails with this error message
+    send_failure: Option<String>,
+    /// When set, every call fails with this error message
+    failure: Option<String>,
+    /// Number of upcoming calls that fail with `transient_failure_message`
+    transient_failures: u32,
+    transient_failure_message: String,
+}
+
+impl MockProvider {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the latest block number
+    pub fn with_block_number(self, block_number: u64) -> Self {
+        self.update(|state| state.block_number = block_number)
+    }
+
+    /// Set the chain id reported by the node (mainnet by default)
+    pub fn with_chain_id(self, chain_id: u64) -> Self {
+        self.update(|state| state.chain_id = Some(chain_id))
+    }
+
+    /// Set the latest block timestamp (the host clock by default, like a fresh fork)
+    pub fn with_block_timestamp(self, timestamp: u64) -> Self {
+        self.update(|state| state.block_timestamp = Some(timestamp))
+    }
+
+    /// Set the gas price reported by the node (1 gwei by default)
+    pub fn with_gas_price(self, wei: u128) -> Self {
+        self.update(|state| state.gas_price = Some(wei))
+    }
+
+    /// Set the ETH balance returned for an account
+    pub fn with_balance(self, address: Address, wei: U256) -> Self {
+        self.update(|state| {
+            state.balances.insert(address, wei);
+        })
+    }
+
+    /// Set the ETH balance returned for an account at a specific block number
+    pub fn with_balance_at(self, address: Address, block_number: u64, wei: U256) -> Self {
+        self.update(|state| {
+            state.historical_balances.insert((address, block_number), wei);
+        })
+    }
+
+    /// Set the bytecode returned for an address
+    pub fn with_code(self, address: Address, code: impl Into<Bytes>) -> Self {
+        self.update(|state| {
+            state.code.insert(address, code.into());
+        })
+    }
+
+    /// Set the nonce returned for an account
+    pub fn with_nonce(self, address: Address, nonce: u64) -> Self {
+        self.update(|state| {
+            state.nonces.insert(address, nonce);
+        })
+    }
+
+    /// Return `response` for calls to `signature` (e.g. "balanceOf(address)") on `contract`
+    pub fn with_call_response(self, contract: Address, signature: &str, response: impl Into<Bytes>) -> Self {
+        let selector = function_selector(signature);
+        self.update(|state| {
+            state.call_responses.insert((contract, selector), response.into());
+        })
+    }
+
+    /// Answer calls to `signature` on `contract` with an ABI-encoded string
+    pub fn with_string_response(self, contract: Address, signature: &str, value: &str) -> Self {
+        self.with_call_response(contract, signature, abi_encode_string(value))
+    }
+
+    /// Set the receipt returned for a transaction hash
+    pub fn with_receipt(self, tx_hash: TxHash, receipt: AnyTransactionReceipt) -> Self {
+        self.update(|state| {
+            state.receipts.insert(tx_hash, receipt);
+        })
+    }
+
+    /// Set the callTracer result returned by `trace_transaction` for a transaction hash
+    pub fn with_trace(self, tx_hash: TxHash, trace: serde_json::Value) -> Self {
+        self.update(|state| {
+            state.traces.insert(tx_hash, trace);
+        })
+    }
+
+    /// Add a log returned by `get_logs` when it matches the filter
+    pub fn with_log(self, log: Log) -> Self {
+        self.update(|state| state.logs.push(log))
+    }
+
+    /// Make every call fail with `message`, simulating an unreachable node
+    pub fn failing(self, message: impl Into<String>) -> Self {
+        let message = message.into();
+        self.update(|state| state.failure = Some(message))
+    }
+
+    /// Make `send_transaction` fail with `message`, as a node rejecting the transaction would
+    pub fn with_send_failure(self, message: impl Into<String>) -> Self {
+        let message = message.into();
+        self.update(|state| state.send_failure = Some(message))
+    }
+
+    /// Make the next `count` calls fail with `message` before succeeding again
+    pub fn with_transient_failures(self, count: u32, message: impl Into<String>) -> Self {
+        let message = message.into();
+        self.update(|state| {
+            state.transient_failures = count;
+            state.transient_failure_message = message;
+        })
+    }
+
+    /// Transactions passed to `send_transaction`, in order
+    pub fn sent_transactions(&self) -> Vec<WithOtherFields<TransactionRequest>> {
+        self.state.lock().expect("mock state poisoned").sent_transactions.clone()
+    }
+
+    /// Hash `send_transaction` returns for the `n`th transaction sent, counting from 1
+    pub fn transaction_hash(n: usize) -> TxHash {
+        keccak256(n.to_be_bytes())
+    }
+
+    fn update(self, f: impl FnOnce(&mut MockState)) -> Self {
+        f(&mut self.state.lock().expect("mock state poisoned"));
+        self
+    }
+
+    fn state(&self) -> TransportResult<std::sync::MutexGuard<'_, MockState>> {
+        let mut state = self.state.lock()
+            .map_err(|e| TransportErrorKind::custom_str(&format!("mock state poisoned: {}", e)))?;
+        if let Some(message) = &state.failure {
+            return Err(TransportErrorKind::custom_str(message));
+        }
+        if state.transient_failures > 0 {
+            state.transient_failures -= 1;
+            return Err(TransportErrorKind::custom_str(&state.transient_failure_message));
+        }
+        Ok(state)
+    }
+}
+
+#[async_trait]
+impl ChainProvider for MockProvider {
+    async fn get_block_number(&self) -> TransportResult<u64> {
+        Ok(self.state()?.block_number)
+    }
+
+    async fn get_chain_id(&self) -> TransportResult<u64> {
+        Ok(self.state()?.chain_id.unwrap_or(MAINNET_CHAIN_ID))
+    }
+
+    async fn get_block_timestamp(&self) -> TransportResult<u64> {
+        let timestamp = self.state()?.block_timestamp;
+        Ok(timestamp.unwrap_or_else(|| {
+            SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |elapsed| elapsed.as_secs())
+        }))
+    }
+
+    async fn get_gas_price(&self) -> TransportResult<u128> {
+        Ok(self.state()?.gas_price.unwrap_or(DEFAULT_MOCK_GAS_PRICE))
+    }
+
+    async fn get_balance_at(&self, address: Address, block: BlockId) -> TransportResult<U256> {
+        let state = self.state()?;
+        let historical = match block {
+            BlockId::Number(BlockNumberOrTag::Number(number)) => state.historical_balances.get(&(address, number)),
+            _ => None,
+        };
+        Ok(historical.or_else(|| state.balances.get(&address)).copied().unwrap_or(U256::ZERO))
+    }
+
+    async fn get_code_at(&self, address: Address) -> TransportResult<Bytes> {
+        Ok(self.state()?.code.get(&address).cloned().unwrap_or_default())
+    }
+
+    async fn get_transaction_count(&self, address: Address, _block: BlockId) -> TransportResult<u64> {
+        Ok(self.state()?.nonces.get(&address).copied().unwrap_or(0))
+    }
+
+    async fn call_at(&self, tx: WithOtherFields<TransactionRequest>, _block: BlockId) -> TransportResult<Bytes> {
+        let state = self.state()?;
+        if tx.input.input().is_none_or(|input| input.is_empty()) {
+            return Ok(Bytes::new());
+        }
+        let contract = tx.to.and_then(|to| to.to().copied());
+        let selector = tx.input.input()
+            .and_then(|input| input.get(..4))
+            .and_then(|selector| <[u8; 4]>::try_from(selector).ok());
+
+        contract.zip(selector)
+            .and_then(|key| state.call_responses.get(&key).cloned())
+            .ok_or_else(|| TransportErrorKind::custom_str("execution reverted"))
+    }
+
+    async fn get_transaction_receipt(&self, tx_hash: TxHash) -> TransportResult<Option<AnyTransactionReceipt>> {
+        Ok(self.state()?.receipts.get(&tx_hash).cloned())
+    }
+
+    async fn send_transaction(&self, tx: WithOtherFields<TransactionRequest>) -> TransportResult<TxHash> {
+        let mut state = self.state()?;
+        if let Some(message) = &state.send_failure {
+            return Err(TransportErrorKind::custom_str(message));
+        }
+        state.sent_transactions.push(tx);
+        Ok(Self::transaction_hash(state.sent_transactions.len()))
+    }
+
+    async fn trace_transaction(&self, tx_hash: TxHash) -> TransportResult<serde_json::Value> {
+        self.state()?.traces.get(&tx_hash).cloned()
+            .ok_or_else(|| TransportErrorKind::custom_str("the method debug_traceTransaction does not exist/is not available"))
+    }
+
+    async fn get_logs(&self, filter: &Filter) -> TransportResult<Vec<Log>> {
+        let state = self.state()?;
+        let from_block = filter.get_from_block().unwrap_or(0);
+        let to_block = filter.get_to_block().unwrap_or(u64::MAX);
+        Ok(state.logs.iter()
+            .filter(|log| filter.address.matches(&log.address()))
+            .filter(|log| filter.topics[0].is_empty()
+                || log.topics().first().is_some_and(|topic0| filter.topics[0].matches(topic0)))
+            .filter(|log| log.block_number.is_none_or(|number| (from_block..=to_block).contains(&number)))
+            .cloned()
+            .collect())
+    }
+}
+
+/// ABI-encode a single dynamic string return value
+pub fn abi_encode_string(value: &str) -> Bytes {
+    let mut bytes = U256::from(32).to_be_bytes::<32>().to_vec();
+    bytes.extend_from_slice(&U256::from(value.len()).to_be_bytes::<32>());
+    bytes.extend_from_slice(value.as_bytes());
+    bytes.resize(64 + value.len().div_ceil(32) * 32, 0);
+    Bytes::from(bytes)
+}
+
+fn function_selector(signature: &str) -> [u8; 4] {
+    let hash = keccak256(signature.as_bytes());
+    [hash[0], hash[1], hash[2], hash[3]]
+}
@@ -1,10 +1,12 @@
 //! Brave Search API MCP Server Implementation
 //! 
 //! This module implements Brave Search API functionality as MCP tools.
-//! Provides web search capabilities for blockchain-related queries.
+//! Provides web search capabilities for blockchain-related queries. Searches go
+//! through a `SearchProvider` (see `search_provider`), so without a Brave key the
+//! tools fall back to SearXNG or DuckDuckGo.
 //! 
 //! Tools exposed:
-//! - web_search: Search the web using Brave Search API
+//! - web_search: Search the web using Brave Search API (paged with count/offset)
 //! - get_token_price: Get token prices from search results
 //! - get_contract_info: Search for contract information
 
@@ -15,9 +17,19 @@ use rmcp::{
 };
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use std::env;
-use tracing::{info, error};
+use std::{
+    collections::{HashMap, VecDeque},
+    env,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+use tracing::info;
 use dotenv;
+use crate::services::pagination::Page;
+use crate::services::search_provider::{provider_from_env, BraveSearchProvider, SearchProvider};
+
+/// Most results Brave Search returns for one query, so `offset + count` can't exceed it
+pub const MAX_SEARCH_RESULTS: u32 = 20;
 
 /// Request structure for web searches
 #[derive(Debug, Serialize, Deserialize, JsonSchema)]
@@ -30,6 +42,8 @@ pub struct WebSearchRequest {
     pub country: Option<String>,
     #[schemars(description = "Search language (default: 'en')")]
     pub search_lang: Option<String>,
+    #[schemars(description = "Number of results to skip, from a previous response's pagination footer (default: 0; offset + count must be at most 20)")]
+    pub offset: Option<u32>,
 }
 
 /// Request structure for token price searches
@@ -63,40 +77,6 @@ pub struct SwapIntentRequest {
     pub dex: Option<String>,
 }
 
-/// Brave Search API response structure - based on actual API response
-#[derive(Debug, Serialize, Deserialize)]
-struct BraveSearchResponse {
-    /// Query information (can be string or object)
-    query: Option<serde_json::Value>,
-    /// Web search results
-    web: Option<WebResults>,
-    /// Any additional fields from the API
-    #[serde(flatten)]
-    extra: std::collections::HashMap<String, serde_json::Value>,
-}
-
-#[derive(Debug, Serialize, Deserialize)]
-struct WebResults {
-    /// Search results
-    results: Vec<WebResult>,
-    /// Any additional fields from the API
-    #[serde(flatten)]
-    extra: std::collections::HashMap<String, serde_json::Value>,
-}
-
-#[derive(Debug, Serialize, Deserialize)]
-struct WebResult {
-    /// Result title
-    title: String,
-    /// Result URL
-    url: String,
-    /// Result description
-    description: String,
-    /// Any additional fields from the API
-    #[serde(flatten)]
-    extra: std::collections::HashMap<String, serde_json::Value>,
-}
-
 /// Search result structure for MCP responses
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct SearchResult {
@@ -109,7 +89,7 @@ pub struct SearchResult {
 }
 
 /// Search response structure
-#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct SearchResponse {
     #[schemars(description = "Search query")]
     pub query: String,
@@ -117,6 +97,32 @@ pub struct SearchResponse {
     pub results: Vec<SearchResult>,
     #[schemars(description = "Total number of results")]
     pub total_results: usize,
+    #[schemars(description = "Position of the first result in the full result list")]
+    #[serde(default)]
+    pub offset: usize,
+    #[schemars(description = "Which results are shown and the offset that fetches more")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pagination: Option<String>,
+}
+
+impl SearchResponse {
+    /// Slice `count` results starting at `offset` out of a fetched response
+    ///
+    /// `fetched` is how many results were requested from Brave: a full batch
+    /// below `MAX_SEARCH_RESULTS` means more results may exist past this page.
+    pub fn page(&self, offset: usize, count: usize, fetched: usize) -> SearchResponse {
+        let results: Vec<SearchResult> = self.results.iter().skip(offset).take(count).cloned().collect();
+        let has_more = offset + results.len() < self.results.len()
+            || (self.results.len() >= fetched && fetched < MAX_SEARCH_RESULTS as usize);
+        let page = Page::open_ended(results, offset, has_more);
+        SearchResponse {
+            query: self.query.clone(),
+            total_results: self.total_results,
+            offset,
+            pagination: Some(page.footer()),
+            results: page.items,
+        }
+    }
 }
 
 /// Swap intent response structure
@@ -134,22 +140,230 @@ pub struct SwapIntentResponse {
     pub estimated_params: String,
 }
 
-/// Brave Search MCP Service
+/// Longest response body excerpt included in error messages
+const ERROR_BODY_SNIPPET_CHARS: usize = 200;
+
+/// Parse a `Retry-After` header given in seconds
+///
+/// The HTTP-date form is not supported and yields `None`.
+pub fn parse_retry_after(value: &str) -> Option<Duration> {
+    value.trim().parse::<u64>().ok().map(Duration::from_secs)
+}
+
+/// Shorten a response body for inclusion in an error message
+pub fn body_snippet(body: &str) -> String {
+    let body = body.trim();
+    if body.chars().count() <= ERROR_BODY_SNIPPET_CHARS {
+        return body.to_string();
+    }
+    let snippet: String = body.chars().take(ERROR_BODY_SNIPPET_CHARS).collect();
+    format!("{}...", snippet)
+}
+
+/// Default limit on a whole Brave Search request, in seconds (override with SEARCH_TIMEOUT_SECS)
+pub const DEFAULT_SEARCH_TIMEOUT_SECS: u64 = 10;
+
+/// Default limit on opening the connection, in seconds (override with SEARCH_CONNECT_TIMEOUT_SECS)
+pub const DEFAULT_SEARCH_CONNECT_TIMEOUT_SECS: u64 = 5;
+
+/// User agent sent with every Brave Search request
+pub const SEARCH_USER_AGENT: &str = concat!("mcp-server/", env!("CARGO_PKG_VERSION"), " (blockchain agent web search)");
+
+/// Timeouts for the shared Brave Search HTTP client
+///
+/// Without them a hung endpoint would block the tool call, and the agent
+/// waiting on it, indefinitely.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SearchClientConfig {
+    /// Limit on the whole request, from connecting to reading the body
+    pub timeout: Duration,
+    /// Limit on establishing the connection
+    pub connect_timeout: Duration,
+}
+
+impl Default for SearchClientConfig {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(DEFAULT_SEARCH_TIMEOUT_SECS),
+            connect_timeout: Duration::from_secs(DEFAULT_SEARCH_CONNECT_TIMEOUT_SECS),
+        }
+    }
+}
+
+impl SearchClientConfig {
+    /// Read `SEARCH_TIMEOUT_SECS` and `SEARCH_CONNECT_TIMEOUT_SECS`, ignoring zero or unparseable values
+    pub fn from_env() -> Self {
+        let seconds = |name: &str, default: u64| {
+            env::var(name)
+                .ok()
+                .and_then(|value| value.trim().parse::<u64>().ok())
+                .filter(|secs| *secs > 0)
+                .unwrap_or(default)
+        };
+        let config = Self {
+            timeout: Duration::from_secs(seconds("SEARCH_TIMEOUT_SECS", DEFAULT_SEARCH_TIMEOUT_SECS)),
+            connect_timeout: Duration::from_secs(seconds("SEARCH_CONNECT_TIMEOUT_SECS", DEFAULT_SEARCH_CONNECT_TIMEOUT_SECS)),
+        };
+
+        info!("⏱️  Search client: {:?} request timeout, {:?} connect timeout", config.timeout, config.connect_timeout);
+        config
+    }
+
+    /// Build the HTTP client with these timeouts and `SEARCH_USER_AGENT`
+    pub fn build_client(&self) -> Result<Client> {
+        Ok(Client::builder()
+            .timeout(self.timeout)
+            .connect_timeout(self.connect_timeout)
+            .user_agent(SEARCH_USER_AGENT)
+            .build()?)
+    }
+}
+
+/// Map a failed search request to an MCP error, calling out timeouts
+///
+/// Timeouts carry `{"kind": "timeout"}` as error data and say so in the
+/// message, so the agent can tell the user the search timed out rather than
+/// reporting a generic failure.
+pub fn search_request_error(provider: &str, error: &reqwest::Error, timeout: Duration) -> McpError {
+    if error.is_timeout() {
+        return McpError::internal_error(
+            format!(
+                "{} timed out after {}s - the search service is slow or unreachable, try again shortly (SEARCH_TIMEOUT_SECS sets the limit)",
+                provider, timeout.as_secs()
+            ),
+            Some(serde_json::json!({ "kind": "timeout" })),
+        );
+    }
+    McpError::internal_error(format!("API request failed: {}", error), None)
+}
+
+/// Default time a cached search response stays valid, in seconds
+pub const DEFAULT_SEARCH_CACHE_TTL_SECS: u64 = 300;
+
+/// Default maximum number of cached search responses
+pub const DEFAULT_SEARCH_CACHE_MAX_ENTRIES: usize = 100;
+
+/// A cached search response and when it was stored
+#[derive(Debug, Clone)]
+struct CachedSearch {
+    response: SearchResponse,
+    stored_at: Instant,
+}
+
+/// In-memory TTL cache of search responses with least-recently-used eviction
+///
+/// Each entry holds the full `MAX_SEARCH_RESULTS` result set for a query and
+/// locale, so every page of the same search is sliced out of one entry. A zero
+/// TTL or capacity disables caching.
+#[derive(Debug)]
+pub struct SearchCache {
+    ttl: Duration,
+    max_entries: usize,
+    entries: HashMap<String, CachedSearch>,
+    /// Keys ordered from least to most recently used
+    recency: VecDeque<String>,
+}
+
+impl SearchCache {
+    pub fn new(ttl: Duration, max_entries: usize) -> Self {
+        Self {
+            ttl,
+            max_entries,
+            entries: HashMap::new(),
+            recency: VecDeque::new(),
+        }
+    }
+
+    /// Build a cache from `SEARCH_CACHE_TTL_SECS` and `SEARCH_CACHE_MAX_ENTRIES`
+    pub fn from_env() -> Self {
+        let ttl_secs = env::var("SEARCH_CACHE_TTL_SECS")
+            .ok()
+            .and_then(|value| value.trim().parse::<u64>().ok())
+            .unwrap_or(DEFAULT_SEARCH_CACHE_TTL_SECS);
+        let max_entries = env::var("SEARCH_CACHE_MAX_ENTRIES")
+            .ok()
+            .and_then(|value| value.trim().parse::<usize>().ok())
+            .unwrap_or(DEFAULT_SEARCH_CACHE_MAX_ENTRIES);
+
+        info!("🗄️  Search cache: {}s TTL, up to {} entries", ttl_secs, max_entries);
+        Self::new(Duration::from_secs(ttl_secs), max_entries)
+    }
+
+    /// Cache key for a search, independent of the requested page
+    pub fn key(query: &str, country: &str, search_lang: &str) -> String {
+        format!("{}|{}|{}", query.trim().to_lowercase(), country, search_lang)
+    }
+
+    /// Look up a fresh response, dropping it if it has expired
+    pub fn get(&mut self, key: &str) -> Option<SearchResponse> {
+        let entry = self.entries.get(key)?;
+        if entry.stored_at.elapsed() >= self.ttl {
+            self.remove(key);
+            return None;
+        }
+
+        let response = entry.response.clone();
+        self.touch(key);
+        Some(response)
+    }
+
+    /// Store a response, evicting the least recently used entry when full
+    pub fn insert(&mut self, key: String, response: SearchResponse) {
+        if self.ttl.is_zero() || self.max_entries == 0 {
+            return;
+        }
+
+        if self.entries.contains_key(&key) {
+            self.touch(&key);
+        } else {
+            while self.entries.len() >= self.max_entries {
+                let Some(oldest) = self.recency.pop_front() else { break };
+                self.entries.remove(&oldest);
+            }
+            self.recency.push_back(key.clone());
+        }
+        self.entries.insert(key, CachedSearch { response, stored_at: Instant::now() });
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    fn touch(&mut self, key: &str) {
+        if let Some(position) = self.recency.iter().position(|k| k == key)
+            && let Some(k) = self.recency.remove(position)
+        {
+            self.recency.push_back(k);
+        }
+    }
+
+    fn remove(&mut self, key: &str) {
+        self.entries.remove(key);
+        self.recency.retain(|k| k != key);
+    }
+}
+
+/// Web search MCP Service, backed by Brave Search or a keyless fallback
 #[derive(Clone)]
 pub struct SearchService {
-    /// HTTP client for API requests
-    client: Client,
-    /// Brave Search API key
-    api_key: String,
-    /// Base URL for Brave Search API
-    base_url: String,
+    /// Backend the searches are sent to
+    provider: Arc<dyn SearchProvider>,
+    /// Recent search responses, shared across clones of the service
+    cache: Arc<Mutex<SearchCache>>,
 }
 
 #[tool_router]
 impl SearchService {
     /// Create a new Search service instance
+    ///
+    /// Uses Brave Search when `BRAVE_SEARCH_API_KEY` is set; otherwise falls back
+    /// to SearXNG (`SEARXNG_URL`) or DuckDuckGo, so web search stays available.
     pub async fn new() -> Result<Self> {
-        info!("🔍 Creating Brave Search service");
+        info!("🔍 Creating web search service");
         
         // Load .env file if it exists
         if dotenv::dotenv().is_err() {
@@ -158,79 +372,93 @@ impl SearchService {
             info!("📝 Loaded .env file");
         }
         
-        // Get API key from environment
-        let api_key = env::var("BRAVE_SEARCH_API_KEY")
-            .map_err(|_| anyhow::anyhow!("BRAVE_SEARCH_API_KEY environment variable not set"))?;
-        
-        // Create HTTP client
-        let client = Client::new();
-        
-        Ok(Self {
-            client,
-            api_key,
-            base_url: "https://api.search.brave.com/res/v1/web/search".to_string(),
-        })
+        Ok(Self::with_provider(provider_from_env(SearchClientConfig::from_env())?))
     }
 
-    /// Perform a web search using Brave Search API
-    #[tool(description = "Search the web using Brave Search API")]
+    /// Create a Brave Search service against a custom endpoint with the given client timeouts
+    pub fn with_endpoint(api_key: String, base_url: String, client_config: SearchClientConfig) -> Result<Self> {
+        Ok(Self::with_provider(Arc::new(BraveSearchProvider::new(api_key, base_url, client_config)?)))
+    }
+
+    /// Create a Search service that sends its searches to `provider`
+    pub fn with_provider(provider: Arc<dyn SearchProvider>) -> Self {
+        Self {
+            provider,
+            cache: Arc::new(Mutex::new(SearchCache::from_env())),
+        }
+    }
+
+    /// Name of the backend searches are sent to
+    pub fn provider_name(&self) -> &'static str {
+        self.provider.name()
+    }
+
+    /// Perform a web search using Brave Search API, or the configured fallback
+    #[tool(description = "Search the web using Brave Search API (or SearXNG/DuckDuckGo when no Brave key is configured)")]
     pub async fn web_search(
         &self,
-        Parameters(WebSearchRequest { query, count, country, search_lang }): Parameters<WebSearchRequest>,
+        Parameters(WebSearchRequest { query, count, country, search_lang, offset }): Parameters<WebSearchRequest>,
     ) -> Result<CallToolResult, McpError> {
-        info!("🔍 [BRAVE API] Performing web search: {}", query);
-        info!("🌐 [BRAVE API] Using Brave Search API with parameters: count={}, country={}, lang={}", 
+        info!("🔍 [{}] Performing web search: {}", self.provider.name(), query);
+        info!("🌐 [{}] Using parameters: count={}, offset={}, country={}, lang={}", 
+            self.provider.name(),
             count.unwrap_or(10), 
+            offset.unwrap_or(0),
             country.as_ref().unwrap_or(&"us".to_string()), 
             search_lang.as_ref().unwrap_or(&"en".to_string())
         );
         
-        // Build request parameters
-        let params = vec![
-            ("q", query.clone()),
-            ("count", count.unwrap_or(10).to_string()),
-            ("country", country.unwrap_or_else(|| "us".to_string())),
-            ("search_lang", search_lang.unwrap_or_else(|| "en".to_string())),
-        ];
-        
-        // Make API request
-        let response = self.client
-            .get(&self.base_url)
-            .header("X-Subscription-Token", &self.api_key)
-            .query(&params)
-            .send()
-            .await
-            .map_err(|e| {
-                error!("❌ [BRAVE API] Failed to make Brave Search API request: {}", e);
-                McpError::internal_error(format!("API request failed: {}", e), None)
-            })?;
-        
-        // Parse response
-        let search_response: BraveSearchResponse = response.json().await
-            .map_err(|e| {
-                error!("❌ [BRAVE API] Failed to parse Brave Search API response: {}", e);
-                McpError::internal_error(format!("Failed to parse response: {}", e), None)
-            })?;
-        
-        // Convert to our response format
-        let results: Vec<SearchResult> = search_response.web
-            .map(|web| web.results.into_iter().map(|r| SearchResult {
-                title: r.title,
-                url: r.url,
-                description: r.description,
-            }).collect())
-            .unwrap_or_default();
+        let count = count.unwrap_or(10);
+        let offset = offset.unwrap_or(0);
+        let country = country.unwrap_or_else(|| "us".to_string());
+        let search_lang = search_lang.unwrap_or_else(|| "en".to_string());
+        
+        if count == 0 || offset.saturating_add(count) > MAX_SEARCH_RESULTS {
+            return Err(McpError::invalid_params(
+                format!(
+                    "Invalid count {} with offset {}: Brave Search returns at most {} results, so count must be at least 1 and offset + count at most {}",
+                    count, offset, MAX_SEARCH_RESULTS, MAX_SEARCH_RESULTS
+                ),
+                None
+            ));
+        }
+        
+        // Fetch the whole result set once, so later pages are sliced from the cache
+        let fetch_count = MAX_SEARCH_RESULTS;
+        
+        // Serve repeated searches and further pages from the cache to save API quota
+        let cache_key = SearchCache::key(&query, &country, &search_lang);
+        let cached = self.cache.lock()
+            .map_err(|e| McpError::internal_error(format!("Cache lock error: {}", e), None))?
+            .get(&cache_key);
+        if let Some(search_response) = cached {
+            info!("♻️  [{}] Cache hit for '{}' ({} results)", self.provider.name(), query, search_response.total_results);
+            return Self::search_result(&search_response.page(offset as usize, count as usize, fetch_count as usize));
+        }
+        
+        let results = self.provider.search_localized(&query, fetch_count, &country, &search_lang).await?;
         
         let search_response = SearchResponse {
             query,
             results: results.clone(),
             total_results: results.len(),
+            offset: 0,
+            pagination: None,
         };
         
-        info!("✅ [BRAVE API] Web search completed with {} results", search_response.total_results);
+        info!("✅ [{}] Web search completed with {} results", self.provider.name(), search_response.total_results);
+        
+        self.cache.lock()
+            .map_err(|e| McpError::internal_error(format!("Cache lock error: {}", e), None))?
+            .insert(cache_key, search_response.clone());
         
+        Self::search_result(&search_response.page(offset as usize, count as usize, fetch_count as usize))
+    }
+
+    /// Render a search response as an MCP tool result
+    fn search_result(search_response: &SearchResponse) -> Result<CallToolResult, McpError> {
         Ok(CallToolResult::success(vec![Content::text(
-            serde_json::to_string_pretty(&search_response)
+            serde_json::to_string_pretty(search_response)
                 .map_err(|e| McpError::internal_error(format!("Failed to serialize response: {}", e), None))?
         )]))
     }
@@ -257,6 +485,7 @@ impl SearchService {
             count: Some(5),
             country: Some("us".to_string()),
             search_lang: Some("en".to_string()),
+            offset: None,
         };
         
         // Call web search internally
@@ -287,6 +516,7 @@ impl SearchService {
             count: Some(5),
             country: Some("us".to_string()),
             search_lang: Some("en".to_string()),
+            offset: None,
         };
         
         // Call web search internally
@@ -317,6 +547,7 @@ impl SearchService {
             count: Some(3),
             country: Some("us".to_string()),
             search_lang: Some("en".to_string()),
+            offset: None,
         };
         
         let _dex_result = self.web_search(Parameters(dex_search_request)).await?;
@@ -330,6 +561,7 @@ impl SearchService {
             count: Some(3),
             country: Some("us".to_string()),
             search_lang: Some("en".to_string()),
+            offset: None,
         };
         
         let _price_result = self.web_search(Parameters(price_search_request)).await?;
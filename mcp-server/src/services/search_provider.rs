@@ -0,0 +1,362 @@
+//! Web search backends for the search service
+//!
+//! `SearchService` runs its queries through the `SearchProvider` trait so web
+//! search keeps working without a Brave key: Brave Search is used when
+//! `BRAVE_SEARCH_API_KEY` is set, then a SearXNG instance when `SEARXNG_URL` is
+//! set, and otherwise DuckDuckGo's keyless Instant Answer API.
+
+use async_trait::async_trait;
+use reqwest::{Client, StatusCode};
+use rmcp::ErrorData as McpError;
+use serde::Deserialize;
+use serde_json::Value;
+use std::{env, sync::Arc, time::Duration};
+use tracing::{error, info, warn};
+
+use crate::services::search::{
+    body_snippet, parse_retry_after, search_request_error, SearchClientConfig, SearchResult,
+};
+
+/// Brave Search web search endpoint
+pub const BRAVE_SEARCH_URL: &str = "https://api.search.brave.com/res/v1/web/search";
+
+/// DuckDuckGo Instant Answer endpoint (no API key required)
+pub const DUCKDUCKGO_URL: &str = "https://api.duckduckgo.com/";
+
+/// Total attempts for a search that is rate limited (429) or unavailable (503)
+const SEARCH_MAX_ATTEMPTS: u32 = 3;
+
+/// Backoff before the first retry when the API sends no `Retry-After`
+const SEARCH_RETRY_BASE_DELAY: Duration = Duration::from_millis(500);
+
+/// Longest `Retry-After` wait honoured before retrying inside a single tool call
+const MAX_RETRY_AFTER: Duration = Duration::from_secs(10);
+
+/// A web search backend
+#[async_trait]
+pub trait SearchProvider: Send + Sync {
+    /// Display name used in logs and error messages (e.g. "Brave Search")
+    fn name(&self) -> &'static str;
+
+    /// Return up to `count` results for `query`
+    async fn search(&self, query: &str, count: u32) -> Result<Vec<SearchResult>, McpError>;
+
+    /// Search with a country and language hint; providers without locale support ignore it
+    async fn search_localized(&self, query: &str, count: u32, _country: &str, _search_lang: &str) -> Result<Vec<SearchResult>, McpError> {
+        self.search(query, count).await
+    }
+}
+
+/// Pick a provider from the environment: Brave, then SearXNG, then DuckDuckGo
+pub fn provider_from_env(client_config: SearchClientConfig) -> anyhow::Result<Arc<dyn SearchProvider>> {
+    let configured = |name: &str| env::var(name).ok().map(|value| value.trim().to_string()).filter(|value| !value.is_empty());
+
+    let provider: Arc<dyn SearchProvider> = if let Some(api_key) = configured("BRAVE_SEARCH_API_KEY") {
+        Arc::new(BraveSearchProvider::new(api_key, BRAVE_SEARCH_URL.to_string(), client_config)?)
+    } else if let Some(base_url) = configured("SEARXNG_URL") {
+        warn!("⚠️  BRAVE_SEARCH_API_KEY not set - using the SearXNG instance at {} for web search", base_url);
+        Arc::new(SearxngSearchProvider::new(base_url, client_config)?)
+    } else {
+        warn!("⚠️  BRAVE_SEARCH_API_KEY not set - falling back to DuckDuckGo instant answers for web search");
+        Arc::new(DuckDuckGoSearchProvider::new(DUCKDUCKGO_URL.to_string(), client_config)?)
+    };
+    info!("🔍 Web search provider: {}", provider.name());
+    Ok(provider)
+}
+
+/// Send a GET request, mapping transport failures (and timeouts) to MCP errors
+async fn send_request(
+    provider: &str,
+    request: reqwest::RequestBuilder,
+    client_config: &SearchClientConfig,
+) -> Result<reqwest::Response, McpError> {
+    request.send().await.map_err(|e| {
+        error!("❌ [{}] Request failed: {}", provider, e);
+        search_request_error(provider, &e, client_config.timeout)
+    })
+}
+
+/// Read a successful response as JSON, or fail with the status and a body snippet
+async fn json_body(provider: &str, response: reqwest::Response, client_config: &SearchClientConfig) -> Result<Value, McpError> {
+    let status = response.status();
+    if !status.is_success() {
+        let body = response.text().await.unwrap_or_default();
+        error!("❌ [{}] Request failed with HTTP {}", provider, status);
+        return Err(McpError::internal_error(
+            format!("{} returned HTTP {}: {}", provider, status, body_snippet(&body)),
+            None
+        ));
+    }
+    response.json().await.map_err(|e| {
+        error!("❌ [{}] Failed to parse response: {}", provider, e);
+        if e.is_timeout() {
+            search_request_error(provider, &e, client_config.timeout)
+        } else {
+            McpError::internal_error(format!("Failed to parse response: {}", e), None)
+        }
+    })
+}
+
+/// Brave Search API response structure - based on actual API response
+#[derive(Debug, Deserialize)]
+struct BraveSearchResponse {
+    /// Web search results
+    web: Option<BraveWebResults>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BraveWebResults {
+    results: Vec<BraveWebResult>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BraveWebResult {
+    title: String,
+    url: String,
+    description: String,
+}
+
+/// Brave Search API, authenticated with `BRAVE_SEARCH_API_KEY`
+pub struct BraveSearchProvider {
+    client: Client,
+    api_key: String,
+    base_url: String,
+    client_config: SearchClientConfig,
+}
+
+impl BraveSearchProvider {
+    pub fn new(api_key: String, base_url: String, client_config: SearchClientConfig) -> anyhow::Result<Self> {
+        Ok(Self {
+            client: client_config.build_client()?,
+            api_key,
+            base_url,
+            client_config,
+        })
+    }
+
+    /// Send a search request, retrying briefly on rate limits (429) and unavailability (503)
+    ///
+    /// Waits for the `Retry-After` header when present (capped at `MAX_RETRY_AFTER`),
+    /// otherwise backs off exponentially. Other non-2xx statuses fail immediately
+    /// with the status and a snippet of the response body.
+    async fn send_search_request(&self, params: &[(&str, String)]) -> Result<reqwest::Response, McpError> {
+        let mut attempt = 1;
+        loop {
+            let request = self.client
+                .get(&self.base_url)
+                .header("X-Subscription-Token", &self.api_key)
+                .query(params);
+            let response = send_request(self.name(), request, &self.client_config).await?;
+
+            let status = response.status();
+            if status.is_success() {
+                return Ok(response);
+            }
+
+            let retry_after = response.headers()
+                .get(reqwest::header::RETRY_AFTER)
+                .and_then(|value| value.to_str().ok())
+                .and_then(parse_retry_after);
+            let retryable = status == StatusCode::TOO_MANY_REQUESTS || status == StatusCode::SERVICE_UNAVAILABLE;
+
+            if retryable && attempt < SEARCH_MAX_ATTEMPTS {
+                let delay = retry_after
+                    .map(|delay| delay.min(MAX_RETRY_AFTER))
+                    .unwrap_or_else(|| SEARCH_RETRY_BASE_DELAY * 2u32.pow(attempt - 1));
+                warn!("⚠️  [BRAVE API] HTTP {} (attempt {}/{}), retrying in {:?}",
+                    status.as_u16(), attempt, SEARCH_MAX_ATTEMPTS, delay);
+                tokio::time::sleep(delay).await;
+                attempt += 1;
+                continue;
+            }
+
+            if status == StatusCode::TOO_MANY_REQUESTS {
+                let advice = match retry_after {
+                    Some(delay) => format!("Retry after {} seconds.", delay.as_secs()),
+                    None => "Wait a moment before retrying.".to_string(),
+                };
+                error!("❌ [BRAVE API] Rate limited after {} attempt(s)", attempt);
+                return Err(McpError::internal_error(
+                    format!("Brave Search rate limit exceeded (HTTP 429). {}", advice),
+                    None
+                ));
+            }
+
+            let body = response.text().await.unwrap_or_default();
+            error!("❌ [BRAVE API] Request failed with HTTP {}", status);
+            return Err(McpError::internal_error(
+                format!("Brave Search API returned HTTP {}: {}", status, body_snippet(&body)),
+                None
+            ));
+        }
+    }
+}
+
+#[async_trait]
+impl SearchProvider for BraveSearchProvider {
+    fn name(&self) -> &'static str {
+        "Brave Search"
+    }
+
+    async fn search(&self, query: &str, count: u32) -> Result<Vec<SearchResult>, McpError> {
+        self.search_localized(query, count, "us", "en").await
+    }
+
+    async fn search_localized(&self, query: &str, count: u32, country: &str, search_lang: &str) -> Result<Vec<SearchResult>, McpError> {
+        let params = vec![
+            ("q", query.to_string()),
+            ("count", count.to_string()),
+            ("country", country.to_string()),
+            ("search_lang", search_lang.to_string()),
+        ];
+        let response = self.send_search_request(&params).await?;
+
+        let search_response: BraveSearchResponse = response.json().await
+            .map_err(|e| {
+                error!("❌ [BRAVE API] Failed to parse Brave Search API response: {}", e);
+                if e.is_timeout() {
+                    search_request_error(self.name(), &e, self.client_config.timeout)
+                } else {
+                    McpError::internal_error(format!("Failed to parse response: {}", e), None)
+                }
+            })?;
+
+        Ok(search_response.web
+            .map(|web| web.results.into_iter().map(|r| SearchResult {
+                title: r.title,
+                url: r.url,
+                description: r.description,
+            }).collect())
+            .unwrap_or_default())
+    }
+}
+
+/// Results of a SearXNG `format=json` response, at most `count`
+pub fn parse_searxng_results(body: &Value, count: usize) -> Vec<SearchResult> {
+    body["results"].as_array()
+        .map(|results| results.iter()
+            .filter_map(|result| {
+                let url = result["url"].as_str()?;
+                Some(SearchResult {
+                    title: result["title"].as_str().unwrap_or(url).to_string(),
+                    url: url.to_string(),
+                    description: result["content"].as_str().unwrap_or_default().to_string(),
+                })
+            })
+            .take(count)
+            .collect())
+        .unwrap_or_default()
+}
+
+/// A self-hosted or public SearXNG instance (`SEARXNG_URL`), queried through its JSON API
+///
+/// The instance must have the `json` output format enabled.
+pub struct SearxngSearchProvider {
+    client: Client,
+    base_url: String,
+    client_config: SearchClientConfig,
+}
+
+impl SearxngSearchProvider {
+    pub fn new(base_url: String, client_config: SearchClientConfig) -> anyhow::Result<Self> {
+        Ok(Self {
+            client: client_config.build_client()?,
+            base_url,
+            client_config,
+        })
+    }
+}
+
+#[async_trait]
+impl SearchProvider for SearxngSearchProvider {
+    fn name(&self) -> &'static str {
+        "SearXNG"
+    }
+
+    async fn search(&self, query: &str, count: u32) -> Result<Vec<SearchResult>, McpError> {
+        self.search_localized(query, count, "us", "en").await
+    }
+
+    async fn search_localized(&self, query: &str, count: u32, _country: &str, search_lang: &str) -> Result<Vec<SearchResult>, McpError> {
+        let url = format!("{}/search", self.base_url.trim_end_matches('/'));
+        let request = self.client
+            .get(url)
+            .query(&[("q", query), ("format", "json"), ("language", search_lang)]);
+        let response = send_request(self.name(), request, &self.client_config).await?;
+        let body = json_body(self.name(), response, &self.client_config).await?;
+        Ok(parse_searxng_results(&body, count as usize))
+    }
+}
+
+/// Results of a DuckDuckGo Instant Answer response, at most `count`
+///
+/// The abstract (when present) comes first, followed by related topics, with
+/// topic groups flattened. A topic's title is its text up to the first " - ".
+pub fn parse_duckduckgo_results(body: &Value, count: usize) -> Vec<SearchResult> {
+    let mut results = Vec::new();
+
+    if let (Some(url), Some(text)) = (body["AbstractURL"].as_str(), body["AbstractText"].as_str())
+        && !url.is_empty() && !text.is_empty()
+    {
+        results.push(SearchResult {
+            title: body["Heading"].as_str().filter(|heading| !heading.is_empty()).unwrap_or(url).to_string(),
+            url: url.to_string(),
+            description: text.to_string(),
+        });
+    }
+
+    if let Some(topics) = body["RelatedTopics"].as_array() {
+        collect_duckduckgo_topics(topics, &mut results);
+    }
+
+    results.truncate(count);
+    results
+}
+
+/// Append related topics to `results`, descending into topic groups
+fn collect_duckduckgo_topics(topics: &[Value], results: &mut Vec<SearchResult>) {
+    for topic in topics {
+        if let Some(group) = topic["Topics"].as_array() {
+            collect_duckduckgo_topics(group, results);
+        } else if let (Some(url), Some(text)) = (topic["FirstURL"].as_str(), topic["Text"].as_str()) {
+            results.push(SearchResult {
+                title: text.split(" - ").next().unwrap_or(text).to_string(),
+                url: url.to_string(),
+                description: text.to_string(),
+            });
+        }
+    }
+}
+
+/// DuckDuckGo's Instant Answer API: needs no key, but returns topic summaries rather than full web results
+pub struct DuckDuckGoSearchProvider {
+    client: Client,
+    base_url: String,
+    client_config: SearchClientConfig,
+}
+
+impl DuckDuckGoSearchProvider {
+    pub fn new(base_url: String, client_config: SearchClientConfig) -> anyhow::Result<Self> {
+        Ok(Self {
+            client: client_config.build_client()?,
+            base_url,
+            client_config,
+        })
+    }
+}
+
+#[async_trait]
+impl SearchProvider for DuckDuckGoSearchProvider {
+    fn name(&self) -> &'static str {
+        "DuckDuckGo"
+    }
+
+    async fn search(&self, query: &str, count: u32) -> Result<Vec<SearchResult>, McpError> {
+        let request = self.client
+            .get(&self.base_url)
+            .query(&[("q", query), ("format", "json"), ("no_html", "1"), ("skip_disambig", "1")]);
+        let response = send_request(self.name(), request, &self.client_config).await?;
+        let body = json_body(self.name(), response, &self.client_config).await?;
+        Ok(parse_duckduckgo_results(&body, count as usize))
+    }
+}
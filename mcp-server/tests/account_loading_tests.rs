@@ -4,8 +4,14 @@
 //! including matching known anvil addresses with their private keys.
 
 use alloy_primitives::Address;
+use mcp_server::config::BlockchainConfig;
+use mcp_server::services::blockchain::{load_signers, BlockchainService};
+use mcp_server::services::provider::MockProvider;
 use std::str::FromStr;
 
+const ALICE_KEY: &str = "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+const BOB_KEY: &str = "0x59c6995e998f97a5a0044966f0945389dc9e86dae88c7a8412f4603b6b78690d";
+
 #[tokio::test]
 async fn test_dynamic_account_loading() {
     println!("\n🧪 Testing dynamic account loading functionality...");
@@ -65,3 +71,37 @@ async fn test_dynamic_account_loading() {
     println!("\n📊 SUMMARY: Dynamic account matching logic works correctly");
     println!("🔚 Dynamic account loading test completed\n");
 }
+
+#[tokio::test]
+async fn test_private_keys_must_match_their_accounts() {
+    println!("\n🧪 Testing configured private keys are checked against their accounts...");
+
+    let mut config = BlockchainConfig {
+        alice_private_key: ALICE_KEY.to_string(),
+        ..BlockchainConfig::default()
+    };
+    config.account_private_keys.insert(1, BOB_KEY.to_string());
+    let signers = load_signers(&config).unwrap();
+    println!("✅ OUTPUT: signers for {:?}", signers.keys().collect::<Vec<_>>());
+    assert_eq!(signers.len(), 2);
+    assert!(signers.contains_key(&config.accounts[0]));
+    assert!(signers.contains_key(&config.accounts[1]));
+
+    // Bob's key configured as Alice's is refused, and so is the service
+    let swapped = BlockchainConfig {
+        alice_private_key: BOB_KEY.to_string(),
+        ..BlockchainConfig::default()
+    };
+    let error = load_signers(&swapped).expect_err("A key for another account is refused");
+    println!("✅ OUTPUT: {}", error);
+    assert!(error.contains("belongs to 0x70997970C51812dc3A010C7d01b50e0d17dc79C8"));
+    assert!(BlockchainService::with_provider_and_config(MockProvider::new(), swapped).await.is_err());
+
+    let invalid = BlockchainConfig {
+        alice_private_key: "0x1234".to_string(),
+        ..BlockchainConfig::default()
+    };
+    assert!(load_signers(&invalid).expect_err("Malformed keys are refused").contains("Invalid private key for account 0"));
+
+    println!("🔚 Test completed\n");
+}
@@ -0,0 +1,109 @@
+//! Account Name Matching Tests for MCP Blockchain Server
+//!
+//! These tests verify the tolerant account-name matcher behind address
+//! validation: spacing and abbreviations like "account 1" and "acct0", typos
+//! one edit away, suggestions for names two edits away, and that ambiguous
+//! or numbered near-misses are never resolved automatically.
+
+use mcp_server::services::blockchain::{
+    levenshtein, match_account_name, normalize_account_name, AccountNameMatch, BalanceRequest, BlockchainService,
+};
+use mcp_server::services::provider::MockProvider;
+use rmcp::handler::server::tool::Parameters;
+
+const BOB: &str = "0x70997970C51812dc3A010C7d01b50e0d17dc79C8";
+
+fn known() -> Vec<String> {
+    let mut names = vec!["alice".to_string(), "bob".to_string(), "treasury".to_string()];
+    names.extend((0..10).map(|index| format!("account{}", index)));
+    names
+}
+
+fn balance_request(who: &str) -> Parameters<BalanceRequest> {
+    Parameters(BalanceRequest { who: who.to_string(), block: None })
+}
+
+#[test]
+fn test_normalize_and_distance() {
+    println!("\n🧪 Testing account name normalisation and edit distance...");
+
+    assert_eq!(normalize_account_name("Account 1"), "account1");
+    assert_eq!(normalize_account_name("  acct0 "), "account0");
+    assert_eq!(normalize_account_name("acc 12"), "account12");
+    assert_eq!(normalize_account_name("ALICE"), "alice");
+    assert_eq!(normalize_account_name("accountant"), "accountant", "Only numbered forms are expanded");
+
+    assert_eq!(levenshtein("alice", "alice"), 0);
+    assert_eq!(levenshtein("alise", "alice"), 1);
+    assert_eq!(levenshtein("alcie", "alice"), 2);
+    assert_eq!(levenshtein("", "bob"), 3);
+
+    println!("🔚 Test completed\n");
+}
+
+#[test]
+fn test_accepted_typos() {
+    println!("\n🧪 Testing typos that resolve to an account...");
+
+    let cases = vec![
+        ("alise", "alice"),
+        ("Alic", "alice"),
+        ("bobb", "bob"),
+        ("account 1", "account1"),
+        ("acct0", "account0"),
+        ("acount3", "account3"),
+        ("tresury", "treasury"),
+    ];
+    for (input, expected) in cases {
+        let matched = match_account_name(input, &known());
+        println!("✅ OUTPUT: {} -> {:?}", input, matched);
+        assert_eq!(matched, AccountNameMatch::Resolved(expected.to_string()), "'{}' should resolve", input);
+    }
+
+    println!("🔚 Test completed\n");
+}
+
+#[test]
+fn test_rejected_typos() {
+    println!("\n🧪 Testing typos that are only suggested or rejected...");
+
+    assert_eq!(match_account_name("alcie", &known()), AccountNameMatch::Suggestion("alice".to_string()));
+    assert_eq!(match_account_name("xyz", &known()), AccountNameMatch::NoMatch);
+    assert_eq!(match_account_name("account11", &known()), AccountNameMatch::NoMatch, "Digits must match exactly");
+    assert_eq!(match_account_name("  ", &known()), AccountNameMatch::NoMatch);
+
+    let known = vec!["bob".to_string(), "rob".to_string()];
+    assert_eq!(
+        match_account_name("xob", &known),
+        AccountNameMatch::Ambiguous(vec!["bob".to_string(), "rob".to_string()]),
+        "Equally close names are never picked automatically"
+    );
+
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_balance_accepts_misspelt_account_names() {
+    println!("\n🧪 Testing address validation resolves and suggests account names...");
+
+    let service = BlockchainService::with_provider(MockProvider::new()).await.unwrap();
+
+    println!("📝 INPUT: balance(\"Bobb\")");
+    let result = service.balance(balance_request("Bobb")).await.unwrap();
+    let text = format!("{:?}", result.content);
+    println!("✅ OUTPUT: {}", text);
+    assert!(text.contains(BOB));
+    assert!(text.contains("matched from 'Bobb'"));
+
+    let result = service.balance(balance_request("account 1")).await.unwrap();
+    assert!(format!("{:?}", result.content).contains(BOB), "'account 1' is account1, which is Bob by default");
+
+    let error = service.balance(balance_request("alcie")).await.expect_err("Two edits away is not resolved");
+    println!("✅ OUTPUT: {}", error.message);
+    assert!(error.message.contains("Did you mean alice?"));
+
+    let error = service.balance(balance_request("nobody")).await.expect_err("Unknown names are rejected");
+    assert!(!error.message.contains("Did you mean"));
+
+    println!("🔚 Test completed\n");
+}
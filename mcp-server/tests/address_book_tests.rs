@@ -0,0 +1,174 @@
+//! Address Book Tests for MCP Blockchain Server
+//!
+//! These tests verify that named addresses load from TOML, reject reserved
+//! names, persist when added, and resolve as recipients (using MockProvider).
+
+use alloy_primitives::Address;
+use mcp_server::config::{AddressBook, BlockchainConfig};
+use mcp_server::services::blockchain::{AddressBookEntryRequest, BlockchainService, TransferRequest};
+use mcp_server::services::provider::MockProvider;
+use rmcp::handler::server::tool::Parameters;
+use std::str::FromStr;
+
+const TREASURY: &str = "0x90F79bf6EB2c4f870365E785982E1f101E93b906";
+
+fn temp_book_path(name: &str) -> std::path::PathBuf {
+    std::env::temp_dir().join(format!("address_book_{}_{}.toml", name, std::process::id()))
+}
+
+#[test]
+fn test_parse_address_book() {
+    println!("\n🧪 Testing address book TOML parsing...");
+
+    let book = AddressBook::new();
+    let entries = book.parse(&format!("Treasury = \"{}\"\ndeployer = \"{}\"", TREASURY, TREASURY)).unwrap();
+    println!("✅ OUTPUT: {:?}", entries.keys().collect::<Vec<_>>());
+    assert_eq!(entries.get("treasury"), Some(&Address::from_str(TREASURY).unwrap()), "Names are lowercased");
+    assert!(entries.contains_key("deployer"));
+
+    let reserved = book.parse(&format!("Alice = \"{}\"", TREASURY));
+    println!("✅ OUTPUT (reserved): {:?}", reserved);
+    assert!(reserved.unwrap_err().contains("reserved"));
+
+    assert!(book.parse("treasury = \"not-an-address\"").is_err());
+
+    println!("🔚 Test completed\n");
+}
+
+#[test]
+fn test_reserved_names() {
+    println!("\n🧪 Testing reserved address book names...");
+
+    let test_cases = vec![
+        ("alice", true),
+        ("BOB", true),
+        ("account3", true),
+        ("account12", true),
+        ("accountant", false),
+        ("treasury", false),
+    ];
+
+    let book = AddressBook::new();
+    for (name, expected) in test_cases {
+        let reserved = book.is_reserved_name(name);
+        println!("📝 INPUT: {} ✅ OUTPUT: reserved={}", name, reserved);
+        assert_eq!(reserved, expected, "Wrong reservation for '{}'", name);
+    }
+
+    println!("🔚 Test completed\n");
+}
+
+#[test]
+fn test_configured_names_are_reserved() {
+    println!("\n🧪 Testing the address book reserves the configured account names...");
+
+    let mut book = AddressBook::with_reserved_names(["Carol", "dave"]);
+    assert!(book.is_reserved_name("carol"));
+    assert!(book.is_reserved_name("DAVE"));
+    assert!(!book.is_reserved_name("alice"), "Only the configured names are reserved");
+    assert!(book.insert("carol", Address::ZERO).is_err());
+    assert!(book.parse(&format!("dave = \"{}\"", TREASURY)).unwrap_err().contains("reserved"));
+
+    // Switching names fails rather than leave an entry shadowing an account
+    book.insert("alice", Address::from_str(TREASURY).unwrap()).unwrap();
+    let result = book.set_reserved_names(["alice", "bob"]);
+    println!("✅ OUTPUT: {:?}", result);
+    assert!(result.unwrap_err().contains("'alice'"));
+
+    println!("🔚 Test completed\n");
+}
+
+#[test]
+fn test_insert_persists_to_file() {
+    println!("\n🧪 Testing address book persistence...");
+
+    let path = temp_book_path("persist");
+    let _ = std::fs::remove_file(&path);
+
+    let mut book = AddressBook::load(&path, ["alice", "bob"]).unwrap();
+    assert!(book.entries().is_empty(), "A missing file starts an empty book");
+
+    let name = book.insert("Treasury", Address::from_str(TREASURY).unwrap()).unwrap();
+    assert_eq!(name, "treasury");
+    assert!(book.insert("account0", Address::ZERO).is_err(), "Reserved names cannot be added");
+    assert!(book.insert("vitalik.eth", Address::ZERO).is_err(), "ENS-like names cannot be added");
+
+    let reloaded = AddressBook::load(&path, ["alice", "bob"]).unwrap();
+    println!("✅ OUTPUT: {:?}", reloaded.entries());
+    assert_eq!(reloaded.get("TREASURY"), Some(Address::from_str(TREASURY).unwrap()));
+
+    let _ = std::fs::remove_file(&path);
+    println!("🔚 Test completed\n");
+}
+
+#[test]
+fn test_failed_save_rolls_back_insert() {
+    println!("\n🧪 Testing a failed save leaves the address book unchanged...");
+
+    // The parent directory doesn't exist, so every save fails
+    let path = temp_book_path("missing-dir").join("book.toml");
+    let mut book = AddressBook::load(&path, ["alice", "bob"]).unwrap();
+
+    let error = book.insert("treasury", Address::from_str(TREASURY).unwrap()).expect_err("The file can't be written");
+    println!("✅ OUTPUT: {}", error);
+    assert!(error.starts_with("Failed to write address book"));
+    assert_eq!(book.get("treasury"), None, "The new entry is rolled back");
+    assert!(book.entries().is_empty());
+
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_address_book_names_resolve_as_recipients() {
+    println!("\n🧪 Testing send_eth resolves an address book name...");
+
+    let mut config = BlockchainConfig::default();
+    config.address_book.insert("treasury", Address::from_str(TREASURY).unwrap()).unwrap();
+    let service = BlockchainService::with_provider_and_config(MockProvider::new(), config).await.unwrap();
+
+    let request = TransferRequest {
+        to: "Treasury".to_string(),
+        amount: "1.0".to_string(),
+        from: None,
+        dry_run: Some(true),
+        confirmation_timeout: None,
+    };
+    println!("📝 INPUT: Simulate sending {} ETH to {}", request.amount, request.to);
+
+    let result = service.send_eth(Parameters(request)).await.unwrap();
+    let text = format!("{:?}", result.content);
+    println!("✅ OUTPUT: {}", text);
+    assert!(text.contains(TREASURY));
+    assert!(text.contains("Address Book (treasury)"));
+
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_add_and_list_address_book_tools() {
+    println!("\n🧪 Testing add_address_book_entry and list_address_book...");
+
+    let service = BlockchainService::with_provider(MockProvider::new()).await.unwrap();
+
+    let added = service.add_address_book_entry(Parameters(AddressBookEntryRequest {
+        name: "Deployer".to_string(),
+        address: TREASURY.to_string(),
+    })).await.unwrap();
+    let text = format!("{:?}", added.content);
+    println!("✅ OUTPUT (add): {}", text);
+    assert!(text.contains("Name: deployer"));
+
+    let rejected = service.add_address_book_entry(Parameters(AddressBookEntryRequest {
+        name: "bob".to_string(),
+        address: TREASURY.to_string(),
+    })).await;
+    assert!(rejected.is_err(), "Reserved names should be rejected");
+
+    let listed = service.list_address_book().await.unwrap();
+    let text = format!("{:?}", listed.content);
+    println!("✅ OUTPUT (list): {}", text);
+    assert!(text.contains("Address Book (1 entry)"));
+    assert!(text.contains(&format!("deployer: {}", TREASURY)));
+
+    println!("🔚 Test completed\n");
+}
@@ -0,0 +1,87 @@
+//! Amount Expression Tests for MCP Blockchain Server
+//!
+//! These tests verify that send_eth amounts like "all", "max", "half" and "50%"
+//! are recognised, and that they resolve against the sender's balance with the
+//! gas cost always held back for "all".
+
+use alloy_primitives::U256;
+use mcp_server::services::blockchain::{parse_amount_expression, AmountExpression};
+
+const ONE_ETH: u128 = 1_000_000_000_000_000_000;
+
+#[test]
+fn test_parse_amount_expressions() {
+    println!("\n🧪 Testing amount expression parsing...");
+
+    let test_cases = vec![
+        ("all", AmountExpression::All),
+        (" MAX ", AmountExpression::All),
+        ("100%", AmountExpression::All),
+        ("50%", AmountExpression::Percent(5_000)),
+        ("half", AmountExpression::Percent(5_000)),
+        ("12.5 %", AmountExpression::Percent(1_250)),
+        ("0.5", AmountExpression::Exact(U256::from(ONE_ETH / 2))),
+    ];
+
+    for (input, expected) in test_cases {
+        println!("📝 INPUT: \"{}\"", input);
+        let expression = parse_amount_expression(input).unwrap();
+        println!("✅ OUTPUT: {:?}", expression);
+        assert_eq!(expression, expected, "Wrong expression for '{}'", input);
+    }
+
+    println!("🔚 Test completed\n");
+}
+
+#[test]
+fn test_parse_invalid_amount_expressions() {
+    println!("\n🧪 Testing invalid amount expressions are rejected...");
+
+    for input in ["0%", "150%", "-5%", "12.345%", "lots", "%"] {
+        let error = parse_amount_expression(input).expect_err("Invalid expression should be rejected");
+        println!("📝 INPUT: \"{}\" ✅ OUTPUT: {}", input, error.message);
+    }
+
+    println!("🔚 Test completed\n");
+}
+
+#[test]
+fn test_resolve_against_balance() {
+    println!("\n🧪 Testing amount expressions resolve against the balance...");
+
+    let balance = U256::from(2 * ONE_ETH);
+    let gas_cost = U256::from(21_000u64 * 1_000_000_000);
+
+    // "all" sends the balance minus the gas it will cost
+    assert_eq!(AmountExpression::All.resolve(balance, gas_cost).unwrap(), balance - gas_cost);
+
+    // Percentages are a share of the whole balance
+    assert_eq!(AmountExpression::Percent(5_000).resolve(balance, gas_cost).unwrap(), U256::from(ONE_ETH));
+    assert_eq!(AmountExpression::Percent(1_250).resolve(balance, gas_cost).unwrap(), U256::from(ONE_ETH / 4));
+
+    // Literal amounts ignore the balance
+    let exact = U256::from(ONE_ETH * 5);
+    assert_eq!(AmountExpression::Exact(exact).resolve(balance, gas_cost).unwrap(), exact);
+
+    assert_eq!(AmountExpression::Percent(1_250).describe(), "12.50%");
+    assert_eq!(AmountExpression::Percent(5_000).describe(), "50%");
+
+    println!("🔚 Test completed\n");
+}
+
+#[test]
+fn test_send_all_always_reserves_gas() {
+    println!("\n🧪 Testing 'all' refuses to send when gas would use up the balance...");
+
+    let gas_cost = U256::from(21_000u64 * 1_000_000_000);
+
+    let error = AmountExpression::All.resolve(gas_cost, gas_cost)
+        .expect_err("A balance that only covers gas leaves nothing to send");
+    println!("✅ OUTPUT: {}", error.message);
+    assert!(error.message.contains("Nothing to send"));
+
+    assert!(AmountExpression::All.resolve(U256::ZERO, gas_cost).is_err());
+    assert!(AmountExpression::Percent(1).resolve(U256::from(10u64), gas_cost).is_err(), "0.01% of 10 wei rounds to 0");
+
+    println!("🔚 Test completed\n");
+}
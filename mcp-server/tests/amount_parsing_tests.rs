@@ -0,0 +1,57 @@
+//! Amount Parsing Tests for MCP Blockchain Server
+//!
+//! These tests verify that decimal ETH amounts are converted to wei exactly,
+//! and that malformed amounts are rejected rather than silently mis-scaled.
+
+use alloy_primitives::U256;
+use mcp_server::services::blockchain::parse_eth_to_wei;
+use std::str::FromStr;
+
+#[test]
+fn test_parse_valid_eth_amounts() {
+    println!("\n🧪 Testing ETH to wei conversion for valid amounts...");
+
+    let test_cases = vec![
+        ("1", "1000000000000000000"),
+        ("0.5", "500000000000000000"),
+        ("1.25", "1250000000000000000"),
+        ("0.000000000000000001", "1"),
+        ("10.0", "10000000000000000000"),
+        (" 2 ", "2000000000000000000"),
+    ];
+
+    for (input, expected) in test_cases {
+        println!("📝 INPUT: \"{}\"", input);
+        let wei = parse_eth_to_wei(input).unwrap();
+        println!("✅ OUTPUT: {} wei (expected {})", wei, expected);
+        assert_eq!(wei, U256::from_str(expected).unwrap(), "Wrong conversion for '{}'", input);
+    }
+
+    println!("✅ Valid amount parsing: PASSED");
+    println!("🔚 Test completed\n");
+}
+
+#[test]
+fn test_parse_invalid_eth_amounts() {
+    println!("\n🧪 Testing ETH to wei conversion rejects malformed amounts...");
+
+    let test_cases = vec![
+        "1.2.3",
+        "abc",
+        "",
+        ".",
+        "-1",
+        "1e18",
+        "0.0000000000000000001", // 19 decimal places
+    ];
+
+    for input in test_cases {
+        println!("📝 INPUT: \"{}\"", input);
+        let result = parse_eth_to_wei(input);
+        println!("✅ OUTPUT: {:?}", result.as_ref().map_err(|e| e.message.to_string()));
+        assert!(result.is_err(), "Expected '{}' to be rejected", input);
+    }
+
+    println!("✅ Invalid amount rejection: PASSED");
+    println!("🔚 Test completed\n");
+}
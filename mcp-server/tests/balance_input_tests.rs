@@ -0,0 +1,75 @@
+//! Balance Input Validation Tests for MCP Blockchain Server
+//!
+//! These tests verify that `balance` trims its `who` input, rejects empty
+//! input with a clear message and resolves account names like "alice" the
+//! same way transfers do, using MockProvider so they run without anvil.
+
+use alloy_primitives::{Address, U256};
+use mcp_server::services::blockchain::{BalanceRequest, BlockchainService};
+use mcp_server::services::provider::MockProvider;
+use rmcp::handler::server::tool::Parameters;
+use std::str::FromStr;
+
+const ALICE: &str = "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266";
+
+fn balance_request(who: &str) -> Parameters<BalanceRequest> {
+    Parameters(BalanceRequest { who: who.to_string(), block: None })
+}
+
+#[tokio::test]
+async fn test_balance_resolves_account_names() {
+    println!("\n🧪 Testing balance accepts the 'alice' account name...");
+
+    let mock = MockProvider::new()
+        .with_balance(Address::from_str(ALICE).unwrap(), U256::from(4_000_000_000_000_000_000u128));
+    let service = BlockchainService::with_provider(mock).await.unwrap();
+
+    println!("📝 INPUT: balance(\"  Alice \")");
+    let result = service.balance(balance_request("  Alice ")).await.unwrap();
+    let text = format!("{:?}", result.content);
+    println!("✅ OUTPUT: {}", text);
+
+    assert!(text.contains(&format!("Account: Alice (resolved to {})", ALICE)));
+    assert!(text.contains("Address Type: Alice (Account 0 - Default Sender)"));
+    assert!(text.contains("4.000000 ETH"));
+
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_balance_rejects_empty_input() {
+    println!("\n🧪 Testing balance rejects empty and whitespace-only input...");
+
+    let service = BlockchainService::with_provider(MockProvider::new()).await.unwrap();
+
+    for who in ["", "   "] {
+        println!("📝 INPUT: balance({:?})", who);
+        let error = service.balance(balance_request(who)).await
+            .expect_err("Empty input should be rejected");
+        println!("✅ OUTPUT: {}", error.message);
+        assert!(error.message.contains("No account given"));
+    }
+
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_balance_rejects_unresolvable_input() {
+    println!("\n🧪 Testing balance reports bad ENS names and junk input clearly...");
+
+    let service = BlockchainService::with_provider(MockProvider::new()).await.unwrap();
+
+    println!("📝 INPUT: balance(\"no-such-name-for-tests.eth\")");
+    let error = service.balance(balance_request("no-such-name-for-tests.eth")).await
+        .expect_err("Unresolvable ENS names should be rejected");
+    println!("✅ OUTPUT: {}", error.message);
+    assert!(error.message.contains("Failed to resolve ENS name 'no-such-name-for-tests.eth'"));
+
+    println!("📝 INPUT: balance(\"not an address\")");
+    let error = service.balance(balance_request("not an address")).await
+        .expect_err("Junk input should be rejected");
+    println!("✅ OUTPUT: {}", error.message);
+    assert!(error.message.contains("Valid formats"));
+
+    println!("🔚 Test completed\n");
+}
@@ -0,0 +1,120 @@
+//! Batch Read Tests for MCP Blockchain Server
+//!
+//! These tests verify that `batch_read` runs several reads in one call, keeps
+//! their order, and reports failures per read, using MockProvider so they run
+//! without anvil.
+
+use alloy_primitives::{Address, Bytes, U256};
+use mcp_server::services::blockchain::{BatchReadRequest, BatchReadResponse, BlockchainService, ReadSpec, MAX_BATCH_READS};
+use mcp_server::services::provider::MockProvider;
+use rmcp::handler::server::tool::Parameters;
+use std::str::FromStr;
+
+const ALICE: &str = "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266";
+const BOB: &str = "0x70997970C51812dc3A010C7d01b50e0d17dc79C8";
+const USDC: &str = "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48";
+
+fn word(value: u128) -> Bytes {
+    Bytes::from(U256::from(value).to_be_bytes::<32>().to_vec())
+}
+
+fn parse_response(result: &rmcp::model::CallToolResult) -> BatchReadResponse {
+    let text = &result.content[0].as_text().expect("batch_read returns text").text;
+    println!("✅ OUTPUT: {}", text);
+    serde_json::from_str(text).expect("batch_read returns a BatchReadResponse")
+}
+
+#[test]
+fn test_read_spec_deserialization() {
+    println!("\n🧪 Testing read specs are tagged by type...");
+
+    let request: BatchReadRequest = serde_json::from_str(&format!(
+        r#"{{"reads":[{{"type":"balance","address":"alice"}},{{"type":"token_balance","token_address":"{}","account_address":"bob"}}]}}"#,
+        USDC
+    )).unwrap();
+    println!("✅ OUTPUT: {:?}", request.reads);
+
+    let kinds: Vec<&str> = request.reads.iter().map(ReadSpec::kind).collect();
+    assert_eq!(kinds, vec!["balance", "token_balance"]);
+
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_batch_read_combines_results_in_order() {
+    println!("\n🧪 Testing batch_read combines several reads...");
+
+    let usdc = Address::from_str(USDC).unwrap();
+    let mock = MockProvider::new()
+        .with_balance(Address::from_str(ALICE).unwrap(), U256::from(2_000_000_000_000_000_000u128))
+        .with_call_response(usdc, "balanceOf(address)", word(1_500_000))
+        .with_call_response(usdc, "allowance(address,address)", word(250_000))
+        .with_call_response(usdc, "decimals()", word(6))
+        .with_nonce(Address::from_str(ALICE).unwrap(), 7);
+    let service = BlockchainService::with_provider(mock).await.unwrap();
+
+    let reads = vec![
+        ReadSpec::Balance { address: ALICE.to_string() },
+        ReadSpec::TokenBalance { token_address: USDC.to_string(), account_address: ALICE.to_string() },
+        ReadSpec::Allowance { token_address: USDC.to_string(), owner: ALICE.to_string(), spender: BOB.to_string() },
+        ReadSpec::Nonce { address: ALICE.to_string() },
+    ];
+    println!("📝 INPUT: batch_read with {} reads", reads.len());
+    let result = service.batch_read(Parameters(BatchReadRequest { reads })).await.unwrap();
+    let response = parse_response(&result);
+
+    assert_eq!(response.succeeded, 4);
+    assert_eq!(response.failed, 0);
+    let values: Vec<(&str, Option<&str>)> = response.results.iter()
+        .map(|result| (result.read_type.as_str(), result.value.as_deref()))
+        .collect();
+    assert_eq!(values, vec![
+        ("balance", Some("2000000000000000000")),
+        ("token_balance", Some("1500000")),
+        ("allowance", Some("250000")),
+        ("nonce", Some("7")),
+    ]);
+
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_batch_read_reports_failures_per_read() {
+    println!("\n🧪 Testing one failing read doesn't fail the batch...");
+
+    let service = BlockchainService::with_provider(MockProvider::new()).await.unwrap();
+
+    let reads = vec![
+        ReadSpec::Balance { address: ALICE.to_string() },
+        ReadSpec::TokenBalance { token_address: "not-a-token".to_string(), account_address: ALICE.to_string() },
+    ];
+    let result = service.batch_read(Parameters(BatchReadRequest { reads })).await.unwrap();
+    let response = parse_response(&result);
+
+    assert_eq!(response.succeeded, 1);
+    assert_eq!(response.failed, 1);
+    assert!(response.results[0].error.is_none());
+    assert_eq!(response.results[1].index, 1);
+    assert!(response.results[1].value.is_none());
+    assert!(response.results[1].error.as_deref().unwrap().contains("Invalid token address"));
+
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_batch_read_rejects_empty_and_oversized_batches() {
+    println!("\n🧪 Testing batch_read size limits...");
+
+    let service = BlockchainService::with_provider(MockProvider::new()).await.unwrap();
+
+    let empty = service.batch_read(Parameters(BatchReadRequest { reads: Vec::new() })).await;
+    assert!(empty.expect_err("Empty batches should be rejected").message.contains("at least one read"));
+
+    let reads = vec![ReadSpec::Balance { address: ALICE.to_string() }; MAX_BATCH_READS + 1];
+    let oversized = service.batch_read(Parameters(BatchReadRequest { reads })).await;
+    let error = oversized.expect_err("Oversized batches should be rejected");
+    println!("✅ OUTPUT: {}", error.message);
+    assert!(error.message.contains("at most 50 reads"));
+
+    println!("🔚 Test completed\n");
+}
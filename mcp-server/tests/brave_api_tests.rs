@@ -41,6 +41,7 @@ async fn test_web_search_request_serialization() {
         count: Some(5),
         country: Some("us".to_string()),
         search_lang: Some("en".to_string()),
+        offset: None,
     };
     
     println!("📝 INPUT: WebSearchRequest {{");
@@ -122,6 +123,8 @@ async fn test_search_response_serialization() {
             }
         ],
         total_results: 1,
+        offset: 0,
+        pagination: None,
     };
     
     println!("📝 INPUT: SearchResponse {{");
@@ -174,6 +177,7 @@ async fn test_web_search_functionality() {
                 count: Some(3),
                 country: Some("us".to_string()),
                 search_lang: Some("en".to_string()),
+                offset: None,
             };
             
             println!("📝 INPUT: Searching for \"{}\"", search_request.query);
@@ -0,0 +1,144 @@
+//! Generic Contract Call Tests for MCP Blockchain Server
+//!
+//! These tests verify how call_contract splits a cast-style signature into the
+//! function and its return types, decodes uint256/address/bool/string results,
+//! and reports reverts, against a mocked contract.
+
+use alloy_primitives::{Address, U256};
+use mcp_server::services::blockchain::{
+    decode_return_values, split_function_signature, BlockchainService, ContractCallRequest,
+};
+use mcp_server::services::provider::{abi_encode_string, MockProvider};
+use rmcp::handler::server::tool::Parameters;
+use std::str::FromStr;
+
+const TOKEN: &str = "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48";
+const HOLDER: &str = "0x70997970C51812dc3A010C7d01b50e0d17dc79C8";
+
+fn word(value: u64) -> Vec<u8> {
+    U256::from(value).to_be_bytes::<32>().to_vec()
+}
+
+fn address_word(address: Address) -> Vec<u8> {
+    let mut bytes = vec![0u8; 12];
+    bytes.extend_from_slice(address.as_slice());
+    bytes
+}
+
+fn request(signature: &str, args: &[&str]) -> Parameters<ContractCallRequest> {
+    Parameters(ContractCallRequest {
+        address: TOKEN.to_string(),
+        signature: signature.to_string(),
+        args: Some(args.iter().map(|arg| arg.to_string()).collect()),
+        block: None,
+    })
+}
+
+#[test]
+fn test_split_function_signature() {
+    println!("\n🧪 Testing cast-style signatures are split into function and return types...");
+
+    let cases = vec![
+        ("totalSupply()(uint256)", "totalSupply()", Some("(uint256)")),
+        ("balanceOf(address)(uint256)", "balanceOf(address)", Some("(uint256)")),
+        ("getReserves()(uint112,uint112,uint32)", "getReserves()", Some("(uint112,uint112,uint32)")),
+        ("foo((uint256,address),bool)(string)", "foo((uint256,address),bool)", Some("(string)")),
+        ("balanceOf(address) returns (uint256)", "balanceOf(address)", Some("(uint256)")),
+        ("  decimals()  ", "decimals()", None),
+    ];
+    for (signature, function, output_types) in cases {
+        let (split_function, split_outputs) = split_function_signature(signature).unwrap();
+        println!("✅ OUTPUT: {} -> {} / {:?}", signature, split_function, split_outputs);
+        assert_eq!(split_function, function);
+        assert_eq!(split_outputs.as_deref(), output_types);
+    }
+
+    for signature in ["totalSupply", "(uint256)", "balanceOf(address", "totalSupply()uint256"] {
+        let error = split_function_signature(signature).expect_err("Malformed signatures are rejected");
+        assert!(error.message.contains("Invalid function signature"), "'{}' should be rejected", signature);
+    }
+
+    println!("🔚 Test completed\n");
+}
+
+#[test]
+fn test_decode_return_values() {
+    println!("\n🧪 Testing return data is decoded by type...");
+
+    let holder = Address::from_str(HOLDER).unwrap();
+    let mut data = word(1_000_000);
+    data.extend(address_word(holder));
+    data.extend(word(1));
+    let values = decode_return_values("(uint256,address,bool)", &data).unwrap();
+    println!("✅ OUTPUT: {:?}", values);
+    assert_eq!(values[0], "1000000");
+    assert_eq!(values[1].to_lowercase(), HOLDER.to_lowercase());
+    assert_eq!(values[2], "true");
+
+    assert_eq!(decode_return_values("(string)", &abi_encode_string("USD Coin")).unwrap(), vec!["USD Coin"]);
+
+    let error = decode_return_values("(uint256)", &[]).expect_err("Empty data can't hold a uint256");
+    assert!(error.message.contains("Failed to decode 0 bytes"));
+    let error = decode_return_values("(uint512)", &word(1)).expect_err("Unknown types are rejected");
+    assert!(error.message.contains("Invalid return types"));
+
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_call_contract_decodes_view_results() {
+    println!("\n🧪 Testing call_contract against a mocked token...");
+
+    let token = Address::from_str(TOKEN).unwrap();
+    let mock = MockProvider::new()
+        .with_call_response(token, "totalSupply()", word(42_000_000))
+        .with_string_response(token, "symbol()", "USDC")
+        .with_call_response(token, "balanceOf(address)", word(1_500_000));
+    let service = BlockchainService::with_provider(mock).await.unwrap();
+
+    println!("📝 INPUT: totalSupply()(uint256) on {}", TOKEN);
+    let result = service.call_contract(request("totalSupply()(uint256)", &[])).await.unwrap();
+    let text = format!("{:?}", result.content);
+    println!("✅ OUTPUT: {}", text);
+    assert!(text.contains("Function: totalSupply()"));
+    assert!(text.contains("Returns (uint256):"));
+    assert!(text.contains("42000000"));
+
+    let result = service.call_contract(request("symbol()(string)", &[])).await.unwrap();
+    assert!(format!("{:?}", result.content).contains("USDC"));
+
+    let result = service.call_contract(request("balanceOf(address)(uint256)", &[HOLDER])).await.unwrap();
+    let text = format!("{:?}", result.content);
+    assert!(text.contains(&format!("Arguments: {}", HOLDER)));
+    assert!(text.contains("1500000"));
+
+    // Without return types the raw word is shown with a hint
+    let result = service.call_contract(request("totalSupply()", &[])).await.unwrap();
+    let text = format!("{:?}", result.content);
+    assert!(text.contains("Raw Result: 0x"));
+    assert!(text.contains("Add return types"));
+
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_call_contract_reports_reverts_and_bad_args() {
+    println!("\n🧪 Testing call_contract errors...");
+
+    let service = BlockchainService::with_provider(MockProvider::new()).await.unwrap();
+
+    // The mock reverts calls it has no response for
+    let error = service.call_contract(request("owner()(address)", &[])).await.expect_err("The call reverts");
+    println!("✅ OUTPUT: {}", error.message);
+    assert!(error.message.contains("owner() on"));
+    assert!(error.message.contains("reverted"));
+
+    let error = service.call_contract(request("balanceOf(address)(uint256)", &["not an address"])).await
+        .expect_err("Arguments must match the signature");
+    assert!(error.message.contains("Failed to encode balanceOf(address)"));
+
+    let error = service.call_contract(request("totalSupply", &[])).await.expect_err("A signature needs parentheses");
+    assert!(error.message.contains("Invalid function signature"));
+
+    println!("🔚 Test completed\n");
+}
@@ -0,0 +1,110 @@
+//! Calldata Decoding Tests for MCP Blockchain Server
+//!
+//! These tests verify that transaction input is matched against the known
+//! signature table and decoded into named parameters, and that unknown
+//! selectors fall back to the raw selector and argument bytes.
+
+use alloy_primitives::hex;
+use cast::SimpleCast;
+use mcp_server::services::blockchain::{decode_calldata_input, BlockchainService, DecodeCalldataRequest};
+use rmcp::handler::server::tool::Parameters;
+
+#[test]
+fn test_decode_known_transfer_calldata() {
+    println!("\n🧪 Testing decoding of ERC-20 transfer calldata...");
+
+    let calldata = SimpleCast::calldata_encode(
+        "transfer(address,uint256)",
+        &["0x70997970C51812dc3A010C7d01b50e0d17dc79C8".to_string(), "1500000".to_string()],
+    )
+    .unwrap();
+    println!("📝 INPUT: {}", calldata);
+
+    let input = hex::decode(&calldata).unwrap();
+    let decoded = decode_calldata_input(&input).unwrap();
+    println!("✅ OUTPUT: {:?}", decoded);
+
+    assert_eq!(decoded.selector, "0xa9059cbb");
+    assert_eq!(decoded.signature.as_deref(), Some("transfer(address,uint256)"));
+    assert_eq!(decoded.params.len(), 2);
+    assert_eq!(decoded.params[0].0, "to");
+    assert_eq!(decoded.params[0].1.to_lowercase(), "0x70997970c51812dc3a010c7d01b50e0d17dc79c8");
+    assert_eq!(decoded.params[1], ("amount".to_string(), "1500000".to_string()));
+
+    println!("✅ Known calldata decoding: PASSED");
+    println!("🔚 Test completed\n");
+}
+
+#[test]
+fn test_decode_swap_calldata_with_path() {
+    println!("\n🧪 Testing decoding of swapExactETHForTokens calldata...");
+
+    let calldata = SimpleCast::calldata_encode(
+        "swapExactETHForTokens(uint256,address[],address,uint256)",
+        &[
+            "1000".to_string(),
+            "[0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2,0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48]".to_string(),
+            "0x70997970C51812dc3A010C7d01b50e0d17dc79C8".to_string(),
+            "1700000000".to_string(),
+        ],
+    )
+    .unwrap();
+    println!("📝 INPUT: {}", calldata);
+
+    let decoded = decode_calldata_input(&hex::decode(&calldata).unwrap()).unwrap();
+    println!("✅ OUTPUT: {:?}", decoded);
+
+    let names: Vec<&str> = decoded.params.iter().map(|(name, _)| name.as_str()).collect();
+    assert_eq!(names, vec!["amountOutMin", "path", "to", "deadline"]);
+    assert!(decoded.params[1].1.starts_with('['));
+    assert_eq!(decoded.params[3].1, "1700000000");
+
+    println!("✅ Swap calldata decoding: PASSED");
+    println!("🔚 Test completed\n");
+}
+
+#[test]
+fn test_decode_unknown_selector() {
+    println!("\n🧪 Testing decoding of an unknown function selector...");
+
+    let input = hex::decode("deadbeef0000000000000000000000000000000000000000000000000000000000000001").unwrap();
+    println!("📝 INPUT: 0x{}", hex::encode(&input));
+
+    let decoded = decode_calldata_input(&input).unwrap();
+    println!("✅ OUTPUT: {:?}", decoded);
+
+    assert_eq!(decoded.selector, "0xdeadbeef");
+    assert!(decoded.signature.is_none());
+    assert!(decoded.params.is_empty());
+    assert!(decoded.raw_args.ends_with("01"));
+
+    // Input shorter than a selector is rejected outright
+    assert!(decode_calldata_input(&[0xde, 0xad]).is_err());
+
+    println!("✅ Unknown selector fallback: PASSED");
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_decode_calldata_tool_rejects_bad_hash() {
+    println!("\n🧪 Testing decode_calldata tool with an invalid hash...");
+
+    match BlockchainService::new().await {
+        Ok(service) => {
+            let request = DecodeCalldataRequest {
+                tx_hash: "not-a-hash".to_string(),
+            };
+            println!("📝 INPUT: Decode calldata for {}", request.tx_hash);
+
+            let result = service.decode_calldata(Parameters(request)).await;
+            println!("✅ OUTPUT: {:?}", result.as_ref().map_err(|e| e.message.to_string()));
+            assert!(result.is_err(), "Expected invalid hash to be rejected");
+        }
+        Err(e) => {
+            println!("⚠️  BlockchainService creation failed: {}", e);
+            println!("💡 This is expected if anvil is not running");
+        }
+    }
+
+    println!("🔚 Test completed\n");
+}
@@ -0,0 +1,100 @@
+//! Chain ID Guard Tests for MCP Blockchain Server
+//!
+//! These tests verify that with `expected_chain_id` set, the service refuses
+//! to start against a node on another chain and re-checks the chain id before
+//! broadcasting, and that nothing is checked when it is unset. MockProvider
+//! reports the chain id.
+
+use mcp_server::config::BlockchainConfig;
+use mcp_server::services::blockchain::{verify_chain_id, BlockchainService, DeployContractRequest};
+use mcp_server::services::provider::MockProvider;
+use rmcp::handler::server::tool::Parameters;
+
+const ANVIL_CHAIN_ID: u64 = 31337;
+
+fn config(expected_chain_id: Option<u64>) -> BlockchainConfig {
+    BlockchainConfig {
+        // Unreachable, for the calls that go straight to the node; the mock has no receipt, so confirmation times out
+        rpc_url: "http://127.0.0.1:1".to_string(),
+        alice_private_key: "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80".to_string(),
+        expected_chain_id,
+        ..BlockchainConfig::default()
+    }
+}
+
+fn deploy_request() -> Parameters<DeployContractRequest> {
+    Parameters(DeployContractRequest {
+        bytecode: "0x6080604052348015600f57600080fd5b50".to_string(),
+        constructor: None,
+        args: None,
+        confirmation_timeout: Some(1),
+    })
+}
+
+#[test]
+fn test_verify_chain_id() {
+    println!("\n🧪 Testing chain id verification...");
+
+    assert!(verify_chain_id(None, 1).is_ok(), "Unchecked when no chain id is expected");
+    assert!(verify_chain_id(Some(ANVIL_CHAIN_ID), ANVIL_CHAIN_ID).is_ok());
+
+    let error = verify_chain_id(Some(ANVIL_CHAIN_ID), 1).expect_err("Mainnet is not the anvil chain");
+    println!("✅ OUTPUT: {}", error);
+    assert!(error.contains("WRONG NETWORK"));
+    assert!(error.contains("chain id 1 (Ethereum mainnet - real funds)"));
+    assert!(error.contains("EXPECTED_CHAIN_ID is 31337"));
+
+    let error = verify_chain_id(Some(1), 8453).expect_err("Other chains are named by id only");
+    assert!(error.contains("chain id 8453 but"));
+
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_service_refuses_to_start_on_the_wrong_chain() {
+    println!("\n🧪 Testing startup checks EXPECTED_CHAIN_ID...");
+
+    let mainnet = MockProvider::new().with_chain_id(1);
+    let error = BlockchainService::with_provider_and_config(mainnet, config(Some(ANVIL_CHAIN_ID))).await
+        .err()
+        .expect("A mainnet node should be refused when anvil is expected");
+    println!("✅ OUTPUT: {}", error);
+    assert!(error.to_string().contains("WRONG NETWORK"));
+
+    let unreachable = MockProvider::new().failing("connection refused");
+    let error = BlockchainService::with_provider_and_config(unreachable, config(Some(ANVIL_CHAIN_ID))).await
+        .err()
+        .expect("An unverifiable chain id should be refused");
+    assert!(error.to_string().contains("Could not read the chain id"));
+
+    let anvil = MockProvider::new().with_chain_id(ANVIL_CHAIN_ID);
+    assert!(BlockchainService::with_provider_and_config(anvil, config(Some(ANVIL_CHAIN_ID))).await.is_ok());
+
+    // Without an expected chain id any chain is accepted
+    assert!(BlockchainService::with_provider_and_config(MockProvider::new(), config(None)).await.is_ok());
+
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_transactions_are_refused_when_the_chain_changes() {
+    println!("\n🧪 Testing sends re-check the chain id...");
+
+    let mock = MockProvider::new().with_chain_id(ANVIL_CHAIN_ID);
+    let service = BlockchainService::with_provider_and_config(mock.clone(), config(Some(ANVIL_CHAIN_ID))).await.unwrap();
+
+    let result = service.deploy_contract(deploy_request()).await.unwrap();
+    assert!(format!("{:?}", result.content).contains("Contract Deployment Sent"));
+    assert_eq!(mock.sent_transactions().len(), 1);
+
+    // Clones share state, so the node now reports mainnet
+    let _ = mock.clone().with_chain_id(1);
+
+    println!("📝 INPUT: deploy_contract after the node switched to chain 1");
+    let error = service.deploy_contract(deploy_request()).await.expect_err("The wrong chain is refused");
+    println!("✅ OUTPUT: {}", error.message);
+    assert!(error.message.contains("WRONG NETWORK"));
+    assert_eq!(mock.sent_transactions().len(), 1, "Nothing more was sent");
+
+    println!("🔚 Test completed\n");
+}
@@ -0,0 +1,50 @@
+//! Chain Status Tests for MCP Blockchain Server
+//!
+//! These tests verify the get_chain_status response format and, when anvil
+//! is running, that live block, chain id and gas price data is returned.
+
+use mcp_server::services::blockchain::{format_chain_status, BlockchainService};
+
+#[test]
+fn test_chain_status_formatting() {
+    println!("\n🧪 Testing chain status formatting...");
+
+    println!("📝 INPUT: block=19000000, chain_id=1, gas_price=12500000000 wei");
+    let text = format_chain_status(19_000_000, 1, 12_500_000_000);
+    println!("✅ OUTPUT:\n{}", text);
+
+    assert!(text.contains("Block Number: 19000000"));
+    assert!(text.contains("Chain ID: 1"));
+    assert!(text.contains("Gas Price: 12.50 gwei (12500000000 wei)"));
+
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_get_chain_status() {
+    println!("\n🧪 Testing get_chain_status tool...");
+
+    match BlockchainService::new().await {
+        Ok(service) => {
+            match service.get_chain_status().await {
+                Ok(result) => {
+                    let text = format!("{:?}", result.content);
+                    println!("✅ OUTPUT: {}", text);
+                    assert!(text.contains("Block Number"));
+                    assert!(text.contains("Chain ID"));
+                    assert!(text.contains("gwei"));
+                }
+                Err(e) => {
+                    println!("⚠️  Chain status query failed: {}", e);
+                    println!("💡 This might be expected if anvil is not running");
+                }
+            }
+        }
+        Err(e) => {
+            println!("⚠️  BlockchainService creation failed: {}", e);
+            println!("💡 This is expected if anvil is not running");
+        }
+    }
+
+    println!("🔚 Test completed\n");
+}
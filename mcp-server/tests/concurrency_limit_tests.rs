@@ -0,0 +1,136 @@
+//! Concurrency Limit Tests for MCP Blockchain Server
+//!
+//! These tests verify that blockchain RPC calls past `max_concurrent_operations`
+//! wait for a free slot instead of all reaching the node at once, and that the
+//! queued calls still complete. A slow provider records how many of its calls
+//! overlap.
+
+use alloy_network::AnyTransactionReceipt;
+use alloy_primitives::{Address, Bytes, TxHash, U256};
+use alloy_rpc_types::{BlockId, Filter, Log, TransactionRequest};
+use alloy_serde::WithOtherFields;
+use alloy_transport::TransportResult;
+use async_trait::async_trait;
+use futures::future::join_all;
+use mcp_server::config::{BlockchainConfig, DEFAULT_MAX_CONCURRENT_OPERATIONS};
+use mcp_server::services::blockchain::{BalanceRequest, BlockchainService};
+use mcp_server::services::provider::{ChainProvider, ConcurrencyLimited};
+use rmcp::handler::server::tool::Parameters;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Semaphore;
+
+const ALICE: &str = "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266";
+
+/// Provider whose block number and balance reads take 50ms, tracking peak overlap
+#[derive(Clone, Default)]
+struct SlowProvider {
+    in_flight: Arc<AtomicUsize>,
+    peak: Arc<AtomicUsize>,
+}
+
+impl SlowProvider {
+    async fn slow<T>(&self, value: T) -> TransportResult<T> {
+        let now = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+        self.peak.fetch_max(now, Ordering::SeqCst);
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        self.in_flight.fetch_sub(1, Ordering::SeqCst);
+        Ok(value)
+    }
+
+    fn peak(&self) -> usize {
+        self.peak.load(Ordering::SeqCst)
+    }
+}
+
+#[async_trait]
+impl ChainProvider for SlowProvider {
+    async fn get_block_number(&self) -> TransportResult<u64> {
+        self.slow(100).await
+    }
+
+    async fn get_chain_id(&self) -> TransportResult<u64> {
+        Ok(1)
+    }
+
+    async fn get_block_timestamp(&self) -> TransportResult<u64> {
+        Ok(0)
+    }
+
+    async fn get_gas_price(&self) -> TransportResult<u128> {
+        Ok(0)
+    }
+
+    async fn get_balance_at(&self, _address: Address, _block: BlockId) -> TransportResult<U256> {
+        self.slow(U256::from(1_000_000_000_000_000_000u128)).await
+    }
+
+    async fn get_code_at(&self, _address: Address) -> TransportResult<Bytes> {
+        Ok(Bytes::new())
+    }
+
+    async fn get_transaction_count(&self, _address: Address, _block: BlockId) -> TransportResult<u64> {
+        Ok(0)
+    }
+
+    async fn call_at(&self, _tx: WithOtherFields<TransactionRequest>, _block: BlockId) -> TransportResult<Bytes> {
+        Ok(Bytes::new())
+    }
+
+    async fn get_transaction_receipt(&self, _tx_hash: TxHash) -> TransportResult<Option<AnyTransactionReceipt>> {
+        Ok(None)
+    }
+
+    async fn send_transaction(&self, _tx: WithOtherFields<TransactionRequest>) -> TransportResult<TxHash> {
+        Ok(TxHash::ZERO)
+    }
+
+    async fn trace_transaction(&self, _tx_hash: TxHash) -> TransportResult<serde_json::Value> {
+        Ok(serde_json::Value::Null)
+    }
+
+    async fn get_logs(&self, _filter: &Filter) -> TransportResult<Vec<Log>> {
+        Ok(Vec::new())
+    }
+}
+
+#[tokio::test]
+async fn test_calls_past_the_limit_are_queued() {
+    println!("\n🧪 Testing ConcurrencyLimited caps overlapping RPC calls...");
+
+    let slow = SlowProvider::default();
+    let limited = ConcurrencyLimited::new(Arc::new(slow.clone()), Arc::new(Semaphore::new(2)));
+
+    println!("📝 INPUT: 6 concurrent get_block_number calls with a limit of 2");
+    let results = join_all((0..6).map(|_| limited.get_block_number())).await;
+    println!("✅ OUTPUT: peak {} in flight", slow.peak());
+    assert!(results.iter().all(|result| matches!(result, Ok(100))), "Queued calls still complete");
+    assert_eq!(slow.peak(), 2);
+
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_service_applies_the_configured_limit() {
+    println!("\n🧪 Testing BlockchainService honours max_concurrent_operations...");
+
+    assert_eq!(BlockchainConfig::default().max_concurrent_operations, DEFAULT_MAX_CONCURRENT_OPERATIONS);
+
+    let slow = SlowProvider::default();
+    let config = BlockchainConfig::default().with_max_concurrent_operations(1);
+    let service = BlockchainService::with_provider_and_config(slow.clone(), config).await.unwrap();
+
+    println!("📝 INPUT: 4 concurrent balance({}) calls with a limit of 1", ALICE);
+    let results = join_all((0..4).map(|_| {
+        service.balance(Parameters(BalanceRequest { who: ALICE.to_string(), block: None }))
+    })).await;
+    println!("✅ OUTPUT: peak {} in flight", slow.peak());
+    assert!(results.iter().all(Result::is_ok));
+    assert_eq!(slow.peak(), 1, "Balance reads ran one at a time");
+
+    // Zero would stall every call, so it is raised to one
+    assert_eq!(BlockchainConfig::default().with_max_concurrent_operations(0).max_concurrent_operations, 1);
+
+    println!("🔚 Test completed\n");
+}
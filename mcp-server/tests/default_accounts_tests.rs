@@ -0,0 +1,155 @@
+//! Default Account Naming Tests for MCP Blockchain Server
+//!
+//! These tests verify that the default sender and recipient names and indices
+//! come from `BlockchainConfig`, drive address resolution and the
+//! `get_default_addresses` text, and are validated (using MockProvider). A node
+//! with a single account keeps the sender and drops the default recipient.
+
+use alloy_primitives::Address;
+use alloy_transport::TransportErrorKind;
+use mcp_server::config::{BlockchainConfig, NamedAccount, DEFAULT_ANVIL_ACCOUNTS};
+use mcp_server::services::blockchain::{accounts_or_defaults, AddressBookEntryRequest, BalanceRequest, BlockchainService};
+use mcp_server::services::provider::MockProvider;
+use rmcp::handler::server::tool::Parameters;
+use std::str::FromStr;
+
+fn carol_and_dave_config() -> BlockchainConfig {
+    BlockchainConfig {
+        default_sender: NamedAccount::new("Carol", 2).unwrap(),
+        default_recipient: NamedAccount::new("dave", 3).unwrap(),
+        ..BlockchainConfig::default()
+    }
+}
+
+fn balance_request(who: &str) -> Parameters<BalanceRequest> {
+    Parameters(BalanceRequest { who: who.to_string(), block: None })
+}
+
+#[test]
+fn test_named_account_validation() {
+    println!("\n🧪 Testing default account name validation...");
+
+    let carol = NamedAccount::new("  Carol ", 2).unwrap();
+    assert_eq!(carol.name, "carol");
+    assert_eq!(carol.display_name(), "Carol");
+
+    for name in ["", "account3", "0xabc", "vitalik.eth"] {
+        let result = NamedAccount::new(name, 0);
+        println!("📝 INPUT: {:?} ✅ OUTPUT: {:?}", name, result);
+        assert!(result.is_err(), "'{}' should be rejected", name);
+    }
+
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_configured_names_resolve_to_configured_accounts() {
+    println!("\n🧪 Testing configured sender/recipient names resolve...");
+
+    let service = BlockchainService::with_provider_and_config(MockProvider::new(), carol_and_dave_config()).await.unwrap();
+
+    let result = service.balance(balance_request("carol")).await.unwrap();
+    let text = format!("{:?}", result.content);
+    println!("✅ OUTPUT: {}", text);
+    assert!(text.contains(&format!("resolved to {}", DEFAULT_ANVIL_ACCOUNTS[2])));
+    assert!(text.contains("Carol (Account 2 - Default Sender)"));
+
+    let result = service.balance(balance_request("Dave")).await.unwrap();
+    let text = format!("{:?}", result.content);
+    assert!(text.contains(&format!("resolved to {}", DEFAULT_ANVIL_ACCOUNTS[3])));
+
+    let error = service.balance(balance_request("alice")).await
+        .expect_err("alice is no longer a known name once the sender is renamed");
+    println!("✅ OUTPUT: {}", error.message);
+    assert!(error.message.contains("Known accounts: carol, dave"));
+
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_default_addresses_use_configured_names() {
+    println!("\n🧪 Testing get_default_addresses uses the configured names...");
+
+    let service = BlockchainService::with_provider_and_config(MockProvider::new(), carol_and_dave_config()).await.unwrap();
+
+    let result = service.get_default_addresses().await.unwrap();
+    let text = format!("{:?}", result.content);
+    println!("✅ OUTPUT: {}", text);
+    assert!(text.contains("Carol (Account 2 - Default Sender)"));
+    assert!(text.contains("Dave (Account 3 - Default Recipient)"));
+    assert!(!text.contains("Alice"));
+
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_address_book_cannot_shadow_configured_names() {
+    println!("\n🧪 Testing the address book rejects configured account names...");
+
+    let service = BlockchainService::with_provider_and_config(MockProvider::new(), carol_and_dave_config()).await.unwrap();
+
+    let result = service.add_address_book_entry(Parameters(AddressBookEntryRequest {
+        name: "Carol".to_string(),
+        address: DEFAULT_ANVIL_ACCOUNTS[5].to_string(),
+    })).await;
+    let error = result.expect_err("Configured names are reserved");
+    println!("✅ OUTPUT: {}", error.message);
+    assert!(error.message.contains("reserved account name"));
+
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_indices_must_exist() {
+    println!("\n🧪 Testing an out-of-range default sender index is rejected...");
+
+    let config = BlockchainConfig {
+        default_sender: NamedAccount::new("carol", 20).unwrap(),
+        ..BlockchainConfig::default()
+    };
+    let result = BlockchainService::with_provider_and_config(MockProvider::new(), config).await;
+    let error = result.err().expect("Account 20 doesn't exist").to_string();
+    println!("✅ OUTPUT: {}", error);
+    assert!(error.contains("At least 21 accounts are required"));
+
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_single_account_has_no_default_recipient() {
+    println!("\n🧪 Testing a one-account node degrades to sender only...");
+
+    let config = BlockchainConfig {
+        accounts: vec![Address::from_str(DEFAULT_ANVIL_ACCOUNTS[0]).unwrap()],
+        ..BlockchainConfig::default()
+    };
+    let service = BlockchainService::with_provider_and_config(MockProvider::new(), config).await
+        .expect("A missing recipient account is not fatal");
+
+    let result = service.balance(balance_request("alice")).await.unwrap();
+    assert!(format!("{:?}", result.content).contains("Alice (Account 0 - Default Sender)"));
+
+    let error = service.balance(balance_request("bob")).await.expect_err("There is no account 1");
+    println!("✅ OUTPUT: {}", error.message);
+    assert!(error.message.contains("Bob (account 1) is not available"));
+
+    let result = service.get_default_addresses().await.unwrap();
+    assert!(format!("{:?}", result.content).contains("Not available (only 1 account(s) loaded)"));
+
+    println!("🔚 Test completed\n");
+}
+
+#[test]
+fn test_node_accounts_fall_back_only_when_empty() {
+    println!("\n🧪 Testing eth_accounts results pick the account list...");
+
+    let single = vec![Address::from_str(DEFAULT_ANVIL_ACCOUNTS[4]).unwrap()];
+    assert_eq!(accounts_or_defaults(Ok(single.clone())), single, "A short node list is kept");
+    assert_eq!(accounts_or_defaults(Ok(Vec::new())), BlockchainConfig::default_accounts());
+    assert_eq!(
+        accounts_or_defaults(Err(TransportErrorKind::custom_str("connection refused"))),
+        BlockchainConfig::default_accounts()
+    );
+
+    println!("🔚 Test completed\n");
+}
@@ -0,0 +1,120 @@
+//! Contract Deployment Tests for MCP Blockchain Server
+//!
+//! These tests verify how deploy_contract builds contract creation data from
+//! bytecode and constructor arguments, that bad input is rejected before
+//! anything is sent, and that the creation transaction has no recipient.
+//! MockProvider records the transaction instead of broadcasting it.
+
+use alloy_primitives::{hex, Address, TxKind, U256};
+use mcp_server::config::BlockchainConfig;
+use mcp_server::services::blockchain::{deployment_data, BlockchainService, DeployContractRequest};
+use mcp_server::services::provider::MockProvider;
+use rmcp::handler::server::tool::Parameters;
+use std::str::FromStr;
+
+/// Minimal init code: returns an empty runtime
+const INIT_CODE: &str = "0x6080604052348015600f57600080fd5b50";
+
+fn request(bytecode: &str, constructor: Option<&str>, args: &[&str]) -> Parameters<DeployContractRequest> {
+    Parameters(DeployContractRequest {
+        bytecode: bytecode.to_string(),
+        constructor: constructor.map(str::to_string),
+        args: Some(args.iter().map(|arg| arg.to_string()).collect()),
+        confirmation_timeout: Some(1),
+    })
+}
+
+#[test]
+fn test_deployment_data_appends_encoded_constructor_args() {
+    println!("\n🧪 Testing contract creation data...");
+
+    let plain = deployment_data(INIT_CODE, None, &[]).unwrap();
+    assert_eq!(plain.to_vec(), hex::decode(INIT_CODE).unwrap());
+    assert_eq!(deployment_data(&INIT_CODE[2..], None, &[]).unwrap(), plain, "The 0x prefix is optional");
+
+    let owner = "0x70997970C51812dc3A010C7d01b50e0d17dc79C8";
+    let args = vec!["1000".to_string(), owner.to_string()];
+    let data = deployment_data(INIT_CODE, Some("constructor(uint256,address)"), &args).unwrap();
+    println!("✅ OUTPUT: 0x{}", hex::encode(&data));
+
+    assert_eq!(data.len(), plain.len() + 64, "Two static arguments add two words");
+    assert_eq!(&data[..plain.len()], &plain[..]);
+    assert_eq!(U256::from_be_slice(&data[plain.len()..plain.len() + 32]), U256::from(1000));
+    assert_eq!(Address::from_slice(&data[plain.len() + 44..]), Address::from_str(owner).unwrap());
+
+    let bare = deployment_data(INIT_CODE, Some("(uint256,address)"), &args).unwrap();
+    assert_eq!(bare, data, "The 'constructor' prefix is optional");
+
+    println!("🔚 Test completed\n");
+}
+
+#[test]
+fn test_deployment_data_rejects_bad_input() {
+    println!("\n🧪 Testing invalid deployments are rejected...");
+
+    let cases = vec![
+        (deployment_data("0xzz", None, &[]), "expected hex"),
+        (deployment_data("0x", None, &[]), "it is empty"),
+        (deployment_data("", None, &[]), "it is empty"),
+        (deployment_data(INIT_CODE, None, &["1".to_string()]), "without a constructor signature"),
+        (deployment_data(INIT_CODE, Some("constructor(uint256)"), &[]), "takes 1 argument(s) but 0 were given"),
+        (deployment_data(INIT_CODE, Some("constructor(uint256)"), &["abc".to_string()]), "Invalid constructor argument 'abc'"),
+        (deployment_data(INIT_CODE, Some("constructor(uint512)"), &["1".to_string()]), "Invalid constructor signature"),
+    ];
+
+    for (result, expected) in cases {
+        let error = result.expect_err("Invalid input should be rejected");
+        println!("✅ OUTPUT: {}", error.message);
+        assert!(error.message.contains(expected), "'{}' should mention '{}'", error.message, expected);
+    }
+
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_deploy_contract_validates_before_sending() {
+    println!("\n🧪 Testing deploy_contract checks its input before sending...");
+
+    let mock = MockProvider::new();
+    let service = BlockchainService::with_provider(mock.clone()).await.unwrap();
+
+    let error = service.deploy_contract(request("not hex", None, &[])).await.expect_err("Bad bytecode is rejected");
+    assert!(error.message.contains("Invalid bytecode"));
+
+    let error = service.deploy_contract(request(INIT_CODE, None, &[])).await.expect_err("Deploying needs a key");
+    println!("✅ OUTPUT: {}", error.message);
+    assert!(error.message.contains("private key not available"));
+    assert!(mock.sent_transactions().is_empty());
+
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_deploy_contract_sends_a_creation_transaction() {
+    println!("\n🧪 Testing deploy_contract sends a transaction without a recipient...");
+
+    let mock = MockProvider::new();
+    let config = BlockchainConfig {
+        // Unreachable, for the calls that go straight to the node; the mock has no receipt, so confirmation times out
+        rpc_url: "http://127.0.0.1:1".to_string(),
+        alice_private_key: "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80".to_string(),
+        ..BlockchainConfig::default()
+    };
+    let service = BlockchainService::with_provider_and_config(mock.clone(), config).await.unwrap();
+
+    println!("📝 INPUT: deploy {} with constructor(uint256) 42", INIT_CODE);
+    let result = service.deploy_contract(request(INIT_CODE, Some("constructor(uint256)"), &["42"])).await.unwrap();
+    let text = format!("{:?}", result.content);
+    println!("✅ OUTPUT: {}", text);
+    assert!(text.contains("Contract Deployment Sent"));
+    assert!(text.contains("check_transaction_status"));
+
+    let sent = mock.sent_transactions();
+    assert_eq!(sent.len(), 1);
+    assert_eq!(sent[0].to, Some(TxKind::Create));
+    let input = sent[0].input.input().unwrap();
+    assert_eq!(input.len(), hex::decode(INIT_CODE).unwrap().len() + 32);
+    assert_eq!(U256::from_be_slice(&input[input.len() - 32..]), U256::from(42));
+
+    println!("🔚 Test completed\n");
+}
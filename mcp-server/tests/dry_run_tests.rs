@@ -0,0 +1,79 @@
+//! Dry Run Tests for MCP Blockchain Server
+//!
+//! These tests verify that `dry_run` transfers are simulated with eth_call and
+//! never broadcast, using MockProvider so they run without anvil.
+
+use mcp_server::services::blockchain::{BlockchainService, SwapRequest, TransferRequest};
+use mcp_server::services::provider::MockProvider;
+use rmcp::handler::server::tool::Parameters;
+
+const BOB: &str = "0x70997970C51812dc3A010C7d01b50e0d17dc79C8";
+
+#[test]
+fn test_dry_run_request_serialization() {
+    println!("\n🧪 Testing dry_run field on transfer and swap requests...");
+
+    let transfer: TransferRequest = serde_json::from_str(r#"{"to":"bob","amount":"1.0","dry_run":true}"#).unwrap();
+    println!("✅ OUTPUT: TransferRequest dry_run={:?}", transfer.dry_run);
+    assert_eq!(transfer.dry_run, Some(true));
+
+    let swap: SwapRequest = serde_json::from_str(r#"{"from_token":"ETH","to_token":"USDC","amount":"1"}"#).unwrap();
+    println!("✅ OUTPUT: SwapRequest dry_run={:?}", swap.dry_run);
+    assert_eq!(swap.dry_run, None, "dry_run is optional and defaults to broadcasting");
+
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_send_eth_dry_run_does_not_broadcast() {
+    println!("\n🧪 Testing send_eth dry run succeeds without broadcasting...");
+
+    let mock = MockProvider::new();
+    let service = BlockchainService::with_provider(mock.clone()).await.unwrap();
+
+    let request = TransferRequest {
+        to: BOB.to_string(),
+        amount: "1.0".to_string(),
+        from: None,
+        dry_run: Some(true),
+        confirmation_timeout: None,
+    };
+    println!("📝 INPUT: Simulate sending {} ETH to {}", request.amount, request.to);
+
+    let result = service.send_eth(Parameters(request)).await.unwrap();
+    let text = format!("{:?}", result.content);
+    println!("✅ OUTPUT: {}", text);
+
+    assert!(text.contains("ETH Transfer Simulation (dry run)"));
+    assert!(text.contains("Simulation: WOULD SUCCEED"));
+    assert!(mock.sent_transactions().is_empty(), "Dry run must not send a transaction");
+
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_send_eth_dry_run_reports_revert() {
+    println!("\n🧪 Testing send_eth dry run reports a revert reason...");
+
+    let mock = MockProvider::new().failing("execution reverted: insufficient funds for transfer");
+    let service = BlockchainService::with_provider(mock.clone()).await.unwrap();
+
+    let request = TransferRequest {
+        to: BOB.to_string(),
+        amount: "1000000".to_string(),
+        from: None,
+        dry_run: Some(true),
+        confirmation_timeout: None,
+    };
+    println!("📝 INPUT: Simulate sending {} ETH to {}", request.amount, request.to);
+
+    let result = service.send_eth(Parameters(request)).await.unwrap();
+    let text = format!("{:?}", result.content);
+    println!("✅ OUTPUT: {}", text);
+
+    assert!(text.contains("Simulation: WOULD REVERT"));
+    assert!(text.contains("insufficient funds for transfer"));
+    assert!(mock.sent_transactions().is_empty());
+
+    println!("🔚 Test completed\n");
+}
@@ -0,0 +1,153 @@
+//! ENS Resolution Tests for MCP Blockchain Server
+//!
+//! These tests verify the ENS request structures and the forward and reverse
+//! resolution tools against a forked mainnet anvil node, plus the get_ens_records
+//! text lookups against a mocked registry and resolver.
+
+use alloy_ens::ENS_ADDRESS;
+use alloy_primitives::Address;
+use mcp_server::services::blockchain::{
+    BlockchainService, EnsRecordsRequest, EnsRecordsResult, EnsResolveRequest, EnsReverseRequest,
+};
+use mcp_server::services::provider::MockProvider;
+use rmcp::handler::server::tool::Parameters;
+use rmcp::model::CallToolResult;
+use std::str::FromStr;
+
+const RESOLVER: &str = "0x231b0Ee14048e9dCcD1d247744d114a4EB5E8E63";
+
+fn address_word(address: Address) -> Vec<u8> {
+    let mut bytes = vec![0u8; 12];
+    bytes.extend_from_slice(address.as_slice());
+    bytes
+}
+
+fn content_texts(result: &CallToolResult) -> Vec<String> {
+    result.content.iter()
+        .map(|content| content.as_text().expect("tools return text content").text.clone())
+        .collect()
+}
+
+fn ens_provider(resolver: Address, text: &str) -> MockProvider {
+    let registry = ENS_ADDRESS;
+    MockProvider::new()
+        .with_call_response(registry, "resolver(bytes32)", address_word(resolver))
+        .with_string_response(resolver, "text(bytes32,string)", text)
+}
+
+#[test]
+fn test_ens_request_serialization() {
+    println!("\n🧪 Testing ENS request serialization...");
+
+    let forward = EnsResolveRequest { name: "vitalik.eth".to_string() };
+    let json = serde_json::to_string(&forward).unwrap();
+    println!("✅ OUTPUT JSON: {}", json);
+    let deserialized: EnsResolveRequest = serde_json::from_str(&json).unwrap();
+    assert_eq!(forward.name, deserialized.name);
+
+    let reverse = EnsReverseRequest { address: "0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045".to_string() };
+    let json = serde_json::to_string(&reverse).unwrap();
+    println!("✅ OUTPUT JSON: {}", json);
+    let deserialized: EnsReverseRequest = serde_json::from_str(&json).unwrap();
+    assert_eq!(reverse.address, deserialized.address);
+
+    println!("✅ ENS request serialization test: PASSED");
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_resolve_and_reverse_resolve_ens() {
+    println!("\n🧪 Testing resolve_ens and reverse_resolve_ens tools...");
+
+    match BlockchainService::new().await {
+        Ok(service) => {
+            println!("📝 INPUT: Resolve vitalik.eth");
+            let result = service
+                .resolve_ens(Parameters(EnsResolveRequest { name: "vitalik.eth".to_string() }))
+                .await
+                .unwrap();
+            println!("✅ OUTPUT: {:?}", result.content);
+            // Resolution can fail without a mainnet fork, but the tool still reports the outcome
+            assert!(format!("{:?}", result.content).contains("Resolved:"));
+
+            println!("📝 INPUT: Reverse resolve vitalik's address");
+            let request = EnsReverseRequest { address: "0xd8dA6BF26964aF9D7eEd9e03E53415D37aA96045".to_string() };
+            let result = service.reverse_resolve_ens(Parameters(request)).await.unwrap();
+            println!("✅ OUTPUT: {:?}", result.content);
+            assert!(format!("{:?}", result.content).contains("Has ENS Name:"));
+
+            println!("📝 INPUT: Reverse resolve an invalid address");
+            let request = EnsReverseRequest { address: "not-an-address".to_string() };
+            assert!(service.reverse_resolve_ens(Parameters(request)).await.is_err());
+        }
+        Err(e) => {
+            println!("⚠️  BlockchainService creation failed: {}", e);
+            println!("💡 This is expected if anvil is not running");
+        }
+    }
+
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_get_ens_records() {
+    println!("\n🧪 Testing get_ens_records reads text records through the resolver...");
+
+    let resolver = Address::from_str(RESOLVER).unwrap();
+    let service = BlockchainService::with_provider(ens_provider(resolver, "https://vitalik.ca")).await.unwrap();
+    let request = EnsRecordsRequest { name: "vitalik.eth".to_string(), keys: vec!["url".to_string()] };
+
+    println!("📝 INPUT: url record of vitalik.eth");
+    let result = service.get_ens_records(Parameters(request)).await.unwrap();
+    let texts = content_texts(&result);
+    println!("✅ OUTPUT: {:?}", texts);
+
+    let records: EnsRecordsResult = serde_json::from_str(&texts[0]).unwrap();
+    assert_eq!(records.resolver, resolver.to_string());
+    assert_eq!(records.records.get("url").map(String::as_str), Some("https://vitalik.ca"));
+    assert!(texts[1].contains("url: https://vitalik.ca"));
+
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_get_ens_records_omits_empty_records() {
+    println!("\n🧪 Testing empty text records are left out...");
+
+    let service = BlockchainService::with_provider(ens_provider(Address::from_str(RESOLVER).unwrap(), "")).await.unwrap();
+    let request = EnsRecordsRequest { name: "vitalik.eth".to_string(), keys: vec!["email".to_string(), "avatar".to_string()] };
+
+    let result = service.get_ens_records(Parameters(request)).await.unwrap();
+    let texts = content_texts(&result);
+    println!("✅ OUTPUT: {:?}", texts);
+
+    let records: EnsRecordsResult = serde_json::from_str(&texts[0]).unwrap();
+    assert!(records.records.is_empty());
+    assert!(texts[1].contains("No records set for: email, avatar"));
+
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_get_ens_records_without_resolver() {
+    println!("\n🧪 Testing names without a resolver, and chains without ENS, are rejected clearly...");
+
+    let service = BlockchainService::with_provider(ens_provider(Address::ZERO, "")).await.unwrap();
+    let request = EnsRecordsRequest { name: "unregistered-name.eth".to_string(), keys: Vec::new() };
+    let error = service.get_ens_records(Parameters(request)).await.expect_err("A name without a resolver can't have records");
+    println!("✅ OUTPUT: {}", error.message);
+    assert!(error.message.contains("unregistered-name.eth does not resolve: it has no resolver set"));
+
+    // A local anvil chain has no code at the registry, so calls return no data
+    let registry = ENS_ADDRESS;
+    let service = BlockchainService::with_provider(MockProvider::new().with_call_response(registry, "resolver(bytes32)", Vec::new())).await.unwrap();
+    let request = EnsRecordsRequest { name: "vitalik.eth".to_string(), keys: Vec::new() };
+    let error = service.get_ens_records(Parameters(request)).await.expect_err("There is no ENS registry to ask");
+    println!("✅ OUTPUT: {}", error.message);
+    assert!(error.message.contains("No ENS registry"));
+
+    let request = EnsRecordsRequest { name: "vitalik".to_string(), keys: Vec::new() };
+    assert!(service.get_ens_records(Parameters(request)).await.is_err(), "A bare label is not an ENS name");
+
+    println!("🔚 Test completed\n");
+}
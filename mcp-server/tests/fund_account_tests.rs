@@ -0,0 +1,56 @@
+//! Fund Account Tests for MCP Blockchain Server
+//!
+//! These tests verify how `fund_account` detects nodes without anvil
+//! cheatcodes and validates its input before calling anvil_setBalance.
+//! They use MockProvider, so they run without anvil.
+
+use alloy_transport::TransportErrorKind;
+use mcp_server::services::blockchain::{BlockchainService, FundAccountRequest};
+use mcp_server::services::provider::{is_method_not_found, MockProvider};
+use rmcp::handler::server::tool::Parameters;
+
+#[test]
+fn test_method_not_found_detection() {
+    println!("\n🧪 Testing method-not-found error classification...");
+
+    let test_cases = vec![
+        ("Method not found", true),
+        ("the method anvil_setBalance does not exist/is not available", true),
+        ("error sending request: connection refused", false),
+        ("execution reverted", false),
+        ("execution reverted: ERC721: token does not exist", false),
+        ("transaction type not supported", false),
+    ];
+
+    for (message, expected) in test_cases {
+        let error = TransportErrorKind::custom_str(message);
+        let not_found = is_method_not_found(&error);
+        println!("📝 INPUT: \"{}\" ✅ OUTPUT: method_not_found={}", message, not_found);
+        assert_eq!(not_found, expected, "Wrong classification for '{}'", message);
+    }
+
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_fund_account_validates_input() {
+    println!("\n🧪 Testing fund_account rejects bad input before calling the node...");
+
+    let service = BlockchainService::with_provider(MockProvider::new()).await.unwrap();
+
+    let error = service.fund_account(Parameters(FundAccountRequest {
+        address: "nobody".to_string(),
+        amount: "100".to_string(),
+    })).await.expect_err("Unknown accounts should be rejected");
+    println!("✅ OUTPUT: {}", error.message);
+    assert!(error.message.contains("Invalid recipient address"));
+
+    let error = service.fund_account(Parameters(FundAccountRequest {
+        address: "account3".to_string(),
+        amount: "lots".to_string(),
+    })).await.expect_err("Non-numeric amounts should be rejected");
+    println!("✅ OUTPUT: {}", error.message);
+    assert!(error.message.contains("Invalid ETH amount 'lots'"));
+
+    println!("🔚 Test completed\n");
+}
@@ -0,0 +1,86 @@
+//! Gas Estimation Tests for MCP Blockchain Server
+//!
+//! These tests verify the transfer cost request structure and the
+//! formatting of gas estimates included in send_eth responses.
+
+use alloy_primitives::U256;
+use mcp_server::services::blockchain::{BlockchainService, GasCostEstimate, TransferCostRequest};
+use rmcp::handler::server::tool::Parameters;
+
+#[test]
+fn test_transfer_cost_request_serialization() {
+    println!("\n🧪 Testing TransferCostRequest serialization...");
+
+    let request = TransferCostRequest {
+        to: "bob".to_string(),
+        amount: "1.0".to_string(),
+    };
+    println!("📝 INPUT STRUCT: TransferCostRequest {{ to: \"{}\", amount: \"{}\" }}", request.to, request.amount);
+
+    let json = serde_json::to_string(&request).unwrap();
+    println!("✅ OUTPUT JSON: {}", json);
+
+    let deserialized: TransferCostRequest = serde_json::from_str(&json).unwrap();
+    assert_eq!(request.to, deserialized.to);
+    assert_eq!(request.amount, deserialized.amount);
+
+    println!("✅ TransferCostRequest serialization test: PASSED");
+    println!("🔚 Test completed\n");
+}
+
+#[test]
+fn test_gas_cost_estimate_summary() {
+    println!("\n🧪 Testing GasCostEstimate summary formatting...");
+
+    // A plain ETH transfer costs 21000 gas; at 2 gwei that's 0.000042 ETH
+    let gas_limit = U256::from(21_000u64);
+    let gas_price = U256::from(2_000_000_000u64);
+    let estimate = GasCostEstimate {
+        gas_limit,
+        gas_price,
+        total_cost: gas_limit * gas_price,
+    };
+
+    let summary = estimate.summary();
+    println!("✅ OUTPUT SUMMARY:\n{}", summary);
+
+    assert!(summary.contains("Estimated Gas: 21000"));
+    assert!(summary.contains("Gas Price: 2.00 gwei (2000000000 wei)"));
+    assert!(summary.contains("42000000000000 wei"));
+    assert!(summary.contains("0.000042 ETH"));
+
+    println!("✅ GasCostEstimate summary test: PASSED");
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_estimate_transfer_cost() {
+    println!("\n🧪 Testing estimate_transfer_cost tool...");
+
+    match BlockchainService::new().await {
+        Ok(service) => {
+            let request = TransferCostRequest {
+                to: "bob".to_string(),
+                amount: "1.0".to_string(),
+            };
+            println!("📝 INPUT: Estimate cost of sending {} ETH to {}", request.amount, request.to);
+
+            match service.estimate_transfer_cost(Parameters(request)).await {
+                Ok(result) => {
+                    println!("✅ OUTPUT: {:?}", result.content);
+                    assert!(format!("{:?}", result.content).contains("Estimated Gas"));
+                }
+                Err(e) => {
+                    println!("⚠️  Cost estimate failed: {}", e);
+                    println!("💡 This might be expected if anvil is not running");
+                }
+            }
+        }
+        Err(e) => {
+            println!("⚠️  BlockchainService creation failed: {}", e);
+            println!("💡 This is expected if anvil is not running");
+        }
+    }
+
+    println!("🔚 Test completed\n");
+}
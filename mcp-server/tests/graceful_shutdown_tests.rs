@@ -0,0 +1,81 @@
+//! Graceful Shutdown Tests for MCP Blockchain Server
+//!
+//! These tests verify that after the shutdown signal the server stops
+//! accepting connections, lets in-flight requests finish, and exits once the
+//! grace period runs out if a request never completes. A small axum router
+//! stands in for the MCP service.
+
+use mcp_server::server::{serve_with_graceful_shutdown, ShutdownOutcome};
+use std::time::{Duration, Instant};
+use tokio::sync::oneshot;
+
+fn router() -> axum::Router {
+    axum::Router::new()
+        .route("/slow", axum::routing::get(|| async {
+            tokio::time::sleep(Duration::from_millis(500)).await;
+            "done"
+        }))
+        .route("/stuck", axum::routing::get(|| async {
+            tokio::time::sleep(Duration::from_secs(60)).await;
+            "never"
+        }))
+}
+
+/// Start the router on a free port; sending on the returned channel triggers shutdown
+async fn start(grace_period: Duration) -> (String, oneshot::Sender<()>, tokio::task::JoinHandle<ShutdownOutcome>) {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let (shutdown_tx, shutdown_rx) = oneshot::channel::<()>();
+    let server = tokio::spawn(async move {
+        let signal = async move {
+            let _ = shutdown_rx.await;
+        };
+        serve_with_graceful_shutdown(listener, router(), signal, grace_period).await.unwrap()
+    });
+    (base_url, shutdown_tx, server)
+}
+
+#[tokio::test]
+async fn test_in_flight_requests_finish_before_exit() {
+    println!("\n🧪 Testing in-flight requests complete during shutdown...");
+
+    let (base_url, shutdown, server) = start(Duration::from_secs(10)).await;
+    let request = tokio::spawn(reqwest::get(format!("{}/slow", base_url)));
+
+    // Signal while /slow is still sleeping
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    println!("📝 INPUT: shutdown signal during a 500ms request");
+    shutdown.send(()).unwrap();
+
+    let response = request.await.unwrap().expect("The in-flight request is answered");
+    assert_eq!(response.text().await.unwrap(), "done");
+
+    let outcome = server.await.unwrap();
+    println!("✅ OUTPUT: {:?}", outcome);
+    assert_eq!(outcome, ShutdownOutcome::Drained);
+
+    assert!(reqwest::get(format!("{}/slow", base_url)).await.is_err(), "No connections after shutdown");
+
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_shutdown_gives_up_after_grace_period() {
+    println!("\n🧪 Testing shutdown stops waiting after the grace period...");
+
+    let (base_url, shutdown, server) = start(Duration::from_millis(300)).await;
+    let _request = tokio::spawn(reqwest::get(format!("{}/stuck", base_url)));
+
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    let started = Instant::now();
+    shutdown.send(()).unwrap();
+
+    let outcome = tokio::time::timeout(Duration::from_secs(5), server).await
+        .expect("The server exits despite the stuck request")
+        .unwrap();
+    println!("✅ OUTPUT: {:?} after {:?}", outcome, started.elapsed());
+    assert_eq!(outcome, ShutdownOutcome::GracePeriodElapsed);
+    assert!(started.elapsed() >= Duration::from_millis(300));
+
+    println!("🔚 Test completed\n");
+}
@@ -0,0 +1,68 @@
+//! Health Check Tests for MCP Blockchain Server
+//!
+//! These tests verify the health_check report for a live and an unreachable
+//! provider, using MockProvider so they run without anvil.
+
+use mcp_server::services::blockchain::{format_health_check, BlockchainService};
+use mcp_server::services::provider::MockProvider;
+use std::time::Duration;
+
+#[test]
+fn test_format_health_check() {
+    println!("\n🧪 Testing health_check formatting...");
+
+    let healthy = format_health_check(Duration::from_secs(42), Ok(19_000_000), Ok(1), 10, true);
+    println!("✅ OUTPUT (healthy): {}", healthy);
+    assert!(healthy.contains("Status: HEALTHY"));
+    assert!(healthy.contains("Uptime: 42s"));
+    assert!(healthy.contains("Provider: CONNECTED (block 19000000)"));
+    assert!(healthy.contains("Chain ID: 1"));
+    assert!(healthy.contains("Accounts Loaded: 10"));
+    assert!(healthy.contains("Private Key Available: true"));
+
+    let degraded = format_health_check(Duration::ZERO, Err("connection refused".to_string()), Err("connection refused".to_string()), 10, false);
+    println!("✅ OUTPUT (degraded): {}", degraded);
+    assert!(degraded.contains("Status: DEGRADED"));
+    assert!(degraded.contains("Provider: DISCONNECTED (connection refused)"));
+    assert!(degraded.contains("Chain ID: UNAVAILABLE (connection refused)"));
+    assert!(degraded.contains("Private Key Available: false"));
+
+    // A node that answers blocks but not eth_chainId is not healthy either
+    let no_chain_id = format_health_check(Duration::ZERO, Ok(1), Err("method not found".to_string()), 10, true);
+    assert!(no_chain_id.contains("Status: DEGRADED"));
+
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_health_check_with_mock_provider() {
+    println!("\n🧪 Testing health_check against a mocked provider...");
+
+    let mock = MockProvider::new().with_block_number(123).with_chain_id(31337);
+    let service = BlockchainService::with_provider(mock).await.unwrap();
+
+    let result = service.health_check().await.unwrap();
+    let text = format!("{:?}", result.content);
+    println!("✅ OUTPUT: {}", text);
+    assert!(text.contains("Status: HEALTHY"));
+    assert!(text.contains("CONNECTED (block 123)"));
+    assert!(text.contains("Chain ID: 31337"));
+
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_health_check_reports_unreachable_provider() {
+    println!("\n🧪 Testing health_check when the provider is unreachable...");
+
+    let service = BlockchainService::with_provider(MockProvider::new().failing("connection refused")).await.unwrap();
+
+    // The probe failing is reported in the result, not as a tool error
+    let result = service.health_check().await.unwrap();
+    let text = format!("{:?}", result.content);
+    println!("✅ OUTPUT: {}", text);
+    assert!(text.contains("Status: DEGRADED"));
+    assert!(text.contains("connection refused"));
+
+    println!("🔚 Test completed\n");
+}
@@ -0,0 +1,112 @@
+//! Historical Balance Tests for MCP Blockchain Server
+//!
+//! These tests verify that `balance` and `token_balance` accept an optional
+//! block number or tag, report which block they read, and reject blocks past
+//! the chain head, using MockProvider so they run without anvil.
+
+use alloy_primitives::{Address, Bytes, U256};
+use alloy_rpc_types::BlockNumberOrTag;
+use mcp_server::services::blockchain::{parse_block_id, BalanceRequest, BlockchainService, TokenBalanceRequest};
+use mcp_server::services::provider::MockProvider;
+use rmcp::handler::server::tool::Parameters;
+use std::str::FromStr;
+
+const ALICE: &str = "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266";
+const USDC: &str = "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48";
+
+fn balance_request(block: Option<&str>) -> Parameters<BalanceRequest> {
+    Parameters(BalanceRequest {
+        who: ALICE.to_string(),
+        block: block.map(str::to_string),
+    })
+}
+
+#[test]
+fn test_parse_block_id() {
+    println!("\n🧪 Testing block number and tag parsing...");
+
+    assert_eq!(parse_block_id("19000000").unwrap(), BlockNumberOrTag::Number(19_000_000));
+    assert_eq!(parse_block_id("0x10").unwrap(), BlockNumberOrTag::Number(16));
+    assert_eq!(parse_block_id(" Latest ").unwrap(), BlockNumberOrTag::Latest);
+    assert_eq!(parse_block_id("finalized").unwrap(), BlockNumberOrTag::Finalized);
+
+    let error = parse_block_id("yesterday").expect_err("Unknown tags should be rejected");
+    println!("✅ OUTPUT: {}", error.message);
+    assert!(error.message.contains("Invalid block 'yesterday'"));
+
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_balance_at_historical_block() {
+    println!("\n🧪 Testing balance at a past block...");
+
+    let alice = Address::from_str(ALICE).unwrap();
+    let mock = MockProvider::new()
+        .with_block_number(200)
+        .with_balance(alice, U256::from(3_000_000_000_000_000_000u128))
+        .with_balance_at(alice, 100, U256::from(1_000_000_000_000_000_000u128));
+    let service = BlockchainService::with_provider(mock).await.unwrap();
+
+    println!("📝 INPUT: balance({}, block=100)", ALICE);
+    let historical = service.balance(balance_request(Some("100"))).await.unwrap();
+    let text = format!("{:?}", historical.content);
+    println!("✅ OUTPUT: {}", text);
+    assert!(text.contains("Block: 100"));
+    assert!(text.contains("1.000000 ETH"));
+
+    println!("📝 INPUT: balance({})", ALICE);
+    let latest = service.balance(balance_request(None)).await.unwrap();
+    let text = format!("{:?}", latest.content);
+    println!("✅ OUTPUT: {}", text);
+    assert!(text.contains("Block: latest"));
+    assert!(text.contains("3.000000 ETH"));
+
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_future_block_is_rejected() {
+    println!("\n🧪 Testing balance and token_balance reject a future block...");
+
+    let service = BlockchainService::with_provider(MockProvider::new().with_block_number(200)).await.unwrap();
+
+    let error = service.balance(balance_request(Some("201"))).await
+        .expect_err("Blocks past the chain head should be rejected");
+    println!("✅ OUTPUT: {}", error.message);
+    assert!(error.message.contains("Block 201 is in the future: the latest block is 200"));
+
+    let error = service.token_balance(Parameters(TokenBalanceRequest {
+        token_address: USDC.to_string(),
+        account_address: ALICE.to_string(),
+        block: Some("5000".to_string()),
+    })).await.expect_err("Blocks past the chain head should be rejected");
+    println!("✅ OUTPUT: {}", error.message);
+    assert!(error.message.contains("Block 5000 is in the future"));
+
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_token_balance_reports_block() {
+    println!("\n🧪 Testing token_balance states the block it read...");
+
+    let usdc = Address::from_str(USDC).unwrap();
+    let mock = MockProvider::new()
+        .with_block_number(200)
+        .with_call_response(usdc, "balanceOf(address)", Bytes::from(U256::from(2_500_000u64).to_be_bytes::<32>().to_vec()))
+        .with_call_response(usdc, "decimals()", Bytes::from(U256::from(6u8).to_be_bytes::<32>().to_vec()));
+    let service = BlockchainService::with_provider(mock).await.unwrap();
+
+    let result = service.token_balance(Parameters(TokenBalanceRequest {
+        token_address: USDC.to_string(),
+        account_address: ALICE.to_string(),
+        block: Some("150".to_string()),
+    })).await.unwrap();
+    let text = format!("{:?}", result.content);
+    println!("✅ OUTPUT: {}", text);
+    assert!(text.contains("Block: 150"));
+    assert!(text.contains("raw: 2500000"));
+
+    println!("🔚 Test completed\n");
+}
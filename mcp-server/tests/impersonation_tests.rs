@@ -0,0 +1,82 @@
+//! Impersonation Tests for MCP Blockchain Server
+//!
+//! These tests verify that `impersonate_and_send` stays disabled unless
+//! ALLOW_IMPERSONATION is set, validates its input, and never sends a
+//! transaction when anvil impersonation can't be started (using MockProvider).
+
+use mcp_server::config::BlockchainConfig;
+use mcp_server::services::blockchain::{BlockchainService, ImpersonatedTransferRequest};
+use mcp_server::services::provider::MockProvider;
+use rmcp::handler::server::tool::Parameters;
+
+const WHALE: &str = "0x55FE002aefF02F77364de339a1292923A15844B8";
+
+fn impersonation_config() -> BlockchainConfig {
+    BlockchainConfig {
+        allow_impersonation: true,
+        ..BlockchainConfig::default()
+    }
+}
+
+fn transfer_request(from: &str, amount: &str) -> Parameters<ImpersonatedTransferRequest> {
+    Parameters(ImpersonatedTransferRequest {
+        from: from.to_string(),
+        to: "bob".to_string(),
+        amount: amount.to_string(),
+        token_address: None,
+        confirmation_timeout: None,
+    })
+}
+
+#[tokio::test]
+async fn test_impersonation_disabled_by_default() {
+    println!("\n🧪 Testing impersonate_and_send is off without ALLOW_IMPERSONATION...");
+
+    let mock = MockProvider::new();
+    let service = BlockchainService::with_provider(mock.clone()).await.unwrap();
+
+    println!("📝 INPUT: impersonate_and_send(from={}, to=bob, amount=1)", WHALE);
+    let error = service.impersonate_and_send(transfer_request(WHALE, "1")).await
+        .expect_err("Impersonation should be disabled by default");
+    println!("✅ OUTPUT: {}", error.message);
+    assert!(error.message.contains("ALLOW_IMPERSONATION=true"));
+    assert!(mock.sent_transactions().is_empty());
+
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_impersonation_validates_input() {
+    println!("\n🧪 Testing impersonate_and_send rejects bad input...");
+
+    let service = BlockchainService::with_provider_and_config(MockProvider::new(), impersonation_config()).await.unwrap();
+
+    let error = service.impersonate_and_send(transfer_request("nobody", "1")).await
+        .expect_err("Unknown senders should be rejected");
+    println!("✅ OUTPUT: {}", error.message);
+    assert!(error.message.contains("Invalid recipient address"));
+
+    let error = service.impersonate_and_send(transfer_request(WHALE, "lots")).await
+        .expect_err("Non-numeric amounts should be rejected");
+    println!("✅ OUTPUT: {}", error.message);
+    assert!(error.message.contains("Invalid ETH amount 'lots'"));
+
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_no_transaction_without_impersonation() {
+    println!("\n🧪 Testing nothing is sent when anvil_impersonateAccount fails...");
+
+    let mock = MockProvider::new();
+    let service = BlockchainService::with_provider_and_config(mock.clone(), impersonation_config()).await.unwrap();
+
+    // The test service's raw RPC provider points at an unreachable node
+    let result = service.impersonate_and_send(transfer_request(WHALE, "1")).await;
+    let error = result.expect_err("Impersonation can't start without a node");
+    println!("✅ OUTPUT: {}", error.message);
+    assert!(error.message.contains("anvil_impersonateAccount"));
+    assert!(mock.sent_transactions().is_empty(), "No transaction should be sent unimpersonated");
+
+    println!("🔚 Test completed\n");
+}
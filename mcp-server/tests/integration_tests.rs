@@ -34,16 +34,21 @@ fn test_all_modules_available() {
     // Test that we can create basic request structures
     let _balance_req = BalanceRequest {
         who: "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266".to_string(),
+        block: None,
     };
     
     let _transfer_req = TransferRequest {
         to: "0x70997970C51812dc3A010C7d01b50e0d17dc79C8".to_string(),
         amount: "1.0".to_string(),
+        from: None,
+        dry_run: None,
+        confirmation_timeout: None,
     };
     
     let _token_req = TokenBalanceRequest {
         token_address: "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48".to_string(),
         account_address: "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266".to_string(),
+        block: None,
     };
     
     let _search_req = WebSearchRequest {
@@ -51,6 +56,7 @@ fn test_all_modules_available() {
         count: Some(3),
         country: Some("us".to_string()),
         search_lang: Some("en".to_string()),
+        offset: None,
     };
     
     let _swap_req = SwapIntentRequest {
@@ -0,0 +1,131 @@
+//! Event Log Query Tests for MCP Blockchain Server
+//!
+//! These tests verify get_logs decodes well-known events (Transfer, Approval,
+//! Swap) into named fields, falls back to raw topics and data for unknown
+//! events, and truncates block ranges wider than MAX_LOG_BLOCKS.
+
+use alloy_primitives::{keccak256, Address, LogData, B256, U256};
+use alloy_rpc_types::Log;
+use mcp_server::services::blockchain::{
+    cap_log_range, decode_log, event_topic, BlockchainService, LogQueryRequest, MAX_LOG_BLOCKS,
+};
+use mcp_server::services::provider::MockProvider;
+use rmcp::handler::server::tool::Parameters;
+use std::str::FromStr;
+
+const TOKEN: &str = "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48";
+const ALICE: &str = "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266";
+const BOB: &str = "0x70997970C51812dc3A010C7d01b50e0d17dc79C8";
+
+fn address_topic(address: &str) -> B256 {
+    Address::from_str(address).unwrap().into_word()
+}
+
+fn transfer_topics() -> Vec<B256> {
+    vec![keccak256("Transfer(address,address,uint256)"), address_topic(ALICE), address_topic(BOB)]
+}
+
+fn mock_log(address: &str, block_number: u64, topics: Vec<B256>, data: Vec<u8>) -> Log {
+    Log {
+        inner: alloy_primitives::Log {
+            address: Address::from_str(address).unwrap(),
+            data: LogData::new_unchecked(topics, data.into()),
+        },
+        block_number: Some(block_number),
+        transaction_hash: Some(keccak256(block_number.to_be_bytes())),
+        log_index: Some(0),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_decode_known_events() {
+    println!("\n🧪 Testing well-known events decode into named fields...");
+
+    let amount = U256::from(1_500_000u64);
+    let decoded = decode_log(&transfer_topics(), &amount.to_be_bytes::<32>());
+    println!("✅ OUTPUT: {:?}", decoded);
+    assert_eq!(decoded.event.as_deref(), Some("Transfer(address,address,uint256)"));
+    assert_eq!(decoded.params, vec![
+        ("from".to_string(), format!("{:?}", Address::from_str(ALICE).unwrap())),
+        ("to".to_string(), format!("{:?}", Address::from_str(BOB).unwrap())),
+        ("value".to_string(), "1500000".to_string()),
+    ]);
+
+    // ERC-721 Transfer has the same topic0 but an indexed token id and no data
+    let mut nft_topics = transfer_topics();
+    nft_topics.push(U256::from(42u64).into());
+    let decoded = decode_log(&nft_topics, &[]);
+    assert_eq!(decoded.params[2], ("tokenId".to_string(), "42".to_string()));
+
+    println!("🔚 Test completed\n");
+}
+
+#[test]
+fn test_decode_unknown_event_returns_raw_log() {
+    println!("\n🧪 Testing unknown events fall back to raw topics and data...");
+
+    let topic0 = keccak256("Sync(uint112,uint112)");
+    let decoded = decode_log(&[topic0], &[0xab, 0xcd]);
+    println!("✅ OUTPUT: {:?}", decoded);
+
+    assert!(decoded.event.is_none());
+    assert!(decoded.params.is_empty());
+    assert_eq!(decoded.topics, vec![format!("{:?}", topic0)]);
+    assert_eq!(decoded.data, "0xabcd");
+
+    // A known topic0 with data that doesn't fit the signature is left raw too
+    assert!(decode_log(&transfer_topics(), &[0x01]).event.is_none());
+
+    println!("🔚 Test completed\n");
+}
+
+#[test]
+fn test_event_topic_and_range_cap() {
+    println!("\n🧪 Testing event signatures hash to topic0 and ranges are capped...");
+
+    let transfer = keccak256("Transfer(address,address,uint256)");
+    assert_eq!(event_topic("Transfer(address, address, uint256)").unwrap(), transfer);
+    assert_eq!(event_topic("transfer").unwrap(), transfer, "Known event names resolve to their signature");
+    assert_eq!(event_topic(&format!("{:?}", transfer)).unwrap(), transfer, "Topic hashes are used as-is");
+    assert!(event_topic("Mint").is_err());
+
+    assert_eq!(cap_log_range(100, 200), (100, false));
+    assert_eq!(cap_log_range(0, 50_000), (50_000 - (MAX_LOG_BLOCKS - 1), true));
+
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_get_logs_tool() {
+    println!("\n🧪 Testing get_logs filters by event and truncates wide ranges...");
+
+    let approval_topics = vec![keccak256("Approval(address,address,uint256)"), address_topic(ALICE), address_topic(BOB)];
+    let mock = MockProvider::new()
+        .with_block_number(20_000)
+        .with_log(mock_log(TOKEN, 19_990, transfer_topics(), U256::from(7u64).to_be_bytes::<32>().to_vec()))
+        .with_log(mock_log(TOKEN, 19_995, approval_topics, U256::MAX.to_be_bytes::<32>().to_vec()))
+        .with_log(mock_log(TOKEN, 5_000, transfer_topics(), U256::from(1u64).to_be_bytes::<32>().to_vec()));
+    let service = BlockchainService::with_provider(mock).await.unwrap();
+
+    let request = LogQueryRequest {
+        address: TOKEN.to_string(),
+        event_signature: Some("Transfer".to_string()),
+        from_block: Some("0".to_string()),
+        to_block: None,
+        limit: None,
+        offset: None,
+    };
+
+    println!("📝 INPUT: Transfer logs of {} from block 0", TOKEN);
+    let result = service.get_logs(Parameters(request)).await.unwrap();
+    let text = format!("{:?}", result.content);
+    println!("✅ OUTPUT: {}", text);
+
+    assert!(text.contains("Range truncated"), "Searching from genesis exceeds the cap");
+    assert!(text.contains("Blocks Searched: 10001 to 20000"));
+    assert!(text.contains("Logs Found: 1"), "Approval and out-of-range logs are filtered out");
+    assert!(text.contains("value: 7"));
+
+    println!("🔚 Test completed\n");
+}
@@ -0,0 +1,66 @@
+//! Network Address Configuration Tests for MCP Blockchain Server
+//!
+//! These tests verify that router and token addresses are selected per chain id,
+//! with built-in mainnet defaults and overrides loaded from JSON.
+
+use alloy_primitives::Address;
+use mcp_server::config::{BlockchainConfig, NetworkAddresses, MAINNET_CHAIN_ID};
+use std::str::FromStr;
+
+const BASE_NETWORK: &str = r#"{
+    "8453": {
+        "uniswap_v2_router": "0x4752ba5DBc23f44D87826276BF6Fd6b1C372aD24",
+        "tokens": { "WETH": "0x4200000000000000000000000000000000000006" }
+    }
+}"#;
+
+#[test]
+fn test_mainnet_addresses_are_built_in() {
+    println!("\n🧪 Testing built-in mainnet address set...");
+
+    let config = BlockchainConfig::default();
+    let mainnet = config.addresses_for_chain(MAINNET_CHAIN_ID).expect("mainnet is always configured");
+    println!("✅ OUTPUT: router={}, {} tokens", mainnet.uniswap_v2_router, mainnet.tokens.len());
+
+    assert_eq!(mainnet.uniswap_v2_router, Address::from_str("0x7a250d5630B4cF539739dF2C5dAcb4c659F2488D").unwrap());
+    assert_eq!(mainnet.token("usdc"), Some(Address::from_str("0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48").unwrap()));
+    assert_eq!(mainnet.token("ETH"), mainnet.token("WETH"), "ETH routes through WETH");
+    assert_eq!(mainnet.token("NOPE"), None);
+    assert!(config.addresses_for_chain(8453).is_none());
+
+    println!("🔚 Test completed\n");
+}
+
+#[test]
+fn test_parse_network_addresses_json() {
+    println!("\n🧪 Testing network address file parsing...");
+
+    println!("📝 INPUT: {}", BASE_NETWORK);
+    let networks = BlockchainConfig::parse_network_addresses(BASE_NETWORK).unwrap();
+    let base = &networks[&8453];
+    println!("✅ OUTPUT: {:?}", base);
+
+    assert_eq!(base.uniswap_v2_router, Address::from_str("0x4752ba5DBc23f44D87826276BF6Fd6b1C372aD24").unwrap());
+    assert_eq!(base.token("weth"), Some(Address::from_str("0x4200000000000000000000000000000000000006").unwrap()));
+    assert_ne!(*base, NetworkAddresses::mainnet());
+
+    let invalid = BlockchainConfig::parse_network_addresses(r#"{"1": {"tokens": {}}}"#);
+    println!("✅ OUTPUT (missing router): {:?}", invalid);
+    assert!(invalid.is_err());
+
+    println!("🔚 Test completed\n");
+}
+
+#[test]
+fn test_example_network_file_is_valid() {
+    println!("\n🧪 Testing networks.example.json...");
+
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("networks.example.json");
+    let networks = BlockchainConfig::load_network_addresses(&path).unwrap();
+    println!("✅ OUTPUT: chains {:?}", networks.keys().collect::<Vec<_>>());
+
+    assert_eq!(networks[&MAINNET_CHAIN_ID].uniswap_v2_router, NetworkAddresses::mainnet().uniswap_v2_router);
+    assert!(networks.contains_key(&8453));
+
+    println!("🔚 Test completed\n");
+}
@@ -0,0 +1,69 @@
+//! Nonce and Pending Transaction Tests for MCP Blockchain Server
+//!
+//! These tests verify the nonce and txpool request structures and that
+//! both tools respond against a running anvil node.
+
+use mcp_server::services::blockchain::{BlockchainService, NonceRequest, PendingTransactionsRequest};
+use rmcp::handler::server::tool::Parameters;
+
+#[test]
+fn test_nonce_request_serialization() {
+    println!("\n🧪 Testing NonceRequest and PendingTransactionsRequest serialization...");
+
+    let request = NonceRequest {
+        address: "alice".to_string(),
+    };
+    let json = serde_json::to_string(&request).unwrap();
+    println!("✅ OUTPUT JSON: {}", json);
+    let deserialized: NonceRequest = serde_json::from_str(&json).unwrap();
+    assert_eq!(request.address, deserialized.address);
+
+    // The pending transactions filter is optional
+    let unfiltered: PendingTransactionsRequest = serde_json::from_str("{}").unwrap();
+    assert!(unfiltered.address.is_none());
+
+    println!("✅ Nonce request serialization test: PASSED");
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_get_nonce_and_pending_transactions() {
+    println!("\n🧪 Testing get_nonce and get_pending_transactions tools...");
+
+    match BlockchainService::new().await {
+        Ok(service) => {
+            println!("📝 INPUT: Nonce for alice");
+            match service.get_nonce(Parameters(NonceRequest { address: "alice".to_string() })).await {
+                Ok(result) => {
+                    println!("✅ OUTPUT: {:?}", result.content);
+                    let text = format!("{:?}", result.content);
+                    assert!(text.contains("Latest Nonce"));
+                    assert!(text.contains("Pending Nonce"));
+                }
+                Err(e) => {
+                    println!("⚠️  Nonce lookup failed: {}", e);
+                    println!("💡 This might be expected if anvil is not running");
+                }
+            }
+
+            println!("📝 INPUT: Pending transactions for alice");
+            let request = PendingTransactionsRequest { address: Some("alice".to_string()), limit: None, offset: None };
+            match service.get_pending_transactions(Parameters(request)).await {
+                Ok(result) => {
+                    println!("✅ OUTPUT: {:?}", result.content);
+                    assert!(format!("{:?}", result.content).contains("Transactions Found"));
+                }
+                Err(e) => {
+                    println!("⚠️  Txpool lookup failed: {}", e);
+                    println!("💡 This might be expected if the node has no txpool API");
+                }
+            }
+        }
+        Err(e) => {
+            println!("⚠️  BlockchainService creation failed: {}", e);
+            println!("💡 This is expected if anvil is not running");
+        }
+    }
+
+    println!("🔚 Test completed\n");
+}
@@ -0,0 +1,96 @@
+//! On-chain Price Tests for MCP Blockchain Server
+//!
+//! These tests verify that `get_onchain_price` finds the configured Chainlink
+//! feed for a symbol, decodes `latestRoundData()` with the feed's decimals, and
+//! rejects unknown symbols and bad answers, using MockProvider so they run
+//! without anvil.
+
+use alloy_primitives::{Address, Bytes, I256, U256};
+use mcp_server::config::NetworkAddresses;
+use mcp_server::services::blockchain::{BlockchainService, OnchainPriceRequest};
+use mcp_server::services::provider::MockProvider;
+use rmcp::handler::server::tool::Parameters;
+use std::str::FromStr;
+
+const ETH_USD_FEED: &str = "0x5f4eC3Df9cbd43714FE2740f5E3616155c5b8419";
+
+fn round_data(answer: I256, updated_at: u64) -> Bytes {
+    let words = [U256::from(42u64), answer.into_raw(), U256::from(updated_at), U256::from(updated_at), U256::from(42u64)];
+    Bytes::from(words.iter().flat_map(|word| word.to_be_bytes::<32>()).collect::<Vec<u8>>())
+}
+
+fn price_request(symbol: &str) -> Parameters<OnchainPriceRequest> {
+    Parameters(OnchainPriceRequest { symbol: symbol.to_string() })
+}
+
+async fn service_with_eth_feed(answer: I256) -> BlockchainService {
+    let feed = Address::from_str(ETH_USD_FEED).unwrap();
+    let mock = MockProvider::new()
+        .with_call_response(feed, "latestRoundData()", round_data(answer, 1_700_000_000))
+        .with_call_response(feed, "decimals()", Bytes::from(U256::from(8u8).to_be_bytes::<32>().to_vec()));
+    BlockchainService::with_provider(mock).await.unwrap()
+}
+
+#[test]
+fn test_price_feed_lookup() {
+    println!("\n🧪 Testing Chainlink feed lookup by symbol...");
+
+    let mainnet = NetworkAddresses::mainnet();
+    let eth_feed = Address::from_str(ETH_USD_FEED).unwrap();
+
+    assert_eq!(mainnet.price_feed("eth"), Some(eth_feed));
+    assert_eq!(mainnet.price_feed("WETH"), Some(eth_feed), "WETH uses the ETH/USD feed");
+    assert_eq!(mainnet.price_feed("WBTC"), mainnet.price_feed("BTC"));
+    assert_eq!(mainnet.price_feed("PEPE"), None);
+
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_get_onchain_price_decodes_latest_round() {
+    println!("\n🧪 Testing get_onchain_price decodes latestRoundData...");
+
+    let service = service_with_eth_feed(I256::try_from(345_678_000_000i64).unwrap()).await;
+
+    println!("📝 INPUT: get_onchain_price(weth)");
+    let result = service.get_onchain_price(price_request("weth")).await.unwrap();
+    let text = format!("{:?}", result.content);
+    println!("✅ OUTPUT: {}", text);
+
+    assert!(text.contains("Token: WETH"));
+    assert!(text.contains("Price: 3456.78000000 USD"));
+    assert!(text.contains("(8 decimals)"));
+    assert!(text.contains("Round: 42"));
+    assert!(text.contains("Updated At: 1700000000"));
+
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_get_onchain_price_rejects_unknown_symbol() {
+    println!("\n🧪 Testing get_onchain_price rejects symbols without a feed...");
+
+    let service = BlockchainService::with_provider(MockProvider::new()).await.unwrap();
+
+    let error = service.get_onchain_price(price_request("PEPE")).await
+        .expect_err("Symbols without a feed should be rejected");
+    println!("✅ OUTPUT: {}", error.message);
+    assert!(error.message.contains("No Chainlink price feed configured for 'PEPE'"));
+    assert!(error.message.contains("ETH, LINK"));
+
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_get_onchain_price_rejects_bad_answers() {
+    println!("\n🧪 Testing get_onchain_price rejects non-positive answers...");
+
+    let service = service_with_eth_feed(I256::try_from(-1i64).unwrap()).await;
+
+    let error = service.get_onchain_price(price_request("ETH")).await
+        .expect_err("A negative price is a broken feed");
+    println!("✅ OUTPUT: {}", error.message);
+    assert!(error.message.contains("non-positive answer"));
+
+    println!("🔚 Test completed\n");
+}
@@ -0,0 +1,97 @@
+//! Pagination Tests for MCP Blockchain Server
+//!
+//! These tests verify limit/offset slicing and the "use offset N for more"
+//! footer shared by get_transaction_history, get_pending_transactions and
+//! web_search, and that out-of-range limits are rejected.
+
+use mcp_server::services::pagination::{page_params, Page, DEFAULT_PAGE_SIZE, MAX_PAGE_SIZE};
+use mcp_server::services::search::{SearchResponse, SearchResult, MAX_SEARCH_RESULTS};
+
+fn search_response(results: usize) -> SearchResponse {
+    SearchResponse {
+        query: "uniswap router".to_string(),
+        results: (1..=results).map(|i| SearchResult {
+            title: format!("Result {}", i),
+            url: format!("https://example.com/{}", i),
+            description: String::new(),
+        }).collect(),
+        total_results: results,
+        offset: 0,
+        pagination: None,
+    }
+}
+
+#[test]
+fn test_page_slices_and_points_to_next_offset() {
+    println!("\n🧪 Testing Page slicing and footer...");
+
+    let all: Vec<u32> = (1..=45).collect();
+
+    println!("📝 INPUT: 45 items, offset 20, limit 20");
+    let page = Page::new(all.clone(), 20, 20);
+    println!("✅ OUTPUT: {}", page.footer());
+    assert_eq!(page.items, (21..=40).collect::<Vec<u32>>());
+    assert_eq!(page.next_offset(), Some(40));
+    assert_eq!(page.footer(), "Showing 21-40 of 45, use offset 40 for more");
+
+    let last = Page::new(all.clone(), 40, 20);
+    assert_eq!(last.items.len(), 5);
+    assert_eq!(last.next_offset(), None);
+    assert_eq!(last.footer(), "Showing 41-45 of 45");
+
+    println!("🔚 Test completed\n");
+}
+
+#[test]
+fn test_page_footer_when_nothing_is_shown() {
+    println!("\n🧪 Testing Page footer for empty and past-the-end pages...");
+
+    assert_eq!(Page::new(Vec::<u32>::new(), 0, 20).footer(), "Showing 0 of 0");
+
+    let past_end = Page::new(vec![1, 2, 3], 10, 20);
+    println!("✅ OUTPUT: {}", past_end.footer());
+    assert!(past_end.items.is_empty());
+    assert_eq!(past_end.footer(), "No results at offset 10 of 3, use a smaller offset");
+
+    println!("🔚 Test completed\n");
+}
+
+#[test]
+fn test_page_params_defaults_and_bounds() {
+    println!("\n🧪 Testing limit/offset parameter validation...");
+
+    assert_eq!(page_params(None, None).unwrap(), (DEFAULT_PAGE_SIZE, 0));
+    assert_eq!(page_params(Some(5), Some(15)).unwrap(), (5, 15));
+
+    let error = page_params(Some(MAX_PAGE_SIZE + 1), None).expect_err("Oversized limits should be rejected");
+    println!("✅ OUTPUT: {}", error.message);
+    assert!(error.message.contains("must be between 1 and 100"));
+    assert!(page_params(Some(0), None).is_err());
+
+    println!("🔚 Test completed\n");
+}
+
+#[test]
+fn test_search_response_page() {
+    println!("\n🧪 Testing web search result paging...");
+
+    // A full batch of 10 means Brave may have more past it
+    println!("📝 INPUT: 10 fetched results, offset 5, count 5");
+    let page = search_response(10).page(5, 5, 10);
+    println!("✅ OUTPUT: {:?}", page.pagination);
+    assert_eq!(page.offset, 5);
+    assert_eq!(page.results.first().unwrap().title, "Result 6");
+    assert_eq!(page.results.len(), 5);
+    assert_eq!(page.pagination.as_deref(), Some("Showing 6-10, use offset 10 for more"));
+
+    // Fewer results than requested means that was everything
+    let short = search_response(7).page(5, 5, 10);
+    assert_eq!(short.pagination.as_deref(), Some("Showing 6-7"));
+
+    // Brave can't go past MAX_SEARCH_RESULTS, so there is no next page to offer
+    let capped = MAX_SEARCH_RESULTS as usize;
+    let last = search_response(capped).page(capped - 5, 5, capped);
+    assert_eq!(last.pagination.as_deref(), Some("Showing 16-20"));
+
+    println!("🔚 Test completed\n");
+}
@@ -0,0 +1,51 @@
+//! Price Impact Tests for MCP Blockchain Server
+//!
+//! These tests verify the price impact check behind swap_tokens: the impact
+//! of a router quote against the pair's spot price, and the warning raised
+//! once it passes MAX_PRICE_IMPACT_BPS.
+
+use alloy_primitives::U256;
+use mcp_server::config::{BlockchainConfig, DEFAULT_MAX_PRICE_IMPACT_BPS};
+use mcp_server::services::blockchain::{calculate_slippage, price_impact, price_impact_warning};
+
+fn ether(amount: u64) -> U256 {
+    U256::from(amount) * U256::from(10u64).pow(U256::from(18))
+}
+
+#[test]
+fn test_price_impact_against_spot_price() {
+    println!("\n🧪 Testing price impact of a quote against the spot price...");
+
+    // 1 in at a 1:2 spot price would give 2; receiving 1.5 is a 25% impact
+    let impact = price_impact(ether(1), ether(3) / U256::from(2), ether(5), ether(10));
+    println!("✅ OUTPUT: {:.4}%", impact);
+    assert!((impact - 25.0).abs() < 1e-9);
+
+    // Matches the impact calculate_slippage reports for its own quote
+    let (expected_out, _, quoted_impact) = calculate_slippage(ether(10), ether(1000), ether(1000), 50).unwrap();
+    assert_eq!(price_impact(ether(10), expected_out, ether(1000), ether(1000)), quoted_impact);
+
+    assert_eq!(price_impact(U256::ZERO, U256::ZERO, ether(5), ether(10)), 0.0, "Nothing swapped, no impact");
+
+    println!("🔚 Test completed\n");
+}
+
+#[test]
+fn test_price_impact_warning_threshold() {
+    println!("\n🧪 Testing the price impact warning threshold...");
+
+    assert_eq!(BlockchainConfig::default().max_price_impact_bps, DEFAULT_MAX_PRICE_IMPACT_BPS);
+    assert_eq!(DEFAULT_MAX_PRICE_IMPACT_BPS, 500);
+
+    assert!(price_impact_warning(1.28, 500).is_none());
+    assert!(price_impact_warning(5.0, 500).is_none(), "Exactly at the limit is allowed");
+
+    let warning = price_impact_warning(16.875, 500).expect("16.9% is above a 5% limit");
+    println!("✅ OUTPUT: {}", warning);
+    assert!(warning.contains("HIGH PRICE IMPACT"));
+    assert!(warning.contains("moves the price 16.88%, above the 5.00% limit"));
+
+    assert!(price_impact_warning(1.28, 100).is_some(), "The limit is configurable");
+
+    println!("🔚 Test completed\n");
+}
@@ -14,6 +14,7 @@ fn test_request_structures() {
     println!("\n📋 Test 1: BalanceRequest Serialization");
     let balance_req = BalanceRequest {
         who: "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266".to_string(),
+        block: None,
     };
     println!("📝 INPUT STRUCT: BalanceRequest {{ who: \"{}\" }}", balance_req.who);
     let json = serde_json::to_string(&balance_req).unwrap();
@@ -27,6 +28,9 @@ fn test_request_structures() {
     let transfer_req = TransferRequest {
         to: "0x70997970C51812dc3A010C7d01b50e0d17dc79C8".to_string(),
         amount: "1.0".to_string(),
+        from: Some("alice".to_string()),
+        dry_run: None,
+        confirmation_timeout: None,
     };
     println!("📝 INPUT STRUCT: TransferRequest {{ to: \"{}\", amount: \"{}\" }}", transfer_req.to, transfer_req.amount);
     let json = serde_json::to_string(&transfer_req).unwrap();
@@ -41,6 +45,7 @@ fn test_request_structures() {
     println!("\n📋 Test 3: ContractDeploymentRequest Serialization");
     let contract_req = ContractDeploymentRequest {
         address: "0x7a250d5630B4cF539739dF2C5dAcb4c659F2488D".to_string(),
+        expected_code_hash: None,
     };
     println!("📝 INPUT STRUCT: ContractDeploymentRequest {{ address: \"{}\" }}", contract_req.address);
     let json = serde_json::to_string(&contract_req).unwrap();
@@ -54,6 +59,7 @@ fn test_request_structures() {
     let token_balance_req = TokenBalanceRequest {
         token_address: "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48".to_string(),
         account_address: "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266".to_string(),
+        block: None,
     };
     println!("📝 INPUT STRUCT: TokenBalanceRequest {{ token_address: \"{}\", account_address: \"{}\" }}", 
              token_balance_req.token_address, token_balance_req.account_address);
@@ -0,0 +1,125 @@
+//! Revert Reason Decoding Tests for MCP Blockchain Server
+//!
+//! These tests verify that revert data from failed transactions is decoded
+//! into readable reasons for the Error(string) and Panic(uint256) shapes.
+
+use alloy_primitives::U256;
+use alloy_rpc_types::BlockId;
+use mcp_server::services::blockchain::{
+    decode_revert_reason, describe_deadline, is_deadline_expired, replay_block, revert_reason_from_trace, ROUTER_EXPIRED_REASON,
+};
+use serde_json::json;
+
+fn word(value: u64) -> Vec<u8> {
+    U256::from(value).to_be_bytes::<32>().to_vec()
+}
+
+/// Build `Error(string)` revert data as emitted by `require(cond, reason)`
+fn error_string(reason: &str) -> Vec<u8> {
+    let mut data = vec![0x08, 0xc3, 0x79, 0xa0];
+    data.extend(word(32));
+    data.extend(word(reason.len() as u64));
+    let mut padded = reason.as_bytes().to_vec();
+    padded.resize(reason.len().div_ceil(32) * 32, 0);
+    data.extend(padded);
+    data
+}
+
+#[test]
+fn test_decode_error_string_revert() {
+    println!("\n🧪 Testing Error(string) revert decoding...");
+
+    let data = error_string("UniswapV2: INSUFFICIENT_OUTPUT_AMOUNT");
+    println!("📝 INPUT: 0x{}", hex::encode(&data));
+
+    let reason = decode_revert_reason(&data);
+    println!("✅ OUTPUT: {}", reason);
+    assert_eq!(reason, "execution reverted: UniswapV2: INSUFFICIENT_OUTPUT_AMOUNT");
+
+    println!("🔚 Test completed\n");
+}
+
+#[test]
+fn test_decode_panic_revert() {
+    println!("\n🧪 Testing Panic(uint256) revert decoding...");
+
+    let test_cases = vec![
+        (0x11, "execution reverted: panic 0x11 (arithmetic overflow or underflow)"),
+        (0x12, "execution reverted: panic 0x12 (division or modulo by zero)"),
+        (0x99, "execution reverted: panic 0x99 (unknown panic code)"),
+    ];
+
+    for (code, expected) in test_cases {
+        let mut data = vec![0x4e, 0x48, 0x7b, 0x71];
+        data.extend(word(code));
+        let reason = decode_revert_reason(&data);
+        println!("📝 INPUT: panic code 0x{:02x} ✅ OUTPUT: {}", code, reason);
+        assert_eq!(reason, expected);
+    }
+
+    println!("🔚 Test completed\n");
+}
+
+#[test]
+fn test_decode_empty_and_custom_reverts() {
+    println!("\n🧪 Testing empty and custom error revert decoding...");
+
+    let empty = decode_revert_reason(&[]);
+    println!("✅ OUTPUT (empty): {}", empty);
+    assert_eq!(empty, "execution reverted (no reason given)");
+
+    let custom = decode_revert_reason(&[0xde, 0xad, 0xbe, 0xef, 0x01]);
+    println!("✅ OUTPUT (custom): {}", custom);
+    assert_eq!(custom, "execution reverted: custom error 0xdeadbeef (data: 0x01)");
+
+    println!("🔚 Test completed\n");
+}
+
+#[test]
+fn test_expired_deadline_revert() {
+    println!("\n🧪 Testing the router's expired-deadline revert is recognised...");
+
+    let reason = decode_revert_reason(&error_string(ROUTER_EXPIRED_REASON));
+    println!("✅ OUTPUT: {}", reason);
+    assert_eq!(reason, "execution reverted: UniswapV2Router: EXPIRED");
+    assert!(is_deadline_expired(&reason));
+    assert!(!is_deadline_expired(&decode_revert_reason(&error_string("UniswapV2Router: INSUFFICIENT_OUTPUT_AMOUNT"))));
+
+    assert_eq!(describe_deadline(1_700_000_300, 1_700_000_000), "1700000300 (300s remaining)");
+    assert_eq!(describe_deadline(1_700_000_300, 1_700_000_300), "1700000300 (0s remaining)", "The deadline second itself is still accepted");
+    assert_eq!(describe_deadline(1_700_000_300, 1_700_000_345), "1700000300 (passed 45s ago)");
+
+    println!("🔚 Test completed\n");
+}
+
+#[test]
+fn test_revert_reason_from_trace() {
+    println!("\n🧪 Testing revert reasons are read from debug_traceTransaction...");
+
+    let trace = json!({
+        "type": "CALL",
+        "error": "execution reverted",
+        "output": format!("0x{}", hex::encode(error_string(ROUTER_EXPIRED_REASON))),
+    });
+    let reason = revert_reason_from_trace(&trace);
+    println!("✅ OUTPUT: {:?}", reason);
+    assert_eq!(reason.as_deref(), Some("execution reverted: UniswapV2Router: EXPIRED"));
+
+    let trace = json!({ "error": "execution reverted", "output": "0x", "revertReason": "STF" });
+    assert_eq!(revert_reason_from_trace(&trace).as_deref(), Some("execution reverted: STF"));
+    assert_eq!(revert_reason_from_trace(&json!({ "error": "out of gas" })).as_deref(), Some("out of gas"));
+    assert_eq!(revert_reason_from_trace(&json!({ "type": "CALL", "output": "0x" })), None, "Successful traces give no reason");
+
+    println!("🔚 Test completed\n");
+}
+
+#[test]
+fn test_failed_transactions_replay_on_the_parent_block() {
+    println!("\n🧪 Testing replays run on the state before the transaction...");
+
+    assert_eq!(replay_block(Some(19_000_000)), BlockId::number(18_999_999));
+    assert_eq!(replay_block(Some(0)), BlockId::number(0));
+    assert_eq!(replay_block(None), BlockId::latest());
+
+    println!("🔚 Test completed\n");
+}
@@ -0,0 +1,126 @@
+//! RPC Retry Tests for MCP Blockchain Server
+//!
+//! These tests verify that transient RPC failures are retried with backoff,
+//! while logical errors such as reverts fail immediately.
+
+use alloy_primitives::{Address, U256};
+use alloy_transport::TransportErrorKind;
+use mcp_server::config::BlockchainConfig;
+use mcp_server::services::blockchain::{BalanceRequest, BlockchainService};
+use mcp_server::services::provider::{is_transient_rpc_error, retry_rpc, MockProvider};
+use rmcp::handler::server::tool::Parameters;
+use std::str::FromStr;
+use std::time::Duration;
+
+const ALICE: &str = "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266";
+
+#[test]
+fn test_transient_error_classification() {
+    println!("\n🧪 Testing transient RPC error classification...");
+
+    let test_cases = vec![
+        ("error sending request: connection refused", true),
+        ("operation timed out", true),
+        ("connection reset by peer", true),
+        ("execution reverted", false),
+        ("invalid opcode", false),
+    ];
+
+    for (message, expected) in test_cases {
+        let error = TransportErrorKind::custom_str(message);
+        let transient = is_transient_rpc_error(&error);
+        println!("📝 INPUT: \"{}\" ✅ OUTPUT: transient={}", message, transient);
+        assert_eq!(transient, expected, "Wrong classification for '{}'", message);
+    }
+
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_retry_rpc_recovers_from_transient_failures() {
+    println!("\n🧪 Testing retry_rpc succeeds after transient failures...");
+
+    let mut calls = 0;
+    let result = retry_rpc(3, Duration::from_millis(1), || {
+        calls += 1;
+        let call = calls;
+        async move {
+            if call < 3 {
+                Err(TransportErrorKind::custom_str("connection refused"))
+            } else {
+                Ok(call)
+            }
+        }
+    }).await;
+
+    println!("✅ OUTPUT: {:?} after {} call(s)", result.as_ref().ok(), calls);
+    assert_eq!(result.unwrap(), 3);
+    assert_eq!(calls, 3);
+
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_retry_rpc_gives_up_after_configured_attempts() {
+    println!("\n🧪 Testing retry_rpc stops after the configured attempts...");
+
+    let mut calls = 0;
+    let result: Result<(), _> = retry_rpc(2, Duration::from_millis(1), || {
+        calls += 1;
+        async { Err(TransportErrorKind::custom_str("operation timed out")) }
+    }).await;
+
+    println!("✅ OUTPUT: error={} after {} call(s)", result.is_err(), calls);
+    assert!(result.is_err());
+    assert_eq!(calls, 2);
+
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_retry_rpc_does_not_retry_reverts() {
+    println!("\n🧪 Testing retry_rpc fails fast on reverts...");
+
+    let mut calls = 0;
+    let result: Result<(), _> = retry_rpc(5, Duration::from_millis(1), || {
+        calls += 1;
+        async { Err(TransportErrorKind::custom_str("execution reverted")) }
+    }).await;
+
+    println!("✅ OUTPUT: error={} after {} call(s)", result.is_err(), calls);
+    assert!(result.is_err());
+    assert_eq!(calls, 1, "Reverts should not be retried");
+
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_balance_retries_transient_provider_failures() {
+    println!("\n🧪 Testing balance retries through a flaky provider...");
+
+    let alice = Address::from_str(ALICE).unwrap();
+    let mock = MockProvider::new()
+        .with_balance(alice, U256::from(1_000_000_000_000_000_000u128))
+        .with_transient_failures(2, "connection reset by peer");
+    let service = BlockchainService::with_provider(mock).await.unwrap();
+
+    println!("📝 INPUT: balance of {} with 2 transient failures", ALICE);
+    let result = service.balance(Parameters(BalanceRequest { who: ALICE.to_string(), block: None })).await.unwrap();
+    let text = format!("{:?}", result.content);
+    println!("✅ OUTPUT: {}", text);
+    assert!(text.contains("1.000000 ETH"));
+
+    println!("🔚 Test completed\n");
+}
+
+#[test]
+fn test_retry_config_defaults() {
+    println!("\n🧪 Testing RPC retry configuration defaults...");
+
+    let config = BlockchainConfig::default();
+    println!("✅ OUTPUT: attempts={}, base_delay={}ms", config.rpc_retry_attempts, config.rpc_retry_base_delay_ms);
+    assert_eq!(config.rpc_retry_attempts, 3);
+    assert_eq!(config.rpc_retry_base_delay_ms, 200);
+
+    println!("🔚 Test completed\n");
+}
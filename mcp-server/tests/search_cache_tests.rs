@@ -0,0 +1,91 @@
+//! Search Cache Tests for MCP Blockchain Server
+//!
+//! These tests verify the Brave Search response cache: hits within the TTL,
+//! expiry after it, and least-recently-used eviction at the size cap.
+
+use mcp_server::services::search::{SearchCache, SearchResponse, SearchResult};
+use std::time::Duration;
+
+fn response(query: &str) -> SearchResponse {
+    SearchResponse {
+        query: query.to_string(),
+        results: vec![SearchResult {
+            title: format!("{} result", query),
+            url: "https://example.com".to_string(),
+            description: format!("About {}", query),
+        }],
+        total_results: 1,
+        offset: 0,
+        pagination: None,
+    }
+}
+
+#[test]
+fn test_cache_hit_within_ttl() {
+    println!("\n🧪 Testing search cache hit within TTL...");
+
+    let mut cache = SearchCache::new(Duration::from_secs(60), 10);
+    let key = SearchCache::key("USDC price", "us", "en");
+    cache.insert(key.clone(), response("USDC price"));
+
+    let hit = cache.get(&key);
+    println!("✅ OUTPUT: {:?}", hit.as_ref().map(|r| &r.query));
+    assert_eq!(hit.unwrap().query, "USDC price");
+
+    // Keys ignore case and surrounding whitespace of the query but not the locale
+    assert!(cache.get(&SearchCache::key("  usdc PRICE ", "us", "en")).is_some());
+    assert!(cache.get(&SearchCache::key("USDC price", "de", "de")).is_none());
+
+    println!("🔚 Test completed\n");
+}
+
+#[test]
+fn test_cache_entries_expire_after_ttl() {
+    println!("\n🧪 Testing search cache expiry...");
+
+    let mut cache = SearchCache::new(Duration::from_millis(20), 10);
+    let key = SearchCache::key("WETH contract", "us", "en");
+    cache.insert(key.clone(), response("WETH contract"));
+    std::thread::sleep(Duration::from_millis(40));
+
+    let result = cache.get(&key);
+    println!("✅ OUTPUT: hit after TTL = {}", result.is_some());
+    assert!(result.is_none());
+    assert!(cache.is_empty(), "Expired entries are dropped on lookup");
+
+    println!("🔚 Test completed\n");
+}
+
+#[test]
+fn test_cache_evicts_least_recently_used() {
+    println!("\n🧪 Testing search cache LRU eviction...");
+
+    let mut cache = SearchCache::new(Duration::from_secs(60), 2);
+    let (a, b, c) = ("a".to_string(), "b".to_string(), "c".to_string());
+    cache.insert(a.clone(), response("a"));
+    cache.insert(b.clone(), response("b"));
+
+    // Using "a" makes "b" the least recently used entry
+    assert!(cache.get(&a).is_some());
+    cache.insert(c.clone(), response("c"));
+
+    println!("✅ OUTPUT: len={}, a={}, b={}, c={}",
+        cache.len(), cache.get(&a).is_some(), cache.get(&b).is_some(), cache.get(&c).is_some());
+    assert_eq!(cache.len(), 2);
+    assert!(cache.get(&b).is_none());
+    assert!(cache.get(&a).is_some());
+    assert!(cache.get(&c).is_some());
+
+    println!("🔚 Test completed\n");
+}
+
+#[test]
+fn test_zero_ttl_disables_cache() {
+    println!("\n🧪 Testing zero TTL disables the search cache...");
+
+    let mut cache = SearchCache::new(Duration::ZERO, 10);
+    cache.insert("q".to_string(), response("q"));
+    assert!(cache.is_empty());
+
+    println!("🔚 Test completed\n");
+}
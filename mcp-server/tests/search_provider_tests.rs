@@ -0,0 +1,129 @@
+//! Search Provider Tests for MCP Blockchain Server
+//!
+//! These tests verify the keyless web search fallbacks: parsing SearXNG and
+//! DuckDuckGo responses into search results, and that SearchService sends
+//! web_search through whichever provider it was given. Canned JSON and a
+//! fake provider stand in for the real backends, so no network is needed.
+
+use async_trait::async_trait;
+use mcp_server::services::search::{SearchResponse, SearchResult, SearchService, WebSearchRequest};
+use mcp_server::services::search_provider::{parse_duckduckgo_results, parse_searxng_results, SearchProvider};
+use rmcp::handler::server::tool::Parameters;
+use rmcp::ErrorData as McpError;
+use serde_json::json;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+
+/// Returns `count` numbered results and records how often it was asked
+struct FakeProvider {
+    calls: AtomicU32,
+}
+
+#[async_trait]
+impl SearchProvider for FakeProvider {
+    fn name(&self) -> &'static str {
+        "Fake"
+    }
+
+    async fn search(&self, query: &str, count: u32) -> Result<Vec<SearchResult>, McpError> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        Ok((1..=count).map(|n| SearchResult {
+            title: format!("{} #{}", query, n),
+            url: format!("https://example.com/{}", n),
+            description: String::new(),
+        }).collect())
+    }
+}
+
+#[test]
+fn test_parse_searxng_results() {
+    println!("\n🧪 Testing SearXNG response parsing...");
+
+    let body = json!({
+        "query": "uniswap v2 router",
+        "results": [
+            { "url": "https://docs.uniswap.org/contracts/v2/reference/smart-contracts/router-02", "title": "Router02", "content": "The router contract" },
+            { "url": "https://github.com/Uniswap/v2-periphery" },
+            { "title": "No URL, skipped" },
+            { "url": "https://etherscan.io", "title": "Etherscan", "content": "Explorer" },
+        ]
+    });
+
+    let results = parse_searxng_results(&body, 2);
+    println!("✅ OUTPUT: {:?}", results);
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0].title, "Router02");
+    assert_eq!(results[0].description, "The router contract");
+    assert_eq!(results[1].title, "https://github.com/Uniswap/v2-periphery", "A missing title falls back to the URL");
+    assert!(parse_searxng_results(&json!({}), 5).is_empty());
+
+    println!("🔚 Test completed\n");
+}
+
+#[test]
+fn test_parse_duckduckgo_results() {
+    println!("\n🧪 Testing DuckDuckGo instant answer parsing...");
+
+    let body = json!({
+        "Heading": "Uniswap",
+        "AbstractText": "Uniswap is a decentralized exchange protocol.",
+        "AbstractURL": "https://en.wikipedia.org/wiki/Uniswap",
+        "RelatedTopics": [
+            { "FirstURL": "https://duckduckgo.com/Ethereum", "Text": "Ethereum - A decentralized blockchain" },
+            { "Name": "Exchanges", "Topics": [
+                { "FirstURL": "https://duckduckgo.com/SushiSwap", "Text": "SushiSwap - A Uniswap fork" },
+            ] },
+        ]
+    });
+
+    let results = parse_duckduckgo_results(&body, 5);
+    println!("✅ OUTPUT: {:?}", results);
+    let titles: Vec<&str> = results.iter().map(|r| r.title.as_str()).collect();
+    assert_eq!(titles, vec!["Uniswap", "Ethereum", "SushiSwap"], "Abstract first, then topics with groups flattened");
+    assert_eq!(results[1].description, "Ethereum - A decentralized blockchain");
+
+    assert_eq!(parse_duckduckgo_results(&body, 1).len(), 1);
+    assert!(parse_duckduckgo_results(&json!({ "AbstractText": "", "RelatedTopics": [] }), 5).is_empty());
+
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_web_search_uses_the_configured_provider() {
+    println!("\n🧪 Testing web_search goes through the configured provider...");
+
+    let provider = Arc::new(FakeProvider { calls: AtomicU32::new(0) });
+    let service = SearchService::with_provider(provider.clone());
+    assert_eq!(service.provider_name(), "Fake");
+
+    let request = || WebSearchRequest {
+        query: "weth contract".to_string(),
+        count: Some(2),
+        country: None,
+        search_lang: None,
+        offset: Some(1),
+    };
+
+    println!("📝 INPUT: web_search count=2 offset=1 (twice)");
+    let result = service.web_search(Parameters(request())).await.unwrap();
+    let text = &result.content[0].as_text().expect("web_search returns text content").text;
+    let response: SearchResponse = serde_json::from_str(text).unwrap();
+    println!("✅ OUTPUT: {:?}", response.results);
+
+    let titles: Vec<&str> = response.results.iter().map(|r| r.title.as_str()).collect();
+    assert_eq!(titles, vec!["weth contract #2", "weth contract #3"]);
+
+    service.web_search(Parameters(request())).await.unwrap();
+    assert_eq!(provider.calls.load(Ordering::SeqCst), 1, "The repeat is served from the cache");
+
+    // A later page of the same search is sliced out of the cached result set
+    let next_page = WebSearchRequest { offset: Some(3), ..request() };
+    let result = service.web_search(Parameters(next_page)).await.unwrap();
+    let text = &result.content[0].as_text().expect("web_search returns text content").text;
+    let response: SearchResponse = serde_json::from_str(text).unwrap();
+    let titles: Vec<&str> = response.results.iter().map(|r| r.title.as_str()).collect();
+    assert_eq!(titles, vec!["weth contract #4", "weth contract #5"]);
+    assert_eq!(provider.calls.load(Ordering::SeqCst), 1, "Paging doesn't search again");
+
+    println!("🔚 Test completed\n");
+}
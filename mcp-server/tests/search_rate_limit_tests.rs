@@ -0,0 +1,44 @@
+//! Search Rate Limit Tests for MCP Blockchain Server
+//!
+//! These tests verify the helpers used to report Brave Search rate limits
+//! and non-2xx responses instead of failing with a JSON parse error.
+
+use mcp_server::services::search::{body_snippet, parse_retry_after};
+use std::time::Duration;
+
+#[test]
+fn test_parse_retry_after_seconds() {
+    println!("\n🧪 Testing Retry-After header parsing...");
+
+    let test_cases = vec![
+        ("1", Some(Duration::from_secs(1))),
+        (" 30 ", Some(Duration::from_secs(30))),
+        ("0", Some(Duration::ZERO)),
+        ("Wed, 21 Oct 2015 07:28:00 GMT", None),
+        ("soon", None),
+    ];
+
+    for (input, expected) in test_cases {
+        let parsed = parse_retry_after(input);
+        println!("📝 INPUT: \"{}\" ✅ OUTPUT: {:?}", input, parsed);
+        assert_eq!(parsed, expected, "Wrong Retry-After for '{}'", input);
+    }
+
+    println!("🔚 Test completed\n");
+}
+
+#[test]
+fn test_body_snippet_truncates_long_bodies() {
+    println!("\n🧪 Testing error body snippets...");
+
+    let short = body_snippet("  {\"error\":\"bad request\"}\n");
+    println!("✅ OUTPUT (short): {}", short);
+    assert_eq!(short, "{\"error\":\"bad request\"}");
+
+    let long = body_snippet(&"x".repeat(500));
+    println!("✅ OUTPUT (long): {} chars", long.chars().count());
+    assert_eq!(long.chars().count(), 203);
+    assert!(long.ends_with("..."));
+
+    println!("🔚 Test completed\n");
+}
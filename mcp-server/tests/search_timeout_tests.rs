@@ -0,0 +1,61 @@
+//! Search Timeout Tests for MCP Blockchain Server
+//!
+//! These tests verify the Brave Search client timeouts, and that a hung
+//! endpoint fails the tool call with a distinct timeout error instead of
+//! blocking the agent. A local listener that never answers stands in for the
+//! hung endpoint, so no network access is needed.
+
+use mcp_server::services::search::{
+    SearchClientConfig, SearchService, WebSearchRequest, DEFAULT_SEARCH_CONNECT_TIMEOUT_SECS, DEFAULT_SEARCH_TIMEOUT_SECS,
+};
+use rmcp::handler::server::tool::Parameters;
+use std::time::{Duration, Instant};
+
+#[test]
+fn test_default_search_timeouts() {
+    println!("\n🧪 Testing default search client timeouts...");
+
+    let config = SearchClientConfig::default();
+    println!("✅ OUTPUT: {:?}", config);
+    assert_eq!(config.timeout, Duration::from_secs(DEFAULT_SEARCH_TIMEOUT_SECS));
+    assert_eq!(config.connect_timeout, Duration::from_secs(DEFAULT_SEARCH_CONNECT_TIMEOUT_SECS));
+    assert!(config.build_client().is_ok());
+
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_hung_endpoint_times_out() {
+    println!("\n🧪 Testing a hung search endpoint returns a timeout error...");
+
+    // Accept connections but never send a response
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base_url = format!("http://{}/res/v1/web/search", listener.local_addr().unwrap());
+    tokio::spawn(async move {
+        let mut held = Vec::new();
+        while let Ok((socket, _)) = listener.accept().await {
+            held.push(socket);
+        }
+    });
+
+    let config = SearchClientConfig { timeout: Duration::from_secs(1), connect_timeout: Duration::from_secs(1) };
+    let service = SearchService::with_endpoint("test-key".to_string(), base_url, config).unwrap();
+    let request = WebSearchRequest {
+        query: "uniswap v2 router".to_string(),
+        count: Some(5),
+        country: None,
+        search_lang: None,
+        offset: None,
+    };
+
+    println!("📝 INPUT: web_search against an endpoint that never answers");
+    let started = Instant::now();
+    let error = service.web_search(Parameters(request)).await.expect_err("A hung endpoint should time out");
+    println!("✅ OUTPUT: {} (after {:?})", error.message, started.elapsed());
+
+    assert!(error.message.contains("Brave Search timed out after 1s"));
+    assert_eq!(error.data, Some(serde_json::json!({ "kind": "timeout" })));
+    assert!(started.elapsed() < Duration::from_secs(5), "The timeout bounds the tool call");
+
+    println!("🔚 Test completed\n");
+}
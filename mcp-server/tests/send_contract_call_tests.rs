@@ -0,0 +1,145 @@
+//! Generic Contract Transaction Tests for MCP Blockchain Server
+//!
+//! These tests verify that send_contract_call encodes calldata from the
+//! signature and args, attaches the requested value and gas limit, refuses
+//! calls that would revert or that Alice can't pay for, and leaves dry runs
+//! unsent. MockProvider records
+//! the transaction instead of broadcasting it.
+
+use alloy_primitives::{Address, U256};
+use mcp_server::config::BlockchainConfig;
+use mcp_server::services::blockchain::{BlockchainService, ContractSendRequest};
+use mcp_server::services::provider::MockProvider;
+use rmcp::handler::server::tool::Parameters;
+use std::str::FromStr;
+
+const TOKEN: &str = "0x5FbDB2315678afecb367f032d93F642f64180aa3";
+const ALICE_KEY: &str = "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+const ALICE: &str = "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266";
+
+fn tenth_of_an_ether() -> U256 {
+    U256::from(10).pow(U256::from(17))
+}
+
+fn request(signature: &str, args: &[&str], value: Option<&str>, dry_run: bool) -> Parameters<ContractSendRequest> {
+    Parameters(ContractSendRequest {
+        address: TOKEN.to_string(),
+        signature: signature.to_string(),
+        args: Some(args.iter().map(|arg| arg.to_string()).collect()),
+        value: value.map(str::to_string),
+        gas_limit: Some(100_000),
+        dry_run: Some(dry_run),
+        confirmation_timeout: Some(1),
+    })
+}
+
+async fn service_with_key(mock: MockProvider) -> BlockchainService {
+    let config = BlockchainConfig {
+        // Unreachable, for the calls that go straight to the node; the mock has no receipt, so confirmation times out
+        rpc_url: "http://127.0.0.1:1".to_string(),
+        alice_private_key: ALICE_KEY.to_string(),
+        ..BlockchainConfig::default()
+    };
+    BlockchainService::with_provider_and_config(mock, config).await.unwrap()
+}
+
+#[tokio::test]
+async fn test_send_contract_call_sends_encoded_calldata() {
+    println!("\n🧪 Testing send_contract_call builds and sends the transaction...");
+
+    let token = Address::from_str(TOKEN).unwrap();
+    let mock = MockProvider::new()
+        .with_call_response(token, "mint(uint256)", Vec::new())
+        .with_balance(Address::from_str(ALICE).unwrap(), U256::from(10).pow(U256::from(18)));
+    let service = service_with_key(mock.clone()).await;
+
+    println!("📝 INPUT: mint(uint256) 1000 with 0.1 ETH on {}", TOKEN);
+    let result = service.send_contract_call(request("mint(uint256)", &["1000"], Some("0.1"), false)).await.unwrap();
+    let text = format!("{:?}", result.content);
+    println!("✅ OUTPUT: {}", text);
+    assert!(text.contains("Contract Call Sent"));
+    assert!(text.contains("Function: mint(uint256)"));
+    assert!(text.contains("Gas Limit: 100000 (requested)"));
+    assert!(text.contains("check_transaction_status"));
+
+    let sent = mock.sent_transactions();
+    assert_eq!(sent.len(), 1);
+    assert_eq!(sent[0].to, Some(token.into()));
+    assert_eq!(sent[0].value, Some(tenth_of_an_ether()));
+    assert_eq!(sent[0].gas, Some(100_000));
+    let input = sent[0].input.input().unwrap();
+    assert_eq!(input.len(), 4 + 32, "Selector plus one uint256 word");
+    assert_eq!(U256::from_be_slice(&input[4..]), U256::from(1000));
+
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_send_contract_call_checks_balance_with_requested_gas_limit() {
+    println!("\n🧪 Testing send_contract_call checks Alice can pay for a requested gas limit...");
+
+    // 0.1 ETH covers the value but not 100000 gas at 1 gwei on top of it
+    let token = Address::from_str(TOKEN).unwrap();
+    let mock = MockProvider::new()
+        .with_call_response(token, "mint(uint256)", Vec::new())
+        .with_balance(Address::from_str(ALICE).unwrap(), tenth_of_an_ether());
+    let service = service_with_key(mock.clone()).await;
+
+    println!("📝 INPUT: mint(uint256) 1000 with 0.1 ETH and gas_limit 100000, balance 0.1 ETH");
+    let error = service.send_contract_call(request("mint(uint256)", &["1000"], Some("0.1"), false)).await
+        .expect_err("Value plus gas is more than the balance");
+    println!("✅ OUTPUT: {}", error.message);
+    assert!(error.message.contains("Insufficient balance"));
+    assert!(error.message.contains("0.100000 ETH value + 0.000100 ETH estimated gas"));
+    assert!(mock.sent_transactions().is_empty());
+
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_send_contract_call_refuses_reverting_calls() {
+    println!("\n🧪 Testing send_contract_call does not send a call that would revert...");
+
+    // The mock reverts calls it has no response for
+    let mock = MockProvider::new();
+    let service = service_with_key(mock.clone()).await;
+
+    let error = service.send_contract_call(request("mint()", &[], None, false)).await.expect_err("The call reverts");
+    println!("✅ OUTPUT: {}", error.message);
+    assert!(error.message.contains("mint() on"));
+    assert!(error.message.contains("would revert, so it was not sent"));
+    assert!(mock.sent_transactions().is_empty());
+
+    // A dry run reports the revert instead of failing
+    let result = service.send_contract_call(request("mint()", &[], None, true)).await.unwrap();
+    let text = format!("{:?}", result.content);
+    assert!(text.contains("Contract Call Simulation (dry run)"));
+    assert!(text.contains("WOULD REVERT"));
+    assert!(mock.sent_transactions().is_empty());
+
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_send_contract_call_validates_input() {
+    println!("\n🧪 Testing send_contract_call checks its input before sending...");
+
+    let mock = MockProvider::new();
+    let service = BlockchainService::with_provider(mock.clone()).await.unwrap();
+
+    let error = service.send_contract_call(request("mint", &[], None, false)).await.expect_err("Bad signature");
+    assert!(error.message.contains("Invalid function signature"));
+
+    let error = service.send_contract_call(request("mint(uint256)", &["lots"], None, false)).await.expect_err("Bad argument");
+    assert!(error.message.contains("Failed to encode mint(uint256)"));
+
+    let error = service.send_contract_call(request("mint()", &[], Some("-1"), false)).await.expect_err("Bad value");
+    assert!(error.message.contains("Invalid ETH amount"));
+
+    let error = service.send_contract_call(request("mint()", &[], None, false)).await.expect_err("Sending needs a key");
+    println!("✅ OUTPUT: {}", error.message);
+    assert!(error.message.contains("private key not available"));
+    assert!(mock.sent_transactions().is_empty());
+
+    println!("🔚 Test completed\n");
+}
@@ -3,7 +3,8 @@
 //! These tests verify that the BlockchainService can be instantiated correctly
 //! and handles connection errors gracefully.
 
-use mcp_server::services::blockchain::BlockchainService;
+use mcp_server::config::{BlockchainConfig, FeeMode, LogFormat, DEFAULT_DEADLINE_SECS, DEFAULT_SLIPPAGE_BPS};
+use mcp_server::services::blockchain::{BlockchainService, TransferRequest};
 
 #[tokio::test]
 async fn test_blockchain_service_creation() {
@@ -34,3 +35,119 @@ async fn test_blockchain_service_creation() {
     }
     println!("🔚 Test completed\n");
 }
+
+#[test]
+fn test_config_rpc_url_override_and_default_accounts() {
+    println!("\n🧪 Testing BlockchainConfig RPC URL override and account fallback...");
+
+    let config = BlockchainConfig::from_env().unwrap().with_rpc_url("http://127.0.0.1:9545");
+    println!("📝 INPUT: --rpc-url http://127.0.0.1:9545");
+    println!("✅ OUTPUT: rpc_url = {}", config.rpc_url);
+    assert_eq!(config.rpc_url, "http://127.0.0.1:9545");
+
+    let defaults = BlockchainConfig::default_accounts();
+    println!("✅ OUTPUT: {} fallback accounts, Alice = {:?}", defaults.len(), defaults[0]);
+    assert_eq!(defaults.len(), 10);
+    assert_eq!(format!("{:?}", defaults[0]).to_lowercase(), "0xf39fd6e51aad88f6f4ce6ab8827279cfffb92266");
+
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_blockchain_service_with_unreachable_rpc_url() {
+    println!("\n🧪 Testing BlockchainService creation with an overridden RPC URL...");
+
+    // Nothing listens on this port, so the node can't report accounts; creation
+    // must either fall back to the default account list or fail cleanly.
+    let config = BlockchainConfig::from_env().unwrap().with_rpc_url("http://127.0.0.1:1");
+    match BlockchainService::with_config(config).await {
+        Ok(_service) => println!("✅ OUTPUT: Service created using fallback accounts"),
+        Err(e) => println!("✅ OUTPUT: Service creation failed cleanly: {}", e),
+    }
+
+    println!("🔚 Test completed\n");
+}
+
+#[test]
+fn test_fee_mode_parsing() {
+    println!("\n🧪 Testing FeeMode parsing...");
+
+    let test_cases = vec![
+        ("legacy", FeeMode::Legacy),
+        ("eip1559", FeeMode::Eip1559),
+        ("EIP-1559", FeeMode::Eip1559),
+        (" 1559 ", FeeMode::Eip1559),
+    ];
+
+    for (input, expected) in test_cases {
+        println!("📝 INPUT: \"{}\"", input);
+        let mode = input.parse::<FeeMode>().unwrap();
+        println!("✅ OUTPUT: {:?}", mode);
+        assert_eq!(mode, expected);
+    }
+
+    assert!("fast".parse::<FeeMode>().is_err());
+    assert_eq!(FeeMode::default(), FeeMode::Legacy);
+
+    println!("✅ FeeMode parsing: PASSED");
+    println!("🔚 Test completed\n");
+}
+
+#[test]
+fn test_log_format_parsing() {
+    println!("\n🧪 Testing LogFormat parsing...");
+
+    assert_eq!("json".parse::<LogFormat>().unwrap(), LogFormat::Json);
+    assert_eq!(" JSON ".parse::<LogFormat>().unwrap(), LogFormat::Json);
+    assert_eq!("pretty".parse::<LogFormat>().unwrap(), LogFormat::Pretty);
+    assert_eq!("text".parse::<LogFormat>().unwrap(), LogFormat::Pretty);
+    assert!("yaml".parse::<LogFormat>().unwrap_err().contains("expected 'pretty' or 'json'"));
+    assert_eq!(LogFormat::default(), LogFormat::Pretty);
+
+    println!("🔚 Test completed\n");
+}
+
+#[test]
+fn test_confirmation_timeout_default_and_override() {
+    println!("\n🧪 Testing confirmation timeout configuration...");
+
+    let config = BlockchainConfig::default();
+    println!("✅ OUTPUT: confirmation_timeout_secs = {}", config.confirmation_timeout_secs);
+    assert_eq!(config.confirmation_timeout_secs, 30);
+
+    let request: TransferRequest =
+        serde_json::from_str(r#"{"to":"bob","amount":"1.0","confirmation_timeout":120}"#).unwrap();
+    println!("📝 INPUT: per-request confirmation_timeout = 120");
+    println!("✅ OUTPUT: {:?}", request.confirmation_timeout);
+    assert_eq!(request.confirmation_timeout, Some(120));
+
+    println!("🔚 Test completed\n");
+}
+
+#[test]
+fn test_swap_default_validation() {
+    println!("\n🧪 Testing DEFAULT_SLIPPAGE_BPS and DEFAULT_DEADLINE_SECS validation...");
+
+    let config = BlockchainConfig::default();
+    assert_eq!(config.default_slippage_bps, DEFAULT_SLIPPAGE_BPS);
+    assert_eq!(config.default_deadline_secs, DEFAULT_DEADLINE_SECS);
+
+    assert_eq!(BlockchainConfig::parse_slippage_bps(" 50 "), Ok(50));
+    assert_eq!(BlockchainConfig::parse_slippage_bps("0"), Ok(0));
+    assert_eq!(BlockchainConfig::parse_slippage_bps("10000"), Ok(10_000));
+    for invalid in ["10001", "-5", "0.5%", "five"] {
+        let error = BlockchainConfig::parse_slippage_bps(invalid).expect_err("Invalid slippage is rejected");
+        println!("✅ OUTPUT: {}", error);
+        assert!(error.starts_with("Invalid DEFAULT_SLIPPAGE_BPS"));
+    }
+
+    assert_eq!(BlockchainConfig::parse_deadline_secs("600"), Ok(600));
+    assert_eq!(BlockchainConfig::parse_deadline_secs("86400"), Ok(86_400));
+    for invalid in ["0", "5", "86401", "5m"] {
+        let error = BlockchainConfig::parse_deadline_secs(invalid).expect_err("Invalid deadlines are rejected");
+        println!("✅ OUTPUT: {}", error);
+        assert!(error.starts_with("Invalid DEFAULT_DEADLINE_SECS"));
+    }
+
+    println!("🔚 Test completed\n");
+}
@@ -0,0 +1,108 @@
+//! Spend Limit Tests for MCP Blockchain Server
+//!
+//! These tests verify that `send_eth` and ETH swaps are refused above
+//! `max_tx_eth` or past the `max_session_eth` budget, and allowed within them,
+//! using dry runs against MockProvider so they run without anvil.
+
+use mcp_server::config::BlockchainConfig;
+use mcp_server::services::blockchain::{BlockchainService, SwapRequest, TransferRequest};
+use mcp_server::services::provider::MockProvider;
+use rmcp::handler::server::tool::Parameters;
+
+const BOB: &str = "0x70997970C51812dc3A010C7d01b50e0d17dc79C8";
+
+async fn service_with_limits(max_tx_eth: Option<f64>, max_session_eth: Option<f64>) -> BlockchainService {
+    let config = BlockchainConfig {
+        max_tx_eth,
+        max_session_eth,
+        ..BlockchainConfig::default()
+    };
+    BlockchainService::with_provider_and_config(MockProvider::new(), config).await.unwrap()
+}
+
+fn dry_run_transfer(amount: &str) -> Parameters<TransferRequest> {
+    Parameters(TransferRequest {
+        to: BOB.to_string(),
+        amount: amount.to_string(),
+        from: None,
+        dry_run: Some(true),
+        confirmation_timeout: None,
+    })
+}
+
+#[tokio::test]
+async fn test_per_transaction_limit() {
+    println!("\n🧪 Testing send_eth enforces MAX_TX_ETH...");
+
+    let service = service_with_limits(Some(1.0), None).await;
+
+    println!("📝 INPUT: send 1.5 ETH with a 1 ETH per-transaction limit");
+    let error = service.send_eth(dry_run_transfer("1.5")).await
+        .expect_err("Transfers above the per-transaction limit should be refused");
+    println!("✅ OUTPUT: {}", error.message);
+    assert!(error.message.contains("per-transaction limit is 1.000000 ETH (MAX_TX_ETH)"));
+    assert!(error.message.contains("spends 1.500000 ETH"));
+
+    println!("📝 INPUT: send exactly 1 ETH");
+    let result = service.send_eth(dry_run_transfer("1")).await.unwrap();
+    let text = format!("{:?}", result.content);
+    assert!(text.contains("ETH Transfer Simulation (dry run)"), "A transfer at the limit is allowed");
+
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_session_limit() {
+    println!("\n🧪 Testing send_eth enforces MAX_SESSION_ETH...");
+
+    let service = service_with_limits(None, Some(0.5)).await;
+
+    let error = service.send_eth(dry_run_transfer("0.75")).await
+        .expect_err("A transfer larger than the session budget should be refused");
+    println!("✅ OUTPUT: {}", error.message);
+    assert!(error.message.contains("only 0.500000 ETH of the 0.500000 ETH session limit remains"));
+    assert!(error.message.contains("MAX_SESSION_ETH"));
+
+    // Dry runs are checked against the budget but don't consume it
+    service.send_eth(dry_run_transfer("0.5")).await.unwrap();
+    service.send_eth(dry_run_transfer("0.5")).await.unwrap();
+
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_eth_swaps_are_limited() {
+    println!("\n🧪 Testing ETH swaps enforce MAX_TX_ETH...");
+
+    let service = service_with_limits(Some(0.1), None).await;
+
+    let error = service.swap_tokens(Parameters(SwapRequest {
+        from_token: "ETH".to_string(),
+        to_token: "WETH".to_string(),
+        amount: "2".to_string(),
+        dex: None,
+        slippage: None,
+        dry_run: Some(true),
+        confirmation_timeout: None,
+        force: None,
+    })).await.expect_err("Wrapping more than the limit should be refused");
+    println!("✅ OUTPUT: {}", error.message);
+    assert!(error.message.contains("Spending limit exceeded"));
+
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_no_limits_by_default() {
+    println!("\n🧪 Testing spend limits are off by default...");
+
+    let config = BlockchainConfig::default();
+    assert_eq!(config.max_tx_eth, None);
+    assert_eq!(config.max_session_eth, None);
+
+    let service = BlockchainService::with_provider(MockProvider::new()).await.unwrap();
+    let result = service.send_eth(dry_run_transfer("500")).await.unwrap();
+    println!("✅ OUTPUT: {:?}", result.content);
+
+    println!("🔚 Test completed\n");
+}
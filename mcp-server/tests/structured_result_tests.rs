@@ -0,0 +1,76 @@
+//! Structured Result Tests for MCP Blockchain Server
+//!
+//! These tests verify that balance and transfer tools return their typed result
+//! as JSON in the first content item, followed by the human-readable summary,
+//! using MockProvider so they run without anvil.
+
+use alloy_primitives::{Address, U256};
+use mcp_server::services::blockchain::{
+    BalanceRequest, BalanceResult, BlockchainService, TransferRequest, TransferResult, TxStatus,
+};
+use mcp_server::services::provider::MockProvider;
+use rmcp::handler::server::tool::Parameters;
+use rmcp::model::CallToolResult;
+use std::str::FromStr;
+
+const BOB: &str = "0x70997970C51812dc3A010C7d01b50e0d17dc79C8";
+
+fn content_texts(result: &CallToolResult) -> Vec<String> {
+    result.content.iter()
+        .map(|content| content.as_text().expect("tools return text content").text.clone())
+        .collect()
+}
+
+#[tokio::test]
+async fn test_balance_returns_json_then_summary() {
+    println!("\n🧪 Testing balance returns a BalanceResult and a summary...");
+
+    let bob = Address::from_str(BOB).unwrap();
+    let mock = MockProvider::new().with_balance(bob, U256::from(1_500_000_000_000_000_000u128));
+    let service = BlockchainService::with_provider(mock).await.unwrap();
+
+    println!("📝 INPUT: balance of bob");
+    let result = service.balance(Parameters(BalanceRequest { who: "bob".to_string(), block: None })).await.unwrap();
+    let texts = content_texts(&result);
+    println!("✅ OUTPUT: {:?}", texts);
+
+    assert_eq!(texts.len(), 2, "JSON result followed by the summary");
+    let balance: BalanceResult = serde_json::from_str(&texts[0]).unwrap();
+    assert_eq!(balance.address, bob.to_string());
+    assert_eq!(balance.asset, "ETH");
+    assert_eq!(balance.balance_raw, "1500000000000000000");
+    assert_eq!(balance.decimals, 18);
+    assert!(texts[1].starts_with("ETH Balance Query:"));
+
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_send_eth_dry_run_result() {
+    println!("\n🧪 Testing send_eth dry run returns a simulated TransferResult...");
+
+    let service = BlockchainService::with_provider(MockProvider::new()).await.unwrap();
+    let request = TransferRequest {
+        to: "bob".to_string(),
+        amount: "0.25".to_string(),
+        from: None,
+        dry_run: Some(true),
+        confirmation_timeout: None,
+    };
+
+    println!("📝 INPUT: Simulate sending 0.25 ETH to bob");
+    let result = service.send_eth(Parameters(request)).await.unwrap();
+    let texts = content_texts(&result);
+    println!("✅ OUTPUT: {:?}", texts);
+
+    let transfer: TransferResult = serde_json::from_str(&texts[0]).unwrap();
+    assert_eq!(transfer.status, TxStatus::Simulated);
+    assert_eq!(transfer.to, "bob");
+    assert_eq!(transfer.to_address, Address::from_str(BOB).unwrap().to_string());
+    assert_eq!(transfer.amount_raw, "250000000000000000");
+    assert!(transfer.tx_hash.is_none(), "Dry runs have no transaction hash");
+    assert!(texts[0].contains("\"status\": \"simulated\""));
+    assert!(texts[1].contains("ETH Transfer Simulation (dry run)"));
+
+    println!("🔚 Test completed\n");
+}
@@ -0,0 +1,230 @@
+//! Swap Execution Tests for MCP Blockchain Server
+//!
+//! These tests drive swap_tokens end to end against MockProvider, which
+//! serves the router quote and pair reserves and records the transaction
+//! instead of broadcasting it. They cover the price impact guard and how an
+//! expired router deadline is reported when sending, once mined, and on a
+//! confirmation timeout.
+
+use alloy_primitives::{address, Address, U256};
+use mcp_server::config::BlockchainConfig;
+use mcp_server::services::blockchain::{BlockchainService, SwapRequest};
+use mcp_server::services::provider::MockProvider;
+use rmcp::handler::server::tool::Parameters;
+use rmcp::model::CallToolResult;
+use serde_json::json;
+use std::time::Duration;
+
+const ALICE_KEY: &str = "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+const ROUTER: Address = address!("0x7a250d5630B4cF539739dF2C5dAcb4c659F2488D");
+const FACTORY: Address = address!("0x5C69bEe701ef814a2B6a3EDD4B1652CB9cc5aA6f");
+const PAIR: Address = address!("0xB4e16d0168e52d35CaCD2c6185b44281Ec28C9Dc");
+const USDC: Address = address!("0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48");
+/// Latest block timestamp served by the mock
+const NOW: u64 = 1_700_000_000;
+const DEADLINE_SECS: u64 = 60;
+
+fn word(value: U256) -> Vec<u8> {
+    value.to_be_bytes::<32>().to_vec()
+}
+
+fn address_word(address: Address) -> Vec<u8> {
+    let mut bytes = vec![0u8; 12];
+    bytes.extend_from_slice(address.as_slice());
+    bytes
+}
+
+fn ether(amount: u64) -> U256 {
+    U256::from(amount) * U256::from(10u64).pow(U256::from(18))
+}
+
+fn usdc(amount: u64) -> U256 {
+    U256::from(amount) * U256::from(1_000_000u64)
+}
+
+/// A WETH/USDC pool holding 10 WETH and 20000 USDC (2000 USDC per ETH) whose router quotes `quote` for 1 ETH
+fn swap_provider(quote: U256) -> MockProvider {
+    // USDC sorts before WETH, so it is the pair's token0
+    let mut reserves = word(usdc(20_000));
+    reserves.extend(word(ether(10)));
+    reserves.extend(word(U256::ZERO));
+
+    // getAmountsOut returns uint256[]: offset, length, then [amount_in, amount_out]
+    let mut amounts = word(U256::from(32));
+    amounts.extend(word(U256::from(2)));
+    amounts.extend(word(ether(1)));
+    amounts.extend(word(quote));
+
+    MockProvider::new()
+        .with_call_response(ROUTER, "factory()", address_word(FACTORY))
+        .with_call_response(FACTORY, "getPair(address,address)", address_word(PAIR))
+        .with_call_response(PAIR, "getReserves()", reserves)
+        .with_call_response(ROUTER, "getAmountsOut(uint256,address[])", amounts)
+        .with_string_response(USDC, "symbol()", "USDC")
+        .with_call_response(USDC, "decimals()", word(U256::from(6)))
+}
+
+async fn service_with_key(mock: MockProvider) -> BlockchainService {
+    let config = BlockchainConfig {
+        rpc_url: "http://127.0.0.1:1".to_string(),
+        alice_private_key: ALICE_KEY.to_string(),
+        default_deadline_secs: DEADLINE_SECS,
+        ..BlockchainConfig::default()
+    };
+    BlockchainService::with_provider_and_config(mock, config).await.unwrap()
+}
+
+fn request(force: Option<bool>) -> Parameters<SwapRequest> {
+    Parameters(SwapRequest {
+        from_token: "ETH".to_string(),
+        to_token: "USDC".to_string(),
+        amount: "1".to_string(),
+        dex: None,
+        slippage: None,
+        dry_run: None,
+        confirmation_timeout: Some(1),
+        force,
+    })
+}
+
+#[tokio::test]
+async fn test_swap_refuses_high_price_impact_unless_forced() {
+    println!("\n🧪 Testing swap_tokens refuses a swap above MAX_PRICE_IMPACT_BPS...");
+
+    // 1500 USDC for 1 ETH against a 2000 USDC spot price is a 25% impact
+    let mock = swap_provider(usdc(1_500));
+    let service = service_with_key(mock.clone()).await;
+
+    println!("📝 INPUT: swap 1 ETH → USDC, quote 1500 USDC, spot 2000 USDC");
+    let error = service.swap_tokens(request(None)).await.expect_err("25% is above the 5% default limit");
+    println!("✅ OUTPUT: {}", error.message);
+    assert!(error.message.contains("HIGH PRICE IMPACT"));
+    assert!(error.message.contains("moves the price 25.00%"));
+    assert!(error.message.contains("set force: true"));
+    assert!(mock.sent_transactions().is_empty(), "A refused swap is never sent");
+
+    println!("📝 INPUT: the same swap with force: true");
+    let result = service.swap_tokens(request(Some(true))).await.unwrap();
+    let text = format!("{:?}", result.content);
+    println!("✅ OUTPUT: {}", text);
+    assert!(text.contains("HIGH PRICE IMPACT"), "The forced swap still carries the warning");
+    assert!(text.contains("Price Impact: 25.00%"));
+
+    let sent = mock.sent_transactions();
+    assert_eq!(sent.len(), 1);
+    assert_eq!(sent[0].to, Some(ROUTER.into()));
+    assert_eq!(sent[0].value, Some(ether(1)));
+
+    println!("🔚 Test completed\n");
+}
+
+/// A reverted receipt for the first transaction the mock sends
+fn failed_receipt() -> alloy_network::AnyTransactionReceipt {
+    serde_json::from_value(json!({
+        "transactionHash": MockProvider::transaction_hash(1),
+        "transactionIndex": "0x0",
+        "blockHash": "0x1d59ff54b1eb26b013ce3cb5fc9dab3705b415a67127a003c3e61eb445bb8df2",
+        "blockNumber": "0x10",
+        "from": "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266",
+        "to": ROUTER,
+        "cumulativeGasUsed": "0x7530",
+        "gasUsed": "0x7530",
+        "effectiveGasPrice": "0x3b9aca00",
+        "contractAddress": null,
+        "logs": [],
+        "logsBloom": format!("0x{}", "0".repeat(512)),
+        "type": "0x0",
+        "status": "0x0"
+    }))
+    .unwrap()
+}
+
+/// The structured swap result, the first content item
+fn swap_json(result: &CallToolResult) -> serde_json::Value {
+    serde_json::from_str(&result.content[0].as_text().expect("tools return text content").text).unwrap()
+}
+
+#[tokio::test]
+async fn test_swap_reports_a_deadline_expired_on_send() {
+    println!("\n🧪 Testing swap_tokens explains a send rejected for an expired deadline...");
+
+    let mock = swap_provider(usdc(1_990))
+        .with_block_timestamp(NOW)
+        .with_send_failure("execution reverted: UniswapV2Router: EXPIRED");
+    let service = service_with_key(mock.clone()).await;
+
+    println!("📝 INPUT: swap 1 ETH → USDC, the node rejects the send with UniswapV2Router: EXPIRED");
+    let error = service.swap_tokens(request(None)).await.expect_err("The send fails");
+    println!("✅ OUTPUT: {}", error.message);
+    assert!(error.message.contains("Swap rejected: the router deadline expired (UniswapV2Router: EXPIRED)"));
+    assert!(error.message.contains(&(NOW + DEADLINE_SECS).to_string()), "The deadline is reported");
+    assert!(error.message.contains("DEFAULT_DEADLINE_SECS (currently 60s)"));
+    assert!(mock.sent_transactions().is_empty());
+
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_swap_reports_a_deadline_expired_once_mined() {
+    println!("\n🧪 Testing swap_tokens explains a mined swap that reverted for an expired deadline...");
+
+    let mock = swap_provider(usdc(1_990))
+        .with_block_timestamp(NOW)
+        .with_receipt(MockProvider::transaction_hash(1), failed_receipt())
+        .with_trace(MockProvider::transaction_hash(1), json!({
+            "error": "execution reverted",
+            "revertReason": "UniswapV2Router: EXPIRED"
+        }));
+    let service = service_with_key(mock.clone()).await;
+
+    println!("📝 INPUT: swap 1 ETH → USDC, mined and reverted with UniswapV2Router: EXPIRED");
+    let result = service.swap_tokens(request(None)).await.unwrap();
+    let text = format!("{:?}", result.content);
+    println!("✅ OUTPUT: {}", text);
+    assert!(text.contains("Revert Reason: execution reverted: UniswapV2Router: EXPIRED"));
+    assert!(text.contains("Deadline Expired: the swap was mined after its deadline"));
+    assert!(text.contains("not a price or liquidity problem"));
+
+    let swap = swap_json(&result);
+    assert_eq!(swap["status"], "failed");
+    assert_eq!(swap["deadline"], NOW + DEADLINE_SECS);
+    assert_eq!(mock.sent_transactions().len(), 1);
+
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_swap_timeout_reports_whether_the_deadline_passed() {
+    println!("\n🧪 Testing swap_tokens says whether the deadline passed when confirmation times out...");
+
+    println!("📝 INPUT: no receipt, block timestamp unchanged");
+    let mock = swap_provider(usdc(1_990)).with_block_timestamp(NOW);
+    let service = service_with_key(mock).await;
+    let result = service.swap_tokens(request(None)).await.unwrap();
+    let text = format!("{:?}", result.content);
+    println!("✅ OUTPUT: {}", text);
+    assert!(text.contains("confirmation timed out"));
+    assert!(text.contains("The deadline has not passed yet"));
+    let swap = swap_json(&result);
+    assert_eq!(swap["status"], "pending");
+    assert_eq!(swap["deadline_passed"], false);
+
+    println!("📝 INPUT: no receipt, block timestamp past the deadline once the swap is sent");
+    let mock = swap_provider(usdc(1_990)).with_block_timestamp(NOW);
+    let service = service_with_key(mock.clone()).await;
+    let clock = tokio::spawn(async move {
+        while mock.sent_transactions().is_empty() {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        mock.with_block_timestamp(NOW + DEADLINE_SECS + 1);
+    });
+    let result = service.swap_tokens(request(None)).await.unwrap();
+    clock.await.unwrap();
+    let text = format!("{:?}", result.content);
+    println!("✅ OUTPUT: {}", text);
+    assert!(text.contains("The swap deadline has already passed"));
+    assert!(text.contains("Resubmit the swap rather than waiting"));
+    assert_eq!(swap_json(&result)["deadline_passed"], true);
+
+    println!("🔚 Test completed\n");
+}
@@ -0,0 +1,91 @@
+//! Swap Output Tests for MCP Blockchain Server
+//!
+//! These tests verify the constant-product swap quote behind
+//! calculate_swap_output against the output amounts from Uniswap V2's own
+//! pair tests, along with slippage, price impact and the tool's formatting.
+
+use alloy_primitives::U256;
+use mcp_server::services::blockchain::{calculate_slippage, BlockchainService, SwapOutputRequest};
+use mcp_server::services::provider::MockProvider;
+use rmcp::handler::server::tool::Parameters;
+
+fn ether(amount: u64) -> U256 {
+    U256::from(amount) * U256::from(10u64).pow(U256::from(18))
+}
+
+#[test]
+fn test_calculate_slippage_matches_uniswap_v2() {
+    println!("\n🧪 Testing swap quotes against Uniswap V2 getAmountOut...");
+
+    // (amount in, reserve in, reserve out, expected out) from UniswapV2Pair.spec.ts
+    let test_cases = vec![
+        (1, 5, 10, "1662497915624478906"),
+        (1, 10, 5, "453305446940074565"),
+        (2, 5, 10, "2851015155847869602"),
+        (1, 100, 100, "987158034397061298"),
+        (1, 1000, 1000, "996006981039903216"),
+    ];
+
+    for (amount_in, reserve_in, reserve_out, expected) in test_cases {
+        println!("📝 INPUT: {} in against reserves {}/{}", amount_in, reserve_in, reserve_out);
+        let (expected_out, _, _) = calculate_slippage(ether(amount_in), ether(reserve_in), ether(reserve_out), 0).unwrap();
+        println!("✅ OUTPUT: {}", expected_out);
+        assert_eq!(expected_out, U256::from_str_radix(expected, 10).unwrap());
+    }
+
+    // The Uniswap V2 library's smallest case
+    let (expected_out, _, _) = calculate_slippage(U256::from(2), U256::from(100), U256::from(100), 0).unwrap();
+    assert_eq!(expected_out, U256::from(1));
+
+    println!("🔚 Test completed\n");
+}
+
+#[test]
+fn test_calculate_slippage_minimum_and_price_impact() {
+    println!("\n🧪 Testing minimum output and price impact...");
+
+    let (expected_out, min_out, price_impact) = calculate_slippage(ether(1), ether(5), ether(10), 50).unwrap();
+    println!("✅ OUTPUT: expected {}, min {}, impact {:.4}%", expected_out, min_out, price_impact);
+    assert_eq!(min_out, U256::from_str_radix("1654185426046356511", 10).unwrap(), "0.5% below the expected output");
+    assert!((price_impact - 16.875).abs() < 0.001, "A fifth of the reserve moves the price ~16.9%");
+
+    // Deep liquidity leaves little more than the 0.3% fee
+    let (_, _, price_impact) = calculate_slippage(ether(1), ether(1_000_000), ether(1_000_000), 50).unwrap();
+    assert!(price_impact > 0.3 && price_impact < 0.31);
+
+    let (expected_out, min_out, price_impact) = calculate_slippage(U256::ZERO, ether(5), ether(10), 50).unwrap();
+    assert_eq!((expected_out, min_out, price_impact), (U256::ZERO, U256::ZERO, 0.0));
+
+    let error = calculate_slippage(ether(1), U256::ZERO, ether(10), 50).expect_err("Empty pairs can't be quoted");
+    assert!(error.message.contains("no liquidity"));
+    assert!(calculate_slippage(U256::MAX, ether(5), ether(10), 50).is_err(), "Overflow is an error, not a panic");
+
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_calculate_swap_output_tool() {
+    println!("\n🧪 Testing calculate_swap_output tool...");
+
+    let service = BlockchainService::with_provider(MockProvider::new()).await.unwrap();
+    let request = SwapOutputRequest {
+        amount_in: "10".to_string(),
+        reserve_in: "1000".to_string(),
+        reserve_out: "3000000".to_string(),
+        slippage: Some("50".to_string()),
+        decimals_in: None,
+        decimals_out: Some(6),
+    };
+
+    println!("📝 INPUT: 10 ETH against 1000 ETH / 3,000,000 USDC at 0.5% slippage");
+    let result = service.calculate_swap_output(Parameters(request)).await.unwrap();
+    let text = format!("{:?}", result.content);
+    println!("✅ OUTPUT: {}", text);
+
+    assert!(text.contains("Amount In: 10.000000000000000000"));
+    assert!(text.contains("Expected Out: 29614.741031 (29614741031 base units)"));
+    assert!(text.contains("Minimum Out (0.50% slippage): 29466.667325 (29466667325 base units)"));
+    assert!(text.contains("Price Impact: 1.28%"));
+
+    println!("🔚 Test completed\n");
+}
@@ -3,7 +3,8 @@
 //! These tests verify that the swap functionality works correctly,
 //! including ETH to token swaps using Uniswap V2 Router.
 
-use mcp_server::services::blockchain::{BlockchainService, SwapRequest};
+use alloy_primitives::U256;
+use mcp_server::services::blockchain::{apply_slippage, parse_slippage_bps, BlockchainService, SwapRequest};
 use rmcp::handler::server::tool::Parameters;
 use std::str::FromStr;
 
@@ -17,6 +18,9 @@ async fn test_swap_request_serialization() {
         amount: "10.0".to_string(),
         dex: Some("Uniswap V2".to_string()),
         slippage: Some("500".to_string()),
+        dry_run: None,
+        confirmation_timeout: None,
+        force: None,
     };
     
     println!("📝 INPUT: SwapRequest {{");
@@ -65,6 +69,9 @@ async fn test_swap_functionality() {
                 amount: "0.1".to_string(), // Small amount for testing
                 dex: Some("Uniswap V2".to_string()),
                 slippage: Some("500".to_string()), // 5% slippage
+                dry_run: None,
+                confirmation_timeout: None,
+                force: None,
             };
             
             println!("📝 INPUT: Swap {} {} to {} on {}", 
@@ -185,6 +192,9 @@ async fn test_eth_to_weth_direct_swap() {
                 amount: "0.01".to_string(), // Small amount for testing
                 dex: Some("WETH Contract".to_string()),
                 slippage: Some("100".to_string()), // 1% slippage (not used for direct swaps)
+                dry_run: None,
+                confirmation_timeout: None,
+                force: None,
             };
             
             println!("📝 INPUT: Swap {} {} to {} using direct WETH contract", 
@@ -249,6 +259,9 @@ async fn test_uniswap_v2_swap_vs_direct() {
                 amount: "0.001".to_string(),
                 dex: Some("WETH Contract".to_string()),
                 slippage: Some("100".to_string()),
+                dry_run: None,
+                confirmation_timeout: None,
+                force: None,
             };
             
             let direct_result = service.swap_tokens(Parameters(direct_swap_request)).await;
@@ -276,6 +289,9 @@ async fn test_uniswap_v2_swap_vs_direct() {
                 amount: "0.001".to_string(),
                 dex: Some("Uniswap V2".to_string()),
                 slippage: Some("500".to_string()),
+                dry_run: None,
+                confirmation_timeout: None,
+                force: None,
             };
             
             let uniswap_result = service.swap_tokens(Parameters(uniswap_swap_request)).await;
@@ -308,3 +324,32 @@ async fn test_uniswap_v2_swap_vs_direct() {
     
     println!("🔚 Swap comparison test completed\n");
 }
+
+#[test]
+fn test_slippage_amount_out_min() {
+    println!("\n🧪 Testing amountOutMin derivation from slippage...");
+
+    let expected = U256::from(1_000_000u64);
+    let test_cases = vec![
+        ("500", U256::from(950_000u64)),  // 5%
+        ("50", U256::from(995_000u64)),   // 0.5%
+        ("0", expected),                   // no slippage allowed
+        ("10000", U256::ZERO),             // accept anything
+    ];
+
+    for (slippage, expected_min) in test_cases {
+        let bps = parse_slippage_bps(slippage).unwrap();
+        let amount_out_min = apply_slippage(expected, bps);
+        println!("📝 INPUT: expected={} slippage={}bps", expected, slippage);
+        println!("✅ OUTPUT: amountOutMin={} (expected {})", amount_out_min, expected_min);
+        assert_eq!(amount_out_min, expected_min);
+    }
+
+    // Out of range or malformed slippage must be rejected
+    assert!(parse_slippage_bps("10001").is_err());
+    assert!(parse_slippage_bps("5%").is_err());
+    assert!(parse_slippage_bps("-1").is_err());
+
+    println!("✅ Slippage protection: PASSED");
+    println!("🔚 Test completed\n");
+}
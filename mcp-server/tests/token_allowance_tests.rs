@@ -0,0 +1,87 @@
+//! Token Allowance Tests for MCP Blockchain Server
+//!
+//! These tests verify the approve_token and get_allowance request
+//! structures and tool behavior against a local anvil fork.
+
+use mcp_server::services::blockchain::{AllowanceRequest, BlockchainService, TokenApprovalRequest};
+use rmcp::handler::server::tool::Parameters;
+
+const USDC_ADDRESS: &str = "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48";
+const UNISWAP_V2_ROUTER: &str = "0x7a250d5630B4cF539739dF2C5dAcb4c659F2488D";
+
+#[test]
+fn test_allowance_request_serialization() {
+    println!("\n🧪 Testing approval and allowance request serialization...");
+
+    let approval = TokenApprovalRequest {
+        token_address: USDC_ADDRESS.to_string(),
+        spender: UNISWAP_V2_ROUTER.to_string(),
+        amount: "250".to_string(),
+        confirmation_timeout: None,
+    };
+    let json = serde_json::to_string(&approval).unwrap();
+    println!("✅ OUTPUT JSON (approval): {}", json);
+    assert!(json.contains("spender"));
+    assert!(json.contains("amount"));
+
+    let allowance = AllowanceRequest {
+        token_address: USDC_ADDRESS.to_string(),
+        owner: "alice".to_string(),
+        spender: UNISWAP_V2_ROUTER.to_string(),
+    };
+    let json = serde_json::to_string(&allowance).unwrap();
+    println!("✅ OUTPUT JSON (allowance): {}", json);
+    let deserialized: AllowanceRequest = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized.owner, "alice");
+    assert_eq!(deserialized.spender, UNISWAP_V2_ROUTER);
+
+    println!("✅ Allowance request serialization test: PASSED");
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_approve_then_read_allowance() {
+    println!("\n🧪 Testing approve_token followed by get_allowance...");
+
+    match BlockchainService::new().await {
+        Ok(service) => {
+            let approval = TokenApprovalRequest {
+                token_address: USDC_ADDRESS.to_string(),
+                spender: UNISWAP_V2_ROUTER.to_string(),
+                amount: "250".to_string(),
+                confirmation_timeout: None,
+            };
+            println!("📝 INPUT: Approve {} USDC for Uniswap V2 Router", approval.amount);
+
+            match service.approve_token(Parameters(approval)).await {
+                Ok(result) => println!("✅ OUTPUT (approve): {:?}", result.content),
+                Err(e) => {
+                    println!("⚠️  Approval failed: {}", e);
+                    println!("💡 This might be expected if no private key is configured");
+                }
+            }
+
+            let allowance = AllowanceRequest {
+                token_address: USDC_ADDRESS.to_string(),
+                owner: "alice".to_string(),
+                spender: UNISWAP_V2_ROUTER.to_string(),
+            };
+            match service.get_allowance(Parameters(allowance)).await {
+                Ok(result) => {
+                    println!("✅ OUTPUT (allowance): {:?}", result.content);
+                    assert!(format!("{:?}", result.content).contains("Token Allowance"));
+                }
+                Err(e) => {
+                    println!("⚠️  Allowance query failed: {}", e);
+                    println!("💡 This might be expected if anvil is not forking mainnet");
+                }
+            }
+        }
+        Err(e) => {
+            println!("⚠️  BlockchainService creation failed: {}", e);
+            println!("💡 This is expected if anvil is not running");
+        }
+    }
+
+    println!("🔚 Test completed\n");
+}
@@ -24,6 +24,7 @@ async fn test_usdc_token_balance_on_forked_mainnet() {
     let token_balance_req = TokenBalanceRequest {
         token_address: usdc_address.to_string(),
         account_address: alice_address.to_string(),
+        block: None,
     };
     
     println!("📝 INPUT: TokenBalanceRequest {{");
@@ -120,6 +121,7 @@ async fn test_multiple_usdc_balance_queries() {
                 let token_balance_req = TokenBalanceRequest {
                     token_address: usdc_address.to_string(),
                     account_address: address.to_string(),
+                    block: None,
                 };
                 let result = service.token_balance(Parameters(token_balance_req)).await;
                 
@@ -0,0 +1,140 @@
+//! Token Decimal Override Tests for MCP Blockchain Server
+//!
+//! These tests verify that configured per-token decimals are used instead of
+//! the token's decimals() call, so tokens without a working decimals() are
+//! not formatted with the 18-decimal fallback. MockProvider serves the
+//! balances.
+
+use alloy_primitives::{Address, Bytes, U256};
+use mcp_server::config::{BlockchainConfig, NetworkAddresses};
+use mcp_server::services::blockchain::{BlockchainService, TokenApprovalRequest, TokenBalanceRequest};
+use mcp_server::services::provider::MockProvider;
+use rmcp::handler::server::tool::Parameters;
+use std::str::FromStr;
+
+const ALICE: &str = "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266";
+const USDC: &str = "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48";
+const USDT: &str = "0xdAC17F958D2ee523a2206206994597C13D831ec7";
+const WBTC: &str = "0x2260FAC5E5542a773Aa44fBCfeDf7C193bc2C599";
+const DAI: &str = "0x6B175474E89094C44Da98b954EedeAC495271d0F";
+const TOKEN: &str = "0x1111111111111111111111111111111111111111";
+const ROUTER: &str = "0x7a250d5630B4cF539739dF2C5dAcb4c659F2488D";
+
+fn uint(value: u64) -> Bytes {
+    Bytes::from(U256::from(value).to_be_bytes::<32>().to_vec())
+}
+
+fn balance_request(token: &str) -> Parameters<TokenBalanceRequest> {
+    Parameters(TokenBalanceRequest {
+        token_address: token.to_string(),
+        account_address: ALICE.to_string(),
+        block: None,
+    })
+}
+
+#[test]
+fn test_mainnet_decimal_overrides_are_built_in() {
+    println!("\n🧪 Testing built-in decimal overrides...");
+
+    let mainnet = NetworkAddresses::mainnet();
+    println!("✅ OUTPUT: {:?}", mainnet.token_decimals);
+    assert_eq!(mainnet.decimals_override(&Address::from_str(USDC).unwrap()), Some(6));
+    assert_eq!(mainnet.decimals_override(&Address::from_str(USDT).unwrap()), Some(6));
+    assert_eq!(mainnet.decimals_override(&Address::from_str(WBTC).unwrap()), Some(8));
+    assert_eq!(mainnet.decimals_override(&Address::from_str(DAI).unwrap()), None, "DAI uses decimals()");
+
+    println!("🔚 Test completed\n");
+}
+
+#[test]
+fn test_decimal_overrides_parse_from_network_file() {
+    println!("\n🧪 Testing token_decimals in the network address file...");
+
+    let networks = BlockchainConfig::parse_network_addresses(r#"{
+        "8453": {
+            "uniswap_v2_router": "0x4752ba5DBc23f44D87826276BF6Fd6b1C372aD24",
+            "token_decimals": { "0x833589fCD6eDb6E08f4c7C32D4f71b54bdA02913": 6 }
+        }
+    }"#).unwrap();
+    let base = &networks[&8453];
+    println!("✅ OUTPUT: {:?}", base.token_decimals);
+    assert_eq!(base.decimals_override(&Address::from_str("0x833589fCD6eDb6E08f4c7C32D4f71b54bdA02913").unwrap()), Some(6));
+
+    let networks = BlockchainConfig::parse_network_addresses(r#"{
+        "8453": { "uniswap_v2_router": "0x4752ba5DBc23f44D87826276BF6Fd6b1C372aD24" }
+    }"#).unwrap();
+    assert!(networks[&8453].token_decimals.is_empty(), "token_decimals is optional");
+
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_override_is_used_when_decimals_call_fails() {
+    println!("\n🧪 Testing token_balance formats an override token without decimals()...");
+
+    // No decimals() response, so without the override WBTC would fall back to 18
+    let wbtc = Address::from_str(WBTC).unwrap();
+    let mock = MockProvider::new().with_call_response(wbtc, "balanceOf(address)", uint(150_000_000));
+    let service = BlockchainService::with_provider(mock).await.unwrap();
+
+    println!("📝 INPUT: token_balance(WBTC, {})", ALICE);
+    let result = service.token_balance(balance_request(WBTC)).await.unwrap();
+    let text = format!("{:?}", result.content);
+    println!("✅ OUTPUT: {}", text);
+    assert!(text.contains("Balance: 1.50000000"));
+
+    // The override also wins over a token that answers decimals() differently
+    let usdc = Address::from_str(USDC).unwrap();
+    let mock = MockProvider::new()
+        .with_call_response(usdc, "balanceOf(address)", uint(2_500_000))
+        .with_call_response(usdc, "decimals()", uint(18));
+    let service = BlockchainService::with_provider(mock).await.unwrap();
+    let result = service.token_balance(balance_request(USDC)).await.unwrap();
+    assert!(format!("{:?}", result.content).contains("Balance: 2.500000"));
+
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_unreadable_decimals_are_not_guessed_or_cached() {
+    println!("\n🧪 Testing a failed decimals() read blocks writes and isn't memoized...");
+
+    let token = Address::from_str(TOKEN).unwrap();
+    let mock = MockProvider::new()
+        .with_string_response(token, "symbol()", "TKN")
+        .with_call_response(token, "balanceOf(address)", uint(1_000_000));
+    let config = BlockchainConfig {
+        // Unreachable, for the calls that go straight to the node; the mock has no receipt, so confirmation times out
+        rpc_url: "http://127.0.0.1:1".to_string(),
+        alice_private_key: "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80".to_string(),
+        ..BlockchainConfig::default()
+    };
+    let service = BlockchainService::with_provider_and_config(mock.clone(), config).await.unwrap();
+    let approval = || Parameters(TokenApprovalRequest {
+        token_address: TOKEN.to_string(),
+        spender: ROUTER.to_string(),
+        amount: "1".to_string(),
+        confirmation_timeout: Some(1),
+    });
+
+    println!("📝 INPUT: approve_token(TKN without decimals(), 1)");
+    let error = service.approve_token(approval()).await.expect_err("Writes don't assume 18 decimals");
+    println!("✅ OUTPUT: {}", error.message);
+    assert!(error.message.contains("Could not read decimals()"));
+    assert!(mock.sent_transactions().is_empty());
+
+    // Reads still display with the 18-decimal fallback
+    let result = service.token_balance(balance_request(TOKEN)).await.unwrap();
+    assert!(format!("{:?}", result.content).contains("(raw: 1000000)"));
+
+    // Once decimals() answers, the earlier failure hasn't stuck in the cache
+    let _ = mock.clone().with_call_response(token, "decimals()", uint(6));
+    let result = service.token_balance(balance_request(TOKEN)).await.unwrap();
+    let text = format!("{:?}", result.content);
+    println!("✅ OUTPUT: {}", text);
+    assert!(text.contains("Balance: 1.000000"));
+    assert!(service.approve_token(approval()).await.is_ok());
+    assert_eq!(mock.sent_transactions().len(), 1);
+
+    println!("🔚 Test completed\n");
+}
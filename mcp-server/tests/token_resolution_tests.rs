@@ -0,0 +1,177 @@
+//! Token Address Resolution Tests for MCP Blockchain Server
+//!
+//! These tests verify that resolve_token_address answers from the configured
+//! token table without searching, and that addresses found by the search
+//! service are only returned once they are a deployed contract whose symbol()
+//! matches. MockProvider serves the contracts and a fake search provider the
+//! search results.
+
+use alloy_primitives::{Address, U256};
+use async_trait::async_trait;
+use mcp_server::services::blockchain::{extract_addresses, BlockchainService, ResolveTokenAddressRequest};
+use mcp_server::services::provider::MockProvider;
+use mcp_server::services::search::{SearchResult, SearchService};
+use mcp_server::services::search_provider::SearchProvider;
+use rmcp::handler::server::tool::Parameters;
+use rmcp::ErrorData as McpError;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+
+const USDC: &str = "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48";
+const PEPE: &str = "0x6982508145454Ce325dDbE47a25d4ec3d2311933";
+const IMPOSTOR: &str = "0x1111111111111111111111111111111111111111";
+
+/// Search provider returning fixed results and counting its calls
+struct FakeProvider {
+    results: Vec<SearchResult>,
+    calls: AtomicU32,
+}
+
+#[async_trait]
+impl SearchProvider for FakeProvider {
+    fn name(&self) -> &'static str {
+        "Fake"
+    }
+
+    async fn search(&self, _query: &str, _count: u32) -> Result<Vec<SearchResult>, McpError> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        Ok(self.results.clone())
+    }
+}
+
+fn search_with(results: Vec<SearchResult>) -> (SearchService, Arc<FakeProvider>) {
+    let provider = Arc::new(FakeProvider { results, calls: AtomicU32::new(0) });
+    (SearchService::with_provider(provider.clone()), provider)
+}
+
+fn result(url: &str, description: &str) -> SearchResult {
+    SearchResult {
+        title: "PEPE token contract".to_string(),
+        url: url.to_string(),
+        description: description.to_string(),
+    }
+}
+
+/// A mock token contract at `address` answering symbol() and decimals()
+fn with_token(mock: MockProvider, address: &str, symbol: &str) -> MockProvider {
+    let address = Address::from_str(address).unwrap();
+    mock.with_code(address, vec![0x60, 0x80])
+        .with_string_response(address, "symbol()", symbol)
+        .with_call_response(address, "decimals()", U256::from(18).to_be_bytes::<32>().to_vec())
+}
+
+fn request(symbol: &str) -> Parameters<ResolveTokenAddressRequest> {
+    Parameters(ResolveTokenAddressRequest { symbol: symbol.to_string(), network: None, search: None })
+}
+
+#[test]
+fn test_extract_addresses() {
+    println!("\n🧪 Testing addresses are pulled out of search text...");
+
+    let text = format!(
+        "See https://etherscan.io/token/{} - PEPE ({}) is also listed at {}. Burn: 0x{}. Too long: 0x{}",
+        PEPE, PEPE.to_lowercase(), IMPOSTOR, "0".repeat(40), "a".repeat(41)
+    );
+    let addresses = extract_addresses(&text);
+    println!("✅ OUTPUT: {:?}", addresses);
+    assert_eq!(addresses, vec![Address::from_str(PEPE).unwrap(), Address::from_str(IMPOSTOR).unwrap()]);
+    assert!(extract_addresses("no addresses here").is_empty());
+
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_configured_tokens_are_resolved_without_searching() {
+    println!("\n🧪 Testing resolve_token_address uses the token table first...");
+
+    let service = BlockchainService::with_provider(with_token(MockProvider::new(), USDC, "USDC")).await.unwrap();
+    let (search, provider) = search_with(Vec::new());
+
+    println!("📝 INPUT: resolve_token_address(\"usdc\")");
+    let result = service.resolve_token_address(&search, request("usdc")).await.unwrap();
+    let text = format!("{:?}", result.content);
+    println!("✅ OUTPUT: {}", text);
+    assert!(text.contains(USDC));
+    assert!(text.contains("Source: network config (chain 1)"));
+    assert!(text.contains("Verified: deployed ERC-20, symbol() = USDC"));
+    assert_eq!(provider.calls.load(Ordering::SeqCst), 0, "No search for configured tokens");
+
+    // Missing code is reported rather than hidden
+    let service = BlockchainService::with_provider(MockProvider::new()).await.unwrap();
+    let result = service.resolve_token_address(&search, request("DAI")).await.unwrap();
+    assert!(format!("{:?}", result.content).contains("Not verified on this chain: no contract deployed"));
+
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_search_candidates_are_verified_on_chain() {
+    println!("\n🧪 Testing resolve_token_address checks search results before returning them...");
+
+    let mock = with_token(with_token(MockProvider::new(), IMPOSTOR, "PEPE2"), PEPE, "PEPE");
+    let service = BlockchainService::with_provider(mock).await.unwrap();
+    let (search, provider) = search_with(vec![
+        result(&format!("https://etherscan.io/token/{}", IMPOSTOR), "A token with a similar name"),
+        result("https://coinmarketcap.com/currencies/pepe/", &format!("PEPE contract: {}", PEPE)),
+    ]);
+
+    println!("📝 INPUT: resolve_token_address(\"PEPE\")");
+    let result = service.resolve_token_address(&search, request("PEPE")).await.unwrap();
+    let text = format!("{:?}", result.content);
+    println!("✅ OUTPUT: {}", text);
+    assert!(text.contains(PEPE));
+    assert!(text.contains("Source: web search for ethereum (2 candidate(s) checked)"));
+    assert!(text.contains("confirm the address on a block explorer"));
+    assert_eq!(provider.calls.load(Ordering::SeqCst), 1);
+
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_unverifiable_search_results_are_rejected() {
+    println!("\n🧪 Testing resolve_token_address refuses candidates that don't check out...");
+
+    // The impostor has the wrong symbol and the second address has no code
+    let service = BlockchainService::with_provider(with_token(MockProvider::new(), IMPOSTOR, "PEPE2")).await.unwrap();
+    let (search, _) = search_with(vec![result(
+        "https://example.com/pepe",
+        &format!("PEPE at {} or {}", IMPOSTOR, PEPE),
+    )]);
+
+    let error = service.resolve_token_address(&search, request("PEPE")).await.expect_err("Nothing verifies");
+    println!("✅ OUTPUT: {}", error.message);
+    assert!(error.message.contains("Could not resolve PEPE"));
+    assert!(error.message.contains("symbol() is PEPE2, not PEPE"));
+    assert!(error.message.contains("no contract deployed"));
+
+    let (empty, _) = search_with(Vec::new());
+    let error = service.resolve_token_address(&empty, request("PEPE")).await.expect_err("Nothing found");
+    assert!(error.message.contains("contained no contract addresses"));
+
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_table_only_lookup_never_searches() {
+    println!("\n🧪 Testing resolve_token_address with search disabled...");
+
+    let service = BlockchainService::with_provider(with_token(MockProvider::new(), PEPE, "PEPE")).await.unwrap();
+    let (search, provider) = search_with(vec![result("https://example.com/pepe", &format!("PEPE at {}", PEPE))]);
+    let table_only = |symbol: &str| Parameters(ResolveTokenAddressRequest {
+        symbol: symbol.to_string(),
+        network: None,
+        search: Some(false),
+    });
+
+    println!("📝 INPUT: resolve_token_address(\"PEPE\", search: false)");
+    let error = service.resolve_token_address(&search, table_only("PEPE")).await.expect_err("PEPE is not in the table");
+    println!("✅ OUTPUT: {}", error.message);
+    assert!(error.message.contains("not in the token table"));
+    assert_eq!(provider.calls.load(Ordering::SeqCst), 0, "No search when it is disabled");
+
+    let result = service.resolve_token_address(&search, table_only("USDC")).await.unwrap();
+    assert!(format!("{:?}", result.content).contains("Source: network config"));
+
+    println!("🔚 Test completed\n");
+}
@@ -0,0 +1,112 @@
+//! Token Transfer Tests for MCP Blockchain Server
+//!
+//! These tests verify the ERC-20 transfer request structure and the
+//! decimals-aware amount scaling used by transfer_token.
+
+use alloy_primitives::U256;
+use mcp_server::services::blockchain::{
+    format_token_amount, parse_token_amount, BlockchainService, TokenTransferRequest,
+};
+use rmcp::handler::server::tool::Parameters;
+
+#[test]
+fn test_token_transfer_request_serialization() {
+    println!("\n🧪 Testing TokenTransferRequest serialization...");
+
+    let request = TokenTransferRequest {
+        token_address: "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48".to_string(),
+        to: "bob".to_string(),
+        amount: "100.5".to_string(),
+        confirmation_timeout: None,
+    };
+    println!("📝 INPUT STRUCT: TokenTransferRequest {{ token_address: \"{}\", to: \"{}\", amount: \"{}\" }}",
+             request.token_address, request.to, request.amount);
+
+    let json = serde_json::to_string(&request).unwrap();
+    println!("✅ OUTPUT JSON: {}", json);
+    assert!(json.contains("token_address"));
+    assert!(json.contains("to"));
+    assert!(json.contains("amount"));
+
+    let deserialized: TokenTransferRequest = serde_json::from_str(&json).unwrap();
+    assert_eq!(request.token_address, deserialized.token_address);
+    assert_eq!(request.to, deserialized.to);
+    assert_eq!(request.amount, deserialized.amount);
+
+    println!("✅ TokenTransferRequest serialization test: PASSED");
+    println!("🔚 Test completed\n");
+}
+
+#[test]
+fn test_token_amount_scaling() {
+    println!("\n🧪 Testing token amount scaling by decimals...");
+
+    let test_cases = vec![
+        ("100.5", 6, U256::from(100_500_000u64)),   // USDC
+        ("1", 18, U256::from(1_000_000_000_000_000_000u64)), // WETH
+        ("0.01", 2, U256::from(1u64)),
+        ("42", 0, U256::from(42u64)),
+    ];
+
+    for (input, decimals, expected) in test_cases {
+        println!("📝 INPUT: \"{}\" with {} decimals", input, decimals);
+        let raw = parse_token_amount(input, decimals, "TOKEN").unwrap();
+        println!("✅ OUTPUT: {} (expected {})", raw, expected);
+        assert_eq!(raw, expected);
+    }
+
+    // More precision than the token supports must be rejected, not truncated
+    println!("📝 INPUT: \"1.0000001\" with 6 decimals");
+    assert!(parse_token_amount("1.0000001", 6, "USDC").is_err());
+    assert!(parse_token_amount("1.5", 0, "TOKEN").is_err());
+
+    println!("✅ Token amount scaling: PASSED");
+    println!("🔚 Test completed\n");
+}
+
+#[test]
+fn test_format_token_amount() {
+    println!("\n🧪 Testing token amount formatting...");
+
+    assert_eq!(format_token_amount(U256::from(100_500_000u64), 6, "USDC"), "100.500000 USDC");
+    assert_eq!(format_token_amount(U256::from(5u64), 6, "USDC"), "0.000005 USDC");
+    assert_eq!(format_token_amount(U256::from(42u64), 0, "TOKEN"), "42 TOKEN");
+
+    println!("✅ Token amount formatting: PASSED");
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_transfer_token() {
+    println!("\n🧪 Testing transfer_token tool...");
+
+    match BlockchainService::new().await {
+        Ok(service) => {
+            let request = TokenTransferRequest {
+                token_address: "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48".to_string(),
+                to: "bob".to_string(),
+                amount: "1".to_string(),
+                confirmation_timeout: None,
+            };
+            println!("📝 INPUT: Transfer {} USDC to {}", request.amount, request.to);
+
+            match service.transfer_token(Parameters(request)).await {
+                Ok(result) => {
+                    println!("✅ OUTPUT: {:?}", result.content);
+                    assert!(format!("{:?}", result.content).contains("Token Transfer"));
+                }
+                Err(e) => {
+                    // Alice usually holds no USDC on a fresh fork, so an insufficient balance error is expected
+                    println!("⚠️  Token transfer failed: {}", e);
+                    println!("💡 This might be expected if anvil is not running or Alice holds no USDC");
+                }
+            }
+        }
+        Err(e) => {
+            println!("⚠️  BlockchainService creation failed: {}", e);
+            println!("💡 This is expected if anvil is not running");
+        }
+    }
+
+    println!("🔚 Test completed\n");
+}
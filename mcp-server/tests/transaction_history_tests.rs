@@ -0,0 +1,83 @@
+//! Transaction History Tests for MCP Blockchain Server
+//!
+//! These tests verify the transaction history request structure and that
+//! oversized block ranges are rejected before any blocks are scanned.
+
+use mcp_server::services::blockchain::{BlockchainService, TransactionHistoryRequest, MAX_HISTORY_BLOCKS};
+use rmcp::handler::server::tool::Parameters;
+
+#[test]
+fn test_transaction_history_request_serialization() {
+    println!("\n🧪 Testing TransactionHistoryRequest serialization...");
+
+    let request = TransactionHistoryRequest {
+        address: "alice".to_string(),
+        block_range: Some(50),
+        limit: Some(10),
+        offset: Some(20),
+    };
+    println!("📝 INPUT STRUCT: TransactionHistoryRequest {{ address: \"{}\", block_range: {:?} }}", request.address, request.block_range);
+
+    let json = serde_json::to_string(&request).unwrap();
+    println!("✅ OUTPUT JSON: {}", json);
+
+    let deserialized: TransactionHistoryRequest = serde_json::from_str(&json).unwrap();
+    assert_eq!(request.address, deserialized.address);
+    assert_eq!(request.block_range, deserialized.block_range);
+    assert_eq!(request.limit, deserialized.limit);
+    assert_eq!(request.offset, deserialized.offset);
+
+    // block_range is optional and defaults when omitted
+    let without_range: TransactionHistoryRequest = serde_json::from_str(r#"{"address":"bob"}"#).unwrap();
+    assert_eq!(without_range.block_range, None);
+    assert_eq!(without_range.limit, None);
+
+    println!("✅ TransactionHistoryRequest serialization test: PASSED");
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_get_transaction_history() {
+    println!("\n🧪 Testing get_transaction_history tool...");
+
+    match BlockchainService::new().await {
+        Ok(service) => {
+            let request = TransactionHistoryRequest {
+                address: "alice".to_string(),
+                block_range: None,
+                limit: None,
+                offset: None,
+            };
+            println!("📝 INPUT: Transaction history for {} over the default range", request.address);
+
+            match service.get_transaction_history(Parameters(request)).await {
+                Ok(result) => {
+                    println!("✅ OUTPUT: {:?}", result.content);
+                    assert!(format!("{:?}", result.content).contains("Transactions Found"));
+                }
+                Err(e) => {
+                    println!("⚠️  Transaction history failed: {}", e);
+                    println!("💡 This might be expected if anvil is not running");
+                }
+            }
+
+            let too_large = TransactionHistoryRequest {
+                address: "alice".to_string(),
+                block_range: Some(MAX_HISTORY_BLOCKS + 1),
+                limit: None,
+                offset: None,
+            };
+            println!("📝 INPUT: Transaction history over {} blocks", MAX_HISTORY_BLOCKS + 1);
+
+            let result = service.get_transaction_history(Parameters(too_large)).await;
+            println!("✅ OUTPUT: {:?}", result.as_ref().map_err(|e| e.message.to_string()));
+            assert!(result.is_err(), "Expected oversized block range to be rejected");
+        }
+        Err(e) => {
+            println!("⚠️  BlockchainService creation failed: {}", e);
+            println!("💡 This is expected if anvil is not running");
+        }
+    }
+
+    println!("🔚 Test completed\n");
+}
@@ -0,0 +1,138 @@
+//! Transaction Monitor Tests for MCP Blockchain Server
+//!
+//! These tests verify that monitor_transaction reports a mined transaction
+//! once it has enough confirmations, keeps polling while it waits for more,
+//! and returns its status log when the time limit passes without a receipt.
+//! MockProvider serves the receipts and block number.
+
+use alloy_primitives::TxHash;
+use mcp_server::config::BlockchainConfig;
+use mcp_server::services::blockchain::{BlockchainService, MonitorLog, MonitorTransactionRequest};
+use mcp_server::services::provider::MockProvider;
+use rmcp::handler::server::tool::Parameters;
+use serde_json::json;
+use std::str::FromStr;
+
+const TX_HASH: &str = "0x5c504ed432cb51138bcf09aa5e8a410dd4a1e204ef84bfed1be16dfba1b22060";
+
+/// A successful receipt for TX_HASH mined in `block`
+fn receipt(block: u64) -> alloy_network::AnyTransactionReceipt {
+    serde_json::from_value(json!({
+        "transactionHash": TX_HASH,
+        "transactionIndex": "0x0",
+        "blockHash": "0x1d59ff54b1eb26b013ce3cb5fc9dab3705b415a67127a003c3e61eb445bb8df2",
+        "blockNumber": format!("{:#x}", block),
+        "from": "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266",
+        "to": "0x70997970C51812dc3A010C7d01b50e0d17dc79C8",
+        "cumulativeGasUsed": "0x5208",
+        "gasUsed": "0x5208",
+        "effectiveGasPrice": "0x3b9aca00",
+        "contractAddress": null,
+        "logs": [],
+        "logsBloom": format!("0x{}", "0".repeat(512)),
+        "type": "0x2",
+        "status": "0x1"
+    }))
+    .unwrap()
+}
+
+fn request(max_duration: u64, confirmations: u64) -> Parameters<MonitorTransactionRequest> {
+    Parameters(MonitorTransactionRequest {
+        tx_hash: TX_HASH.to_string(),
+        poll_interval: Some(1),
+        max_duration: Some(max_duration),
+        confirmations: Some(confirmations),
+    })
+}
+
+async fn service(mock: MockProvider) -> BlockchainService {
+    let config = BlockchainConfig {
+        // Nothing listens here, so mempool lookups fail fast
+        rpc_url: "http://127.0.0.1:1".to_string(),
+        ..BlockchainConfig::default()
+    };
+    BlockchainService::with_provider_and_config(mock, config).await.unwrap()
+}
+
+#[test]
+fn test_monitor_log_collapses_repeated_statuses() {
+    println!("\n🧪 Testing the monitor status log...");
+
+    let mut log = MonitorLog::default();
+    log.record(0, "PENDING - still in mempool");
+    log.record(2, "PENDING - still in mempool");
+    log.record(4, "PENDING - still in mempool");
+    log.record(6, "MINED - in block 100, 1 of 2 confirmations");
+    log.record(8, "CONFIRMED - mined in block 100, 2 confirmation(s)");
+    println!("✅ OUTPUT:\n{}", log);
+
+    assert_eq!(log.lines(), vec![
+        "[0s-4s] PENDING - still in mempool (3 checks)",
+        "[6s] MINED - in block 100, 1 of 2 confirmations",
+        "[8s] CONFIRMED - mined in block 100, 2 confirmation(s)",
+    ]);
+    assert!(MonitorLog::default().lines().is_empty());
+
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_monitor_transaction_reports_confirmed_receipt() {
+    println!("\n🧪 Testing monitor_transaction returns once the transaction is confirmed...");
+
+    let hash = TxHash::from_str(TX_HASH).unwrap();
+    let mock = MockProvider::new().with_receipt(hash, receipt(100)).with_block_number(101);
+    let service = service(mock).await;
+
+    println!("📝 INPUT: monitor_transaction {} waiting for 2 confirmations", TX_HASH);
+    let result = service.monitor_transaction(request(10, 2)).await.unwrap();
+    let text = format!("{:?}", result.content);
+    println!("✅ OUTPUT: {}", text);
+    assert!(text.contains("Transaction Monitor: CONFIRMED after 0s"));
+    assert!(text.contains("CONFIRMED - mined in block 100, 2 confirmation(s)"));
+    assert!(text.contains("SUCCESS"));
+
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_monitor_transaction_waits_for_confirmations() {
+    println!("\n🧪 Testing monitor_transaction keeps polling until enough blocks are mined...");
+
+    let hash = TxHash::from_str(TX_HASH).unwrap();
+    let mock = MockProvider::new().with_receipt(hash, receipt(100)).with_block_number(100);
+    let service = service(mock).await;
+
+    let result = service.monitor_transaction(request(1, 3)).await.unwrap();
+    let text = format!("{:?}", result.content);
+    println!("✅ OUTPUT: {}", text);
+    assert!(text.contains("NOT CONFIRMED after"));
+    assert!(text.contains("MINED - in block 100, 1 of 3 confirmations (2 checks)"));
+
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_monitor_transaction_times_out_while_pending() {
+    println!("\n🧪 Testing monitor_transaction gives up after max_duration...");
+
+    let service = service(MockProvider::new()).await;
+
+    let result = service.monitor_transaction(request(1, 1)).await.unwrap();
+    let text = format!("{:?}", result.content);
+    println!("✅ OUTPUT: {}", text);
+    assert!(text.contains("NOT CONFIRMED after"));
+    assert!(text.contains("PENDING"));
+    assert!(text.contains("(2 checks)"), "The two polls are collapsed into one log line");
+    assert!(text.contains("Run monitor_transaction again"));
+
+    let error = service.monitor_transaction(Parameters(MonitorTransactionRequest {
+        tx_hash: "0x1234".to_string(),
+        poll_interval: None,
+        max_duration: None,
+        confirmations: None,
+    })).await.expect_err("Bad hashes are rejected");
+    assert!(error.message.contains("Invalid transaction hash"));
+
+    println!("🔚 Test completed\n");
+}
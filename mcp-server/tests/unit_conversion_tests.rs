@@ -0,0 +1,82 @@
+//! Unit Conversion Tests for MCP Blockchain Server
+//!
+//! These tests verify the SimpleCast-backed wei/gwei/ether conversions behind
+//! convert_units, and the shared gas price formatting used in transaction
+//! responses.
+
+use mcp_server::services::blockchain::{convert_eth_units, format_gas_price, BlockchainService, UnitConversionRequest};
+use mcp_server::services::provider::MockProvider;
+use rmcp::handler::server::tool::Parameters;
+
+#[test]
+fn test_convert_eth_units() {
+    println!("\n🧪 Testing wei/gwei/ether conversions...");
+
+    let test_cases = vec![
+        (("0.00003", "eth", "gwei"), ("30000", "30000000000000")),
+        (("1", "ether", "wei"), ("1000000000000000000", "1000000000000000000")),
+        (("21000000000", "wei", "gwei"), ("21", "21000000000")),
+        (("1.5", "GWEI", "wei"), ("1500000000", "1500000000")),
+        (("1500000000", "gwei", "ether"), ("1.5", "1500000000000000000")),
+    ];
+
+    for ((value, from, to), (expected, expected_wei)) in test_cases {
+        println!("📝 INPUT: {} {} → {}", value, from, to);
+        let (converted, wei) = convert_eth_units(value, from, to).unwrap();
+        println!("✅ OUTPUT: {} ({} wei)", converted, wei);
+        assert_eq!(converted, expected, "Wrong conversion of {} {} to {}", value, from, to);
+        assert_eq!(wei, expected_wei);
+    }
+
+    println!("🔚 Test completed\n");
+}
+
+#[test]
+fn test_convert_eth_units_rejects_bad_input() {
+    println!("\n🧪 Testing invalid conversions are rejected...");
+
+    let error = convert_eth_units("1", "finney", "wei").expect_err("Unknown units should be rejected");
+    println!("✅ OUTPUT: {}", error.message);
+    assert!(error.message.contains("expected wei, gwei or ether"));
+
+    assert!(convert_eth_units("abc", "ether", "wei").is_err());
+    assert!(convert_eth_units("0.5", "wei", "gwei").is_err(), "There is no such thing as half a wei");
+
+    println!("🔚 Test completed\n");
+}
+
+#[test]
+fn test_format_gas_price() {
+    println!("\n🧪 Testing gas price formatting...");
+
+    assert_eq!(format_gas_price(12_500_000_000), "12.50 gwei (12500000000 wei)");
+    assert_eq!(format_gas_price(1_000_000_000), "1.00 gwei (1000000000 wei)");
+    assert_eq!(format_gas_price(0), "0.00 gwei (0 wei)");
+    // Sub-0.01 gwei L2 prices don't round to zero
+    assert_eq!(format_gas_price(1_000_000), "0.001 gwei (1000000 wei)");
+
+    println!("🔚 Test completed\n");
+}
+
+#[tokio::test]
+async fn test_convert_units_tool() {
+    println!("\n🧪 Testing convert_units tool...");
+
+    let service = BlockchainService::with_provider(MockProvider::new()).await.unwrap();
+    let request = UnitConversionRequest {
+        value: "0.00003".to_string(),
+        from: "ETH".to_string(),
+        to: "gwei".to_string(),
+    };
+
+    println!("📝 INPUT: convert 0.00003 ETH to gwei");
+    let result = service.convert_units(Parameters(request)).await.unwrap();
+    let text = format!("{:?}", result.content);
+    println!("✅ OUTPUT: {}", text);
+
+    assert!(text.contains("Input: 0.00003 ether"));
+    assert!(text.contains("Result: 30000 gwei"));
+    assert!(text.contains("In Wei: 30000000000000 wei"));
+
+    println!("🔚 Test completed\n");
+}
@@ -1,8 +1,7 @@
 use anyhow::Result;
-use baml_client::apis::configuration::Configuration;
-use baml_client::apis::default_api;
-use baml_client::models::ExtractResumeRequest;
 use dotenv::dotenv;
+use rig_client::resume::{extract_resume, extract_resume_with_schema};
+use serde_json::json;
 use std::env;
 
 #[tokio::main]
@@ -20,9 +19,6 @@ async fn main() -> Result<()> {
     println!("OpenAI API key present: {}", std::env::var("OPENAI_API_KEY").is_ok());
     println!("Anthropic API key present: {}", std::env::var("ANTHROPIC_API_KEY").is_ok());
 
-    // Create configuration with default settings
-    let config = Configuration::new();
-
     // Sample resume text
     let resume_text = r#"
         John Doe
@@ -39,11 +35,8 @@ async fn main() -> Result<()> {
         - Cloud Architecture
     "#;
 
-    // Create the request object
-    let request = ExtractResumeRequest::new(resume_text.to_string());
-
-    // Call the BAML-generated ExtractResume function
-    let result = default_api::extract_resume(&config, request).await?;
+    // Extract the standard Resume fields
+    let result = extract_resume(resume_text).await?;
 
     // Print the extracted resume information
     println!("Extracted Resume Information:");
@@ -58,5 +51,15 @@ async fn main() -> Result<()> {
         println!("- {}", skill);
     }
 
+    // Extract a custom set of fields without defining a struct for them
+    let schema = json!({
+        "name": "string",
+        "phone": "string",
+        "education": "string[]",
+        "years_of_experience": "int",
+    });
+    let custom = extract_resume_with_schema(resume_text, schema).await?;
+    println!("\nCustom Fields:\n{}", serde_json::to_string_pretty(&custom)?);
+
     Ok(())
 }
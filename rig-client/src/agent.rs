@@ -7,13 +7,18 @@
 //! 4. Returns human-friendly responses
 //! 5. **NEW**: Automatically uses RAG system for Uniswap documentation
 
-use rig::completion::Prompt;
-use rig::providers::anthropic::{self, CLAUDE_3_HAIKU};
+use futures::StreamExt;
+use rig::agent::MultiTurnStreamItem;
+use rig::agent::prompt_request::streaming::StreamingError;
+use rig::completion::message::{AssistantContent, Message};
+use rig::completion::{Prompt, PromptError};
+use rig::streaming::{StreamedAssistantContent, StreamingPrompt};
+use rig::providers::anthropic;
 use rig::client::CompletionClient;
 use rig::vector_store::in_memory_store::InMemoryVectorStore;
 use rig::embeddings::EmbeddingsBuilder;
 use rig::Embed;
-use rig_fastembed::{Client as FastembedClient, FastembedModel};
+use rig_fastembed::Client as FastembedClient;
 
 /// Simple text document for RAG
 #[derive(rig::Embed, Clone, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq)]
@@ -23,34 +28,361 @@ struct SimpleTextDocument {
 }
 use rmcp::{
     transport::StreamableHttpClientTransport,
-    model::{ClientInfo, ClientCapabilities, Implementation, Tool},
+    model::{CallToolRequestParam, ClientInfo, ClientCapabilities, Implementation, Tool},
     ServiceExt, RoleClient,
 };
+use std::sync::Arc;
+use std::time::Duration;
 use tracing::{debug, error, info, warn};
-use crate::rag::UniswapRagSystem;
+use crate::audit::{AuditHook, AuditLog};
+use crate::config::{DefaultAccounts, ModelSettings};
+use crate::intent::{account_address, account_index, is_address, parse_intent, resolved_token_address, summary_text, Intent};
+use crate::rag::{SearchFilter, UniswapRagSystem};
+use crate::self_test::{
+    check_output, find_tx_hash, first_account_address, health_status, private_key_available, SelfTestReport,
+    StepOutcome, SELF_TEST_ROUTER, SELF_TEST_TOKEN, SELF_TEST_WRAP_ETH,
+};
+use serde_json::json;
+
+/// Positive routing exemplars: questions that should be answered from Uniswap documentation
+const DOCUMENTATION_EXEMPLARS: &[&str] = &[
+    "How does Uniswap V3 work?",
+    "How do I calculate slippage for Uniswap V3?",
+    "What's the difference between exactInput and exactOutput?",
+    "Show me the SwapRouter contract interface",
+    "Explain how Uniswap V2 pairs and the factory work",
+    "What parameters does swapExactTokensForTokens accept?",
+    "How do flash swaps work in Uniswap?",
+    "What is the Uniswap V3 oracle and how do I use it?",
+    "Explain concentrated liquidity and tick ranges",
+    "Why does my Uniswap swap revert with insufficient output amount?",
+    "Show me the Uniswap V2 documentation for adding liquidity",
+    "What are the best practices for setting a swap deadline?",
+];
+
+/// Negative routing exemplars: blockchain operations that should go straight to MCP tools
+const OPERATION_EXEMPLARS: &[&str] = &[
+    "send 1 ETH to Bob",
+    "send 0.5 ETH from Alice to Bob",
+    "How much USDC does Alice have?",
+    "Check Alice's ETH balance",
+    "Is Uniswap V2 Router deployed?",
+    "swap 1 ETH for USDC",
+    "transfer 100 USDC to Bob",
+    "approve the router to spend my tokens",
+    "check the status of my transaction",
+    "get the list of available accounts",
+    "what is the current gas price",
+    "search for the current Ethereum price",
+];
+
+/// How much closer the query must be to a documentation exemplar than to an operation exemplar
+const DOCUMENTATION_ROUTING_MARGIN: f64 = 0.05;
+
+/// Running MCP client session
+type McpClient = rmcp::service::RunningService<RoleClient, rmcp::model::InitializeRequestParam>;
+
+/// Appended to the system prompt when the agent runs without an MCP server
+const NO_MCP_NOTICE: &str = "
+
+**MCP SERVER NOT CONNECTED:**
+The blockchain tools listed above are unavailable in this session. Answer general and
+Uniswap documentation questions normally. For blockchain operations (balances, transfers,
+swaps, contract checks), explain that the MCP server is not connected and that the user
+should start it (`cd mcp-server && cargo run --release`) and restart the client without --no-mcp.";
+
+/// Actionable message shown when the MCP server cannot be reached at startup
+pub fn mcp_unreachable_help(mcp_server_url: &str, attempts: u32) -> String {
+    format!(
+        "❌ Could not reach the MCP server at {} after {} attempt(s).\n\
+        \n\
+        To start it:\n\
+        1. Start anvil: anvil --fork-url <mainnet RPC URL>\n\
+        2. Start the MCP server: cd mcp-server && cargo run --release\n\
+        3. Restart this client\n\
+        \n\
+        Use --mcp-server <url> if the server runs elsewhere, or --no-mcp to continue\n\
+        without blockchain tools (general and RAG questions only).",
+        mcp_server_url, attempts
+    )
+}
+
+/// Whether a prompt error means Claude ran out of tool-call rounds
+///
+/// rig reports an exhausted `multi_turn` budget as `MaxDepthError`; streaming
+/// prompts wrap the same error in `StreamingError::Prompt`.
+pub fn is_turn_limit_error(error: &PromptError) -> bool {
+    matches!(error, PromptError::MaxDepthError { .. })
+}
+
+/// Warning returned when the agent stops because it hit the round limit
+pub fn turn_limit_warning(max_turns: usize) -> String {
+    format!("⚠️ Stopped after {} tool-call rounds; the task may be incomplete. \
+        Ask me to continue, or raise the limit with --max-turns.", max_turns)
+}
+
+/// Claude's last answer from the chat history of a stopped prompt, followed by the turn limit warning
+pub fn turn_limit_response(chat_history: &[Message], max_turns: usize) -> String {
+    let partial = chat_history.iter().rev().find_map(|message| match message {
+        Message::Assistant { content, .. } => {
+            let text: Vec<&str> = content.iter()
+                .filter_map(|content| match content {
+                    AssistantContent::Text(text) => Some(text.text.trim()),
+                    _ => None,
+                })
+                .filter(|text| !text.is_empty())
+                .collect();
+            (!text.is_empty()).then(|| text.join("\n"))
+        }
+        _ => None,
+    });
+    match partial {
+        Some(partial) => format!("{}\n\n{}", partial, turn_limit_warning(max_turns)),
+        None => turn_limit_warning(max_turns),
+    }
+}
+
+/// A user query after routing and optional RAG enhancement
+struct PreparedPrompt {
+    /// Prompt text to send to Claude
+    text: String,
+    /// Whether routing classified the query as a documentation question
+    is_documentation_query: bool,
+    /// Whether RAG context was added to `text`
+    rag_applied: bool,
+}
+
+/// Incremental output from `BlockchainAgent::process_command_streaming`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StreamEvent {
+    /// A chunk of response text from Claude
+    Text(String),
+    /// Claude is calling the named MCP tool
+    ToolCall(String),
+}
 
-/// Helper struct for semantic intent classification
-struct IntentCluster {
-    name: &'static str,
-    examples: Vec<&'static str>,
+/// A live MCP server session and the URL it was opened on
+struct McpConnection {
+    url: String,
+    client: McpClient,
+}
+
+/// Merge the tool lists of several MCP servers, keeping the first server that offers each tool name
+///
+/// Each tool is paired with the handle of the server that provides it.
+pub fn merge_tool_lists<S: Copy>(toolsets: Vec<(Vec<Tool>, S)>) -> Vec<(Tool, S)> {
+    let mut seen = std::collections::HashSet::new();
+    let mut merged = Vec::new();
+    for (tools, server) in toolsets {
+        for tool in tools {
+            if seen.insert(tool.name.clone()) {
+                merged.push((tool, server));
+            } else {
+                warn!("⚠️ Tool '{}' is offered by more than one MCP server - using the first", tool.name);
+            }
+        }
+    }
+    merged
 }
 
 /// The main blockchain agent that combines Claude AI with MCP tools and RAG
 pub struct BlockchainAgent {
     /// Claude AI agent configured with MCP tools and RAG dynamic context
     claude_agent: rig::agent::Agent<anthropic::completion::CompletionModel>,
-    /// MCP client that must be kept alive for the connection
-    _mcp_client: rmcp::service::RunningService<RoleClient, rmcp::model::InitializeRequestParam>,
+    /// Claude client the agent was built with, reused whenever it is rebuilt
+    anthropic_client: anthropic::Client,
+    /// MCP sessions that must be kept alive for their tools (empty in `--no-mcp` mode)
+    mcp_connections: Vec<McpConnection>,
     /// RAG system for Uniswap documentation and contracts (kept for manual search)
     rag_system: Option<UniswapRagSystem>,
+    /// Minimum relevance score for RAG results injected into prompts
+    rag_min_score: f64,
+    /// Character budget for documentation excerpts injected into one prompt
+    rag_context_budget: usize,
+    /// Fastembed model the RAG system is built with
+    embedding_model: String,
+    /// Similarity above which near-duplicate documents are left out of the RAG index
+    rag_dedup_threshold: f64,
+    /// Longest chunk, in characters, embedded in one piece when the RAG index is built
+    rag_max_chunk_chars: usize,
+    /// Model, temperature and token limit used whenever the Claude agent is built
+    model_settings: ModelSettings,
+    /// JSONL audit log of tool calls, if `--audit-log` was given
+    audit_log: Option<Arc<AuditLog>>,
+    /// Print a `→ tool {args}` / `← result` line for every tool call (`--trace-tools`)
+    tool_trace: bool,
+    /// Answer commands matching the intent grammar with a direct tool call (`--fast-path`)
+    fast_path: bool,
 }
 
 impl BlockchainAgent {
-    /// Create a new blockchain agent that connects to MCP server
-    pub async fn new(anthropic_client: anthropic::Client, mcp_server_url: &str) -> crate::Result<Self> {
+    /// Create a new blockchain agent that connects to every given MCP server
+    pub async fn new(anthropic_client: anthropic::Client, mcp_server_urls: &[String]) -> crate::Result<Self> {
+        Self::with_model_settings(anthropic_client, mcp_server_urls, ModelSettings::default()).await
+    }
+
+    /// Create a new blockchain agent with a Claude client built from ANTHROPIC_API_KEY
+    ///
+    /// Fails with a configuration error if the key is missing or empty rather
+    /// than building a client whose first request would be rejected.
+    pub async fn from_env(mcp_server_urls: &[String]) -> crate::Result<Self> {
+        let api_key = crate::config::anthropic_api_key_from_env()?;
+        Self::new(anthropic::Client::new(&api_key), mcp_server_urls).await
+    }
+
+    /// Create a new blockchain agent using the given Claude model settings
+    pub async fn with_model_settings(
+        anthropic_client: anthropic::Client,
+        mcp_server_urls: &[String],
+        model_settings: ModelSettings,
+    ) -> crate::Result<Self> {
+        Self::with_connection_retry(anthropic_client, mcp_server_urls, model_settings, 1, Duration::ZERO).await
+    }
+
+    /// Create a new blockchain agent, retrying each MCP connection with exponential backoff
+    ///
+    /// Tools from all servers are merged and registered on one Claude agent.
+    /// `attempts` counts the first try, so `1` disables retries. The delay before
+    /// retry `n` is `base_delay * 2^(n-1)`.
+    pub async fn with_connection_retry(
+        anthropic_client: anthropic::Client,
+        mcp_server_urls: &[String],
+        model_settings: ModelSettings,
+        attempts: u32,
+        base_delay: Duration,
+    ) -> crate::Result<Self> {
         info!("🔧 Initializing Blockchain Agent with Claude and MCP");
         
-        // Initialize MCP client connection
+        let mut mcp_connections = Vec::with_capacity(mcp_server_urls.len());
+        let mut toolsets = Vec::with_capacity(mcp_server_urls.len());
+        for url in mcp_server_urls {
+            let (client, tools) = Self::connect_with_retry(url, attempts, base_delay).await?;
+            info!("✅ Retrieved {} tools from MCP server at {}", tools.len(), url);
+            toolsets.push(tools);
+            mcp_connections.push(McpConnection { url: url.clone(), client });
+        }
+        
+        let tools = merge_tool_lists(toolsets.into_iter()
+            .zip(mcp_connections.iter().map(|connection| &connection.client))
+            .collect());
+        for (tool, _) in &tools {
+            debug!("📋 Available tool: {}", tool.name);
+        }
+        
+        // Validate that we have the required tools for PRD functionality
+        let required_tools = ["send_eth", "token_balance", "is_contract_deployed", "get_accounts", "get_private_keys", "get_default_addresses"];
+        let available_tool_names: Vec<&str> = tools.iter().map(|(t, _)| t.name.as_ref()).collect();
+        
+        for required_tool in &required_tools {
+            if !available_tool_names.contains(required_tool) {
+                warn!("⚠️ Required tool '{}' not found in MCP server", required_tool);
+            }
+        }
+        
+        info!("🔍 PRD Tool Validation: All required tools available");
+
+        // Create Claude agent with MCP tools
+        let claude_agent = Self::build_claude_agent(&anthropic_client, &model_settings, tools);
+        
+        info!("🤖 Claude AI Agent initialized with MCP tools (model: {})", model_settings.model);
+        
+        Ok(Self {
+            claude_agent,
+            anthropic_client,
+            mcp_connections,
+            rag_system: None,
+            rag_min_score: crate::rag::DEFAULT_MIN_RELEVANCE,
+            rag_context_budget: crate::rag::DEFAULT_CONTEXT_BUDGET_CHARS,
+            embedding_model: crate::rag::DEFAULT_EMBEDDING_MODEL.to_string(),
+            rag_dedup_threshold: crate::rag::DEFAULT_DEDUP_THRESHOLD,
+            rag_max_chunk_chars: crate::rag::DEFAULT_MAX_EMBED_CHARS,
+            model_settings,
+            audit_log: None,
+            tool_trace: false,
+            fast_path: false,
+        })
+    }
+
+    /// Create a degraded agent without an MCP connection
+    ///
+    /// General and RAG questions still work; blockchain operations are answered
+    /// with a note that the MCP server is not connected.
+    pub fn without_mcp(anthropic_client: anthropic::Client, model_settings: ModelSettings) -> Self {
+        warn!("⚠️ Running without MCP server - blockchain tools are disabled");
+        
+        let claude_agent = Self::build_claude_agent(&anthropic_client, &model_settings, Vec::new());
+        
+        Self {
+            claude_agent,
+            anthropic_client,
+            mcp_connections: Vec::new(),
+            rag_system: None,
+            rag_min_score: crate::rag::DEFAULT_MIN_RELEVANCE,
+            rag_context_budget: crate::rag::DEFAULT_CONTEXT_BUDGET_CHARS,
+            embedding_model: crate::rag::DEFAULT_EMBEDDING_MODEL.to_string(),
+            rag_dedup_threshold: crate::rag::DEFAULT_DEDUP_THRESHOLD,
+            rag_max_chunk_chars: crate::rag::DEFAULT_MAX_EMBED_CHARS,
+            model_settings,
+            audit_log: None,
+            tool_trace: false,
+            fast_path: false,
+        }
+    }
+
+    /// Record every tool call to the given audit log
+    pub fn set_audit_log(&mut self, audit_log: AuditLog) {
+        self.audit_log = Some(Arc::new(audit_log));
+    }
+
+    /// Print a compact trace line for every tool call and its result
+    pub fn set_tool_trace(&mut self, enabled: bool) {
+        self.tool_trace = enabled;
+    }
+
+    /// Call the MCP tool directly for commands the intent parser recognises, bypassing Claude
+    pub fn set_fast_path(&mut self, enabled: bool) {
+        self.fast_path = enabled;
+    }
+
+    /// Whether recognised commands bypass Claude
+    pub fn fast_path(&self) -> bool {
+        self.fast_path
+    }
+
+    /// Hook attached to every prompt: audit logging and the optional tool trace
+    fn tool_hook(&self) -> AuditHook {
+        AuditHook::new(self.audit_log.clone()).with_trace(self.tool_trace)
+    }
+
+    /// Whether blockchain tools are available through a connected MCP server
+    pub fn has_mcp(&self) -> bool {
+        !self.mcp_connections.is_empty()
+    }
+
+    /// Connect to one MCP server, retrying failed attempts with exponential backoff
+    async fn connect_with_retry(url: &str, attempts: u32, base_delay: Duration) -> crate::Result<(McpClient, Vec<Tool>)> {
+        let attempts = attempts.max(1);
+        let mut attempt = 1;
+        loop {
+            match Self::connect_mcp(url).await {
+                Ok(connection) => return Ok(connection),
+                Err(e) if attempt < attempts => {
+                    let delay = base_delay.saturating_mul(1u32 << (attempt - 1).min(16));
+                    warn!("⚠️ MCP connection attempt {}/{} failed: {} - retrying in {:?}", attempt, attempts, e, delay);
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                Err(e) => {
+                    return Err(crate::ClientError::mcp(format!(
+                        "{} (tried {} {} time(s))", e, url, attempts
+                    )));
+                }
+            }
+        }
+    }
+
+    /// Open one MCP connection and fetch its tool list
+    async fn connect_mcp(mcp_server_url: &str) -> Result<(McpClient, Vec<Tool>), String> {
         let mcp_transport = StreamableHttpClientTransport::from_uri(mcp_server_url);
         
         let mcp_client_info = ClientInfo {
@@ -63,78 +395,242 @@ impl BlockchainAgent {
         };
 
         info!("🔗 Connecting to MCP server at: {}", mcp_server_url);
-        let mcp_client = mcp_client_info.serve(mcp_transport).await.inspect_err(|e| {
-            error!("❌ MCP client connection failed: {:?}", e);
-        }).map_err(|e| {
-            crate::ClientError::McpConnection(format!("Failed to connect to MCP server: {}", e))
-        })?;
+        let mcp_client = mcp_client_info.serve(mcp_transport).await
+            .map_err(|e| {
+                error!("❌ MCP client connection failed: {:?}", e);
+                format!("Failed to connect to MCP server: {}", e)
+            })?;
 
         // Get available tools from MCP server
         info!("🛠️ Fetching available tools from MCP server...");
-        let tools: Vec<Tool> = mcp_client.list_tools(Default::default()).await
+        let tools = mcp_client.list_tools(Default::default()).await
             .map_err(|e| {
                 error!("❌ Failed to fetch tools from MCP server: {:?}", e);
-                crate::ClientError::McpConnection(format!("Failed to fetch tools from MCP server: {}", e))
+                format!("Failed to fetch tools from MCP server: {}", e)
             })?
             .tools;
         
-        info!("✅ Retrieved {} tools from MCP server", tools.len());
-        for tool in &tools {
-            debug!("📋 Available tool: {}", tool.name);
-        }
-        
-        // Validate that we have the required tools for PRD functionality
-        let required_tools = ["send_eth", "token_balance", "is_contract_deployed", "get_accounts", "get_private_keys", "get_default_addresses"];
-        let available_tool_names: Vec<&str> = tools.iter().map(|t| t.name.as_ref()).collect();
-        
-        for required_tool in &required_tools {
-            if !available_tool_names.contains(required_tool) {
-                warn!("⚠️ Required tool '{}' not found in MCP server", required_tool);
-            }
+        Ok((mcp_client, tools))
+    }
+
+    /// Build the Claude agent with the system prompt and every MCP tool registered
+    fn build_claude_agent(
+        anthropic_client: &anthropic::Client,
+        model_settings: &ModelSettings,
+        tools: Vec<(Tool, &McpClient)>,
+    ) -> rig::agent::Agent<anthropic::completion::CompletionModel> {
+        let mut preamble = Self::get_system_prompt(model_settings);
+        if tools.is_empty() {
+            preamble.push_str(NO_MCP_NOTICE);
         }
         
-        info!("🔍 PRD Tool Validation: All required tools available");
-
-        // Create Claude agent with MCP tools
         let agent_builder = anthropic_client
-            .agent(CLAUDE_3_HAIKU)
+            .agent(&model_settings.model)
             .name("RIG Agent")
-            .preamble(&Self::get_system_prompt())
-            .temperature(0.1) // Low temperature for consistent responses
-            .max_tokens(4096); // Maximum allowed for Claude 3 Haiku
+            .preamble(&preamble)
+            .temperature(model_settings.temperature)
+            .max_tokens(model_settings.max_tokens);
         
         // Add each MCP tool to the agent using fold pattern - following rmcp.rs example
-        let claude_agent = tools
+        tools
             .into_iter()
-            .fold(agent_builder, |agent, tool| {
+            .fold(agent_builder, |agent, (tool, mcp_client)| {
                 debug!("🔧 Adding MCP tool to agent: {}", tool.name);
                 agent.rmcp_tool(tool, mcp_client.clone())
             })
-            .build();
-        
-        info!("🤖 Claude AI Agent initialized with MCP tools");
-        
-        Ok(Self {
-            claude_agent,
-            _mcp_client: mcp_client,
-            rag_system: None,
-        })
+            .build()
     }
 
     /// Process a natural language command using Claude with MCP tools and RAG
     pub async fn process_command(&self, user_input: &str) -> crate::Result<String> {
         debug!("📝 Processing command: {}", user_input);
         
+        if let Some((tool, arguments)) = self.fast_path_call(user_input).await? {
+            return self.run_fast_path(tool, arguments).await;
+        }
+        
+        // For general questions, use a simpler approach without tool calling
+        let Some(PreparedPrompt { text: enhanced_input, .. }) = self.prepare_prompt(user_input).await? else {
+            return self.handle_general_question(user_input).await;
+        };
+        
+        // Use Claude with MCP tools to process the command
+        // Claude will automatically call the appropriate MCP tools based on the user's request
+        let max_turns = self.model_settings.max_turns;
+        let response = match self.claude_agent
+            .prompt(&enhanced_input)
+            .multi_turn(max_turns)
+            .with_hook(self.tool_hook())
+            .await
+        {
+            Ok(response) => response,
+            Err(PromptError::MaxDepthError { chat_history, .. }) => {
+                // Keep the partial answer and say why it stopped
+                warn!("⚠️ Claude stopped after {} tool-call rounds", max_turns);
+                turn_limit_response(&chat_history, max_turns)
+            }
+            Err(e) => {
+                error!("❌ Claude processing failed: {}", e);
+                return Err(e.into());
+            }
+        };
+            
+        debug!("🤖 Claude response: {}", response);
+        
+        Ok(response)
+    }
+
+    /// Process a command like `process_command`, reporting tokens and tool calls as they arrive
+    ///
+    /// `on_event` is called for every text chunk and every MCP tool call; the
+    /// full response text is also returned once the stream finishes.
+    pub async fn process_command_streaming<F>(&self, user_input: &str, mut on_event: F) -> crate::Result<String>
+    where
+        F: FnMut(StreamEvent),
+    {
+        debug!("📝 Processing command (streaming): {}", user_input);
+        
+        if let Some((tool, arguments)) = self.fast_path_call(user_input).await? {
+            on_event(StreamEvent::ToolCall(tool.to_string()));
+            let response = self.run_fast_path(tool, arguments).await?;
+            on_event(StreamEvent::Text(response.clone()));
+            return Ok(response);
+        }
+        
+        let Some(PreparedPrompt { text: enhanced_input, .. }) = self.prepare_prompt(user_input).await? else {
+            let response = self.handle_general_question(user_input).await?;
+            on_event(StreamEvent::Text(response.clone()));
+            return Ok(response);
+        };
+        
+        let max_turns = self.model_settings.max_turns;
+        let mut stream = self.claude_agent
+            .stream_prompt(&enhanced_input)
+            .multi_turn(max_turns)
+            .with_hook(self.tool_hook())
+            .await;
+        
+        let mut response = String::new();
+        while let Some(item) = stream.next().await {
+            let item = match item {
+                Ok(item) => item,
+                Err(StreamingError::Prompt(e)) if is_turn_limit_error(&e) => {
+                    // Keep the partial answer and say why it stopped
+                    warn!("⚠️ Claude stopped after {} tool-call rounds", max_turns);
+                    let warning = format!("\n\n{}", turn_limit_warning(max_turns));
+                    response.push_str(&warning);
+                    on_event(StreamEvent::Text(warning));
+                    break;
+                }
+                Err(e) => {
+                    error!("❌ Claude streaming failed: {}", e);
+                    return Err(e.into());
+                }
+            };
+            
+            match item {
+                MultiTurnStreamItem::StreamItem(StreamedAssistantContent::Text(text)) => {
+                    response.push_str(&text.text);
+                    on_event(StreamEvent::Text(text.text));
+                }
+                MultiTurnStreamItem::StreamItem(StreamedAssistantContent::ToolCall(tool_call)) => {
+                    debug!("🔧 Streaming tool call: {}", tool_call.function.name);
+                    on_event(StreamEvent::ToolCall(tool_call.function.name));
+                }
+                _ => {}
+            }
+        }
+        
+        debug!("🤖 Claude streamed response: {}", response);
+        
+        Ok(response)
+    }
+
+    /// The tool call answering `user_input` when the fast path is on and the command is recognised
+    ///
+    /// Returns `None`, sending the command to Claude, when the fast path is off,
+    /// there is no MCP server, the input doesn't match the intent grammar, or a
+    /// token balance names a token or account that can't be looked up without Claude.
+    async fn fast_path_call(&self, user_input: &str) -> crate::Result<Option<(&'static str, serde_json::Map<String, serde_json::Value>)>> {
+        if !self.fast_path || !self.has_mcp() {
+            return Ok(None);
+        }
+        let Some(mut intent) = parse_intent(user_input) else {
+            debug!("⚡ No fast-path match, using Claude: {}", user_input);
+            return Ok(None);
+        };
+        
+        // token_balance only takes addresses, so the token's symbol and anvil account names are looked up first
+        if let Intent::TokenBalance { token, .. } = &mut intent {
+            // Table only: a miss must not spend a web search or fill the swap token cache
+            let symbol = json!({ "symbol": token, "search": false }).as_object().cloned();
+            let resolved = match self.call_mcp_tool("resolve_token_address", symbol).await {
+                Ok(output) => resolved_token_address(&output),
+                Err(e) => {
+                    debug!("⚡ resolve_token_address failed for {}: {}", token, e);
+                    None
+                }
+            };
+            let Some(address) = resolved else {
+                debug!("⚡ {} is not in the server's token table, using Claude", token);
+                return Ok(None);
+            };
+            *token = address;
+        }
+        if let Intent::TokenBalance { who, .. } = &mut intent && !is_address(who) {
+            let Some(index) = account_index(who, &self.model_settings.accounts) else {
+                debug!("⚡ No address for '{}' without Claude, using Claude", who);
+                return Ok(None);
+            };
+            let accounts = self.call_mcp_tool("get_accounts", None).await?;
+            let Some(address) = account_address(&accounts, index) else {
+                debug!("⚡ get_accounts has no account {}, using Claude", index);
+                return Ok(None);
+            };
+            *who = address;
+        }
+        
+        Ok(Some((intent.tool(), intent.arguments())))
+    }
+
+    /// Call the tool chosen by the fast path, recording it like a call Claude made
+    ///
+    /// The hook sees the full tool output; the caller gets only its summary.
+    async fn run_fast_path(&self, tool: &'static str, arguments: serde_json::Map<String, serde_json::Value>) -> crate::Result<String> {
+        info!("⚡ Fast path: calling {} without Claude", tool);
+        let hook = self.tool_hook();
+        let args = serde_json::Value::Object(arguments.clone()).to_string();
+        hook.tool_called(tool, &args);
+        let result = self.call_mcp_tool(tool, Some(arguments)).await;
+        let output = match &result {
+            Ok(output) => output.clone(),
+            Err(e) => e.to_string(),
+        };
+        hook.tool_returned(tool, &args, &output);
+        result.map(|output| summary_text(&output).to_string())
+    }
+
+    /// Build the prompt sent to Claude, adding RAG context for documentation queries
+    ///
+    /// Returns `None` for general questions that are answered without tool calling.
+    async fn prepare_prompt(&self, user_input: &str) -> crate::Result<Option<PreparedPrompt>> {
         // Check if this is a general question that doesn't require tool calling
-        let is_general_question = self.is_general_question(user_input);
+        if self.is_general_question(user_input) {
+            return Ok(None);
+        }
         
         // Check if this is a documentation/help query that should trigger RAG
         let is_documentation_query = self.is_documentation_query(user_input).await?;
         
-        let enhanced_input = if is_documentation_query && self.rag_system.is_some() {
-            // Add RAG context to the query
-            match self.enhance_query_with_rag(user_input).await {
-                Ok(enhanced) => enhanced,
+        let mut rag_applied = false;
+        let text = if is_documentation_query && self.rag_system.is_some() {
+            // Add RAG context to the query, narrowed to a Uniswap version if one is mentioned
+            let filter = SearchFilter::from_query(user_input);
+            match self.enhance_query_with_rag(user_input, filter.as_ref()).await {
+                Ok(enhanced) => {
+                    rag_applied = enhanced != user_input;
+                    enhanced
+                }
                 Err(e) => {
                     warn!("⚠️ Failed to enhance query with RAG: {}, using original query", e);
                     user_input.to_string()
@@ -144,25 +640,52 @@ impl BlockchainAgent {
             user_input.to_string()
         };
         
-        // For general questions, use a simpler approach without tool calling
-        if is_general_question {
-            return self.handle_general_question(user_input).await;
+        Ok(Some(PreparedPrompt { text, is_documentation_query, rag_applied }))
+    }
+
+    /// Show the prompt `process_command` would send to Claude, without calling the API
+    ///
+    /// Runs the same routing and RAG enhancement, then returns the routing decision,
+    /// a summary of the preamble and the fully assembled prompt.
+    pub async fn preview_prompt(&self, user_input: &str) -> crate::Result<String> {
+        let preamble = Self::get_system_prompt(&self.model_settings);
+        let preamble_title = preamble.lines().find(|line| !line.trim().is_empty()).unwrap_or("").trim();
+        
+        if let Some((tool, arguments)) = self.fast_path_call(user_input).await? {
+            return Ok(format!(
+                "Prompt Preview (Claude was not called)\n\
+                Route: fast path (answered by {} directly, Claude is not called)\n\
+                Arguments: {}",
+                tool,
+                serde_json::Value::Object(arguments)
+            ));
         }
         
-        // Use Claude with MCP tools to process the command
-        // Claude will automatically call the appropriate MCP tools based on the user's request
-        let response = self.claude_agent
-            .prompt(&enhanced_input)
-            .multi_turn(5) // Allow up to 5 tool call rounds for complex operations
-            .await
-            .map_err(|e| {
-                error!("❌ Claude processing failed: {}", e);
-                crate::ClientError::ClaudeApi(format!("Failed to process command with Claude: {}", e))
-            })?;
-            
-        debug!("🤖 Claude response: {}", response);
+        let (route, prompt) = match self.prepare_prompt(user_input).await? {
+            None => ("general question (answered locally, Claude is not called)", user_input.to_string()),
+            Some(prepared) if prepared.rag_applied => ("documentation query (RAG context injected)", prepared.text),
+            Some(prepared) if prepared.is_documentation_query => ("documentation query (no RAG context matched)", prepared.text),
+            Some(prepared) => ("tool-enabled command", prepared.text),
+        };
         
-        Ok(response)
+        Ok(format!(
+            "Prompt Preview (Claude was not called)\n\
+            Route: {}\n\
+            Model: {} (temperature {}, max tokens {}, max tool rounds {})\n\
+            MCP servers: {}\n\
+            Preamble: \"{}\" ({} characters)\n\
+            ──── Prompt ────\n\
+            {}",
+            route,
+            self.model_settings.model,
+            self.model_settings.temperature,
+            self.model_settings.max_tokens,
+            self.model_settings.max_turns,
+            self.mcp_connections.len(),
+            preamble_title,
+            preamble.len(),
+            prompt
+        ))
     }
 
     /// Check if the input is a general question that doesn't require tool calling
@@ -236,127 +759,40 @@ impl BlockchainAgent {
     }
 
     /// Check if the input is a documentation/help query that should trigger RAG
+    ///
+    /// Embeds the query against positive (documentation) and negative (operation)
+    /// exemplars and routes to RAG when the nearest documentation exemplar beats the
+    /// nearest operation exemplar by `DOCUMENTATION_ROUTING_MARGIN`. Without an
+    /// initialized RAG system there is nothing to embed with or route to, so every
+    /// query is treated as an operation, as the original classifier did.
     async fn is_documentation_query(&self, input: &str) -> crate::Result<bool> {
-        // Preprocess the query
-        let processed_input = self.preprocess_query(input);
-        // If RAG system is not initialized, return false
         let rag_system = match &self.rag_system {
             Some(rag) => rag,
             None => return Ok(false),
         };
-
-        // Define query intents with rich semantic examples
-        let intent_clusters = vec![
-            // Documentation intent cluster
-            IntentCluster {
-                name: "documentation",
-                examples: vec![
-                    // Conceptual understanding
-                    "explain how the system works in detail",
-                    "help me understand the architecture",
-                    "what is the purpose of this component",
-                    "describe the relationship between A and B",
-                    "clarify how these parts interact",
-                    
-                    // Technical documentation
-                    "show me the API documentation",
-                    "what are the configuration options",
-                    "list all available parameters",
-                    "explain the function parameters",
-                    "what are the return values",
-                    
-                    // Best practices
-                    "what is the recommended way to implement this",
-                    "show me the best practices for X",
-                    "how should I structure this code",
-                    "what are common pitfalls to avoid",
-                    "guide me through the proper setup",
-                    
-                    // Troubleshooting
-                    "why am I getting this error",
-                    "how do I fix this issue",
-                    "what causes this behavior",
-                    "help me debug this problem",
-                    "explain the error message",
-                ],
-            },
-            // Operation intent cluster
-            IntentCluster {
-                name: "operation",
-                examples: vec![
-                    // Direct actions
-                    "execute the transaction",
-                    "deploy the contract",
-                    "send tokens to address",
-                    "transfer funds between accounts",
-                    "call this function now",
-                    
-                    // State queries
-                    "get the current balance",
-                    "check transaction status",
-                    "fetch contract state",
-                    "read storage value",
-                    "query event logs",
-                    
-                    // Multi-step operations
-                    "swap tokens using the router",
-                    "provide liquidity to pool",
-                    "stake tokens in contract",
-                    "bridge assets to L2",
-                    "upgrade proxy implementation",
-                ],
-            },
-        ];
-
-        // First, try direct semantic search in the documentation
-        let direct_results = rag_system.search(&processed_input, 3).await?;
         
-        // Calculate documentation relevance score
-        let doc_relevance = direct_results.first()
+        // Preprocess the query
+        let processed_input = self.preprocess_query(input);
+        
+        let exemplars: Vec<&str> = DOCUMENTATION_EXEMPLARS.iter()
+            .chain(OPERATION_EXEMPLARS.iter())
+            .copied()
+            .collect();
+        let results = rag_system.search_examples(&processed_input, &exemplars).await?;
+        
+        // Nearest exemplar score on each side
+        let best_score = |set: &[&str]| results.iter()
+            .filter(|(_, _, text)| set.contains(&text.as_str()))
             .map(|(score, _, _)| *score)
-            .unwrap_or(0.0);
-
-        // Get semantic similarity scores for each intent cluster
-        let mut cluster_scores = Vec::new();
+            .fold(f64::MIN, f64::max);
+        let documentation_score = best_score(DOCUMENTATION_EXEMPLARS);
+        let operation_score = best_score(OPERATION_EXEMPLARS);
         
-        for cluster in &intent_clusters {
-            let results = rag_system.search_examples(&processed_input, &cluster.examples).await?;
-            
-            // Calculate cluster score using top 3 matches with exponential decay
-            let cluster_score = results.iter()
-                .take(3)
-                .enumerate()
-                .map(|(i, (score, _, _))| score * (0.7_f64.powi(i as i32)))
-                .sum::<f64>() / 3.0;
-                
-            cluster_scores.push((cluster.name, cluster_score));
-            
-            info!("📊 {}: {:.3}", cluster.name, cluster_score);
-        }
-
-        // Find the highest scoring intent cluster
-        let (best_intent, best_score) = cluster_scores.iter()
-            .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap())
-            .unwrap();
-
-        // Calculate confidence metrics
-        let score_diff = cluster_scores.iter()
-            .filter(|(name, _)| *name != *best_intent)
-            .map(|(_, score)| best_score - score)
-            .min_by(|a, b| a.partial_cmp(b).unwrap())
-            .unwrap_or(0.0);
-
-        info!("📊 Intent Analysis:");
-        info!("   Best Intent: {} (score: {:.3})", best_intent, best_score);
-        info!("   Score Difference: {:.3}", score_diff);
-        info!("   Doc Relevance: {:.3}", doc_relevance);
-
-        // Make classification decision based on:
-        // 1. Intent classification confidence (score_diff)
-        // 2. Documentation relevance
-        // 3. Absolute intent score
-        let is_doc_query = (*best_intent == "documentation" && score_diff > 0.15) || doc_relevance > 0.8;
+        let is_doc_query = documentation_score - operation_score > DOCUMENTATION_ROUTING_MARGIN;
         
+        info!("📊 Intent Analysis:");
+        info!("   Documentation similarity: {:.3}", documentation_score);
+        info!("   Operation similarity: {:.3}", operation_score);
         info!("📑 Query Classification: {}", if is_doc_query { "Documentation" } else { "Operation" });
         
         Ok(is_doc_query)
@@ -499,20 +935,174 @@ I'm here to make blockchain interactions simple and accessible through natural l
 
     /// Test the MCP connection and available tools
     pub async fn test_connection(&self) -> crate::Result<String> {
-        info!("🧪 Testing MCP connection and tools...");
+        info!("🧪 Testing MCP connections with health_check...");
         
-        // Test with a simple command that should use MCP tools
-        let test_response = self.process_command("Get the list of available accounts").await?;
+        if self.mcp_connections.is_empty() {
+            return Err(crate::ClientError::mcp("Not connected to an MCP server (running with --no-mcp)"));
+        }
+        
+        // Call the health check tool directly - no Claude round trip needed
+        let mut report = Vec::with_capacity(self.mcp_connections.len());
+        let mut healthy = true;
+        for connection in &self.mcp_connections {
+            let result = connection.client.call_tool(CallToolRequestParam {
+                name: "health_check".into(),
+                arguments: None,
+            }).await;
+            
+            match result {
+                Ok(result) => {
+                    let health: Vec<&str> = result.content.iter()
+                        .filter_map(|content| content.as_text())
+                        .map(|text| text.text.as_str())
+                        .collect();
+                    let health = health.join("\n");
+                    // A server that answers but can't reach its node is not a working connection
+                    if health_status(&health).is_some_and(|status| status != "HEALTHY") {
+                        healthy = false;
+                    }
+                    report.push(format!("🔗 {}\n{}", connection.url, health));
+                }
+                // Servers without a health_check tool (e.g. search-only) are still connected
+                Err(e) => report.push(format!("🔗 {}\nConnected, but health_check is unavailable: {}", connection.url, e)),
+            }
+        }
+        
+        if !healthy {
+            warn!("⚠️ MCP connection test found an unhealthy server");
+            return Err(crate::ClientError::mcp(format!("A server reported an unhealthy status.\n{}", report.join("\n\n"))));
+        }
         
         info!("✅ MCP connection test successful");
-        Ok(format!("Connection test successful. Available accounts:\n{}", test_response))
+        Ok(format!("Connection test successful.\n{}", report.join("\n\n")))
+    }
+
+    /// Call an MCP tool directly, without going through Claude, and return its text output
+    ///
+    /// Tries each connected server in turn and returns the first successful result.
+    pub async fn call_mcp_tool(
+        &self,
+        name: &str,
+        arguments: Option<serde_json::Map<String, serde_json::Value>>,
+    ) -> crate::Result<String> {
+        let mut last_error = "Not connected to an MCP server (running with --no-mcp)".to_string();
+        for connection in &self.mcp_connections {
+            let result = connection.client.call_tool(CallToolRequestParam {
+                name: name.to_string().into(),
+                arguments: arguments.clone(),
+            }).await;
+            
+            match result {
+                Ok(result) => {
+                    let text: Vec<&str> = result.content.iter()
+                        .filter_map(|content| content.as_text())
+                        .map(|text| text.text.as_str())
+                        .collect();
+                    return Ok(text.join("\n"));
+                }
+                Err(e) => last_error = format!("{}: {}", connection.url, e),
+            }
+        }
+        Err(crate::ClientError::mcp(format!("Tool '{}' failed: {}", name, last_error)))
+    }
+
+    /// Smoke-test the MCP blockchain tools against the connected node
+    ///
+    /// Calls get_accounts, balance, token_balance (USDC), is_contract_deployed
+    /// (Uniswap V2 Router), an ETH→WETH wrap and check_transaction_status in
+    /// turn, without Claude. The wrap and status check are skipped when the
+    /// server has no private key, and steps missing an input from an earlier
+    /// failed step are skipped rather than failed.
+    pub async fn run_self_test(&self) -> crate::Result<SelfTestReport> {
+        if self.mcp_connections.is_empty() {
+            return Err(crate::ClientError::mcp("Not connected to an MCP server (running with --no-mcp)"));
+        }
+        info!("🧪 Running MCP tool self-test...");
+        
+        let mut report = SelfTestReport::default();
+        let args = |value: serde_json::Value| value.as_object().cloned();
+        
+        let accounts = self.call_mcp_tool("get_accounts", None).await;
+        let account = accounts.as_ref().ok().and_then(|output| first_account_address(output));
+        report.record("get_accounts", match (&accounts, &account) {
+            (Ok(_), Some(address)) => StepOutcome::Passed(format!("account 0 is {}", address)),
+            (Ok(output), None) => check_output(output, "\"address\""),
+            (Err(e), _) => StepOutcome::Failed(e.to_string()),
+        });
+        
+        match &account {
+            Some(address) => {
+                let balance = self.call_mcp_tool("balance", args(json!({ "who": address }))).await;
+                report.record("balance", Self::self_test_outcome(balance, "Balance: "));
+                
+                let token_balance = self.call_mcp_tool(
+                    "token_balance",
+                    args(json!({ "token_address": SELF_TEST_TOKEN, "account_address": address })),
+                ).await;
+                report.record("token_balance", Self::self_test_outcome(token_balance, "Balance: "));
+            }
+            None => {
+                for tool in ["balance", "token_balance"] {
+                    report.record(tool, StepOutcome::Skipped("needs an account from get_accounts".to_string()));
+                }
+            }
+        }
+        
+        let deployed = self.call_mcp_tool("is_contract_deployed", args(json!({ "address": SELF_TEST_ROUTER }))).await;
+        report.record("is_contract_deployed", Self::self_test_outcome(deployed, "Status: DEPLOYED"));
+        
+        // Only move value when the server can sign
+        let can_sign = self.call_mcp_tool("health_check", None).await
+            .map(|output| private_key_available(&output))
+            .unwrap_or(false);
+        if !can_sign {
+            let reason = "no private key on the MCP server (set ALICE_PRIVATE_KEY)".to_string();
+            report.record("swap_tokens (ETH → WETH)", StepOutcome::Skipped(reason.clone()));
+            report.record("check_transaction_status", StepOutcome::Skipped(reason));
+        } else {
+            let wrap = self.call_mcp_tool(
+                "swap_tokens",
+                args(json!({ "from_token": "ETH", "to_token": "WETH", "amount": SELF_TEST_WRAP_ETH })),
+            ).await;
+            let tx_hash = wrap.as_ref().ok().and_then(|output| find_tx_hash(output));
+            report.record("swap_tokens (ETH → WETH)", Self::self_test_outcome(wrap, "ETH to WETH Swap"));
+            
+            match tx_hash {
+                Some(tx_hash) => {
+                    let status = self.call_mcp_tool(
+                        "check_transaction_status",
+                        args(json!({ "tx_hash": tx_hash, "timeout": 30 })),
+                    ).await;
+                    report.record("check_transaction_status", Self::self_test_outcome(status, "SUCCESS"));
+                }
+                None => report.record(
+                    "check_transaction_status",
+                    StepOutcome::Skipped("the wrap returned no transaction hash".to_string()),
+                ),
+            }
+        }
+        
+        info!("🧪 Self-test finished: {} passed, {} failed, {} skipped", report.passed(), report.failed(), report.skipped());
+        Ok(report)
+    }
+
+    /// Outcome of a self-test tool call that should answer with a line containing `expected`
+    fn self_test_outcome(result: crate::Result<String>, expected: &str) -> StepOutcome {
+        match result {
+            Ok(output) => check_output(&output, expected),
+            Err(e) => StepOutcome::Failed(e.to_string()),
+        }
     }
 
     /// Initialize the RAG system with Uniswap documentation and integrate with agent
     pub async fn initialize_rag_system(&mut self, docs_path: Option<&str>) -> crate::Result<()> {
         info!("🔧 Initializing AGENTIC RAG system for Uniswap documentation");
         
-        let mut rag_system = UniswapRagSystem::new().await?;
+        let mut rag_system = UniswapRagSystem::with_index_settings(
+            &self.embedding_model,
+            self.rag_dedup_threshold,
+            self.rag_max_chunk_chars,
+        ).await?;
         
         // Try to load documentation from the specified path
         if let Some(path) = docs_path {
@@ -527,8 +1117,9 @@ I'm here to make blockchain interactions simple and accessible through natural l
         
         // Create embeddings for agentic RAG integration
         info!("🤖 Creating embeddings for agentic RAG integration...");
+        let (_, fastembed_model) = crate::rag::parse_embedding_model(&self.embedding_model)?;
         let embedding_client = FastembedClient::new();
-        let embedding_model = embedding_client.embedding_model(&FastembedModel::AllMiniLML6V2Q);
+        let embedding_model = embedding_client.embedding_model(&fastembed_model);
         
         // Get all documents from RAG system and convert to simple text format
         let documents = rag_system.get_all_documents().await?;
@@ -542,15 +1133,17 @@ I'm here to make blockchain interactions simple and accessible through natural l
                 doc.metadata.tags.join(", "), 
                 doc.content
             );
+            // Keep each input within the embedding model's limit, as the RAG index does
+            let doc_text: String = doc_text.chars().take(self.rag_max_chunk_chars).collect();
             let simple_doc = SimpleTextDocument { content: doc_text };
             embeddings_builder = embeddings_builder.document(simple_doc)
-                .map_err(|e| crate::ClientError::RagError(format!("Failed to add document: {}", e)))?;
+                .map_err(|e| crate::ClientError::rag_with("Failed to add document", e))?;
         }
         
         let embeddings = embeddings_builder
             .build()
             .await
-            .map_err(|e| crate::ClientError::RagError(format!("Failed to build embeddings: {}", e)))?;
+            .map_err(|e| crate::ClientError::rag_with("Failed to build embeddings", e))?;
         
         // Create vector store and index for dynamic context
         let vector_store = InMemoryVectorStore::from_documents(embeddings);
@@ -558,28 +1151,18 @@ I'm here to make blockchain interactions simple and accessible through natural l
         
         // Recreate the agent with dynamic context
         info!("🔄 Recreating agent with dynamic RAG context...");
-        let anthropic_client = anthropic::Client::new(&std::env::var("ANTHROPIC_API_KEY").unwrap_or_default());
         
-        // Get MCP tools from the existing connection
-        let tools: Vec<Tool> = self._mcp_client.list_tools(Default::default()).await
-            .map_err(|e| crate::ClientError::McpConnection(format!("Failed to fetch tools: {}", e)))?
-            .tools;
+        // Get MCP tools from the existing connections
+        let mut toolsets = Vec::with_capacity(self.mcp_connections.len());
+        for connection in &self.mcp_connections {
+            let tools = connection.client.list_tools(Default::default()).await
+                .map_err(|e| crate::ClientError::mcp_with(format!("Failed to fetch tools from {}", connection.url), e))?
+                .tools;
+            toolsets.push((tools, &connection.client));
+        }
         
         // Create new agent with enhanced RAG guidance (without dynamic context for now)
-        let agent_builder = anthropic_client
-            .agent(CLAUDE_3_HAIKU)
-            .preamble(&Self::get_system_prompt())
-            .temperature(0.1)
-            .max_tokens(4096);
-        
-        // Add MCP tools
-        let claude_agent = tools
-            .into_iter()
-            .fold(agent_builder, |agent, tool| {
-                debug!("🔧 Adding MCP tool to agent: {}", tool.name);
-                agent.rmcp_tool(tool, self._mcp_client.clone())
-            })
-            .build();
+        let claude_agent = Self::build_claude_agent(&self.anthropic_client, &self.model_settings, merge_tool_lists(toolsets));
         
         // Update the agent
         self.claude_agent = claude_agent;
@@ -591,25 +1174,82 @@ I'm here to make blockchain interactions simple and accessible through natural l
         Ok(())
     }
 
+    /// Set the minimum relevance score for RAG context injection
+    pub fn set_rag_min_score(&mut self, min_score: f64) {
+        self.rag_min_score = min_score;
+    }
+
+    /// Set the character budget for documentation excerpts injected into a prompt
+    pub fn set_rag_context_budget(&mut self, budget_chars: usize) {
+        self.rag_context_budget = budget_chars;
+    }
+
+    /// Set the similarity above which near-duplicate documents are dropped when the RAG index is built
+    pub fn set_rag_dedup_threshold(&mut self, threshold: f64) {
+        self.rag_dedup_threshold = threshold;
+    }
+
+    /// Set the longest chunk embedded in one piece when the RAG index is built; longer chunks are split
+    pub fn set_rag_max_chunk_chars(&mut self, max_chars: usize) {
+        self.rag_max_chunk_chars = max_chars.max(1);
+    }
+
+    /// Set the fastembed model used when the RAG system is next initialized
+    pub fn set_embedding_model(&mut self, model_name: &str) -> crate::Result<()> {
+        let (name, _) = crate::rag::parse_embedding_model(model_name)?;
+        self.embedding_model = name.to_string();
+        Ok(())
+    }
+
     /// Enhance a query with relevant RAG context
-    async fn enhance_query_with_rag(&self, query: &str) -> crate::Result<String> {
+    async fn enhance_query_with_rag(&self, query: &str, filter: Option<&SearchFilter>) -> crate::Result<String> {
         if let Some(rag_system) = &self.rag_system {
-            // Search for relevant documents
-            let results = rag_system.search(query, 3).await?;
+            // Search for relevant documents, ignoring poor matches
+            let results = match filter {
+                Some(filter) => {
+                    let filtered: Vec<_> = rag_system.search_filtered(query, 3, filter).await?
+                        .into_iter()
+                        .filter(|(score, _, _)| *score >= self.rag_min_score)
+                        .collect();
+                    if filtered.is_empty() {
+                        debug!("📭 No RAG results matched {:?}, searching without the filter", filter);
+                        rag_system.search_with_threshold(query, 3, self.rag_min_score).await?
+                    } else {
+                        filtered
+                    }
+                }
+                None => rag_system.search_with_threshold(query, 3, self.rag_min_score).await?,
+            };
             
             if results.is_empty() {
+                debug!("📭 No RAG results above {:.2} relevance, using original query", self.rag_min_score);
                 return Ok(query.to_string());
             }
             
+            // Pick the excerpts of each document closest to the query, within the context budget
+            let mut chunks = Vec::new();
+            for (document, (_, _, doc)) in results.iter().enumerate() {
+                chunks.extend(rag_system.relevant_chunks(query, doc, document, crate::rag::MAX_CHUNKS_PER_DOCUMENT).await?);
+            }
+            let chunks = crate::rag::fit_chunks_to_budget(chunks, self.rag_context_budget);
+            
             // Build context from search results
             let mut context = String::new();
             context.push_str("\n\nRELEVANT UNISWAP DOCUMENTATION:\n");
             context.push_str("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━\n");
             
-            for (score, _id, doc) in &results {
+            for (document, (score, _id, doc)) in results.iter().enumerate() {
+                let excerpts: Vec<&str> = chunks.iter()
+                    .filter(|chunk| chunk.document == document)
+                    .map(|chunk| chunk.text.as_str())
+                    .collect();
+                if excerpts.is_empty() {
+                    debug!("✂️ No excerpts of {} fit the {} character RAG budget", doc.title, self.rag_context_budget);
+                    continue;
+                }
                 context.push_str(&format!("📋 Document: {} (Relevance: {:.1}%)\n", doc.title, (score * 100.0).min(100.0)));
                 context.push_str(&format!("🏷️  Tags: {}\n", doc.metadata.tags.join(", ")));
-                context.push_str(&format!("📝 Content:\n{}\n\n", doc.content));
+                context.push_str(&format!("📝 Relevant Excerpts:\n{}\n\n", excerpts.join("\n…\n")));
                 context.push_str("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━\n\n");
             }
             
@@ -621,44 +1261,106 @@ I'm here to make blockchain interactions simple and accessible through natural l
         }
     }
 
-    /// Search for relevant Uniswap documentation
-    pub async fn search_documentation(&self, query: &str, limit: usize) -> crate::Result<Vec<(f64, String, crate::rag::UniswapDocument)>> {
+    /// Search for relevant Uniswap documentation, `limit` results starting at rank `offset`
+    pub async fn search_documentation(&self, query: &str, limit: usize, offset: usize) -> crate::Result<crate::rag::SearchPage> {
         if let Some(rag_system) = &self.rag_system {
-            rag_system.search(query, limit).await
+            rag_system.search_page(query, limit, offset).await
         } else {
-            Err(crate::ClientError::RagError("RAG system not initialized".to_string()))
+            Err(crate::ClientError::rag("RAG system not initialized"))
+        }
+    }
+
+    /// Search the documentation and write the top `limit` results to `path` as markdown
+    ///
+    /// Returns the number of results written.
+    pub async fn save_search_results(&self, query: &str, limit: usize, path: &std::path::Path) -> crate::Result<usize> {
+        let Some(rag_system) = &self.rag_system else {
+            return Err(crate::ClientError::rag("RAG system not initialized - use 'rag-init' first"));
+        };
+        
+        let page = rag_system.search_page(query, limit, 0).await?;
+        let markdown = crate::rag::search_results_markdown(query, rag_system.embedding_model_name(), chrono::Utc::now(), &page.results);
+        
+        if let Some(parent) = path.parent()
+            && !parent.as_os_str().is_empty() {
+                std::fs::create_dir_all(parent)?;
+            }
+        std::fs::write(path, markdown)?;
+        
+        info!("📝 Saved {} search results for '{}' to {}", page.results.len(), query, path.display());
+        Ok(page.results.len())
+    }
+
+    /// Rebuild the RAG index from the documentation sources, returning the old and new document counts
+    ///
+    /// The rebuilt index is also written to the index cache so the next startup picks it up.
+    pub async fn reindex_rag_system(&mut self) -> crate::Result<(usize, usize)> {
+        let Some(rag_system) = &mut self.rag_system else {
+            return Err(crate::ClientError::rag("RAG system not initialized - use 'rag-init' first"));
+        };
+        
+        let counts = rag_system.reindex().await?;
+        
+        let index_path = UniswapRagSystem::index_path();
+        if let Err(e) = rag_system.save_index(&index_path) {
+            warn!("⚠️ Failed to cache rebuilt RAG index at {}: {}", index_path.display(), e);
         }
+        Ok(counts)
     }
 
     /// Get RAG system status
     pub fn rag_status(&self) -> Option<String> {
         self.rag_system.as_ref().map(|rag| {
-            format!("RAG System: {} documents indexed", rag.document_count())
+            format!("RAG System: {} documents indexed with {}", rag.document_count(), rag.embedding_model_name())
         })
     }
 
+    /// Export the indexed RAG corpus to a JSON file
+    pub fn export_rag_documents(&self, path: &std::path::Path) -> crate::Result<usize> {
+        match &self.rag_system {
+            Some(rag_system) => rag_system.export_documents(path),
+            None => Err(crate::ClientError::rag("RAG system not initialized")),
+        }
+    }
+
+    /// Summary of the indexed RAG corpus
+    pub fn rag_corpus_stats(&self) -> Option<crate::rag::CorpusStats> {
+        self.rag_system.as_ref().map(|rag| rag.corpus_stats())
+    }
+
+    /// Health metrics of the RAG index
+    pub fn rag_stats(&self) -> Option<crate::rag::IndexStats> {
+        self.rag_system.as_ref().map(|rag| rag.stats())
+    }
+
     /// Test function to verify RAG logic (for debugging)
     #[cfg(test)]
     pub async fn test_rag_logic(&self, input: &str) -> crate::Result<bool> {
         self.is_documentation_query(input).await
     }
 
-    /// Generate the system prompt for Claude
-    fn get_system_prompt() -> String {
+    /// Generate the system prompt for Claude from the `--system-prompt` template or the built-in one
+    fn get_system_prompt(settings: &ModelSettings) -> String {
+        let template = settings.system_prompt.as_deref().unwrap_or_else(Self::default_system_prompt);
+        render_system_prompt(template, &settings.accounts, &settings.model)
+    }
+
+    /// The built-in system prompt template, a starting point for `--system-prompt` files
+    pub fn default_system_prompt() -> &'static str {
         r#"
 You are an expert Ethereum blockchain assistant with access to powerful blockchain tools via an MCP server and an AGENTIC RAG system for Uniswap documentation.
 
 CRITICAL DEFAULT ADDRESSES (PRD Requirements):
-- Alice: Account 0 from anvil (DEFAULT SENDER)
-- Bob: Account 1 from anvil (DEFAULT RECIPIENT)
+- {SENDER}: Account {SENDER_INDEX} from anvil (DEFAULT SENDER)
+- {RECIPIENT}: Account {RECIPIENT_INDEX} from anvil (DEFAULT RECIPIENT)
 
 IMPORTANT RULES:
-1. Alice (Account 0) is ALWAYS the default sender unless explicitly specified otherwise
-2. Bob (Account 1) is the default recipient when no recipient is specified
+1. {SENDER} (Account {SENDER_INDEX}) is ALWAYS the default sender unless explicitly specified otherwise
+2. {RECIPIENT} (Account {RECIPIENT_INDEX}) is the default recipient when no recipient is specified
 3. Addresses are dynamically loaded from anvil as per PRD requirement
-4. When users say "send X ETH to Bob" - Alice is the sender
-5. When users say "send X ETH from Alice to Bob" - use Alice as sender
-6. When users say "send X ETH" without specifying sender - Alice is the sender
+4. When users say "send X ETH to {RECIPIENT}" - {SENDER} is the sender
+5. When users say "send X ETH from {SENDER} to {RECIPIENT}" - use {SENDER} as sender
+6. When users say "send X ETH" without specifying sender - {SENDER} is the sender
 
 CONVERSATION MODES:
 You can handle two types of interactions:
@@ -687,10 +1389,41 @@ Available MCP Tools:
 - get_default_addresses: Get the default sender and recipient addresses (PRD configuration)
 - get_accounts: Get list of available public addresses
 - get_private_keys: Get account info including private keys (if available)
-- send_eth: Send ETH from Alice to a recipient address
-- token_balance: Check token balance for any address
-- is_contract_deployed: Check if a contract is deployed at an address
-- web_search: Search the web for current information
+- send_eth: Send ETH from {SENDER} to a recipient address (dry_run: true simulates it without broadcasting; amount can be "all"/"max" or a percentage like "50%")
+- estimate_transfer_cost: Estimate gas and total cost of an ETH transfer without sending it
+- token_balance: Check token balance for any address (optional block: number or tag to read a past balance)
+- weth_balance: Check the WETH balance of an address (no token address needed, WETH is 1:1 with ETH)
+- transfer_token: Send ERC-20 tokens (e.g., USDC) from {SENDER} to a recipient
+- approve_token: Approve a spender (e.g., Uniswap V2 Router) to move {SENDER}'s tokens
+- get_allowance: Check how many tokens a spender may move on behalf of an owner
+- nft_owner: Find who owns an ERC-721 token (collection address + token id)
+- nft_balance: Count the NFTs an address holds in an ERC-721 collection
+- batch_read: Run several reads at once (reads: [{type: balance|token_balance|allowance|nonce, ...params}]) - prefer it over many separate calls
+- get_transaction_history: List recent transactions sent or received by an address (scans the last 100 blocks by default; returns 20 at a time - pass the offset from the footer to see more)
+- resolve_token_address: Find a token's contract address by symbol (token table first, then a web search verified on-chain) - use before swapping or checking a token outside WETH/USDC/USDT/DAI/WBTC/UNI/LINK; the response says whether the address came from the table or the web
+- get_logs: Read the event logs a contract emitted (optional event_signature like Transfer/Approval/Swap and from_block/to_block; searches at most 10000 blocks) - use to verify a swap or transfer emitted the expected events
+- decode_calldata: Decode a transaction's input (function name and parameters) from its hash
+- get_nonce: Show the latest and pending nonce for an address (detects queued transactions)
+- check_transaction_status: Show whether a transaction hash succeeded or failed, with its receipt (optional timeout to wait for it)
+- monitor_transaction: Watch a transaction hash until it is mined (polls every 2s for up to 120s by default; optional confirmations) - use when a send or swap reports it is still pending
+- get_pending_transactions: List transactions waiting in the node's txpool (paged with limit/offset like get_transaction_history)
+- fund_account: Set an account's ETH balance on anvil (e.g., give account3 100 ETH before using it as a sender)
+- impersonate_and_send: Send ETH or tokens from any address (e.g., a USDC whale) on an anvil fork - only when the server enables impersonation
+- get_onchain_price: Get a token's USD price from its Chainlink feed (ETH, WETH, BTC, WBTC, USDC, USDT, DAI, LINK, UNI) - use this rather than web_search for prices and slippage math
+- get_chain_status: Show the current block number, chain id and gas price (use to check the network is live)
+- convert_units: Convert an amount between wei, gwei and ether
+- calculate_swap_output: Expected output, minimum output after slippage and price impact of a Uniswap V2 swap, given the pair reserves
+- health_check: Check the MCP server is up and connected (uptime, provider status, accounts, private key)
+- is_contract_deployed: Check if a contract is deployed at an address (reports code size, keccak256 code hash and EIP-170 limit; pass expected_code_hash to verify the code)
+- deploy_contract: Deploy a contract from {SENDER} given its creation bytecode (optional constructor signature and args); returns the new contract address to check with is_contract_deployed
+- call_contract: Call any view function on a contract with a cast-style signature including return types (e.g., "totalSupply()(uint256)") and string args; use it for reads the other tools don't cover
+- send_contract_call: Send a transaction from {SENDER} calling any contract function (e.g., "mint()") with string args and optional ETH value/gas_limit; use dry_run: true to check it first, and prefer the dedicated tools for ETH, token transfers and swaps
+- add_address_book_entry: Save a named address (e.g., treasury) so it can be used as a recipient
+- list_address_book: List the saved named addresses
+- resolve_ens: Resolve an ENS name (e.g., vitalik.eth) to an address
+- reverse_resolve_ens: Look up the primary ENS name of an address
+- get_ens_records: Read ENS text records (avatar, email, url, com.twitter, ...) for a name
+- web_search: Search the web for current information (count/offset page through up to 20 results)
 
 **GENERAL CONVERSATION EXAMPLES:**
 - "What tools do you have access to?" → List your capabilities without calling tools
@@ -699,9 +1432,24 @@ Available MCP Tools:
 - "Tell me about yourself" → Explain your role and capabilities
 
 **BLOCKCHAIN OPERATION EXAMPLES:**
-- "send 1 ETH to Bob" → Use send_eth tool
-- "How much USDC does Alice have?" → Use token_balance tool
+- "send 1 ETH to {RECIPIENT}" → Use send_eth tool
+- "Would sending 1 ETH to {RECIPIENT} work?" → Use send_eth tool with dry_run: true
+- "send all my ETH to {RECIPIENT}" / "send half my balance to {RECIPIENT}" → Use send_eth tool with amount "all" / "50%" (never compute the amount yourself)
+- "How much USDC does {SENDER} have?" → Use token_balance tool
+- "How much USDC did {SENDER} have at block 19000000?" → Use token_balance tool with block: "19000000"
+- "How much WETH does {SENDER} have?" → Use weth_balance tool
+- "Show {SENDER}'s ETH, USDC balance and nonce" → Use one batch_read call with three reads
+- "send 100 USDC to {RECIPIENT}" → Use transfer_token tool
+- "save 0x90F7...b906 as treasury" → Use add_address_book_entry tool, then "send 1 ETH to treasury" works
 - "Is Uniswap V2 Router deployed?" → Use is_contract_deployed tool
+- "What's the total supply of USDC?" → Use call_contract tool with the USDC address and signature: "totalSupply()(uint256)"
+- "Call mint() on the test token at 0x5FbD...0aa3" → Use send_contract_call tool with signature: "mint()"
+- "My swap timed out, did it go through?" → Use monitor_transaction tool with the tx_hash from the timeout message
+- "Did my last swap emit a Swap event?" → Use get_logs tool with the pair address and event_signature: "Swap"
+- "What block are we on?" / "What's the gas price?" → Use get_chain_status tool
+- "How many gwei is 0.00003 ETH?" → Use convert_units tool with value: "0.00003", from: "ether", to: "gwei"
+- "How much USDC will I get for 10 ETH at 0.5% slippage?" → Use calculate_swap_output tool with amount_in: "10", the pair reserves, slippage: "50", decimals_out: 6
+- "What's the price of ETH?" → Use get_onchain_price tool with symbol: "ETH"
 
 **IMPORTANT: RAG functionality is NOT available as MCP tools. Use CLI commands only.**
 
@@ -722,8 +1470,8 @@ The RAG system is automatically triggered ONLY for documentation questions about
 
 **IMPORTANT:** RAG is NOT triggered for:
 - Simple swap commands (e.g., "swap 1 ETH for USDC")
-- Balance queries (e.g., "How much USDC does Alice have?")
-- Transaction operations (e.g., "send 1 ETH to Bob")
+- Balance queries (e.g., "How much USDC does {SENDER} have?")
+- Transaction operations (e.g., "send 1 ETH to {RECIPIENT}")
 - Contract deployment checks (e.g., "Is Uniswap Router deployed?")
 
 When RAG is triggered, you will automatically receive relevant Uniswap documentation to provide comprehensive, accurate answers.
@@ -736,17 +1484,16 @@ RESPONSE FORMATTING REQUIREMENTS:
 5. Use clear section headers and bullet points for better readability
 6. Always include the complete tool response text - do not summarize or omit details
 7. For errors, provide clear explanations and suggestions for resolution
-8. CRITICAL: NEVER say "the transaction hash is provided above" - ALWAYS include the actual hash in your response
-9. CRITICAL: Copy and paste the COMPLETE tool response text into your final answer
+8. balance, token_balance, weth_balance, send_eth, transfer_token and swap_tokens return a JSON result first, then a human-readable summary - take the status, amounts and tx_hash from the JSON fields and present the summary to the user (don't paste the raw JSON)
+9. CRITICAL: Copy the COMPLETE human-readable summary into your final answer
 10. CRITICAL: If a tool returns transaction details, include ALL of them in your response
+11. If swap_tokens refuses a swap for HIGH PRICE IMPACT, show the warning and ask the user before retrying with force: true - never set force on your own
 
 For transfers:
-- Default to using Alice as the sender if not specified
+- Default to using {SENDER} as the sender if not specified
 - Validate addresses and amounts before executing
-- ALWAYS include the complete transaction hash in your response when a transfer is successful
+- Include the tx_hash from the JSON result prominently in your response when a transfer is sent
 - Format transaction details clearly with proper labels
-- NEVER say "transaction hash is provided above" - ALWAYS include the actual hash
-- When you receive a transaction hash from the tool, include it prominently in your response
 
 For balance queries:
 - Use the balance tool for ETH balances
@@ -768,21 +1515,34 @@ For Uniswap documentation queries:
 - **CRITICAL: Do NOT use any tool calls for RAG functionality**
 
 EXAMPLE RESPONSE FORMAT:
-"I'll help you send 1 ETH from Alice to Bob.
+"I'll help you send 1 ETH from {SENDER} to {RECIPIENT}.
 
 [Tool Response]
 ETH Transfer Successful:
-From: 0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266 (Alice)
-To: 0x70997970C51812dc3A010C7d01b50e0d17dc79C8 (Bob)
+From: 0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266 ({SENDER})
+To: 0x70997970C51812dc3A010C7d01b50e0d17dc79C8 ({RECIPIENT})
 Amount: 1.0 ETH
 Transaction Hash: 0x0d7131d30ea1bcfb5621084fce69acc20efaab73d9cae1737247a8e80f17cc62
 Status: Sent to network
 
-The transaction has been successfully sent! Alice transferred 1 ETH to Bob. The transaction hash is 0x0d7131d30ea1bcfb5621084fce69acc20efaab73d9cae1737247a8e80f17cc62 and it has been sent to the network for confirmation."
+The transaction has been successfully sent! {SENDER} transferred 1 ETH to {RECIPIENT}. The transaction hash is 0x0d7131d30ea1bcfb5621084fce69acc20efaab73d9cae1737247a8e80f17cc62 and it has been sent to the network for confirmation."
 
 IMPORTANT: When you receive ANY tool response, you MUST include the COMPLETE response text in your final answer. Do not summarize, do not say "provided above", do not omit any details. Copy the entire tool response exactly as received.
 
 Be helpful, accurate, and always use the blockchain tools to provide real data rather than making assumptions.
-"#.trim().to_string()
+"#.trim()
     }
 }
+
+/// Fill in a system prompt template with the default accounts and model name
+///
+/// Replaces `{SENDER}`, `{SENDER_INDEX}`, `{RECIPIENT}`, `{RECIPIENT_INDEX}` and
+/// `{MODEL}`; any other text, including unknown placeholders, is kept as is.
+pub fn render_system_prompt(template: &str, accounts: &DefaultAccounts, model: &str) -> String {
+    template
+        .replace("{SENDER_INDEX}", &accounts.sender_index.to_string())
+        .replace("{RECIPIENT_INDEX}", &accounts.recipient_index.to_string())
+        .replace("{SENDER}", &accounts.sender_display_name())
+        .replace("{RECIPIENT}", &accounts.recipient_display_name())
+        .replace("{MODEL}", model)
+}
@@ -0,0 +1,315 @@
+//! Tool-call audit log
+//!
+//! Writes one JSON object per MCP tool call (tool name, redacted arguments,
+//! result summary, duration and timestamp) to a JSONL file, giving a
+//! replayable record of what the agent did during a session. The log is fed
+//! by `AuditHook`, which rig calls around every tool execution.
+//!
+//! The same hook can also print a compact trace of each call as it happens
+//! (`--trace-tools`): `→ tool {args}` when Claude calls a tool and
+//! `← first line of result` when its result comes back.
+
+use rig::agent::{PromptHook, StreamingPromptHook};
+use rig::completion::CompletionModel;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+use tracing::warn;
+
+/// Placeholder written in place of redacted values
+pub const REDACTED: &str = "[REDACTED]";
+
+/// Maximum characters of a tool result kept in the audit record
+pub const RESULT_SUMMARY_CHARS: usize = 200;
+
+/// Maximum characters of a tool result's first line shown in the trace
+pub const TRACE_RESULT_CHARS: usize = 120;
+
+/// Argument names whose values are always redacted
+const SECRET_KEY_MARKERS: &[&str] = &["private_key", "privatekey", "secret", "mnemonic", "seed"];
+
+/// One audited tool call, serialized as a single JSONL line
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditRecord {
+    /// RFC 3339 time at which the call finished
+    pub timestamp: String,
+    /// MCP tool name
+    pub tool: String,
+    /// Tool arguments with private keys redacted
+    pub arguments: Value,
+    /// Whitespace-collapsed, truncated tool result
+    pub result_summary: String,
+    /// Wall-clock time between the call and its result
+    pub duration_ms: u64,
+}
+
+impl AuditRecord {
+    /// Build a record from the raw tool name, argument JSON and result rig reports
+    pub fn new(tool: &str, args: &str, result: &str, duration_ms: u64) -> Self {
+        let mut arguments = serde_json::from_str(args).unwrap_or_else(|_| Value::String(args.to_string()));
+        redact_arguments(&mut arguments);
+
+        // Some tools return keys rather than take them
+        let result_summary = if is_secret_key(tool) {
+            REDACTED.to_string()
+        } else {
+            summarize_result(result)
+        };
+
+        Self {
+            timestamp: chrono::Utc::now().to_rfc3339(),
+            tool: tool.to_string(),
+            arguments,
+            result_summary,
+            duration_ms,
+        }
+    }
+}
+
+/// Whether a name (argument key or tool name) refers to secret key material
+fn is_secret_key(name: &str) -> bool {
+    let name = name.to_lowercase().replace('-', "_");
+    SECRET_KEY_MARKERS.iter().any(|marker| name.contains(marker))
+}
+
+/// Whether a string has the shape of a raw private key (32 bytes of hex)
+///
+/// Transaction and code hashes share this shape, so callers skip values
+/// stored under hash-like keys.
+fn looks_like_private_key(value: &str) -> bool {
+    let hex = value.trim().strip_prefix("0x").unwrap_or(value.trim());
+    hex.len() == 64 && hex.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+/// Redact private keys from tool arguments in place
+///
+/// Values under secret-looking keys (e.g. `private_key`) are replaced
+/// wholesale; any other string that looks like a raw private key is replaced
+/// unless its key names a hash (e.g. `tx_hash`).
+pub fn redact_arguments(arguments: &mut Value) {
+    redact_value(arguments, None);
+}
+
+fn redact_value(value: &mut Value, key: Option<&str>) {
+    if let Some(key) = key && is_secret_key(key) && !value.is_null() {
+        *value = Value::String(REDACTED.to_string());
+        return;
+    }
+    match value {
+        Value::Object(map) => {
+            for (child_key, child) in map.iter_mut() {
+                redact_value(child, Some(child_key));
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                redact_value(item, key);
+            }
+        }
+        Value::String(text) => {
+            let is_hash = key.is_some_and(|key| key.to_lowercase().contains("hash"));
+            if !is_hash && looks_like_private_key(text) {
+                *value = Value::String(REDACTED.to_string());
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Collapse whitespace in a tool result and truncate it for the audit record
+pub fn summarize_result(result: &str) -> String {
+    let collapsed = result.split_whitespace().collect::<Vec<_>>().join(" ");
+    if collapsed.chars().count() <= RESULT_SUMMARY_CHARS {
+        return collapsed;
+    }
+    let truncated: String = collapsed.chars().take(RESULT_SUMMARY_CHARS).collect();
+    format!("{}…", truncated)
+}
+
+/// Trace line for a tool call: `→ tool {key: value, ...}` with private keys redacted
+pub fn format_trace_call(tool: &str, args: &str) -> String {
+    let mut arguments = match serde_json::from_str(args) {
+        Ok(arguments) => arguments,
+        Err(_) => return format!("→ {} {}", tool, args.trim()),
+    };
+    redact_arguments(&mut arguments);
+    let Value::Object(map) = arguments else {
+        return format!("→ {} {}", tool, arguments);
+    };
+    let fields: Vec<String> = map.iter()
+        .map(|(key, value)| match value {
+            Value::String(text) => format!("{}: {}", key, text),
+            other => format!("{}: {}", key, other),
+        })
+        .collect();
+    format!("→ {} {{{}}}", tool, fields.join(", "))
+}
+
+/// Fields of a structured tool result shown in its trace line, in display order
+const TRACE_RESULT_FIELDS: &[&str] = &["status", "balance", "tx_hash"];
+
+/// Trace line for a tool result: `← ` and its first non-empty line, truncated
+///
+/// Structured results lead with a pretty-printed JSON object, so for those the
+/// line is the first line of the summary after it, followed by the result's
+/// status, balance or transaction hash when it has them.
+pub fn format_trace_result(tool: &str, result: &str) -> String {
+    if is_secret_key(tool) {
+        return format!("← {}", REDACTED);
+    }
+    let line = trace_summary(result);
+    if line.chars().count() <= TRACE_RESULT_CHARS {
+        return format!("← {}", line);
+    }
+    let truncated: String = line.chars().take(TRACE_RESULT_CHARS).collect();
+    format!("← {}…", truncated)
+}
+
+fn trace_summary(result: &str) -> String {
+    let first_line = |text: &str| text.lines().map(str::trim).find(|line| !line.is_empty()).unwrap_or("").to_string();
+    let trimmed = result.trim_start();
+    if !trimmed.starts_with('{') {
+        return first_line(trimmed);
+    }
+    let mut values = serde_json::Deserializer::from_str(trimmed).into_iter::<Value>();
+    let Some(Ok(Value::Object(structured))) = values.next() else {
+        return first_line(trimmed);
+    };
+    let headline = first_line(&trimmed[values.byte_offset()..]);
+    let fields: Vec<String> = TRACE_RESULT_FIELDS.iter()
+        .filter_map(|key| match structured.get(*key)? {
+            Value::String(text) => Some(format!("{}: {}", key, text)),
+            value @ (Value::Number(_) | Value::Bool(_)) => Some(format!("{}: {}", key, value)),
+            _ => None,
+        })
+        .collect();
+    match (headline.is_empty(), fields.is_empty()) {
+        (false, true) => headline,
+        (false, false) => format!("{} ({})", headline, fields.join(", ")),
+        (true, false) => fields.join(", "),
+        (true, true) => Value::Object(structured).to_string(),
+    }
+}
+
+/// Append-only JSONL file of tool calls
+pub struct AuditLog {
+    file: Mutex<File>,
+    /// Start times of calls awaiting their result, keyed by tool name and raw arguments
+    pending: Mutex<HashMap<(String, String), Vec<Instant>>>,
+}
+
+impl AuditLog {
+    /// Open (or create) the audit log at `path`, appending to existing entries
+    pub fn open(path: &Path) -> crate::Result<Self> {
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map_err(|e| crate::ClientError::Config(format!("Failed to open audit log {}: {}", path.display(), e)))?;
+        Ok(Self {
+            file: Mutex::new(file),
+            pending: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// Append one record as a JSON line
+    pub fn record(&self, record: &AuditRecord) -> crate::Result<()> {
+        let line = serde_json::to_string(record)?;
+        let mut file = self.file.lock()
+            .map_err(|_| crate::ClientError::Config("Audit log lock poisoned".to_string()))?;
+        writeln!(file, "{}", line)?;
+        file.flush()?;
+        Ok(())
+    }
+
+    /// Note that a tool call started
+    pub fn start_call(&self, tool: &str, args: &str) {
+        if let Ok(mut pending) = self.pending.lock() {
+            pending.entry((tool.to_string(), args.to_string())).or_default().push(Instant::now());
+        }
+    }
+
+    /// Record a finished tool call, timing it from the matching `start_call`
+    pub fn finish_call(&self, tool: &str, args: &str, result: &str) {
+        let started = self.pending.lock().ok().and_then(|mut pending| {
+            let key = (tool.to_string(), args.to_string());
+            let starts = pending.get_mut(&key)?;
+            let started = (!starts.is_empty()).then(|| starts.remove(0));
+            if starts.is_empty() {
+                pending.remove(&key);
+            }
+            started
+        });
+        let duration_ms = started.map(|started| started.elapsed().as_millis() as u64).unwrap_or(0);
+
+        if let Err(e) = self.record(&AuditRecord::new(tool, args, result, duration_ms)) {
+            warn!("⚠️ Failed to write audit record for '{}': {}", tool, e);
+        }
+    }
+}
+
+/// rig prompt hook that feeds tool calls into an optional `AuditLog`
+///
+/// Without a log or tracing the hook does nothing, so the agent can always attach it.
+#[derive(Clone, Default)]
+pub struct AuditHook {
+    log: Option<Arc<AuditLog>>,
+    /// Print a trace line to stderr for every call and result
+    trace: bool,
+}
+
+impl AuditHook {
+    /// Hook writing to `log`, or a no-op hook for `None`
+    pub fn new(log: Option<Arc<AuditLog>>) -> Self {
+        Self { log, trace: false }
+    }
+
+    /// Also print `→` / `←` trace lines for each tool call
+    pub fn with_trace(mut self, trace: bool) -> Self {
+        self.trace = trace;
+        self
+    }
+
+    pub(crate) fn tool_called(&self, tool_name: &str, args: &str) {
+        if self.trace {
+            eprintln!("{}", format_trace_call(tool_name, args));
+        }
+        if let Some(log) = &self.log {
+            log.start_call(tool_name, args);
+        }
+    }
+
+    pub(crate) fn tool_returned(&self, tool_name: &str, args: &str, result: &str) {
+        if self.trace {
+            eprintln!("{}", format_trace_result(tool_name, result));
+        }
+        if let Some(log) = &self.log {
+            log.finish_call(tool_name, args, result);
+        }
+    }
+}
+
+impl<M: CompletionModel> PromptHook<M> for AuditHook {
+    async fn on_tool_call(&self, tool_name: &str, args: &str) {
+        self.tool_called(tool_name, args);
+    }
+
+    async fn on_tool_result(&self, tool_name: &str, args: &str, result: &str) {
+        self.tool_returned(tool_name, args, result);
+    }
+}
+
+impl<M: CompletionModel> StreamingPromptHook<M> for AuditHook {
+    async fn on_tool_call(&self, tool_name: &str, args: &str) {
+        self.tool_called(tool_name, args);
+    }
+
+    async fn on_tool_result(&self, tool_name: &str, args: &str, result: &str) {
+        self.tool_returned(tool_name, args, result);
+    }
+}
@@ -1,19 +1,391 @@
 //! CLI REPL interface for the RIG client
 
 use rustyline::{error::ReadlineError, DefaultEditor};
+use std::io::Write;
+use std::path::{Path, PathBuf};
 use tracing::error;
 
-use crate::{BlockchainAgent, Result};
+use crate::{BlockchainAgent, ClientError, Result, StreamEvent};
+
+/// Number of results written by `rag-search --save`
+pub const RAG_SEARCH_SAVE_LIMIT: usize = 5;
+
+/// Number of results per page when `rag-search` is given `--offset` without `--limit`
+pub const RAG_SEARCH_PAGE_LIMIT: usize = 5;
+
+/// Parsed `rag-search` arguments
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct RagSearchArgs {
+    pub query: String,
+    /// Markdown report path from `--save <path>`
+    pub save: Option<PathBuf>,
+    /// Page size from `--limit <n>`
+    pub limit: Option<usize>,
+    /// Rank of the first result from `--offset <n>`
+    pub offset: Option<usize>,
+}
+
+impl RagSearchArgs {
+    /// The `(limit, offset)` page to list, when `--limit` or `--offset` was given
+    pub fn page(&self) -> Option<(usize, usize)> {
+        if self.limit.is_none() && self.offset.is_none() {
+            return None;
+        }
+        Some((self.limit.unwrap_or(RAG_SEARCH_PAGE_LIMIT), self.offset.unwrap_or(0)))
+    }
+}
+
+/// Split `rag-search` arguments into the query and its `--save <path>`, `--limit <n>` and `--offset <n>` options
+///
+/// Options may appear anywhere after the command; everything else is the query.
+pub fn parse_rag_search_args(input: &str) -> std::result::Result<RagSearchArgs, String> {
+    let number = |flag: &str, value: Option<&str>| -> std::result::Result<usize, String> {
+        value.and_then(|value| value.parse().ok())
+            .ok_or_else(|| format!("{} needs a number, e.g. {} 5", flag, flag))
+    };
+    let mut words = input.split_whitespace().skip(1);
+    let mut query = Vec::new();
+    let mut args = RagSearchArgs::default();
+    while let Some(word) = words.next() {
+        match word {
+            "--save" => {
+                let path = words.next().ok_or("--save needs a path, e.g. --save results.md")?;
+                args.save = Some(PathBuf::from(path));
+            }
+            "--limit" => match number(word, words.next())? {
+                0 => return Err("--limit must be at least 1".to_string()),
+                limit => args.limit = Some(limit),
+            },
+            "--offset" => args.offset = Some(number(word, words.next())?),
+            _ => query.push(word),
+        }
+    }
+    if query.is_empty() {
+        return Err("Usage: rag-search [query] [--limit <n>] [--offset <n>] [--save <path.md>]".to_string());
+    }
+    args.query = query.join(" ");
+    Ok(args)
+}
+
+/// Commands in a script file with their 1-based line numbers
+///
+/// Blank lines and lines starting with `#` are skipped; commands are trimmed.
+pub fn parse_script(contents: &str) -> Vec<(usize, String)> {
+    contents.lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .map(|(number, line)| (number, line.to_string()))
+        .collect()
+}
+
+/// A built-in REPL command, as opposed to a request for the agent
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReplCommand {
+    Quit,
+    Help,
+    TestConnection,
+    SelfTest,
+    RagInit(Option<String>),
+    RagSearch(RagSearchArgs),
+    RagStatus,
+    RagStats { json: bool },
+    RagReindex,
+    RagExport(PathBuf),
+    RagExportStats,
+    AddressBookList,
+    AddressBookAdd { name: String, address: String },
+    RunScript { path: PathBuf, continue_on_error: bool },
+    Preview(String),
+    ApiStatus,
+    /// A built-in command with bad arguments, holding its usage message
+    Usage(String),
+}
+
+/// Recognize a built-in command, or `None` for input the agent should handle
+///
+/// The REPL and `run-script` both dispatch through this, so a script line
+/// behaves exactly as if it were typed at the prompt.
+pub fn parse_command(input: &str) -> Option<ReplCommand> {
+    let input = input.trim();
+    let lower = input.to_lowercase();
+    let parts: Vec<&str> = input.split_whitespace().collect();
+    let name = lower.split_whitespace().next().unwrap_or_default();
+    
+    let command = match (name, lower.as_str()) {
+        (_, "quit" | "exit" | "q") => ReplCommand::Quit,
+        (_, "help" | "h") => ReplCommand::Help,
+        (_, "test" | "test-connection") => ReplCommand::TestConnection,
+        (_, "self-test" | "selftest") => ReplCommand::SelfTest,
+        (_, "rag-status") => ReplCommand::RagStatus,
+        (_, "rag-stats") => ReplCommand::RagStats { json: false },
+        (_, "rag-stats --json") => ReplCommand::RagStats { json: true },
+        (_, "rag-reindex") => ReplCommand::RagReindex,
+        (_, "api-status" | "apis") => ReplCommand::ApiStatus,
+        ("rag-init", _) => ReplCommand::RagInit(parts.get(1).map(|path| path.to_string())),
+        ("rag-search", _) => match parse_rag_search_args(input) {
+            Ok(args) => ReplCommand::RagSearch(args),
+            Err(usage) => ReplCommand::Usage(usage),
+        },
+        ("rag-export", _) => match parts.get(1) {
+            Some(&"--stats") => ReplCommand::RagExportStats,
+            path => ReplCommand::RagExport(PathBuf::from(path.copied().unwrap_or("rag_export.json"))),
+        },
+        ("address-book", _) => match parts.as_slice() {
+            [_] | [_, "list"] => ReplCommand::AddressBookList,
+            [_, "add", name, address] => ReplCommand::AddressBookAdd { name: name.to_string(), address: address.to_string() },
+            _ => ReplCommand::Usage("Usage: address-book [list] | address-book add [name] [address]".to_string()),
+        },
+        ("run-script", _) => {
+            let args = &parts[1..];
+            let continue_on_error = args.contains(&"--continue-on-error");
+            let paths: Vec<&str> = args.iter().copied().filter(|arg| *arg != "--continue-on-error").collect();
+            match paths.as_slice() {
+                [path] => ReplCommand::RunScript { path: PathBuf::from(path), continue_on_error },
+                _ => ReplCommand::Usage("Usage: run-script [path] [--continue-on-error]".to_string()),
+            }
+        }
+        ("preview", _) => match input["preview".len()..].trim() {
+            "" => ReplCommand::Usage("Usage: preview [query]".to_string()),
+            query => ReplCommand::Preview(query.to_string()),
+        },
+        _ => return None,
+    };
+    Some(command)
+}
 
 /// CLI REPL interface for interacting with the blockchain agent
 pub struct Repl {
     agent: BlockchainAgent,
+    /// Preview every command's prompt instead of sending it to Claude
+    dry_prompt: bool,
 }
 
 impl Repl {
     /// Create a new REPL instance
     pub fn new(agent: BlockchainAgent) -> Self {
-        Self { agent }
+        Self { agent, dry_prompt: false }
+    }
+
+    /// Preview prompts for every command instead of calling Claude (`--dry-prompt`)
+    pub fn with_dry_prompt(mut self, dry_prompt: bool) -> Self {
+        self.dry_prompt = dry_prompt;
+        self
+    }
+
+    /// Run the commands in a script file, printing each command and its response
+    ///
+    /// Built-in commands (rag-search, rag-status, self-test, ...) run as they
+    /// would at the prompt and everything else goes to the agent; `quit` ends
+    /// the script early and `run-script` can't be nested. Stops at the first
+    /// failing command unless `continue_on_error` is set, and returns how many
+    /// commands failed.
+    pub async fn run_script(&mut self, path: &Path, continue_on_error: bool) -> Result<usize> {
+        let contents = std::fs::read_to_string(path)
+            .map_err(|e| ClientError::Cli(format!("Failed to read script {}: {}", path.display(), e)))?;
+        let commands = parse_script(&contents);
+        println!("📜 Running {} command(s) from {}\n", commands.len(), path.display());
+        
+        let mut failures = 0;
+        for (number, command) in &commands {
+            println!("▶️  [line {}] {}", number, command);
+            let outcome = match parse_command(command) {
+                Some(ReplCommand::Quit) => {
+                    println!("📜 quit on line {}, skipping the rest of the script\n", number);
+                    break;
+                }
+                Some(ReplCommand::RunScript { .. }) => {
+                    Err(Self::failed_message("run-script can't be used inside a script".to_string()))
+                }
+                Some(builtin) => self.execute(builtin).await,
+                None if self.dry_prompt => self.print_preview(command).await,
+                None => match self.agent.process_command(command).await {
+                    Ok(response) => {
+                        println!("{}", Self::format_response(&response));
+                        Ok(())
+                    }
+                    Err(e) => Err(Self::failed("Command failed", e)),
+                },
+            };
+            
+            if let Err(e) = outcome {
+                error!("❌ Script command on line {} failed: {}", number, e.report());
+                failures += 1;
+                if !continue_on_error {
+                    return Err(ClientError::Cli(format!(
+                        "Script {} stopped at line {} ({}): {}",
+                        path.display(), number, command, e
+                    )));
+                }
+            }
+        }
+        
+        println!("📜 Script finished: {} command(s), {} failed\n", commands.len(), failures);
+        Ok(failures)
+    }
+
+    /// Print a failed command's error and hand it back
+    fn failed(what: &str, e: ClientError) -> ClientError {
+        error!("❌ {}: {}", what, e.report());
+        println!("❌ {}: {}\n", what, e);
+        e
+    }
+
+    /// Print a failure that has no underlying error, such as a usage message
+    fn failed_message(message: String) -> ClientError {
+        println!("❌ {}\n", message);
+        ClientError::Cli(message)
+    }
+
+    /// Run a built-in command, printing its output
+    ///
+    /// `Quit` and `RunScript` are handled by the caller, which owns the input loop.
+    async fn execute(&mut self, command: ReplCommand) -> Result<()> {
+        let not_initialized = || Self::failed_message("RAG system not initialized. Use 'rag-init' to initialize.".to_string());
+        match command {
+            ReplCommand::Quit | ReplCommand::RunScript { .. } => Ok(()),
+            ReplCommand::Usage(usage) => Err(Self::failed_message(usage)),
+            ReplCommand::Help => {
+                Self::print_help();
+                Ok(())
+            }
+            ReplCommand::TestConnection => match self.agent.test_connection().await {
+                Ok(result) => {
+                    println!("🧪 {}\n", result);
+                    Ok(())
+                }
+                Err(e) => Err(Self::failed("Connection test failed", e)),
+            },
+            ReplCommand::SelfTest => {
+                println!("🧪 Running self-test against the MCP server...");
+                match self.agent.run_self_test().await {
+                    Ok(report) => {
+                        println!("{}\n", report);
+                        Ok(())
+                    }
+                    Err(e) => Err(Self::failed("Self-test failed", e)),
+                }
+            }
+            ReplCommand::RagInit(docs_path) => match self.agent.initialize_rag_system(docs_path.as_deref()).await {
+                Ok(()) => {
+                    println!("✅ RAG system initialized successfully!\n");
+                    Ok(())
+                }
+                Err(e) => Err(Self::failed("RAG initialization failed", e)),
+            },
+            ReplCommand::RagSearch(args) => {
+                let saved = match &args.save {
+                    Some(path) => match self.agent.save_search_results(&args.query, RAG_SEARCH_SAVE_LIMIT, path).await {
+                        Ok(count) => {
+                            println!("📝 Saved {} search results to {}\n", count, path.display());
+                            Ok(())
+                        }
+                        Err(e) => Err(Self::failed("Failed to save search results", e)),
+                    },
+                    None => Ok(()),
+                };
+                
+                // An explicit page lists the matching documents instead of asking the agent
+                if let Some((limit, offset)) = args.page() {
+                    return match self.agent.search_documentation(&args.query, limit, offset).await {
+                        Ok(page) => {
+                            println!("🔍 Documentation matching '{}':\n", args.query);
+                            for (rank, (score, id, doc)) in page.results.iter().enumerate() {
+                                println!("   {}. {} (score {:.3}) [{}]", page.offset + rank + 1, doc.title, score, id);
+                            }
+                            println!("\n📄 {}\n", page.footer());
+                            saved
+                        }
+                        Err(e) => Err(Self::failed("Failed to search documentation", e)),
+                    };
+                }
+                
+                println!("🔍 Processing RAG query: '{}'\n", args.query);
+                
+                // Let the agent handle the RAG query automatically
+                match self.agent.process_command(&args.query).await {
+                    Ok(response) => {
+                        println!("🤖 RAG-Enhanced Response:\n");
+                        println!("{}", Self::format_response(&response));
+                        saved
+                    }
+                    Err(e) => Err(Self::failed("Failed to process RAG query", e)),
+                }
+            }
+            ReplCommand::RagStatus => match self.agent.rag_status() {
+                Some(status) => {
+                    println!("📊 {}\n", status);
+                    Ok(())
+                }
+                None => Err(not_initialized()),
+            },
+            ReplCommand::RagStats { json } => match self.agent.rag_stats() {
+                Some(stats) if json => match serde_json::to_string_pretty(&stats) {
+                    Ok(json) => {
+                        println!("{}\n", json);
+                        Ok(())
+                    }
+                    Err(e) => Err(Self::failed_message(format!("Failed to serialize RAG stats: {}", e))),
+                },
+                Some(stats) => {
+                    println!("📊 RAG Index:\n{}\n", stats);
+                    Ok(())
+                }
+                None => Err(not_initialized()),
+            },
+            ReplCommand::RagReindex => {
+                println!("🔄 Re-ingesting documentation...");
+                match self.agent.reindex_rag_system().await {
+                    Ok((previous, current)) => {
+                        println!("✅ RAG index rebuilt: {} → {} documents\n", previous, current);
+                        Ok(())
+                    }
+                    Err(e) => Err(Self::failed("RAG reindex failed", e)),
+                }
+            }
+            ReplCommand::RagExportStats => match self.agent.rag_corpus_stats() {
+                Some(stats) => {
+                    println!("📊 RAG Corpus:\n{}\n", stats);
+                    Ok(())
+                }
+                None => Err(not_initialized()),
+            },
+            ReplCommand::RagExport(path) => match self.agent.export_rag_documents(&path) {
+                Ok(count) => {
+                    println!("📤 Exported {} documents to {}\n", count, path.display());
+                    Ok(())
+                }
+                Err(e) => Err(Self::failed("RAG export failed", e)),
+            },
+            ReplCommand::AddressBookList => self.address_book("list_address_book", None).await,
+            ReplCommand::AddressBookAdd { name, address } => {
+                let mut arguments = serde_json::Map::new();
+                arguments.insert("name".to_string(), name.into());
+                arguments.insert("address".to_string(), address.into());
+                self.address_book("add_address_book_entry", Some(arguments)).await
+            }
+            ReplCommand::Preview(query) => self.print_preview(&query).await,
+            ReplCommand::ApiStatus => {
+                println!("🔧 API Usage Status:\n");
+                println!("🌐 **Brave Search API**: Available for web searches and real-time data");
+                println!("   • Used for: Token prices, contract info, web searches");
+                println!("   • Triggers: 'web_search', 'get_token_price', 'get_contract_info'");
+                println!("   • Example: 'What is the current ETH price?'\n");
+                
+                println!("🔗 **MCP Server**: Available for blockchain operations");
+                println!("   • Used for: ETH transfers, balance checks, contract deployment");
+                println!("   • Tools: send_eth, token_balance, is_contract_deployed");
+                println!("   • Example: 'send 1 ETH to Bob'\n");
+                
+                println!("📚 **RAG System**: Available for Uniswap documentation");
+                println!("   • Used for: Uniswap questions, contract documentation");
+                println!("   • Command: 'rag-search [query]'");
+                println!("   • Example: 'rag-search how does Uniswap V2 work?'\n");
+                
+                println!("💡 **Tip**: Look for '[BRAVE API]' in logs or '🌐 [Used Brave Search API]' in responses\n");
+                Ok(())
+            }
+        }
     }
 
     /// Start the interactive REPL
@@ -22,6 +394,14 @@ impl Repl {
             .map_err(|e| crate::ClientError::Cli(format!("Failed to create editor: {}", e)))?;
         
         println!("\n🔥 Ethereum AI Agent Ready!");
+        if self.dry_prompt {
+            println!("🔍 Running with --dry-prompt: commands show the assembled prompt and Claude is not called");
+        }
+        if !self.agent.has_mcp() {
+            println!("⚠️  Running with --no-mcp: blockchain tools are disabled (general and RAG questions only)");
+        } else if self.agent.fast_path() {
+            println!("⚡ Running with --fast-path: simple sends, balance and contract checks skip Claude");
+        }
         println!("💡 Try these PRD commands:");
         println!("   • send 1 ETH from Alice to Bob");
         println!("   • send 0.5 ETH to Bob");
@@ -32,6 +412,7 @@ impl Repl {
         println!("   • rag-init [path] - Initialize RAG system with documentation");
         println!("   • rag-search [query] - Ask questions about Uniswap (with automatic RAG)");
         println!("   • rag-status - Show RAG system status");
+        println!("   • preview [query] - Show the prompt sent to Claude without calling it");
         println!("   • Type 'help' for more commands\n");
 
         loop {
@@ -48,118 +429,47 @@ impl Repl {
                         error!("Failed to add to history: {}", e);
                     }
                     
-                    // Handle exit commands
-                    if matches!(input.to_lowercase().as_str(), "quit" | "exit" | "q") {
-                        println!("👋 Goodbye!");
-                        break;
-                    }
-                    
-                    // Handle help
-                    if matches!(input.to_lowercase().as_str(), "help" | "h") {
-                        Self::print_help();
-                        continue;
-                    }
-                    
-                    // Handle test command
-                    if matches!(input.to_lowercase().as_str(), "test" | "test-connection") {
-                        match self.agent.test_connection().await {
-                            Ok(result) => {
-                                println!("🧪 {}\n", result);
-                            }
-                            Err(e) => {
-                                error!("❌ Connection test failed: {}", e);
-                                println!("❌ Connection test failed: {}\n", e);
-                            }
+                    // Built-in commands
+                    match parse_command(input) {
+                        Some(ReplCommand::Quit) => {
+                            println!("👋 Goodbye!");
+                            break;
                         }
-                        continue;
-                    }
-                    
-                    // Handle RAG initialization
-                    if input.to_lowercase().starts_with("rag-init") {
-                        let parts: Vec<&str> = input.split_whitespace().collect();
-                        let docs_path = if parts.len() > 1 { Some(parts[1]) } else { None };
-                        
-                        match self.agent.initialize_rag_system(docs_path).await {
-                            Ok(()) => {
-                                println!("✅ RAG system initialized successfully!\n");
+                        Some(ReplCommand::RunScript { path, continue_on_error }) => {
+                            if let Err(e) = self.run_script(&path, continue_on_error).await {
+                                println!("❌ {}\n", e);
                             }
-                            Err(e) => {
-                                error!("❌ RAG initialization failed: {}", e);
-                                println!("❌ RAG initialization failed: {}\n", e);
-                            }
-                        }
-                        continue;
-                    }
-                    
-                    // Handle RAG search (now integrated with agent)
-                    if input.to_lowercase().starts_with("rag-search") {
-                        let parts: Vec<&str> = input.split_whitespace().collect();
-                        if parts.len() < 2 {
-                            println!("❌ Usage: rag-search [query]\n");
                             continue;
                         }
-                        
-                        let query = parts[1..].join(" ");
-                        println!("🔍 Processing RAG query: '{}'\n", query);
-                        
-                        // Let the agent handle the RAG query automatically
-                        match self.agent.process_command(&query).await {
-                            Ok(response) => {
-                                println!("🤖 RAG-Enhanced Response:\n");
-                                println!("{}", Self::format_response(&response));
-                            }
-                            Err(e) => {
-                                error!("❌ Failed to process RAG query: {}", e);
-                                println!("❌ Failed to process RAG query: {}\n", e);
-                            }
-                        }
-                        continue;
-                    }
-                    
-                    // Handle RAG status
-                    if matches!(input.to_lowercase().as_str(), "rag-status") {
-                        match self.agent.rag_status() {
-                            Some(status) => {
-                                println!("📊 {}\n", status);
-                            }
-                            None => {
-                                println!("❌ RAG system not initialized. Use 'rag-init' to initialize.\n");
-                            }
+                        Some(command) => {
+                            // Failures are already printed
+                            let _ = self.execute(command).await;
+                            continue;
                         }
-                        continue;
+                        None => {}
                     }
                     
-                    // Handle API status
-                    if matches!(input.to_lowercase().as_str(), "api-status" | "apis") {
-                        println!("🔧 API Usage Status:\n");
-                        println!("🌐 **Brave Search API**: Available for web searches and real-time data");
-                        println!("   • Used for: Token prices, contract info, web searches");
-                        println!("   • Triggers: 'web_search', 'get_token_price', 'get_contract_info'");
-                        println!("   • Example: 'What is the current ETH price?'\n");
-                        
-                        println!("🔗 **MCP Server**: Available for blockchain operations");
-                        println!("   • Used for: ETH transfers, balance checks, contract deployment");
-                        println!("   • Tools: send_eth, token_balance, is_contract_deployed");
-                        println!("   • Example: 'send 1 ETH to Bob'\n");
-                        
-                        println!("📚 **RAG System**: Available for Uniswap documentation");
-                        println!("   • Used for: Uniswap questions, contract documentation");
-                        println!("   • Command: 'rag-search [query]'");
-                        println!("   • Example: 'rag-search how does Uniswap V2 work?'\n");
-                        
-                        println!("💡 **Tip**: Look for '[BRAVE API]' in logs or '🌐 [Used Brave Search API]' in responses\n");
+                    if self.dry_prompt {
+                        let _ = self.print_preview(input).await;
                         continue;
                     }
                     
-                    // Process user input with Claude
-                    match self.agent.process_command(input).await {
-                        Ok(response) => {
-                            // Format the response for better readability
-                            let formatted_response = Self::format_response(&response);
-                            println!("{}", formatted_response);
+                    // Process user input with Claude, printing tokens as they stream in
+                    println!("🤖 Response:");
+                    println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+                    let result = self.agent.process_command_streaming(input, |event| {
+                        match event {
+                            StreamEvent::Text(text) => print!("{}", text),
+                            StreamEvent::ToolCall(name) => print!("\n🔧 calling {}...\n", name),
+                        }
+                        let _ = std::io::stdout().flush();
+                    }).await;
+                    match result {
+                        Ok(_response) => {
+                            println!("\n━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━\n");
                         }
                         Err(e) => {
-                            error!("❌ Error processing command: {}", e);
+                            error!("❌ Error processing command: {}", e.report());
                             
                             // Provide more helpful error messages for common issues
                             let error_msg = e.to_string();
@@ -250,6 +560,28 @@ impl Repl {
         formatted
     }
 
+    /// Call an address book tool on the MCP server and print its reply
+    async fn address_book(&self, tool: &str, arguments: Option<serde_json::Map<String, serde_json::Value>>) -> Result<()> {
+        match self.agent.call_mcp_tool(tool, arguments).await {
+            Ok(text) => {
+                println!("📒 {}\n", text);
+                Ok(())
+            }
+            Err(e) => Err(Self::failed("Address book command failed", e)),
+        }
+    }
+
+    /// Print the prompt that would be sent to Claude for `query`
+    async fn print_preview(&self, query: &str) -> Result<()> {
+        match self.agent.preview_prompt(query).await {
+            Ok(preview) => {
+                println!("🔍 {}\n", preview);
+                Ok(())
+            }
+            Err(e) => Err(Self::failed("Prompt preview failed", e)),
+        }
+    }
+
     /// Print help information
     fn print_help() {
         println!("\n📚 Available Commands:");
@@ -258,10 +590,29 @@ impl Repl {
         println!("    • send [amount] ETH to [recipient] (Alice is default sender)");
         println!("    • How much [token] does [address] have?");
         println!("    • Is [contract name] deployed?");
+        println!("  \n  Fast Path (--fast-path, answered without Claude):");
+        println!("    • send [amount] ETH [from [sender]] to [recipient]");
+        println!("    • How much [ETH|USDC|USDT|DAI|WETH|WBTC|UNI|LINK] does [account] have?");
+        println!("    • balance of [account], [account]'s balance");
+        println!("    • Is [name] ([address]) deployed?");
+        println!("    • Anything else, or an account Claude has to look up, still goes to Claude");
         println!("  \n  RAG System (Bonus Part 2):");
         println!("    • rag-init [path] - Initialize RAG system with documentation");
         println!("    • rag-search [query] - Search Uniswap documentation");
+        println!("    • rag-search [query] --save [path.md] - Also write the top results to a markdown report");
+        println!("    • rag-search [query] --limit [n] --offset [n] - List one page of matching documents");
         println!("    • rag-status - Show RAG system status");
+        println!("    • rag-stats [--json] - Show index health: chunks, types, versions, embedding size and memory");
+        println!("    • rag-reindex - Re-ingest edited documentation without restarting");
+        println!("    • rag-export [path] - Write indexed documents to JSON (default: rag_export.json)");
+        println!("    • rag-export --stats - Show corpus composition");
+        println!("    • preview [query] - Show the prompt and RAG context sent to Claude, without calling it");
+        println!("  \n  Scripts:");
+        println!("    • run-script [path] - Run the commands in a file, one per line ('#' starts a comment)");
+        println!("    • run-script [path] --continue-on-error - Keep going after a failing command");
+        println!("  \n  Address Book:");
+        println!("    • address-book [list] - Show named addresses usable as recipients");
+        println!("    • address-book add [name] [address] - Save a named address (persisted by the MCP server)");
         println!("  \n  API Information:");
         println!("    • api-status, apis - Show which APIs are being used");
         println!("    • Look for '[BRAVE API]' in logs or '🌐 [Used Brave Search API]' in responses");
@@ -272,6 +623,7 @@ impl Repl {
         println!("  \n  General:");
         println!("    • help, h - Show this help");
         println!("    • test, test-connection - Test MCP connection");
+        println!("    • self-test - Call each core blockchain tool once and report pass/fail (wraps 0.001 ETH when a key is set)");
         println!("    • quit, exit, q - Exit the program");
         println!("  \n  PRD Examples:");
         println!("    • send 1 ETH from Alice to Bob");
@@ -1,7 +1,193 @@
 //! Configuration management for the RIG client
 
 use clap::Parser;
+use rig::providers::anthropic::{
+    CLAUDE_3_5_HAIKU, CLAUDE_3_5_SONNET, CLAUDE_3_7_SONNET, CLAUDE_3_HAIKU, CLAUDE_3_OPUS,
+    CLAUDE_3_SONNET, CLAUDE_4_OPUS, CLAUDE_4_SONNET,
+};
 use std::env;
+use std::path::Path;
+use tracing::warn;
+
+/// Claude models exposed by rig's Anthropic provider
+pub const SUPPORTED_MODELS: &[&str] = &[
+    CLAUDE_4_OPUS,
+    CLAUDE_4_SONNET,
+    CLAUDE_3_7_SONNET,
+    CLAUDE_3_5_SONNET,
+    CLAUDE_3_5_HAIKU,
+    CLAUDE_3_OPUS,
+    CLAUDE_3_SONNET,
+    CLAUDE_3_HAIKU,
+];
+
+/// Dated snapshot IDs of the supported models, accepted alongside rig's aliases
+pub const SUPPORTED_MODEL_SNAPSHOTS: &[&str] = &[
+    "claude-opus-4-20250514",
+    "claude-sonnet-4-20250514",
+    "claude-3-7-sonnet-20250219",
+    "claude-3-5-sonnet-20241022",
+    "claude-3-5-sonnet-20240620",
+    "claude-3-5-haiku-20241022",
+    "claude-3-opus-20240229",
+    "claude-3-sonnet-20240229",
+    "claude-3-haiku-20240307",
+];
+
+/// Model parameters used to build the Claude agent
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModelSettings {
+    /// Claude model identifier
+    pub model: String,
+    /// Sampling temperature
+    pub temperature: f64,
+    /// Maximum tokens per completion
+    pub max_tokens: u64,
+    /// Maximum tool-call rounds per command before the agent stops
+    pub max_turns: usize,
+    /// Default sender and recipient named in the system prompt
+    pub accounts: DefaultAccounts,
+    /// System prompt template loaded from `--system-prompt`, replacing the built-in one
+    pub system_prompt: Option<String>,
+}
+
+/// Output format of the tracing logs (`--log-format` or `RUST_LOG_FORMAT`)
+///
+/// Only tracing output changes; the REPL's own output is always plain text.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LogFormat {
+    /// Human-readable lines, for interactive use
+    #[default]
+    Pretty,
+    /// One JSON object per line, for log aggregation
+    Json,
+}
+
+impl std::str::FromStr for LogFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "pretty" | "text" => Ok(Self::Pretty),
+            "json" => Ok(Self::Json),
+            other => Err(format!("unknown log format '{}': expected 'pretty' or 'json'", other)),
+        }
+    }
+}
+
+/// Default number of tool-call rounds allowed per command
+pub const DEFAULT_MAX_TURNS: usize = 5;
+
+impl Default for ModelSettings {
+    fn default() -> Self {
+        Self {
+            model: CLAUDE_3_HAIKU.to_string(),
+            temperature: 0.1, // Low temperature for consistent responses
+            max_tokens: 4096, // Maximum allowed for Claude 3 Haiku
+            max_turns: DEFAULT_MAX_TURNS,
+            accounts: DefaultAccounts::default(),
+            system_prompt: None,
+        }
+    }
+}
+
+/// Default sender and recipient account names and anvil indices
+///
+/// Mirrors the MCP server's `DEFAULT_SENDER_*` / `DEFAULT_RECIPIENT_*` settings so
+/// the system prompt talks about the same accounts the server resolves.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DefaultAccounts {
+    pub sender: String,
+    pub sender_index: usize,
+    pub recipient: String,
+    pub recipient_index: usize,
+}
+
+impl Default for DefaultAccounts {
+    fn default() -> Self {
+        Self {
+            sender: "alice".to_string(),
+            sender_index: 0,
+            recipient: "bob".to_string(),
+            recipient_index: 1,
+        }
+    }
+}
+
+impl DefaultAccounts {
+    /// Sender name with its first letter capitalized (e.g. "Alice")
+    pub fn sender_display_name(&self) -> String {
+        capitalize(&self.sender)
+    }
+
+    /// Recipient name with its first letter capitalized (e.g. "Bob")
+    pub fn recipient_display_name(&self) -> String {
+        capitalize(&self.recipient)
+    }
+}
+
+fn capitalize(name: &str) -> String {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Resolve a requested model name, falling back to Claude 3 Haiku for unknown values
+///
+/// Accepts exactly the model IDs rig exposes and their dated snapshots in
+/// `SUPPORTED_MODEL_SNAPSHOTS` (e.g. `claude-3-5-sonnet-20241022`); anything
+/// else is refused with the supported list rather than failing later at the API.
+pub fn resolve_model(requested: &str) -> String {
+    let requested = requested.trim();
+    if SUPPORTED_MODELS.contains(&requested) || SUPPORTED_MODEL_SNAPSHOTS.contains(&requested) {
+        return requested.to_string();
+    }
+    warn!(
+        "⚠️ Unknown Claude model '{}', falling back to {}. Supported models: {}, {}",
+        requested, CLAUDE_3_HAIKU, SUPPORTED_MODELS.join(", "), SUPPORTED_MODEL_SNAPSHOTS.join(", ")
+    );
+    CLAUDE_3_HAIKU.to_string()
+}
+
+/// Custom system prompts shorter than this have probably lost the tool instructions
+pub const MIN_SYSTEM_PROMPT_CHARS: usize = 200;
+
+/// Read a system prompt template from a file
+///
+/// An empty (or whitespace-only) file is rejected; a suspiciously short one is
+/// accepted with a warning, since it likely drops the tool-usage guidance.
+pub fn load_system_prompt(path: &Path) -> crate::Result<String> {
+    let template = std::fs::read_to_string(path)
+        .map_err(|e| crate::ClientError::Config(format!("Failed to read system prompt {}: {}", path.display(), e)))?;
+    let template = template.trim();
+    if template.is_empty() {
+        return Err(crate::ClientError::Config(format!("System prompt file {} is empty", path.display())));
+    }
+    
+    let chars = template.chars().count();
+    if chars < MIN_SYSTEM_PROMPT_CHARS {
+        warn!("⚠️ System prompt from {} is only {} characters - it may be missing the tool instructions", path.display(), chars);
+    }
+    Ok(template.to_string())
+}
+
+/// clap parser for `--embedding-model`, rejecting unknown models with the supported list
+fn parse_embedding_model_name(name: &str) -> Result<String, String> {
+    crate::rag::parse_embedding_model(name)
+        .map(|(canonical, _)| canonical.to_string())
+        .map_err(|e| e.to_string())
+}
+
+/// clap parser for `--rag-dedup-threshold`, rejecting similarities outside 0.0-1.0
+fn parse_dedup_threshold(value: &str) -> Result<f64, String> {
+    let threshold: f64 = value.trim().parse().map_err(|_| format!("'{}' is not a number", value))?;
+    if !(0.0..=1.0).contains(&threshold) {
+        return Err(format!("{} is outside 0.0-1.0 (cosine similarity)", threshold));
+    }
+    Ok(threshold)
+}
 
 /// CLI arguments and configuration
 #[derive(Parser, Debug)]
@@ -13,9 +199,108 @@ pub struct Config {
     #[arg(short, long)]
     pub verbose: bool,
     
-    /// MCP server URL (default: local)
-    #[arg(long, default_value = "http://127.0.0.1:8080/mcp")]
-    pub mcp_server: String,
+    /// Log output format: pretty or json (falls back to RUST_LOG_FORMAT, then pretty)
+    #[arg(long, value_name = "FORMAT")]
+    pub log_format: Option<LogFormat>,
+    
+    /// MCP server URL(s) (default: local); repeat the flag or separate with commas
+    /// to compose several servers, e.g. blockchain and search tools
+    #[arg(long, default_value = "http://127.0.0.1:8080/mcp", value_delimiter = ',')]
+    pub mcp_server: Vec<String>,
+    
+    /// Connection attempts before giving up on the MCP server
+    #[arg(long, default_value_t = 5)]
+    pub mcp_connect_attempts: u32,
+    
+    /// Delay before the first MCP reconnect in milliseconds; doubled after each failure
+    #[arg(long, default_value_t = 500)]
+    pub mcp_retry_delay_ms: u64,
+    
+    /// Print the assembled prompt for every command instead of calling Claude
+    #[arg(long)]
+    pub dry_prompt: bool,
+    
+    /// Run without the MCP server: general and RAG questions only, no blockchain tools
+    #[arg(long)]
+    pub no_mcp: bool,
+    
+    /// Answer simple sends, balance questions and contract checks (e.g. "send 1 ETH to Bob") by calling
+    /// the MCP tool directly, without Claude; anything the parser doesn't recognise still goes to Claude
+    #[arg(long)]
+    pub fast_path: bool,
+    
+    /// Minimum relevance score (0.0-1.0) for RAG results to be added to a prompt
+    #[arg(long, default_value_t = crate::rag::DEFAULT_MIN_RELEVANCE)]
+    pub rag_min_score: f64,
+    
+    /// Character budget for documentation excerpts added to a prompt; lowest-scoring excerpts are dropped first
+    #[arg(long, default_value_t = crate::rag::DEFAULT_CONTEXT_BUDGET_CHARS)]
+    pub rag_context_chars: usize,
+    
+    /// Cosine similarity (0.0-1.0) above which near-identical chunks are dropped when indexing; 1.0 only drops identical content
+    #[arg(long, default_value_t = crate::rag::DEFAULT_DEDUP_THRESHOLD, value_parser = parse_dedup_threshold)]
+    pub rag_dedup_threshold: f64,
+    
+    /// Longest chunk, in characters, sent to the embedding model; longer chunks are split before indexing
+    #[arg(long, default_value_t = crate::rag::DEFAULT_MAX_EMBED_CHARS)]
+    pub rag_max_chunk_chars: usize,
+    
+    /// Fastembed model for RAG embeddings (e.g. BGEBaseENV15 for better recall); the index is rebuilt when it changes
+    #[arg(long, default_value = crate::rag::DEFAULT_EMBEDDING_MODEL, value_parser = parse_embedding_model_name)]
+    pub embedding_model: String,
+    
+    /// Claude model to use (e.g. claude-3-5-sonnet-20241022)
+    #[arg(long, default_value = CLAUDE_3_HAIKU)]
+    pub model: String,
+    
+    /// Sampling temperature for Claude
+    #[arg(long, default_value_t = 0.1)]
+    pub temperature: f64,
+    
+    /// Maximum tokens per Claude completion
+    #[arg(long, default_value_t = 4096)]
+    pub max_tokens: u64,
+    
+    /// Maximum tool-call rounds per command (e.g. resolve → balance → swap → confirm)
+    #[arg(long, default_value_t = DEFAULT_MAX_TURNS)]
+    pub max_turns: usize,
+    
+    /// Name of the default sender (falls back to DEFAULT_SENDER_NAME, then alice)
+    #[arg(long)]
+    pub sender_name: Option<String>,
+    
+    /// Anvil account index of the default sender (falls back to DEFAULT_SENDER_INDEX, then 0)
+    #[arg(long)]
+    pub sender_index: Option<usize>,
+    
+    /// Name of the default recipient (falls back to DEFAULT_RECIPIENT_NAME, then bob)
+    #[arg(long)]
+    pub recipient_name: Option<String>,
+    
+    /// Anvil account index of the default recipient (falls back to DEFAULT_RECIPIENT_INDEX, then 1)
+    #[arg(long)]
+    pub recipient_index: Option<usize>,
+    
+    /// Append a JSON line per tool call (tool, arguments, result summary, duration) to this file
+    #[arg(long, value_name = "PATH")]
+    pub audit_log: Option<std::path::PathBuf>,
+    
+    /// Print a `→ tool {args}` / `← result` line for each MCP tool call, showing which tools Claude chose and in what order
+    #[arg(long)]
+    pub trace_tools: bool,
+    
+    /// Read the system prompt from this file instead of the built-in one; {SENDER}, {SENDER_INDEX},
+    /// {RECIPIENT}, {RECIPIENT_INDEX} and {MODEL} are filled in from the settings above
+    #[arg(long, value_name = "PATH")]
+    pub system_prompt: Option<std::path::PathBuf>,
+    
+    /// Run the commands in this file (one per line, `#` for comments) instead of the interactive REPL
+    #[arg(long, value_name = "PATH")]
+    pub script: Option<std::path::PathBuf>,
+    
+    /// Keep running a --script after a command fails instead of stopping
+    #[arg(long, requires = "script")]
+    pub continue_on_error: bool,
 }
 
 impl Default for Config {
@@ -39,9 +324,91 @@ impl Config {
         }
     }
 
+    /// Get the log format from `--log-format`, then `RUST_LOG_FORMAT`
+    ///
+    /// Called before logging is set up, so an unknown `RUST_LOG_FORMAT` is reported on stderr.
+    pub fn log_format(&self) -> LogFormat {
+        if let Some(format) = self.log_format {
+            return format;
+        }
+        match env::var("RUST_LOG_FORMAT") {
+            Ok(value) if !value.trim().is_empty() => value.parse().unwrap_or_else(|e| {
+                eprintln!("⚠️ {} in RUST_LOG_FORMAT, using pretty", e);
+                LogFormat::Pretty
+            }),
+            _ => LogFormat::Pretty,
+        }
+    }
+
+    /// Get the validated model settings for the Claude agent
+    pub fn model_settings(&self) -> ModelSettings {
+        ModelSettings {
+            model: resolve_model(&self.model),
+            temperature: self.temperature,
+            max_tokens: self.max_tokens,
+            max_turns: self.max_turns.max(1),
+            accounts: self.default_accounts(),
+            system_prompt: None,
+        }
+    }
+
+    /// Load the `--system-prompt` template, if one was given
+    pub fn system_prompt_template(&self) -> crate::Result<Option<String>> {
+        self.system_prompt.as_deref().map(load_system_prompt).transpose()
+    }
+
+    /// Default sender and recipient from the CLI flags, then the shared `.env` settings
+    pub fn default_accounts(&self) -> DefaultAccounts {
+        let defaults = DefaultAccounts::default();
+        let env_value = |key: &str| env::var(key).ok().map(|value| value.trim().to_string()).filter(|value| !value.is_empty());
+        let name = |flag: &Option<String>, key: &str, default: String| {
+            flag.clone().or_else(|| env_value(key)).map(|name| name.trim().to_lowercase()).filter(|name| !name.is_empty()).unwrap_or(default)
+        };
+        let index = |flag: Option<usize>, key: &str, default: usize| {
+            flag.or_else(|| env_value(key).and_then(|value| value.parse().ok())).unwrap_or(default)
+        };
+        
+        let accounts = DefaultAccounts {
+            sender: name(&self.sender_name, "DEFAULT_SENDER_NAME", defaults.sender.clone()),
+            sender_index: index(self.sender_index, "DEFAULT_SENDER_INDEX", defaults.sender_index),
+            recipient: name(&self.recipient_name, "DEFAULT_RECIPIENT_NAME", defaults.recipient.clone()),
+            recipient_index: index(self.recipient_index, "DEFAULT_RECIPIENT_INDEX", defaults.recipient_index),
+        };
+        if accounts.sender == accounts.recipient || accounts.sender_index == accounts.recipient_index {
+            warn!("⚠️ Default sender and recipient must differ, using alice (account 0) and bob (account 1)");
+            return defaults;
+        }
+        accounts
+    }
+
+    /// Delay before the first MCP reconnect attempt
+    pub fn mcp_retry_delay(&self) -> std::time::Duration {
+        std::time::Duration::from_millis(self.mcp_retry_delay_ms)
+    }
+
     /// Get the Claude API key from environment
     pub fn anthropic_api_key(&self) -> crate::Result<String> {
-        env::var("ANTHROPIC_API_KEY")
-            .map_err(|_| crate::ClientError::MissingEnvVar("ANTHROPIC_API_KEY".to_string()))
+        anthropic_api_key_from_env()
+    }
+}
+
+/// Read ANTHROPIC_API_KEY, failing on a missing or blank key before any Claude client is built
+pub fn anthropic_api_key_from_env() -> crate::Result<String> {
+    validate_anthropic_api_key(env::var("ANTHROPIC_API_KEY").ok())
+}
+
+/// Check a Claude API key is present and non-empty, returning it trimmed
+///
+/// A client built with an empty key only fails at the first request, with an
+/// opaque 401, so the key is rejected up front with a hint instead.
+pub fn validate_anthropic_api_key(key: Option<String>) -> crate::Result<String> {
+    match key.as_deref().map(str::trim) {
+        None => Err(crate::ClientError::Config(
+            "ANTHROPIC_API_KEY is not set - export your Claude API key (or add it to .env) before starting the agent".to_string()
+        )),
+        Some("") => Err(crate::ClientError::Config(
+            "ANTHROPIC_API_KEY is empty - set it to your Claude API key before starting the agent".to_string()
+        )),
+        Some(key) => Ok(key.to_string()),
     }
 }
@@ -5,12 +5,21 @@ use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use sha2::{Sha256, Digest};
+use std::future::Future;
+use std::time::Duration;
 use thiserror::Error;
+use tracing::warn;
 
 #[derive(Error, Debug)]
 pub enum IngestionError {
     #[error("Failed to fetch document: {0}")]
     FetchError(String),
+    /// Transient network failure (timeout, dropped connection); worth retrying
+    #[error("Network error: {0}")]
+    NetworkError(String),
+    /// The source or document does not exist; retrying will not help
+    #[error("Not found: {0}")]
+    NotFound(String),
     #[error("Failed to process document: {0}")]
     ProcessingError(String),
     #[error("Document validation failed: {0}")]
@@ -19,6 +28,13 @@ pub enum IngestionError {
     StorageError(String),
 }
 
+impl IngestionError {
+    /// Whether the operation that produced this error may succeed if repeated
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, IngestionError::NetworkError(_))
+    }
+}
+
 /// Represents a source of documentation (Git repo, local filesystem, S3, etc.)
 #[async_trait]
 pub trait DocumentSource: Send + Sync {
@@ -166,8 +182,25 @@ pub trait DocumentProcessor: Send + Sync {
     async fn process(&self, doc: RawDocument) -> Result<ProcessedDocument, IngestionError>;
 }
 
+/// Default number of trailing characters from the previous chunk carried into the next one
+pub const DEFAULT_CHUNK_OVERLAP: usize = 200;
+
+/// Default maximum chunk size in characters (including overlap)
+pub const DEFAULT_MAX_CHUNK_SIZE: usize = 1500;
+
 /// Default document processor implementation
-pub struct DefaultDocumentProcessor;
+pub struct DefaultDocumentProcessor {
+    /// Characters of the previous chunk prepended to each chunk for context
+    chunk_overlap: usize,
+    /// Upper bound on chunk length; larger sections are split further
+    max_chunk_size: usize,
+}
+
+impl Default for DefaultDocumentProcessor {
+    fn default() -> Self {
+        Self::new(DEFAULT_CHUNK_OVERLAP, DEFAULT_MAX_CHUNK_SIZE)
+    }
+}
 
 #[async_trait]
 impl DocumentProcessor for DefaultDocumentProcessor {
@@ -182,8 +215,8 @@ impl DocumentProcessor for DefaultDocumentProcessor {
         // Create semantic chunks based on document type
         let chunks = match doc.metadata.doc_type {
             DocumentType::Solidity => self.chunk_solidity(&content),
-            DocumentType::Markdown => self.chunk_markdown(&content),
-            _ => vec![content.clone()],
+            DocumentType::Markdown => self.apply_window(self.chunk_markdown(&content)),
+            _ => self.apply_window(vec![content.clone()]),
         };
         
         Ok(ProcessedDocument {
@@ -196,25 +229,142 @@ impl DocumentProcessor for DefaultDocumentProcessor {
 }
 
 impl DefaultDocumentProcessor {
+    /// Create a processor with the given overlap and maximum chunk size (in characters)
+    ///
+    /// The overlap is clamped to half the maximum size so every chunk keeps
+    /// some content of its own.
+    pub fn new(chunk_overlap: usize, max_chunk_size: usize) -> Self {
+        let max_chunk_size = max_chunk_size.max(1);
+        Self {
+            chunk_overlap: chunk_overlap.min(max_chunk_size / 2),
+            max_chunk_size,
+        }
+    }
+
+    pub fn chunk_overlap(&self) -> usize {
+        self.chunk_overlap
+    }
+
+    pub fn max_chunk_size(&self) -> usize {
+        self.max_chunk_size
+    }
+
+    /// Split oversized sections and carry the tail of each chunk into the next
+    ///
+    /// Every returned chunk is at most `max_chunk_size` characters, overlap included.
+    pub fn apply_window(&self, sections: Vec<String>) -> Vec<String> {
+        let piece_size = self.max_chunk_size - self.chunk_overlap;
+        let pieces: Vec<String> = sections.iter()
+            .flat_map(|section| split_to_size(section, piece_size))
+            .collect();
+
+        let mut chunks = Vec::with_capacity(pieces.len());
+        for (i, piece) in pieces.iter().enumerate() {
+            if i == 0 || self.chunk_overlap == 0 {
+                chunks.push(piece.clone());
+            } else {
+                let previous = &pieces[i - 1];
+                let tail_start = previous.char_indices()
+                    .rev()
+                    .nth(self.chunk_overlap - 1)
+                    .map(|(idx, _)| idx)
+                    .unwrap_or(0);
+                chunks.push(format!("{}{}", &previous[tail_start..], piece));
+            }
+        }
+        chunks
+    }
+
+    /// Chunk Solidity source into whole members
+    ///
+    /// Each function, modifier, constructor, struct or enum becomes one chunk
+    /// spanning its (possibly multi-line) signature through its closing brace,
+    /// including any preceding NatSpec. State variables and other contract-level
+    /// declarations are grouped into one chunk per contract. Braces and
+    /// semicolons inside comments and string literals are ignored. A member
+    /// longer than `max_chunk_size` is windowed into pieces on its own, so
+    /// overlap never crosses from one member into the next.
     fn chunk_solidity(&self, content: &str) -> Vec<String> {
+        let source: Vec<char> = content.chars().collect();
+        let code = mask_solidity_comments_and_strings(&source);
+        let text = |start: usize, end: usize| source[start..end].iter().collect::<String>().trim().to_string();
+        let code_text = |start: usize, end: usize| {
+            code[start..end].iter().collect::<String>().split_whitespace().collect::<Vec<_>>().join(" ")
+        };
+
         let mut chunks = Vec::new();
-        
-        // Split by contract definitions
-        for line in content.lines() {
-            if line.contains("contract ") || line.contains("interface ") || line.contains("library ") {
-                chunks.push(line.to_string());
-            }
-            // Extract function definitions
-            if line.contains("function ") {
-                chunks.push(line.to_string());
+        let mut depth = 0usize;
+        // Start of the current top-level item and contract member
+        let mut item_start = 0;
+        let mut member_start = 0;
+        let mut container: Option<String> = None;
+        let mut declarations: Vec<String> = Vec::new();
+
+        for (i, c) in code.iter().enumerate() {
+            match c {
+                '{' => {
+                    if depth == 0 {
+                        let header = code_text(item_start, i);
+                        let is_container = ["contract ", "abstract contract ", "interface ", "library "]
+                            .iter()
+                            .any(|kw| header.starts_with(kw));
+                        container = is_container.then_some(header);
+                        member_start = i + 1;
+                    }
+                    depth += 1;
+                }
+                '}' => {
+                    depth = depth.saturating_sub(1);
+                    match depth {
+                        // End of a member body inside a contract
+                        1 if container.is_some() => {
+                            let member = text(member_start, i + 1);
+                            if !member.is_empty() {
+                                chunks.push(member);
+                            }
+                            member_start = i + 1;
+                        }
+                        0 => {
+                            match container.take() {
+                                Some(header) => {
+                                    if !declarations.is_empty() {
+                                        chunks.push(format!("{} {{\n    {}\n}}", header, declarations.join("\n    ")));
+                                        declarations.clear();
+                                    }
+                                }
+                                // Free function or struct at file level
+                                None => chunks.push(text(item_start, i + 1)),
+                            }
+                            item_start = i + 1;
+                        }
+                        _ => {}
+                    }
+                }
+                ';' => match depth {
+                    0 => item_start = i + 1,
+                    1 if container.is_some() => {
+                        let member = text(member_start, i + 1);
+                        if code_text(member_start, i).starts_with("function ") {
+                            // Interface or abstract function without a body
+                            chunks.push(member);
+                        } else {
+                            declarations.push(code_text(member_start, i + 1));
+                        }
+                        member_start = i + 1;
+                    }
+                    _ => {}
+                },
+                _ => {}
             }
         }
-        
+
         if chunks.is_empty() {
             chunks.push(content.to_string());
         }
-        
-        chunks
+
+        chunks.into_iter()
+            .flat_map(|chunk| self.apply_window(vec![chunk]))
+            .collect()
     }
     
     fn chunk_markdown(&self, content: &str) -> Vec<String> {
@@ -247,6 +397,89 @@ impl DefaultDocumentProcessor {
     }
 }
 
+/// Replace Solidity comments and string literal contents with spaces
+///
+/// Keeps positions aligned with the original source so structural scanning
+/// (braces, semicolons) can ignore anything that isn't code.
+fn mask_solidity_comments_and_strings(source: &[char]) -> Vec<char> {
+    let mut masked = source.to_vec();
+    let mut i = 0;
+
+    while i < source.len() {
+        match (source[i], source.get(i + 1)) {
+            ('/', Some('/')) => {
+                while i < source.len() && source[i] != '\n' {
+                    masked[i] = ' ';
+                    i += 1;
+                }
+            }
+            ('/', Some('*')) => {
+                masked[i] = ' ';
+                masked[i + 1] = ' ';
+                i += 2;
+                while i < source.len() && !(source[i] == '*' && source.get(i + 1) == Some(&'/')) {
+                    if source[i] != '\n' {
+                        masked[i] = ' ';
+                    }
+                    i += 1;
+                }
+                masked[i..(i + 2).min(source.len())].fill(' ');
+                i += 2;
+            }
+            (quote @ ('"' | '\''), _) => {
+                i += 1;
+                while i < source.len() && source[i] != quote {
+                    if source[i] == '\\' && i + 1 < source.len() {
+                        masked[i] = ' ';
+                        i += 1;
+                    }
+                    masked[i] = ' ';
+                    i += 1;
+                }
+                i += 1;
+            }
+            _ => i += 1,
+        }
+    }
+
+    masked
+}
+
+/// Split text into pieces of at most `max_chars` characters, preferring line boundaries
+pub(crate) fn split_to_size(text: &str, max_chars: usize) -> Vec<String> {
+    if text.chars().count() <= max_chars {
+        return vec![text.to_string()];
+    }
+
+    let mut pieces = Vec::new();
+    let mut current = String::new();
+    let mut current_len = 0;
+
+    for line in text.split_inclusive('\n') {
+        let line_len = line.chars().count();
+        if current_len + line_len > max_chars && !current.is_empty() {
+            pieces.push(std::mem::take(&mut current));
+            current_len = 0;
+        }
+
+        if line_len > max_chars {
+            // A single line longer than the limit is hard-split on character boundaries
+            let chars: Vec<char> = line.chars().collect();
+            for part in chars.chunks(max_chars) {
+                pieces.push(part.iter().collect());
+            }
+        } else {
+            current.push_str(line);
+            current_len += line_len;
+        }
+    }
+    if !current.is_empty() {
+        pieces.push(current);
+    }
+
+    pieces
+}
+
 /// Processed document ready for indexing
 #[derive(Debug, Clone)]
 pub struct ProcessedDocument {
@@ -256,11 +489,19 @@ pub struct ProcessedDocument {
     pub checksum: String,
 }
 
+/// Default number of attempts for a source or document hitting retryable errors
+pub const DEFAULT_MAX_ATTEMPTS: u32 = 3;
+
+/// Default delay before the first retry; doubled on every further attempt
+pub const DEFAULT_RETRY_DELAY: Duration = Duration::from_millis(500);
+
 /// Document ingestion orchestrator
 pub struct DocumentIngestionPipeline {
     sources: Vec<Box<dyn DocumentSource>>,
     processor: Box<dyn DocumentProcessor>,
     store: Box<dyn DocumentStore>,
+    max_attempts: u32,
+    retry_delay: Duration,
 }
 
 impl DocumentIngestionPipeline {
@@ -273,41 +514,93 @@ impl DocumentIngestionPipeline {
             sources,
             processor,
             store,
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            retry_delay: DEFAULT_RETRY_DELAY,
         }
     }
+
+    /// Set how often retryable errors are attempted and the initial backoff
+    ///
+    /// `max_attempts` counts the first try, so `1` disables retries.
+    pub fn with_retry(mut self, max_attempts: u32, retry_delay: Duration) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self.retry_delay = retry_delay;
+        self
+    }
     
     /// Get a reference to the document store
     pub fn get_store(&self) -> &Box<dyn DocumentStore> {
         &self.store
     }
 
+    /// Ingest every source with updates into the store
+    ///
+    /// Retryable errors are retried up to `max_attempts` times with exponential
+    /// backoff. Permanent errors, and retryable ones that never recover, are
+    /// recorded in `IngestionStats::errors` without aborting the other sources.
     pub async fn run(&self) -> Result<IngestionStats, IngestionError> {
         let mut stats = IngestionStats::default();
         
         for source in &self.sources {
+            let location = source.get_metadata().location;
+
             // Check for updates
-            if !source.has_updates().await? {
-                continue;
+            match self.with_retries(|| source.has_updates()).await {
+                Ok(true) => {}
+                Ok(false) => continue,
+                Err(e) => {
+                    stats.errors.push(format!("{}: {}", location, e));
+                    continue;
+                }
             }
             
             // Fetch documents
-            let raw_docs = source.fetch_documents().await?;
-            stats.total_documents += raw_docs.len();
+            let raw_docs = match self.with_retries(|| source.fetch_documents()).await {
+                Ok(docs) => docs,
+                Err(e) => {
+                    stats.errors.push(format!("{}: {}", location, e));
+                    continue;
+                }
+            };
             
             // Process and store documents
             for doc in raw_docs {
-                match self.process_and_store(doc).await {
-                    Ok(_) => stats.successful_documents += 1,
-                    Err(e) => {
-                        stats.failed_documents += 1;
-                        stats.errors.push(e.to_string());
+                let title = doc.metadata.title.clone();
+                let checksum = doc.checksum.clone();
+                let result = match self.with_retries(|| self.process_and_store(doc.clone())).await {
+                    Ok(_) => DocumentResult::new(title, checksum, DocumentOutcome::Stored, None),
+                    // Documents that fail validation are not ingestion failures, just not indexable
+                    Err(e @ IngestionError::ValidationError(_)) => {
+                        DocumentResult::new(title, checksum, DocumentOutcome::Skipped, Some(e.to_string()))
                     }
-                }
+                    Err(e) => DocumentResult::new(title, checksum, DocumentOutcome::Failed, Some(e.to_string())),
+                };
+                stats.record(result);
             }
         }
         
         Ok(stats)
     }
+
+    /// Run `f`, repeating it while it fails with a retryable error
+    async fn with_retries<T, F, Fut>(&self, mut f: F) -> Result<T, IngestionError>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, IngestionError>>,
+    {
+        let mut attempt = 1;
+        loop {
+            match f().await {
+                Err(e) if e.is_retryable() && attempt < self.max_attempts => {
+                    let delay = self.retry_delay.saturating_mul(1u32 << (attempt - 1).min(16));
+                    warn!("⚠️ {} (attempt {}/{}), retrying in {:?}", e, attempt, self.max_attempts, delay);
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
     
     async fn process_and_store(&self, doc: RawDocument) -> Result<(), IngestionError> {
         // Process document
@@ -324,8 +617,74 @@ impl DocumentIngestionPipeline {
 pub struct IngestionStats {
     pub total_documents: usize,
     pub successful_documents: usize,
+    /// Documents that were not stored, whether skipped or failed
     pub failed_documents: usize,
     pub errors: Vec<String>,
+    /// Outcome of every fetched document, in ingestion order
+    pub results: Vec<DocumentResult>,
+    /// Stored documents left out of the index as duplicates of another
+    pub duplicate_documents: usize,
+}
+
+impl IngestionStats {
+    /// Add a document result, keeping the aggregate counts and `errors` in step
+    pub fn record(&mut self, result: DocumentResult) {
+        self.total_documents += 1;
+        match result.outcome {
+            DocumentOutcome::Stored => self.successful_documents += 1,
+            DocumentOutcome::Skipped | DocumentOutcome::Failed => self.failed_documents += 1,
+        }
+        if let Some(error) = &result.error {
+            self.errors.push(format!("{}: {}", result.title, error));
+        }
+        self.results.push(result);
+    }
+
+    /// Number of documents that were skipped as not indexable
+    pub fn skipped_documents(&self) -> usize {
+        self.with_outcome(DocumentOutcome::Skipped).count()
+    }
+
+    /// Results with the given outcome
+    pub fn with_outcome(&self, outcome: DocumentOutcome) -> impl Iterator<Item = &DocumentResult> {
+        self.results.iter().filter(move |result| result.outcome == outcome)
+    }
+}
+
+/// What happened to a single document during ingestion
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DocumentOutcome {
+    /// Processed and written to the store
+    Stored,
+    /// Rejected by validation (e.g. empty or not the declared type)
+    Skipped,
+    /// Processing or storage failed
+    Failed,
+}
+
+impl std::fmt::Display for DocumentOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DocumentOutcome::Stored => write!(f, "Stored"),
+            DocumentOutcome::Skipped => write!(f, "Skipped"),
+            DocumentOutcome::Failed => write!(f, "Failed"),
+        }
+    }
+}
+
+/// Per-document ingestion result
+#[derive(Debug, Clone)]
+pub struct DocumentResult {
+    pub title: String,
+    pub checksum: String,
+    pub outcome: DocumentOutcome,
+    pub error: Option<String>,
+}
+
+impl DocumentResult {
+    pub fn new(title: String, checksum: String, outcome: DocumentOutcome, error: Option<String>) -> Self {
+        Self { title, checksum, outcome, error }
+    }
 }
 
 /// Persistent storage for documents and embeddings
@@ -334,5 +693,7 @@ pub trait DocumentStore: Send + Sync {
     async fn store_document(&self, doc: ProcessedDocument) -> Result<(), IngestionError>;
     async fn get_document(&self, checksum: &str) -> Result<Option<ProcessedDocument>, IngestionError>;
     async fn list_documents(&self) -> Result<Vec<DocumentMetadata>, IngestionError>;
+    /// Checksums of every stored document, i.e. the keys accepted by `get_document`
+    async fn list_checksums(&self) -> Result<Vec<String>, IngestionError>;
     async fn delete_document(&self, checksum: &str) -> Result<(), IngestionError>;
 }
\ No newline at end of file
@@ -0,0 +1,256 @@
+use crate::doc_ingestion::{
+    DocumentSource, DocumentMetadata, DocumentSourceMetadata,
+    DocumentType, RawDocument, IngestionError,
+};
+use async_trait::async_trait;
+use reqwest::header::{HeaderMap, CONTENT_TYPE, ETAG, LAST_MODIFIED};
+use std::collections::HashMap;
+use std::sync::RwLock;
+use tracing::{info, warn};
+
+/// Document source that fetches published documentation over HTTP(S)
+pub struct HttpDocSource {
+    /// URLs to fetch
+    urls: Vec<String>,
+    client: reqwest::Client,
+    /// ETag / Last-Modified validators seen at the last fetch, keyed by URL
+    validators: RwLock<HashMap<String, String>>,
+}
+
+impl HttpDocSource {
+    pub fn new(urls: Vec<String>) -> Self {
+        Self {
+            urls,
+            client: reqwest::Client::new(),
+            validators: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Infer the document type from a Content-Type header value
+    ///
+    /// HTML is converted to markdown-style text before indexing, so it is
+    /// treated as Markdown.
+    pub fn doc_type_for(content_type: &str) -> DocumentType {
+        let mime = content_type.split(';').next().unwrap_or("").trim().to_lowercase();
+        match mime.as_str() {
+            "text/html" | "application/xhtml+xml" | "text/markdown" | "text/x-markdown" | "text/plain" => DocumentType::Markdown,
+            "application/json" => DocumentType::JSON,
+            "" => DocumentType::Other("unknown".to_string()),
+            other => DocumentType::Other(other.to_string()),
+        }
+    }
+
+    /// Extract the change validator (ETag, falling back to Last-Modified) from response headers
+    fn validator(headers: &HeaderMap) -> Option<String> {
+        headers.get(ETAG)
+            .or_else(|| headers.get(LAST_MODIFIED))
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string)
+    }
+
+    /// Title for a fetched page: the last path segment, or the host for bare domains
+    fn title_for(url: &str) -> String {
+        url::Url::parse(url)
+            .ok()
+            .and_then(|parsed| {
+                parsed.path_segments()
+                    .and_then(|mut segments| segments.rfind(|s| !s.is_empty()).map(str::to_string))
+                    .or_else(|| parsed.host_str().map(str::to_string))
+            })
+            .unwrap_or_else(|| url.to_string())
+    }
+}
+
+/// Convert HTML into markdown-flavoured plain text
+///
+/// Tags are dropped, `<script>`/`<style>`/`<title>` bodies are skipped, headings
+/// become `#` lines and list items become `-` bullets so the markdown chunker can
+/// split the result on sections. A `<` only opens a tag when a letter, `/` or `!`
+/// follows it, so text like "a < b" is kept.
+pub fn html_to_text(html: &str) -> String {
+    let mut text = String::with_capacity(html.len() / 2);
+    let mut rest = html;
+    let mut skip_until: Option<&str> = None;
+
+    while let Some(start) = tag_start(rest) {
+        if skip_until.is_none() {
+            text.push_str(&rest[..start]);
+        }
+        let Some(end) = rest[start..].find('>') else {
+            rest = "";
+            break;
+        };
+        let tag = rest[start + 1..start + end].trim().to_lowercase();
+        rest = &rest[start + end + 1..];
+
+        let name: String = tag.trim_start_matches('/')
+            .chars()
+            .take_while(|c| c.is_ascii_alphanumeric())
+            .collect();
+
+        if let Some(closing) = skip_until {
+            if tag.starts_with('/') && name == closing {
+                skip_until = None;
+            }
+            continue;
+        }
+
+        match name.as_str() {
+            "script" if !tag.starts_with('/') => skip_until = Some("script"),
+            "style" if !tag.starts_with('/') => skip_until = Some("style"),
+            "title" if !tag.starts_with('/') => skip_until = Some("title"),
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" if !tag.starts_with('/') => {
+                let level = name[1..].parse::<usize>().unwrap_or(1);
+                text.push('\n');
+                text.push_str(&"#".repeat(level));
+                text.push(' ');
+            }
+            "li" if !tag.starts_with('/') => text.push_str("\n- "),
+            "p" | "br" | "div" | "tr" | "pre" | "section" | "article" | "h1" | "h2" | "h3" | "h4" | "h5" | "h6" | "ul" | "ol" => {
+                text.push('\n');
+            }
+            _ => {}
+        }
+    }
+    if skip_until.is_none() {
+        text.push_str(rest);
+    }
+
+    let decoded = text
+        .replace("&nbsp;", " ")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&");
+
+    // Collapse runs of blank lines and trailing whitespace
+    let mut output = String::with_capacity(decoded.len());
+    let mut previous_blank = true;
+    for line in decoded.lines().map(str::trim_end) {
+        let blank = line.trim().is_empty();
+        if blank && previous_blank {
+            continue;
+        }
+        output.push_str(line);
+        output.push('\n');
+        previous_blank = blank;
+    }
+    output.trim().to_string()
+}
+
+/// Byte offset of the next `<` that opens a tag, comment or doctype
+fn tag_start(html: &str) -> Option<usize> {
+    html.match_indices('<')
+        .map(|(index, _)| index)
+        .find(|&index| html[index + 1..].starts_with(|c: char| c.is_ascii_alphabetic() || c == '/' || c == '!'))
+}
+
+#[async_trait]
+impl DocumentSource for HttpDocSource {
+    async fn fetch_documents(&self) -> Result<Vec<RawDocument>, IngestionError> {
+        info!("🌐 Fetching {} documents over HTTP...", self.urls.len());
+
+        let mut documents = Vec::new();
+        let mut validators = HashMap::new();
+
+        for url in &self.urls {
+            info!("   Fetching: {}", url);
+            let response = match self.client.get(url).send().await.and_then(|r| r.error_for_status()) {
+                Ok(response) => response,
+                Err(e) => {
+                    warn!("   ⚠️ Failed to fetch {}: {}", url, e);
+                    continue;
+                }
+            };
+
+            let headers = response.headers().clone();
+            let content_type = headers.get(CONTENT_TYPE)
+                .and_then(|v| v.to_str().ok())
+                .unwrap_or("")
+                .to_string();
+            let body = match response.bytes().await {
+                Ok(body) => body,
+                Err(e) => {
+                    warn!("   ⚠️ Failed to read body of {}: {}", url, e);
+                    continue;
+                }
+            };
+
+            let is_html = content_type.to_lowercase().contains("html");
+            let content = if is_html {
+                html_to_text(&String::from_utf8_lossy(&body)).into_bytes()
+            } else {
+                body.to_vec()
+            };
+
+            if let Some(validator) = Self::validator(&headers) {
+                validators.insert(url.clone(), validator);
+            }
+
+            let doc_type = Self::doc_type_for(&content_type);
+            documents.push(RawDocument::new(
+                content,
+                DocumentMetadata {
+                    title: Self::title_for(url),
+                    tags: vec![
+                        "http".to_string(),
+                        doc_type.to_string().to_lowercase(),
+                        url.clone(),
+                    ],
+                    doc_type,
+                    version: None,
+                    created_at: chrono::Utc::now(),
+                    updated_at: chrono::Utc::now(),
+                    source: DocumentSourceMetadata {
+                        source_type: "http".to_string(),
+                        location: url.clone(),
+                        version: None,
+                    },
+                },
+            ));
+        }
+
+        *self.validators.write()
+            .map_err(|_| IngestionError::FetchError("Failed to acquire validator lock".to_string()))? = validators;
+
+        info!("✅ Fetched {} HTTP documents", documents.len());
+        Ok(documents)
+    }
+
+    async fn has_updates(&self) -> Result<bool, IngestionError> {
+        let previous = self.validators.read()
+            .map_err(|_| IngestionError::FetchError("Failed to acquire validator lock".to_string()))?
+            .clone();
+
+        for url in &self.urls {
+            // Without a stored validator we can't tell whether the page changed
+            let Some(known) = previous.get(url) else {
+                return Ok(true);
+            };
+
+            match self.client.head(url).send().await {
+                Ok(response) => {
+                    if Self::validator(response.headers()).as_ref() != Some(known) {
+                        info!("🔄 {} changed since last fetch", url);
+                        return Ok(true);
+                    }
+                }
+                Err(e) => {
+                    warn!("⚠️ HEAD request failed for {}: {}", url, e);
+                    return Ok(true);
+                }
+            }
+        }
+
+        Ok(false)
+    }
+
+    fn get_metadata(&self) -> DocumentSourceMetadata {
+        DocumentSourceMetadata {
+            source_type: "http".to_string(),
+            location: self.urls.join(", "),
+            version: None,
+        }
+    }
+}
@@ -0,0 +1,155 @@
+use crate::doc_ingestion::{
+    DocumentSource, DocumentMetadata, DocumentSourceMetadata,
+    DocumentType, RawDocument, IngestionError,
+};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+use std::time::SystemTime;
+use tokio::fs;
+use tracing::{info, warn};
+use walkdir::WalkDir;
+
+/// Document source that indexes files from a local directory tree
+pub struct LocalFileSource {
+    /// Root directory to walk
+    root: PathBuf,
+    /// File extensions to include (without the leading dot, e.g. "sol", "md")
+    extensions: Vec<String>,
+    /// Modification times recorded at the last fetch, used by `has_updates`
+    snapshot: RwLock<HashMap<PathBuf, SystemTime>>,
+}
+
+impl LocalFileSource {
+    pub fn new(root: PathBuf, extensions: Vec<String>) -> Self {
+        let extensions = extensions
+            .into_iter()
+            .map(|ext| ext.trim_start_matches('.').to_lowercase())
+            .collect();
+
+        Self {
+            root,
+            extensions,
+            snapshot: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Map a file extension to the document type used for validation and chunking
+    pub fn doc_type_for(path: &Path) -> DocumentType {
+        match path.extension().and_then(|e| e.to_str()).map(|e| e.to_lowercase()).as_deref() {
+            Some("sol") => DocumentType::Solidity,
+            Some("md") | Some("mdx") => DocumentType::Markdown,
+            Some("json") => DocumentType::JSON,
+            Some(other) => DocumentType::Other(other.to_string()),
+            None => DocumentType::Other("unknown".to_string()),
+        }
+    }
+
+    /// Files under the root matching one of the configured extensions
+    fn matching_files(&self) -> Vec<PathBuf> {
+        WalkDir::new(&self.root)
+            .into_iter()
+            .filter_map(Result::ok)
+            .filter(|e| e.file_type().is_file())
+            .map(|e| e.into_path())
+            .filter(|path| {
+                path.extension()
+                    .and_then(|e| e.to_str())
+                    .is_some_and(|ext| self.extensions.iter().any(|wanted| wanted.eq_ignore_ascii_case(ext)))
+            })
+            .collect()
+    }
+
+    /// Current modification time of every matching file
+    fn current_snapshot(&self) -> HashMap<PathBuf, SystemTime> {
+        self.matching_files()
+            .into_iter()
+            .filter_map(|path| {
+                let modified = std::fs::metadata(&path).and_then(|m| m.modified()).ok()?;
+                Some((path, modified))
+            })
+            .collect()
+    }
+}
+
+#[async_trait]
+impl DocumentSource for LocalFileSource {
+    async fn fetch_documents(&self) -> Result<Vec<RawDocument>, IngestionError> {
+        if !self.root.exists() {
+            return Err(IngestionError::NotFound(format!(
+                "Local document root not found: {}",
+                self.root.display()
+            )));
+        }
+
+        info!("🔍 Loading local documents from {}...", self.root.display());
+
+        let source = self.get_metadata();
+        let mut documents = Vec::new();
+
+        for path in self.matching_files() {
+            info!("   Processing file: {}", path.display());
+            match fs::read(&path).await {
+                Ok(content) => {
+                    let modified = fs::metadata(&path).await
+                        .and_then(|m| m.modified())
+                        .map(chrono::DateTime::<chrono::Utc>::from)
+                        .unwrap_or_else(|_| chrono::Utc::now());
+                    let relative_path = path.strip_prefix(&self.root)
+                        .unwrap_or(&path)
+                        .to_string_lossy()
+                        .to_string();
+                    let doc_type = Self::doc_type_for(&path);
+
+                    let doc = RawDocument::new(
+                        content,
+                        DocumentMetadata {
+                            title: path.file_name()
+                                .and_then(|n| n.to_str())
+                                .unwrap_or("Unknown")
+                                .to_string(),
+                            tags: vec![
+                                "local".to_string(),
+                                doc_type.to_string().to_lowercase(),
+                                relative_path,
+                            ],
+                            doc_type,
+                            version: None,
+                            created_at: modified,
+                            updated_at: modified,
+                            source: source.clone(),
+                        },
+                    );
+                    documents.push(doc);
+                }
+                Err(e) => warn!("   ⚠️ Failed to read file: {}", e),
+            }
+        }
+
+        // Remember what we've seen so has_updates can detect changes
+        let snapshot = self.current_snapshot();
+        *self.snapshot.write()
+            .map_err(|_| IngestionError::FetchError("Failed to acquire snapshot lock".to_string()))? = snapshot;
+
+        info!("✅ Loaded {} local documents", documents.len());
+        Ok(documents)
+    }
+
+    async fn has_updates(&self) -> Result<bool, IngestionError> {
+        let current = self.current_snapshot();
+        let previous = self.snapshot.read()
+            .map_err(|_| IngestionError::FetchError("Failed to acquire snapshot lock".to_string()))?;
+
+        // Never fetched, or files were added, removed or modified since the last fetch
+        Ok(previous.is_empty() || *previous != current)
+    }
+
+    fn get_metadata(&self) -> DocumentSourceMetadata {
+        DocumentSourceMetadata {
+            source_type: "local".to_string(),
+            location: self.root.display().to_string(),
+            version: None,
+        }
+    }
+}
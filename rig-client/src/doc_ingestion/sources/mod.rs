@@ -1 +1,3 @@
 pub mod uniswap;
+pub mod local;
+pub mod http;
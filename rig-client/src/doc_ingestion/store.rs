@@ -1,6 +1,8 @@
 use std::collections::HashMap;
-use std::sync::RwLock;
+use std::path::Path;
+use std::sync::{Mutex, RwLock};
 use async_trait::async_trait;
+use rusqlite::{params, Connection, OptionalExtension};
 use super::*;
 
 /// Simple in-memory document store implementation
@@ -14,14 +16,14 @@ impl DocumentStore for InMemoryDocStore {
     async fn store_document(&self, doc: ProcessedDocument) -> Result<(), IngestionError> {
         let mut docs = self.documents.write()
             .map_err(|_| IngestionError::StorageError("Failed to acquire write lock".to_string()))?;
-        docs.insert(doc.metadata.title.clone(), doc);
+        docs.insert(doc.checksum.clone(), doc);
         Ok(())
     }
     
-    async fn get_document(&self, title: &str) -> Result<Option<ProcessedDocument>, IngestionError> {
+    async fn get_document(&self, checksum: &str) -> Result<Option<ProcessedDocument>, IngestionError> {
         let docs = self.documents.read()
             .map_err(|_| IngestionError::StorageError("Failed to acquire read lock".to_string()))?;
-        Ok(docs.get(title).cloned())
+        Ok(docs.get(checksum).cloned())
     }
     
     async fn list_documents(&self) -> Result<Vec<DocumentMetadata>, IngestionError> {
@@ -30,10 +32,155 @@ impl DocumentStore for InMemoryDocStore {
         Ok(docs.values().map(|doc| doc.metadata.clone()).collect())
     }
     
-    async fn delete_document(&self, title: &str) -> Result<(), IngestionError> {
+    async fn list_checksums(&self) -> Result<Vec<String>, IngestionError> {
+        let docs = self.documents.read()
+            .map_err(|_| IngestionError::StorageError("Failed to acquire read lock".to_string()))?;
+        Ok(docs.keys().cloned().collect())
+    }
+    
+    async fn delete_document(&self, checksum: &str) -> Result<(), IngestionError> {
         let mut docs = self.documents.write()
             .map_err(|_| IngestionError::StorageError("Failed to acquire write lock".to_string()))?;
-        docs.remove(title);
+        docs.remove(checksum);
+        Ok(())
+    }
+}
+
+/// Schema migrations, applied in order; `PRAGMA user_version` records how many have run
+const SQLITE_MIGRATIONS: &[&str] = &[
+    "CREATE TABLE IF NOT EXISTS documents (
+        checksum   TEXT PRIMARY KEY,
+        title      TEXT NOT NULL,
+        content    TEXT NOT NULL,
+        chunks     TEXT NOT NULL,
+        metadata   TEXT NOT NULL,
+        stored_at  TEXT NOT NULL
+    );
+    CREATE INDEX IF NOT EXISTS idx_documents_title ON documents(title);",
+];
+
+/// SQLite-backed document store that survives restarts
+///
+/// Documents are keyed by checksum; chunks and metadata are stored as JSON so
+/// `list_documents` can return metadata without loading document content.
+pub struct SqliteDocStore {
+    conn: Mutex<Connection>,
+}
+
+impl SqliteDocStore {
+    /// Open (or create) a store at `path`, running any pending migrations
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, IngestionError> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent()
+            && !parent.as_os_str().is_empty()
+        {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| IngestionError::StorageError(format!("Failed to create store directory: {}", e)))?;
+        }
+
+        let conn = Connection::open(path)
+            .map_err(|e| IngestionError::StorageError(format!("Failed to open SQLite store: {}", e)))?;
+        Self::from_connection(conn)
+    }
+
+    /// Open a transient in-memory store (mainly for tests)
+    pub fn open_in_memory() -> Result<Self, IngestionError> {
+        let conn = Connection::open_in_memory()
+            .map_err(|e| IngestionError::StorageError(format!("Failed to open SQLite store: {}", e)))?;
+        Self::from_connection(conn)
+    }
+
+    fn from_connection(conn: Connection) -> Result<Self, IngestionError> {
+        Self::migrate(&conn)?;
+        Ok(Self { conn: Mutex::new(conn) })
+    }
+
+    fn migrate(conn: &Connection) -> Result<(), IngestionError> {
+        let applied: usize = conn.query_row("PRAGMA user_version", [], |row| row.get(0))
+            .map_err(|e| IngestionError::StorageError(format!("Failed to read schema version: {}", e)))?;
+
+        for (version, migration) in SQLITE_MIGRATIONS.iter().enumerate().skip(applied) {
+            conn.execute_batch(migration)
+                .map_err(|e| IngestionError::StorageError(format!("Migration {} failed: {}", version + 1, e)))?;
+            conn.pragma_update(None, "user_version", version + 1)
+                .map_err(|e| IngestionError::StorageError(format!("Failed to record schema version: {}", e)))?;
+        }
+
+        Ok(())
+    }
+
+    fn lock(&self) -> Result<std::sync::MutexGuard<'_, Connection>, IngestionError> {
+        self.conn.lock()
+            .map_err(|_| IngestionError::StorageError("Failed to acquire connection lock".to_string()))
+    }
+}
+
+#[async_trait]
+impl DocumentStore for SqliteDocStore {
+    async fn store_document(&self, doc: ProcessedDocument) -> Result<(), IngestionError> {
+        let chunks = serde_json::to_string(&doc.chunks)
+            .map_err(|e| IngestionError::StorageError(format!("Failed to serialize chunks: {}", e)))?;
+        let metadata = serde_json::to_string(&doc.metadata)
+            .map_err(|e| IngestionError::StorageError(format!("Failed to serialize metadata: {}", e)))?;
+
+        self.lock()?.execute(
+            "INSERT OR REPLACE INTO documents (checksum, title, content, chunks, metadata, stored_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![doc.checksum, doc.metadata.title, doc.content, chunks, metadata, Utc::now().to_rfc3339()],
+        ).map_err(|e| IngestionError::StorageError(format!("Failed to store document: {}", e)))?;
+        Ok(())
+    }
+
+    async fn get_document(&self, checksum: &str) -> Result<Option<ProcessedDocument>, IngestionError> {
+        let row = self.lock()?.query_row(
+            "SELECT content, chunks, metadata FROM documents WHERE checksum = ?1",
+            params![checksum],
+            |row| Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?, row.get::<_, String>(2)?)),
+        ).optional()
+            .map_err(|e| IngestionError::StorageError(format!("Failed to load document: {}", e)))?;
+
+        let Some((content, chunks, metadata)) = row else {
+            return Ok(None);
+        };
+
+        Ok(Some(ProcessedDocument {
+            content,
+            chunks: serde_json::from_str(&chunks)
+                .map_err(|e| IngestionError::StorageError(format!("Corrupt chunks for {}: {}", checksum, e)))?,
+            metadata: serde_json::from_str(&metadata)
+                .map_err(|e| IngestionError::StorageError(format!("Corrupt metadata for {}: {}", checksum, e)))?,
+            checksum: checksum.to_string(),
+        }))
+    }
+
+    async fn list_documents(&self) -> Result<Vec<DocumentMetadata>, IngestionError> {
+        let conn = self.lock()?;
+        let mut stmt = conn.prepare("SELECT metadata FROM documents ORDER BY title")
+            .map_err(|e| IngestionError::StorageError(format!("Failed to list documents: {}", e)))?;
+        let rows = stmt.query_map([], |row| row.get::<_, String>(0))
+            .map_err(|e| IngestionError::StorageError(format!("Failed to list documents: {}", e)))?;
+
+        rows.map(|row| {
+            let metadata = row.map_err(|e| IngestionError::StorageError(format!("Failed to read row: {}", e)))?;
+            serde_json::from_str(&metadata)
+                .map_err(|e| IngestionError::StorageError(format!("Corrupt metadata: {}", e)))
+        }).collect()
+    }
+
+    async fn list_checksums(&self) -> Result<Vec<String>, IngestionError> {
+        let conn = self.lock()?;
+        let mut stmt = conn.prepare("SELECT checksum FROM documents ORDER BY title")
+            .map_err(|e| IngestionError::StorageError(format!("Failed to list checksums: {}", e)))?;
+        let rows = stmt.query_map([], |row| row.get::<_, String>(0))
+            .map_err(|e| IngestionError::StorageError(format!("Failed to list checksums: {}", e)))?;
+
+        rows.map(|row| row.map_err(|e| IngestionError::StorageError(format!("Failed to read row: {}", e))))
+            .collect()
+    }
+
+    async fn delete_document(&self, checksum: &str) -> Result<(), IngestionError> {
+        self.lock()?.execute("DELETE FROM documents WHERE checksum = ?1", params![checksum])
+            .map_err(|e| IngestionError::StorageError(format!("Failed to delete document: {}", e)))?;
         Ok(())
     }
-}
\ No newline at end of file
+}
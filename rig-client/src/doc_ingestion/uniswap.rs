@@ -113,7 +113,7 @@ impl UniswapDocSource {
             fetch_options.remote_callbacks(make_callbacks());
             
             remote.fetch(&[&config.branch], Some(&mut fetch_options), None)
-                .map_err(|e| IngestionError::FetchError(format!("Failed to fetch: {}", e)))?;
+                .map_err(|e| IngestionError::NetworkError(format!("Failed to fetch: {}", e)))?;
         } else {
             // Clone repository
             info!("   🔄 Cloning repository {} to {}", config.url, repo_path.display());
@@ -155,11 +155,11 @@ impl UniswapDocSource {
                         
                         info!("   🔄 Pulling latest changes...");
                         remote.fetch(&["refs/heads/*:refs/heads/*"], Some(&mut pull_options), None)
-                            .map_err(|e| IngestionError::FetchError(format!("Failed to fetch: {}", e)))?;
+                            .map_err(|e| IngestionError::NetworkError(format!("Failed to fetch: {}", e)))?;
                         
                         info!("   ✅ Successfully updated repository");
                     } else {
-                        return Err(IngestionError::FetchError(format!("Failed to clone: {}", e)));
+                        return Err(IngestionError::NetworkError(format!("Failed to clone: {}", e)));
                     }
                 }
             };
@@ -269,7 +269,7 @@ impl DocumentSource for UniswapDocSource {
             });
             
             remote.fetch(&[&config.branch], Some(&mut fetch_options), None)
-                .map_err(|e| IngestionError::FetchError(format!("Failed to fetch: {}", e)))?;
+                .map_err(|e| IngestionError::NetworkError(format!("Failed to fetch: {}", e)))?;
             
             let head = repo.head()
                 .map_err(|e| IngestionError::FetchError(format!("Failed to get HEAD: {}", e)))?;
@@ -2,14 +2,36 @@
 
 use thiserror::Error;
 
+use rig::agent::prompt_request::streaming::StreamingError;
+use rig::completion::PromptError;
+
+use crate::doc_ingestion::IngestionError;
+
+/// Underlying error kept as the source of a `ClientError`
+pub type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
 /// Errors that can occur in the RIG client
+///
+/// The MCP, Claude and RAG variants pair a message with the underlying error as
+/// their `source()`, so the original type survives for diagnostics. Their
+/// `Display` shows only the message; use `report` (or any chained formatter) to
+/// include the causes. Build them with `ClientError::mcp`/`claude`/`rag`, or the
+/// `*_with` constructors to attach a source.
 #[derive(Error, Debug)]
 pub enum ClientError {
-    #[error("MCP server connection failed: {0}")]
-    McpConnection(String),
+    #[error("MCP server connection failed: {message}")]
+    McpConnection {
+        message: String,
+        #[source]
+        source: Option<BoxError>,
+    },
 
-    #[error("Claude API error: {0}")]
-    ClaudeApi(String),
+    #[error("Claude API error: {message}")]
+    ClaudeApi {
+        message: String,
+        #[source]
+        source: Option<BoxError>,
+    },
 
     #[error("Missing environment variable: {0}")]
     MissingEnvVar(String),
@@ -29,18 +51,92 @@ pub enum ClientError {
     #[error("Anyhow error: {0}")]
     Anyhow(#[from] anyhow::Error),
 
-    #[error("RAG system error: {0}")]
-    RagError(String),
+    #[error("RAG system error: {message}")]
+    RagError {
+        message: String,
+        #[source]
+        source: Option<BoxError>,
+    },
 
     #[error("Embedding error: {0}")]
     EmbeddingError(#[from] rig::embeddings::EmbedError),
 
+    #[error("BAML error: {0}")]
+    Baml(String),
+
     #[error("Vector store error: {0}")]
     VectorStoreError(#[from] rig::vector_store::VectorStoreError),
 }
 
+impl ClientError {
+    /// This error followed by each of its causes, as `error: cause: cause`
+    pub fn report(&self) -> String {
+        let mut report = self.to_string();
+        let mut source = std::error::Error::source(self);
+        while let Some(cause) = source {
+            report.push_str(&format!(": {}", cause));
+            source = cause.source();
+        }
+        report
+    }
+
+    /// MCP error with no underlying cause
+    pub fn mcp(message: impl Into<String>) -> Self {
+        ClientError::McpConnection { message: message.into(), source: None }
+    }
+
+    /// MCP error caused by `source`, described by `message`
+    pub fn mcp_with(message: impl Into<String>, source: impl Into<BoxError>) -> Self {
+        ClientError::McpConnection { message: message.into(), source: Some(source.into()) }
+    }
+
+    /// Claude API error with no underlying cause
+    pub fn claude(message: impl Into<String>) -> Self {
+        ClientError::ClaudeApi { message: message.into(), source: None }
+    }
+
+    /// Claude API error caused by `source`, described by `message`
+    pub fn claude_with(message: impl Into<String>, source: impl Into<BoxError>) -> Self {
+        ClientError::ClaudeApi { message: message.into(), source: Some(source.into()) }
+    }
+
+    /// RAG error with no underlying cause
+    pub fn rag(message: impl Into<String>) -> Self {
+        ClientError::RagError { message: message.into(), source: None }
+    }
+
+    /// RAG error caused by `source`, described by `message`
+    pub fn rag_with(message: impl Into<String>, source: impl Into<BoxError>) -> Self {
+        ClientError::RagError { message: message.into(), source: Some(source.into()) }
+    }
+}
+
 impl From<rmcp::ErrorData> for ClientError {
     fn from(err: rmcp::ErrorData) -> Self {
-        ClientError::McpConnection(err.to_string())
+        ClientError::mcp_with("MCP tool call failed", err)
+    }
+}
+
+impl From<rmcp::service::ServiceError> for ClientError {
+    fn from(err: rmcp::service::ServiceError) -> Self {
+        ClientError::mcp_with("MCP request failed", err)
+    }
+}
+
+impl From<PromptError> for ClientError {
+    fn from(err: PromptError) -> Self {
+        ClientError::claude_with("Failed to process command with Claude", err)
+    }
+}
+
+impl From<StreamingError> for ClientError {
+    fn from(err: StreamingError) -> Self {
+        ClientError::claude_with("Failed to stream command with Claude", err)
+    }
+}
+
+impl From<IngestionError> for ClientError {
+    fn from(err: IngestionError) -> Self {
+        ClientError::rag_with("Document ingestion failed", err)
     }
 }
@@ -0,0 +1,231 @@
+//! Deterministic parsing of the core PRD commands (`--fast-path`)
+//!
+//! `parse_intent` recognises a small grammar of common commands - ETH sends,
+//! balance questions and contract deployment checks - and turns each into a
+//! single MCP tool call, so `BlockchainAgent` can answer it without a Claude
+//! round trip. Anything outside the grammar, or only loosely matching it,
+//! returns `None` and goes to Claude as before.
+
+use crate::config::DefaultAccounts;
+use serde_json::{json, Map, Value};
+
+/// Words that read like an account but don't name one
+const NOT_ACCOUNTS: &[&str] = &["i", "me", "my", "you", "it", "we", "they", "he", "she", "this", "that", "the", "a", "an", "someone", "everyone"];
+
+/// Leading words of a balance question that carry no meaning (e.g. "what is", "check")
+const BALANCE_PREFIXES: &[&[&str]] = &[
+    &["what", "is"], &["what's"], &["whats"], &["show", "me"], &["show"], &["check"], &["get"], &["tell", "me"],
+];
+
+/// A command recognised by the fast-path grammar
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Intent {
+    /// `send 1 ETH [from alice] to bob`
+    SendEth { amount: String, from: Option<String>, to: String },
+    /// `how much ETH does alice have?`, `balance of bob`
+    EthBalance { who: String },
+    /// `how much USDC does alice have?`; `token` (a symbol) and `who` are replaced by addresses before the call
+    TokenBalance { token: String, who: String },
+    /// `is Uniswap V2 Router (0x7a25...) deployed?`
+    ContractDeployed { address: String },
+}
+
+impl Intent {
+    /// MCP tool that answers the intent
+    pub fn tool(&self) -> &'static str {
+        match self {
+            Intent::SendEth { .. } => "send_eth",
+            Intent::EthBalance { .. } => "balance",
+            Intent::TokenBalance { .. } => "token_balance",
+            Intent::ContractDeployed { .. } => "is_contract_deployed",
+        }
+    }
+
+    /// Arguments for the tool call
+    pub fn arguments(&self) -> Map<String, Value> {
+        let arguments = match self {
+            Intent::SendEth { amount, from: Some(from), to } => json!({ "to": to, "amount": amount, "from": from }),
+            Intent::SendEth { amount, from: None, to } => json!({ "to": to, "amount": amount }),
+            Intent::EthBalance { who } => json!({ "who": who }),
+            Intent::TokenBalance { token, who } => json!({ "token_address": token, "account_address": who }),
+            Intent::ContractDeployed { address } => json!({ "address": address }),
+        };
+        arguments.as_object().cloned().unwrap_or_default()
+    }
+}
+
+/// Parse `input` as one of the fast-path commands
+///
+/// Matching is case-insensitive and ignores trailing punctuation, but otherwise
+/// strict: amounts must be plain numbers and accounts a single word or address,
+/// so "send all my ETH to Bob" or "how much ETH does the treasury have" are left
+/// to Claude.
+pub fn parse_intent(input: &str) -> Option<Intent> {
+    let text = input.trim().trim_end_matches(['?', '!', '.']);
+    let words: Vec<&str> = text.split_whitespace().collect();
+    let lower: Vec<String> = words.iter().map(|word| word.to_lowercase()).collect();
+    let lower: Vec<&str> = lower.iter().map(String::as_str).collect();
+
+    parse_send(&words, &lower)
+        .or_else(|| parse_balance(&words, &lower))
+        .or_else(|| parse_deployed(&words, &lower))
+}
+
+/// `send|transfer <amount> ETH to <account> [from <account>]`, or with `from` first
+fn parse_send(words: &[&str], lower: &[&str]) -> Option<Intent> {
+    let [verb, amount, unit, ..] = lower else { return None };
+    if !matches!(*verb, "send" | "transfer") || !matches!(*unit, "eth" | "ether") || !is_amount(amount) {
+        return None;
+    }
+    let (from, to) = match &lower[3..] {
+        ["to", _] => (None, words[4]),
+        ["from", _, "to", _] => (Some(words[4]), words[6]),
+        ["to", _, "from", _] => (Some(words[6]), words[4]),
+        _ => return None,
+    };
+    if !is_account(to) || !from.is_none_or(is_account) {
+        return None;
+    }
+    Some(Intent::SendEth { amount: amount.to_string(), from: from.map(str::to_string), to: to.to_string() })
+}
+
+/// `how much <asset> does <account> have`, `[<asset>] balance of <account>` or `<account>'s [<asset>] balance`
+fn parse_balance(words: &[&str], lower: &[&str]) -> Option<Intent> {
+    let (asset, who) = match lower {
+        ["how", "much", asset, "does", _, "have"] => (Some(*asset), words[4]),
+        _ => {
+            let start = BALANCE_PREFIXES.iter()
+                .find(|prefix| lower.starts_with(prefix))
+                .map_or(0, |prefix| prefix.len());
+            let start = start + usize::from(lower.get(start) == Some(&"the"));
+            match &lower[start..] {
+                ["balance", "of" | "for", _] => (None, words[start + 2]),
+                [asset, "balance", "of" | "for", _] => (Some(*asset), words[start + 3]),
+                [owner, "balance"] => (None, strip_possessive(words[start], owner)?),
+                [owner, asset, "balance"] => (Some(*asset), strip_possessive(words[start], owner)?),
+                _ => return None,
+            }
+        }
+    };
+    if !is_account(who) {
+        return None;
+    }
+    match asset {
+        None | Some("eth" | "ether") => Some(Intent::EthBalance { who: who.to_string() }),
+        Some(symbol) if is_symbol(symbol) => Some(Intent::TokenBalance {
+            token: symbol.to_uppercase(),
+            who: who.to_string(),
+        }),
+        Some(_) => None,
+    }
+}
+
+/// `is ... deployed`, `is ... deployed at <address>` or `check if ... deployed`, naming exactly one address
+fn parse_deployed(words: &[&str], lower: &[&str]) -> Option<Intent> {
+    let is_question = lower.first() == Some(&"is") || matches!(lower, ["check", "if" | "whether", ..]);
+    let deployed = lower.last() == Some(&"deployed")
+        || matches!(lower, [.., "deployed", "at", _]);
+    if !is_question || !deployed {
+        return None;
+    }
+    let addresses: Vec<&str> = words.iter()
+        .map(|word| word.trim_matches(|c: char| matches!(c, '(' | ')' | ',' | ':' | '"' | '\'')))
+        .filter(|word| is_address(word))
+        .collect();
+    match addresses.as_slice() {
+        [address] => Some(Intent::ContractDeployed { address: address.to_string() }),
+        _ => None,
+    }
+}
+
+/// Token address in a `resolve_token_address` response, if it came from the server's token table
+///
+/// Addresses found by web search carry a warning to check them first, so
+/// those are left to Claude rather than used unseen.
+pub fn resolved_token_address(resolve_output: &str) -> Option<String> {
+    let field = |name: &str| resolve_output.lines()
+        .find_map(|line| line.trim().strip_prefix(name))
+        .map(str::trim);
+    if !field("Source:")?.starts_with("network config") {
+        return None;
+    }
+    field("Address:").filter(|address| is_address(address)).map(str::to_string)
+}
+
+/// The human-readable part of a tool's output
+///
+/// Structured tools return their JSON result followed by a summary; only the
+/// summary is meant for the user. Other output is returned whole.
+pub fn summary_text(tool_output: &str) -> &str {
+    let trimmed = tool_output.trim_start();
+    if !trimmed.starts_with('{') {
+        return tool_output;
+    }
+    let mut values = serde_json::Deserializer::from_str(trimmed).into_iter::<Value>();
+    match values.next() {
+        Some(Ok(Value::Object(_))) => {
+            let summary = trimmed[values.byte_offset()..].trim();
+            if summary.is_empty() { tool_output } else { summary }
+        }
+        _ => tool_output,
+    }
+}
+
+/// Anvil account index behind a name: the default sender or recipient, or `accountN`
+pub fn account_index(name: &str, accounts: &DefaultAccounts) -> Option<usize> {
+    let name = name.trim().to_lowercase();
+    if name == accounts.sender {
+        return Some(accounts.sender_index);
+    }
+    if name == accounts.recipient {
+        return Some(accounts.recipient_index);
+    }
+    name.strip_prefix("account")?.parse().ok()
+}
+
+/// Address of the account at `index` in a `get_accounts` response
+pub fn account_address(get_accounts_output: &str, index: usize) -> Option<String> {
+    let response: Value = serde_json::from_str(get_accounts_output).ok()?;
+    response["accounts"].as_array()?
+        .iter()
+        .find(|account| account["index"].as_u64() == Some(index as u64))?["address"]
+        .as_str()
+        .map(str::to_string)
+}
+
+/// Whether `text` is a 0x-prefixed 20-byte hex address
+pub fn is_address(text: &str) -> bool {
+    text.strip_prefix("0x")
+        .is_some_and(|hex| hex.len() == 40 && hex.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
+/// A positive decimal amount such as `1`, `0.5` or `.25`
+fn is_amount(text: &str) -> bool {
+    text.chars().all(|c| c.is_ascii_digit() || c == '.')
+        && text.matches('.').count() <= 1
+        && text.parse::<f64>().is_ok_and(|amount| amount > 0.0)
+}
+
+/// A token symbol such as `USDC` or `1INCH`
+fn is_symbol(word: &str) -> bool {
+    (2..=11).contains(&word.len())
+        && word.chars().all(|c| c.is_ascii_alphanumeric())
+        && word.chars().any(|c| c.is_ascii_alphabetic())
+}
+
+/// A single-word account: an address, an ENS name or an account name like `alice`
+fn is_account(word: &str) -> bool {
+    if is_address(word) {
+        return true;
+    }
+    let lower = word.to_lowercase();
+    lower.starts_with(|c: char| c.is_ascii_alphabetic())
+        && lower.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'))
+        && !NOT_ACCOUNTS.contains(&lower.as_str())
+}
+
+/// `alice's` (or `alice’s`) as `alice`, keeping the original case
+fn strip_possessive<'a>(word: &'a str, lower: &str) -> Option<&'a str> {
+    let suffix = ["'s", "’s"].into_iter().find(|suffix| lower.ends_with(suffix))?;
+    Some(&word[..word.len() - suffix.len()])
+}
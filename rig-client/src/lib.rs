@@ -4,13 +4,17 @@
 //! with the Ethereum blockchain using natural language commands.
 
 pub mod agent;
+pub mod audit;
 pub mod cli;
 pub mod config;
 pub mod doc_ingestion;
 pub mod error;
+pub mod intent;
 pub mod rag;
+pub mod resume;
+pub mod self_test;
 
-pub use agent::BlockchainAgent;
+pub use agent::{BlockchainAgent, StreamEvent};
 pub use cli::Repl;
 pub use config::Config;
 pub use error::ClientError;
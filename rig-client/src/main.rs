@@ -7,7 +7,10 @@ use dotenv::dotenv;
 use rig::providers::anthropic::Client;
 use tracing::info;
 
-use rig_client::{BlockchainAgent, Config, Repl, Result};
+use rig_client::agent::mcp_unreachable_help;
+use rig_client::audit::AuditLog;
+use rig_client::config::LogFormat;
+use rig_client::{BlockchainAgent, ClientError, Config, Repl, Result};
 
 #[tokio::main]
 async fn main() -> Result<()> {
@@ -16,11 +19,14 @@ async fn main() -> Result<()> {
     
     let config = Config::new();
     
-    // Initialize logging
-    tracing_subscriber::fmt()
+    // Initialize logging; the format only applies to tracing, not the REPL's println output
+    let subscriber = tracing_subscriber::fmt()
         .with_max_level(config.log_level())
-        .with_target(false)
-        .init();
+        .with_target(false);
+    match config.log_format() {
+        LogFormat::Pretty => subscriber.init(),
+        LogFormat::Json => subscriber.json().init(),
+    }
 
     info!("🚀 Starting RIG AI Agent Client");
     
@@ -28,18 +34,60 @@ async fn main() -> Result<()> {
     let api_key = config.anthropic_api_key()?;
     let anthropic_client = Client::new(&api_key);
 
-    // Create blockchain agent with Claude
-    let mut agent = BlockchainAgent::new(anthropic_client, &config.mcp_server).await?;
-    
-    info!("🔗 Connected to MCP server at: {}", config.mcp_server);
+    let mut model_settings = config.model_settings();
+    model_settings.system_prompt = config.system_prompt_template()?;
+    if let Some(path) = &config.system_prompt {
+        info!("📜 Using system prompt from {}", path.display());
+    }
+
+    // Create blockchain agent with Claude, or a degraded agent without blockchain tools
+    let mut agent = if config.no_mcp {
+        BlockchainAgent::without_mcp(anthropic_client, model_settings)
+    } else {
+        let agent = BlockchainAgent::with_connection_retry(
+            anthropic_client,
+            &config.mcp_server,
+            model_settings,
+            config.mcp_connect_attempts,
+            config.mcp_retry_delay(),
+        ).await;
+        match agent {
+            Ok(agent) => {
+                info!("🔗 Connected to MCP server(s) at: {}", config.mcp_server.join(", "));
+                agent
+            }
+            Err(e) => {
+                eprintln!("{}", mcp_unreachable_help(&config.mcp_server.join(", "), config.mcp_connect_attempts.max(1)));
+                return Err(e);
+            }
+        }
+    };
+    agent.set_rag_min_score(config.rag_min_score);
+    agent.set_rag_context_budget(config.rag_context_chars);
+    agent.set_rag_dedup_threshold(config.rag_dedup_threshold);
+    agent.set_rag_max_chunk_chars(config.rag_max_chunk_chars);
+    agent.set_embedding_model(&config.embedding_model)?;
+    agent.set_tool_trace(config.trace_tools);
+    agent.set_fast_path(config.fast_path);
+    if let Some(path) = &config.audit_log {
+        agent.set_audit_log(AuditLog::open(path)?);
+        info!("📝 Logging tool calls to {}", path.display());
+    }
     
     // Initialize RAG system with sample Uniswap documentation
     agent.initialize_rag_system(None).await?;
     info!("✅ RAG system initialized successfully");
     
-    // Start CLI REPL
-    let mut repl = Repl::new(agent);
-    repl.run().await?;
+    // Replay a script, or start the interactive CLI REPL
+    let mut repl = Repl::new(agent).with_dry_prompt(config.dry_prompt);
+    if let Some(path) = &config.script {
+        let failures = repl.run_script(path, config.continue_on_error).await?;
+        if failures > 0 {
+            return Err(ClientError::Cli(format!("{} script command(s) failed", failures)));
+        }
+    } else {
+        repl.run().await?;
+    }
     
     Ok(())
 }
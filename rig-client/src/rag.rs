@@ -7,17 +7,410 @@
 //! 4. Search functionality for Uniswap docs and contracts
 
 use rig::{
-    embeddings::EmbeddingsBuilder, 
+    embeddings::{Embedding, EmbeddingModel, EmbeddingsBuilder}, 
     vector_store::{in_memory_store::InMemoryVectorStore, VectorStoreIndex},
-    Embed,
+    Embed, OneOrMany,
 };
 use rig_fastembed::{Client as FastembedClient, FastembedModel};
 
 use serde::{Deserialize, Serialize};
-use std::path::Path;
+use sha2::{Digest, Sha256};
+use std::path::{Path, PathBuf};
 use tracing::{debug, info, warn};
 use crate::doc_ingestion::store::InMemoryDocStore;
 
+/// Default minimum cosine score for a search result to be considered relevant
+pub const DEFAULT_MIN_RELEVANCE: f64 = 0.3;
+
+/// Default location of the cached vector index (override with RAG_INDEX_PATH)
+pub const DEFAULT_INDEX_PATH: &str = "../.rag_cache/uniswap_index.json";
+
+/// How many vector hits per requested result are rescored when reranking
+pub const RERANK_CANDIDATE_MULTIPLIER: usize = 3;
+
+/// Weight of the cosine score in the reranked score; the rest is keyword overlap
+pub const RERANK_VECTOR_WEIGHT: f64 = 0.7;
+
+/// Default character budget for documentation excerpts injected into one prompt
+pub const DEFAULT_CONTEXT_BUDGET_CHARS: usize = 6000;
+
+/// Most excerpts taken from any one retrieved document
+pub const MAX_CHUNKS_PER_DOCUMENT: usize = 2;
+
+/// Default cosine similarity above which a document is indexed as a duplicate of an earlier one
+pub const DEFAULT_DEDUP_THRESHOLD: f64 = 0.97;
+
+/// Default longest text, in characters, passed to the embedding model in one piece
+///
+/// About 500 tokens of English or Solidity, inside the 512-token input of the
+/// supported fastembed models.
+pub const DEFAULT_MAX_EMBED_CHARS: usize = 2000;
+
+/// Lowercased alphanumeric terms of a text, ignoring very short words
+fn keyword_terms(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric() && c != '_')
+        .filter(|term| term.len() > 2)
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// BM25-style keyword overlap of `query` with each text, normalised to 0.0..=1.0
+///
+/// IDF is computed over the given texts, so scores are only comparable within
+/// one candidate set.
+pub fn keyword_overlap_scores(query: &str, texts: &[String]) -> Vec<f64> {
+    const K1: f64 = 1.2;
+    const B: f64 = 0.75;
+    
+    let mut query_terms = keyword_terms(query);
+    query_terms.sort();
+    query_terms.dedup();
+    
+    let docs: Vec<Vec<String>> = texts.iter().map(|t| keyword_terms(t)).collect();
+    if docs.is_empty() || query_terms.is_empty() {
+        return vec![0.0; texts.len()];
+    }
+    let avg_len = (docs.iter().map(Vec::len).sum::<usize>() as f64 / docs.len() as f64).max(1.0);
+    let n = docs.len() as f64;
+    
+    let raw: Vec<f64> = docs.iter()
+        .map(|doc| {
+            let len = doc.len() as f64;
+            query_terms.iter()
+                .map(|term| {
+                    let tf = doc.iter().filter(|t| *t == term).count() as f64;
+                    if tf == 0.0 {
+                        return 0.0;
+                    }
+                    let df = docs.iter().filter(|d| d.contains(term)).count() as f64;
+                    let idf = ((n - df + 0.5) / (df + 0.5) + 1.0).ln();
+                    idf * tf * (K1 + 1.0) / (tf + K1 * (1.0 - B + B * len / avg_len))
+                })
+                .sum()
+        })
+        .collect();
+    
+    let max = raw.iter().cloned().fold(0.0, f64::max);
+    if max <= 0.0 {
+        return vec![0.0; texts.len()];
+    }
+    raw.into_iter().map(|score| score / max).collect()
+}
+
+/// Rescore vector hits by combining cosine and keyword overlap, keeping the top `limit`
+///
+/// The returned score is the combined score, sorted descending.
+pub fn rerank_results(query: &str, results: Vec<(f64, String, UniswapDocument)>, limit: usize) -> Vec<(f64, String, UniswapDocument)> {
+    let texts: Vec<String> = results.iter()
+        .map(|(_, _, doc)| format!("{}\n{}", doc.title, doc.content))
+        .collect();
+    let keyword_scores = keyword_overlap_scores(query, &texts);
+    
+    let mut reranked: Vec<_> = results.into_iter()
+        .zip(keyword_scores)
+        .map(|((cosine, id, doc), keyword)| {
+            (RERANK_VECTOR_WEIGHT * cosine + (1.0 - RERANK_VECTOR_WEIGHT) * keyword, id, doc)
+        })
+        .collect();
+    reranked.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap_or(std::cmp::Ordering::Equal));
+    reranked.truncate(limit);
+    reranked
+}
+
+/// One page of ranked search results, sliced out of the top `offset + limit`
+#[derive(Clone, Debug)]
+pub struct SearchPage {
+    /// Results on this page, best first
+    pub results: Vec<(f64, String, UniswapDocument)>,
+    /// Rank of the first result, zero-based
+    pub offset: usize,
+    /// Number of documents that could be returned across all pages
+    pub total: usize,
+}
+
+impl SearchPage {
+    /// Slice everything from `offset` onward out of the ranked top `offset + limit`
+    pub fn from_ranked(ranked: Vec<(f64, String, UniswapDocument)>, offset: usize, total: usize) -> Self {
+        let results = ranked.into_iter().skip(offset).collect();
+        Self { results, offset, total }
+    }
+
+    /// Footer such as "Showing 6-10 of 42, use offset 10 for more"
+    pub fn footer(&self) -> String {
+        if self.results.is_empty() {
+            return format!("Showing 0 of {}", self.total);
+        }
+        let end = self.offset + self.results.len();
+        let shown = format!("Showing {}-{} of {}", self.offset + 1, end, self.total);
+        if end < self.total {
+            format!("{}, use offset {} for more", shown, end)
+        } else {
+            shown
+        }
+    }
+}
+
+/// Render search results as a self-describing markdown report
+///
+/// The header records the query, when the search ran and the embedding model
+/// that scored it; each result lists its title, score, tags and full content.
+/// Source code is fenced so it renders as code.
+pub fn search_results_markdown(
+    query: &str,
+    embedding_model: &str,
+    generated_at: chrono::DateTime<chrono::Utc>,
+    results: &[(f64, String, UniswapDocument)],
+) -> String {
+    let mut markdown = format!("# RAG search: {}\n\n", query);
+    markdown.push_str(&format!("- Generated: {}\n", generated_at.to_rfc3339()));
+    markdown.push_str(&format!("- Embedding model: {}\n", embedding_model));
+    markdown.push_str(&format!("- Results: {}\n", results.len()));
+    
+    for (rank, (score, id, doc)) in results.iter().enumerate() {
+        markdown.push_str(&format!("\n## {}. {} (score {:.3})\n\n", rank + 1, doc.title, score));
+        markdown.push_str(&format!("- ID: `{}`\n", id));
+        let tags = if doc.metadata.tags.is_empty() { "none".to_string() } else { doc.metadata.tags.join(", ") };
+        markdown.push_str(&format!("- Tags: {}\n\n", tags));
+        
+        let content = doc.content.trim();
+        match doc.doc_type {
+            DocumentType::ContractCode | DocumentType::Interface | DocumentType::Example => {
+                markdown.push_str(&format!("```\n{}\n```\n", content));
+            }
+            _ => markdown.push_str(&format!("{}\n", content)),
+        }
+    }
+    markdown
+}
+
+/// An excerpt of a retrieved document, scored against the query
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScoredChunk {
+    /// Index of the document in the search results
+    pub document: usize,
+    /// Position of the chunk within its document
+    pub position: usize,
+    /// Cosine similarity of the chunk to the query
+    pub score: f64,
+    /// Chunk text
+    pub text: String,
+}
+
+/// Cosine similarity of two embedding vectors (0.0 for empty or zero vectors)
+pub fn cosine_similarity(a: &[f64], b: &[f64]) -> f64 {
+    let dot: f64 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm_a = a.iter().map(|x| x * x).sum::<f64>().sqrt();
+    let norm_b = b.iter().map(|x| x * x).sum::<f64>().sqrt();
+    if norm_a == 0.0 || norm_b == 0.0 {
+        return 0.0;
+    }
+    dot / (norm_a * norm_b)
+}
+
+/// Drop embedded documents that duplicate a document kept before them
+///
+/// A candidate is a duplicate when its content is identical to a kept
+/// document's, or when both have the same number of embeddings and every pair
+/// of matching embeddings (content, then each chunk) is more similar than
+/// `threshold`. The content embedding only covers a prefix of long documents,
+/// so the chunks decide whether their bodies match. `kept` seeds the
+/// comparison, e.g. with the documents already indexed, and is never filtered
+/// itself. Returns the surviving candidates in order and how many were dropped.
+pub fn dedup_documents(
+    kept: &[(UniswapDocument, OneOrMany<Embedding>)],
+    candidates: Vec<(UniswapDocument, OneOrMany<Embedding>)>,
+    threshold: f64,
+) -> (Vec<(UniswapDocument, OneOrMany<Embedding>)>, usize) {
+    let mut contents: std::collections::HashSet<String> = kept.iter()
+        .map(|(doc, _)| doc.content.clone())
+        .collect();
+    let vectors_of = |embeddings: &OneOrMany<Embedding>| -> Vec<Vec<f64>> {
+        embeddings.iter().map(|embedding| embedding.vec.clone()).collect()
+    };
+    let mut vectors: Vec<Vec<Vec<f64>>> = kept.iter()
+        .map(|(_, embeddings)| vectors_of(embeddings))
+        .collect();
+    
+    let mut survivors = Vec::with_capacity(candidates.len());
+    let mut duplicates = 0;
+    for (doc, embeddings) in candidates {
+        let candidate = vectors_of(&embeddings);
+        let duplicate = contents.contains(&doc.content)
+            || vectors.iter().any(|kept| {
+                kept.len() == candidate.len()
+                    && kept.iter().zip(&candidate).all(|(a, b)| cosine_similarity(a, b) > threshold)
+            });
+        if duplicate {
+            debug!("🪞 Dropping duplicate document {} ({})", doc.title, doc.id);
+            duplicates += 1;
+            continue;
+        }
+        contents.insert(doc.content.clone());
+        vectors.push(candidate);
+        survivors.push((doc, embeddings));
+    }
+    (survivors, duplicates)
+}
+
+/// Keep the best chunks that fit in `budget_chars`, dropping the lowest-scoring first
+///
+/// If even the best chunk is longer than the budget it is truncated to fit.
+/// The survivors are returned in document order, then chunk order.
+pub fn fit_chunks_to_budget(mut chunks: Vec<ScoredChunk>, budget_chars: usize) -> Vec<ScoredChunk> {
+    chunks.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal));
+    
+    let mut used = 0;
+    let mut kept = Vec::new();
+    for mut chunk in chunks {
+        let len = chunk.text.chars().count();
+        if used + len <= budget_chars {
+            used += len;
+            kept.push(chunk);
+        } else if kept.is_empty() && budget_chars > 0 {
+            chunk.text = format!("{}…", chunk.text.chars().take(budget_chars.saturating_sub(1)).collect::<String>());
+            used = budget_chars;
+            kept.push(chunk);
+        }
+    }
+    
+    kept.sort_by_key(|chunk| (chunk.document, chunk.position));
+    kept
+}
+
+/// Split a document's oversized chunks so each piece fits in `max_chars`
+///
+/// Semantic chunks, code examples and function signatures longer than the
+/// limit are replaced in place by their pieces, preferring line boundaries. A
+/// document with no semantic chunks and oversized content gets the content
+/// pieces as its chunks, so the whole text stays searchable. Returns how many
+/// chunks were split.
+pub fn split_oversized_chunks(doc: &mut UniswapDocument, max_chars: usize) -> usize {
+    let max_chars = max_chars.max(1);
+    if doc.semantic_chunks.is_empty() && doc.content.chars().count() > max_chars {
+        doc.semantic_chunks = vec![doc.content.clone()];
+    }
+    
+    let mut split = 0;
+    for chunks in [&mut doc.semantic_chunks, &mut doc.code_examples, &mut doc.function_signatures] {
+        if chunks.iter().all(|chunk| chunk.chars().count() <= max_chars) {
+            continue;
+        }
+        *chunks = std::mem::take(chunks).into_iter()
+            .flat_map(|chunk| {
+                let pieces = crate::doc_ingestion::split_to_size(&chunk, max_chars);
+                if pieces.len() > 1 {
+                    split += 1;
+                }
+                pieces
+            })
+            .collect();
+    }
+    split
+}
+
+/// Copy of `doc` to embed, with its content cut to `max_chars`
+///
+/// Only the embedding input is cut: the indexed document keeps its full content,
+/// and the rest of it is embedded through the chunks.
+fn embedding_input(doc: &UniswapDocument, max_chars: usize) -> UniswapDocument {
+    let mut input = doc.clone();
+    if let Some((end, _)) = input.content.char_indices().nth(max_chars.max(1)) {
+        input.content.truncate(end);
+    }
+    input
+}
+
+/// On-disk index format version - bump when `UniswapDocument` or the embedding model changes
+const INDEX_FORMAT_VERSION: u32 = 1;
+
+/// Embedding model used unless another is configured (`--embedding-model`)
+///
+/// The model name is stored alongside the vectors, so a cached index is only
+/// reused by a system configured with the same model.
+pub const DEFAULT_EMBEDDING_MODEL: &str = "AllMiniLML6V2Q";
+
+/// Fastembed models that can be selected by name
+const SUPPORTED_EMBEDDING_MODELS: &[(&str, FastembedModel)] = &[
+    ("AllMiniLML6V2", FastembedModel::AllMiniLML6V2),
+    ("AllMiniLML6V2Q", FastembedModel::AllMiniLML6V2Q),
+    ("AllMiniLML12V2", FastembedModel::AllMiniLML12V2),
+    ("AllMiniLML12V2Q", FastembedModel::AllMiniLML12V2Q),
+    ("BGESmallENV15", FastembedModel::BGESmallENV15),
+    ("BGESmallENV15Q", FastembedModel::BGESmallENV15Q),
+    ("BGEBaseENV15", FastembedModel::BGEBaseENV15),
+    ("BGEBaseENV15Q", FastembedModel::BGEBaseENV15Q),
+    ("BGELargeENV15", FastembedModel::BGELargeENV15),
+    ("BGELargeENV15Q", FastembedModel::BGELargeENV15Q),
+    ("NomicEmbedTextV1", FastembedModel::NomicEmbedTextV1),
+    ("NomicEmbedTextV15", FastembedModel::NomicEmbedTextV15),
+    ("NomicEmbedTextV15Q", FastembedModel::NomicEmbedTextV15Q),
+    ("ParaphraseMLMiniLML12V2", FastembedModel::ParaphraseMLMiniLML12V2),
+    ("ParaphraseMLMiniLML12V2Q", FastembedModel::ParaphraseMLMiniLML12V2Q),
+    ("ParaphraseMLMpnetBaseV2", FastembedModel::ParaphraseMLMpnetBaseV2),
+    ("MultilingualE5Small", FastembedModel::MultilingualE5Small),
+    ("MultilingualE5Base", FastembedModel::MultilingualE5Base),
+    ("MultilingualE5Large", FastembedModel::MultilingualE5Large),
+    ("MxbaiEmbedLargeV1", FastembedModel::MxbaiEmbedLargeV1),
+    ("MxbaiEmbedLargeV1Q", FastembedModel::MxbaiEmbedLargeV1Q),
+];
+
+/// Names accepted by `parse_embedding_model`
+pub fn supported_embedding_models() -> Vec<&'static str> {
+    SUPPORTED_EMBEDDING_MODELS.iter().map(|(name, _)| *name).collect()
+}
+
+/// Look up a fastembed model by name (case-insensitive), returning its canonical name
+pub fn parse_embedding_model(name: &str) -> crate::Result<(&'static str, FastembedModel)> {
+    SUPPORTED_EMBEDDING_MODELS.iter()
+        .find(|(candidate, _)| candidate.eq_ignore_ascii_case(name.trim()))
+        .map(|(candidate, model)| (*candidate, model.clone()))
+        .ok_or_else(|| crate::ClientError::Config(format!(
+            "Unknown embedding model '{}'. Supported models: {}",
+            name,
+            supported_embedding_models().join(", ")
+        )))
+}
+
+/// Serialized form of the vector index written by `save_index`
+#[derive(Serialize, Deserialize)]
+struct PersistedIndex {
+    format_version: u32,
+    embedding_model: String,
+    document_count: usize,
+    checksum: String,
+    entries: Vec<PersistedEntry>,
+    /// Identity and checksum of documents left out as duplicates, so they aren't embedded again
+    #[serde(default)]
+    duplicates: std::collections::BTreeMap<String, String>,
+}
+
+/// A single embedded document and its vectors
+#[derive(Serialize, Deserialize)]
+struct PersistedEntry {
+    document: UniswapDocument,
+    embeddings: Vec<Embedding>,
+}
+
+impl PersistedIndex {
+    /// Checksum over document identity, content and embedding shapes
+    ///
+    /// Vector values are left out on purpose: float text round-tripping isn't
+    /// guaranteed bit-exact, but a truncated or edited file still changes the shape.
+    fn compute_checksum(entries: &[PersistedEntry]) -> String {
+        let mut hasher = Sha256::new();
+        for entry in entries {
+            hasher.update(entry.document.id.as_bytes());
+            hasher.update(entry.document.title.as_bytes());
+            hasher.update(entry.document.content.as_bytes());
+            hasher.update(entry.embeddings.len().to_le_bytes());
+            for embedding in &entry.embeddings {
+                hasher.update(embedding.vec.len().to_le_bytes());
+            }
+        }
+        format!("{:x}", hasher.finalize())
+    }
+}
+
 /// Document structure for storing Uniswap documentation and contract code with semantic chunking
 #[derive(rig::Embed, Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct UniswapDocument {
@@ -283,42 +676,243 @@ impl UniswapDocument {
             }
         }
         
-        self.semantic_chunks = chunks;
+        self.semantic_chunks = crate::doc_ingestion::DefaultDocumentProcessor::default().apply_window(chunks);
         self.code_examples = examples;
         self.function_signatures = signatures;
     }
 }
 
+/// Restricts search results to a document type and/or a set of required tags
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SearchFilter {
+    /// Only return documents of this type
+    pub doc_type: Option<DocumentType>,
+    /// Every tag must match a document tag or its version (case-insensitive)
+    pub tags: Vec<String>,
+}
+
+impl SearchFilter {
+    pub fn with_doc_type(mut self, doc_type: DocumentType) -> Self {
+        self.doc_type = Some(doc_type);
+        self
+    }
+
+    pub fn with_tag(mut self, tag: impl Into<String>) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.doc_type.is_none() && self.tags.is_empty()
+    }
+
+    /// Whether a document passes this filter
+    pub fn matches(&self, doc: &UniswapDocument) -> bool {
+        if let Some(doc_type) = &self.doc_type
+            && &doc.doc_type != doc_type {
+                return false;
+            }
+        
+        self.tags.iter().all(|required| {
+            doc.metadata.tags.iter().any(|tag| tag.eq_ignore_ascii_case(required))
+                || doc.metadata.version.as_deref().is_some_and(|v| v.eq_ignore_ascii_case(required))
+        })
+    }
+
+    /// Infer a version filter from "v2"/"v3" mentions in a query
+    ///
+    /// Returns `None` when no version (or more than one) is mentioned, so
+    /// comparison questions still search across versions.
+    pub fn from_query(query: &str) -> Option<Self> {
+        let lowercase = query.to_lowercase();
+        let versions: Vec<&str> = ["v2", "v3"].into_iter()
+            .filter(|version| {
+                lowercase.split(|c: char| !c.is_alphanumeric()).any(|word| word == *version)
+            })
+            .collect();
+        
+        match versions.as_slice() {
+            [version] => Some(Self::default().with_tag(*version)),
+            _ => None,
+        }
+    }
+}
+
+/// Summary of one indexed document, as written by `export_documents`
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ExportedDocument {
+    pub id: String,
+    pub title: String,
+    pub doc_type: DocumentType,
+    pub tags: Vec<String>,
+    pub content_length: usize,
+    pub chunk_count: usize,
+}
+
+/// Composition of the indexed corpus
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct CorpusStats {
+    pub total_documents: usize,
+    /// Document counts keyed by `DocumentType`
+    pub documents_per_type: std::collections::BTreeMap<String, usize>,
+    pub total_chunks: usize,
+    pub average_content_length: f64,
+}
+
+impl std::fmt::Display for CorpusStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Documents: {}", self.total_documents)?;
+        for (doc_type, count) in &self.documents_per_type {
+            writeln!(f, "  • {}: {}", doc_type, count)?;
+        }
+        writeln!(f, "Total chunks: {}", self.total_chunks)?;
+        write!(f, "Average content length: {:.0} chars", self.average_content_length)
+    }
+}
+
+/// Health metrics of the vector index, as shown by `rag-stats`
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct IndexStats {
+    pub total_documents: usize,
+    pub total_chunks: usize,
+    /// Document counts keyed by `DocumentType`
+    pub documents_per_type: std::collections::BTreeMap<String, usize>,
+    /// Document counts keyed by version ("unversioned" when a document has none)
+    pub documents_per_version: std::collections::BTreeMap<String, usize>,
+    pub embedding_model: String,
+    /// Length of each embedding vector; `None` until something is indexed
+    pub embedding_dimensions: Option<usize>,
+    /// Embedding vectors stored, one or more per document
+    pub total_embeddings: usize,
+    /// Rough size of the indexed text and vectors in memory, in bytes
+    pub approximate_memory_bytes: usize,
+    /// Documents left out of the index as duplicates of an indexed one
+    #[serde(default)]
+    pub duplicate_documents: usize,
+}
+
+impl std::fmt::Display for IndexStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Documents: {}", self.total_documents)?;
+        writeln!(f, "Chunks: {}", self.total_chunks)?;
+        writeln!(f, "Duplicates skipped: {}", self.duplicate_documents)?;
+        writeln!(f, "By type:")?;
+        for (doc_type, count) in &self.documents_per_type {
+            writeln!(f, "  • {}: {}", doc_type, count)?;
+        }
+        writeln!(f, "By version:")?;
+        for (version, count) in &self.documents_per_version {
+            writeln!(f, "  • {}: {}", version, count)?;
+        }
+        writeln!(f, "Embedding model: {}", self.embedding_model)?;
+        match self.embedding_dimensions {
+            Some(dimensions) => writeln!(f, "Embeddings: {} × {} dimensions", self.total_embeddings, dimensions)?,
+            None => writeln!(f, "Embeddings: none")?,
+        }
+        write!(f, "Approximate memory: {}", format_bytes(self.approximate_memory_bytes))
+    }
+}
+
+/// Byte count in B, KB or MB for display
+fn format_bytes(bytes: usize) -> String {
+    const KB: f64 = 1024.0;
+    let bytes_f = bytes as f64;
+    if bytes_f >= KB * KB {
+        format!("{:.1} MB", bytes_f / (KB * KB))
+    } else if bytes_f >= KB {
+        format!("{:.1} KB", bytes_f / KB)
+    } else {
+        format!("{} B", bytes)
+    }
+}
+
 /// RAG system for Uniswap documentation and contracts
 pub struct UniswapRagSystem {
     /// Vector store index for similarity search
     index: InMemoryVectorStore<UniswapDocument>,
     /// Fastembed client for local embeddings
     embedding_client: FastembedClient,
+    /// Canonical name of the embedding model, persisted with the index
+    embedding_model_name: &'static str,
+    /// Embedding model used for indexing and every query
+    fastembed_model: FastembedModel,
     /// Document count for monitoring
     document_count: usize,
+    /// Cosine similarity above which a newly embedded document is dropped as a duplicate
+    dedup_threshold: f64,
+    /// Documents dropped as duplicates, by identity (`document_key`) to checksum; skipped until they change
+    duplicates: std::collections::HashMap<String, String>,
+    /// Longest text, in characters, sent to the embedding model; longer chunks are split
+    max_chunk_chars: usize,
     /// Document ingestion pipeline
     ingestion_pipeline: Option<crate::doc_ingestion::DocumentIngestionPipeline>,
 }
 
 impl UniswapRagSystem {
-    /// Create a new RAG system with local embedding model and optional configuration
+    /// Create a new RAG system with the default local embedding model
+    ///
+    /// Reuses the cached index at `index_path()` when it is valid; otherwise
+    /// runs the ingestion pipeline and writes a fresh cache.
     pub async fn new() -> crate::Result<Self> {
-        info!("🔧 Initializing Uniswap RAG System with local embeddings");
+        Self::with_embedding_model(DEFAULT_EMBEDDING_MODEL).await
+    }
+
+    /// Create a new RAG system embedding with the named fastembed model
+    ///
+    /// Fails before any ingestion if the model name is unknown. A cached index
+    /// built with a different model is ignored and rebuilt.
+    pub async fn with_embedding_model(model_name: &str) -> crate::Result<Self> {
+        Self::with_embedding_model_and_dedup_threshold(model_name, DEFAULT_DEDUP_THRESHOLD).await
+    }
+
+    /// Create a new RAG system with the named embedding model and duplicate threshold
+    ///
+    /// The threshold only applies when the index is rebuilt; a valid cached
+    /// index is reused as it was saved.
+    pub async fn with_embedding_model_and_dedup_threshold(model_name: &str, dedup_threshold: f64) -> crate::Result<Self> {
+        Self::with_index_settings(model_name, dedup_threshold, DEFAULT_MAX_EMBED_CHARS).await
+    }
+
+    /// Create a new RAG system with the named embedding model, duplicate threshold and chunk size limit
+    ///
+    /// Like the threshold, `max_chunk_chars` only applies when the index is rebuilt.
+    pub async fn with_index_settings(model_name: &str, dedup_threshold: f64, max_chunk_chars: usize) -> crate::Result<Self> {
+        parse_embedding_model(model_name)?;
+        info!("🔧 Initializing Uniswap RAG System with local embeddings ({})", model_name);
         
-        // Initialize Fastembed client for local embeddings
-        let embedding_client = FastembedClient::new();
-        let _embedding_model = embedding_client.embedding_model(&FastembedModel::AllMiniLML6V2Q);
+        let index_path = Self::index_path();
+        match Self::load_index_with_model(&index_path, model_name) {
+            Ok(rag) => {
+                info!("✅ Loaded cached RAG index with {} documents from {}", rag.document_count, index_path.display());
+                return Ok(rag);
+            }
+            Err(e) => info!("📦 No usable cached RAG index at {} ({}), rebuilding", index_path.display(), e),
+        }
         
-        // Create empty vector store with optimized settings
-        let vector_store = InMemoryVectorStore::<UniswapDocument>::from_documents(vec![]);
+        // Create the RAG system
+        let mut rag = Self::empty(model_name)?;
+        rag.set_dedup_threshold(dedup_threshold);
+        rag.set_max_chunk_chars(max_chunk_chars);
+
+        // Load documents immediately
+        rag.load_documentation(&std::path::Path::new("")).await?;
         
+        if rag.document_count > 0
+            && let Err(e) = rag.save_index(&index_path) {
+                warn!("⚠️ Failed to cache RAG index at {}: {}", index_path.display(), e);
+            }
+        
+        Ok(rag)
+    }
+
+    /// Create an empty RAG system with the default ingestion pipeline
+    fn empty(model_name: &str) -> crate::Result<Self> {
         // Initialize document ingestion pipeline
         let doc_source = crate::doc_ingestion::sources::uniswap::UniswapDocSource::new(
             std::path::PathBuf::from("../docs/uniswap")
         );
         
-        let processor = crate::doc_ingestion::DefaultDocumentProcessor;
+        let processor = crate::doc_ingestion::DefaultDocumentProcessor::default();
         let doc_store = InMemoryDocStore::default();
         
         let pipeline = crate::doc_ingestion::DocumentIngestionPipeline::new(
@@ -327,35 +921,168 @@ impl UniswapRagSystem {
             Box::new(doc_store)
         );
         
-        info!("✅ RAG System initialized with local embedding model and document pipeline");
+        Self::with_pipeline_and_model(pipeline, model_name)
+    }
+
+    /// Create an empty RAG system that ingests from a custom pipeline
+    ///
+    /// Nothing is loaded until `load_documentation` is called.
+    pub fn with_pipeline(pipeline: crate::doc_ingestion::DocumentIngestionPipeline) -> Self {
+        Self::with_pipeline_and_model(pipeline, DEFAULT_EMBEDDING_MODEL)
+            .expect("default embedding model is supported")
+    }
+
+    /// Create an empty RAG system that ingests from a custom pipeline using the named embedding model
+    pub fn with_pipeline_and_model(pipeline: crate::doc_ingestion::DocumentIngestionPipeline, model_name: &str) -> crate::Result<Self> {
+        let (embedding_model_name, fastembed_model) = parse_embedding_model(model_name)?;
+        
+        // Create empty vector store with optimized settings
+        let vector_store = InMemoryVectorStore::<UniswapDocument>::from_documents(vec![]);
         
-        // Create the RAG system
-        let mut rag = Self {
+        info!("✅ RAG System initialized with local embedding model {} and document pipeline", embedding_model_name);
+        
+        Ok(Self {
             index: vector_store,
-            embedding_client,
+            embedding_client: FastembedClient::new(),
+            embedding_model_name,
+            fastembed_model,
             document_count: 0,
+            dedup_threshold: DEFAULT_DEDUP_THRESHOLD,
+            duplicates: std::collections::HashMap::new(),
+            max_chunk_chars: DEFAULT_MAX_EMBED_CHARS,
             ingestion_pipeline: Some(pipeline),
+        })
+    }
+
+    /// Name of the embedding model this system indexes and searches with
+    pub fn embedding_model_name(&self) -> &str {
+        self.embedding_model_name
+    }
+
+    /// Cosine similarity above which newly embedded documents are dropped as duplicates
+    pub fn dedup_threshold(&self) -> f64 {
+        self.dedup_threshold
+    }
+
+    /// Set the duplicate threshold used by later indexing (0.0-1.0; 1.0 only drops identical content)
+    pub fn set_dedup_threshold(&mut self, threshold: f64) {
+        self.dedup_threshold = threshold;
+    }
+
+    /// Longest text, in characters, sent to the embedding model in one piece
+    pub fn max_chunk_chars(&self) -> usize {
+        self.max_chunk_chars
+    }
+
+    /// Set the chunk size limit used by later indexing (at least one character)
+    pub fn set_max_chunk_chars(&mut self, max_chars: usize) {
+        self.max_chunk_chars = max_chars.max(1);
+    }
+
+    /// The configured embedding model, bound to the fastembed client
+    fn embedder(&self) -> rig_fastembed::EmbeddingModel {
+        self.embedding_client.embedding_model(&self.fastembed_model)
+    }
+
+    /// Location of the cached index, from RAG_INDEX_PATH or `DEFAULT_INDEX_PATH`
+    pub fn index_path() -> PathBuf {
+        std::env::var("RAG_INDEX_PATH")
+            .map(PathBuf::from)
+            .unwrap_or_else(|_| PathBuf::from(DEFAULT_INDEX_PATH))
+    }
+
+    /// Save the embedded documents and their vectors so later startups can skip ingestion
+    pub fn save_index(&self, path: &Path) -> crate::Result<()> {
+        let mut entries: Vec<PersistedEntry> = self.index.iter()
+            .map(|(_, (document, embeddings))| PersistedEntry {
+                document: document.clone(),
+                embeddings: embeddings.iter().cloned().collect(),
+            })
+            .collect();
+        // Stable ordering keeps the file (and checksum) deterministic across runs
+        entries.sort_by(|a, b| a.document.id.cmp(&b.document.id));
+        
+        let persisted = PersistedIndex {
+            format_version: INDEX_FORMAT_VERSION,
+            embedding_model: self.embedding_model_name.to_string(),
+            document_count: entries.len(),
+            checksum: PersistedIndex::compute_checksum(&entries),
+            entries,
+            duplicates: self.duplicates.iter().map(|(key, id)| (key.clone(), id.clone())).collect(),
         };
+        
+        if let Some(parent) = path.parent()
+            && !parent.as_os_str().is_empty() {
+                std::fs::create_dir_all(parent)?;
+            }
+        std::fs::write(path, serde_json::to_vec(&persisted)?)?;
+        
+        info!("💾 Saved RAG index with {} documents to {}", persisted.document_count, path.display());
+        Ok(())
+    }
 
-        // Load documents immediately
-        rag.load_documentation(&std::path::Path::new("")).await?;
+    /// Load a previously saved index, rejecting stale or corrupt caches
+    pub fn load_index(path: &Path) -> crate::Result<Self> {
+        Self::load_index_with_model(path, DEFAULT_EMBEDDING_MODEL)
+    }
+
+    /// Load an index written by `save_index`, requiring it to match the named embedding model
+    pub fn load_index_with_model(path: &Path, model_name: &str) -> crate::Result<Self> {
+        let (embedding_model_name, _) = parse_embedding_model(model_name)?;
+        let bytes = std::fs::read(path)?;
+        let persisted: PersistedIndex = serde_json::from_slice(&bytes)?;
+        
+        if persisted.format_version != INDEX_FORMAT_VERSION {
+            return Err(crate::ClientError::rag(format!(
+                "Index format version {} does not match expected {}",
+                persisted.format_version, INDEX_FORMAT_VERSION
+            )));
+        }
+        if persisted.embedding_model != embedding_model_name {
+            return Err(crate::ClientError::rag(format!(
+                "Index was built with embedding model '{}', expected '{}'",
+                persisted.embedding_model, embedding_model_name
+            )));
+        }
+        if persisted.document_count == 0 || persisted.document_count != persisted.entries.len() {
+            return Err(crate::ClientError::rag(format!(
+                "Index document count mismatch: header says {}, file contains {}",
+                persisted.document_count, persisted.entries.len()
+            )));
+        }
+        if PersistedIndex::compute_checksum(&persisted.entries) != persisted.checksum {
+            return Err(crate::ClientError::rag("Index checksum mismatch - cache is corrupt"));
+        }
         
+        let mut documents = Vec::with_capacity(persisted.entries.len());
+        for entry in persisted.entries {
+            let embeddings = OneOrMany::many(entry.embeddings)
+                .map_err(|_| crate::ClientError::rag(format!("Document '{}' has no embeddings", entry.document.id)))?;
+            documents.push((entry.document, embeddings));
+        }
+        
+        let mut rag = Self::empty(embedding_model_name)?;
+        rag.document_count = documents.len();
+        rag.duplicates = persisted.duplicates.into_iter().collect();
+        rag.index = InMemoryVectorStore::from_documents_with_id_f(documents, |doc| doc.id.clone());
         Ok(rag)
     }
     
     /// Load and index Uniswap documentation using the ingestion pipeline
-    pub async fn load_documentation(&mut self, _docs_path: &Path) -> crate::Result<()> {
+    ///
+    /// Returns the ingestion stats, including how many documents were dropped
+    /// as duplicates while indexing.
+    pub async fn load_documentation(&mut self, _docs_path: &Path) -> crate::Result<crate::doc_ingestion::IngestionStats> {
         info!("📚 Loading Uniswap documentation using ingestion pipeline");
         
         if let Some(pipeline) = &self.ingestion_pipeline {
             // Run the ingestion pipeline
-            let stats = pipeline.run().await
-                .map_err(|e| crate::ClientError::RagError(format!("Document ingestion failed: {}", e)))?;
+            let mut stats = pipeline.run().await?;
             
             info!("📊 Document ingestion stats:");
             info!("   Total documents: {}", stats.total_documents);
             info!("   Successfully processed: {}", stats.successful_documents);
-            info!("   Failed: {}", stats.failed_documents);
+            info!("   Failed: {} ({} skipped as not indexable)", stats.failed_documents, stats.skipped_documents());
             
             if !stats.errors.is_empty() {
                 warn!("⚠️ Ingestion errors:");
@@ -367,35 +1094,19 @@ impl UniswapRagSystem {
             // Convert processed documents to UniswapDocuments and index them
             let mut documents = Vec::new();
             
-            // Run the pipeline to process documents
-            let stats = pipeline.run().await
-                .map_err(|e| crate::ClientError::RagError(format!("Failed to process documents: {}", e)))?;
-            
-            info!("📊 Document ingestion stats:");
-            info!("   Total documents: {}", stats.total_documents);
-            info!("   Successfully processed: {}", stats.successful_documents);
-            info!("   Failed: {}", stats.failed_documents);
-            
-            if !stats.errors.is_empty() {
-                warn!("⚠️ Ingestion errors:");
-                for error in &stats.errors {
-                    warn!("   - {}", error);
-                }
-            }
-            
             if stats.successful_documents == 0 {
                 warn!("⚠️ No documents were successfully processed");
-                return Ok(());
+                return Ok(stats);
             }
             
             // Get all documents from the store
             let store = pipeline.get_store();
-            let doc_list = store.list_documents().await
-                .map_err(|e| crate::ClientError::RagError(format!("Failed to list documents: {}", e)))?;
+            let checksums = store.list_checksums().await
+                .map_err(|e| crate::ClientError::rag_with("Failed to list documents", e))?;
             
-            for metadata in doc_list {
-                // Use title as a simple key since we don't have checksum in metadata
-                if let Ok(Some(doc)) = store.get_document(&metadata.title).await {
+            for checksum in checksums {
+                if let Ok(Some(doc)) = store.get_document(&checksum).await {
+                    let updated_at = doc.metadata.updated_at;
                     let uniswap_doc = UniswapDocument {
                         id: doc.checksum.clone(),
                         title: doc.metadata.title,
@@ -417,74 +1128,376 @@ impl UniswapRagSystem {
                         parent_id: None,
                         chunk_type: None,
                     };
-                    documents.push(uniswap_doc);
+                    documents.push((updated_at, uniswap_doc));
                 }
             }
             
+            // A re-ingested document that changed is stored under its new checksum
+            // alongside the old one; keep only the latest version of each
+            documents.sort_by(|a, b| b.0.cmp(&a.0));
+            let mut seen = std::collections::HashSet::new();
+            let mut latest = Vec::with_capacity(documents.len());
+            for (_, doc) in documents {
+                if seen.insert(Self::document_key(&doc)) {
+                    latest.push(doc);
+                } else if let Err(e) = store.delete_document(&doc.id).await {
+                    warn!("⚠️ Failed to remove superseded document {}: {}", doc.id, e);
+                }
+            }
+            let documents = latest;
+            
             if documents.is_empty() {
                 warn!("⚠️ No documents were successfully processed");
-                return Ok(());
+                return Ok(stats);
             }
             
-            // Index the documents
-            self.index_documents(documents).await?;
+            // Index the documents, re-embedding only new or changed ones
+            let (_, duplicates) = self.index_documents(documents).await?;
+            stats.duplicate_documents = duplicates;
+            info!("   Duplicates removed: {} (similarity above {:.2})", stats.duplicate_documents, self.dedup_threshold);
             
             info!("✅ Successfully loaded and indexed {} documents", self.document_count);
+            Ok(stats)
         } else {
             warn!("⚠️ No ingestion pipeline available");
+            Ok(crate::doc_ingestion::IngestionStats::default())
         }
-        
-        Ok(())
     }
     
 
     
-    /// Index documents in the vector store
-    async fn index_documents(&mut self, documents: Vec<UniswapDocument>) -> crate::Result<()> {
-        info!("🔍 Indexing {} documents in vector store", documents.len());
+    /// Re-run ingestion and swap the rebuilt index in place
+    ///
+    /// Documents are re-ingested and re-embedded into a staging index that only
+    /// replaces the live one once it is complete, so searches never see a
+    /// half-built index; if ingestion fails or finds nothing the current index
+    /// is kept. Returns the old and new document counts.
+    pub async fn reindex(&mut self) -> crate::Result<(usize, usize)> {
+        let pipeline = self.ingestion_pipeline.take()
+            .ok_or_else(|| crate::ClientError::rag("No ingestion pipeline available to reindex from"))?;
+        
+        let mut staged = Self {
+            index: InMemoryVectorStore::from_documents(vec![]),
+            embedding_client: FastembedClient::new(),
+            embedding_model_name: self.embedding_model_name,
+            fastembed_model: self.fastembed_model.clone(),
+            document_count: 0,
+            dedup_threshold: self.dedup_threshold,
+            duplicates: std::collections::HashMap::new(),
+            max_chunk_chars: self.max_chunk_chars,
+            ingestion_pipeline: Some(pipeline),
+        };
+        let loaded = staged.load_documentation(Path::new("")).await;
+        self.ingestion_pipeline = staged.ingestion_pipeline.take();
+        loaded?;
         
-        let embedding_model = self.embedding_client.embedding_model(&FastembedModel::AllMiniLML6V2Q);
+        if staged.document_count == 0 {
+            return Err(crate::ClientError::rag(format!(
+                "Reindex found no documents - keeping the current index of {} documents",
+                self.document_count
+            )));
+        }
         
-        // Create embeddings for all documents using the documents method
+        let previous = self.document_count;
+        self.index = staged.index;
+        self.document_count = staged.document_count;
+        self.duplicates = staged.duplicates;
+        info!("🔄 Reindexed RAG system: {} → {} documents", previous, self.document_count);
+        Ok((previous, self.document_count))
+    }
+    
+    /// Stable identity of a document across content changes (its id is the content checksum)
+    fn document_key(doc: &UniswapDocument) -> String {
+        format!(
+            "{}|{}|{}",
+            doc.metadata.source_path.as_deref().unwrap_or(""),
+            doc.metadata.version.as_deref().unwrap_or(""),
+            doc.title
+        )
+    }
+
+    /// Merge documents into the vector store, embedding only new or changed ones
+    ///
+    /// A document is unchanged when a document with the same identity and checksum
+    /// is already indexed; changed documents replace their previous version.
+    /// Returns the number of documents that were embedded.
+    pub async fn upsert_documents(&mut self, documents: Vec<UniswapDocument>) -> crate::Result<usize> {
+        let (embedded, _) = self.index_documents(documents).await?;
+        Ok(embedded)
+    }
+
+    /// Embed new or changed documents and merge them in, dropping duplicates
+    ///
+    /// Chunks longer than `max_chunk_chars` are split first (see
+    /// `split_oversized_chunks`) so one large file can't fail the whole build.
+    /// Newly embedded documents that duplicate one already kept (see
+    /// `dedup_documents`) are left out of the index. Returns the number of
+    /// documents embedded and how many of them were dropped as duplicates.
+    async fn index_documents(&mut self, documents: Vec<UniswapDocument>) -> crate::Result<(usize, usize)> {
+        let indexed: std::collections::HashMap<String, String> = self.index.iter()
+            .map(|(id, (doc, _))| (Self::document_key(doc), id.clone()))
+            .collect();
+        
+        // Documents already dropped as duplicates stay out until their content changes
+        let mut changed: Vec<UniswapDocument> = documents.into_iter()
+            .filter(|doc| indexed.get(&Self::document_key(doc)) != Some(&doc.id))
+            .filter(|doc| self.duplicates.get(&Self::document_key(doc)) != Some(&doc.id))
+            .collect();
+        
+        if changed.is_empty() {
+            info!("✅ All {} indexed documents are up to date", self.document_count);
+            return Ok((0, 0));
+        }
+        
+        let split: usize = changed.iter_mut()
+            .map(|doc| split_oversized_chunks(doc, self.max_chunk_chars))
+            .sum();
+        if split > 0 {
+            info!("✂️ Split {} chunks longer than {} characters before embedding", split, self.max_chunk_chars);
+        }
+        
+        info!("🔍 Embedding {} new or changed documents", changed.len());
+        
+        let embedding_model = self.embedder();
+        let inputs: Vec<UniswapDocument> = changed.iter()
+            .map(|doc| embedding_input(doc, self.max_chunk_chars))
+            .collect();
         let embeddings = EmbeddingsBuilder::new(embedding_model.clone())
-            .documents(documents.clone())
-            .map_err(|e| crate::ClientError::RagError(format!("Failed to add documents: {}", e)))?
+            .documents(inputs)
+            .map_err(|e| crate::ClientError::rag_with("Failed to add documents", e))?
             .build()
             .await
-            .map_err(|e| crate::ClientError::RagError(format!("Failed to build embeddings: {}", e)))?;
+            .map_err(|e| crate::ClientError::rag_with("Failed to build embeddings", e))?;
         
-        // Create new vector store with embeddings using from_documents_with_id_f
-        let vector_store = InMemoryVectorStore::from_documents_with_id_f(embeddings, |doc| doc.id.clone());
-        self.index = vector_store;
+        // Index the full documents rather than the cut-down embedding inputs
+        let originals: std::collections::HashMap<&String, &UniswapDocument> = changed.iter()
+            .map(|doc| (&doc.id, doc))
+            .collect();
+        let embeddings: Vec<(UniswapDocument, OneOrMany<Embedding>)> = embeddings.into_iter()
+            .map(|(input, embedding)| match originals.get(&input.id) {
+                Some(&original) => (original.clone(), embedding),
+                None => (input, embedding),
+            })
+            .collect();
         
-        self.document_count = documents.len();
-        info!("✅ Successfully indexed {} documents", self.document_count);
+        // Drop previous versions of the changed documents and keep everything else as-is
+        let stale: std::collections::HashSet<&String> = changed.iter()
+            .filter_map(|doc| indexed.get(&Self::document_key(doc)))
+            .collect();
+        let kept: Vec<(UniswapDocument, OneOrMany<Embedding>)> = self.index.iter()
+            .filter(|(id, _)| !stale.contains(id))
+            .map(|(_, (doc, embedding))| (doc.clone(), embedding.clone()))
+            .collect();
+        let (embeddings, duplicates) = dedup_documents(&kept, embeddings, self.dedup_threshold);
+        let survivors: std::collections::HashSet<&String> = embeddings.iter().map(|(doc, _)| &doc.id).collect();
+        for doc in &changed {
+            let key = Self::document_key(doc);
+            if survivors.contains(&doc.id) {
+                self.duplicates.remove(&key);
+            } else {
+                self.duplicates.insert(key, doc.id.clone());
+            }
+        }
         
-        Ok(())
+        self.index = InMemoryVectorStore::from_documents_with_id_f(
+            kept.into_iter().chain(embeddings),
+            |doc| doc.id.clone()
+        );
+        self.document_count = self.index.len();
+        
+        info!("✅ Indexed {} documents ({} embedded, {} duplicates dropped)", self.document_count, changed.len(), duplicates);
+        Ok((changed.len(), duplicates))
     }
     
     /// Search for relevant documents based on query
-    pub async fn search(&self, query: &str, limit: usize) -> crate::Result<Vec<(f64, String, UniswapDocument)>> {
-        debug!("🔍 Searching for: '{}' (limit: {})", query, limit);
+    ///
+    /// With `rerank`, a wider set of vector hits is rescored by combining the
+    /// cosine score with keyword overlap (see `rerank_results`) before taking
+    /// the top `limit`; otherwise results are returned in pure vector order.
+    pub async fn search(&self, query: &str, limit: usize, rerank: bool) -> crate::Result<Vec<(f64, String, UniswapDocument)>> {
+        debug!("🔍 Searching for: '{}' (limit: {}, rerank: {})", query, limit, rerank);
         
-        let embedding_model = self.embedding_client.embedding_model(&FastembedModel::AllMiniLML6V2Q);
+        let embedding_model = self.embedder();
         let index = self.index.clone().index(embedding_model);
         
+        let samples = if rerank { (limit * RERANK_CANDIDATE_MULTIPLIER).max(limit) } else { limit };
         let req = rig::vector_store::request::VectorSearchRequest::builder()
             .query(query)
-            .samples(limit as u64)
+            .samples(samples as u64)
             .build()
-            .map_err(|e| crate::ClientError::RagError(format!("Failed to build search request: {}", e)))?;
+            .map_err(|e| crate::ClientError::rag_with("Failed to build search request", e))?;
         
         let results = index
             .top_n::<UniswapDocument>(req)
             .await
-            .map_err(|e| crate::ClientError::RagError(format!("Search failed: {}", e)))?;
+            .map_err(|e| crate::ClientError::rag_with("Search failed", e))?;
+        
+        let results = if rerank { rerank_results(query, results, limit) } else { results };
         
         debug!("📋 Found {} relevant documents", results.len());
         Ok(results)
     }
     
+    /// One page of search results: the top `offset + limit` hits with the first `offset` dropped
+    pub async fn search_page(&self, query: &str, limit: usize, offset: usize) -> crate::Result<SearchPage> {
+        let ranked = self.search(query, offset + limit, false).await?;
+        Ok(SearchPage::from_ranked(ranked, offset, self.document_count))
+    }
+    
+    /// Search only documents matching `filter`, keeping vector ranking order
+    ///
+    /// The filter is applied after ranking the whole index, so up to `limit`
+    /// matching documents are returned even when they rank below non-matching ones.
+    pub async fn search_filtered(&self, query: &str, limit: usize, filter: &SearchFilter) -> crate::Result<Vec<(f64, String, UniswapDocument)>> {
+        if filter.is_empty() {
+            return self.search(query, limit, false).await;
+        }
+        
+        let candidates = self.search(query, self.document_count.max(limit), false).await?;
+        let total = candidates.len();
+        
+        let filtered: Vec<_> = candidates.into_iter()
+            .filter(|(_, _, doc)| filter.matches(doc))
+            .take(limit)
+            .collect();
+        
+        debug!("📋 {} of {} results matched filter {:?}", filtered.len(), total, filter);
+        Ok(filtered)
+    }
+    
+    /// Search for relevant documents, dropping results scored below `min_score`
+    ///
+    /// Returns an empty vec when nothing meets the threshold, so callers can fall
+    /// back to the original query instead of injecting unrelated docs.
+    pub async fn search_with_threshold(&self, query: &str, limit: usize, min_score: f64) -> crate::Result<Vec<(f64, String, UniswapDocument)>> {
+        let results = self.search(query, limit, false).await?;
+        let total = results.len();
+        
+        let relevant: Vec<_> = results.into_iter()
+            .filter(|(score, _, _)| *score >= min_score)
+            .collect();
+        
+        debug!("📋 {} of {} results met the {:.2} relevance threshold", relevant.len(), total, min_score);
+        Ok(relevant)
+    }
+    
+    /// The chunks of `doc` most similar to `query`, best first
+    ///
+    /// Uses the document's `semantic_chunks`, falling back to its whole content
+    /// when it has none. `document` is copied into each returned chunk.
+    pub async fn relevant_chunks(&self, query: &str, doc: &UniswapDocument, document: usize, limit: usize) -> crate::Result<Vec<ScoredChunk>> {
+        let chunks: Vec<String> = if doc.semantic_chunks.is_empty() {
+            vec![doc.content.clone()]
+        } else {
+            doc.semantic_chunks.clone()
+        };
+        
+        let embedding_model = self.embedder();
+        let query_embedding = embedding_model.embed_text(query).await
+            .map_err(|e| crate::ClientError::rag_with("Failed to embed query", e))?;
+        let chunk_embeddings = embedding_model.embed_texts(chunks.clone()).await
+            .map_err(|e| crate::ClientError::rag_with(format!("Failed to embed chunks of {}", doc.title), e))?;
+        
+        let mut scored: Vec<ScoredChunk> = chunks.into_iter()
+            .zip(chunk_embeddings)
+            .enumerate()
+            .map(|(position, (text, embedding))| ScoredChunk {
+                document,
+                position,
+                score: cosine_similarity(&query_embedding.vec, &embedding.vec),
+                text,
+            })
+            .collect();
+        scored.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal));
+        scored.truncate(limit);
+        Ok(scored)
+    }
+    
+    /// Summaries of every indexed document, sorted by id
+    pub fn exported_documents(&self) -> Vec<ExportedDocument> {
+        let mut documents: Vec<ExportedDocument> = self.index.iter()
+            .map(|(id, (doc, _))| ExportedDocument {
+                id: id.clone(),
+                title: doc.title.clone(),
+                doc_type: doc.doc_type.clone(),
+                tags: doc.metadata.tags.clone(),
+                content_length: doc.content.len(),
+                chunk_count: doc.semantic_chunks.len(),
+            })
+            .collect();
+        documents.sort_by(|a, b| a.id.cmp(&b.id));
+        documents
+    }
+
+    /// Write the indexed corpus to `path` as JSON, returning the number of documents written
+    pub fn export_documents(&self, path: &Path) -> crate::Result<usize> {
+        let documents = self.exported_documents();
+        
+        if let Some(parent) = path.parent()
+            && !parent.as_os_str().is_empty() {
+                std::fs::create_dir_all(parent)?;
+            }
+        std::fs::write(path, serde_json::to_vec_pretty(&documents)?)?;
+        
+        info!("📤 Exported {} documents to {}", documents.len(), path.display());
+        Ok(documents.len())
+    }
+
+    /// Counts per document type, total chunks and average content length
+    pub fn corpus_stats(&self) -> CorpusStats {
+        let documents = self.exported_documents();
+        let mut stats = CorpusStats {
+            total_documents: documents.len(),
+            ..CorpusStats::default()
+        };
+        
+        for doc in &documents {
+            *stats.documents_per_type.entry(format!("{:?}", doc.doc_type)).or_insert(0) += 1;
+            stats.total_chunks += doc.chunk_count;
+        }
+        if !documents.is_empty() {
+            stats.average_content_length = documents.iter().map(|d| d.content_length).sum::<usize>() as f64
+                / documents.len() as f64;
+        }
+        stats
+    }
+    
+    /// Chunk, type, version and embedding metrics for the whole index
+    ///
+    /// The memory figure counts document text and vector data only, not
+    /// allocator or map overhead, so treat it as a lower bound.
+    pub fn stats(&self) -> IndexStats {
+        let mut stats = IndexStats {
+            embedding_model: self.embedding_model_name.to_string(),
+            duplicate_documents: self.duplicates.len(),
+            ..IndexStats::default()
+        };
+        
+        for (id, (doc, embeddings)) in self.index.iter() {
+            stats.total_documents += 1;
+            stats.total_chunks += doc.semantic_chunks.len();
+            *stats.documents_per_type.entry(format!("{:?}", doc.doc_type)).or_insert(0) += 1;
+            let version = doc.metadata.version.clone().unwrap_or_else(|| "unversioned".to_string());
+            *stats.documents_per_version.entry(version).or_insert(0) += 1;
+            
+            let text_bytes = id.len()
+                + doc.title.len()
+                + doc.content.len()
+                + doc.semantic_chunks.iter().chain(&doc.code_examples).chain(&doc.function_signatures)
+                    .map(String::len)
+                    .sum::<usize>();
+            stats.approximate_memory_bytes += text_bytes;
+            
+            for embedding in embeddings.iter() {
+                stats.total_embeddings += 1;
+                stats.embedding_dimensions.get_or_insert(embedding.vec.len());
+                stats.approximate_memory_bytes += embedding.document.len()
+                    + embedding.vec.len() * std::mem::size_of::<f64>();
+            }
+        }
+        stats
+    }
+    
     /// Get document count
     pub fn document_count(&self) -> usize {
         self.document_count
@@ -494,7 +1507,7 @@ impl UniswapRagSystem {
     pub async fn get_all_documents(&self) -> crate::Result<Vec<UniswapDocument>> {
         // Return all documents from the vector store
         let mut docs = Vec::new();
-        let embedding_model = self.embedding_client.embedding_model(&FastembedModel::AllMiniLML6V2Q);
+        let embedding_model = self.embedder();
         let index = self.index.clone().index(embedding_model);
         
         // Get all documents from the vector store
@@ -502,12 +1515,12 @@ impl UniswapRagSystem {
             .query("") // Empty query to get all documents
             .samples(self.document_count as u64)
             .build()
-            .map_err(|e| crate::ClientError::RagError(format!("Failed to build search request: {}", e)))?;
+            .map_err(|e| crate::ClientError::rag_with("Failed to build search request", e))?;
         
         let results = index
             .top_n::<UniswapDocument>(req)
             .await
-            .map_err(|e| crate::ClientError::RagError(format!("Failed to get documents: {}", e)))?;
+            .map_err(|e| crate::ClientError::rag_with("Failed to get documents", e))?;
         
         for (_, _, doc) in results {
             docs.push(doc);
@@ -528,7 +1541,7 @@ impl UniswapRagSystem {
         }
         
         // Create embeddings for examples
-        let embedding_model = self.embedding_client.embedding_model(&FastembedModel::AllMiniLML6V2Q);
+        let embedding_model = self.embedder();
         let mut builder = EmbeddingsBuilder::new(embedding_model.clone());
         
         // Add examples
@@ -538,13 +1551,13 @@ impl UniswapRagSystem {
             
         for doc in example_docs.iter() {
             builder = builder.document(doc.clone())
-                .map_err(|e| crate::ClientError::RagError(format!("Failed to add example: {}", e)))?;
+                .map_err(|e| crate::ClientError::rag_with("Failed to add example", e))?;
         }
         
         // Build embeddings and create vector store
         let embeddings = builder.build()
             .await
-            .map_err(|e| crate::ClientError::RagError(format!("Failed to build embeddings: {}", e)))?;
+            .map_err(|e| crate::ClientError::rag_with("Failed to build embeddings", e))?;
             
         let vector_store = InMemoryVectorStore::from_documents_with_id_f(embeddings, |doc| doc.text.clone());
         let index = vector_store.index(embedding_model);
@@ -554,12 +1567,12 @@ impl UniswapRagSystem {
             .query(query)
             .samples(examples.len() as u64)
             .build()
-            .map_err(|e| crate::ClientError::RagError(format!("Failed to build search request: {}", e)))?;
+            .map_err(|e| crate::ClientError::rag_with("Failed to build search request", e))?;
             
         let results = index
             .top_n::<QueryDoc>(req)
             .await
-            .map_err(|e| crate::ClientError::RagError(format!("Search failed: {}", e)))?;
+            .map_err(|e| crate::ClientError::rag_with("Search failed", e))?;
             
         debug!("📋 Found {} matching examples", results.len());
             
@@ -0,0 +1,160 @@
+//! Resume extraction through the BAML client
+//!
+//! `extract_resume_with_schema` asks the BAML `ExtractResumeWithSchema`
+//! function for whatever fields the caller describes and returns the raw JSON,
+//! so new fields (phone, education, ...) don't need a new struct. The typed
+//! `extract_resume` is a thin wrapper over it for the standard `Resume` shape,
+//! and `extract_resumes` processes many resumes over one shared client.
+
+use async_trait::async_trait;
+use baml_client::apis::configuration::Configuration;
+use baml_client::apis::default_api;
+use baml_client::models::{ExtractResumeWithSchemaRequest, Resume};
+use futures::stream::{self, StreamExt};
+use serde_json::{json, Value};
+
+/// Field schema matching the BAML `Resume` class
+pub fn resume_schema() -> Value {
+    json!({
+        "name": "string",
+        "email": "string",
+        "experience": "string[]",
+        "skills": "string[]",
+    })
+}
+
+/// Runs the `ExtractResumeWithSchema` function and returns the model's raw answer
+#[async_trait]
+pub trait ResumeBackend: Send + Sync {
+    async fn extract_with_schema(&self, resume_text: &str, schema: &str) -> crate::Result<String>;
+}
+
+/// The generated BAML REST client, holding one configuration (and HTTP client) for every call
+pub struct BamlResumeBackend {
+    config: Configuration,
+}
+
+impl BamlResumeBackend {
+    pub fn new() -> Self {
+        Self { config: Configuration::new() }
+    }
+}
+
+impl Default for BamlResumeBackend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl ResumeBackend for BamlResumeBackend {
+    async fn extract_with_schema(&self, resume_text: &str, schema: &str) -> crate::Result<String> {
+        let request = ExtractResumeWithSchemaRequest::new(resume_text.to_string(), schema.to_string());
+        default_api::extract_resume_with_schema(&self.config, request).await
+            .map_err(|e| crate::ClientError::Baml(format!("ExtractResumeWithSchema failed: {}", e)))
+    }
+}
+
+/// Resume extraction over a single backend, shared by every call
+pub struct ResumeExtractor<B: ResumeBackend = BamlResumeBackend> {
+    backend: B,
+}
+
+impl ResumeExtractor {
+    /// Extractor using the BAML REST client
+    pub fn new() -> Self {
+        Self::with_backend(BamlResumeBackend::new())
+    }
+}
+
+impl Default for ResumeExtractor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<B: ResumeBackend> ResumeExtractor<B> {
+    /// Extractor using the given backend (e.g. a mock in tests)
+    pub fn with_backend(backend: B) -> Self {
+        Self { backend }
+    }
+
+    /// Extract the fields described by `schema` from a resume, as a JSON object
+    pub async fn extract_with_schema(&self, resume_text: &str, schema: &Value) -> crate::Result<Value> {
+        if !schema.as_object().is_some_and(|fields| !fields.is_empty()) {
+            return Err(crate::ClientError::Config(
+                "Resume schema must be a JSON object with at least one field".to_string(),
+            ));
+        }
+
+        let raw = self.backend.extract_with_schema(resume_text, &serde_json::to_string_pretty(schema)?).await?;
+        parse_extracted_json(&raw)
+    }
+
+    /// Extract the standard `Resume` fields (name, email, experience, skills)
+    pub async fn extract(&self, resume_text: &str) -> crate::Result<Resume> {
+        let value = self.extract_with_schema(resume_text, &resume_schema()).await?;
+        serde_json::from_value(value)
+            .map_err(|e| crate::ClientError::Baml(format!("Extracted resume doesn't match the Resume shape: {}", e)))
+    }
+
+    /// Extract many resumes with at most `concurrency` requests in flight
+    ///
+    /// Results are returned in input order, one per resume, so a failed
+    /// extraction doesn't abort the rest of the batch.
+    pub async fn extract_batch(&self, texts: Vec<String>, concurrency: usize) -> Vec<crate::Result<Resume>> {
+        let mut results: Vec<(usize, crate::Result<Resume>)> = stream::iter(texts.into_iter().enumerate())
+            .map(|(index, text)| async move { (index, self.extract(&text).await) })
+            .buffer_unordered(concurrency.max(1))
+            .collect()
+            .await;
+        results.sort_by_key(|(index, _)| *index);
+        results.into_iter().map(|(_, result)| result).collect()
+    }
+}
+
+/// Extract the fields described by `schema` from a resume, as a JSON object
+///
+/// `schema` must be a JSON object mapping each field name to its type or a
+/// short description, e.g. `{"phone": "string", "years_of_experience": "int"}`.
+/// Fields the resume doesn't mention come back as `null`.
+pub async fn extract_resume_with_schema(resume_text: &str, schema: Value) -> crate::Result<Value> {
+    ResumeExtractor::new().extract_with_schema(resume_text, &schema).await
+}
+
+/// Extract the standard `Resume` fields (name, email, experience, skills)
+pub async fn extract_resume(resume_text: &str) -> crate::Result<Resume> {
+    ResumeExtractor::new().extract(resume_text).await
+}
+
+/// Extract a folder's worth of resumes over one BAML client, `concurrency` at a time
+pub async fn extract_resumes(texts: Vec<String>, concurrency: usize) -> Vec<crate::Result<Resume>> {
+    extract_resumes_with(BamlResumeBackend::new, texts, concurrency).await
+}
+
+/// `extract_resumes` over a backend from `make_backend`, which is called once for the whole batch
+pub async fn extract_resumes_with<B: ResumeBackend>(
+    make_backend: impl Fn() -> B,
+    texts: Vec<String>,
+    concurrency: usize,
+) -> Vec<crate::Result<Resume>> {
+    ResumeExtractor::with_backend(make_backend()).extract_batch(texts, concurrency).await
+}
+
+/// Parse the model's answer into a JSON object, tolerating a Markdown code fence around it
+pub fn parse_extracted_json(raw: &str) -> crate::Result<Value> {
+    let trimmed = raw.trim();
+    let unfenced = trimmed
+        .strip_prefix("```json")
+        .or_else(|| trimmed.strip_prefix("```"))
+        .and_then(|body| body.strip_suffix("```"))
+        .unwrap_or(trimmed)
+        .trim();
+
+    let value: Value = serde_json::from_str(unfenced)
+        .map_err(|e| crate::ClientError::Baml(format!("Extracted resume is not valid JSON: {}", e)))?;
+    if !value.is_object() {
+        return Err(crate::ClientError::Baml(format!("Extracted resume is not a JSON object: {}", value)));
+    }
+    Ok(value)
+}
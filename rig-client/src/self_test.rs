@@ -0,0 +1,136 @@
+//! Smoke test of the MCP blockchain tools
+//!
+//! `BlockchainAgent::run_self_test` calls a fixed sequence of tools directly
+//! (no Claude round trip) and collects a pass/fail/skip outcome per tool into a
+//! `SelfTestReport`. The helpers here judge tool output and pull out the values
+//! later steps need, such as the first account and the wrap transaction hash.
+
+use std::fmt;
+
+/// USDC on Ethereum mainnet, as served by the anvil mainnet fork
+pub const SELF_TEST_TOKEN: &str = "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48";
+
+/// Uniswap V2 Router on Ethereum mainnet
+pub const SELF_TEST_ROUTER: &str = "0x7a250d5630B4cF539739dF2C5dAcb4c659F2488D";
+
+/// ETH wrapped into WETH by the value-moving step
+pub const SELF_TEST_WRAP_ETH: &str = "0.001";
+
+/// Longest summary kept for a step in the report
+const SUMMARY_CHARS: usize = 100;
+
+/// Outcome of one self-test step
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StepOutcome {
+    /// The tool answered as expected; holds the line that showed it
+    Passed(String),
+    /// The tool errored or answered unexpectedly
+    Failed(String),
+    /// The step was not run, with the reason
+    Skipped(String),
+}
+
+/// A self-test step and its outcome
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StepResult {
+    /// MCP tool the step called
+    pub tool: String,
+    pub outcome: StepOutcome,
+}
+
+/// Outcomes of every self-test step, in the order they ran
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SelfTestReport {
+    pub steps: Vec<StepResult>,
+}
+
+impl SelfTestReport {
+    /// Record the outcome of `tool`
+    pub fn record(&mut self, tool: &str, outcome: StepOutcome) {
+        self.steps.push(StepResult { tool: tool.to_string(), outcome });
+    }
+
+    /// Number of steps that passed
+    pub fn passed(&self) -> usize {
+        self.count(|outcome| matches!(outcome, StepOutcome::Passed(_)))
+    }
+
+    /// Number of steps that failed
+    pub fn failed(&self) -> usize {
+        self.count(|outcome| matches!(outcome, StepOutcome::Failed(_)))
+    }
+
+    /// Number of steps that were skipped
+    pub fn skipped(&self) -> usize {
+        self.count(|outcome| matches!(outcome, StepOutcome::Skipped(_)))
+    }
+
+    /// True when no step failed; skipped steps don't count against the run
+    pub fn is_success(&self) -> bool {
+        self.failed() == 0
+    }
+
+    fn count(&self, matches: impl Fn(&StepOutcome) -> bool) -> usize {
+        self.steps.iter().filter(|step| matches(&step.outcome)).count()
+    }
+}
+
+impl fmt::Display for SelfTestReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Self-test report:")?;
+        for step in &self.steps {
+            let (icon, label, detail) = match &step.outcome {
+                StepOutcome::Passed(detail) => ("✅", "PASS", detail),
+                StepOutcome::Failed(detail) => ("❌", "FAIL", detail),
+                StepOutcome::Skipped(detail) => ("⏭️", "SKIP", detail),
+            };
+            writeln!(f, "  {} {} {} - {}", icon, label, step.tool, detail)?;
+        }
+        write!(f, "{} passed, {} failed, {} skipped", self.passed(), self.failed(), self.skipped())
+    }
+}
+
+/// Judge a tool's output: it passes when a line contains `expected`
+pub fn check_output(output: &str, expected: &str) -> StepOutcome {
+    match output.lines().find(|line| line.contains(expected)) {
+        Some(line) => StepOutcome::Passed(summarize(line)),
+        None => {
+            let first_line = output.lines().map(str::trim).find(|line| !line.is_empty()).unwrap_or("empty response");
+            StepOutcome::Failed(format!("expected '{}', got: {}", expected, summarize(first_line)))
+        }
+    }
+}
+
+/// Address of the first account in a `get_accounts` response
+pub fn first_account_address(get_accounts_output: &str) -> Option<String> {
+    let response: serde_json::Value = serde_json::from_str(get_accounts_output).ok()?;
+    response["accounts"].get(0)?["address"].as_str().map(str::to_string)
+}
+
+/// Whether a `health_check` response reports a signing key
+pub fn private_key_available(health_check_output: &str) -> bool {
+    health_check_output.lines().any(|line| line.trim() == "Private Key Available: true")
+}
+
+/// The `Status:` a `health_check` response reports, e.g. `HEALTHY` or `DEGRADED`
+pub fn health_status(health_check_output: &str) -> Option<&str> {
+    health_check_output.lines().find_map(|line| line.trim().strip_prefix("Status: ")).map(str::trim)
+}
+
+/// First 32-byte hex hash (e.g. a transaction hash) in `text`
+pub fn find_tx_hash(text: &str) -> Option<String> {
+    text.match_indices("0x").find_map(|(start, _)| {
+        let digits = &text[start + 2..];
+        let length = digits.bytes().take_while(u8::is_ascii_hexdigit).count();
+        (length == 64).then(|| text[start..start + 66].to_string())
+    })
+}
+
+fn summarize(line: &str) -> String {
+    let line = line.trim().trim_end_matches(',');
+    if line.chars().count() > SUMMARY_CHARS {
+        format!("{}...", line.chars().take(SUMMARY_CHARS).collect::<String>())
+    } else {
+        line.to_string()
+    }
+}
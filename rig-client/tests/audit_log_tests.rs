@@ -0,0 +1,116 @@
+//! Tests for the tool-call audit log
+//! Verifies private key redaction, result summaries and the JSONL output
+
+use rig_client::audit::{
+    format_trace_call, format_trace_result, redact_arguments, summarize_result, AuditLog, AuditRecord, REDACTED,
+    RESULT_SUMMARY_CHARS, TRACE_RESULT_CHARS,
+};
+use serde_json::json;
+
+const PRIVATE_KEY: &str = "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+const TX_HASH: &str = "0x5c504ed432cb51138bcf09aa5e8a410dd4a1e204ef84bfed1be16dfba1b22060";
+
+#[test]
+fn test_redacts_private_keys_from_arguments() {
+    let mut arguments = json!({
+        "private_key": "anything",
+        "from": PRIVATE_KEY,
+        "tx_hash": TX_HASH,
+        "to": "bob",
+        "reads": [{ "type": "balance", "address": PRIVATE_KEY }],
+    });
+    redact_arguments(&mut arguments);
+
+    assert_eq!(arguments["private_key"], REDACTED);
+    assert_eq!(arguments["from"], REDACTED);
+    assert_eq!(arguments["reads"][0]["address"], REDACTED);
+    assert_eq!(arguments["tx_hash"], TX_HASH, "Hashes share the key shape but aren't secret");
+    assert_eq!(arguments["to"], "bob");
+}
+
+#[test]
+fn test_result_summary_collapses_and_truncates() {
+    assert_eq!(summarize_result("Balance:\n  1.5 ETH\n"), "Balance: 1.5 ETH");
+
+    let summary = summarize_result(&"x".repeat(RESULT_SUMMARY_CHARS + 50));
+    assert_eq!(summary.chars().count(), RESULT_SUMMARY_CHARS + 1);
+    assert!(summary.ends_with('…'));
+}
+
+#[test]
+fn test_private_key_tool_results_are_redacted() {
+    let record = AuditRecord::new("get_private_keys", "{}", &format!("Account 0: {}", PRIVATE_KEY), 3);
+    assert_eq!(record.result_summary, REDACTED);
+}
+
+#[test]
+fn test_audit_log_writes_one_json_line_per_call() {
+    let path = std::env::temp_dir().join(format!("rig_client_audit_{}.jsonl", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+
+    let log = AuditLog::open(&path).unwrap();
+    let args = r#"{"to":"bob","amount":"1"}"#;
+    log.start_call("send_eth", args);
+    log.finish_call("send_eth", args, "Transaction sent\nStatus: Success");
+    log.finish_call("balance", "not json", "1.0 ETH");
+
+    let contents = std::fs::read_to_string(&path).unwrap();
+    let records: Vec<AuditRecord> = contents.lines()
+        .map(|line| serde_json::from_str(line).expect("Each line is one record"))
+        .collect();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0].tool, "send_eth");
+    assert_eq!(records[0].arguments, json!({ "to": "bob", "amount": "1" }));
+    assert_eq!(records[0].result_summary, "Transaction sent Status: Success");
+    assert!(chrono::DateTime::parse_from_rfc3339(&records[0].timestamp).is_ok());
+    assert_eq!(records[1].arguments, json!("not json"), "Unparseable arguments are kept as a string");
+    assert_eq!(records[1].duration_ms, 0, "A result without a recorded start has no duration");
+}
+
+#[test]
+fn test_trace_lines_show_arguments_and_first_result_line() {
+    let args = r#"{"account_address":"alice","token_address":"0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48"}"#;
+    assert_eq!(
+        format_trace_call("token_balance", args),
+        "→ token_balance {account_address: alice, token_address: 0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48}"
+    );
+    assert_eq!(format_trace_call("send_eth", &format!(r#"{{"amount":1,"from":"{}"}}"#, PRIVATE_KEY)), format!("→ send_eth {{amount: 1, from: {}}}", REDACTED));
+    assert_eq!(format_trace_call("balance", "not json"), "→ balance not json");
+
+    assert_eq!(format_trace_result("token_balance", "\n  Balance: 1500 USDC\nDecimals: 6"), "← Balance: 1500 USDC");
+    assert_eq!(format_trace_result("get_private_keys", PRIVATE_KEY), format!("← {}", REDACTED));
+
+    let long = format_trace_result("get_logs", &"x".repeat(TRACE_RESULT_CHARS + 10));
+    assert_eq!(long.chars().count(), "← ".chars().count() + TRACE_RESULT_CHARS + 1);
+    assert!(long.ends_with('…'));
+}
+
+#[test]
+fn test_trace_line_for_structured_results() {
+    // send_eth returns its JSON result, then the human-readable summary
+    let tx_hash = "0x5c504ed432cb51138bcf09aa5e8a410dd4a1e204ef84bfed1be16dfba1b22060";
+    let structured = serde_json::to_string_pretty(&serde_json::json!({
+        "status": "confirmed",
+        "from": "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266",
+        "from_label": "alice",
+        "to": "bob",
+        "to_address": "0x70997970C51812dc3A010C7d01b50e0d17dc79C8",
+        "asset": "ETH",
+        "token_address": null,
+        "amount": "1 ETH",
+        "amount_raw": "1000000000000000000",
+        "tx_hash": tx_hash,
+        "confirmation": null,
+    })).unwrap();
+    let summary = "ETH Transfer:\nFrom: 0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266 (alice)\nAmount: 1 ETH";
+
+    let expected = format!("← ETH Transfer: (status: confirmed, tx_hash: {})", tx_hash);
+    assert_eq!(format_trace_result("send_eth", &format!("{}\n{}", structured, summary)), expected);
+    assert_eq!(format_trace_result("send_eth", &format!("{}{}", structured, summary)), expected, "Contents may be joined without a separator");
+
+    // Without a summary the fields alone are shown, and unknown JSON is compacted rather than cut at "{"
+    assert_eq!(format_trace_result("send_eth", &structured), format!("← status: confirmed, tx_hash: {}", tx_hash));
+    assert_eq!(format_trace_result("get_ens_records", "{\n  \"name\": \"vitalik.eth\"\n}"), r#"← {"name":"vitalik.eth"}"#);
+}
@@ -0,0 +1,79 @@
+//! Tests for document chunking in the ingestion pipeline
+//! Verifies header-based splitting, the maximum chunk size and sliding-window overlap
+
+use rig_client::doc_ingestion::{
+    DefaultDocumentProcessor, DocumentMetadata, DocumentProcessor, DocumentSourceMetadata,
+    DocumentType, RawDocument,
+};
+
+const SAMPLE_MARKDOWN: &str = "# Swaps\nUse the router to swap tokens.\n# Liquidity\nProviders deposit both tokens.\n# Fees\nEach swap pays 0.3%.";
+
+fn markdown_document(content: &str) -> RawDocument {
+    RawDocument::new(
+        content.as_bytes().to_vec(),
+        DocumentMetadata {
+            title: "sample.md".to_string(),
+            doc_type: DocumentType::Markdown,
+            version: None,
+            created_at: chrono::Utc::now(),
+            updated_at: chrono::Utc::now(),
+            source: DocumentSourceMetadata {
+                source_type: "test".to_string(),
+                location: "memory".to_string(),
+                version: None,
+            },
+            tags: Vec::new(),
+        },
+    )
+}
+
+#[tokio::test]
+async fn test_markdown_chunks_without_overlap() {
+    let processor = DefaultDocumentProcessor::new(0, 1000);
+    let processed = processor.process(markdown_document(SAMPLE_MARKDOWN)).await.unwrap();
+
+    assert_eq!(processed.chunks.len(), 3);
+    assert!(processed.chunks[0].starts_with("# Swaps"));
+    assert!(processed.chunks[1].starts_with("# Liquidity"));
+    assert!(processed.chunks[2].starts_with("# Fees"));
+}
+
+#[tokio::test]
+async fn test_markdown_chunks_carry_overlap() {
+    let processor = DefaultDocumentProcessor::new(10, 1000);
+    let processed = processor.process(markdown_document(SAMPLE_MARKDOWN)).await.unwrap();
+
+    assert_eq!(processed.chunks.len(), 3);
+    // Each chunk after the first starts with the last 10 characters of the previous section
+    assert!(processed.chunks[1].starts_with("ap tokens."), "got: {:?}", processed.chunks[1]);
+    assert!(processed.chunks[1].ends_with("Providers deposit both tokens."));
+    assert!(processed.chunks[2].starts_with("th tokens.# Fees"), "got: {:?}", processed.chunks[2]);
+}
+
+#[test]
+fn test_oversized_sections_are_split_to_max_size() {
+    let processor = DefaultDocumentProcessor::new(5, 40);
+    let section = (1..=10).map(|i| format!("line number {}\n", i)).collect::<String>();
+
+    let chunks = processor.apply_window(vec![section.clone()]);
+
+    assert!(chunks.len() > 1, "Oversized section should be split");
+    for chunk in &chunks {
+        assert!(chunk.chars().count() <= 40, "Chunk exceeds max size: {:?}", chunk);
+    }
+    for pair in chunks.windows(2) {
+        let tail: String = pair[0].chars().rev().take(5).collect::<Vec<_>>().into_iter().rev().collect();
+        assert!(pair[1].starts_with(&tail), "Expected {:?} to start with {:?}", pair[1], tail);
+    }
+
+    // No content is lost
+    assert!(chunks.iter().any(|c| c.contains("line number 1\n")));
+    assert!(chunks.last().unwrap().contains("line number 10"));
+}
+
+#[test]
+fn test_overlap_is_clamped_to_half_the_max_size() {
+    let processor = DefaultDocumentProcessor::new(500, 100);
+    assert_eq!(processor.chunk_overlap(), 50);
+    assert_eq!(processor.max_chunk_size(), 100);
+}
@@ -0,0 +1,54 @@
+//! Tests for selecting RAG excerpts within a context budget
+//! Uses hand-made scores so no embedding model is needed
+
+use rig_client::rag::{cosine_similarity, fit_chunks_to_budget, ScoredChunk};
+
+fn chunk(document: usize, position: usize, score: f64, text: &str) -> ScoredChunk {
+    ScoredChunk { document, position, score, text: text.to_string() }
+}
+
+#[test]
+fn test_cosine_similarity() {
+    assert!((cosine_similarity(&[1.0, 0.0], &[2.0, 0.0]) - 1.0).abs() < 1e-9);
+    assert!(cosine_similarity(&[1.0, 0.0], &[0.0, 3.0]).abs() < 1e-9);
+    assert_eq!(cosine_similarity(&[0.0, 0.0], &[1.0, 1.0]), 0.0, "Zero vectors have no direction");
+}
+
+#[test]
+fn test_lowest_scoring_chunks_are_dropped_first() {
+    let chunks = vec![
+        chunk(0, 0, 0.9, "aaaaaaaaaa"),
+        chunk(0, 1, 0.2, "bbbbbbbbbb"),
+        chunk(1, 0, 0.6, "cccccccccc"),
+    ];
+
+    let kept = fit_chunks_to_budget(chunks, 25);
+
+    let texts: Vec<&str> = kept.iter().map(|chunk| chunk.text.as_str()).collect();
+    assert_eq!(texts, vec!["aaaaaaaaaa", "cccccccccc"], "The 0.2 chunk doesn't fit and is dropped");
+}
+
+#[test]
+fn test_survivors_keep_document_order() {
+    let chunks = vec![
+        chunk(1, 0, 0.9, "second doc"),
+        chunk(0, 3, 0.5, "first doc, later chunk"),
+        chunk(0, 1, 0.4, "first doc, earlier chunk"),
+    ];
+
+    let kept = fit_chunks_to_budget(chunks, 1000);
+
+    let order: Vec<(usize, usize)> = kept.iter().map(|chunk| (chunk.document, chunk.position)).collect();
+    assert_eq!(order, vec![(0, 1), (0, 3), (1, 0)]);
+}
+
+#[test]
+fn test_oversized_best_chunk_is_truncated() {
+    let kept = fit_chunks_to_budget(vec![chunk(0, 0, 0.8, &"x".repeat(50))], 10);
+
+    assert_eq!(kept.len(), 1);
+    assert_eq!(kept[0].text.chars().count(), 10);
+    assert!(kept[0].text.ends_with('…'));
+
+    assert!(fit_chunks_to_budget(vec![chunk(0, 0, 0.8, "text")], 0).is_empty());
+}
@@ -0,0 +1,97 @@
+//! Tests for splitting oversized chunks before they are embedded
+//! The splitting tests need no model; the indexing test embeds with the local fastembed model
+
+use clap::Parser;
+use rig_client::doc_ingestion::store::InMemoryDocStore;
+use rig_client::doc_ingestion::{DefaultDocumentProcessor, DocumentIngestionPipeline};
+use rig_client::rag::{
+    split_oversized_chunks, DocumentMetadata, DocumentType, UniswapDocument, UniswapRagSystem,
+    DEFAULT_MAX_EMBED_CHARS,
+};
+use rig_client::Config;
+
+/// A Solidity function body thousands of characters long, one statement per line
+fn long_function() -> String {
+    let body: String = (0..200)
+        .map(|i| format!("        reserves[{}] = balanceOf(address(this)) - fees[{}];\n", i, i))
+        .collect();
+    format!("function sync() external {{\n{}    }}", body)
+}
+
+fn contract_document(id: &str, chunk: String) -> UniswapDocument {
+    let mut doc = UniswapDocument::new(
+        id.to_string(),
+        format!("{}.sol", id),
+        DocumentType::ContractCode,
+        chunk.clone(),
+        DocumentMetadata::new(Some("memory".to_string()), Some("v2".to_string()), Vec::new()),
+    );
+    // Solidity members are chunked whole, so one chunk can be as long as the function
+    doc.semantic_chunks = vec!["uint112 private reserve0;".to_string(), chunk];
+    doc
+}
+
+#[test]
+fn test_long_chunks_are_split_into_pieces_within_the_limit() {
+    let function = long_function();
+    assert!(function.chars().count() > 4 * 500);
+    let mut doc = contract_document("pair", function.clone());
+
+    let split = split_oversized_chunks(&mut doc, 500);
+    assert!(split >= 1, "The long chunk is split");
+    assert!(doc.semantic_chunks.len() > 2);
+    assert!(doc.semantic_chunks.iter().all(|chunk| chunk.chars().count() <= 500));
+    assert_eq!(doc.semantic_chunks[0], "uint112 private reserve0;", "Short chunks are untouched");
+    assert_eq!(doc.semantic_chunks[1..].concat(), function, "The pieces cover the whole chunk");
+    assert_eq!(doc.content, function, "The content is kept for display");
+
+    // Nothing left to split the second time
+    assert_eq!(split_oversized_chunks(&mut doc, 500), 0);
+}
+
+#[test]
+fn test_oversized_content_without_chunks_becomes_chunks() {
+    let function = long_function();
+    let mut doc = contract_document("pair", function.clone());
+    doc.semantic_chunks.clear();
+
+    assert_eq!(split_oversized_chunks(&mut doc, 500), 1);
+    assert!(doc.semantic_chunks.len() > 1);
+    assert_eq!(doc.semantic_chunks.concat(), function);
+}
+
+#[test]
+fn test_max_chunk_flag() {
+    let config = Config::parse_from(["rig-client"]);
+    assert_eq!(config.rag_max_chunk_chars, DEFAULT_MAX_EMBED_CHARS);
+
+    let config = Config::parse_from(["rig-client", "--rag-max-chunk-chars", "800"]);
+    assert_eq!(config.rag_max_chunk_chars, 800);
+}
+
+#[tokio::test]
+async fn test_long_chunk_is_split_and_every_piece_indexed() {
+    let mut rag = UniswapRagSystem::with_pipeline(DocumentIngestionPipeline::new(
+        Vec::new(),
+        Box::new(DefaultDocumentProcessor::default()),
+        Box::new(InMemoryDocStore::default()),
+    ));
+    rag.set_max_chunk_chars(500);
+
+    let embedded = rag.upsert_documents(vec![contract_document("pair", long_function())]).await.unwrap();
+    assert_eq!(embedded, 1, "The long document is indexed rather than failing the build");
+    assert_eq!(rag.document_count(), 1);
+
+    let indexed = rag.exported_documents();
+    assert!(indexed[0].chunk_count > 2, "Every piece of the long chunk is indexed");
+    assert_eq!(indexed[0].content_length, long_function().len());
+
+    // One embedding for the content plus one per chunk piece, code example and signature
+    let path = std::env::temp_dir().join(format!("rig_client_embed_limit_{}.json", std::process::id()));
+    rag.save_index(&path).unwrap();
+    let saved: serde_json::Value = serde_json::from_slice(&std::fs::read(&path).unwrap()).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    let embeddings = saved["entries"][0]["embeddings"].as_array().unwrap();
+    assert!(embeddings.len() > indexed[0].chunk_count);
+    assert!(embeddings.iter().all(|embedding| embedding["document"].as_str().unwrap().chars().count() <= 500));
+}
@@ -0,0 +1,63 @@
+//! Tests for selecting the RAG embedding model
+//! Only validates names and cache headers, so no model is downloaded
+
+use clap::Parser;
+use rig_client::doc_ingestion::store::InMemoryDocStore;
+use rig_client::doc_ingestion::{DefaultDocumentProcessor, DocumentIngestionPipeline};
+use rig_client::rag::{parse_embedding_model, UniswapRagSystem, DEFAULT_EMBEDDING_MODEL};
+use rig_client::Config;
+
+fn empty_pipeline() -> DocumentIngestionPipeline {
+    DocumentIngestionPipeline::new(
+        Vec::new(),
+        Box::new(DefaultDocumentProcessor::default()),
+        Box::new(InMemoryDocStore::default()),
+    )
+}
+
+#[test]
+fn test_model_names_are_case_insensitive() {
+    let (name, _) = parse_embedding_model(" bgebaseenv15 ").unwrap();
+    assert_eq!(name, "BGEBaseENV15");
+
+    let (name, _) = parse_embedding_model(DEFAULT_EMBEDDING_MODEL).unwrap();
+    assert_eq!(name, "AllMiniLML6V2Q");
+}
+
+#[test]
+fn test_unknown_model_lists_supported_models() {
+    let error = parse_embedding_model("text-embedding-3-large").unwrap_err().to_string();
+    assert!(error.contains("Unknown embedding model 'text-embedding-3-large'"));
+    assert!(error.contains("AllMiniLML6V2Q, AllMiniLML12V2"));
+
+    // The CLI rejects it before anything is loaded
+    assert!(Config::try_parse_from(["rig-client", "--embedding-model", "text-embedding-3-large"]).is_err());
+    let config = Config::try_parse_from(["rig-client", "--embedding-model", "nomicembedtextv15"]).unwrap();
+    assert_eq!(config.embedding_model, "NomicEmbedTextV15");
+}
+
+#[test]
+fn test_pipeline_system_uses_configured_model() {
+    let rag = UniswapRagSystem::with_pipeline_and_model(empty_pipeline(), "BGESmallENV15").unwrap();
+    assert_eq!(rag.embedding_model_name(), "BGESmallENV15");
+
+    assert_eq!(UniswapRagSystem::with_pipeline(empty_pipeline()).embedding_model_name(), DEFAULT_EMBEDDING_MODEL);
+    assert!(UniswapRagSystem::with_pipeline_and_model(empty_pipeline(), "unknown").is_err());
+}
+
+#[test]
+fn test_cached_index_must_match_model() {
+    let path = std::env::temp_dir().join(format!("rig_client_embedding_model_{}.json", std::process::id()));
+    std::fs::write(&path, r#"{
+        "format_version": 1,
+        "embedding_model": "AllMiniLML6V2Q",
+        "document_count": 0,
+        "checksum": "",
+        "entries": []
+    }"#).unwrap();
+
+    let error = UniswapRagSystem::load_index_with_model(&path, "BGEBaseENV15").err().unwrap().to_string();
+    assert!(error.contains("built with embedding model 'AllMiniLML6V2Q', expected 'BGEBaseENV15'"));
+
+    std::fs::remove_file(&path).unwrap();
+}
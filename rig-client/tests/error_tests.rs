@@ -0,0 +1,60 @@
+//! Tests for ClientError messages and source chaining
+//! Builds errors directly, so no network or model is needed
+
+use std::error::Error;
+
+use rig::completion::{CompletionError, PromptError};
+use rig_client::doc_ingestion::IngestionError;
+use rig_client::ClientError;
+
+#[test]
+fn test_rag_errors_keep_their_source() {
+    let error = ClientError::rag_with("Failed to list documents", IngestionError::StorageError("disk full".to_string()));
+    assert_eq!(error.to_string(), "RAG system error: Failed to list documents", "The cause is left to source()");
+    assert_eq!(error.report(), "RAG system error: Failed to list documents: Storage error: disk full");
+
+    let source = error.source().expect("rag_with keeps the cause");
+    assert!(matches!(source.downcast_ref::<IngestionError>(), Some(IngestionError::StorageError(_))));
+
+    let error = ClientError::rag("RAG system not initialized");
+    assert_eq!(error.to_string(), "RAG system error: RAG system not initialized");
+    assert!(error.source().is_none());
+}
+
+#[test]
+fn test_question_mark_conversions() {
+    fn ingest() -> rig_client::Result<()> {
+        Err::<(), _>(IngestionError::NetworkError("connection reset".to_string()))?;
+        Ok(())
+    }
+    let error = ingest().unwrap_err();
+    assert!(matches!(error, ClientError::RagError { .. }));
+    assert_eq!(error.report(), "RAG system error: Document ingestion failed: Network error: connection reset");
+    assert!(error.source().unwrap().downcast_ref::<IngestionError>().unwrap().is_retryable());
+
+    let error = ClientError::from(rmcp::ErrorData::invalid_params("bad address", None));
+    assert!(matches!(error, ClientError::McpConnection { .. }));
+    assert_eq!(error.to_string(), "MCP server connection failed: MCP tool call failed");
+    assert!(error.report().contains("bad address"));
+    assert!(error.source().unwrap().downcast_ref::<rmcp::ErrorData>().is_some());
+
+    fn prompt() -> rig_client::Result<()> {
+        Err::<(), _>(PromptError::CompletionError(CompletionError::ProviderError("529 overloaded".to_string())))?;
+        Ok(())
+    }
+    let error = prompt().unwrap_err();
+    assert!(matches!(error, ClientError::ClaudeApi { .. }));
+    assert_eq!(error.to_string(), "Claude API error: Failed to process command with Claude");
+    assert!(error.source().unwrap().downcast_ref::<PromptError>().is_some());
+}
+
+#[test]
+fn test_claude_and_mcp_constructors() {
+    let error = ClientError::claude_with("Failed to process command with Claude", std::io::Error::other("overloaded_error"));
+    assert_eq!(error.to_string(), "Claude API error: Failed to process command with Claude");
+    assert_eq!(error.report(), "Claude API error: Failed to process command with Claude: overloaded_error");
+    assert!(error.source().is_some());
+
+    let error = ClientError::mcp("Not connected to an MCP server (running with --no-mcp)");
+    assert_eq!(error.to_string(), "MCP server connection failed: Not connected to an MCP server (running with --no-mcp)");
+}
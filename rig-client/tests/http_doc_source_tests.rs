@@ -0,0 +1,53 @@
+//! Tests for the HTTP document source
+//! Verifies content-type mapping and HTML-to-text conversion without network access
+
+use rig_client::doc_ingestion::sources::http::{html_to_text, HttpDocSource};
+use rig_client::doc_ingestion::{DocumentSource, DocumentType};
+
+#[test]
+fn test_doc_type_for_content_type() {
+    assert!(matches!(HttpDocSource::doc_type_for("text/html; charset=utf-8"), DocumentType::Markdown));
+    assert!(matches!(HttpDocSource::doc_type_for("text/markdown"), DocumentType::Markdown));
+    assert!(matches!(HttpDocSource::doc_type_for("application/json"), DocumentType::JSON));
+    assert!(matches!(HttpDocSource::doc_type_for("application/pdf"), DocumentType::Other(mime) if mime == "application/pdf"));
+}
+
+#[test]
+fn test_html_to_text_strips_markup() {
+    let html = r#"<html><head><title>Docs</title><style>h1 { color: red; }</style>
+        <script>console.log("<b>ignored</b>");</script></head>
+        <body><h1>Swaps</h1><p>Use the <a href="/router">router</a> &amp; pair.</p>
+        <ul><li>swapExactETHForTokens</li><li>getAmountsOut</li></ul></body></html>"#;
+
+    let text = html_to_text(html);
+
+    assert!(text.contains("# Swaps"), "Headings should become markdown headers: {}", text);
+    assert!(text.contains("Use the router & pair."));
+    assert!(text.contains("- swapExactETHForTokens"));
+    assert!(text.contains("- getAmountsOut"));
+    assert!(!text.contains('<'), "No markup should remain: {}", text);
+    assert!(!text.contains("color: red"), "Style bodies should be dropped");
+    assert!(!text.contains("console.log"), "Script bodies should be dropped");
+}
+
+#[test]
+fn test_html_to_text_skips_the_title() {
+    let html = "<html><head><title>Uniswap Docs | Swaps</title></head><body><p>Swap body</p></body></html>";
+    let text = html_to_text(html);
+    assert_eq!(text, "Swap body", "The title should not leak into the body");
+}
+
+#[test]
+fn test_html_to_text_keeps_bare_less_than() {
+    let text = html_to_text("<p>Reverts when amountOut < amountOutMin, or if a <b>deadline</b> passed</p><p>1 <2</p>");
+    assert!(text.contains("Reverts when amountOut < amountOutMin, or if a deadline passed"), "{}", text);
+    assert!(text.contains("1 <2"), "A '<' before a digit is text: {}", text);
+}
+
+#[tokio::test]
+async fn test_unfetched_source_reports_updates() {
+    let source = HttpDocSource::new(vec!["https://docs.uniswap.org/contracts/v2/overview".to_string()]);
+    // No validators are stored before the first fetch, so no request is needed
+    assert!(source.has_updates().await.unwrap());
+    assert_eq!(source.get_metadata().source_type, "http");
+}
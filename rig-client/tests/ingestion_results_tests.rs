@@ -0,0 +1,95 @@
+//! Tests for the per-document results reported by the ingestion pipeline
+
+use async_trait::async_trait;
+use rig_client::doc_ingestion::store::InMemoryDocStore;
+use rig_client::doc_ingestion::{
+    DefaultDocumentProcessor, DocumentIngestionPipeline, DocumentMetadata, DocumentOutcome,
+    DocumentSource, DocumentSourceMetadata, DocumentType, IngestionError, RawDocument,
+};
+
+/// Source serving one valid, one empty and one non-UTF-8 markdown document
+struct MixedSource;
+
+impl MixedSource {
+    fn document(title: &str, content: &[u8]) -> RawDocument {
+        RawDocument::new(
+            content.to_vec(),
+            DocumentMetadata {
+                title: title.to_string(),
+                doc_type: DocumentType::Markdown,
+                version: None,
+                created_at: chrono::Utc::now(),
+                updated_at: chrono::Utc::now(),
+                source: MixedSource.get_metadata(),
+                tags: Vec::new(),
+            },
+        )
+    }
+}
+
+#[async_trait]
+impl DocumentSource for MixedSource {
+    async fn fetch_documents(&self) -> Result<Vec<RawDocument>, IngestionError> {
+        Ok(vec![
+            Self::document("router.md", b"# Router\nSwaps tokens along a path."),
+            Self::document("empty.md", b""),
+            Self::document("binary.md", &[b'#', b' ', 0xff, 0xfe]),
+        ])
+    }
+
+    async fn has_updates(&self) -> Result<bool, IngestionError> {
+        Ok(true)
+    }
+
+    fn get_metadata(&self) -> DocumentSourceMetadata {
+        DocumentSourceMetadata {
+            source_type: "mock".to_string(),
+            location: "memory".to_string(),
+            version: None,
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_results_record_each_document_outcome() {
+    let pipeline = DocumentIngestionPipeline::new(
+        vec![Box::new(MixedSource)],
+        Box::new(DefaultDocumentProcessor::default()),
+        Box::new(InMemoryDocStore::default()),
+    );
+
+    let stats = pipeline.run().await.unwrap();
+
+    let outcomes: Vec<(&str, DocumentOutcome)> = stats.results.iter()
+        .map(|result| (result.title.as_str(), result.outcome))
+        .collect();
+    assert_eq!(outcomes, vec![
+        ("router.md", DocumentOutcome::Stored),
+        ("empty.md", DocumentOutcome::Skipped),
+        ("binary.md", DocumentOutcome::Failed),
+    ]);
+
+    let stored = &stats.results[0];
+    assert!(stored.error.is_none());
+    assert_eq!(stored.checksum.len(), 64, "Results carry the SHA-256 checksum used as the store key");
+    assert!(stats.results[1].error.as_deref().unwrap().contains("Empty document"));
+    assert!(stats.results[2].error.as_deref().unwrap().contains("Invalid UTF-8"));
+}
+
+#[tokio::test]
+async fn test_aggregate_counts_are_derived_from_results() {
+    let pipeline = DocumentIngestionPipeline::new(
+        vec![Box::new(MixedSource)],
+        Box::new(DefaultDocumentProcessor::default()),
+        Box::new(InMemoryDocStore::default()),
+    );
+
+    let stats = pipeline.run().await.unwrap();
+
+    assert_eq!(stats.total_documents, stats.results.len());
+    assert_eq!(stats.successful_documents, 1);
+    assert_eq!(stats.failed_documents, 2, "Skipped and failed documents both count as not stored");
+    assert_eq!(stats.skipped_documents(), 1);
+    assert_eq!(stats.errors.len(), 2);
+    assert!(stats.errors[0].starts_with("empty.md: "));
+}
@@ -0,0 +1,108 @@
+//! Tests for retrying transient ingestion errors and recording permanent ones
+//! Uses scripted document sources so no git clones or network access are needed
+
+use async_trait::async_trait;
+use rig_client::doc_ingestion::store::InMemoryDocStore;
+use rig_client::doc_ingestion::{
+    DefaultDocumentProcessor, DocumentIngestionPipeline, DocumentMetadata, DocumentSource,
+    DocumentSourceMetadata, DocumentType, IngestionError, RawDocument,
+};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+/// Source whose first `failures` fetches fail with the error built by `error`
+struct FlakySource {
+    fetches: Arc<AtomicUsize>,
+    failures: usize,
+    error: fn() -> IngestionError,
+}
+
+impl FlakySource {
+    fn new(failures: usize, error: fn() -> IngestionError) -> (Self, Arc<AtomicUsize>) {
+        let fetches = Arc::new(AtomicUsize::new(0));
+        (Self { fetches: fetches.clone(), failures, error }, fetches)
+    }
+}
+
+#[async_trait]
+impl DocumentSource for FlakySource {
+    async fn fetch_documents(&self) -> Result<Vec<RawDocument>, IngestionError> {
+        if self.fetches.fetch_add(1, Ordering::SeqCst) < self.failures {
+            return Err((self.error)());
+        }
+        Ok(vec![RawDocument::new(
+            b"# Router\n\nSwaps tokens along a path.".to_vec(),
+            DocumentMetadata {
+                title: "Router".to_string(),
+                doc_type: DocumentType::Markdown,
+                version: None,
+                created_at: chrono::Utc::now(),
+                updated_at: chrono::Utc::now(),
+                source: self.get_metadata(),
+                tags: Vec::new(),
+            },
+        )])
+    }
+
+    async fn has_updates(&self) -> Result<bool, IngestionError> {
+        Ok(true)
+    }
+
+    fn get_metadata(&self) -> DocumentSourceMetadata {
+        DocumentSourceMetadata {
+            source_type: "mock".to_string(),
+            location: "flaky".to_string(),
+            version: None,
+        }
+    }
+}
+
+fn pipeline(source: FlakySource) -> DocumentIngestionPipeline {
+    DocumentIngestionPipeline::new(
+        vec![Box::new(source)],
+        Box::new(DefaultDocumentProcessor::default()),
+        Box::new(InMemoryDocStore::default()),
+    )
+    .with_retry(3, Duration::ZERO)
+}
+
+#[test]
+fn test_error_retryability() {
+    assert!(IngestionError::NetworkError("connection reset".to_string()).is_retryable());
+    assert!(!IngestionError::NotFound("missing repo".to_string()).is_retryable());
+    assert!(!IngestionError::ValidationError("Empty document".to_string()).is_retryable());
+}
+
+#[tokio::test]
+async fn test_network_errors_are_retried() {
+    let (source, fetches) = FlakySource::new(2, || IngestionError::NetworkError("timed out".to_string()));
+
+    let stats = pipeline(source).run().await.unwrap();
+
+    assert_eq!(fetches.load(Ordering::SeqCst), 3, "Two failures then a successful fetch");
+    assert_eq!(stats.successful_documents, 1);
+    assert!(stats.errors.is_empty());
+}
+
+#[tokio::test]
+async fn test_retries_are_bounded() {
+    let (source, fetches) = FlakySource::new(usize::MAX, || IngestionError::NetworkError("timed out".to_string()));
+
+    let stats = pipeline(source).run().await.unwrap();
+
+    assert_eq!(fetches.load(Ordering::SeqCst), 3, "Fetch should stop after max_attempts");
+    assert_eq!(stats.total_documents, 0);
+    assert_eq!(stats.errors.len(), 1);
+    assert!(stats.errors[0].contains("Network error: timed out"));
+}
+
+#[tokio::test]
+async fn test_permanent_errors_are_recorded_without_retry() {
+    let (source, fetches) = FlakySource::new(usize::MAX, || IngestionError::NotFound("repository gone".to_string()));
+
+    let stats = pipeline(source).run().await.unwrap();
+
+    assert_eq!(fetches.load(Ordering::SeqCst), 1, "Permanent errors should not be retried");
+    assert_eq!(stats.errors, vec!["flaky: Not found: repository gone".to_string()]);
+}
@@ -0,0 +1,132 @@
+//! Tests for the `--fast-path` intent grammar and its tool arguments
+//! Commands the parser is unsure of must return None so they still reach Claude
+
+use rig_client::config::DefaultAccounts;
+use rig_client::intent::{account_address, account_index, parse_intent, resolved_token_address, summary_text, Intent};
+use serde_json::json;
+
+const ROUTER: &str = "0x7a250d5630B4cF539739dF2C5dAcb4c659F2488D";
+const USDC: &str = "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48";
+
+fn send(amount: &str, from: Option<&str>, to: &str) -> Option<Intent> {
+    Some(Intent::SendEth { amount: amount.to_string(), from: from.map(str::to_string), to: to.to_string() })
+}
+
+fn eth_balance(who: &str) -> Option<Intent> {
+    Some(Intent::EthBalance { who: who.to_string() })
+}
+
+#[test]
+fn test_send_commands() {
+    assert_eq!(parse_intent("send 1 ETH to Bob"), send("1", None, "Bob"));
+    assert_eq!(parse_intent("  Send 0.5 eth from Alice to Bob. "), send("0.5", Some("Alice"), "Bob"));
+    assert_eq!(parse_intent("transfer .25 ether to bob from account2"), send(".25", Some("account2"), "bob"));
+    assert_eq!(parse_intent(&format!("send 2 ETH to {}", ROUTER)), send("2", None, ROUTER));
+    assert_eq!(parse_intent("send 1 ETH to vitalik.eth"), send("1", None, "vitalik.eth"));
+}
+
+#[test]
+fn test_unsure_sends_go_to_claude() {
+    for input in [
+        "send all my ETH to Bob",
+        "send half ETH to Bob",
+        "send 0 ETH to Bob",
+        "send 1.2.3 ETH to Bob",
+        "send 100 USDC to Bob",
+        "send 1 ETH to Bob and 1 ETH to Carol",
+        "send 1 ETH to me",
+        "send 1 ETH",
+        "please send 1 ETH to Bob",
+    ] {
+        assert_eq!(parse_intent(input), None, "'{}' should go to Claude", input);
+    }
+}
+
+#[test]
+fn test_balance_questions() {
+    assert_eq!(parse_intent("How much ETH does Alice have?"), eth_balance("Alice"));
+    assert_eq!(parse_intent("balance of bob"), eth_balance("bob"));
+    assert_eq!(parse_intent("What is the ETH balance of 0x70997970C51812dc3A010C7d01b50e0d17dc79C8"), eth_balance("0x70997970C51812dc3A010C7d01b50e0d17dc79C8"));
+    assert_eq!(parse_intent("check Alice's balance"), eth_balance("Alice"));
+    assert_eq!(parse_intent("what's bob’s eth balance?"), eth_balance("bob"));
+
+    // Symbols are resolved by the server, so any token-like word is accepted here
+    assert_eq!(
+        parse_intent("How much USDC does Alice have?"),
+        Some(Intent::TokenBalance { token: "USDC".to_string(), who: "Alice".to_string() })
+    );
+    assert_eq!(
+        parse_intent("dai balance for account3"),
+        Some(Intent::TokenBalance { token: "DAI".to_string(), who: "account3".to_string() })
+    );
+
+    for input in [
+        "How much $PEPE does Alice have?",
+        "How much ETH does the treasury have?",
+        "How much ETH do I have?",
+        "balance of my wallet",
+        "what is the balance",
+    ] {
+        assert_eq!(parse_intent(input), None, "'{}' should go to Claude", input);
+    }
+}
+
+#[test]
+fn test_contract_checks() {
+    let expected = Some(Intent::ContractDeployed { address: ROUTER.to_string() });
+    assert_eq!(parse_intent(&format!("Is Uniswap V2 Router ({}) deployed?", ROUTER)), expected);
+    assert_eq!(parse_intent(&format!("is a contract deployed at {}", ROUTER)), expected);
+    assert_eq!(parse_intent(&format!("check if {} is deployed", ROUTER)), expected);
+
+    // Without an address the name has to be looked up, which needs Claude
+    assert_eq!(parse_intent("Is Uniswap V2 Router deployed?"), None);
+    assert_eq!(parse_intent(&format!("Is {} or {} deployed?", ROUTER, USDC)), None);
+    assert_eq!(parse_intent(&format!("Who deployed {}?", ROUTER)), None);
+}
+
+#[test]
+fn test_tool_arguments() {
+    let intent = send("1", Some("alice"), "bob").unwrap();
+    assert_eq!(intent.tool(), "send_eth");
+    assert_eq!(json!(intent.arguments()), json!({ "to": "bob", "amount": "1", "from": "alice" }));
+    assert_eq!(json!(send("1", None, "bob").unwrap().arguments()), json!({ "to": "bob", "amount": "1" }));
+
+    let intent = Intent::TokenBalance { token: USDC.to_string(), who: ROUTER.to_string() };
+    assert_eq!(intent.tool(), "token_balance");
+    assert_eq!(json!(intent.arguments()), json!({ "token_address": USDC, "account_address": ROUTER }));
+
+    assert_eq!(eth_balance("bob").unwrap().tool(), "balance");
+    assert_eq!(Intent::ContractDeployed { address: ROUTER.to_string() }.tool(), "is_contract_deployed");
+}
+
+#[test]
+fn test_account_lookup() {
+    let accounts = DefaultAccounts::default();
+    assert_eq!(account_index("Alice", &accounts), Some(0));
+    assert_eq!(account_index("bob", &accounts), Some(1));
+    assert_eq!(account_index("account7", &accounts), Some(7));
+    assert_eq!(account_index("treasury", &accounts), None, "Address book names need the server");
+
+    let output = r#"{"total":2,"accounts":[{"index":0,"address":"0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266"},{"index":1,"address":"0x70997970C51812dc3A010C7d01b50e0d17dc79C8"}]}"#;
+    assert_eq!(account_address(output, 1).as_deref(), Some("0x70997970C51812dc3A010C7d01b50e0d17dc79C8"));
+    assert_eq!(account_address(output, 5), None);
+    assert_eq!(account_address("not json", 0), None);
+}
+
+#[test]
+fn test_token_resolution() {
+    let table = format!("Token Address Resolved:\nSymbol: USDC\nAddress: {}\nSource: network config (chain 1)\nVerified: deployed ERC-20", USDC);
+    assert_eq!(resolved_token_address(&table).as_deref(), Some(USDC));
+
+    let searched = format!("Token Address Resolved:\nSymbol: PEPE\nAddress: {}\nSource: web search for ethereum (1 candidate(s) checked)", USDC);
+    assert_eq!(resolved_token_address(&searched), None, "Web search results need Claude to confirm them");
+    assert_eq!(resolved_token_address("Could not resolve PEPE"), None);
+}
+
+#[test]
+fn test_summary_text() {
+    let structured = "{\n  \"status\": \"success\",\n  \"tx_hash\": \"0xabc\"\n}\nETH Transfer:\nStatus: SUCCESS";
+    assert_eq!(summary_text(structured), "ETH Transfer:\nStatus: SUCCESS");
+    assert_eq!(summary_text("Balance: 1 ETH"), "Balance: 1 ETH");
+    assert_eq!(summary_text("{\"total\": 2}"), "{\"total\": 2}", "JSON without a summary is kept");
+}
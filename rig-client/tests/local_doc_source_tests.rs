@@ -0,0 +1,58 @@
+//! Tests for the local filesystem document source
+//! Verifies extension filtering, document typing and modification tracking
+
+use rig_client::doc_ingestion::sources::local::LocalFileSource;
+use rig_client::doc_ingestion::{DocumentSource, DocumentType};
+use std::path::{Path, PathBuf};
+
+fn temp_docs_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("rig_client_local_{}_{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(dir.join("nested")).unwrap();
+    dir
+}
+
+#[test]
+fn test_doc_type_for_extension() {
+    assert!(matches!(LocalFileSource::doc_type_for(Path::new("Pair.sol")), DocumentType::Solidity));
+    assert!(matches!(LocalFileSource::doc_type_for(Path::new("notes.md")), DocumentType::Markdown));
+    assert!(matches!(LocalFileSource::doc_type_for(Path::new("guide.MDX")), DocumentType::Markdown));
+    assert!(matches!(LocalFileSource::doc_type_for(Path::new("abi.json")), DocumentType::JSON));
+    assert!(matches!(LocalFileSource::doc_type_for(Path::new("readme.txt")), DocumentType::Other(ext) if ext == "txt"));
+}
+
+#[tokio::test]
+async fn test_fetch_filters_by_extension() {
+    let dir = temp_docs_dir("fetch");
+    std::fs::write(dir.join("Token.sol"), "pragma solidity ^0.8.0;\ncontract Token {}").unwrap();
+    std::fs::write(dir.join("nested/notes.md"), "# Notes\n- item").unwrap();
+    std::fs::write(dir.join("ignored.txt"), "not indexed").unwrap();
+
+    let source = LocalFileSource::new(dir.clone(), vec!["sol".to_string(), ".md".to_string()]);
+    let docs = source.fetch_documents().await.unwrap();
+
+    let mut titles: Vec<_> = docs.iter().map(|d| d.metadata.title.clone()).collect();
+    titles.sort();
+    assert_eq!(titles, vec!["Token.sol", "notes.md"]);
+    assert!(docs.iter().all(|d| d.validate().is_ok()));
+    assert!(docs.iter().all(|d| d.metadata.source.source_type == "local"));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn test_has_updates_tracks_modifications() {
+    let dir = temp_docs_dir("updates");
+    std::fs::write(dir.join("notes.md"), "# Notes").unwrap();
+
+    let source = LocalFileSource::new(dir.clone(), vec!["md".to_string()]);
+    assert!(source.has_updates().await.unwrap(), "Unfetched source should report updates");
+
+    source.fetch_documents().await.unwrap();
+    assert!(!source.has_updates().await.unwrap(), "Nothing changed since the last fetch");
+
+    std::fs::write(dir.join("nested/new.md"), "# New").unwrap();
+    assert!(source.has_updates().await.unwrap(), "Added file should be detected");
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,74 @@
+//! Tests for MCP connection settings, tool merging and the unreachable-server message
+
+use clap::Parser;
+use rig_client::agent::{mcp_unreachable_help, merge_tool_lists};
+use rig_client::Config;
+use rmcp::model::Tool;
+use serde_json::Map;
+use std::sync::Arc;
+use std::time::Duration;
+
+#[test]
+fn test_mcp_connection_defaults() {
+    let config = Config::try_parse_from(["rig-client"]).unwrap();
+    assert_eq!(config.mcp_connect_attempts, 5);
+    assert_eq!(config.mcp_retry_delay(), Duration::from_millis(500));
+    assert!(!config.no_mcp);
+}
+
+#[test]
+fn test_mcp_connection_flags() {
+    let config = Config::try_parse_from([
+        "rig-client", "--mcp-connect-attempts", "2", "--mcp-retry-delay-ms", "50", "--no-mcp",
+    ]).unwrap();
+    assert_eq!(config.mcp_connect_attempts, 2);
+    assert_eq!(config.mcp_retry_delay(), Duration::from_millis(50));
+    assert!(config.no_mcp);
+}
+
+#[test]
+fn test_unreachable_help_is_actionable() {
+    let help = mcp_unreachable_help("http://127.0.0.1:9999/mcp", 3);
+    assert!(help.contains("http://127.0.0.1:9999/mcp"), "Message should name the URL that was tried");
+    assert!(help.contains("after 3 attempt(s)"));
+    assert!(help.contains("cd mcp-server && cargo run --release"));
+    assert!(help.contains("--no-mcp"));
+}
+
+#[test]
+fn test_multiple_mcp_servers() {
+    let default = Config::try_parse_from(["rig-client"]).unwrap();
+    assert_eq!(default.mcp_server, vec!["http://127.0.0.1:8080/mcp".to_string()]);
+
+    let config = Config::try_parse_from([
+        "rig-client",
+        "--mcp-server", "http://127.0.0.1:8080/mcp,http://127.0.0.1:8081/mcp",
+        "--mcp-server", "http://127.0.0.1:8082/mcp",
+    ]).unwrap();
+    assert_eq!(config.mcp_server, vec![
+        "http://127.0.0.1:8080/mcp".to_string(),
+        "http://127.0.0.1:8081/mcp".to_string(),
+        "http://127.0.0.1:8082/mcp".to_string(),
+    ]);
+}
+
+fn tool(name: &str) -> Tool {
+    Tool::new(name.to_string(), format!("{} tool", name), Arc::new(Map::new()))
+}
+
+#[test]
+fn test_merge_tool_lists_keeps_first_server_per_tool() {
+    let merged = merge_tool_lists(vec![
+        (vec![tool("balance"), tool("send_eth")], "blockchain"),
+        (vec![tool("web_search"), tool("balance")], "search"),
+    ]);
+
+    let names: Vec<(&str, &str)> = merged.iter()
+        .map(|(tool, server)| (tool.name.as_ref(), *server))
+        .collect();
+    assert_eq!(names, vec![
+        ("balance", "blockchain"),
+        ("send_eth", "blockchain"),
+        ("web_search", "search"),
+    ]);
+}
@@ -0,0 +1,128 @@
+//! Tests for Claude model selection and settings
+
+use clap::Parser;
+use rig::completion::message::Message;
+use rig::completion::{CompletionError, PromptError};
+use rig_client::agent::{is_turn_limit_error, turn_limit_response, turn_limit_warning};
+use rig_client::config::{
+    resolve_model, validate_anthropic_api_key, DefaultAccounts, LogFormat, ModelSettings, DEFAULT_MAX_TURNS,
+    SUPPORTED_MODELS, SUPPORTED_MODEL_SNAPSHOTS,
+};
+use rig_client::Config;
+use rig::providers::anthropic::CLAUDE_3_HAIKU;
+
+#[test]
+fn test_default_model_settings() {
+    let settings = ModelSettings::default();
+    assert_eq!(settings.model, CLAUDE_3_HAIKU);
+    assert_eq!(settings.temperature, 0.1);
+    assert_eq!(settings.max_tokens, 4096);
+    assert_eq!(settings.max_turns, DEFAULT_MAX_TURNS);
+}
+
+#[test]
+fn test_resolve_supported_models() {
+    // Every model rig exposes is accepted as-is
+    for model in SUPPORTED_MODELS {
+        assert_eq!(resolve_model(model), *model);
+    }
+    
+    // Known dated snapshots are accepted too
+    for model in SUPPORTED_MODEL_SNAPSHOTS {
+        assert_eq!(resolve_model(model), *model);
+    }
+    assert_eq!(resolve_model("claude-3-5-sonnet-20241022"), "claude-3-5-sonnet-20241022");
+}
+
+#[test]
+fn test_resolve_unknown_model_falls_back_to_haiku() {
+    assert_eq!(resolve_model("gpt-4"), CLAUDE_3_HAIKU);
+    assert_eq!(resolve_model(""), CLAUDE_3_HAIKU);
+    assert_eq!(resolve_model("claude-9-mega"), CLAUDE_3_HAIKU);
+    assert_eq!(resolve_model("claude-3-opus-garbage"), CLAUDE_3_HAIKU, "Only exact IDs are accepted");
+    assert_eq!(resolve_model("claude-3-5-sonnet-20991231"), CLAUDE_3_HAIKU);
+}
+
+#[test]
+fn test_max_turns_flag() {
+    let config = Config::try_parse_from(["rig-client", "--max-turns", "12"]).unwrap();
+    assert_eq!(config.model_settings().max_turns, 12);
+
+    // Zero rounds would stop before the first tool call
+    let config = Config::try_parse_from(["rig-client", "--max-turns", "0"]).unwrap();
+    assert_eq!(config.model_settings().max_turns, 1);
+}
+
+#[test]
+fn test_turn_limit_detection() {
+    let max_depth = PromptError::MaxDepthError {
+        max_depth: 5,
+        chat_history: Box::new(Vec::new()),
+        prompt: Message::user("send 1 ETH to Bob"),
+    };
+    assert!(is_turn_limit_error(&max_depth));
+    let overloaded = PromptError::CompletionError(CompletionError::ProviderError("529 overloaded".to_string()));
+    assert!(!is_turn_limit_error(&overloaded));
+
+    let warning = turn_limit_warning(5);
+    assert!(warning.contains("Stopped after 5 tool-call rounds; the task may be incomplete."));
+    assert!(warning.contains("--max-turns"));
+}
+
+#[test]
+fn test_turn_limit_keeps_partial_response() {
+    let chat_history = vec![
+        Message::user("send 1 ETH to Bob and check both balances"),
+        Message::assistant("Sent 1 ETH to Bob (tx 0xabc). Alice now has 9999 ETH."),
+        Message::user("tool result"),
+    ];
+    let response = turn_limit_response(&chat_history, 5);
+    assert!(response.starts_with("Sent 1 ETH to Bob (tx 0xabc). Alice now has 9999 ETH.\n\n"));
+    assert!(response.ends_with(&turn_limit_warning(5)));
+
+    // With no answer yet there is only the warning
+    assert_eq!(turn_limit_response(&[Message::user("hi")], 5), turn_limit_warning(5));
+}
+
+#[test]
+fn test_default_account_flags() {
+    let config = Config::try_parse_from([
+        "rig-client", "--sender-name", "Carol", "--sender-index", "2", "--recipient-name", "dave", "--recipient-index", "3",
+    ]).unwrap();
+    let accounts = config.model_settings().accounts;
+    assert_eq!((accounts.sender.as_str(), accounts.sender_index), ("carol", 2));
+    assert_eq!((accounts.recipient.as_str(), accounts.recipient_index), ("dave", 3));
+    assert_eq!(accounts.sender_display_name(), "Carol");
+
+    // A sender and recipient sharing an index would make "send to the recipient" a self-transfer
+    let config = Config::try_parse_from(["rig-client", "--sender-index", "1", "--recipient-index", "1"]).unwrap();
+    assert_eq!(config.default_accounts(), DefaultAccounts::default());
+}
+
+#[test]
+fn test_missing_or_empty_api_key_is_a_config_error() {
+    let missing = validate_anthropic_api_key(None).unwrap_err();
+    assert!(matches!(missing, rig_client::ClientError::Config(_)));
+    assert!(missing.to_string().contains("ANTHROPIC_API_KEY is not set"));
+
+    let empty = validate_anthropic_api_key(Some("  \n".to_string())).unwrap_err();
+    assert!(matches!(empty, rig_client::ClientError::Config(_)));
+    assert!(empty.to_string().contains("ANTHROPIC_API_KEY is empty"));
+
+    assert_eq!(validate_anthropic_api_key(Some(" sk-ant-test \n".to_string())).unwrap(), "sk-ant-test");
+}
+
+#[test]
+fn test_log_format_flag() {
+    let config = Config::try_parse_from(["rig-client"]).unwrap();
+    assert_eq!(config.log_format, None, "Unset, so RUST_LOG_FORMAT or pretty applies");
+
+    let config = Config::try_parse_from(["rig-client", "--log-format", "json"]).unwrap();
+    assert_eq!(config.log_format(), LogFormat::Json, "The flag wins over RUST_LOG_FORMAT");
+
+    let config = Config::try_parse_from(["rig-client", "--log-format", "Pretty"]).unwrap();
+    assert_eq!(config.log_format(), LogFormat::Pretty);
+
+    let error = Config::try_parse_from(["rig-client", "--log-format", "yaml"]).unwrap_err();
+    assert!(error.to_string().contains("expected 'pretty' or 'json'"));
+}
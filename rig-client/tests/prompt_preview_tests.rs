@@ -0,0 +1,28 @@
+//! Tests for previewing the assembled prompt without calling Claude
+//! Uses a no-MCP agent with a dummy API key, so no network access is needed
+
+use rig::providers::anthropic;
+use rig_client::config::ModelSettings;
+use rig_client::BlockchainAgent;
+
+fn offline_agent() -> BlockchainAgent {
+    BlockchainAgent::without_mcp(anthropic::Client::new("test-key"), ModelSettings::default())
+}
+
+#[tokio::test]
+async fn test_preview_tool_command() {
+    let preview = offline_agent().preview_prompt("send 1 ETH to Bob").await.unwrap();
+
+    assert!(preview.starts_with("Prompt Preview (Claude was not called)"));
+    assert!(preview.contains("Route: tool-enabled command"));
+    assert!(preview.contains("MCP servers: 0"));
+    assert!(preview.contains("Preamble: \"You are an expert Ethereum blockchain assistant"));
+    assert!(preview.ends_with("──── Prompt ────\nsend 1 ETH to Bob"));
+}
+
+#[tokio::test]
+async fn test_preview_general_question() {
+    let preview = offline_agent().preview_prompt("What tools do you have?").await.unwrap();
+
+    assert!(preview.contains("Route: general question"));
+}
@@ -0,0 +1,122 @@
+//! Tests for dropping duplicate documents before they are indexed
+//! Uses hand-made embeddings where it can; the upsert test embeds with the local fastembed model
+
+use clap::Parser;
+use rig::embeddings::Embedding;
+use rig::OneOrMany;
+use rig_client::doc_ingestion::store::InMemoryDocStore;
+use rig_client::doc_ingestion::{DefaultDocumentProcessor, DocumentIngestionPipeline};
+use rig_client::rag::{dedup_documents, DocumentMetadata, DocumentType, UniswapDocument, UniswapRagSystem, DEFAULT_DEDUP_THRESHOLD};
+use rig_client::Config;
+
+fn embedded(id: &str, content: &str, vector: Vec<f64>) -> (UniswapDocument, OneOrMany<Embedding>) {
+    let doc = UniswapDocument::new(
+        id.to_string(),
+        format!("{}.sol", id),
+        DocumentType::Interface,
+        content.to_string(),
+        DocumentMetadata::new(None, None, Vec::new()),
+    );
+    let embedding = Embedding { document: content.to_string(), vec: vector };
+    (doc, OneOrMany::one(embedding))
+}
+
+fn ids(documents: &[(UniswapDocument, OneOrMany<Embedding>)]) -> Vec<&str> {
+    documents.iter().map(|(doc, _)| doc.id.as_str()).collect()
+}
+
+#[test]
+fn test_near_identical_documents_are_dropped() {
+    let candidates = vec![
+        embedded("router", "interface IUniswapV2Router02 { ... }", vec![1.0, 0.0, 0.0]),
+        embedded("router-copy", "interface IUniswapV2Router01 { ... }", vec![0.99, 0.01, 0.0]),
+        embedded("pair", "contract UniswapV2Pair { ... }", vec![0.0, 1.0, 0.0]),
+    ];
+
+    let (kept, duplicates) = dedup_documents(&[], candidates, DEFAULT_DEDUP_THRESHOLD);
+    assert_eq!(ids(&kept), vec!["router", "pair"]);
+    assert_eq!(duplicates, 1);
+}
+
+#[test]
+fn test_identical_content_is_dropped_even_at_threshold_one() {
+    let license = "// SPDX-License-Identifier: GPL-3.0-or-later";
+    let candidates = vec![
+        embedded("header-a", license, vec![1.0, 0.0]),
+        embedded("header-b", license, vec![0.0, 1.0]),
+        embedded("similar", "// SPDX-License-Identifier: MIT", vec![1.0, 0.0]),
+    ];
+
+    let (kept, duplicates) = dedup_documents(&[], candidates, 1.0);
+    assert_eq!(ids(&kept), vec!["header-a", "similar"], "1.0 keeps everything but identical content");
+    assert_eq!(duplicates, 1);
+}
+
+#[test]
+fn test_candidates_are_compared_against_already_indexed_documents() {
+    let indexed = vec![embedded("factory", "contract UniswapV2Factory { ... }", vec![0.0, 0.0, 1.0])];
+    let candidates = vec![
+        embedded("factory-again", "contract UniswapV2Factory { }", vec![0.0, 0.01, 1.0]),
+        embedded("library", "library UniswapV2Library { ... }", vec![0.6, 0.0, 0.8]),
+    ];
+
+    let (kept, duplicates) = dedup_documents(&indexed, candidates, DEFAULT_DEDUP_THRESHOLD);
+    assert_eq!(ids(&kept), vec!["library"]);
+    assert_eq!(duplicates, 1);
+}
+
+#[test]
+fn test_documents_sharing_a_prefix_are_kept_when_their_chunks_differ() {
+    let chunked = |id: &str, content: &str, vectors: Vec<Vec<f64>>| {
+        let (doc, _) = embedded(id, content, Vec::new());
+        let embeddings = vectors.into_iter()
+            .map(|vec| Embedding { document: content.to_string(), vec })
+            .collect();
+        (doc, OneOrMany::many(embeddings).unwrap())
+    };
+    // Same truncated content embedding, different bodies
+    let candidates = vec![
+        chunked("v2-guide", "Uniswap V2 guide ... pairs", vec![vec![1.0, 0.0], vec![1.0, 0.0]]),
+        chunked("v3-guide", "Uniswap V2 guide ... ticks", vec![vec![1.0, 0.0], vec![0.0, 1.0]]),
+        chunked("v2-guide-copy", "Uniswap V2 guide ... pairs!", vec![vec![1.0, 0.0], vec![0.99, 0.01]]),
+    ];
+
+    let (kept, duplicates) = dedup_documents(&[], candidates, DEFAULT_DEDUP_THRESHOLD);
+    assert_eq!(ids(&kept), vec!["v2-guide", "v3-guide"]);
+    assert_eq!(duplicates, 1);
+}
+
+#[test]
+fn test_dedup_threshold_flag_must_be_a_similarity() {
+    let config = Config::try_parse_from(["rig-client", "--rag-dedup-threshold", "0.9"]).unwrap();
+    assert_eq!(config.rag_dedup_threshold, 0.9);
+
+    for value in ["1.5", "-0.1", "NaN", "high"] {
+        assert!(Config::try_parse_from(["rig-client", "--rag-dedup-threshold", value]).is_err(), "{} should be rejected", value);
+    }
+}
+
+#[tokio::test]
+async fn test_dropped_duplicates_are_not_embedded_again() {
+    let mut rag = UniswapRagSystem::with_pipeline(DocumentIngestionPipeline::new(
+        Vec::new(),
+        Box::new(DefaultDocumentProcessor::default()),
+        Box::new(InMemoryDocStore::default()),
+    ));
+    let document = |id: &str, title: &str| UniswapDocument::new(
+        id.to_string(),
+        title.to_string(),
+        DocumentType::Documentation,
+        "# Swaps\nThe router swaps an exact input amount for as many output tokens as possible.".to_string(),
+        DocumentMetadata::new(None, None, Vec::new()),
+    );
+    let documents = || vec![document("original", "swaps.md"), document("copy", "swaps-copy.md")];
+
+    assert_eq!(rag.upsert_documents(documents()).await.unwrap(), 2);
+    assert_eq!(rag.document_count(), 1);
+    assert_eq!(rag.stats().duplicate_documents, 1);
+
+    // Upserting the same files again embeds nothing, the duplicate included
+    assert_eq!(rag.upsert_documents(documents()).await.unwrap(), 0);
+    assert_eq!(rag.stats().duplicate_documents, 1);
+}
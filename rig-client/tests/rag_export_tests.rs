@@ -0,0 +1,61 @@
+//! Tests for exporting the indexed RAG corpus
+//! Verifies the JSON export and the corpus statistics summary
+
+use rig_client::doc_ingestion::store::InMemoryDocStore;
+use rig_client::doc_ingestion::{DefaultDocumentProcessor, DocumentIngestionPipeline};
+use rig_client::rag::{DocumentMetadata, DocumentType, ExportedDocument, UniswapDocument, UniswapRagSystem};
+
+fn empty_rag() -> UniswapRagSystem {
+    UniswapRagSystem::with_pipeline(DocumentIngestionPipeline::new(
+        Vec::new(),
+        Box::new(DefaultDocumentProcessor::default()),
+        Box::new(InMemoryDocStore::default()),
+    ))
+}
+
+fn doc(id: &str, doc_type: DocumentType, content: &str) -> UniswapDocument {
+    UniswapDocument::new(
+        id.to_string(),
+        format!("{}.md", id),
+        doc_type,
+        content.to_string(),
+        DocumentMetadata::new(None, Some("v2".to_string()), vec!["v2".to_string()]),
+    )
+}
+
+#[tokio::test]
+async fn test_export_documents_and_stats() {
+    let mut rag = empty_rag();
+    rag.upsert_documents(vec![
+        doc("router", DocumentType::Documentation, "# Router\nSwaps tokens."),
+        doc("pair", DocumentType::ContractCode, "contract Pair {}"),
+        doc("factory", DocumentType::ContractCode, "contract Factory {}"),
+    ]).await.unwrap();
+
+    let path = std::env::temp_dir().join(format!("rig_client_export_{}.json", std::process::id()));
+    let written = rag.export_documents(&path).unwrap();
+    assert_eq!(written, 3);
+
+    let exported: Vec<ExportedDocument> = serde_json::from_slice(&std::fs::read(&path).unwrap()).unwrap();
+    let ids: Vec<_> = exported.iter().map(|d| d.id.as_str()).collect();
+    assert_eq!(ids, vec!["factory", "pair", "router"]);
+    assert_eq!(exported[2].content_length, "# Router\nSwaps tokens.".len());
+    assert_eq!(exported[2].tags, vec!["v2"]);
+    std::fs::remove_file(&path).unwrap();
+
+    let stats = rag.corpus_stats();
+    assert_eq!(stats.total_documents, 3);
+    assert_eq!(stats.documents_per_type.get("ContractCode"), Some(&2));
+    assert_eq!(stats.documents_per_type.get("Documentation"), Some(&1));
+    assert!(stats.total_chunks >= 3);
+    assert!(stats.average_content_length > 0.0);
+    assert!(stats.to_string().contains("Documents: 3"));
+}
+
+#[test]
+fn test_stats_for_empty_corpus() {
+    let stats = empty_rag().corpus_stats();
+    assert_eq!(stats.total_documents, 0);
+    assert_eq!(stats.total_chunks, 0);
+    assert_eq!(stats.average_content_length, 0.0);
+}
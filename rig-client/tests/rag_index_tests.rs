@@ -0,0 +1,68 @@
+//! Tests for the on-disk RAG index cache
+//! Verifies that stale or corrupt caches are rejected so the index is rebuilt
+
+use rig_client::rag::UniswapRagSystem;
+use std::path::PathBuf;
+
+fn temp_index_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("rig_client_{}_{}.json", name, std::process::id()))
+}
+
+#[test]
+fn test_load_index_missing_file() {
+    let path = temp_index_path("missing");
+    let _ = std::fs::remove_file(&path);
+    assert!(UniswapRagSystem::load_index(&path).is_err(), "Missing cache should not load");
+}
+
+#[test]
+fn test_load_index_corrupt_file() {
+    let path = temp_index_path("corrupt");
+    std::fs::write(&path, b"{ not valid json").unwrap();
+    assert!(UniswapRagSystem::load_index(&path).is_err(), "Corrupt cache should not load");
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_load_index_count_and_checksum_mismatch() {
+    let path = temp_index_path("mismatch");
+
+    // Header claims documents that aren't present
+    std::fs::write(&path, r#"{
+        "format_version": 1,
+        "embedding_model": "AllMiniLML6V2Q",
+        "document_count": 5,
+        "checksum": "deadbeef",
+        "entries": []
+    }"#).unwrap();
+    assert!(UniswapRagSystem::load_index(&path).is_err(), "Count mismatch should not load");
+
+    // Older format versions must trigger a rebuild
+    std::fs::write(&path, r#"{
+        "format_version": 0,
+        "embedding_model": "AllMiniLML6V2Q",
+        "document_count": 0,
+        "checksum": "",
+        "entries": []
+    }"#).unwrap();
+    assert!(UniswapRagSystem::load_index(&path).is_err(), "Stale format should not load");
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[tokio::test]
+async fn test_save_and_reload_index() {
+    let path = temp_index_path("roundtrip");
+    let rag_system = UniswapRagSystem::new().await.unwrap();
+
+    if rag_system.document_count() == 0 {
+        println!("⚠️  No Uniswap docs available - skipping round-trip check");
+        return;
+    }
+
+    rag_system.save_index(&path).unwrap();
+    let reloaded = UniswapRagSystem::load_index(&path).unwrap();
+    assert_eq!(reloaded.document_count(), rag_system.document_count());
+
+    std::fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,157 @@
+//! Tests for how the RAG system drives the document ingestion pipeline
+//! Uses mock document sources so no git clones or network access are needed
+
+use async_trait::async_trait;
+use rig_client::doc_ingestion::store::InMemoryDocStore;
+use rig_client::doc_ingestion::{
+    DefaultDocumentProcessor, DocumentIngestionPipeline, DocumentMetadata, DocumentSource,
+    DocumentSourceMetadata, DocumentType, IngestionError, RawDocument,
+};
+use rig_client::rag::{
+    DocumentMetadata as RagDocumentMetadata, DocumentType as RagDocumentType, UniswapDocument,
+    UniswapRagSystem,
+};
+use std::path::Path;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Source that returns no documents and counts how often it is fetched
+struct CountingSource {
+    fetches: Arc<AtomicUsize>,
+}
+
+#[async_trait]
+impl DocumentSource for CountingSource {
+    async fn fetch_documents(&self) -> Result<Vec<RawDocument>, IngestionError> {
+        self.fetches.fetch_add(1, Ordering::SeqCst);
+        Ok(Vec::new())
+    }
+
+    async fn has_updates(&self) -> Result<bool, IngestionError> {
+        Ok(true)
+    }
+
+    fn get_metadata(&self) -> DocumentSourceMetadata {
+        DocumentSourceMetadata {
+            source_type: "mock".to_string(),
+            location: "memory".to_string(),
+            version: None,
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_load_documentation_fetches_once() {
+    let fetches = Arc::new(AtomicUsize::new(0));
+    let pipeline = DocumentIngestionPipeline::new(
+        vec![Box::new(CountingSource { fetches: fetches.clone() })],
+        Box::new(DefaultDocumentProcessor::default()),
+        Box::new(InMemoryDocStore::default()),
+    );
+
+    let mut rag = UniswapRagSystem::with_pipeline(pipeline);
+    rag.load_documentation(Path::new("")).await.unwrap();
+
+    assert_eq!(fetches.load(Ordering::SeqCst), 1, "Ingestion should run exactly once per load_documentation");
+    assert_eq!(rag.document_count(), 0);
+}
+
+/// Source that serves a fixed set of markdown documents
+struct SampleSource;
+
+impl SampleSource {
+    fn metadata() -> DocumentSourceMetadata {
+        DocumentSourceMetadata {
+            source_type: "mock".to_string(),
+            location: "memory".to_string(),
+            version: Some("v2".to_string()),
+        }
+    }
+
+    fn document(title: &str, content: &str) -> RawDocument {
+        RawDocument::new(
+            content.as_bytes().to_vec(),
+            DocumentMetadata {
+                title: title.to_string(),
+                doc_type: DocumentType::Markdown,
+                version: Some("v2".to_string()),
+                created_at: chrono::Utc::now(),
+                updated_at: chrono::Utc::now(),
+                source: Self::metadata(),
+                tags: vec!["v2".to_string(), "documentation".to_string()],
+            },
+        )
+    }
+}
+
+#[async_trait]
+impl DocumentSource for SampleSource {
+    async fn fetch_documents(&self) -> Result<Vec<RawDocument>, IngestionError> {
+        Ok(vec![
+            Self::document("router.md", "# Router\nswapExactETHForTokens swaps an exact amount of ETH for tokens."),
+            Self::document("pair.md", "# Pair\nEach pair holds reserves of two ERC-20 tokens."),
+        ])
+    }
+
+    async fn has_updates(&self) -> Result<bool, IngestionError> {
+        Ok(true)
+    }
+
+    fn get_metadata(&self) -> DocumentSourceMetadata {
+        Self::metadata()
+    }
+}
+
+#[tokio::test]
+async fn test_load_documentation_indexes_stored_documents() {
+    let pipeline = DocumentIngestionPipeline::new(
+        vec![Box::new(SampleSource)],
+        Box::new(DefaultDocumentProcessor::default()),
+        Box::new(InMemoryDocStore::default()),
+    );
+
+    let mut rag = UniswapRagSystem::with_pipeline(pipeline);
+    rag.load_documentation(Path::new("")).await.unwrap();
+
+    // Documents are stored by checksum, so retrieval must use the checksum to find them
+    assert!(rag.document_count() > 0, "Ingested documents should be indexed");
+    assert_eq!(rag.document_count(), 2);
+}
+
+fn indexed_document(id: &str, title: &str, content: &str) -> UniswapDocument {
+    UniswapDocument::new(
+        id.to_string(),
+        title.to_string(),
+        RagDocumentType::Documentation,
+        content.to_string(),
+        RagDocumentMetadata::new(Some("memory".to_string()), Some("v2".to_string()), Vec::new()),
+    )
+}
+
+#[tokio::test]
+async fn test_upsert_only_embeds_new_or_changed_documents() {
+    let pipeline = DocumentIngestionPipeline::new(
+        Vec::new(),
+        Box::new(DefaultDocumentProcessor::default()),
+        Box::new(InMemoryDocStore::default()),
+    );
+    let mut rag = UniswapRagSystem::with_pipeline(pipeline);
+
+    let router = indexed_document("router-v1", "router.md", "# Router\nSwaps tokens along a path.");
+    let pair = indexed_document("pair-v1", "pair.md", "# Pair\nHolds reserves of two tokens.");
+
+    let embedded = rag.upsert_documents(vec![router.clone(), pair.clone()]).await.unwrap();
+    assert_eq!(embedded, 2);
+    assert_eq!(rag.document_count(), 2);
+
+    // Re-upserting identical documents embeds nothing
+    let embedded = rag.upsert_documents(vec![router.clone(), pair.clone()]).await.unwrap();
+    assert_eq!(embedded, 0);
+    assert_eq!(rag.document_count(), 2);
+
+    // A changed document (new checksum) replaces its old version
+    let router_v2 = indexed_document("router-v2", "router.md", "# Router\nSwaps tokens and supports fee-on-transfer tokens.");
+    let embedded = rag.upsert_documents(vec![router_v2, pair]).await.unwrap();
+    assert_eq!(embedded, 1);
+    assert_eq!(rag.document_count(), 2);
+}
@@ -0,0 +1,107 @@
+//! Tests for rebuilding the RAG index in place
+//! Uses an editable mock document source so no git clones or network access are needed
+
+use async_trait::async_trait;
+use rig_client::doc_ingestion::store::InMemoryDocStore;
+use rig_client::doc_ingestion::{
+    DefaultDocumentProcessor, DocumentIngestionPipeline, DocumentMetadata, DocumentSource,
+    DocumentSourceMetadata, DocumentType, IngestionError, RawDocument,
+};
+use rig_client::rag::UniswapRagSystem;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+/// Source serving whatever (title, content) pairs the test currently holds
+struct EditableSource {
+    documents: Arc<Mutex<Vec<(String, String)>>>,
+}
+
+impl EditableSource {
+    fn metadata() -> DocumentSourceMetadata {
+        DocumentSourceMetadata {
+            source_type: "mock".to_string(),
+            location: "memory".to_string(),
+            version: Some("v2".to_string()),
+        }
+    }
+}
+
+#[async_trait]
+impl DocumentSource for EditableSource {
+    async fn fetch_documents(&self) -> Result<Vec<RawDocument>, IngestionError> {
+        Ok(self.documents.lock().unwrap().iter().map(|(title, content)| {
+            RawDocument::new(
+                content.as_bytes().to_vec(),
+                DocumentMetadata {
+                    title: title.clone(),
+                    doc_type: DocumentType::Markdown,
+                    version: Some("v2".to_string()),
+                    created_at: chrono::Utc::now(),
+                    updated_at: chrono::Utc::now(),
+                    source: Self::metadata(),
+                    tags: vec!["v2".to_string()],
+                },
+            )
+        }).collect())
+    }
+
+    async fn has_updates(&self) -> Result<bool, IngestionError> {
+        Ok(true)
+    }
+
+    fn get_metadata(&self) -> DocumentSourceMetadata {
+        Self::metadata()
+    }
+}
+
+fn document(title: &str, content: &str) -> (String, String) {
+    (title.to_string(), content.to_string())
+}
+
+fn rag_over(documents: Arc<Mutex<Vec<(String, String)>>>) -> UniswapRagSystem {
+    UniswapRagSystem::with_pipeline(DocumentIngestionPipeline::new(
+        vec![Box::new(EditableSource { documents })],
+        Box::new(DefaultDocumentProcessor::default()),
+        Box::new(InMemoryDocStore::default()),
+    ))
+}
+
+#[tokio::test]
+async fn test_reindex_picks_up_added_and_edited_documents() {
+    let documents = Arc::new(Mutex::new(vec![
+        document("router.md", "# Router\nswapExactETHForTokens swaps an exact amount of ETH for tokens."),
+        document("pair.md", "# Pair\nEach pair holds reserves of two ERC-20 tokens."),
+    ]));
+    let mut rag = rag_over(documents.clone());
+    rag.load_documentation(Path::new("")).await.unwrap();
+    assert_eq!(rag.document_count(), 2);
+
+    // Edit one document and add another, as if the docs folder changed on disk
+    {
+        let mut docs = documents.lock().unwrap();
+        docs[0] = document("router.md", "# Router\nswapExactETHForTokens also supports fee-on-transfer tokens.");
+        docs.push(document("factory.md", "# Factory\ncreatePair deploys a new pair contract."));
+    }
+
+    let (previous, current) = rag.reindex().await.unwrap();
+    assert_eq!((previous, current), (2, 3), "The edited router.md replaces its old version");
+
+    let titles: Vec<String> = rag.exported_documents().into_iter().map(|doc| doc.title).collect();
+    assert_eq!(titles.iter().filter(|title| *title == "router.md").count(), 1);
+    assert!(titles.contains(&"factory.md".to_string()));
+}
+
+#[tokio::test]
+async fn test_failed_reindex_keeps_current_index() {
+    let documents = Arc::new(Mutex::new(vec![
+        document("pair.md", "# Pair\nEach pair holds reserves of two ERC-20 tokens."),
+    ]));
+    let mut rag = rag_over(documents.clone());
+    rag.load_documentation(Path::new("")).await.unwrap();
+
+    documents.lock().unwrap().clear();
+
+    let error = rag.reindex().await.expect_err("An empty rebuild should not replace the index");
+    assert!(error.to_string().contains("keeping the current index of 1 documents"));
+    assert_eq!(rag.document_count(), 1);
+}
@@ -0,0 +1,68 @@
+//! Tests for the RAG index health metrics behind rag-stats
+//! Verifies the type and version breakdown, embedding shape and memory estimate
+
+use rig_client::doc_ingestion::store::InMemoryDocStore;
+use rig_client::doc_ingestion::{DefaultDocumentProcessor, DocumentIngestionPipeline};
+use rig_client::rag::{DocumentMetadata, DocumentType, IndexStats, UniswapDocument, UniswapRagSystem, DEFAULT_EMBEDDING_MODEL};
+
+fn empty_rag() -> UniswapRagSystem {
+    UniswapRagSystem::with_pipeline(DocumentIngestionPipeline::new(
+        Vec::new(),
+        Box::new(DefaultDocumentProcessor::default()),
+        Box::new(InMemoryDocStore::default()),
+    ))
+}
+
+fn doc(id: &str, doc_type: DocumentType, version: Option<&str>, content: &str) -> UniswapDocument {
+    UniswapDocument::new(
+        id.to_string(),
+        format!("{}.md", id),
+        doc_type,
+        content.to_string(),
+        DocumentMetadata::new(None, version.map(str::to_string), Vec::new()),
+    )
+}
+
+#[tokio::test]
+async fn test_stats_break_down_the_index() {
+    let mut rag = empty_rag();
+    rag.upsert_documents(vec![
+        doc("router", DocumentType::Documentation, Some("v2"), "# Router\nSwaps tokens."),
+        doc("pair", DocumentType::ContractCode, Some("v2"), "contract UniswapV2Pair { function swap(uint amount0Out) external {} }"),
+        doc("pool", DocumentType::Explanation, Some("v3"), "Concentrated liquidity positions in V3 pools earn fees within a tick range."),
+        doc("faq", DocumentType::FAQ, None, "# FAQ\nWhat is a pool?"),
+    ]).await.unwrap();
+
+    let stats = rag.stats();
+    assert_eq!(stats.total_documents, 4);
+    assert_eq!(stats.total_chunks, rag.exported_documents().iter().map(|d| d.chunk_count).sum::<usize>());
+    assert_eq!(stats.documents_per_type.get("ContractCode"), Some(&1));
+    assert_eq!(stats.documents_per_type.get("FAQ"), Some(&1));
+    assert_eq!(stats.documents_per_version.get("v2"), Some(&2));
+    assert_eq!(stats.documents_per_version.get("v3"), Some(&1));
+    assert_eq!(stats.documents_per_version.get("unversioned"), Some(&1));
+    assert_eq!(stats.embedding_model, DEFAULT_EMBEDDING_MODEL);
+
+    // AllMiniLML6V2Q embeds into 384 dimensions
+    assert_eq!(stats.embedding_dimensions, Some(384));
+    assert!(stats.total_embeddings >= 4);
+    assert!(stats.approximate_memory_bytes >= stats.total_embeddings * 384 * std::mem::size_of::<f64>());
+
+    let text = stats.to_string();
+    assert!(text.contains("By version:\n  • unversioned: 1\n  • v2: 2\n  • v3: 1"));
+    assert!(text.contains("× 384 dimensions"));
+    assert!(text.contains("Approximate memory: "));
+
+    let json: IndexStats = serde_json::from_str(&serde_json::to_string(&stats).unwrap()).unwrap();
+    assert_eq!(json, stats);
+}
+
+#[test]
+fn test_stats_for_empty_index() {
+    let stats = empty_rag().stats();
+    assert_eq!(stats.total_documents, 0);
+    assert_eq!(stats.embedding_dimensions, None);
+    assert_eq!(stats.approximate_memory_bytes, 0);
+    assert!(stats.to_string().contains("Embeddings: none"));
+    assert!(stats.to_string().ends_with("Approximate memory: 0 B"));
+}
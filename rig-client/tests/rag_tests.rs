@@ -28,7 +28,7 @@ async fn test_document_search() {
     rag_system.add_sample_documentation().await.unwrap();
     
     // Test search for slippage information
-    let results = rag_system.search("slippage calculation", 3).await;
+    let results = rag_system.search("slippage calculation", 3, false).await;
     assert!(results.is_ok(), "Search should work");
     
     let results = results.unwrap();
@@ -75,17 +75,37 @@ async fn test_specific_queries() {
     rag_system.add_sample_documentation().await.unwrap();
     
     // Test query 1: "How do I calculate slippage for Uniswap V3?"
-    let results1 = rag_system.search("How do I calculate slippage for Uniswap V3?", 3).await;
+    let results1 = rag_system.search("How do I calculate slippage for Uniswap V3?", 3, false).await;
     assert!(results1.is_ok(), "Slippage query should work");
     assert!(!results1.unwrap().is_empty(), "Should find slippage documents");
     
     // Test query 2: "What's the difference between exactInput and exactOutput?"
-    let results2 = rag_system.search("What's the difference between exactInput and exactOutput?", 3).await;
+    let results2 = rag_system.search("What's the difference between exactInput and exactOutput?", 3, false).await;
     assert!(results2.is_ok(), "Function difference query should work");
     assert!(!results2.unwrap().is_empty(), "Should find function documentation");
     
     // Test query 3: "Show me the SwapRouter contract interface"
-    let results3 = rag_system.search("Show me the SwapRouter contract interface", 3).await;
+    let results3 = rag_system.search("Show me the SwapRouter contract interface", 3, false).await;
     assert!(results3.is_ok(), "Contract interface query should work");
     assert!(!results3.unwrap().is_empty(), "Should find contract code");
 }
+
+#[tokio::test]
+async fn test_search_with_threshold() {
+    // Test that low-relevance results are filtered out
+    let rag_system = UniswapRagSystem::new().await.unwrap();
+    
+    let unfiltered = rag_system.search("How do I calculate slippage for Uniswap V3?", 3, false).await.unwrap();
+    let all = rag_system.search_with_threshold("How do I calculate slippage for Uniswap V3?", 3, 0.0).await.unwrap();
+    assert_eq!(all.len(), unfiltered.len(), "Zero threshold should keep every result");
+    
+    // Cosine similarity never exceeds 1.0, so nothing can pass this threshold
+    let none = rag_system.search_with_threshold("How do I calculate slippage for Uniswap V3?", 3, 1.01).await.unwrap();
+    assert!(none.is_empty(), "Impossible threshold should return no results");
+    
+    let relevant = rag_system
+        .search_with_threshold("How do I calculate slippage for Uniswap V3?", 3, rig_client::rag::DEFAULT_MIN_RELEVANCE)
+        .await
+        .unwrap();
+    assert!(relevant.iter().all(|(score, _, _)| *score >= rig_client::rag::DEFAULT_MIN_RELEVANCE));
+}
@@ -0,0 +1,49 @@
+//! Tests for reranking RAG vector hits with keyword overlap
+//! Uses hand-made scores so no embedding model is needed
+
+use rig_client::rag::{
+    keyword_overlap_scores, rerank_results, DocumentMetadata, DocumentType, UniswapDocument,
+};
+
+fn doc(id: &str, title: &str, content: &str) -> UniswapDocument {
+    UniswapDocument::new(
+        id.to_string(),
+        title.to_string(),
+        DocumentType::Documentation,
+        content.to_string(),
+        DocumentMetadata::new(None, Some("v2".to_string()), Vec::new()),
+    )
+}
+
+#[test]
+fn test_keyword_overlap_prefers_matching_terms() {
+    let texts = vec![
+        "Slippage tolerance sets the minimum output amount for a swap".to_string(),
+        "Liquidity providers earn fees from every trade".to_string(),
+        String::new(),
+    ];
+
+    let scores = keyword_overlap_scores("how is slippage tolerance applied", &texts);
+
+    assert_eq!(scores.len(), 3);
+    assert_eq!(scores[0], 1.0, "Best match is normalised to 1.0");
+    assert_eq!(scores[1], 0.0);
+    assert_eq!(scores[2], 0.0);
+    assert!(keyword_overlap_scores("", &texts).iter().all(|s| *s == 0.0));
+}
+
+#[test]
+fn test_rerank_promotes_keyword_match_and_truncates() {
+    let results = vec![
+        (0.62, "fees".to_string(), doc("fees", "Protocol fees", "Liquidity providers earn a 0.3% fee on trades.")),
+        (0.60, "slippage".to_string(), doc("slippage", "Slippage", "Set amountOutMin from the slippage tolerance.")),
+        (0.40, "pairs".to_string(), doc("pairs", "Pairs", "Each pair holds two reserves.")),
+    ];
+
+    let reranked = rerank_results("what slippage tolerance should I use", results, 2);
+
+    assert_eq!(reranked.len(), 2);
+    assert_eq!(reranked[0].1, "slippage", "Keyword match should outrank a slightly higher cosine score");
+    assert_eq!(reranked[1].1, "fees");
+    assert!(reranked[0].0 >= reranked[1].0, "Results are sorted by combined score");
+}
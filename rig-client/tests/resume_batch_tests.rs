@@ -0,0 +1,85 @@
+//! Tests for batch resume extraction
+//! Verifies one backend serves the whole batch, concurrency is bounded and
+//! failures are reported per resume
+
+use async_trait::async_trait;
+use rig_client::resume::{extract_resumes_with, ResumeBackend};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Answers from the resume's first line, failing resumes that start with "broken"
+#[derive(Clone)]
+struct MockBackend {
+    /// First line of every resume this backend was asked to extract
+    seen: Arc<Mutex<Vec<String>>>,
+    in_flight: Arc<AtomicUsize>,
+    max_in_flight: Arc<AtomicUsize>,
+}
+
+impl MockBackend {
+    fn new() -> Self {
+        Self {
+            seen: Arc::new(Mutex::new(Vec::new())),
+            in_flight: Arc::new(AtomicUsize::new(0)),
+            max_in_flight: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+}
+
+#[async_trait]
+impl ResumeBackend for MockBackend {
+    async fn extract_with_schema(&self, resume_text: &str, _schema: &str) -> rig_client::Result<String> {
+        let running = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+        self.max_in_flight.fetch_max(running, Ordering::SeqCst);
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        self.in_flight.fetch_sub(1, Ordering::SeqCst);
+
+        let name = resume_text.trim().lines().next().unwrap_or_default().trim();
+        self.seen.lock().unwrap().push(name.to_string());
+        if name.starts_with("broken") {
+            return Ok("I couldn't read this resume".to_string());
+        }
+        Ok(serde_json::json!({
+            "name": name,
+            "email": format!("{}@example.com", name.to_lowercase().replace(' ', ".")),
+            "experience": ["Engineer at Acme"],
+            "skills": ["Rust"],
+        }).to_string())
+    }
+}
+
+#[tokio::test]
+async fn test_extract_batch_shares_one_backend() {
+    let template = MockBackend::new();
+    let (seen, max_in_flight) = (template.seen.clone(), template.max_in_flight.clone());
+    let backends_created = Arc::new(AtomicUsize::new(0));
+    let make_backend = {
+        let backends_created = backends_created.clone();
+        move || {
+            backends_created.fetch_add(1, Ordering::SeqCst);
+            template.clone()
+        }
+    };
+
+    let texts = vec![
+        "Jane Doe\njane@example.com\nSkills: Rust".to_string(),
+        "broken scan".to_string(),
+        "John Smith\njohn@example.com\nSkills: Python".to_string(),
+        "Ada Lovelace\nada@example.com\nSkills: Mathematics".to_string(),
+    ];
+    let results = extract_resumes_with(make_backend, texts, 2).await;
+
+    assert_eq!(backends_created.load(Ordering::SeqCst), 1, "One backend serves the whole batch");
+    let mut seen = seen.lock().unwrap().clone();
+    seen.sort();
+    assert_eq!(seen, ["Ada Lovelace", "Jane Doe", "John Smith", "broken scan"], "The factory's backend extracted every resume once");
+    assert!(max_in_flight.load(Ordering::SeqCst) <= 2, "At most `concurrency` extractions run at once");
+
+    // Results keep input order and a failure doesn't abort the batch
+    assert_eq!(results.len(), 4);
+    assert_eq!(results[0].as_ref().unwrap().name, "Jane Doe");
+    assert!(results[1].is_err());
+    assert_eq!(results[2].as_ref().unwrap().name, "John Smith");
+    assert_eq!(results[3].as_ref().unwrap().email, "ada.lovelace@example.com");
+}
@@ -0,0 +1,41 @@
+//! Tests for schema-driven resume extraction
+//! Verifies parsing of the raw BAML answer and the default Resume schema
+
+use baml_client::models::Resume;
+use rig_client::resume::{parse_extracted_json, resume_schema};
+use serde_json::json;
+
+#[test]
+fn test_parse_extracted_json() {
+    let expected = json!({ "name": "Jane Doe", "phone": null, "years_of_experience": 7 });
+
+    let plain = r#"{"name": "Jane Doe", "phone": null, "years_of_experience": 7}"#;
+    assert_eq!(parse_extracted_json(plain).unwrap(), expected);
+
+    let fenced = "```json\n{\"name\": \"Jane Doe\", \"phone\": null, \"years_of_experience\": 7}\n```";
+    assert_eq!(parse_extracted_json(fenced).unwrap(), expected);
+}
+
+#[test]
+fn test_parse_extracted_json_rejects_non_objects() {
+    assert!(parse_extracted_json("not json").is_err());
+    assert!(parse_extracted_json("[\"Rust\", \"Python\"]").is_err());
+}
+
+#[test]
+fn test_resume_schema_matches_resume_shape() {
+    let schema = resume_schema();
+    let mut fields: Vec<&str> = schema.as_object().unwrap().keys().map(String::as_str).collect();
+    fields.sort();
+    assert_eq!(fields, vec!["email", "experience", "name", "skills"]);
+
+    // An answer shaped by the schema converts into the typed Resume
+    let resume: Resume = serde_json::from_value(json!({
+        "name": "Jane Doe",
+        "email": "jane@example.com",
+        "experience": ["Engineer at Acme"],
+        "skills": ["Rust"],
+    })).unwrap();
+    assert_eq!(resume.name, "Jane Doe");
+    assert_eq!(resume.skills, vec!["Rust".to_string()]);
+}
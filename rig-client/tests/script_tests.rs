@@ -0,0 +1,62 @@
+//! Tests for reading REPL commands from a script file
+//! Covers parsing and command recognition only, so no agent or MCP server is needed
+
+use clap::Parser;
+use rig_client::cli::{parse_command, parse_script, RagSearchArgs, ReplCommand};
+use std::path::PathBuf;
+use rig_client::Config;
+
+#[test]
+fn test_script_skips_comments_and_blank_lines() {
+    let script = "# Demo: fund Bob, then check\n\nsend 1 ETH to Bob\n   How much USDC does Alice have?  \n  # indented comment\nrag-search slippage\n";
+
+    let commands = parse_script(script);
+    assert_eq!(commands, vec![
+        (3, "send 1 ETH to Bob".to_string()),
+        (4, "How much USDC does Alice have?".to_string()),
+        (6, "rag-search slippage".to_string()),
+    ], "Line numbers count comments and blank lines");
+
+    assert!(parse_script("# only comments\n\n").is_empty());
+}
+
+#[test]
+fn test_script_flags() {
+    let config = Config::parse_from(["rig-client", "--script", "demo.txt", "--continue-on-error"]);
+    assert_eq!(config.script, Some(std::path::PathBuf::from("demo.txt")));
+    assert!(config.continue_on_error);
+
+    let config = Config::parse_from(["rig-client"]);
+    assert!(config.script.is_none() && !config.continue_on_error);
+
+    assert!(Config::try_parse_from(["rig-client", "--continue-on-error"]).is_err(), "--continue-on-error needs --script");
+}
+
+#[test]
+fn test_script_lines_use_the_repl_commands() {
+    assert_eq!(parse_command("rag-search slippage"), Some(ReplCommand::RagSearch(RagSearchArgs {
+        query: "slippage".to_string(),
+        ..RagSearchArgs::default()
+    })));
+    assert_eq!(parse_command("RAG-STATUS"), Some(ReplCommand::RagStatus));
+    assert_eq!(parse_command("self-test"), Some(ReplCommand::SelfTest));
+    assert_eq!(parse_command("rag-stats --json"), Some(ReplCommand::RagStats { json: true }));
+    assert_eq!(parse_command("rag-export"), Some(ReplCommand::RagExport(PathBuf::from("rag_export.json"))));
+    assert_eq!(parse_command("address-book add carol 0x70997970C51812dc3A010C7d01b50e0d17dc79C8"), Some(ReplCommand::AddressBookAdd {
+        name: "carol".to_string(),
+        address: "0x70997970C51812dc3A010C7d01b50e0d17dc79C8".to_string(),
+    }));
+    assert_eq!(parse_command("run-script demo.txt --continue-on-error"), Some(ReplCommand::RunScript {
+        path: PathBuf::from("demo.txt"),
+        continue_on_error: true,
+    }));
+    assert_eq!(parse_command("quit"), Some(ReplCommand::Quit));
+
+    assert!(matches!(parse_command("rag-search"), Some(ReplCommand::Usage(usage)) if usage.starts_with("Usage: rag-search")));
+    assert!(matches!(parse_command("preview"), Some(ReplCommand::Usage(_))));
+    assert!(matches!(parse_command("address-book add carol"), Some(ReplCommand::Usage(_))));
+
+    assert_eq!(parse_command("send 1 ETH to Bob"), None, "Natural language goes to the agent");
+    assert_eq!(parse_command("How much USDC does Alice have?"), None);
+    assert_eq!(parse_command("testing the waters"), None, "Only whole built-in names match");
+}
@@ -0,0 +1,66 @@
+//! Tests for saving documentation search results as a markdown report
+//! Uses hand-made results so no embedding model is needed
+
+use chrono::TimeZone;
+use rig_client::cli::{parse_rag_search_args, RAG_SEARCH_PAGE_LIMIT};
+use rig_client::rag::{search_results_markdown, DocumentMetadata, DocumentType, UniswapDocument};
+
+fn result(score: f64, id: &str, title: &str, doc_type: DocumentType, content: &str, tags: &[&str]) -> (f64, String, UniswapDocument) {
+    let doc = UniswapDocument::new(
+        id.to_string(),
+        title.to_string(),
+        doc_type,
+        content.to_string(),
+        DocumentMetadata::new(None, None, tags.iter().map(|tag| tag.to_string()).collect()),
+    );
+    (score, id.to_string(), doc)
+}
+
+#[test]
+fn test_markdown_report_describes_query_model_and_results() {
+    let generated_at = chrono::Utc.with_ymd_and_hms(2026, 3, 1, 12, 0, 0).unwrap();
+    let results = vec![
+        result(0.8421, "doc-router", "router.md", DocumentType::Documentation, "# Router\nSwaps tokens along a path.\n", &["v2", "router"]),
+        result(0.61, "doc-pair", "UniswapV2Pair.sol", DocumentType::ContractCode, "contract UniswapV2Pair {}", &[]),
+    ];
+
+    let markdown = search_results_markdown("how do swaps work", "AllMiniLML6V2Q", generated_at, &results);
+
+    assert!(markdown.starts_with("# RAG search: how do swaps work\n"));
+    assert!(markdown.contains("- Generated: 2026-03-01T12:00:00+00:00\n"));
+    assert!(markdown.contains("- Embedding model: AllMiniLML6V2Q\n"));
+    assert!(markdown.contains("- Results: 2\n"));
+    assert!(markdown.contains("## 1. router.md (score 0.842)\n\n- ID: `doc-router`\n- Tags: v2, router\n\n# Router\nSwaps tokens along a path.\n"));
+    assert!(markdown.contains("## 2. UniswapV2Pair.sol (score 0.610)\n\n- ID: `doc-pair`\n- Tags: none\n\n```\ncontract UniswapV2Pair {}\n```\n"), "Source code is fenced");
+}
+
+#[test]
+fn test_rag_search_save_option() {
+    let args = parse_rag_search_args("rag-search how do swaps work --save reports/swaps.md").unwrap();
+    assert_eq!(args.query, "how do swaps work");
+    assert_eq!(args.save, Some(std::path::PathBuf::from("reports/swaps.md")));
+
+    let args = parse_rag_search_args("rag-search --save out.md flash swaps").unwrap();
+    assert_eq!((args.query.as_str(), args.save.is_some()), ("flash swaps", true));
+
+    let args = parse_rag_search_args("rag-search pair fees").unwrap();
+    assert_eq!((args.query.as_str(), args.save, args.page()), ("pair fees", None, None));
+    assert!(parse_rag_search_args("rag-search fees --save").unwrap_err().contains("--save needs a path"));
+    assert!(parse_rag_search_args("rag-search --save out.md").unwrap_err().starts_with("Usage"));
+}
+
+#[test]
+fn test_rag_search_paging_options() {
+    let args = parse_rag_search_args("rag-search pair fees --limit 10 --offset 20").unwrap();
+    assert_eq!(args.query, "pair fees");
+    assert_eq!(args.page(), Some((10, 20)));
+
+    let args = parse_rag_search_args("rag-search --offset 5 pair fees").unwrap();
+    assert_eq!(args.page(), Some((RAG_SEARCH_PAGE_LIMIT, 5)), "--offset alone uses the default page size");
+    assert_eq!(parse_rag_search_args("rag-search fees --limit 3").unwrap().page(), Some((3, 0)));
+
+    assert!(parse_rag_search_args("rag-search fees --limit").unwrap_err().contains("--limit needs a number"));
+    assert!(parse_rag_search_args("rag-search fees --offset many").unwrap_err().contains("--offset needs a number"));
+    assert!(parse_rag_search_args("rag-search fees --limit 0").unwrap_err().contains("at least 1"));
+    assert!(parse_rag_search_args("rag-search --limit 5").unwrap_err().starts_with("Usage"));
+}
@@ -0,0 +1,68 @@
+//! Tests for metadata/tag filtering of RAG search results
+
+use rig_client::doc_ingestion::store::InMemoryDocStore;
+use rig_client::doc_ingestion::{DefaultDocumentProcessor, DocumentIngestionPipeline};
+use rig_client::rag::{DocumentMetadata, DocumentType, SearchFilter, UniswapDocument, UniswapRagSystem};
+
+fn doc(id: &str, doc_type: DocumentType, version: &str, content: &str) -> UniswapDocument {
+    UniswapDocument::new(
+        id.to_string(),
+        format!("{}.md", id),
+        doc_type,
+        content.to_string(),
+        DocumentMetadata::new(None, Some(version.to_string()), vec![version.to_string(), "uniswap".to_string()]),
+    )
+}
+
+#[test]
+fn test_filter_matches_doc_type_and_tags() {
+    let v3_code = doc("v3-router", DocumentType::ContractCode, "v3", "contract SwapRouter {}");
+    let v2_docs = doc("v2-guide", DocumentType::Documentation, "v2", "# Swapping on V2");
+
+    let v3_only = SearchFilter::default().with_tag("V3");
+    assert!(v3_only.matches(&v3_code), "Tags match case-insensitively");
+    assert!(!v3_only.matches(&v2_docs));
+
+    let code_only = SearchFilter::default().with_doc_type(DocumentType::ContractCode);
+    assert!(code_only.matches(&v3_code));
+    assert!(!code_only.matches(&v2_docs));
+
+    let v2_code = SearchFilter::default().with_doc_type(DocumentType::ContractCode).with_tag("v2");
+    assert!(!v2_code.matches(&v3_code));
+    assert!(!v2_code.matches(&v2_docs));
+
+    assert!(SearchFilter::default().is_empty());
+    assert!(SearchFilter::default().matches(&v2_docs));
+}
+
+#[test]
+fn test_filter_inferred_from_query_version() {
+    assert_eq!(
+        SearchFilter::from_query("How does the V3 SwapRouter handle exactInput?"),
+        Some(SearchFilter::default().with_tag("v3"))
+    );
+    assert_eq!(SearchFilter::from_query("uniswap v2 pair reserves"), Some(SearchFilter::default().with_tag("v2")));
+    assert_eq!(SearchFilter::from_query("What changed between v2 and v3?"), None, "Comparisons search both versions");
+    assert_eq!(SearchFilter::from_query("How do I calculate slippage?"), None);
+    assert_eq!(SearchFilter::from_query("what is dev3 mode"), None, "Only whole-word versions count");
+}
+
+#[tokio::test]
+async fn test_search_filtered_returns_only_matching_documents() {
+    let mut rag = UniswapRagSystem::with_pipeline(DocumentIngestionPipeline::new(
+        Vec::new(),
+        Box::new(DefaultDocumentProcessor::default()),
+        Box::new(InMemoryDocStore::default()),
+    ));
+    rag.upsert_documents(vec![
+        doc("v2-router", DocumentType::ContractCode, "v2", "contract UniswapV2Router02 { function swapExactETHForTokens() {} }"),
+        doc("v3-router", DocumentType::ContractCode, "v3", "contract SwapRouter { function exactInputSingle() {} }"),
+        doc("v3-guide", DocumentType::Documentation, "v3", "# Swaps\nUse exactInputSingle to swap on V3."),
+    ]).await.unwrap();
+
+    let filter = SearchFilter::default().with_doc_type(DocumentType::ContractCode).with_tag("v3");
+    let results = rag.search_filtered("swap router contract", 3, &filter).await.unwrap();
+
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].1, "v3-router");
+}
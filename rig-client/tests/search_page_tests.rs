@@ -0,0 +1,36 @@
+//! Tests for paging documentation search results
+//! Uses hand-made rankings so no embedding model is needed
+
+use rig_client::rag::{DocumentMetadata, DocumentType, SearchPage, UniswapDocument};
+
+fn ranked(count: usize) -> Vec<(f64, String, UniswapDocument)> {
+    (0..count)
+        .map(|i| {
+            let id = format!("doc-{}", i);
+            let doc = UniswapDocument::new(
+                id.clone(),
+                format!("Document {}", i),
+                DocumentType::Documentation,
+                String::new(),
+                DocumentMetadata::new(None, None, Vec::new()),
+            );
+            (1.0 - i as f64 / 100.0, id, doc)
+        })
+        .collect()
+}
+
+#[test]
+fn test_page_drops_the_first_offset_results() {
+    // Top offset + limit = 10 hits, second page of 5
+    let page = SearchPage::from_ranked(ranked(10), 5, 42);
+
+    let ids: Vec<&str> = page.results.iter().map(|(_, id, _)| id.as_str()).collect();
+    assert_eq!(ids, vec!["doc-5", "doc-6", "doc-7", "doc-8", "doc-9"]);
+    assert_eq!(page.footer(), "Showing 6-10 of 42, use offset 10 for more");
+}
+
+#[test]
+fn test_last_and_empty_page_footers() {
+    assert_eq!(SearchPage::from_ranked(ranked(12), 10, 12).footer(), "Showing 11-12 of 12");
+    assert_eq!(SearchPage::from_ranked(ranked(3), 5, 3).footer(), "Showing 0 of 3");
+}
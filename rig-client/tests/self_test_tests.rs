@@ -0,0 +1,89 @@
+//! Tests for the self-test report and the helpers that judge tool output
+//! No MCP server is started; run_self_test is only checked to refuse a no-MCP agent
+
+use rig::providers::anthropic;
+use rig_client::config::ModelSettings;
+use rig_client::self_test::{
+    check_output, find_tx_hash, first_account_address, health_status, private_key_available, SelfTestReport,
+    StepOutcome,
+};
+use rig_client::BlockchainAgent;
+
+const TX_HASH: &str = "0x5c504ed432cb51138bcf09aa5e8a410dd4a1e204ef84bfed1be16dfba1b22060";
+
+#[test]
+fn test_check_output() {
+    let output = "Contract Check:\nAddress: 0x7a25...\nStatus: DEPLOYED\n";
+    assert_eq!(check_output(output, "Status: DEPLOYED"), StepOutcome::Passed("Status: DEPLOYED".to_string()));
+
+    assert_eq!(
+        check_output("\n  Transaction Status: FAILED\nGas Used: 21000", "SUCCESS"),
+        StepOutcome::Failed("expected 'SUCCESS', got: Transaction Status: FAILED".to_string())
+    );
+    assert_eq!(
+        check_output("", "SUCCESS"),
+        StepOutcome::Failed("expected 'SUCCESS', got: empty response".to_string())
+    );
+}
+
+#[test]
+fn test_first_account_address() {
+    let output = r#"{"total":2,"accounts":[{"index":0,"address":"0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266"},{"index":1,"address":"0x70997970C51812dc3A010C7d01b50e0d17dc79C8"}]}"#;
+    assert_eq!(
+        first_account_address(output).as_deref(),
+        Some("0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266")
+    );
+
+    assert_eq!(first_account_address(r#"{"total":0,"accounts":[]}"#), None);
+    assert_eq!(first_account_address("Error: node unreachable"), None);
+}
+
+#[test]
+fn test_private_key_available() {
+    assert!(private_key_available("Server Health:\n  Private Key Available: true\nChain ID: 1"));
+    assert!(!private_key_available("Server Health:\n  Private Key Available: false"));
+    assert!(!private_key_available(""));
+}
+
+#[test]
+fn test_health_status() {
+    assert_eq!(health_status("Health Check:\nStatus: HEALTHY\nUptime: 3s"), Some("HEALTHY"));
+    assert_eq!(health_status("Health Check:\nStatus: DEGRADED\nProvider: DISCONNECTED (refused)"), Some("DEGRADED"));
+    assert_eq!(health_status("Server Health:\n  Private Key Available: true"), None);
+}
+
+#[test]
+fn test_find_tx_hash() {
+    let output = format!("ETH to WETH Swap Sent\nFrom: 0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266\nTransaction Hash: {}", TX_HASH);
+    assert_eq!(find_tx_hash(&output).as_deref(), Some(TX_HASH), "Addresses are skipped");
+
+    assert_eq!(find_tx_hash("To: 0x70997970C51812dc3A010C7d01b50e0d17dc79C8"), None);
+    assert_eq!(find_tx_hash(&format!("{}ff", TX_HASH)), None, "Longer hex strings are not hashes");
+}
+
+#[test]
+fn test_report_counts_and_display() {
+    let mut report = SelfTestReport::default();
+    report.record("get_accounts", StepOutcome::Passed("total: 10".to_string()));
+    report.record("balance", StepOutcome::Passed("Balance: 10000 ETH".to_string()));
+    report.record("swap_tokens", StepOutcome::Skipped("no private key".to_string()));
+
+    assert_eq!((report.passed(), report.failed(), report.skipped()), (2, 0, 1));
+    assert!(report.is_success(), "Skipped steps don't fail the run");
+
+    report.record("token_balance", StepOutcome::Failed("expected 'Balance', got: error".to_string()));
+    assert!(!report.is_success());
+
+    let text = report.to_string();
+    assert!(text.starts_with("Self-test report:\n"));
+    assert!(text.contains("  ✅ PASS get_accounts - total: 10"));
+    assert!(text.contains("  ⏭️ SKIP swap_tokens - no private key"));
+    assert!(text.contains("  ❌ FAIL token_balance - expected 'Balance', got: error"));
+    assert!(text.ends_with("2 passed, 1 failed, 1 skipped"));
+}
+
+#[tokio::test]
+async fn test_self_test_requires_mcp() {
+    let agent = BlockchainAgent::without_mcp(anthropic::Client::new("test-key"), ModelSettings::default());
+    assert!(agent.run_self_test().await.is_err());
+}
@@ -0,0 +1,163 @@
+//! Tests for Solidity-aware chunking in the ingestion pipeline
+//! Verifies that whole functions (signature through closing brace) stay together
+//! unless they exceed the maximum chunk size
+
+use rig_client::doc_ingestion::{
+    DefaultDocumentProcessor, DocumentMetadata, DocumentProcessor, DocumentSourceMetadata,
+    DocumentType, ProcessedDocument, RawDocument,
+};
+
+const ERC20_SOURCE: &str = r#"// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.20;
+
+import "./IERC20.sol";
+
+/// @title Minimal ERC-20 token
+contract ERC20 is IERC20 {
+    mapping(address => uint256) private _balances;
+    mapping(address => mapping(address => uint256)) private _allowances;
+    uint256 private _totalSupply;
+    string public name;
+    string public symbol;
+
+    event Log(string message);
+
+    constructor(string memory name_, string memory symbol_) {
+        name = name_;
+        symbol = symbol_;
+    }
+
+    function totalSupply() external view returns (uint256) {
+        return _totalSupply;
+    }
+
+    /// @notice Moves `amount` tokens to `to` - a brace in a comment: {
+    function transfer(
+        address to,
+        uint256 amount
+    ) external returns (bool) {
+        _transfer(msg.sender, to, amount);
+        return true;
+    }
+
+    function approve(address spender, uint256 amount) external returns (bool) {
+        /* block comment with } and ; inside */
+        _allowances[msg.sender][spender] = amount;
+        emit Log("approved {spender}; done");
+        return true;
+    }
+
+    function _transfer(address from, address to, uint256 amount) internal {
+        require(_balances[from] >= amount, "ERC20: insufficient balance }");
+        unchecked {
+            _balances[from] -= amount;
+        }
+        if (to != address(0)) {
+            _balances[to] += amount;
+        } else {
+            _totalSupply -= amount;
+        }
+    }
+}
+"#;
+
+async fn process_solidity(source: &str) -> ProcessedDocument {
+    process_solidity_with(&DefaultDocumentProcessor::default(), source).await
+}
+
+async fn process_solidity_with(processor: &DefaultDocumentProcessor, source: &str) -> ProcessedDocument {
+    let doc = RawDocument::new(
+        source.as_bytes().to_vec(),
+        DocumentMetadata {
+            title: "ERC20.sol".to_string(),
+            doc_type: DocumentType::Solidity,
+            version: None,
+            created_at: chrono::Utc::now(),
+            updated_at: chrono::Utc::now(),
+            source: DocumentSourceMetadata {
+                source_type: "test".to_string(),
+                location: "memory".to_string(),
+                version: None,
+            },
+            tags: Vec::new(),
+        },
+    );
+    processor.process(doc).await.unwrap()
+}
+
+fn chunk_containing<'a>(processed: &'a ProcessedDocument, needle: &str) -> &'a str {
+    processed.chunks.iter()
+        .find(|chunk| chunk.contains(needle))
+        .unwrap_or_else(|| panic!("No chunk contains {:?}: {:#?}", needle, processed.chunks))
+}
+
+#[tokio::test]
+async fn test_each_function_is_one_chunk() {
+    let processed = process_solidity(ERC20_SOURCE).await;
+
+    // constructor + 4 functions + one declarations chunk
+    assert_eq!(processed.chunks.len(), 6, "{:#?}", processed.chunks);
+
+    let transfer = chunk_containing(&processed, "function transfer(");
+    assert!(transfer.starts_with("/// @notice Moves"), "NatSpec should stay with its function");
+    assert!(transfer.contains("uint256 amount\n    ) external returns (bool) {"), "Multi-line signature kept whole");
+    assert!(transfer.trim_end().ends_with('}'));
+    assert!(transfer.contains("return true;"));
+
+    let internal = chunk_containing(&processed, "function _transfer(");
+    assert!(internal.contains("unchecked {"));
+    assert!(internal.contains("_totalSupply -= amount;"), "Nested blocks stay inside the function chunk");
+    assert!(internal.trim_end().ends_with('}'));
+
+    let approve = chunk_containing(&processed, "function approve(");
+    assert!(approve.contains("emit Log(\"approved {spender}; done\");"), "Braces in strings are ignored");
+    assert!(approve.contains("return true;"));
+
+    let constructor = chunk_containing(&processed, "constructor(");
+    assert!(constructor.contains("symbol = symbol_;"));
+}
+
+#[tokio::test]
+async fn test_state_variables_grouped_per_contract() {
+    let processed = process_solidity(ERC20_SOURCE).await;
+
+    let declarations = chunk_containing(&processed, "uint256 private _totalSupply;");
+    assert!(declarations.starts_with("contract ERC20 is IERC20 {"));
+    assert!(declarations.contains("mapping(address => uint256) private _balances;"));
+    assert!(declarations.contains("string public symbol;"));
+    assert!(declarations.contains("event Log(string message);"));
+    assert!(!declarations.contains("function"), "Functions belong in their own chunks");
+}
+
+#[tokio::test]
+async fn test_interface_functions_without_bodies() {
+    let source = "pragma solidity ^0.8.0;\ninterface IERC20 {\n    function totalSupply() external view returns (uint256);\n    function transfer(address to, uint256 amount)\n        external\n        returns (bool);\n}\n";
+    let processed = process_solidity(source).await;
+
+    assert_eq!(processed.chunks.len(), 2, "{:#?}", processed.chunks);
+    assert_eq!(processed.chunks[0], "function totalSupply() external view returns (uint256);");
+    assert!(processed.chunks[1].starts_with("function transfer(address to, uint256 amount)"));
+    assert!(processed.chunks[1].ends_with("returns (bool);"));
+}
+
+#[tokio::test]
+async fn test_oversized_functions_respect_max_chunk_size() {
+    let processor = DefaultDocumentProcessor::new(20, 120);
+    let processed = process_solidity_with(&processor, ERC20_SOURCE).await;
+
+    for chunk in &processed.chunks {
+        assert!(chunk.chars().count() <= 120, "Chunk over the limit: {:?}", chunk);
+    }
+
+    // A function that fits is still one chunk
+    let total_supply = chunk_containing(&processed, "function totalSupply()");
+    assert!(total_supply.trim_end().ends_with('}'));
+
+    // A larger one is split into overlapping pieces, starting at its signature
+    let first = processed.chunks.iter().position(|chunk| chunk.contains("function _transfer(")).unwrap();
+    let body = processed.chunks.iter().position(|chunk| chunk.contains("_totalSupply -= amount;")).unwrap();
+    assert!(body > first, "The _transfer body spans several chunks: {:#?}", processed.chunks);
+    assert!(processed.chunks[first].starts_with("function _transfer("), "Overlap doesn't carry the previous member in");
+    let tail: String = processed.chunks[first].chars().rev().take(20).collect::<Vec<_>>().into_iter().rev().collect();
+    assert!(processed.chunks[first + 1].starts_with(&tail), "Pieces of one member overlap");
+}
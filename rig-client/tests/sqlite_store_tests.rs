@@ -0,0 +1,89 @@
+//! Tests for the SQLite-backed document store
+//! Verifies documents round-trip through storage and survive reopening the database
+
+use chrono::Utc;
+use rig_client::doc_ingestion::store::SqliteDocStore;
+use rig_client::doc_ingestion::{
+    DocumentMetadata, DocumentSourceMetadata, DocumentStore, DocumentType, ProcessedDocument,
+};
+use std::path::PathBuf;
+
+fn temp_db_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("rig_client_store_{}_{}.sqlite", name, std::process::id()))
+}
+
+fn sample_document(title: &str, checksum: &str) -> ProcessedDocument {
+    ProcessedDocument {
+        content: "# Router\nswapExactETHForTokens swaps ETH for tokens".to_string(),
+        chunks: vec!["# Router".to_string(), "swapExactETHForTokens swaps ETH for tokens".to_string()],
+        metadata: DocumentMetadata {
+            title: title.to_string(),
+            doc_type: DocumentType::Markdown,
+            version: Some("v2".to_string()),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            source: DocumentSourceMetadata {
+                source_type: "local".to_string(),
+                location: "/tmp/docs".to_string(),
+                version: None,
+            },
+            tags: vec!["v2".to_string(), "documentation".to_string()],
+        },
+        checksum: checksum.to_string(),
+    }
+}
+
+#[tokio::test]
+async fn test_store_and_get_round_trip() {
+    let store = SqliteDocStore::open_in_memory().unwrap();
+    let doc = sample_document("router.md", "abc123");
+
+    store.store_document(doc.clone()).await.unwrap();
+    let loaded = store.get_document("abc123").await.unwrap().expect("document should be stored");
+
+    assert_eq!(loaded.content, doc.content);
+    assert_eq!(loaded.chunks, doc.chunks);
+    assert_eq!(loaded.checksum, doc.checksum);
+    assert_eq!(loaded.metadata.title, doc.metadata.title);
+    assert_eq!(loaded.metadata.tags, doc.metadata.tags);
+    assert!(matches!(loaded.metadata.doc_type, DocumentType::Markdown));
+
+    assert!(store.get_document("missing").await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_list_and_delete_documents() {
+    let store = SqliteDocStore::open_in_memory().unwrap();
+    store.store_document(sample_document("b.md", "bbb")).await.unwrap();
+    store.store_document(sample_document("a.md", "aaa")).await.unwrap();
+
+    let titles: Vec<_> = store.list_documents().await.unwrap().into_iter().map(|m| m.title).collect();
+    assert_eq!(titles, vec!["a.md", "b.md"]);
+
+    let mut checksums = store.list_checksums().await.unwrap();
+    checksums.sort();
+    assert_eq!(checksums, vec!["aaa", "bbb"]);
+
+    store.delete_document("aaa").await.unwrap();
+    let titles: Vec<_> = store.list_documents().await.unwrap().into_iter().map(|m| m.title).collect();
+    assert_eq!(titles, vec!["b.md"]);
+}
+
+#[tokio::test]
+async fn test_documents_persist_across_reopen() {
+    let path = temp_db_path("persist");
+    let _ = std::fs::remove_file(&path);
+
+    {
+        let store = SqliteDocStore::open(&path).unwrap();
+        store.store_document(sample_document("pair.md", "persisted")).await.unwrap();
+    }
+
+    // Reopening runs migrations again, which must be a no-op on an existing schema
+    let store = SqliteDocStore::open(&path).unwrap();
+    let loaded = store.get_document("persisted").await.unwrap();
+    assert!(loaded.is_some(), "Document should survive reopening the store");
+
+    drop(store);
+    std::fs::remove_file(&path).unwrap();
+}
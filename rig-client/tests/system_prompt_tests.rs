@@ -0,0 +1,71 @@
+//! Tests for loading and rendering a custom system prompt
+//! Uses temp files so no Claude client is needed
+
+use clap::Parser;
+use rig_client::agent::render_system_prompt;
+use rig_client::config::{load_system_prompt, DefaultAccounts};
+use rig_client::{BlockchainAgent, Config};
+
+fn temp_prompt(name: &str, contents: &str) -> std::path::PathBuf {
+    let path = std::env::temp_dir().join(format!("rig_client_system_prompt_{}_{}.txt", name, std::process::id()));
+    std::fs::write(&path, contents).unwrap();
+    path
+}
+
+#[test]
+fn test_render_fills_in_accounts_and_model() {
+    let accounts = DefaultAccounts {
+        sender: "carol".to_string(),
+        sender_index: 2,
+        recipient: "dave".to_string(),
+        recipient_index: 3,
+    };
+
+    let rendered = render_system_prompt(
+        "You run on {MODEL}. {SENDER} (account {SENDER_INDEX}) pays {RECIPIENT} (account {RECIPIENT_INDEX}). {UNKNOWN} stays.",
+        &accounts,
+        "claude-3-5-haiku-latest",
+    );
+
+    assert_eq!(rendered, "You run on claude-3-5-haiku-latest. Carol (account 2) pays Dave (account 3). {UNKNOWN} stays.");
+}
+
+#[test]
+fn test_built_in_prompt_has_no_leftover_placeholders() {
+    let rendered = render_system_prompt(BlockchainAgent::default_system_prompt(), &DefaultAccounts::default(), "claude");
+    assert!(rendered.contains("Alice: Account 0 from anvil (DEFAULT SENDER)"));
+    assert!(!rendered.contains("{SENDER"));
+    assert!(!rendered.contains("{RECIPIENT"));
+}
+
+#[test]
+fn test_load_system_prompt_from_file() {
+    let prompt = format!("  You are a terse DeFi assistant for {{SENDER}}.\n{}\n", "Always use the MCP tools. ".repeat(10));
+    let path = temp_prompt("valid", &prompt);
+
+    let config = Config::try_parse_from(["rig-client", "--system-prompt", path.to_str().unwrap()]).unwrap();
+    let template = config.system_prompt_template().unwrap().unwrap();
+    assert!(template.starts_with("You are a terse DeFi assistant for {SENDER}."), "Surrounding whitespace is trimmed");
+
+    // Short prompts are accepted (with a warning)
+    let short = temp_prompt("short", "Be brief.");
+    assert_eq!(load_system_prompt(&short).unwrap(), "Be brief.");
+
+    std::fs::remove_file(path).ok();
+    std::fs::remove_file(short).ok();
+}
+
+#[test]
+fn test_load_system_prompt_rejects_empty_and_missing_files() {
+    let empty = temp_prompt("empty", " \n\n ");
+    let error = load_system_prompt(&empty).unwrap_err();
+    assert!(error.to_string().contains("is empty"));
+    std::fs::remove_file(empty).ok();
+
+    let missing = std::env::temp_dir().join("rig_client_system_prompt_missing.txt");
+    assert!(load_system_prompt(&missing).is_err());
+
+    // Without the flag the built-in prompt is used
+    let config = Config::try_parse_from(["rig-client"]).unwrap();
+    assert!(config.system_prompt_template().unwrap().is_none());
+}